@@ -29,6 +29,7 @@ pub struct Capabilities {
     pub os: OsInfo,
 
     /// Available diagnostic tools and their capabilities.
+    #[serde(serialize_with = "crate::serialize_sorted")]
     pub tools: HashMap<String, ToolInfo>,
 
     /// Linux /proc filesystem availability (None on non-Linux).
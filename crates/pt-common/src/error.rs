@@ -22,6 +22,7 @@
 //! ```json
 //! {
 //!   "code": 11,
+//!   "code_name": "invalid-priors",
 //!   "category": "config",
 //!   "message": "invalid priors file: parse error at line 5",
 //!   "recoverable": true,
@@ -143,6 +144,12 @@ pub enum Error {
     #[error("permission denied accessing process {pid}")]
     PermissionDenied { pid: u32 },
 
+    #[error("invalid {kind}: {value:?}")]
+    InvalidId { kind: &'static str, value: String },
+
+    #[error("invalid pid list entry {token:?}: {reason}")]
+    InvalidPidList { token: String, reason: String },
+
     // Inference errors (30-39)
     #[error("inference failed: {0}")]
     Inference(String),
@@ -206,6 +213,8 @@ impl Error {
             Error::ProcessNotFound { .. } => 21,
             Error::IdentityMismatch { .. } => 22,
             Error::PermissionDenied { .. } => 23,
+            Error::InvalidId { .. } => 24,
+            Error::InvalidPidList { .. } => 25,
             Error::Inference(_) => 30,
             Error::NumericalInstability(_) => 31,
             Error::ActionFailed(_) => 40,
@@ -221,6 +230,37 @@ impl Error {
         }
     }
 
+    /// Returns a stable kebab-case identifier for this error type.
+    ///
+    /// Unlike the message, the name never changes when wording is revised,
+    /// so agents can match on it alongside the numeric [`code`](Self::code).
+    pub fn code_name(&self) -> &'static str {
+        match self {
+            Error::Config(_) => "config-error",
+            Error::InvalidPriors(_) => "invalid-priors",
+            Error::InvalidPolicy(_) => "invalid-policy",
+            Error::SchemaValidation(_) => "schema-validation",
+            Error::Collection(_) => "collection-failed",
+            Error::ProcessNotFound { .. } => "process-not-found",
+            Error::IdentityMismatch { .. } => "pid-reuse",
+            Error::PermissionDenied { .. } => "permission-denied",
+            Error::InvalidId { .. } => "invalid-id",
+            Error::InvalidPidList { .. } => "invalid-pid-list",
+            Error::Inference(_) => "inference-failed",
+            Error::NumericalInstability(_) => "numerical-instability",
+            Error::ActionFailed(_) => "action-failed",
+            Error::PolicyBlocked(_) => "policy-blocked",
+            Error::ActionTimeout { .. } => "action-timeout",
+            Error::SessionNotFound { .. } => "session-not-found",
+            Error::SessionExpired { .. } => "session-expired",
+            Error::SessionCorrupted(_) => "session-corrupted",
+            Error::Io(_) => "io-error",
+            Error::Json(_) => "json-error",
+            Error::UnsupportedPlatform(_) => "unsupported-platform",
+            Error::CapabilityMissing(_) => "capability-missing",
+        }
+    }
+
     /// Returns the error category for grouping and filtering.
     pub fn category(&self) -> ErrorCategory {
         match self {
@@ -232,7 +272,9 @@ impl Error {
             Error::Collection(_)
             | Error::ProcessNotFound { .. }
             | Error::IdentityMismatch { .. }
-            | Error::PermissionDenied { .. } => ErrorCategory::Collection,
+            | Error::PermissionDenied { .. }
+            | Error::InvalidId { .. }
+            | Error::InvalidPidList { .. } => ErrorCategory::Collection,
 
             Error::Inference(_) | Error::NumericalInstability(_) => ErrorCategory::Inference,
 
@@ -270,6 +312,8 @@ impl Error {
             Error::ProcessNotFound { .. } => false, // Process is gone
             Error::IdentityMismatch { .. } => false, // TOCTOU failure
             Error::PermissionDenied { .. } => true, // Can elevate
+            Error::InvalidId { .. } => false,       // Input is malformed
+            Error::InvalidPidList { .. } => false,  // Input is malformed
 
             // Inference: may be recoverable with different inputs
             Error::Inference(_) => true,
@@ -307,6 +351,8 @@ impl Error {
             Error::ProcessNotFound { .. } => SuggestedAction::Skip,
             Error::IdentityMismatch { .. } => SuggestedAction::Rescan,
             Error::PermissionDenied { .. } => SuggestedAction::Elevate,
+            Error::InvalidId { .. } => SuggestedAction::Skip,
+            Error::InvalidPidList { .. } => SuggestedAction::Abort,
 
             Error::Inference(_) => SuggestedAction::Retry,
             Error::NumericalInstability(_) => SuggestedAction::Skip,
@@ -355,6 +401,12 @@ impl Error {
             Error::PermissionDenied { .. } => {
                 "Run with elevated privileges: 'sudo pt' or set CAP_KILL capability."
             }
+            Error::InvalidId { .. } => {
+                "The identifier is malformed. Copy it verbatim from 'pt agent' output or session listings."
+            }
+            Error::InvalidPidList { .. } => {
+                "Use comma-separated PIDs or ascending ranges, e.g. '1000-1005,2000'."
+            }
 
             Error::Inference(_) => {
                 "Retry with '--deep' for more evidence. If persistent, report as a bug with session bundle."
@@ -411,6 +463,8 @@ impl Error {
             Error::ProcessNotFound { .. } => "Process Not Found",
             Error::IdentityMismatch { .. } => "Process Identity Mismatch",
             Error::PermissionDenied { .. } => "Permission Denied",
+            Error::InvalidId { .. } => "Invalid Identifier",
+            Error::InvalidPidList { .. } => "Invalid PID List",
 
             Error::Inference(_) => "Inference Error",
             Error::NumericalInstability(_) => "Numerical Instability",
@@ -440,6 +494,10 @@ pub struct StructuredError {
     /// Stable error code.
     pub code: u32,
 
+    /// Stable kebab-case error name (see [`Error::code_name`]).
+    #[serde(default)]
+    pub code_name: String,
+
     /// Error category for grouping.
     pub category: ErrorCategory,
 
@@ -453,7 +511,11 @@ pub struct StructuredError {
     pub suggested_action: SuggestedAction,
 
     /// Additional structured context (e.g., pid, file path).
-    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    #[serde(
+        default,
+        skip_serializing_if = "HashMap::is_empty",
+        serialize_with = "crate::serialize_sorted"
+    )]
     pub context: HashMap<String, serde_json::Value>,
 }
 
@@ -469,6 +531,14 @@ impl From<&Error> for StructuredError {
             Error::PermissionDenied { pid } => {
                 context.insert("pid".to_string(), serde_json::json!(pid));
             }
+            Error::InvalidId { kind, value } => {
+                context.insert("kind".to_string(), serde_json::json!(kind));
+                context.insert("value".to_string(), serde_json::json!(value));
+            }
+            Error::InvalidPidList { token, reason } => {
+                context.insert("token".to_string(), serde_json::json!(token));
+                context.insert("reason".to_string(), serde_json::json!(reason));
+            }
             Error::IdentityMismatch { expected, actual } => {
                 context.insert("expected_start_id".to_string(), serde_json::json!(expected));
                 context.insert("actual_start_id".to_string(), serde_json::json!(actual));
@@ -487,6 +557,7 @@ impl From<&Error> for StructuredError {
 
         StructuredError {
             code: err.code(),
+            code_name: err.code_name().to_string(),
             category: err.category(),
             message: err.to_string(),
             recoverable: err.is_recoverable(),
@@ -705,6 +776,80 @@ mod tests {
         assert_eq!(Error::ActionTimeout { seconds: 30 }.code(), 42);
     }
 
+    fn all_variants() -> Vec<Error> {
+        vec![
+            Error::Config(String::new()),
+            Error::InvalidPriors(String::new()),
+            Error::InvalidPolicy(String::new()),
+            Error::SchemaValidation(String::new()),
+            Error::Collection(String::new()),
+            Error::ProcessNotFound { pid: 1 },
+            Error::IdentityMismatch {
+                expected: String::new(),
+                actual: String::new(),
+            },
+            Error::PermissionDenied { pid: 1 },
+            Error::InvalidId {
+                kind: "process id",
+                value: String::new(),
+            },
+            Error::InvalidPidList {
+                token: String::new(),
+                reason: String::new(),
+            },
+            Error::Inference(String::new()),
+            Error::NumericalInstability(String::new()),
+            Error::ActionFailed(String::new()),
+            Error::PolicyBlocked(String::new()),
+            Error::ActionTimeout { seconds: 1 },
+            Error::SessionNotFound {
+                session_id: String::new(),
+            },
+            Error::SessionExpired {
+                session_id: String::new(),
+            },
+            Error::SessionCorrupted(String::new()),
+            Error::Io(std::io::Error::other("io")),
+            Error::Json(serde_json::from_str::<u8>("x").unwrap_err()),
+            Error::UnsupportedPlatform(String::new()),
+            Error::CapabilityMissing(String::new()),
+        ]
+    }
+
+    #[test]
+    fn test_error_code_names_unique_and_stable() {
+        let variants = all_variants();
+        let names: std::collections::HashSet<&str> =
+            variants.iter().map(Error::code_name).collect();
+        assert_eq!(names.len(), variants.len(), "duplicate code_name");
+        let codes: std::collections::HashSet<u32> = variants.iter().map(Error::code).collect();
+        assert_eq!(codes.len(), variants.len(), "duplicate code");
+
+        for name in &names {
+            assert!(
+                name.chars().all(|c| c.is_ascii_lowercase() || c == '-'),
+                "{name} is not kebab-case"
+            );
+        }
+        assert_eq!(Error::Config("x".into()).code_name(), "config-error");
+        assert_eq!(
+            Error::IdentityMismatch {
+                expected: "a".into(),
+                actual: "b".into()
+            }
+            .code_name(),
+            "pid-reuse"
+        );
+    }
+
+    #[test]
+    fn test_structured_error_includes_code_name() {
+        let err = Error::ProcessNotFound { pid: 7 };
+        let json = StructuredError::from(&err).to_json();
+        assert!(json.contains(r#""code_name":"process-not-found""#));
+        assert_eq!(err.to_string(), "process 7 not found");
+    }
+
     #[test]
     fn test_error_category() {
         assert_eq!(
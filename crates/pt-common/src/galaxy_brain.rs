@@ -118,6 +118,7 @@ pub struct MathCard {
     pub equations: Vec<Equation>,
 
     /// Concrete computed values.
+    #[serde(serialize_with = "crate::serialize_sorted")]
     pub values: HashMap<String, ComputedValue>,
 
     /// One-line plain-English explanation.
@@ -2,13 +2,24 @@
 //!
 //! These types ensure safe process identification across the codebase.
 //! A process is uniquely identified by (pid, start_id, uid) tuple.
+//!
+//! `ProcessId`, `StartId` and `SessionId` are totally ordered and hashable,
+//! and their `FromStr` implementations accept exactly the strings their
+//! `Display` implementations produce, so IDs can be sorted in telemetry and
+//! parsed back out of logs without loss.
 
+use crate::error::Error;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashSet;
 use std::fmt;
+use std::str::FromStr;
 
 /// Process ID wrapper with display formatting.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, JsonSchema,
+)]
 #[serde(transparent)]
 pub struct ProcessId(pub u32);
 
@@ -18,19 +29,100 @@ impl fmt::Display for ProcessId {
     }
 }
 
+impl FromStr for ProcessId {
+    type Err = Error;
+
+    /// Parse the canonical decimal form (no sign, no leading zeros).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::InvalidId {
+            kind: "process id",
+            value: s.to_string(),
+        };
+        let canonical = !s.is_empty()
+            && s.bytes().all(|b| b.is_ascii_digit())
+            && (s == "0" || !s.starts_with('0'));
+        if !canonical {
+            return Err(invalid());
+        }
+        s.parse::<u32>().map(ProcessId).map_err(|_| invalid())
+    }
+}
+
 impl From<u32> for ProcessId {
     fn from(pid: u32) -> Self {
         ProcessId(pid)
     }
 }
 
+/// Environment variable that carries a session ID between commands.
+pub const SESSION_ID_ENV: &str = "PT_SESSION_ID";
+
+/// Default cap on how many PIDs [`ProcessId::parse_list`] may expand to.
+pub const DEFAULT_PID_LIST_LIMIT: usize = 4096;
+
+impl ProcessId {
+    /// Parse a comma-separated list of PIDs and inclusive ranges, e.g.
+    /// `1000-1005,2000`.
+    ///
+    /// Ranges are expanded and duplicates dropped, keeping first-seen order.
+    /// Expansion is capped at [`DEFAULT_PID_LIST_LIMIT`].
+    pub fn parse_list(s: &str) -> Result<Vec<ProcessId>, Error> {
+        Self::parse_list_with_limit(s, DEFAULT_PID_LIST_LIMIT)
+    }
+
+    /// Like [`Self::parse_list`], rejecting lists whose ranges expand to more
+    /// than `max` PIDs in total (duplicates included).
+    pub fn parse_list_with_limit(s: &str, max: usize) -> Result<Vec<ProcessId>, Error> {
+        let mut seen = HashSet::new();
+        let mut pids = Vec::new();
+        let mut expanded = 0u64;
+        for token in s.split(',').map(str::trim) {
+            let invalid = |reason: String| Error::InvalidPidList {
+                token: token.to_string(),
+                reason,
+            };
+            let bound = |part: &str| {
+                part.trim()
+                    .parse::<ProcessId>()
+                    .map_err(|_| invalid(format!("{:?} is not a valid process id", part.trim())))
+            };
+            if token.is_empty() {
+                return Err(invalid("empty entry".to_string()));
+            }
+            let (start, end) = match token.split_once('-') {
+                Some((start, end)) => (bound(start)?, bound(end)?),
+                None => {
+                    let pid = bound(token)?;
+                    (pid, pid)
+                }
+            };
+            if start > end {
+                return Err(invalid(format!("range is inverted ({start} > {end})")));
+            }
+            expanded += u64::from(end.0 - start.0) + 1;
+            if expanded > max as u64 {
+                return Err(invalid(format!("list expands to more than {max} pids")));
+            }
+            for pid in start.0..=end.0 {
+                if seen.insert(pid) {
+                    pids.push(ProcessId(pid));
+                }
+            }
+        }
+        Ok(pids)
+    }
+}
+
 /// Start ID - unique identifier for a specific process incarnation.
 ///
 /// Format: `<boot_id>:<start_time_ticks>:<pid>` (Linux)
 /// or `<boot_id>:<start_time>:<pid>` (macOS)
 ///
 /// This disambiguates PID reuse across reboots and within a boot.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+/// Ordering is lexicographic on the string form.
+#[derive(
+    Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, JsonSchema,
+)]
 #[serde(transparent)]
 pub struct StartId(pub String);
 
@@ -75,11 +167,67 @@ impl fmt::Display for StartId {
     }
 }
 
+impl StartId {
+    /// Start-time jitter tolerated by [`same_incarnation`](Self::same_incarnation).
+    ///
+    /// 150 ticks is 1.5s at the usual 100Hz `USER_HZ`, enough to absorb
+    /// `ps` rounding and small drift between collection paths.
+    pub const START_TIME_TOLERANCE: u64 = 150;
+
+    /// Whether `current` refers to the same process incarnation as `self`.
+    ///
+    /// Accepts an exact match, or matching start times (the middle field of
+    /// `boot:start:pid`, or a bare start time) within
+    /// [`START_TIME_TOLERANCE`](Self::START_TIME_TOLERANCE).
+    pub fn same_incarnation(&self, current: &StartId) -> bool {
+        if self.0 == current.0 {
+            return true;
+        }
+
+        fn extract_start_time(id: &str) -> Option<&str> {
+            let parts: Vec<&str> = id.split(':').collect();
+            match parts.len() {
+                1 => Some(parts[0]),
+                3 => Some(parts[1]),
+                _ => None,
+            }
+        }
+
+        match (extract_start_time(&self.0), extract_start_time(&current.0)) {
+            (Some(e), Some(c)) => {
+                if let (Ok(e_ticks), Ok(c_ticks)) = (e.parse::<u64>(), c.parse::<u64>()) {
+                    if e_ticks.abs_diff(c_ticks) <= Self::START_TIME_TOLERANCE {
+                        return true;
+                    }
+                }
+                e == c
+            }
+            _ => false,
+        }
+    }
+}
+
+impl FromStr for StartId {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        StartId::parse(s).ok_or_else(|| Error::InvalidId {
+            kind: "start id",
+            value: s.to_string(),
+        })
+    }
+}
+
 /// Session ID for tracking triage sessions.
 ///
 /// Format: `pt-YYYYMMDD-HHMMSS-XXXX`
 /// Example: `pt-20260115-143022-a7xq`
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+///
+/// Because the timestamp is fixed-width, ordering sorts sessions
+/// chronologically (ties broken by suffix).
+#[derive(
+    Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, JsonSchema,
+)]
 #[serde(transparent)]
 pub struct SessionId(pub String);
 
@@ -124,6 +272,58 @@ impl SessionId {
         }
         Some(SessionId(s.to_string()))
     }
+
+    /// Read the session ID from [`SESSION_ID_ENV`].
+    ///
+    /// Returns `Ok(None)` when the variable is unset or empty, and an error
+    /// when it holds anything that is not a valid session ID, so a typo never
+    /// silently starts an unrelated session.
+    pub fn from_env() -> Result<Option<Self>, Error> {
+        Self::from_env_value(std::env::var_os(SESSION_ID_ENV))
+    }
+
+    fn from_env_value(value: Option<std::ffi::OsString>) -> Result<Option<Self>, Error> {
+        let Some(value) = value.filter(|v| !v.is_empty()) else {
+            return Ok(None);
+        };
+        let value = value.into_string().map_err(|raw| Error::InvalidId {
+            kind: "session id",
+            value: raw.to_string_lossy().into_owned(),
+        })?;
+        value.trim().parse().map(Some)
+    }
+
+    /// Set [`SESSION_ID_ENV`] for this process and any commands it spawns.
+    ///
+    /// Mutating the environment is not thread-safe; call this before
+    /// spawning threads, or pass [`SESSION_ID_ENV`] to `Command::env` instead.
+    pub fn export_env(&self) {
+        std::env::set_var(SESSION_ID_ENV, &self.0);
+    }
+
+    /// Derive a deterministic sub-session ID, e.g. one per fleet host.
+    ///
+    /// The child keeps the parent's timestamp, so it sorts next to it, and
+    /// replaces the suffix with a hash of the parent ID and `tag`. The same
+    /// parent and tag always give the same child.
+    pub fn derive_child(&self, tag: &str) -> SessionId {
+        let split = self.0.char_indices().rev().nth(3).map_or(0, |(i, _)| i);
+        let (prefix, parent_suffix) = self.0.split_at(split);
+        let mut round = 0u8;
+        loop {
+            let digest = Sha256::new()
+                .chain_update(self.0.as_bytes())
+                .chain_update([0, round])
+                .chain_update(tag.as_bytes())
+                .finalize();
+            let value = u32::from_be_bytes([0, digest[0], digest[1], digest[2]]);
+            let suffix = encode_base32_suffix(value);
+            if suffix != parent_suffix {
+                return SessionId(format!("{prefix}{suffix}"));
+            }
+            round = round.wrapping_add(1);
+        }
+    }
 }
 
 impl Default for SessionId {
@@ -138,6 +338,17 @@ impl fmt::Display for SessionId {
     }
 }
 
+impl FromStr for SessionId {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        SessionId::parse(s).ok_or_else(|| Error::InvalidId {
+            kind: "session id",
+            value: s.to_string(),
+        })
+    }
+}
+
 /// Quality/provenance indicator for process identity.
 ///
 /// Indicates how reliable the identity tuple is for TOCTOU protection.
@@ -253,11 +464,120 @@ impl ProcessIdentity {
     }
 }
 
+/// Outcome of checking a `(ProcessId, StartId)` pair against the live system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum IdentityStatus {
+    /// The PID still refers to the expected process incarnation.
+    Same,
+    /// The PID is alive but belongs to a different process (PID reuse).
+    Reused,
+    /// No process with this PID exists, or its start time is unreadable.
+    Gone,
+}
+
+/// Source of the current [`StartId`] for a live PID.
+///
+/// Start times come from the platform:
+/// - Linux: field 22 (`starttime`, clock ticks since boot) of
+///   `/proc/<pid>/stat`, qualified by `/proc/sys/kernel/random/boot_id`.
+///   See [`ProcStartIdSource`].
+/// - macOS: the collector derives seconds since the epoch from `ps -o lstart`;
+///   there is no in-crate source, so callers supply their own.
+pub trait StartIdSource {
+    /// Current start ID of `pid`, or `None` if the process does not exist.
+    fn current_start_id(&self, pid: ProcessId) -> Option<StartId>;
+}
+
+/// Check that `pid` still refers to the process identified by `expected`.
+pub fn verify_identity_with(
+    source: &dyn StartIdSource,
+    pid: ProcessId,
+    expected: &StartId,
+) -> IdentityStatus {
+    match source.current_start_id(pid) {
+        None => IdentityStatus::Gone,
+        Some(current) if expected.same_incarnation(&current) => IdentityStatus::Same,
+        Some(_) => IdentityStatus::Reused,
+    }
+}
+
+/// The `/proc/<pid>/stat` fields used for identity checks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProcStat {
+    /// Process state (field 3), e.g. `'R'`, `'S'`, `'Z'`.
+    pub state: char,
+    /// Start time in clock ticks since boot (field 22).
+    pub start_time: u64,
+}
+
+impl ProcStat {
+    /// Parse the contents of a `/proc/<pid>/stat` file.
+    pub fn parse(content: &str) -> Option<Self> {
+        // comm may contain spaces or parens; fields resume after the last ')'.
+        let after_comm = content.get(content.rfind(')')? + 2..)?;
+        let mut fields = after_comm.split_whitespace();
+        let state = fields.next()?.chars().next()?;
+        let start_time = fields.nth(18)?.parse().ok()?;
+        Some(Self { state, start_time })
+    }
+
+    /// Read and parse `/proc/<pid>/stat`; `None` if the process is gone.
+    #[cfg(target_os = "linux")]
+    pub fn read(pid: ProcessId) -> Option<Self> {
+        let content = std::fs::read(format!("/proc/{}/stat", pid.0)).ok()?;
+        Self::parse(&String::from_utf8_lossy(&content))
+    }
+}
+
+/// [`StartIdSource`] backed by procfs.
+#[cfg(target_os = "linux")]
+#[derive(Debug, Clone)]
+pub struct ProcStartIdSource {
+    boot_id: String,
+}
+
+#[cfg(target_os = "linux")]
+impl ProcStartIdSource {
+    /// Read the boot ID once; falls back to `"unknown"` if unavailable.
+    pub fn new() -> Self {
+        let boot_id = std::fs::read_to_string("/proc/sys/kernel/random/boot_id")
+            .map(|s| s.trim().to_string())
+            .unwrap_or_else(|_| "unknown".to_string());
+        Self { boot_id }
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl Default for ProcStartIdSource {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl StartIdSource for ProcStartIdSource {
+    fn current_start_id(&self, pid: ProcessId) -> Option<StartId> {
+        let stat = ProcStat::read(pid)?;
+        Some(StartId::from_linux(&self.boot_id, stat.start_time, pid.0))
+    }
+}
+
+/// Check `(pid, expected)` against procfs.
+#[cfg(target_os = "linux")]
+pub fn verify_identity(pid: ProcessId, expected: StartId) -> IdentityStatus {
+    verify_identity_with(&ProcStartIdSource::new(), pid, &expected)
+}
+
 fn generate_base32_suffix() -> String {
     let uuid = uuid::Uuid::new_v4();
     let bytes = uuid.as_bytes();
-    let mut value = ((bytes[0] as u32) << 16) | ((bytes[1] as u32) << 8) | (bytes[2] as u32);
-    value &= 0x000F_FFFF;
+    encode_base32_suffix(((bytes[0] as u32) << 16) | ((bytes[1] as u32) << 8) | (bytes[2] as u32))
+}
+
+/// Encode the low 20 bits of `value` as four RFC 4648 base32 characters.
+fn encode_base32_suffix(value: u32) -> String {
+    let value = value & 0x000F_FFFF;
     let alphabet = b"abcdefghijklmnopqrstuvwxyz234567";
     let mut out = String::with_capacity(4);
     for shift in [15_u32, 10, 5, 0] {
@@ -270,6 +590,271 @@ fn generate_base32_suffix() -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn prop_process_id_roundtrip(pid in any::<u32>()) {
+            let id = ProcessId(pid);
+            prop_assert_eq!(id.to_string().parse::<ProcessId>().unwrap(), id);
+            let json = serde_json::to_string(&id).unwrap();
+            prop_assert_eq!(serde_json::from_str::<ProcessId>(&json).unwrap(), id);
+        }
+
+        #[test]
+        fn prop_start_id_roundtrip(
+            boot in "[a-z0-9-]{1,36}",
+            ticks in any::<u64>(),
+            pid in any::<u32>(),
+        ) {
+            let id = StartId::from_linux(&boot, ticks, pid);
+            prop_assert_eq!(id.to_string().parse::<StartId>().unwrap(), id.clone());
+            let json = serde_json::to_string(&id).unwrap();
+            prop_assert_eq!(serde_json::from_str::<StartId>(&json).unwrap(), id);
+        }
+
+        #[test]
+        fn prop_session_id_roundtrip(s in "pt-[0-9]{8}-[0-9]{6}-[a-z2-7]{4}") {
+            let id: SessionId = s.parse().unwrap();
+            prop_assert_eq!(id.to_string(), s);
+            let json = serde_json::to_string(&id).unwrap();
+            prop_assert_eq!(serde_json::from_str::<SessionId>(&json).unwrap(), id);
+        }
+
+        #[test]
+        fn prop_process_id_order_matches_numeric(a in any::<u32>(), b in any::<u32>()) {
+            prop_assert_eq!(ProcessId(a).cmp(&ProcessId(b)), a.cmp(&b));
+        }
+    }
+
+    #[test]
+    fn test_parse_list_expands_ranges_and_dedups() {
+        let pids = ProcessId::parse_list("1000-1003, 2000,1001,7-7").unwrap();
+        let pids: Vec<u32> = pids.into_iter().map(|p| p.0).collect();
+        assert_eq!(pids, vec![1000, 1001, 1002, 1003, 2000, 7]);
+    }
+
+    #[test]
+    fn test_parse_list_reports_failing_token() {
+        let token = |s: &str| match ProcessId::parse_list(s).unwrap_err() {
+            Error::InvalidPidList { token, .. } => token,
+            other => panic!("unexpected error {other:?}"),
+        };
+        assert_eq!(token("1,5-1,9"), "5-1");
+        assert_eq!(token("1,abc"), "abc");
+        assert_eq!(token("1,,2"), "");
+        assert_eq!(token("-5"), "-5");
+        assert_eq!(token("10-"), "10-");
+        assert_eq!(token("1-2-3"), "1-2-3");
+
+        let err = ProcessId::parse_list("3,5-1").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#"invalid pid list entry "5-1": range is inverted (5 > 1)"#
+        );
+    }
+
+    #[test]
+    fn test_parse_list_caps_expansion() {
+        assert_eq!(
+            ProcessId::parse_list_with_limit("1-10", 10).unwrap().len(),
+            10
+        );
+        let err = ProcessId::parse_list_with_limit("1-10,11", 10).unwrap_err();
+        assert!(matches!(err, Error::InvalidPidList { ref token, .. } if token == "11"));
+        // A huge range is rejected without being materialized.
+        assert!(ProcessId::parse_list("0-4294967295").is_err());
+    }
+
+    struct FixedSource(Option<StartId>);
+
+    impl StartIdSource for FixedSource {
+        fn current_start_id(&self, _pid: ProcessId) -> Option<StartId> {
+            self.0.clone()
+        }
+    }
+
+    #[test]
+    fn test_same_incarnation() {
+        let id = |s: &str| StartId(s.to_string());
+        assert!(id("abc:123:456").same_incarnation(&id("abc:123:456")));
+        // Only the start time is compared.
+        assert!(id("abc:123:456").same_incarnation(&id("def:123:789")));
+        assert!(!id("abc:123:456").same_incarnation(&id("abc:999:456")));
+        // Jitter within tolerance.
+        assert!(id("abc:10000:456").same_incarnation(&id("abc:10050:456")));
+        assert!(id("abc:10000:456").same_incarnation(&id("abc:10150:456")));
+        assert!(!id("abc:10000:456").same_incarnation(&id("abc:10200:456")));
+    }
+
+    #[test]
+    fn test_verify_identity_with_source() {
+        let expected = StartId::from_linux("boot", 10_000, 42);
+        let pid = ProcessId(42);
+
+        let same = FixedSource(Some(expected.clone()));
+        assert_eq!(
+            verify_identity_with(&same, pid, &expected),
+            IdentityStatus::Same
+        );
+
+        let reused = FixedSource(Some(StartId::from_linux("boot", 99_000, 42)));
+        assert_eq!(
+            verify_identity_with(&reused, pid, &expected),
+            IdentityStatus::Reused
+        );
+
+        let gone = FixedSource(None);
+        assert_eq!(
+            verify_identity_with(&gone, pid, &expected),
+            IdentityStatus::Gone
+        );
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_verify_identity_self() {
+        let pid = ProcessId(std::process::id());
+        let source = ProcStartIdSource::new();
+        let current = source.current_start_id(pid).expect("read own start id");
+        assert_eq!(verify_identity(pid, current.clone()), IdentityStatus::Same);
+
+        let ticks: u64 = current.0.split(':').nth(1).unwrap().parse().unwrap();
+        let stale = StartId::from_linux("boot", ticks + 10_000, pid.0);
+        assert_eq!(verify_identity(pid, stale.clone()), IdentityStatus::Reused);
+        assert_eq!(
+            verify_identity(ProcessId(u32::MAX), stale),
+            IdentityStatus::Gone
+        );
+    }
+
+    #[test]
+    fn test_proc_stat_parse() {
+        let line = "4242 (my (odd) cmd) Z 1 4242 4242 0 -1 4194304 100 0 0 0 \
+                    5 3 0 0 20 0 1 0 987654 1234567 89 18446744073709551615";
+        assert_eq!(
+            ProcStat::parse(line),
+            Some(ProcStat {
+                state: 'Z',
+                start_time: 987654,
+            })
+        );
+        assert_eq!(ProcStat::parse("4242 (truncated) S 1 2"), None);
+    }
+
+    #[test]
+    fn test_generated_session_id_roundtrips() {
+        let sid = SessionId::new();
+        assert_eq!(sid.to_string().parse::<SessionId>().unwrap(), sid);
+    }
+
+    #[test]
+    fn test_session_ids_sort_chronologically() {
+        let mut ids: Vec<SessionId> = [
+            "pt-20260115-143022-a7xq",
+            "pt-20251231-235959-zzzz",
+            "pt-20260115-090000-aaaa",
+        ]
+        .iter()
+        .map(|s| s.parse().unwrap())
+        .collect();
+        ids.sort();
+        let sorted: Vec<String> = ids.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            sorted,
+            vec![
+                "pt-20251231-235959-zzzz",
+                "pt-20260115-090000-aaaa",
+                "pt-20260115-143022-a7xq",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_malformed_ids_rejected() {
+        for bad in ["", "+5", "007", "-1", "4294967296", " 12"] {
+            let err = bad.parse::<ProcessId>().unwrap_err();
+            assert!(
+                matches!(
+                    err,
+                    Error::InvalidId {
+                        kind: "process id",
+                        ..
+                    }
+                ),
+                "{bad}"
+            );
+        }
+        for bad in [
+            "",
+            "pt-2026-01-15",
+            "pt-20260115-143022-A7XQ",
+            "pt-20260115-143022-a7x1",
+        ] {
+            let err = bad.parse::<SessionId>().unwrap_err();
+            assert!(
+                matches!(
+                    err,
+                    Error::InvalidId {
+                        kind: "session id",
+                        ..
+                    }
+                ),
+                "{bad}"
+            );
+        }
+        for bad in ["", ":1:2", "boot:x:2", "boot:1:2:3"] {
+            let err = bad.parse::<StartId>().unwrap_err();
+            assert!(
+                matches!(
+                    err,
+                    Error::InvalidId {
+                        kind: "start id",
+                        ..
+                    }
+                ),
+                "{bad}"
+            );
+        }
+        assert_eq!("0".parse::<ProcessId>().unwrap(), ProcessId(0));
+    }
+
+    #[test]
+    fn test_session_id_from_env_value() {
+        let sid = "pt-20260115-143022-a7xq";
+        assert_eq!(SessionId::from_env_value(None).unwrap(), None);
+        assert_eq!(SessionId::from_env_value(Some("".into())).unwrap(), None);
+        assert_eq!(
+            SessionId::from_env_value(Some(sid.into())).unwrap(),
+            Some(SessionId(sid.to_string()))
+        );
+        let err = SessionId::from_env_value(Some("pt-bogus".into())).unwrap_err();
+        assert!(
+            matches!(err, Error::InvalidId { kind: "session id", ref value } if value == "pt-bogus")
+        );
+    }
+
+    #[test]
+    fn test_session_id_env_roundtrip() {
+        let sid = SessionId::new();
+        sid.export_env();
+        assert_eq!(SessionId::from_env().unwrap(), Some(sid));
+        std::env::remove_var(SESSION_ID_ENV);
+        assert_eq!(SessionId::from_env().unwrap(), None);
+    }
+
+    #[test]
+    fn test_derive_child_is_deterministic_and_valid() {
+        let parent: SessionId = "pt-20260115-143022-a7xq".parse().unwrap();
+        let child = parent.derive_child("host-a");
+        assert_eq!(child, parent.derive_child("host-a"));
+        assert_ne!(child, parent);
+        assert_ne!(child, parent.derive_child("host-b"));
+        assert_eq!(child.0.parse::<SessionId>().unwrap(), child);
+        assert!(child.0.starts_with("pt-20260115-143022-"));
+        let other_parent: SessionId = "pt-20260115-143022-b7xq".parse().unwrap();
+        assert_ne!(other_parent.derive_child("host-a"), child);
+    }
 
     #[test]
     fn test_session_id_format() {
@@ -4,6 +4,7 @@
 //! - Process identity types with safety guarantees
 //! - Session and schema versioning
 //! - Common error types
+//! - Stable process exit codes
 //! - Output format specifications
 //! - Configuration loading and validation
 //! - Capabilities detection and caching
@@ -15,6 +16,7 @@ pub mod capabilities;
 pub mod categories;
 pub mod config;
 pub mod error;
+pub mod exit_code;
 pub mod galaxy_brain;
 pub mod id;
 pub mod lineage_evidence;
@@ -47,18 +49,26 @@ pub use error::{
     format_batch_human, format_error_human, BatchError, BatchResult, BatchSummary, Error,
     ErrorCategory, Result, StructuredError, SuggestedAction,
 };
+pub use exit_code::ExitCode;
 pub use galaxy_brain::{
     CardId, CliHints, CliOutputFormat, CliVerbosity, ComputedValue, Equation, GalaxyBrainData,
     MathCard, MathRenderer, Reference, RenderHints, ReportHints, TuiColorScheme, TuiHints,
     ValueFormat, ValueType, GALAXY_BRAIN_SCHEMA_VERSION,
 };
-pub use id::{IdentityQuality, ProcessId, ProcessIdentity, SessionId, StartId};
+#[cfg(target_os = "linux")]
+pub use id::{verify_identity, ProcStartIdSource};
+pub use id::{
+    verify_identity_with, IdentityQuality, IdentityStatus, ProcStat, ProcessId, ProcessIdentity,
+    SessionId, StartId, StartIdSource, DEFAULT_PID_LIST_LIMIT, SESSION_ID_ENV,
+};
 pub use lineage_evidence::{
     normalize_lineage, AncestorEntry, LineageCollectionMethod, NormalizedLineage, OwnershipState,
     RawLineageEvidence, SessionContext, SupervisorEvidence, SupervisorKind, TtyEvidence,
     LINEAGE_EVIDENCE_MISSING, LINEAGE_EVIDENCE_NORMALIZED, LINEAGE_EVIDENCE_VERSION,
 };
-pub use output::OutputFormat;
+pub use output::{
+    serialize_sorted, to_csv_rows, CsvRowWriter, NdjsonWriter, OutputFormat, CSV_COLUMNS,
+};
 pub use provenance::{
     CandidateBlastRadiusOutput, CandidateProvenanceOutput, NarrativeSection, NarrativeVerbosity,
     ProvenanceFeatureContribution, ProvenanceFeatureInput, ProvenanceNarrative,
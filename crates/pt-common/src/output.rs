@@ -1,7 +1,12 @@
 //! Output format specifications.
 
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
+use std::io::{self, Write};
+
 use clap::ValueEnum;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, Serializer};
+use serde_json::{Map, Value};
 
 /// Supported output formats for CLI commands.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum, Serialize, Deserialize)]
@@ -14,6 +19,9 @@ pub enum OutputFormat {
     /// Token-Optimized Object Notation (TOON)
     Toon,
 
+    /// YAML rendering of the structured output
+    Yaml,
+
     /// Human-readable Markdown
     Md,
 
@@ -34,6 +42,15 @@ pub enum OutputFormat {
 
     /// Structured natural language for agent-to-user communication
     Prose,
+
+    /// Self-contained HTML report with inline CSS
+    Html,
+
+    /// RFC 4180 CSV, one row per candidate
+    Csv,
+
+    /// Newline-delimited JSON, one object per process, led by a metadata record
+    Ndjson,
 }
 
 impl std::fmt::Display for OutputFormat {
@@ -41,6 +58,7 @@ impl std::fmt::Display for OutputFormat {
         match self {
             OutputFormat::Json => write!(f, "json"),
             OutputFormat::Toon => write!(f, "toon"),
+            OutputFormat::Yaml => write!(f, "yaml"),
             OutputFormat::Md => write!(f, "md"),
             OutputFormat::Jsonl => write!(f, "jsonl"),
             OutputFormat::Summary => write!(f, "summary"),
@@ -48,6 +66,245 @@ impl std::fmt::Display for OutputFormat {
             OutputFormat::Slack => write!(f, "slack"),
             OutputFormat::Exitcode => write!(f, "exitcode"),
             OutputFormat::Prose => write!(f, "prose"),
+            OutputFormat::Html => write!(f, "html"),
+            OutputFormat::Csv => write!(f, "csv"),
+            OutputFormat::Ndjson => write!(f, "ndjson"),
+        }
+    }
+}
+
+/// Serialize a map with its keys in sorted order so output is stable.
+///
+/// For `#[serde(serialize_with = "pt_common::serialize_sorted")]` on the
+/// `HashMap` fields of output structs, whose iteration order otherwise
+/// differs from run to run.
+pub fn serialize_sorted<S, K, V, H>(
+    map: &HashMap<K, V, H>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    K: Serialize + Ord,
+    V: Serialize,
+{
+    map.iter().collect::<BTreeMap<_, _>>().serialize(serializer)
+}
+
+/// Column order for CSV candidate rows.
+pub const CSV_COLUMNS: [&str; 6] = [
+    "pid",
+    "command",
+    "classification",
+    "recommended_action",
+    "posterior_useful",
+    "posterior_abandoned",
+];
+
+/// Streams candidate JSON objects to `W` as CSV rows in [`CSV_COLUMNS`] order.
+///
+/// The header is written before the first row unless disabled with
+/// [`CsvRowWriter::with_header`], so output from several scans can be
+/// concatenated into one file. Missing fields become empty cells.
+pub struct CsvRowWriter<W: Write> {
+    out: W,
+    header: bool,
+}
+
+impl<W: Write> CsvRowWriter<W> {
+    pub fn new(out: W) -> Self {
+        Self { out, header: true }
+    }
+
+    /// Whether to emit the header line before the first row.
+    pub fn with_header(mut self, header: bool) -> Self {
+        self.header = header;
+        self
+    }
+
+    /// Write one candidate (an entry of a plan's `candidates` array).
+    pub fn write_candidate(&mut self, candidate: &Value) -> io::Result<()> {
+        if std::mem::take(&mut self.header) {
+            self.write_record(CSV_COLUMNS.iter().map(|c| Cow::Borrowed(*c)))?;
+        }
+        let posterior = &candidate["posterior"];
+        self.write_record(
+            [
+                &candidate["pid"],
+                &candidate["command"],
+                &candidate["classification"],
+                &candidate["recommended_action"],
+                &posterior["useful"],
+                &posterior["abandoned"],
+            ]
+            .into_iter()
+            .map(csv_cell),
+        )
+    }
+
+    /// Emit the header even if no rows were written.
+    pub fn finish(mut self) -> io::Result<W> {
+        if std::mem::take(&mut self.header) {
+            self.write_record(CSV_COLUMNS.iter().map(|c| Cow::Borrowed(*c)))?;
+        }
+        self.out.flush()?;
+        Ok(self.out)
+    }
+
+    fn write_record<'a>(&mut self, fields: impl Iterator<Item = Cow<'a, str>>) -> io::Result<()> {
+        for (i, field) in fields.enumerate() {
+            if i > 0 {
+                self.out.write_all(b",")?;
+            }
+            self.out.write_all(csv_quote(&field).as_bytes())?;
+        }
+        self.out.write_all(b"\r\n")
+    }
+}
+
+/// Render `candidates` as CSV, optionally preceded by the header line.
+pub fn to_csv_rows(candidates: &[Value], include_header: bool) -> String {
+    let mut writer = CsvRowWriter::new(Vec::new()).with_header(include_header);
+    for candidate in candidates {
+        writer
+            .write_candidate(candidate)
+            .expect("writing to a Vec cannot fail");
+    }
+    let bytes = writer.finish().expect("writing to a Vec cannot fail");
+    String::from_utf8(bytes).expect("CSV built from UTF-8 strings")
+}
+
+fn csv_cell(value: &Value) -> Cow<'_, str> {
+    match value {
+        Value::Null => Cow::Borrowed(""),
+        Value::String(s) => Cow::Borrowed(s),
+        other => Cow::Owned(other.to_string()),
+    }
+}
+
+/// Quote a field per RFC 4180 when it contains a delimiter, quote, or line break.
+fn csv_quote(field: &str) -> Cow<'_, str> {
+    if field.contains([',', '"', '\n', '\r']) {
+        Cow::Owned(format!("\"{}\"", field.replace('"', "\"\"")))
+    } else {
+        Cow::Borrowed(field)
+    }
+}
+
+/// Writes newline-delimited JSON, flushing after every line.
+///
+/// The first line is a metadata record (`"record": "metadata"`) carrying the
+/// schema version, so consumers can validate before reading any process
+/// records. Each later line is one process object, written as soon as it is
+/// available instead of after the whole result set is buffered.
+pub struct NdjsonWriter<W: Write> {
+    out: W,
+}
+
+impl<W: Write> NdjsonWriter<W> {
+    pub fn new(out: W) -> Self {
+        Self { out }
+    }
+
+    /// Write the leading metadata record; object fields of `extra` are merged in.
+    pub fn write_metadata(&mut self, schema_version: &str, extra: Value) -> io::Result<()> {
+        let mut record = Map::new();
+        record.insert("record".to_string(), Value::from("metadata"));
+        record.insert("schema_version".to_string(), Value::from(schema_version));
+        if let Value::Object(fields) = extra {
+            record.extend(fields);
         }
+        self.write_record(&record)
+    }
+
+    /// Write one record as a single line and flush it.
+    pub fn write_record<T: Serialize + ?Sized>(&mut self, record: &T) -> io::Result<()> {
+        serde_json::to_writer(&mut self.out, record)?;
+        self.out.write_all(b"\n")?;
+        self.out.flush()
+    }
+
+    pub fn into_inner(self) -> W {
+        self.out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Counts {
+        #[serde(serialize_with = "serialize_sorted")]
+        by_name: HashMap<String, u32>,
+    }
+
+    #[test]
+    fn serialize_sorted_ignores_hash_map_order() {
+        let forward = Counts {
+            by_name: (0..64).map(|i| (format!("k{i:02}"), i)).collect(),
+        };
+        let backward = Counts {
+            by_name: (0..64).rev().map(|i| (format!("k{i:02}"), i)).collect(),
+        };
+        let json = serde_json::to_string(&forward).unwrap();
+        assert_eq!(json, serde_json::to_string(&backward).unwrap());
+        assert!(json.starts_with(r#"{"by_name":{"k00":0,"k01":1,"#));
+    }
+
+    #[test]
+    fn csv_rows_use_stable_columns_and_rfc4180_quoting() {
+        let candidates = vec![
+            serde_json::json!({
+                "pid": 42,
+                "command": "sh -c \"echo a,b\"\nnext",
+                "classification": "abandoned",
+                "recommended_action": "kill",
+                "posterior": {"useful": 0.1, "abandoned": 0.85, "zombie": 0.05},
+                "extra": "ignored",
+            }),
+            serde_json::json!({"pid": 7, "command": "sleep"}),
+        ];
+        assert_eq!(
+            to_csv_rows(&candidates, true),
+            "pid,command,classification,recommended_action,posterior_useful,posterior_abandoned\r\n\
+             42,\"sh -c \"\"echo a,b\"\"\nnext\",abandoned,kill,0.1,0.85\r\n\
+             7,sleep,,,,\r\n"
+        );
+    }
+
+    #[test]
+    fn csv_header_is_toggleable_for_concatenation() {
+        let candidates = vec![serde_json::json!({"pid": 1, "command": "a"})];
+        let first = to_csv_rows(&candidates, true);
+        let second = to_csv_rows(&candidates, false);
+        assert_eq!(second, "1,a,,,,\r\n");
+        assert_eq!(format!("{first}{second}").matches("pid,").count(), 1);
+        // An empty scan still produces a header when requested.
+        assert_eq!(to_csv_rows(&[], true).lines().count(), 1);
+        assert!(to_csv_rows(&[], false).is_empty());
+    }
+
+    #[test]
+    fn ndjson_leads_with_metadata_and_writes_one_object_per_line() {
+        let mut writer = NdjsonWriter::new(Vec::new());
+        writer
+            .write_metadata("1.0.0", serde_json::json!({"command": "scan"}))
+            .unwrap();
+        writer
+            .write_record(&serde_json::json!({"pid": 1, "cmd": "a\nb"}))
+            .unwrap();
+        writer.write_record(&serde_json::json!({"pid": 2})).unwrap();
+
+        let text = String::from_utf8(writer.into_inner()).unwrap();
+        let lines: Vec<Value> = text
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0]["record"], "metadata");
+        assert_eq!(lines[0]["schema_version"], "1.0.0");
+        assert_eq!(lines[0]["command"], "scan");
+        assert_eq!(lines[1]["cmd"], "a\nb");
+        assert_eq!(lines[2]["pid"], 2);
     }
 }
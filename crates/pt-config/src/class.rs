@@ -0,0 +1,89 @@
+//! Process classes of the classification model.
+//!
+//! [`Class`] is the single list of classes that per-class tables are keyed
+//! by: the policy's [`LossMatrix`](crate::policy::LossMatrix), the priors'
+//! [`ClassPriors`](crate::priors::ClassPriors) and, in pt-core, the
+//! posterior's `ClassScores`. Code that only needs "every class"
+//! iterates [`Class::ALL`] and indexes those tables by class instead of
+//! naming fields, so adding a class means extending this enum, adding the
+//! matching field to each table, and giving it a loss row.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// A class of the classification model, in canonical order.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum Class {
+    Useful,
+    UsefulBad,
+    Abandoned,
+    Zombie,
+}
+
+impl Class {
+    /// Number of classes.
+    pub const COUNT: usize = 4;
+
+    /// Every class, in canonical order (the order of [`Class::index`]).
+    pub const ALL: [Class; Class::COUNT] = [
+        Class::Useful,
+        Class::UsefulBad,
+        Class::Abandoned,
+        Class::Zombie,
+    ];
+
+    /// Position of this class in [`Class::ALL`].
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Snake-case name, as used in config files and serialized output.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Class::Useful => "useful",
+            Class::UsefulBad => "useful_bad",
+            Class::Abandoned => "abandoned",
+            Class::Zombie => "zombie",
+        }
+    }
+}
+
+impl fmt::Display for Class {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Class {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Class::ALL
+            .into_iter()
+            .find(|class| class.as_str() == s)
+            .ok_or_else(|| format!("unknown class: {}", s))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn order_names_and_parsing_agree() {
+        for (idx, class) in Class::ALL.into_iter().enumerate() {
+            assert_eq!(class.index(), idx);
+            assert_eq!(class.as_str().parse::<Class>(), Ok(class));
+            assert_eq!(
+                serde_json::to_string(&class).unwrap(),
+                format!("\"{}\"", class)
+            );
+        }
+        assert!("malware".parse::<Class>().is_err());
+    }
+}
@@ -0,0 +1,230 @@
+//! `${VAR}` expansion inside config files.
+//!
+//! Opt-in via [`ConfigPaths::with_env_expansion`](crate::resolve::ConfigPaths::with_env_expansion).
+//! Expansion runs on the parsed JSON before deserialization and only
+//! touches string scalars, so keys and structure are never rewritten.
+//!
+//! Supported forms:
+//! - `${VAR}`: value of `VAR`; error if unset
+//! - `${VAR:-default}`: value of `VAR`, or `default` if unset or empty
+//! - `$$`: a literal `$`
+//!
+//! A string that is exactly one reference (e.g. `"${PT_KILL_THRESHOLD}"`)
+//! becomes a JSON number or boolean when the expanded text parses as one,
+//! so numeric thresholds can be templated too.
+
+use serde_json::Value;
+
+use crate::validate::{ValidationError, ValidationResult};
+
+/// Expand environment references in every string scalar of `value`.
+pub fn expand_env_vars(value: &mut Value) -> ValidationResult<()> {
+    expand_env_vars_with(value, &|name| std::env::var(name).ok())
+}
+
+/// [`expand_env_vars`] resolving variable names through `lookup`.
+pub fn expand_env_vars_with(
+    value: &mut Value,
+    lookup: &dyn Fn(&str) -> Option<String>,
+) -> ValidationResult<()> {
+    expand_with(value, "$", lookup)
+}
+
+/// Expand references in a single string such as a config path.
+///
+/// `field` names the string in errors. Unlike string scalars in a file, the
+/// result always stays text.
+pub fn expand_env_str_with(
+    s: &str,
+    field: &str,
+    lookup: &dyn Fn(&str) -> Option<String>,
+) -> ValidationResult<String> {
+    Ok(match expand_scalar(s, field, lookup)? {
+        Some(Value::String(expanded)) => expanded,
+        Some(typed) => typed.to_string(),
+        None => s.to_string(),
+    })
+}
+
+fn expand_with(
+    value: &mut Value,
+    path: &str,
+    lookup: &dyn Fn(&str) -> Option<String>,
+) -> ValidationResult<()> {
+    match value {
+        Value::String(s) => {
+            if let Some(expanded) = expand_scalar(s, path, lookup)? {
+                *value = expanded;
+            }
+        }
+        Value::Array(items) => {
+            for (i, item) in items.iter_mut().enumerate() {
+                expand_with(item, &format!("{}[{}]", path, i), lookup)?;
+            }
+        }
+        Value::Object(map) => {
+            for (key, item) in map.iter_mut() {
+                expand_with(item, &format!("{}.{}", path, key), lookup)?;
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Expand one string; returns `None` when it contains no references.
+fn expand_scalar(
+    s: &str,
+    path: &str,
+    lookup: &dyn Fn(&str) -> Option<String>,
+) -> ValidationResult<Option<Value>> {
+    if !s.contains('$') {
+        return Ok(None);
+    }
+
+    let mut out = String::with_capacity(s.len());
+    let mut references = 0;
+    let mut rest = s;
+
+    while let Some(pos) = rest.find('$') {
+        out.push_str(&rest[..pos]);
+        let tail = &rest[pos..];
+
+        if let Some(after) = tail.strip_prefix("$$") {
+            out.push('$');
+            rest = after;
+        } else if let Some(body) = tail.strip_prefix("${") {
+            let end = body
+                .find('}')
+                .ok_or_else(|| ValidationError::InvalidValue {
+                    field: path.to_string(),
+                    message: format!("unterminated '${{' in {:?}", s),
+                })?;
+            let (name, default) = match body[..end].split_once(":-") {
+                Some((name, default)) => (name, Some(default)),
+                None => (&body[..end], None),
+            };
+            if name.is_empty() {
+                return Err(ValidationError::InvalidValue {
+                    field: path.to_string(),
+                    message: format!("empty variable name in {:?}", s),
+                });
+            }
+
+            let resolved = match (lookup(name), default) {
+                (Some(v), Some(d)) if v.is_empty() => d.to_string(),
+                (Some(v), _) => v,
+                (None, Some(d)) => d.to_string(),
+                (None, None) => {
+                    return Err(ValidationError::UnsetEnvVar {
+                        var: name.to_string(),
+                        field: path.to_string(),
+                    })
+                }
+            };
+            out.push_str(&resolved);
+            references += 1;
+            rest = &body[end + 1..];
+        } else {
+            out.push('$');
+            rest = &tail[1..];
+        }
+    }
+    out.push_str(rest);
+
+    let whole_reference = references == 1 && s.starts_with("${") && s.ends_with('}');
+    if whole_reference {
+        if let Ok(typed @ (Value::Number(_) | Value::Bool(_))) = serde_json::from_str(&out) {
+            return Ok(Some(typed));
+        }
+    }
+    Ok(Some(Value::String(out)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn env(name: &str) -> Option<String> {
+        match name {
+            "PT_KILL_THRESHOLD" => Some("0.85".to_string()),
+            "PT_HOST" => Some("build-01".to_string()),
+            "PT_EMPTY" => Some(String::new()),
+            _ => None,
+        }
+    }
+
+    fn expand(mut value: Value) -> ValidationResult<Value> {
+        expand_with(&mut value, "$", &env)?;
+        Ok(value)
+    }
+
+    #[test]
+    fn test_set_variable_expands() {
+        let out = expand(json!({
+            "threshold": "${PT_KILL_THRESHOLD}",
+            "notes": "host=${PT_HOST}",
+            "list": ["${PT_HOST}-a"]
+        }))
+        .unwrap();
+        assert_eq!(out["threshold"], json!(0.85));
+        assert_eq!(out["notes"], "host=build-01");
+        assert_eq!(out["list"][0], "build-01-a");
+    }
+
+    #[test]
+    fn test_unset_with_default() {
+        let out = expand(json!({
+            "a": "${PT_MISSING:-fallback}",
+            "b": "${PT_MISSING:-3}",
+            "c": "${PT_EMPTY:-used}"
+        }))
+        .unwrap();
+        assert_eq!(out["a"], "fallback");
+        assert_eq!(out["b"], json!(3));
+        assert_eq!(out["c"], "used");
+    }
+
+    #[test]
+    fn test_unset_without_default_errors() {
+        let err = expand(json!({"guardrails": {"note": "${PT_MISSING}"}})).unwrap_err();
+        match err {
+            ValidationError::UnsetEnvVar { var, field } => {
+                assert_eq!(var, "PT_MISSING");
+                assert_eq!(field, "$.guardrails.note");
+            }
+            other => panic!("unexpected error: {other}"),
+        }
+    }
+
+    #[test]
+    fn test_literals_untouched() {
+        let out = expand(json!({"pattern": "^foo$", "cost": "$$5", "n": 1})).unwrap();
+        assert_eq!(out["pattern"], "^foo$");
+        assert_eq!(out["cost"], "$5");
+        assert_eq!(out["n"], 1);
+    }
+
+    #[test]
+    fn test_expand_str_keeps_text() {
+        assert_eq!(
+            expand_env_str_with("/srv/${PT_HOST}/pt", "config_dir", &env).unwrap(),
+            "/srv/build-01/pt"
+        );
+        assert_eq!(
+            expand_env_str_with("${PT_KILL_THRESHOLD}", "x", &env).unwrap(),
+            "0.85"
+        );
+        assert_eq!(expand_env_str_with("/plain", "x", &env).unwrap(), "/plain");
+        assert!(matches!(
+            expand_env_str_with("${PT_MISSING}/pt", "config_dir", &env),
+            Err(ValidationError::UnsetEnvVar { field, .. }) if field == "config_dir"
+        ));
+    }
+
+    #[test]
+    fn test_unterminated_reference_errors() {
+        assert!(expand(json!({"x": "${PT_HOST"})).is_err());
+    }
+}
@@ -4,9 +4,14 @@
 //! - Typed Rust structs for priors.json and policy.json
 //! - Config resolution (CLI → env → XDG → defaults)
 //! - Schema and semantic validation
+//! - Migration of older config documents to the current schema
 //! - Config snapshots for session telemetry
+//! - Hot reload of validated config (optionally on SIGHUP)
 //! - Configuration presets for common deployment scenarios
 
+pub mod class;
+pub mod env_expand;
+pub mod migrate;
 pub mod policy;
 pub mod policy_bundle;
 pub mod preset;
@@ -15,11 +20,17 @@ pub mod provenance;
 pub mod resolve;
 pub mod snapshot;
 pub mod validate;
+pub mod watcher;
 
+pub use class::Class;
+pub use migrate::migrate_config;
 pub use policy::Policy;
 pub use policy_bundle::{PolicyBundle, PolicyBundleError, PolicyMode};
-pub use preset::{get_preset, list_presets, PresetError, PresetInfo, PresetName};
-pub use priors::Priors;
+pub use preset::{
+    get_preset, list_presets, resolve_with_presets, PresetDiff, PresetError, PresetFieldDiff,
+    PresetInfo, PresetName,
+};
+pub use priors::{fit_from_outcomes, OutcomeSample, Priors};
 pub use provenance::{
     EffectiveProvenanceControls, ProvenanceCollectionDepth, ProvenanceControlSurface,
     ProvenanceControlSurfaceKind, ProvenanceControls, ProvenanceDegradationLevel,
@@ -27,9 +38,12 @@ pub use provenance::{
     ProvenancePerformanceBudget, ProvenancePersistenceMode, ProvenanceRedactionLevel,
     ProvenanceRolloutPosture, PROVENANCE_CONTROL_MODEL_VERSION,
 };
-pub use resolve::{resolve_config, ConfigPaths};
-pub use snapshot::ConfigSnapshot;
-pub use validate::{ValidationError, ValidationResult};
+pub use resolve::{resolve_config, ConfigFormat, ConfigPaths};
+pub use snapshot::{ChangeKind, ConfigDiff, ConfigFieldChange, ConfigSnapshot};
+pub use validate::{
+    loss_matrix_findings, validate_loss_matrix_coherence, ValidationError, ValidationResult,
+};
+pub use watcher::{ConfigWatcher, LoadedConfig};
 
 /// Schema version for configuration files.
 pub const CONFIG_SCHEMA_VERSION: &str = "1.0.0";
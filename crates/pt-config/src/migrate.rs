@@ -0,0 +1,170 @@
+//! Forward migrations for older priors.json and policy.json documents.
+//!
+//! Migrations operate on the raw JSON value so that documents which no
+//! longer deserialize into the current types can still be brought forward.
+//! Each entry upgrades documents older than its target version; entries are
+//! applied in order and the result is validated against the current schema.
+
+use serde_json::{Map, Value};
+
+use crate::validate::{validate_policy, validate_priors, ValidationError, ValidationResult};
+use crate::{Policy, Priors, CONFIG_SCHEMA_VERSION};
+
+/// A registered schema migration.
+struct Migration {
+    /// Version produced by this migration.
+    to: &'static str,
+    apply: fn(&mut Map<String, Value>),
+}
+
+/// Registered migrations, oldest first.
+const MIGRATIONS: &[Migration] = &[Migration {
+    to: "1.0.0",
+    apply: rename_max_kills,
+}];
+
+/// Bring a priors or policy document written at schema version `from` up to
+/// [`CONFIG_SCHEMA_VERSION`], then validate it.
+///
+/// Documents newer than the current schema are rejected rather than
+/// downgraded.
+pub fn migrate_config(value: &mut Value, from: &str) -> ValidationResult<()> {
+    let mut version = parse_version(from)?;
+    let current = parse_version(CONFIG_SCHEMA_VERSION)?;
+    if version > current {
+        return Err(ValidationError::VersionMismatch {
+            expected: CONFIG_SCHEMA_VERSION.to_string(),
+            actual: from.to_string(),
+        });
+    }
+
+    let Value::Object(doc) = value else {
+        return Err(ValidationError::SchemaError(
+            "config document must be a JSON object".to_string(),
+        ));
+    };
+
+    for migration in MIGRATIONS {
+        let target = parse_version(migration.to)?;
+        if version < target {
+            (migration.apply)(doc);
+            version = target;
+        }
+    }
+    doc.insert(
+        "schema_version".to_string(),
+        Value::String(CONFIG_SCHEMA_VERSION.to_string()),
+    );
+
+    validate_document(value)
+}
+
+/// Validate a migrated document as priors or policy, based on its shape.
+fn validate_document(value: &Value) -> ValidationResult<()> {
+    let parse_error = |e: serde_json::Error| ValidationError::ParseError(e.to_string());
+    if value.get("classes").is_some() {
+        let priors: Priors = serde_json::from_value(value.clone()).map_err(parse_error)?;
+        validate_priors(&priors)
+    } else if value.get("loss_matrix").is_some() {
+        let policy: Policy = serde_json::from_value(value.clone()).map_err(parse_error)?;
+        validate_policy(&policy)
+    } else {
+        Err(ValidationError::SchemaError(
+            "document is neither priors (classes) nor policy (loss_matrix)".to_string(),
+        ))
+    }
+}
+
+/// Parse a `major.minor.patch` schema version.
+fn parse_version(version: &str) -> ValidationResult<(u32, u32, u32)> {
+    let invalid = || ValidationError::InvalidValue {
+        field: "schema_version".to_string(),
+        message: format!("expected major.minor.patch, got {:?}", version),
+    };
+    let mut parts = version.trim().split('.').map(|p| p.parse::<u32>());
+    match (parts.next(), parts.next(), parts.next(), parts.next()) {
+        (Some(Ok(major)), Some(Ok(minor)), Some(Ok(patch)), None) => Ok((major, minor, patch)),
+        _ => Err(invalid()),
+    }
+}
+
+/// 0.x policies called the per-run kill cap `max_kills`.
+fn rename_max_kills(doc: &mut Map<String, Value>) {
+    let Some(Value::Object(guardrails)) = doc.get_mut("guardrails") else {
+        return;
+    };
+    if guardrails.contains_key("max_kills_per_run") {
+        return;
+    }
+    if let Some(max_kills) = guardrails.remove("max_kills") {
+        guardrails.insert("max_kills_per_run".to_string(), max_kills);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn v0_policy() -> Value {
+        let mut policy = serde_json::to_value(Policy::default()).unwrap();
+        policy["schema_version"] = Value::from("0.9.0");
+        let guardrails = policy["guardrails"].as_object_mut().unwrap();
+        let max_kills = guardrails.remove("max_kills_per_run").unwrap();
+        guardrails.insert("max_kills".to_string(), max_kills);
+        policy
+    }
+
+    #[test]
+    fn test_v0_policy_round_trips_to_current() {
+        let mut policy = v0_policy();
+        assert!(serde_json::from_value::<Policy>(policy.clone()).is_err());
+
+        migrate_config(&mut policy, "0.9.0").unwrap();
+        assert_eq!(policy["schema_version"], CONFIG_SCHEMA_VERSION);
+        assert!(policy["guardrails"].get("max_kills").is_none());
+
+        let migrated: Policy = serde_json::from_value(policy.clone()).unwrap();
+        assert_eq!(
+            serde_json::to_value(&migrated).unwrap(),
+            serde_json::to_value(Policy::default()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_v0_priors_are_stamped_and_validated() {
+        let mut priors = serde_json::to_value(Priors::default()).unwrap();
+        priors["schema_version"] = Value::from("0.3.1");
+        migrate_config(&mut priors, "0.3.1").unwrap();
+        assert_eq!(priors["schema_version"], CONFIG_SCHEMA_VERSION);
+
+        priors["schema_version"] = Value::from("0.3.1");
+        priors["classes"]["useful"]["prior_prob"] = Value::from(0.99);
+        let err = migrate_config(&mut priors, "0.3.1").unwrap_err();
+        assert!(err.to_string().contains("sum to 1.0"), "{}", err);
+    }
+
+    #[test]
+    fn test_current_version_is_a_no_op() {
+        let mut policy = serde_json::to_value(Policy::default()).unwrap();
+        let before = policy.clone();
+        migrate_config(&mut policy, CONFIG_SCHEMA_VERSION).unwrap();
+        assert_eq!(policy, before);
+    }
+
+    #[test]
+    fn test_rejects_newer_and_malformed_versions() {
+        let mut policy = serde_json::to_value(Policy::default()).unwrap();
+        assert!(matches!(
+            migrate_config(&mut policy, "2.0.0"),
+            Err(ValidationError::VersionMismatch { .. })
+        ));
+        assert!(matches!(
+            migrate_config(&mut policy, "1.0"),
+            Err(ValidationError::InvalidValue { .. })
+        ));
+        assert!(matches!(
+            migrate_config(&mut Value::from(1), "0.9.0"),
+            Err(ValidationError::SchemaError(_))
+        ));
+    }
+}
@@ -2,8 +2,11 @@
 //!
 //! These types match the policy.schema.json specification.
 
+use crate::class::Class;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::ops::{Index, IndexMut};
 
 /// Complete policy configuration.
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -36,11 +39,52 @@ pub struct Policy {
     pub load_aware: LoadAwareDecision,
     #[serde(default)]
     pub decision_time_bound: DecisionTimeBound,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub probe_costs: Option<ProbeCosts>,
+    #[serde(default)]
+    pub protection_list: ProtectionList,
 
     #[serde(default)]
     pub notes: Option<String>,
 }
 
+/// Overrides for the value-of-information probe cost model.
+///
+/// Probes are keyed by their serialized probe type name (e.g. `deep_scan`);
+/// probes not listed keep their built-in costs.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ProbeCosts {
+    #[serde(default)]
+    pub costs: BTreeMap<String, ProbeCostOverride>,
+    /// Scales every probe cost; must be > 0.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_multiplier: Option<f64>,
+}
+
+impl ProbeCosts {
+    /// Probe names accepted as `costs` keys: the serialized VOI probe types.
+    pub const PROBE_NAMES: &'static [&'static str] = &[
+        "wait15_min",
+        "wait5_min",
+        "quick_scan",
+        "deep_scan",
+        "stack_sample",
+        "strace",
+        "net_snapshot",
+        "io_snapshot",
+        "cgroup_inspect",
+    ];
+}
+
+/// Cost components for one probe; all must be non-negative.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ProbeCostOverride {
+    pub time_seconds: f64,
+    pub overhead: f64,
+    pub intrusiveness: f64,
+    pub risk: f64,
+}
+
 /// Time-to-decision bound configuration.
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct DecisionTimeBound {
@@ -68,6 +112,9 @@ impl Default for DecisionTimeBound {
 }
 
 /// Loss matrix by class for each action.
+///
+/// Rows can be addressed by field or indexed by [`Class`]; prefer
+/// [`LossMatrix::rows`] or `matrix[class]` when every class is handled alike.
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct LossMatrix {
     pub useful: LossRow,
@@ -76,6 +123,39 @@ pub struct LossMatrix {
     pub zombie: LossRow,
 }
 
+impl LossMatrix {
+    /// Every row with its class, in [`Class::ALL`] order.
+    pub fn rows(&self) -> impl Iterator<Item = (Class, &LossRow)> {
+        Class::ALL
+            .into_iter()
+            .map(move |class| (class, &self[class]))
+    }
+}
+
+impl Index<Class> for LossMatrix {
+    type Output = LossRow;
+
+    fn index(&self, class: Class) -> &LossRow {
+        match class {
+            Class::Useful => &self.useful,
+            Class::UsefulBad => &self.useful_bad,
+            Class::Abandoned => &self.abandoned,
+            Class::Zombie => &self.zombie,
+        }
+    }
+}
+
+impl IndexMut<Class> for LossMatrix {
+    fn index_mut(&mut self, class: Class) -> &mut LossRow {
+        match class {
+            Class::Useful => &mut self.useful,
+            Class::UsefulBad => &mut self.useful_bad,
+            Class::Abandoned => &mut self.abandoned,
+            Class::Zombie => &mut self.zombie,
+        }
+    }
+}
+
 /// Loss values for each action against a class.
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct LossRow {
@@ -181,6 +261,10 @@ pub struct Guardrails {
     #[serde(default)]
     pub max_kills_per_day: Option<u32>,
 
+    /// Cap on Kill/Restart actions as a fraction of a run's candidates (0, 1].
+    #[serde(default)]
+    pub max_kill_fraction: Option<f64>,
+
     pub min_process_age_seconds: u64,
 
     #[serde(default)]
@@ -214,6 +298,7 @@ impl Default for Guardrails {
             max_kills_per_minute: Some(5),
             max_kills_per_hour: Some(20),
             max_kills_per_day: Some(100),
+            max_kill_fraction: None,
             min_process_age_seconds: 300,
             require_confirmation: Some(true),
         }
@@ -288,6 +373,21 @@ pub struct RobotMode {
     pub require_human_for_supervised: bool,
 }
 
+/// Process-name lists that bypass inference entirely.
+///
+/// Entries are globs matched against the process name (`comm`) only, never
+/// the command line. A `never_act_on` match always wins over an
+/// `always_flag` match.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct ProtectionList {
+    /// Processes that are always kept, whatever the posterior says.
+    #[serde(default)]
+    pub never_act_on: Vec<String>,
+    /// Processes that are always surfaced for review with a reversible action.
+    #[serde(default)]
+    pub always_flag: Vec<String>,
+}
+
 /// Signature-informed inference fast-path controls.
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct SignatureFastPath {
@@ -541,6 +641,8 @@ impl Default for Policy {
             data_loss_gates: DataLossGates::default(),
             load_aware: LoadAwareDecision::default(),
             decision_time_bound: DecisionTimeBound::default(),
+            probe_costs: None,
+            protection_list: ProtectionList::default(),
             notes: None,
         }
     }
@@ -569,13 +671,7 @@ impl Policy {
 
     /// Get the loss for a given class and action.
     pub fn loss(&self, class: &str, action: &str) -> Option<f64> {
-        let row = match class {
-            "useful" => &self.loss_matrix.useful,
-            "useful_bad" => &self.loss_matrix.useful_bad,
-            "abandoned" => &self.loss_matrix.abandoned,
-            "zombie" => &self.loss_matrix.zombie,
-            _ => return None,
-        };
+        let row = &self.loss_matrix[class.parse::<Class>().ok()?];
 
         match action {
             "keep" => Some(row.keep),
@@ -827,6 +923,29 @@ mod tests {
         }
     }
 
+    #[test]
+    fn loss_matrix_index_matches_fields() {
+        let mut matrix = LossMatrix::default();
+        let by_field = [
+            matrix.useful.kill,
+            matrix.useful_bad.kill,
+            matrix.abandoned.kill,
+            matrix.zombie.kill,
+        ];
+        let by_class: Vec<f64> = matrix.rows().map(|(_, row)| row.kill).collect();
+        assert_eq!(by_class, by_field);
+
+        // Each class's row is serialized under the class name.
+        let json = serde_json::to_value(&matrix).unwrap();
+        assert_eq!(json.as_object().unwrap().len(), Class::COUNT);
+        for (class, row) in matrix.rows() {
+            assert_eq!(json[class.as_str()]["keep"], row.keep);
+        }
+
+        matrix[Class::Zombie].kill = 7.0;
+        assert_eq!(matrix.zombie.kill, 7.0);
+    }
+
     // ── is_protected ───────────────────────────────────────────────
 
     #[test]
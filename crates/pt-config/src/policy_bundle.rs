@@ -2,7 +2,7 @@
 //!
 //! A `PolicyBundle` wraps a [`Policy`] with metadata for versioning, integrity
 //! verification, and progressive delivery stages. Bundles can optionally carry
-//! an ECDSA signature that is verified against trusted public keys.
+//! a detached ed25519 signature that is verified against a trusted public key.
 //!
 //! # Progressive Delivery Stages
 //!
@@ -10,12 +10,15 @@
 //!    policy, but don't act on them. Enables off-policy comparison.
 //! 2. **Canary** — apply the new policy to a configurable subset of candidates.
 //! 3. **Default** — full rollout; the new policy replaces the active policy.
+//! 4. **Signed** — full rollout that refuses bundles without a valid
+//!    signature from the operator's key.
 //!
 //! # Fallback Behavior
 //!
 //! On any error (parse, schema mismatch, signature failure, corrupt hash),
 //! loading falls back to [`Policy::default()`] embedded in the binary.
 
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 
@@ -33,6 +36,8 @@ pub enum PolicyMode {
     Canary,
     /// Full rollout.
     Default,
+    /// Full rollout, accepted only with a valid signature.
+    Signed,
 }
 
 impl std::fmt::Display for PolicyMode {
@@ -41,6 +46,7 @@ impl std::fmt::Display for PolicyMode {
             PolicyMode::Shadow => write!(f, "shadow"),
             PolicyMode::Canary => write!(f, "canary"),
             PolicyMode::Default => write!(f, "default"),
+            PolicyMode::Signed => write!(f, "signed"),
         }
     }
 }
@@ -68,8 +74,8 @@ pub struct PolicyBundle {
     #[serde(default)]
     pub policy_hash: Option<String>,
 
-    /// Optional ECDSA P-256 signature (base64-encoded DER) over the policy
-    /// hash. Verified using the infrastructure in `install/signature.rs`.
+    /// Optional detached ed25519 signature (hex) over the bundle's policy
+    /// hash and delivery metadata. See [`PolicyBundle::sign`].
     #[serde(default)]
     pub signature: Option<String>,
 
@@ -106,6 +112,9 @@ pub enum PolicyBundleError {
 
     #[error("signature present but no verifier provided")]
     NoVerifier,
+
+    #[error("signature invalid: {0}")]
+    SignatureInvalid(String),
 }
 
 // ── Bundle implementation ───────────────────────────────────────────────
@@ -136,9 +145,33 @@ impl PolicyBundle {
     }
 
     /// Parse a bundle from JSON, verifying integrity.
+    ///
+    /// For callers without a trusted key: bundles that carry a signature or
+    /// are in `Signed` mode are rejected with [`PolicyBundleError::NoVerifier`]
+    /// rather than loaded unchecked; use [`PolicyBundle::from_json_verified`]
+    /// for those.
     pub fn from_json(json: &str) -> Result<Self, PolicyBundleError> {
         let bundle: PolicyBundle = serde_json::from_str(json)?;
         bundle.verify_integrity()?;
+        if bundle.signature.is_some() || bundle.policy_mode == PolicyMode::Signed {
+            return Err(PolicyBundleError::NoVerifier);
+        }
+        Ok(bundle)
+    }
+
+    /// Parse a bundle from JSON, verifying integrity and its signature.
+    ///
+    /// Supplying a trusted key means signatures are required: every bundle
+    /// must carry a valid signature from `pubkey`, whatever its own mode
+    /// says, so stripping the signature or downgrading the mode cannot
+    /// bypass verification.
+    pub fn from_json_verified(
+        json: &str,
+        pubkey: &VerifyingKey,
+    ) -> Result<Self, PolicyBundleError> {
+        let bundle: PolicyBundle = serde_json::from_str(json)?;
+        bundle.verify_integrity()?;
+        bundle.verify(pubkey)?;
         Ok(bundle)
     }
 
@@ -195,14 +228,60 @@ impl PolicyBundle {
         Ok(())
     }
 
+    /// Return a copy of this bundle carrying a signature made with `key`.
+    ///
+    /// The signature covers the policy hash (recomputed from the policy, so
+    /// a stale `policy_hash` cannot be signed over) together with the bundle
+    /// version, delivery mode, canary fraction, changelog and timestamp.
+    pub fn sign(&self, key: &SigningKey) -> Result<Self, PolicyBundleError> {
+        let signature = key.sign(&self.signing_payload()?);
+        let mut signed = self.clone();
+        signed.signature = Some(hex::encode(signature.to_bytes()));
+        Ok(signed)
+    }
+
+    /// Verify the bundle's signature against `pubkey`.
+    ///
+    /// Fails with [`PolicyBundleError::SignatureInvalid`] when the bundle is
+    /// unsigned, the signature is malformed, or it does not match.
+    pub fn verify(&self, pubkey: &VerifyingKey) -> Result<(), PolicyBundleError> {
+        let encoded = self
+            .signature
+            .as_deref()
+            .ok_or_else(|| PolicyBundleError::SignatureInvalid("bundle is unsigned".into()))?;
+        let bytes = hex::decode(encoded)
+            .map_err(|e| PolicyBundleError::SignatureInvalid(format!("bad encoding: {e}")))?;
+        let signature = Signature::from_slice(&bytes)
+            .map_err(|e| PolicyBundleError::SignatureInvalid(format!("bad signature: {e}")))?;
+        pubkey
+            .verify(&self.signing_payload()?, &signature)
+            .map_err(|_| {
+                PolicyBundleError::SignatureInvalid("signature does not match bundle".into())
+            })
+    }
+
+    /// Canonical bytes covered by the bundle signature.
+    fn signing_payload(&self) -> Result<Vec<u8>, PolicyBundleError> {
+        let policy_json = serde_json::to_string(&self.policy)?;
+        let payload = serde_json::json!({
+            "bundle_version": self.bundle_version,
+            "policy_hash": sha256_hex(policy_json.as_bytes()),
+            "policy_mode": self.policy_mode,
+            "canary_fraction": self.canary_fraction,
+            "changelog": self.changelog,
+            "created_at": self.created_at,
+        });
+        Ok(serde_json::to_vec(&payload)?)
+    }
+
     /// Whether this bundle should apply to a given candidate.
     ///
-    /// In `Default` mode, always returns true.
+    /// In `Default` and `Signed` modes, always returns true.
     /// In `Shadow` mode, always returns false (the policy is for logging only).
     /// In `Canary` mode, uses a simple hash-based selection.
     pub fn should_apply(&self, candidate_id: &str) -> bool {
         match self.policy_mode {
-            PolicyMode::Default => true,
+            PolicyMode::Default | PolicyMode::Signed => true,
             PolicyMode::Shadow => false,
             PolicyMode::Canary => {
                 let frac = self.canary_fraction.unwrap_or(0.1);
@@ -316,7 +395,12 @@ mod tests {
 
     #[test]
     fn policy_mode_serde() {
-        for mode in &[PolicyMode::Shadow, PolicyMode::Canary, PolicyMode::Default] {
+        for mode in &[
+            PolicyMode::Shadow,
+            PolicyMode::Canary,
+            PolicyMode::Default,
+            PolicyMode::Signed,
+        ] {
             let json = serde_json::to_string(mode).unwrap();
             let back: PolicyMode = serde_json::from_str(&json).unwrap();
             assert_eq!(*mode, back);
@@ -328,6 +412,7 @@ mod tests {
         assert_eq!(PolicyMode::Shadow.to_string(), "shadow");
         assert_eq!(PolicyMode::Canary.to_string(), "canary");
         assert_eq!(PolicyMode::Default.to_string(), "default");
+        assert_eq!(PolicyMode::Signed.to_string(), "signed");
     }
 
     #[test]
@@ -355,6 +440,114 @@ mod tests {
         assert_eq!(h1.len(), 64); // 256 bits = 64 hex chars
     }
 
+    fn test_key(seed: u8) -> SigningKey {
+        SigningKey::from_bytes(&[seed; 32])
+    }
+
+    #[test]
+    fn sign_verify_roundtrip() {
+        let key = test_key(7);
+        let bundle = PolicyBundle::new(Policy::default(), PolicyMode::Signed)
+            .unwrap()
+            .sign(&key)
+            .unwrap();
+        assert!(bundle.verify(&key.verifying_key()).is_ok());
+
+        let json = bundle.to_json().unwrap();
+        let back = PolicyBundle::from_json_verified(&json, &key.verifying_key()).unwrap();
+        assert_eq!(back.policy_mode, PolicyMode::Signed);
+        assert!(back.should_apply("any-candidate"));
+    }
+
+    #[test]
+    fn tampered_signed_bundle_rejected() {
+        let key = test_key(7);
+        let signed = PolicyBundle::new(Policy::default(), PolicyMode::Default)
+            .unwrap()
+            .sign(&key)
+            .unwrap();
+
+        // Policy change with a recomputed hash still breaks the signature.
+        let mut tampered = signed.clone();
+        tampered.policy.loss_matrix.useful.kill = 0.0;
+        let json = serde_json::to_string(&tampered.policy).unwrap();
+        tampered.policy_hash = Some(sha256_hex(json.as_bytes()));
+        assert!(tampered.verify_integrity().is_ok());
+        assert!(matches!(
+            tampered.verify(&key.verifying_key()),
+            Err(PolicyBundleError::SignatureInvalid(_))
+        ));
+
+        // Delivery metadata is covered too.
+        let mut demoted = signed.clone();
+        demoted.policy_mode = PolicyMode::Canary;
+        demoted.canary_fraction = Some(1.0);
+        assert!(demoted.verify(&key.verifying_key()).is_err());
+
+        // A different key does not verify.
+        assert!(signed.verify(&test_key(8).verifying_key()).is_err());
+
+        let mut garbled = signed;
+        garbled.signature = Some("zz".to_string());
+        assert!(matches!(
+            garbled.verify(&key.verifying_key()),
+            Err(PolicyBundleError::SignatureInvalid(_))
+        ));
+    }
+
+    #[test]
+    fn signed_mode_refuses_unsigned_bundles() {
+        let key = test_key(7);
+        let unsigned = PolicyBundle::new(Policy::default(), PolicyMode::Signed).unwrap();
+        let json = unsigned.to_json().unwrap();
+
+        assert!(matches!(
+            PolicyBundle::from_json(&json),
+            Err(PolicyBundleError::NoVerifier)
+        ));
+        assert!(matches!(
+            PolicyBundle::from_json_verified(&json, &key.verifying_key()),
+            Err(PolicyBundleError::SignatureInvalid(_))
+        ));
+
+        // Without a key, unsigned bundles in other modes are accepted.
+        let plain = PolicyBundle::embedded_default().to_json().unwrap();
+        assert!(PolicyBundle::from_json(&plain).is_ok());
+    }
+
+    #[test]
+    fn stripped_or_downgraded_bundle_rejected_when_key_configured() {
+        let key = test_key(7);
+        let signed = PolicyBundle::new(Policy::default(), PolicyMode::Signed)
+            .unwrap()
+            .sign(&key)
+            .unwrap();
+
+        // Signature stripped and mode downgraded: the bundle no longer asks
+        // to be verified, but the caller's key still requires it.
+        let mut stripped = signed.clone();
+        stripped.signature = None;
+        stripped.policy_mode = PolicyMode::Default;
+        let json = stripped.to_json().unwrap();
+        assert!(matches!(
+            PolicyBundle::from_json_verified(&json, &key.verifying_key()),
+            Err(PolicyBundleError::SignatureInvalid(_))
+        ));
+
+        // Mode downgraded with the old signature kept.
+        let mut downgraded = signed.clone();
+        downgraded.policy_mode = PolicyMode::Default;
+        let json = downgraded.to_json().unwrap();
+        assert!(PolicyBundle::from_json_verified(&json, &key.verifying_key()).is_err());
+
+        // A signed bundle cannot be loaded unchecked through from_json.
+        let json = signed.to_json().unwrap();
+        assert!(matches!(
+            PolicyBundle::from_json(&json),
+            Err(PolicyBundleError::NoVerifier)
+        ));
+    }
+
     #[test]
     fn no_hash_still_validates() {
         let mut bundle = PolicyBundle::new(Policy::default(), PolicyMode::Default).unwrap();
@@ -9,8 +9,9 @@
 use crate::policy::{
     AlphaInvesting, ConfidenceLevel, DataLossGates, DecisionTimeBound, FdrControl, FdrMethod,
     Guardrails, LoadAwareDecision, LossMatrix, LossRow, PatternEntry, PatternKind, Policy,
-    RobotMode, SignatureFastPath,
+    ProtectionList, RobotMode, SignatureFastPath,
 };
+use crate::priors::Priors;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
@@ -94,6 +95,14 @@ pub enum PresetError {
     InvalidOverride(String),
     /// Preset file corrupted.
     CorruptPresetFile(String),
+    /// Two presets that cannot be layered together: one tightens a
+    /// kill-safety field that the other loosens.
+    ConflictingLayers {
+        first: PresetName,
+        second: PresetName,
+        /// Dotted path of the first field they pull in opposite directions.
+        field: String,
+    },
 }
 
 impl fmt::Display for PresetError {
@@ -113,6 +122,15 @@ impl fmt::Display for PresetError {
             }
             PresetError::InvalidOverride(msg) => write!(f, "Invalid override: {}", msg),
             PresetError::CorruptPresetFile(msg) => write!(f, "Corrupt preset file: {}", msg),
+            PresetError::ConflictingLayers {
+                first,
+                second,
+                field,
+            } => write!(
+                f,
+                "Presets '{}' and '{}' cannot be layered: one tightens {} and the other loosens it",
+                first, second, field
+            ),
         }
     }
 }
@@ -129,6 +147,173 @@ pub fn get_preset(name: PresetName) -> Policy {
     }
 }
 
+/// Policy fields that gate how readily pt kills, as (JSON pointer, whether
+/// a larger value is safer).
+///
+/// Two presets conflict when, relative to [`Policy::default`], one moves a
+/// field here toward safety and the other away from it. Layering them would
+/// let whichever comes last silently undo the other's guarantee (e.g.
+/// `developer` lowers `robot_mode.min_posterior` that `paranoid` raises), so
+/// [`resolve_with_presets`] rejects the stack instead.
+const KILL_SAFETY_FIELDS: &[(&str, bool)] = &[
+    ("/robot_mode/min_posterior", true),
+    ("/robot_mode/max_blast_radius_mb", false),
+    ("/guardrails/max_kills_per_run", false),
+    ("/guardrails/min_process_age_seconds", true),
+    ("/loss_matrix/useful/kill", true),
+    ("/fdr_control/alpha", false),
+];
+
+/// Build a policy from an ordered stack of presets plus user overrides.
+///
+/// Precedence, lowest to highest:
+/// 1. built-in defaults ([`Policy::default`])
+/// 2. each preset in `layers`, in order
+/// 3. `overrides` (a partial policy JSON object)
+///
+/// A preset contributes only the fields where it differs from the
+/// defaults, so a later preset does not reset what an earlier one tuned
+/// just because it keeps the default there. Layers merge field by field:
+/// objects are merged recursively, while scalars and arrays from a higher
+/// layer replace the lower value. Path resolution (CLI, environment, XDG)
+/// happens separately and decides which override file is used, not how it
+/// merges.
+///
+/// Presets whose safety postures conflict (see [`KILL_SAFETY_FIELDS`]) are
+/// rejected with [`PresetError::ConflictingLayers`].
+///
+/// Unless `overrides` sets `inherits`, the result records the preset ids
+/// it was built from there.
+pub fn resolve_with_presets(
+    layers: &[PresetName],
+    overrides: Option<&serde_json::Value>,
+) -> Result<Policy, PresetError> {
+    if layers.is_empty() {
+        return Err(PresetError::InvalidOverride(
+            "at least one preset layer is required".to_string(),
+        ));
+    }
+
+    let to_value = |policy: Policy| {
+        serde_json::to_value(policy).map_err(|e| PresetError::CorruptPresetFile(e.to_string()))
+    };
+    let defaults = to_value(Policy::default())?;
+    let deltas = layers
+        .iter()
+        .map(|&name| {
+            Ok((
+                name,
+                changed_fields(&defaults, &to_value(get_preset(name))?),
+            ))
+        })
+        .collect::<Result<Vec<_>, PresetError>>()?;
+
+    for (i, (a, a_delta)) in deltas.iter().enumerate() {
+        for (b, b_delta) in &deltas[i + 1..] {
+            if let Some(field) = conflicting_field(&defaults, a_delta, b_delta) {
+                return Err(PresetError::ConflictingLayers {
+                    first: *a,
+                    second: *b,
+                    field,
+                });
+            }
+        }
+    }
+
+    let mut merged = defaults;
+    for (_, delta) in deltas {
+        merge_layer(&mut merged, delta);
+    }
+
+    let explicit_inherits = match overrides {
+        Some(value @ serde_json::Value::Object(map)) => {
+            merge_layer(&mut merged, value.clone());
+            map.contains_key("inherits")
+        }
+        Some(_) => {
+            return Err(PresetError::InvalidOverride(
+                "overrides must be a JSON object".to_string(),
+            ))
+        }
+        None => false,
+    };
+
+    let mut policy: Policy =
+        serde_json::from_value(merged).map_err(|e| PresetError::InvalidOverride(e.to_string()))?;
+    if !explicit_inherits {
+        policy.inherits = layers
+            .iter()
+            .map(|name| format!("preset:{}", name.as_str()))
+            .collect();
+    }
+    Ok(policy)
+}
+
+/// The parts of `value` that differ from `base`: objects are compared
+/// recursively, anything else (including arrays) as a whole.
+fn changed_fields(base: &serde_json::Value, value: &serde_json::Value) -> serde_json::Value {
+    use serde_json::Value;
+
+    match (base, value) {
+        (Value::Object(base), Value::Object(map)) => Value::Object(
+            map.iter()
+                .filter_map(|(key, child)| match base.get(key) {
+                    Some(base_child) if base_child == child => None,
+                    Some(base_child @ Value::Object(_)) if child.is_object() => {
+                        Some((key.clone(), changed_fields(base_child, child)))
+                    }
+                    _ => Some((key.clone(), child.clone())),
+                })
+                .collect(),
+        ),
+        _ => value.clone(),
+    }
+}
+
+/// First kill-safety field that one delta tightens and the other loosens.
+fn conflicting_field(
+    defaults: &serde_json::Value,
+    a: &serde_json::Value,
+    b: &serde_json::Value,
+) -> Option<String> {
+    // +1 when `delta` makes the field safer than the default, -1 when less safe.
+    let direction = |delta: &serde_json::Value, pointer: &str, higher_is_safer: bool| {
+        let default = defaults.pointer(pointer)?.as_f64()?;
+        let value = delta.pointer(pointer)?.as_f64()?;
+        let raised = value.partial_cmp(&default)? as i8;
+        Some(if higher_is_safer { raised } else { -raised })
+    };
+    KILL_SAFETY_FIELDS
+        .iter()
+        .find(|(pointer, higher_is_safer)| {
+            match (
+                direction(a, pointer, *higher_is_safer),
+                direction(b, pointer, *higher_is_safer),
+            ) {
+                (Some(x), Some(y)) => x * y < 0,
+                _ => false,
+            }
+        })
+        .map(|(pointer, _)| pointer.trim_start_matches('/').replace('/', "."))
+}
+
+/// Merge `upper` into `lower`, with `upper` winning on conflicts.
+fn merge_layer(lower: &mut serde_json::Value, upper: serde_json::Value) {
+    match (lower, upper) {
+        (serde_json::Value::Object(lower), serde_json::Value::Object(upper)) => {
+            for (key, value) in upper {
+                match lower.get_mut(&key) {
+                    Some(existing) => merge_layer(existing, value),
+                    None => {
+                        lower.insert(key, value);
+                    }
+                }
+            }
+        }
+        (lower, upper) => *lower = upper,
+    }
+}
+
 /// Developer preset: aggressive detection, lower thresholds.
 ///
 /// Characteristics:
@@ -213,6 +398,7 @@ fn developer_preset() -> Policy {
             max_kills_per_minute: Some(10),
             max_kills_per_hour: Some(50),
             max_kills_per_day: Some(200),
+            max_kill_fraction: None,
             min_process_age_seconds: 1800, // 30 minutes (shorter than default)
             require_confirmation: Some(true), // Still interactive by default
         },
@@ -255,6 +441,8 @@ fn developer_preset() -> Policy {
 
         load_aware: LoadAwareDecision::default(),
         decision_time_bound: DecisionTimeBound::default(),
+        probe_costs: None,
+        protection_list: ProtectionList::default(),
     }
 }
 
@@ -409,6 +597,7 @@ fn server_preset() -> Policy {
             max_kills_per_minute: Some(2),
             max_kills_per_hour: Some(10),
             max_kills_per_day: Some(30),
+            max_kill_fraction: None,
             min_process_age_seconds: 14400, // 4 hours
             require_confirmation: Some(true),
         },
@@ -472,6 +661,8 @@ fn server_preset() -> Policy {
             overhead_budget_seconds: 600,
             fallback_action: "keep".to_string(), // Default to keeping on timeout
         },
+        probe_costs: None,
+        protection_list: ProtectionList::default(),
     }
 }
 
@@ -574,6 +765,7 @@ fn ci_preset() -> Policy {
             max_kills_per_minute: Some(5),
             max_kills_per_hour: Some(30),
             max_kills_per_day: Some(100),
+            max_kill_fraction: None,
             min_process_age_seconds: 3600, // 1 hour (long enough for most CI jobs)
             require_confirmation: Some(false), // NO interactive prompts
         },
@@ -620,6 +812,8 @@ fn ci_preset() -> Policy {
             overhead_budget_seconds: 120,
             fallback_action: "keep".to_string(),
         },
+        probe_costs: None,
+        protection_list: ProtectionList::default(),
     }
 }
 
@@ -829,6 +1023,7 @@ fn paranoid_preset() -> Policy {
             max_kills_per_minute: Some(1),
             max_kills_per_hour: Some(5),
             max_kills_per_day: Some(10),
+            max_kill_fraction: None,
             min_process_age_seconds: 86400, // 24 hours
             require_confirmation: Some(true),
         },
@@ -897,13 +1092,15 @@ fn paranoid_preset() -> Policy {
             overhead_budget_seconds: 1200,
             fallback_action: "keep".to_string(), // Always default to keeping
         },
+        probe_costs: None,
+        protection_list: ProtectionList::default(),
     }
 }
 
 /// Information about a preset for display.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PresetInfo {
-    pub name: String,
+    pub name: PresetName,
     pub description: String,
     pub min_process_age_seconds: u64,
     pub max_kills_per_run: u32,
@@ -917,7 +1114,7 @@ impl PresetInfo {
     pub fn from_preset(name: PresetName) -> Self {
         let policy = get_preset(name);
         Self {
-            name: name.as_str().to_string(),
+            name,
             description: name.description().to_string(),
             min_process_age_seconds: policy.guardrails.min_process_age_seconds,
             max_kills_per_run: policy.guardrails.max_kills_per_run,
@@ -926,6 +1123,85 @@ impl PresetInfo {
             fdr_alpha: policy.fdr_control.alpha,
         }
     }
+
+    /// List the config fields that applying this preset would change.
+    ///
+    /// Fields are compared leaf by leaf (so nested loss-matrix cells are
+    /// reported individually); arrays are compared as a whole. Presets only
+    /// override the policy, so `priors` never contributes a change today.
+    pub fn diff_against(&self, current: &Policy, _priors: &Priors) -> PresetDiff {
+        let to_value =
+            |policy: &Policy| serde_json::to_value(policy).expect("policy serializes to JSON");
+        let mut changes = Vec::new();
+        diff_policy_values(
+            "",
+            &to_value(current),
+            &to_value(&get_preset(self.name)),
+            &mut changes,
+        );
+        changes.sort_by(|a, b| a.path.cmp(&b.path));
+        PresetDiff {
+            preset: self.name,
+            changes,
+        }
+    }
+}
+
+/// Fields a preset would override in the current policy.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PresetDiff {
+    /// The preset being previewed.
+    pub preset: PresetName,
+    /// Overridden fields, sorted by path.
+    pub changes: Vec<PresetFieldDiff>,
+}
+
+impl PresetDiff {
+    /// Whether applying the preset would leave the policy unchanged.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+/// A single policy field the preset overrides, addressed by dotted path
+/// (e.g. `loss_matrix.abandoned.kill`). A side missing the field is `null`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PresetFieldDiff {
+    pub path: String,
+    pub current: serde_json::Value,
+    pub preset: serde_json::Value,
+}
+
+/// Recursively compare two policy values, recording differing leaves.
+fn diff_policy_values(
+    path: &str,
+    current: &serde_json::Value,
+    preset: &serde_json::Value,
+    out: &mut Vec<PresetFieldDiff>,
+) {
+    use serde_json::Value;
+
+    if let (Value::Object(a), Value::Object(b)) = (current, preset) {
+        for key in a.keys().chain(b.keys().filter(|k| !a.contains_key(*k))) {
+            let child = if path.is_empty() {
+                key.clone()
+            } else {
+                format!("{}.{}", path, key)
+            };
+            diff_policy_values(
+                &child,
+                a.get(key).unwrap_or(&Value::Null),
+                b.get(key).unwrap_or(&Value::Null),
+                out,
+            );
+        }
+    } else if current != preset {
+        out.push(PresetFieldDiff {
+            path: path.to_string(),
+            current: current.clone(),
+            preset: preset.clone(),
+        });
+    }
 }
 
 /// List all available presets with summary information.
@@ -1000,10 +1276,10 @@ mod tests {
     fn test_list_presets() {
         let presets = list_presets();
         assert_eq!(presets.len(), 4);
-        assert!(presets.iter().any(|p| p.name == "developer"));
-        assert!(presets.iter().any(|p| p.name == "server"));
-        assert!(presets.iter().any(|p| p.name == "ci"));
-        assert!(presets.iter().any(|p| p.name == "paranoid"));
+        assert!(presets.iter().any(|p| p.name == PresetName::Developer));
+        assert!(presets.iter().any(|p| p.name == PresetName::Server));
+        assert!(presets.iter().any(|p| p.name == PresetName::Ci));
+        assert!(presets.iter().any(|p| p.name == PresetName::Paranoid));
     }
 
     #[test]
@@ -1418,10 +1694,48 @@ mod tests {
 
     // ── PresetInfo ────────────────────────────────────────────────────
 
+    #[test]
+    fn test_diff_against_identical_policy_is_empty() {
+        for &name in PresetName::ALL {
+            let diff =
+                PresetInfo::from_preset(name).diff_against(&get_preset(name), &Priors::default());
+            assert!(diff.is_empty(), "{}: {:?}", name, diff.changes);
+        }
+    }
+
+    #[test]
+    fn test_diff_against_reports_nested_loss_matrix_cells() {
+        let current = Policy::default();
+        let preset = get_preset(PresetName::Paranoid);
+        let diff = PresetInfo::from_preset(PresetName::Paranoid)
+            .diff_against(&current, &Priors::default());
+        assert_eq!(diff.preset, PresetName::Paranoid);
+
+        let kill = diff
+            .changes
+            .iter()
+            .find(|c| c.path == "loss_matrix.useful.kill")
+            .expect("useful kill loss differs");
+        assert_eq!(
+            kill.current,
+            serde_json::json!(current.loss_matrix.useful.kill)
+        );
+        assert_eq!(
+            kill.preset,
+            serde_json::json!(preset.loss_matrix.useful.kill)
+        );
+        assert!(diff.changes.iter().all(|c| c.path != "loss_matrix.useful"));
+
+        let mut paths: Vec<&str> = diff.changes.iter().map(|c| c.path.as_str()).collect();
+        let unsorted = paths.clone();
+        paths.sort();
+        assert_eq!(paths, unsorted);
+    }
+
     #[test]
     fn preset_info_fields_from_policy() {
         let info = PresetInfo::from_preset(PresetName::Developer);
-        assert_eq!(info.name, "developer");
+        assert_eq!(info.name, PresetName::Developer);
         assert_eq!(info.min_process_age_seconds, 1800);
         assert_eq!(info.max_kills_per_run, 20);
         assert!(!info.robot_mode_enabled);
@@ -1445,6 +1759,139 @@ mod tests {
         }
     }
 
+    // ── Preset layering ───────────────────────────────────────────────
+
+    #[test]
+    fn layering_override_wins_and_preset_fills_unset_fields() {
+        let overrides = serde_json::json!({
+            "loss_matrix": {
+                "useful": { "keep": 0.0, "kill": 500.0 }
+            },
+            "guardrails": { "max_kills_per_run": 2 }
+        });
+        let policy = resolve_with_presets(&[PresetName::Server], Some(&overrides)).unwrap();
+        let base = get_preset(PresetName::Server);
+
+        // Override fields win.
+        assert_eq!(policy.loss_matrix.useful.kill, 500.0);
+        assert_eq!(policy.guardrails.max_kills_per_run, 2);
+
+        // Fields the override left unset come from the preset.
+        assert_eq!(
+            policy.loss_matrix.useful.pause,
+            base.loss_matrix.useful.pause
+        );
+        assert_eq!(
+            policy.loss_matrix.abandoned.kill,
+            base.loss_matrix.abandoned.kill
+        );
+        assert_eq!(
+            policy.guardrails.min_process_age_seconds,
+            base.guardrails.min_process_age_seconds
+        );
+        assert_eq!(policy.fdr_control.alpha, base.fdr_control.alpha);
+        assert_eq!(policy.inherits, vec!["preset:server".to_string()]);
+    }
+
+    #[test]
+    fn layering_later_presets_take_precedence() {
+        let policy = resolve_with_presets(&[PresetName::Server, PresetName::Ci], None).unwrap();
+        let ci = get_preset(PresetName::Ci);
+        assert_eq!(policy.robot_mode.enabled, ci.robot_mode.enabled);
+        assert_eq!(
+            policy.guardrails.min_process_age_seconds,
+            ci.guardrails.min_process_age_seconds
+        );
+        assert_eq!(
+            policy.inherits,
+            vec!["preset:server".to_string(), "preset:ci".to_string()]
+        );
+    }
+
+    #[test]
+    fn layering_keeps_fields_a_later_preset_leaves_at_default() {
+        let server = get_preset(PresetName::Server);
+        let ci = get_preset(PresetName::Ci);
+        let defaults = Policy::default();
+        // CI keeps these at their defaults, so Server's values survive.
+        assert_eq!(
+            ci.robot_mode.min_posterior,
+            defaults.robot_mode.min_posterior
+        );
+        assert_eq!(ci.fdr_control.alpha, defaults.fdr_control.alpha);
+        assert_eq!(ci.loss_matrix.useful.kill, defaults.loss_matrix.useful.kill);
+
+        let policy = resolve_with_presets(&[PresetName::Server, PresetName::Ci], None).unwrap();
+        assert_eq!(
+            policy.robot_mode.min_posterior,
+            server.robot_mode.min_posterior
+        );
+        assert_eq!(policy.fdr_control.alpha, server.fdr_control.alpha);
+        assert_eq!(
+            policy.loss_matrix.useful.kill,
+            server.loss_matrix.useful.kill
+        );
+        // ...while the fields CI does change still win.
+        assert_eq!(
+            policy.guardrails.require_confirmation,
+            ci.guardrails.require_confirmation
+        );
+    }
+
+    #[test]
+    fn single_layer_reproduces_the_preset() {
+        for &name in PresetName::ALL {
+            let mut expected = get_preset(name);
+            expected.inherits = vec![format!("preset:{}", name.as_str())];
+            let policy = resolve_with_presets(&[name], None).unwrap();
+            assert_eq!(
+                serde_json::to_value(&policy).unwrap(),
+                serde_json::to_value(&expected).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn layering_rejects_conflicting_presets() {
+        let err =
+            resolve_with_presets(&[PresetName::Paranoid, PresetName::Developer], None).unwrap_err();
+        assert!(matches!(
+            &err,
+            PresetError::ConflictingLayers {
+                first: PresetName::Paranoid,
+                second: PresetName::Developer,
+                field,
+            } if field == "robot_mode.min_posterior"
+        ));
+        assert!(err.to_string().contains("robot_mode.min_posterior"));
+
+        // Developer loosens what Server tightens, too.
+        assert!(matches!(
+            resolve_with_presets(&[PresetName::Developer, PresetName::Server], None),
+            Err(PresetError::ConflictingLayers { .. })
+        ));
+        // Presets pulling the same way stack fine.
+        assert!(resolve_with_presets(&[PresetName::Server, PresetName::Paranoid], None).is_ok());
+        assert!(resolve_with_presets(&[PresetName::Ci, PresetName::Paranoid], None).is_ok());
+    }
+
+    #[test]
+    fn layering_rejects_empty_and_bad_overrides() {
+        assert!(matches!(
+            resolve_with_presets(&[], None),
+            Err(PresetError::InvalidOverride(_))
+        ));
+        assert!(matches!(
+            resolve_with_presets(&[PresetName::Ci], Some(&serde_json::json!([1]))),
+            Err(PresetError::InvalidOverride(_))
+        ));
+        let wrong_type = serde_json::json!({ "guardrails": { "max_kills_per_run": "many" } });
+        assert!(matches!(
+            resolve_with_presets(&[PresetName::Ci], Some(&wrong_type)),
+            Err(PresetError::InvalidOverride(_))
+        ));
+    }
+
     // ── All presets serde roundtrip ───────────────────────────────────
 
     #[test]
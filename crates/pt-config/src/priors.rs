@@ -2,8 +2,11 @@
 //!
 //! These types match the priors.schema.json specification.
 
+use crate::class::Class;
+use pt_math::digamma;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::ops::{Index, IndexMut};
 
 /// Complete priors configuration.
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -64,6 +67,30 @@ pub struct ClassPriors {
     pub zombie: ClassParams,
 }
 
+impl Index<Class> for ClassPriors {
+    type Output = ClassParams;
+
+    fn index(&self, class: Class) -> &ClassParams {
+        match class {
+            Class::Useful => &self.useful,
+            Class::UsefulBad => &self.useful_bad,
+            Class::Abandoned => &self.abandoned,
+            Class::Zombie => &self.zombie,
+        }
+    }
+}
+
+impl IndexMut<Class> for ClassPriors {
+    fn index_mut(&mut self, class: Class) -> &mut ClassParams {
+        match class {
+            Class::Useful => &mut self.useful,
+            Class::UsefulBad => &mut self.useful_bad,
+            Class::Abandoned => &mut self.abandoned,
+            Class::Zombie => &mut self.zombie,
+        }
+    }
+}
+
 /// Parameters for a single process class.
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ClassParams {
@@ -448,6 +475,218 @@ impl Default for Priors {
     }
 }
 
+// ── Fitting from recorded outcomes ────────────────────────────────
+
+/// Pseudo-count added to every class when estimating base rates (Laplace
+/// smoothing), so a class with no recorded outcomes keeps a non-zero prior.
+pub const FIT_CLASS_PSEUDO_COUNT: f64 = 1.0;
+
+/// Labeled samples a class needs before its feature likelihoods are refit.
+/// Sparser classes keep the built-in default hyperparameters.
+pub const FIT_MIN_CLASS_SAMPLES: usize = 10;
+
+/// Upper bound on `alpha + beta` for fitted Beta likelihoods, so a large
+/// table cannot make a likelihood arbitrarily confident.
+pub const FIT_MAX_BETA_CONCENTRATION: f64 = 100.0;
+
+const FIT_MAX_ITERS: usize = 200;
+const FIT_TOLERANCE: f64 = 1e-10;
+const FIT_UNIT_CLAMP: f64 = 1e-6;
+
+/// One labeled process: an `outcomes` row joined with its `proc_features` row.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OutcomeSample {
+    pub class: Class,
+    /// CPU utilization in [0, 1].
+    pub cpu_utilization: Option<f64>,
+    /// Process age in seconds.
+    pub runtime_s: Option<f64>,
+    pub is_orphan: bool,
+    pub has_tty: bool,
+    pub has_network: Option<bool>,
+    pub io_active: Option<bool>,
+}
+
+/// Fit priors from labeled outcomes by empirical Bayes.
+///
+/// Starts from the built-in defaults and replaces, per class:
+/// - `prior_prob`: Laplace-smoothed base rate
+///   `(n_c + FIT_CLASS_PSEUDO_COUNT) / (N + 4 * FIT_CLASS_PSEUDO_COUNT)`
+/// - `cpu_beta`: Beta maximum-likelihood fit of CPU utilization
+/// - `runtime_gamma`: Gamma maximum-likelihood fit of process age
+/// - `orphan_beta`, `tty_beta`, `net_beta`, `io_active_beta`: Beta posterior
+///   of the flag rate under a uniform prior
+///
+/// Fitted Beta likelihoods are capped at [`FIT_MAX_BETA_CONCENTRATION`], and
+/// classes with fewer than [`FIT_MIN_CLASS_SAMPLES`] samples keep their
+/// default likelihoods.
+pub fn fit_from_outcomes<'a>(batches: impl IntoIterator<Item = &'a [OutcomeSample]>) -> Priors {
+    let mut by_class: [Vec<&OutcomeSample>; Class::COUNT] = Default::default();
+    for sample in batches.into_iter().flatten() {
+        by_class[sample.class.index()].push(sample);
+    }
+    let total: usize = by_class.iter().map(Vec::len).sum();
+    let denom = total as f64 + FIT_CLASS_PSEUDO_COUNT * Class::COUNT as f64;
+
+    let mut priors = Priors {
+        description: Some(format!("Fitted from {} recorded outcomes", total)),
+        ..Priors::default()
+    };
+
+    for class in Class::ALL {
+        let samples = &by_class[class.index()];
+        let params = &mut priors.classes[class];
+        params.prior_prob = (samples.len() as f64 + FIT_CLASS_PSEUDO_COUNT) / denom;
+        if samples.len() < FIT_MIN_CLASS_SAMPLES {
+            continue;
+        }
+
+        let cpu: Vec<f64> = samples.iter().filter_map(|s| s.cpu_utilization).collect();
+        if let Some(fit) = fit_beta_mle(&cpu) {
+            params.cpu_beta = fit;
+        }
+        let runtimes: Vec<f64> = samples
+            .iter()
+            .filter_map(|s| s.runtime_s)
+            .filter(|&t| t > 0.0)
+            .collect();
+        if let Some(fit) = fit_gamma_mle(&runtimes) {
+            params.runtime_gamma = Some(fit);
+        }
+        if let Some(fit) = fit_flag_rate(samples.iter().map(|s| Some(s.is_orphan))) {
+            params.orphan_beta = fit;
+        }
+        if let Some(fit) = fit_flag_rate(samples.iter().map(|s| Some(s.has_tty))) {
+            params.tty_beta = fit;
+        }
+        if let Some(fit) = fit_flag_rate(samples.iter().map(|s| s.has_network)) {
+            params.net_beta = fit;
+        }
+        if let Some(fit) = fit_flag_rate(samples.iter().map(|s| s.io_active)) {
+            params.io_active_beta = Some(fit);
+        }
+    }
+
+    priors
+}
+
+/// Beta(mean, concentration) with the concentration capped.
+fn capped_beta(mean: f64, concentration: f64) -> BetaParams {
+    let concentration = concentration.min(FIT_MAX_BETA_CONCENTRATION);
+    BetaParams::new(mean * concentration, (1.0 - mean) * concentration)
+}
+
+/// Posterior Beta(1 + k, 1 + n - k) for a flag observed in k of n samples.
+fn fit_flag_rate(flags: impl Iterator<Item = Option<bool>>) -> Option<BetaParams> {
+    let (n, k) = flags.flatten().fold((0usize, 0usize), |(n, k), flag| {
+        (n + 1, k + usize::from(flag))
+    });
+    if n < FIT_MIN_CLASS_SAMPLES {
+        return None;
+    }
+    let concentration = n as f64 + 2.0;
+    Some(capped_beta((k as f64 + 1.0) / concentration, concentration))
+}
+
+/// Maximum-likelihood Beta fit via Minka's fixed-point iteration, started
+/// from the method-of-moments estimate.
+fn fit_beta_mle(xs: &[f64]) -> Option<BetaParams> {
+    if xs.len() < FIT_MIN_CLASS_SAMPLES {
+        return None;
+    }
+    let xs: Vec<f64> = xs
+        .iter()
+        .map(|x| x.clamp(FIT_UNIT_CLAMP, 1.0 - FIT_UNIT_CLAMP))
+        .collect();
+    let n = xs.len() as f64;
+    let mean = xs.iter().sum::<f64>() / n;
+    let var = xs.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n;
+    let common = mean * (1.0 - mean) / var - 1.0;
+    if !common.is_finite() || common <= 0.0 {
+        // No spread: the data pins the mean, so use the maximum concentration.
+        return Some(capped_beta(mean, FIT_MAX_BETA_CONCENTRATION));
+    }
+
+    let mean_ln_x = xs.iter().map(|x| x.ln()).sum::<f64>() / n;
+    let mean_ln_1mx = xs.iter().map(|x| (1.0 - x).ln()).sum::<f64>() / n;
+    let (mut alpha, mut beta) = (mean * common, (1.0 - mean) * common);
+    for _ in 0..FIT_MAX_ITERS {
+        let psi_sum = digamma(alpha + beta);
+        let next_alpha = inv_digamma(psi_sum + mean_ln_x);
+        let next_beta = inv_digamma(psi_sum + mean_ln_1mx);
+        let converged = (next_alpha - alpha).abs() < FIT_TOLERANCE * alpha
+            && (next_beta - beta).abs() < FIT_TOLERANCE * beta;
+        alpha = next_alpha;
+        beta = next_beta;
+        if converged {
+            break;
+        }
+    }
+    if !(alpha.is_finite() && beta.is_finite() && alpha > 0.0 && beta > 0.0) {
+        return None;
+    }
+    Some(capped_beta(alpha / (alpha + beta), alpha + beta))
+}
+
+/// Maximum-likelihood Gamma fit: Newton's method on
+/// `ln(shape) - digamma(shape) = ln(mean) - mean(ln x)`.
+fn fit_gamma_mle(xs: &[f64]) -> Option<GammaParams> {
+    if xs.len() < FIT_MIN_CLASS_SAMPLES {
+        return None;
+    }
+    let n = xs.len() as f64;
+    let mean = xs.iter().sum::<f64>() / n;
+    let s = mean.ln() - xs.iter().map(|x| x.ln()).sum::<f64>() / n;
+    if !s.is_finite() || s <= 0.0 {
+        return None;
+    }
+
+    // Closed-form approximation as the starting point.
+    let mut shape = (3.0 - s + ((s - 3.0).powi(2) + 24.0 * s).sqrt()) / (12.0 * s);
+    for _ in 0..FIT_MAX_ITERS {
+        let f = shape.ln() - digamma(shape) - s;
+        let df = 1.0 / shape - trigamma(shape);
+        let next = shape - f / df;
+        let next = if next > 0.0 { next } else { shape / 2.0 };
+        let converged = (next - shape).abs() < FIT_TOLERANCE * shape;
+        shape = next;
+        if converged {
+            break;
+        }
+    }
+    if !shape.is_finite() || shape <= 0.0 {
+        return None;
+    }
+    Some(GammaParams::new(shape, shape / mean))
+}
+
+/// Inverse of the digamma function by Newton's method (Minka's start).
+fn inv_digamma(y: f64) -> f64 {
+    let mut x = if y >= -2.22 {
+        y.exp() + 0.5
+    } else {
+        -1.0 / (y - digamma(1.0))
+    };
+    for _ in 0..FIT_MAX_ITERS {
+        let next = x - (digamma(x) - y) / trigamma(x);
+        let next = if next > 0.0 { next } else { x / 2.0 };
+        let converged = (next - x).abs() < FIT_TOLERANCE * x;
+        x = next;
+        if converged {
+            break;
+        }
+    }
+    x
+}
+
+/// Trigamma ψ'(x) as a central difference of `digamma`.
+///
+/// Only steers Newton steps, so modest accuracy is sufficient.
+fn trigamma(x: f64) -> f64 {
+    let h = 1e-4 * x;
+    (digamma(x + h) - digamma(x - h)) / (2.0 * h)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1037,4 +1276,104 @@ mod tests {
         assert!(back.zombie.is_some());
         assert_eq!(back.comment.as_deref(), Some("test"));
     }
+
+    // ── fit_from_outcomes ──────────────────────────────────────────
+
+    fn synthetic_sample(class: Class, i: usize) -> OutcomeSample {
+        let frac = (i as f64 + 0.5) / 40.0;
+        let (cpu, orphan, tty) = match class {
+            Class::Useful => (0.2 + 0.3 * frac, i.is_multiple_of(10), !i.is_multiple_of(3)),
+            Class::UsefulBad => (0.6 + 0.3 * frac, i.is_multiple_of(5), i.is_multiple_of(2)),
+            Class::Abandoned => (
+                0.01 + 0.05 * frac,
+                !i.is_multiple_of(5),
+                i.is_multiple_of(10),
+            ),
+            Class::Zombie => (0.0, true, false),
+        };
+        OutcomeSample {
+            class,
+            cpu_utilization: Some(cpu),
+            runtime_s: Some(60.0 * (1.0 + 10.0 * frac)),
+            is_orphan: orphan,
+            has_tty: tty,
+            has_network: Some(i.is_multiple_of(4)),
+            io_active: None,
+        }
+    }
+
+    #[test]
+    fn fit_balanced_outcomes_recovers_uniform_base_rates() {
+        let samples: Vec<OutcomeSample> = Class::ALL
+            .iter()
+            .flat_map(|&class| (0..40).map(move |i| synthetic_sample(class, i)))
+            .collect();
+        let (first, second) = samples.split_at(70);
+        let priors = fit_from_outcomes([first, second]);
+
+        crate::validate::validate_priors(&priors).unwrap();
+        for class in ["useful", "useful_bad", "abandoned", "zombie"] {
+            let p = priors.class_prior(class).unwrap();
+            assert!((p - 0.25).abs() < 1e-12, "{} prior {}", class, p);
+        }
+
+        let classes = &priors.classes;
+        assert!(classes.abandoned.orphan_beta.mean() > classes.useful.orphan_beta.mean());
+        assert!(classes.useful_bad.cpu_beta.mean() > classes.abandoned.cpu_beta.mean());
+        assert!(classes.zombie.cpu_beta.mean() < 0.01);
+        assert!(
+            classes.zombie.orphan_beta.alpha + classes.zombie.orphan_beta.beta
+                <= FIT_MAX_BETA_CONCENTRATION + 1e-9
+        );
+        assert!(classes.useful.runtime_gamma.is_some());
+    }
+
+    #[test]
+    fn fit_keeps_sparse_classes_nonzero() {
+        let samples: Vec<OutcomeSample> = (0..30)
+            .map(|i| synthetic_sample(Class::Useful, i))
+            .collect();
+        let priors = fit_from_outcomes([samples.as_slice()]);
+
+        crate::validate::validate_priors(&priors).unwrap();
+        assert!((priors.classes.zombie.prior_prob - 1.0 / 34.0).abs() < 1e-12);
+        assert_eq!(
+            priors.classes.zombie.cpu_beta,
+            Priors::default().classes.zombie.cpu_beta
+        );
+    }
+
+    #[test]
+    fn fit_empty_outcomes_gives_uniform_defaults() {
+        let priors = fit_from_outcomes(std::iter::empty::<&[OutcomeSample]>());
+        assert!(priors.priors_sum_to_one(1e-12));
+        assert!((priors.classes.useful.prior_prob - 0.25).abs() < 1e-12);
+    }
+
+    #[test]
+    fn beta_mle_recovers_parameters() {
+        let xs: Vec<f64> = (0..400)
+            .map(|i| pt_math::beta_inv_cdf((i as f64 + 0.5) / 400.0, 2.0, 5.0))
+            .collect();
+        let fit = fit_beta_mle(&xs).unwrap();
+        assert!((fit.alpha - 2.0).abs() < 0.1, "alpha {}", fit.alpha);
+        assert!((fit.beta - 5.0).abs() < 0.25, "beta {}", fit.beta);
+    }
+
+    #[test]
+    fn gamma_mle_recovers_parameters() {
+        let xs: Vec<f64> = (0..400)
+            .map(|i| pt_math::gamma_inv_cdf((i as f64 + 0.5) / 400.0, 3.0, 0.01))
+            .collect();
+        let fit = fit_gamma_mle(&xs).unwrap();
+        assert!((fit.shape - 3.0).abs() < 0.15, "shape {}", fit.shape);
+        assert!((fit.rate - 0.01).abs() < 0.0005, "rate {}", fit.rate);
+    }
+
+    #[test]
+    fn inv_digamma_roundtrips() {
+        for x in [0.01, 0.5, 1.0, 7.5, 300.0] {
+            assert!((inv_digamma(digamma(x)) - x).abs() < 1e-8 * x.max(1.0));
+        }
+    }
 }
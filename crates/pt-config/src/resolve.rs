@@ -4,6 +4,12 @@
 
 use std::path::{Path, PathBuf};
 
+use serde::de::DeserializeOwned;
+
+use crate::env_expand::expand_env_vars;
+use crate::validate::{ValidationError, ValidationResult};
+use crate::{Policy, Priors};
+
 /// Discovered configuration file paths.
 #[derive(Debug, Clone, Default)]
 pub struct ConfigPaths {
@@ -18,6 +24,143 @@ pub struct ConfigPaths {
 
     /// Source of the policy config (for diagnostics).
     pub policy_source: ConfigSource,
+
+    /// Expand `${VAR}` references in string values when loading.
+    pub expand_env: bool,
+
+    /// File format to parse; `None` detects it from each file's extension.
+    pub format: Option<ConfigFormat>,
+}
+
+impl ConfigPaths {
+    /// Enable or disable `${VAR}` expansion when loading (off by default).
+    pub fn with_env_expansion(mut self, enabled: bool) -> Self {
+        self.expand_env = enabled;
+        self
+    }
+
+    /// Parse every file as `format` regardless of its extension.
+    pub fn with_format(mut self, format: ConfigFormat) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// Load the resolved priors file, or `None` when using built-in defaults.
+    pub fn load_priors(&self) -> ValidationResult<Option<Priors>> {
+        self.priors
+            .as_deref()
+            .map(|path| self.load_file(path))
+            .transpose()
+    }
+
+    /// Load the resolved policy file, or `None` when using built-in defaults.
+    pub fn load_policy(&self) -> ValidationResult<Option<Policy>> {
+        self.policy
+            .as_deref()
+            .map(|path| self.load_file(path))
+            .transpose()
+    }
+
+    fn load_file<T: DeserializeOwned>(&self, path: &Path) -> ValidationResult<T> {
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            ValidationError::IoError(format!("Failed to read {}: {}", path.display(), e))
+        })?;
+        let format = self.format.unwrap_or_else(|| ConfigFormat::from_path(path));
+        if !self.expand_env {
+            return format.parse(&content);
+        }
+        let mut value = format.parse::<serde_json::Value>(&content)?;
+        expand_env_vars(&mut value)?;
+        serde_json::from_value(value)
+            .map_err(|e| ValidationError::ParseError(format!("Invalid {}: {}", format, e)))
+    }
+}
+
+/// Serialization format of a configuration file.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ConfigFormat {
+    /// JSON (the canonical format).
+    #[default]
+    Json,
+
+    /// YAML, deserialized through the same serde derives as JSON.
+    Yaml,
+}
+
+impl ConfigFormat {
+    /// Format implied by a file extension: `.yaml`/`.yml` are YAML, anything
+    /// else is JSON.
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("yaml") || ext.eq_ignore_ascii_case("yml") => {
+                ConfigFormat::Yaml
+            }
+            _ => ConfigFormat::Json,
+        }
+    }
+
+    /// Deserialize `content` in this format.
+    ///
+    /// YAML errors name the offending line; duplicate keys are rejected rather
+    /// than silently resolved.
+    pub fn parse<T: DeserializeOwned>(self, content: &str) -> ValidationResult<T> {
+        match self {
+            ConfigFormat::Json => serde_json::from_str(content)
+                .map_err(|e| ValidationError::ParseError(format!("Invalid JSON: {}", e))),
+            ConfigFormat::Yaml => {
+                let parsed = serde_yaml::from_str(content).map_err(|e| yaml_error(content, &e))?;
+                // Untyped maps (and env expansion's JSON value) silently keep
+                // the last of duplicate keys; reject them instead.
+                serde_yaml::from_str::<serde_yaml::Value>(content)
+                    .map_err(|e| yaml_error(content, &e))?;
+                Ok(parsed)
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for ConfigFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigFormat::Json => write!(f, "JSON"),
+            ConfigFormat::Yaml => write!(f, "YAML"),
+        }
+    }
+}
+
+/// Parse error for invalid YAML, naming the line when it can be located.
+fn yaml_error(content: &str, err: &serde_yaml::Error) -> ValidationError {
+    let message = err.to_string();
+    match err.location() {
+        Some(location) if !message.contains("line") => {
+            // serde_yaml reports duplicates at the enclosing map; point at
+            // the repeated key instead.
+            let line =
+                duplicate_key_line(content, &message, location.line()).unwrap_or(location.line());
+            ValidationError::ParseError(format!("Invalid YAML at line {}: {}", line, message))
+        }
+        _ => ValidationError::ParseError(format!("Invalid YAML: {}", message)),
+    }
+}
+
+/// Line of the second occurrence of the key named in a duplicate-key error.
+fn duplicate_key_line(content: &str, message: &str, from_line: usize) -> Option<usize> {
+    if !message.starts_with("duplicate") {
+        return None;
+    }
+    let key = message.split(['`', '"']).nth(1)?;
+    let prefix = format!("{}:", key);
+    content
+        .lines()
+        .enumerate()
+        .skip(from_line.saturating_sub(1))
+        .filter(|(_, line)| {
+            line.trim_start()
+                .trim_start_matches("- ")
+                .starts_with(&prefix)
+        })
+        .nth(1)
+        .map(|(index, _)| index + 1)
 }
 
 /// Where a configuration file was found.
@@ -173,7 +316,7 @@ pub fn list_config_files(dir: &Path) -> Vec<PathBuf> {
             let path = entry.path();
             if path.is_file() {
                 if let Some(ext) = path.extension() {
-                    if ext == "json" {
+                    if ext == "json" || ext == "yaml" || ext == "yml" {
                         files.push(path);
                     }
                 }
@@ -214,6 +357,113 @@ mod tests {
         assert_eq!(paths.policy_source, ConfigSource::BuiltinDefault);
     }
 
+    #[test]
+    fn test_env_expansion_is_opt_in() {
+        let dir = tempfile::tempdir().unwrap();
+        let policy_path = dir.path().join("policy.json");
+        let mut policy = serde_json::to_value(Policy::default()).unwrap();
+        policy["notes"] = serde_json::json!("owner ${PT_TEST_RESOLVE_UNSET_VAR:-ops}");
+        std::fs::write(&policy_path, policy.to_string()).unwrap();
+
+        let paths = resolve_config(None, Some(&policy_path));
+        let literal = paths.load_policy().unwrap().unwrap();
+        assert_eq!(
+            literal.notes.as_deref(),
+            Some("owner ${PT_TEST_RESOLVE_UNSET_VAR:-ops}")
+        );
+
+        let expanded = paths
+            .with_env_expansion(true)
+            .load_policy()
+            .unwrap()
+            .unwrap();
+        assert_eq!(expanded.notes.as_deref(), Some("owner ops"));
+    }
+
+    #[test]
+    fn test_format_detected_from_extension() {
+        assert_eq!(
+            ConfigFormat::from_path(Path::new("policy.yaml")),
+            ConfigFormat::Yaml
+        );
+        assert_eq!(
+            ConfigFormat::from_path(Path::new("policy.YML")),
+            ConfigFormat::Yaml
+        );
+        assert_eq!(
+            ConfigFormat::from_path(Path::new("policy.json")),
+            ConfigFormat::Json
+        );
+        assert_eq!(
+            ConfigFormat::from_path(Path::new("policy")),
+            ConfigFormat::Json
+        );
+    }
+
+    #[test]
+    fn test_policy_yaml_round_trip_is_lossless() {
+        let json = serde_json::to_string(&Policy::default()).unwrap();
+        let from_json: Policy = ConfigFormat::Json.parse(&json).unwrap();
+        let yaml = serde_yaml::to_string(&from_json).unwrap();
+        let from_yaml: Policy = ConfigFormat::Yaml.parse(&yaml).unwrap();
+        assert_eq!(
+            serde_json::to_value(&from_json).unwrap(),
+            serde_json::to_value(&from_yaml).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_load_yaml_policy_by_extension_and_flag() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut policy = serde_json::to_value(Policy::default()).unwrap();
+        policy["notes"] = serde_json::json!("from yaml");
+        let yaml = serde_yaml::to_string(&policy).unwrap();
+
+        let yaml_path = dir.path().join("policy.yaml");
+        std::fs::write(&yaml_path, &yaml).unwrap();
+        let loaded = resolve_config(None, Some(&yaml_path))
+            .load_policy()
+            .unwrap()
+            .unwrap();
+        assert_eq!(loaded.notes.as_deref(), Some("from yaml"));
+
+        // An extension-less file needs the explicit flag.
+        let bare_path = dir.path().join("policy");
+        std::fs::write(&bare_path, &yaml).unwrap();
+        let paths = resolve_config(None, Some(&bare_path));
+        assert!(paths.load_policy().is_err());
+        let loaded = paths
+            .with_format(ConfigFormat::Yaml)
+            .with_env_expansion(true)
+            .load_policy()
+            .unwrap()
+            .unwrap();
+        assert_eq!(loaded.notes.as_deref(), Some("from yaml"));
+    }
+
+    #[test]
+    fn test_invalid_yaml_names_line() {
+        let err = ConfigFormat::Yaml
+            .parse::<Policy>("schema_version: \"1.0.0\"\nnotes: [unclosed\n")
+            .unwrap_err();
+        assert!(err.to_string().contains("line"), "{}", err);
+
+        let err = ConfigFormat::Yaml
+            .parse::<Policy>("notes: a\nnotes: b\n")
+            .unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("duplicate"), "{}", message);
+        assert!(message.contains("line 2"), "{}", message);
+
+        // Untyped maps would otherwise keep the last value.
+        let err = ConfigFormat::Yaml
+            .parse::<serde_json::Value>("a: 1\nnotes: a\nnotes: b\n")
+            .unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("duplicate"), "{}", message);
+        assert!(message.contains("line 3"), "{}", message);
+    }
+
     #[test]
     fn test_xdg_config_dir() {
         let dir = xdg_config_dir();
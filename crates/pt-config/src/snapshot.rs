@@ -48,10 +48,72 @@ pub struct ConfigSnapshot {
     /// Combined hash of all config files (for quick comparison).
     pub combined_hash: String,
 
+    /// SHA-256 of the resolved priors and policy as canonical (sorted-key)
+    /// JSON. Equal for logically identical configs, whatever their files
+    /// looked like.
+    #[serde(default)]
+    pub config_hash: String,
+
+    /// Resolved priors as JSON (`null` in snapshots that predate it).
+    #[serde(default)]
+    pub priors: serde_json::Value,
+
+    /// Resolved policy as JSON (`null` in snapshots that predate it).
+    #[serde(default)]
+    pub policy: serde_json::Value,
+
     /// Key configuration values for quick reference.
     pub summary: ConfigSummary,
 }
 
+/// Snapshot fields derived from others (or from the moment of capture),
+/// left out of [`ConfigSnapshot::diff`] so each change is reported once.
+const DIFF_IGNORED_FIELDS: &[&str] = &[
+    "timestamp",
+    "priors_hash",
+    "policy_hash",
+    "combined_hash",
+    "config_hash",
+    "summary",
+];
+
+/// Field-level differences between two config snapshots.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ConfigDiff {
+    /// Changed, added, and removed fields, sorted by path.
+    pub changes: Vec<ConfigFieldChange>,
+}
+
+impl ConfigDiff {
+    /// Whether the snapshots are equivalent (ignoring the capture time).
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+/// How a field differs between two snapshots.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeKind {
+    /// Present in both with different values.
+    Changed,
+    /// Present only in the newer snapshot.
+    Added,
+    /// Present only in the older snapshot.
+    Removed,
+}
+
+/// A single differing field, addressed by dotted path (e.g.
+/// `policy.fdr_control.alpha`). The missing side of an added or removed field is
+/// `null`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConfigFieldChange {
+    pub path: String,
+    pub kind: ChangeKind,
+    pub old: serde_json::Value,
+    pub new: serde_json::Value,
+}
+
 /// Summary of key configuration values.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ConfigSummary {
@@ -112,6 +174,11 @@ impl ConfigSnapshot {
         let combined_hash = hash_content(&combined);
 
         let summary = build_summary(priors, policy);
+        let resolved_priors =
+            serde_json::to_value(priors.cloned().unwrap_or_default()).unwrap_or_default();
+        let resolved_policy =
+            serde_json::to_value(policy.cloned().unwrap_or_default()).unwrap_or_default();
+        let config_hash = resolved_config_hash(&resolved_priors, &resolved_policy);
 
         ConfigSnapshot {
             timestamp,
@@ -124,6 +191,9 @@ impl ConfigSnapshot {
             policy_path: paths.policy.as_ref().map(|p| p.display().to_string()),
             policy_source: paths.policy_source.to_string(),
             combined_hash,
+            config_hash,
+            priors: resolved_priors,
+            policy: resolved_policy,
             summary,
         }
     }
@@ -134,6 +204,8 @@ impl ConfigSnapshot {
         let hostname = hostname::get()
             .ok()
             .map(|h| h.to_string_lossy().to_string());
+        let priors = serde_json::to_value(Priors::default()).unwrap_or_default();
+        let policy = serde_json::to_value(Policy::default()).unwrap_or_default();
 
         ConfigSnapshot {
             timestamp,
@@ -146,6 +218,9 @@ impl ConfigSnapshot {
             policy_path: None,
             policy_source: ConfigSource::BuiltinDefault.to_string(),
             combined_hash: hash_content("none:none"),
+            config_hash: resolved_config_hash(&priors, &policy),
+            priors,
+            policy,
             summary: ConfigSummary::defaults(),
         }
     }
@@ -165,6 +240,28 @@ impl ConfigSnapshot {
         self.combined_hash == other.combined_hash
     }
 
+    /// List the fields that changed from `prev` to `self`.
+    ///
+    /// Every leaf of the resolved priors and policy is compared with its old
+    /// and new value, along with where each was loaded from. Keys that exist
+    /// in only one snapshot (e.g. across schema versions) show up as added
+    /// or removed. Hashes, the summary and the capture time are derived, so
+    /// they are skipped.
+    pub fn diff(&self, prev: &ConfigSnapshot) -> ConfigDiff {
+        let old = diffable_value(prev);
+        let new = diffable_value(self);
+        let mut changes = Vec::new();
+        diff_values("", &old, &new, &mut changes);
+        changes.sort_by(|a, b| a.path.cmp(&b.path));
+        ConfigDiff { changes }
+    }
+
+    /// Hash of the resolved configuration, for grouping runs that used
+    /// identical settings.
+    pub fn hash(&self) -> &str {
+        &self.config_hash
+    }
+
     /// Get a short identifier for this snapshot (first 12 chars of hash).
     pub fn short_id(&self) -> &str {
         &self.combined_hash[..12.min(self.combined_hash.len())]
@@ -238,6 +335,112 @@ fn build_summary(priors: Option<&Priors>, policy: Option<&Policy>) -> ConfigSumm
     }
 }
 
+/// `snapshot` as JSON without the fields in [`DIFF_IGNORED_FIELDS`].
+fn diffable_value(snapshot: &ConfigSnapshot) -> serde_json::Value {
+    let mut value = serde_json::to_value(snapshot).unwrap_or_default();
+    if let Some(map) = value.as_object_mut() {
+        for field in DIFF_IGNORED_FIELDS {
+            map.remove(*field);
+        }
+    }
+    value
+}
+
+/// Recursively compare two JSON values, recording differing leaves.
+fn diff_values(
+    path: &str,
+    old: &serde_json::Value,
+    new: &serde_json::Value,
+    out: &mut Vec<ConfigFieldChange>,
+) {
+    use serde_json::Value;
+
+    let child = |key: &str| {
+        if path.is_empty() {
+            key.to_string()
+        } else {
+            format!("{}.{}", path, key)
+        }
+    };
+
+    match (old, new) {
+        (Value::Object(a), Value::Object(b)) => {
+            for (key, old_value) in a {
+                match b.get(key) {
+                    Some(new_value) => diff_values(&child(key), old_value, new_value, out),
+                    None => out.push(ConfigFieldChange {
+                        path: child(key),
+                        kind: ChangeKind::Removed,
+                        old: old_value.clone(),
+                        new: Value::Null,
+                    }),
+                }
+            }
+            for (key, new_value) in b {
+                if !a.contains_key(key) {
+                    out.push(ConfigFieldChange {
+                        path: child(key),
+                        kind: ChangeKind::Added,
+                        old: Value::Null,
+                        new: new_value.clone(),
+                    });
+                }
+            }
+        }
+        _ if old != new => out.push(ConfigFieldChange {
+            path: path.to_string(),
+            kind: ChangeKind::Changed,
+            old: old.clone(),
+            new: new.clone(),
+        }),
+        _ => {}
+    }
+}
+
+/// Hash resolved priors and policy as canonical JSON.
+fn resolved_config_hash(priors: &serde_json::Value, policy: &serde_json::Value) -> String {
+    let value = serde_json::json!({
+        "priors": priors,
+        "policy": policy,
+    });
+    let mut canonical = String::new();
+    write_canonical_json(&value, &mut canonical);
+    hash_content(&canonical)
+}
+
+/// Serialize `value` compactly with object keys sorted at every level.
+fn write_canonical_json(value: &serde_json::Value, out: &mut String) {
+    use serde_json::Value;
+
+    match value {
+        Value::Object(map) => {
+            let mut entries: Vec<_> = map.iter().collect();
+            entries.sort_by(|a, b| a.0.cmp(b.0));
+            out.push('{');
+            for (i, (key, child)) in entries.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                out.push_str(&Value::String(key.clone()).to_string());
+                out.push(':');
+                write_canonical_json(child, out);
+            }
+            out.push('}');
+        }
+        Value::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_canonical_json(item, out);
+            }
+            out.push(']');
+        }
+        other => out.push_str(&other.to_string()),
+    }
+}
+
 /// Hash content with SHA-256 and return hex string.
 fn hash_content(content: &str) -> String {
     let mut hasher = Sha256::new();
@@ -280,11 +483,135 @@ mod tests {
         assert_eq!(hash1.len(), 64); // SHA-256 produces 64 hex chars
     }
 
+    #[test]
+    fn test_diff_identical_ignores_timestamp() {
+        let s1 = ConfigSnapshot::defaults_only();
+        let mut s2 = s1.clone();
+        s2.timestamp += chrono::Duration::days(1);
+        assert!(s2.diff(&s1).is_empty());
+    }
+
+    #[test]
+    fn test_diff_reports_changed_fields() {
+        let paths = ConfigPaths::default();
+        let priors = Priors::default();
+        let prev = ConfigSnapshot::new(Some(&priors), Some(&Policy::default()), &paths, None, None);
+        let mut policy = Policy::default();
+        policy.fdr_control.alpha = 0.1;
+        policy.guardrails.max_kills_per_run += 5;
+        let next = ConfigSnapshot::new(Some(&priors), Some(&policy), &paths, None, Some("{}"));
+
+        let diff = next.diff(&prev);
+        let paths: Vec<&str> = diff.changes.iter().map(|c| c.path.as_str()).collect();
+        // Hashes and the summary change too but are not reported.
+        assert_eq!(
+            paths,
+            vec![
+                "policy.fdr_control.alpha",
+                "policy.guardrails.max_kills_per_run"
+            ]
+        );
+
+        let alpha = &diff.changes[0];
+        assert_eq!(alpha.kind, ChangeKind::Changed);
+        assert_eq!(
+            alpha.old,
+            serde_json::json!(Policy::default().fdr_control.alpha)
+        );
+        assert_eq!(alpha.new, serde_json::json!(0.1));
+
+        let kills = &diff.changes[1];
+        assert_eq!(
+            kills.old,
+            serde_json::json!(Policy::default().guardrails.max_kills_per_run)
+        );
+        assert_eq!(
+            kills.new,
+            serde_json::json!(policy.guardrails.max_kills_per_run)
+        );
+
+        let json = serde_json::to_string(&diff).unwrap();
+        let parsed: ConfigDiff = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, diff);
+    }
+
+    #[test]
+    fn test_diff_handles_added_and_removed_keys() {
+        // Simulate a snapshot from another schema version: one policy key
+        // missing and one extra key present.
+        let current = ConfigSnapshot::defaults_only();
+        let mut older = current.clone();
+        older.policy.as_object_mut().unwrap().remove("robot_mode");
+        older.policy["legacy_flag"] = serde_json::json!(true);
+        let changes = current.diff(&older).changes;
+
+        let added = changes
+            .iter()
+            .find(|c| c.path == "policy.robot_mode")
+            .unwrap();
+        assert_eq!(added.kind, ChangeKind::Added);
+        assert!(added.old.is_null());
+        assert_eq!(added.new, current.policy["robot_mode"]);
+
+        let removed = changes
+            .iter()
+            .find(|c| c.path == "policy.legacy_flag")
+            .unwrap();
+        assert_eq!(removed.kind, ChangeKind::Removed);
+        assert_eq!(removed.old, serde_json::json!(true));
+    }
+
+    #[test]
+    fn test_config_hash_ignores_field_order() {
+        let policy = serde_json::to_value(Policy::default()).unwrap();
+        let mut reversed = String::from("{");
+        let fields: Vec<_> = policy.as_object().unwrap().iter().rev().collect();
+        for (i, (key, value)) in fields.into_iter().enumerate() {
+            if i > 0 {
+                reversed.push(',');
+            }
+            reversed.push_str(&format!("{:?}:{}", key, value));
+        }
+        reversed.push('}');
+        let reordered: Policy = serde_json::from_str(&reversed).unwrap();
+
+        let paths = ConfigPaths::default();
+        let priors = Priors::default();
+        let snapshot = ConfigSnapshot::new(
+            Some(&priors),
+            Some(&reordered),
+            &paths,
+            None,
+            Some(&reversed),
+        );
+        let defaults = ConfigSnapshot::defaults_only();
+        assert_eq!(snapshot.hash(), defaults.hash());
+        assert_eq!(snapshot.hash().len(), 64);
+        // The file-content hash still tells the two apart.
+        assert!(!snapshot.matches(&defaults));
+
+        let mut changed = Policy::default();
+        changed.guardrails.max_kills_per_run += 1;
+        let snapshot = ConfigSnapshot::new(Some(&priors), Some(&changed), &paths, None, None);
+        assert_ne!(snapshot.hash(), defaults.hash());
+    }
+
+    #[test]
+    fn test_canonical_json_sorts_nested_keys() {
+        let mut out = String::new();
+        write_canonical_json(
+            &serde_json::json!({"b": [{"z": 1, "a": null}], "a": "x"}),
+            &mut out,
+        );
+        assert_eq!(out, r#"{"a":"x","b":[{"a":null,"z":1}]}"#);
+    }
+
     #[test]
     fn test_snapshot_json_roundtrip() {
         let snapshot = ConfigSnapshot::defaults_only();
         let json = snapshot.to_json().unwrap();
         let restored = ConfigSnapshot::from_json(&json).unwrap();
         assert!(snapshot.matches(&restored));
+        assert!(restored.diff(&snapshot).is_empty());
     }
 }
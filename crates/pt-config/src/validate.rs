@@ -28,6 +28,9 @@ pub enum ValidationError {
 
     #[error("Version mismatch: expected {expected}, got {actual}")]
     VersionMismatch { expected: String, actual: String },
+
+    #[error("Environment variable {var} referenced at {field} is not set and has no default")]
+    UnsetEnvVar { var: String, field: String },
 }
 
 impl ValidationError {
@@ -41,6 +44,7 @@ impl ValidationError {
             ValidationError::MissingField(_) => 64,
             ValidationError::InvalidValue { .. } => 65,
             ValidationError::VersionMismatch { .. } => 66,
+            ValidationError::UnsetEnvVar { .. } => 67,
         }
     }
 }
@@ -204,8 +208,60 @@ pub fn validate_policy(policy: &crate::policy::Policy) -> ValidationResult<()> {
         ));
     }
 
+    if let Some(fraction) = policy.guardrails.max_kill_fraction {
+        if !(fraction.is_finite() && fraction > 0.0 && fraction <= 1.0) {
+            return Err(ValidationError::InvalidValue {
+                field: "guardrails.max_kill_fraction".to_string(),
+                message: format!("Must be in (0, 1], got {}", fraction),
+            });
+        }
+    }
+
     validate_load_aware(&policy.load_aware)?;
 
+    if let Some(probe_costs) = &policy.probe_costs {
+        validate_probe_costs(probe_costs)?;
+    }
+
+    Ok(())
+}
+
+fn validate_probe_costs(probe_costs: &crate::policy::ProbeCosts) -> ValidationResult<()> {
+    if let Some(multiplier) = probe_costs.base_multiplier {
+        if !(multiplier.is_finite() && multiplier > 0.0) {
+            return Err(ValidationError::InvalidValue {
+                field: "probe_costs.base_multiplier".to_string(),
+                message: format!("Must be > 0, got {}", multiplier),
+            });
+        }
+    }
+
+    for (probe, cost) in &probe_costs.costs {
+        if !crate::policy::ProbeCosts::PROBE_NAMES.contains(&probe.as_str()) {
+            return Err(ValidationError::InvalidValue {
+                field: format!("probe_costs.costs.{}", probe),
+                message: format!(
+                    "Unknown probe, expected one of: {}",
+                    crate::policy::ProbeCosts::PROBE_NAMES.join(", ")
+                ),
+            });
+        }
+        let components = [
+            ("time_seconds", cost.time_seconds),
+            ("overhead", cost.overhead),
+            ("intrusiveness", cost.intrusiveness),
+            ("risk", cost.risk),
+        ];
+        for (name, value) in components {
+            if !(value.is_finite() && value >= 0.0) {
+                return Err(ValidationError::InvalidValue {
+                    field: format!("probe_costs.costs.{}.{}", probe, name),
+                    message: format!("Must be non-negative, got {}", value),
+                });
+            }
+        }
+    }
+
     Ok(())
 }
 
@@ -281,14 +337,7 @@ fn validate_load_aware(load_aware: &crate::policy::LoadAwareDecision) -> Validat
 /// Validate loss matrix has all required values.
 fn validate_loss_matrix(matrix: &crate::policy::LossMatrix) -> ValidationResult<()> {
     // All losses must be non-negative
-    let classes = [
-        ("useful", &matrix.useful),
-        ("useful_bad", &matrix.useful_bad),
-        ("abandoned", &matrix.abandoned),
-        ("zombie", &matrix.zombie),
-    ];
-
-    for (name, row) in classes {
+    for (name, row) in matrix.rows() {
         if row.keep < 0.0 {
             return Err(ValidationError::InvalidValue {
                 field: format!("loss_matrix.{}.keep", name),
@@ -306,10 +355,248 @@ fn validate_loss_matrix(matrix: &crate::policy::LossMatrix) -> ValidationResult<
     Ok(())
 }
 
+/// Check a loss matrix for economically incoherent entries.
+///
+/// These are the mistakes that schema validation cannot catch but that
+/// silently produce dangerous decisions:
+/// - any loss cell (including optional actions) below zero
+/// - for a `useful` process, `kill` or `restart` costing no more than `keep`
+///   (the destructive action weakly dominates leaving it alone)
+/// - `kill` losses that rise as a process becomes less useful
+///   (`useful` ≥ `useful_bad` ≥ `abandoned` is expected)
+/// - for a `zombie`, `kill` costing more than `keep`
+/// - `keep` costing more for a `useful` process than for an `abandoned`
+///   or `zombie` one
+///
+/// Each finding names the offending cell. Callers decide whether to treat
+/// them as warnings; see [`validate_loss_matrix_coherence`].
+pub fn loss_matrix_findings(matrix: &crate::policy::LossMatrix) -> Vec<ValidationError> {
+    let mut findings = Vec::new();
+
+    for (class, row) in matrix.rows() {
+        let cells = [
+            ("keep", Some(row.keep)),
+            ("pause", row.pause),
+            ("throttle", row.throttle),
+            ("kill", Some(row.kill)),
+            ("restart", row.restart),
+            ("renice", row.renice),
+        ];
+        for (action, value) in cells {
+            if let Some(v) = value.filter(|v| *v < 0.0) {
+                findings.push(ValidationError::InvalidValue {
+                    field: format!("loss_matrix.{}.{}", class, action),
+                    message: format!("loss must be non-negative, got {}", v),
+                });
+            }
+        }
+    }
+
+    let useful = &matrix.useful;
+    for (action, value) in [("kill", Some(useful.kill)), ("restart", useful.restart)] {
+        if let Some(v) = value.filter(|v| *v <= useful.keep) {
+            findings.push(ValidationError::InvalidValue {
+                field: format!("loss_matrix.useful.{}", action),
+                message: format!(
+                    "{} on a useful process ({}) must cost more than keep ({})",
+                    action, v, useful.keep
+                ),
+            });
+        }
+    }
+
+    let kill_order = [
+        ("useful", matrix.useful.kill),
+        ("useful_bad", matrix.useful_bad.kill),
+        ("abandoned", matrix.abandoned.kill),
+    ];
+    for pair in kill_order.windows(2) {
+        let ((more_useful, higher), (less_useful, lower)) = (pair[0], pair[1]);
+        if lower > higher {
+            findings.push(ValidationError::InvalidValue {
+                field: format!("loss_matrix.{}.kill", less_useful),
+                message: format!(
+                    "killing a {} process ({}) costs more than killing a {} one ({})",
+                    less_useful, lower, more_useful, higher
+                ),
+            });
+        }
+    }
+
+    let zombie = &matrix.zombie;
+    if zombie.kill > zombie.keep {
+        findings.push(ValidationError::InvalidValue {
+            field: "loss_matrix.zombie.kill".to_string(),
+            message: format!(
+                "killing a zombie process ({}) must not cost more than keeping it ({})",
+                zombie.kill, zombie.keep
+            ),
+        });
+    }
+
+    for (class, keep) in [
+        ("abandoned", matrix.abandoned.keep),
+        ("zombie", matrix.zombie.keep),
+    ] {
+        if keep < matrix.useful.keep {
+            findings.push(ValidationError::InvalidValue {
+                field: format!("loss_matrix.{}.keep", class),
+                message: format!(
+                    "keeping a {} process ({}) costs less than keeping a useful one ({})",
+                    class, keep, matrix.useful.keep
+                ),
+            });
+        }
+    }
+
+    findings
+}
+
+/// Run [`loss_matrix_findings`], failing only when `strict` is set.
+///
+/// In non-strict mode the findings are returned as warnings for the caller
+/// to report; in strict mode the first finding becomes the error.
+pub fn validate_loss_matrix_coherence(
+    matrix: &crate::policy::LossMatrix,
+    strict: bool,
+) -> ValidationResult<Vec<ValidationError>> {
+    let findings = loss_matrix_findings(matrix);
+    if strict {
+        if let Some(first) = findings.into_iter().next() {
+            return Err(first);
+        }
+        return Ok(Vec::new());
+    }
+    Ok(findings)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn inverted_matrix() -> crate::policy::LossMatrix {
+        let mut matrix = crate::policy::Policy::default().loss_matrix;
+        // Killing a useful process is now cheaper than keeping it, and
+        // killing an abandoned one costs more than killing a useful one.
+        matrix.useful.keep = 10.0;
+        matrix.useful.kill = 1.0;
+        matrix.abandoned.kill = 500.0;
+        matrix.zombie.pause = Some(-0.5);
+        matrix
+    }
+
+    #[test]
+    fn test_default_and_preset_loss_matrices_are_coherent() {
+        assert!(loss_matrix_findings(&crate::policy::Policy::default().loss_matrix).is_empty());
+        for &name in crate::preset::PresetName::ALL {
+            let policy = crate::preset::get_preset(name);
+            let findings = loss_matrix_findings(&policy.loss_matrix);
+            assert!(findings.is_empty(), "{}: {:?}", name, findings);
+        }
+    }
+
+    #[test]
+    fn test_inverted_loss_matrix_findings_name_cells() {
+        let findings = loss_matrix_findings(&inverted_matrix());
+        let fields: Vec<String> = findings
+            .iter()
+            .map(|f| match f {
+                ValidationError::InvalidValue { field, .. } => field.clone(),
+                other => panic!("unexpected finding: {other}"),
+            })
+            .collect();
+
+        assert!(fields.contains(&"loss_matrix.useful.kill".to_string()));
+        assert!(fields.contains(&"loss_matrix.abandoned.kill".to_string()));
+        assert!(fields.contains(&"loss_matrix.zombie.pause".to_string()));
+        assert!(
+            fields.contains(&"loss_matrix.abandoned.keep".to_string())
+                || fields.contains(&"loss_matrix.zombie.keep".to_string())
+        );
+    }
+
+    #[test]
+    fn test_zombie_kill_costlier_than_keep_is_a_warning() {
+        let mut matrix = crate::policy::Policy::default().loss_matrix;
+        matrix.zombie.keep = 0.1;
+        matrix.zombie.kill = 2.0;
+
+        let warnings = validate_loss_matrix_coherence(&matrix, false).unwrap();
+        assert_eq!(warnings.len(), 1, "{:?}", warnings);
+        match &warnings[0] {
+            ValidationError::InvalidValue { field, message } => {
+                assert_eq!(field, "loss_matrix.zombie.kill");
+                assert!(message.contains("zombie"), "{}", message);
+            }
+            other => panic!("unexpected finding: {other}"),
+        }
+
+        // Equal costs are allowed.
+        matrix.zombie.kill = matrix.zombie.keep;
+        assert!(loss_matrix_findings(&matrix).is_empty());
+    }
+
+    #[test]
+    fn test_loss_matrix_coherence_strict_flag() {
+        let matrix = inverted_matrix();
+
+        let warnings = validate_loss_matrix_coherence(&matrix, false).unwrap();
+        assert!(!warnings.is_empty());
+
+        let err = validate_loss_matrix_coherence(&matrix, true).unwrap_err();
+        assert!(matches!(err, ValidationError::InvalidValue { .. }));
+
+        let default = crate::policy::Policy::default().loss_matrix;
+        assert!(validate_loss_matrix_coherence(&default, true)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_probe_cost_validation() {
+        use crate::policy::{Policy, ProbeCostOverride, ProbeCosts};
+
+        let free = ProbeCostOverride {
+            time_seconds: 0.0,
+            overhead: 0.0,
+            intrusiveness: 0.0,
+            risk: 0.0,
+        };
+        let mut policy = Policy {
+            probe_costs: Some(ProbeCosts {
+                costs: [("deep_scan".to_string(), free.clone())].into(),
+                base_multiplier: Some(2.0),
+            }),
+            ..Policy::default()
+        };
+        assert!(validate_policy(&policy).is_ok());
+
+        policy.probe_costs.as_mut().unwrap().base_multiplier = Some(0.0);
+        let err = validate_policy(&policy).unwrap_err();
+        assert!(
+            matches!(err, ValidationError::InvalidValue { ref field, .. } if field == "probe_costs.base_multiplier")
+        );
+
+        let probe_costs = policy.probe_costs.as_mut().unwrap();
+        probe_costs.base_multiplier = None;
+        probe_costs.costs.insert(
+            "strace".to_string(),
+            ProbeCostOverride { risk: -0.1, ..free },
+        );
+        let err = validate_policy(&policy).unwrap_err();
+        assert!(
+            matches!(err, ValidationError::InvalidValue { ref field, .. } if field == "probe_costs.costs.strace.risk")
+        );
+
+        let probe_costs = policy.probe_costs.as_mut().unwrap();
+        probe_costs.costs.remove("strace");
+        probe_costs.costs.insert("deep_scna".to_string(), free);
+        let err = validate_policy(&policy).unwrap_err();
+        assert!(
+            matches!(err, ValidationError::InvalidValue { ref field, .. } if field == "probe_costs.costs.deep_scna")
+        );
+    }
+
     #[test]
     fn test_beta_validation() {
         let valid = crate::priors::BetaParams {
@@ -617,6 +904,17 @@ mod tests {
         assert!(validate_policy(&policy).is_err());
     }
 
+    #[test]
+    fn policy_guardrails_kill_fraction_out_of_range() {
+        let mut policy = crate::policy::Policy::default();
+        policy.guardrails.max_kill_fraction = Some(0.0);
+        assert!(validate_policy(&policy).is_err());
+        policy.guardrails.max_kill_fraction = Some(1.5);
+        assert!(validate_policy(&policy).is_err());
+        policy.guardrails.max_kill_fraction = Some(0.25);
+        assert!(validate_policy(&policy).is_ok());
+    }
+
     #[test]
     fn policy_wrong_schema_version() {
         let policy = crate::policy::Policy {
@@ -0,0 +1,151 @@
+//! Config hot-reload for long-running processes.
+//!
+//! [`ConfigWatcher`] owns the active priors and policy and can re-resolve
+//! and re-validate them on demand. A new config is swapped in atomically
+//! only when both files load and pass validation; otherwise the previous
+//! config stays active, so a bad edit never leaves a daemon without a
+//! policy. Hosts call [`ConfigWatcher::reload`] from their own signal
+//! handling (e.g. `pt-core shadow run` on SIGHUP).
+
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+
+use crate::resolve::{resolve_config, ConfigPaths};
+use crate::validate::{validate_policy, validate_priors, ValidationResult};
+use crate::{Policy, Priors};
+
+/// A validated priors/policy pair and where it came from.
+#[derive(Debug, Clone)]
+pub struct LoadedConfig {
+    pub priors: Priors,
+    pub policy: Policy,
+    pub paths: ConfigPaths,
+}
+
+impl LoadedConfig {
+    /// Resolve, load, and validate configuration.
+    ///
+    /// Missing files fall back to built-in defaults, as at startup.
+    pub fn load(cli_priors: Option<&Path>, cli_policy: Option<&Path>) -> ValidationResult<Self> {
+        let paths = resolve_config(cli_priors, cli_policy);
+        let priors = paths.load_priors()?.unwrap_or_default();
+        let policy = paths.load_policy()?.unwrap_or_default();
+
+        validate_priors(&priors)?;
+        validate_policy(&policy)?;
+
+        Ok(Self {
+            priors,
+            policy,
+            paths,
+        })
+    }
+}
+
+/// Holds the active configuration and reloads it on request.
+#[derive(Debug)]
+pub struct ConfigWatcher {
+    cli_priors: Option<PathBuf>,
+    cli_policy: Option<PathBuf>,
+    current: RwLock<Arc<LoadedConfig>>,
+}
+
+impl ConfigWatcher {
+    /// Load the initial configuration. Fails if it does not validate.
+    pub fn new(cli_priors: Option<&Path>, cli_policy: Option<&Path>) -> ValidationResult<Self> {
+        let initial = LoadedConfig::load(cli_priors, cli_policy)?;
+        Ok(Self {
+            cli_priors: cli_priors.map(Path::to_path_buf),
+            cli_policy: cli_policy.map(Path::to_path_buf),
+            current: RwLock::new(Arc::new(initial)),
+        })
+    }
+
+    /// The currently active configuration.
+    pub fn current(&self) -> Arc<LoadedConfig> {
+        Arc::clone(&self.current.read().unwrap_or_else(|e| e.into_inner()))
+    }
+
+    /// Re-resolve and re-validate the config, swapping it in on success.
+    ///
+    /// On failure the previous config is kept and the error is returned.
+    pub fn reload(&self) -> ValidationResult<()> {
+        let next = LoadedConfig::load(self.cli_priors.as_deref(), self.cli_policy.as_deref())?;
+        *self.current.write().unwrap_or_else(|e| e.into_inner()) = Arc::new(next);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn write_policy(path: &Path, policy: &Policy) {
+        std::fs::write(path, serde_json::to_string(policy).unwrap()).unwrap();
+    }
+
+    fn write_priors(path: &Path) {
+        std::fs::write(path, serde_json::to_string(&Priors::default()).unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_reload_picks_up_valid_change() {
+        let dir = tempdir().unwrap();
+        let priors_path = dir.path().join("priors.json");
+        let policy_path = dir.path().join("policy.json");
+        write_priors(&priors_path);
+        write_policy(&policy_path, &Policy::default());
+
+        let watcher = ConfigWatcher::new(Some(&priors_path), Some(&policy_path)).unwrap();
+        let before = watcher.current().policy.guardrails.max_kills_per_run;
+
+        let mut edited = Policy::default();
+        edited.guardrails.max_kills_per_run = before + 7;
+        write_policy(&policy_path, &edited);
+
+        watcher.reload().unwrap();
+        assert_eq!(
+            watcher.current().policy.guardrails.max_kills_per_run,
+            before + 7
+        );
+    }
+
+    #[test]
+    fn test_reload_rejects_invalid_and_keeps_previous() {
+        let dir = tempdir().unwrap();
+        let priors_path = dir.path().join("priors.json");
+        let policy_path = dir.path().join("policy.json");
+        write_priors(&priors_path);
+        write_policy(&policy_path, &Policy::default());
+
+        let watcher = ConfigWatcher::new(Some(&priors_path), Some(&policy_path)).unwrap();
+        let held = watcher.current();
+
+        // Semantically invalid: alpha outside [0, 1].
+        let mut invalid = Policy::default();
+        invalid.fdr_control.alpha = 2.0;
+        write_policy(&policy_path, &invalid);
+        assert!(watcher.reload().is_err());
+        assert!(Arc::ptr_eq(&held, &watcher.current()));
+
+        // Unparseable JSON is rejected the same way.
+        std::fs::write(&policy_path, "{ not json").unwrap();
+        assert!(watcher.reload().is_err());
+        assert!(
+            (watcher.current().policy.fdr_control.alpha - held.policy.fdr_control.alpha).abs()
+                < f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_new_fails_on_invalid_initial_config() {
+        let dir = tempdir().unwrap();
+        let policy_path = dir.path().join("policy.json");
+        let mut invalid = Policy::default();
+        invalid.robot_mode.min_posterior = -1.0;
+        write_policy(&policy_path, &invalid);
+
+        assert!(ConfigWatcher::new(None, Some(&policy_path)).is_err());
+    }
+}
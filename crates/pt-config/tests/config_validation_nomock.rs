@@ -237,5 +237,5 @@ fn test_presets_are_deterministic() {
     assert_eq!(first_json, second_json);
 
     let presets = list_presets();
-    assert!(presets.iter().any(|p| p.name == PresetName::Ci.as_str()));
+    assert!(presets.iter().any(|p| p.name == PresetName::Ci));
 }
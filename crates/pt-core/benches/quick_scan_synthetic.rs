@@ -25,7 +25,7 @@ fn build_synthetic_ps_output_10k() -> String {
         let tty = "?";
         let etimes = 3600 + (i as u64);
 
-        // Fields must match the expected positions in `parse_ps_line_*`:
+        // Headerless rows follow the requested `-o` order in quick_scan:
         // pid ppid uid user pgid sid state %cpu rss vsz tty lstart(5 fields) etimes comm args...
         out.push_str(&format!(
             "{pid} {ppid} {uid} user {pgid} {sid} {state} {cpu:.1} {rss} {vsz} {tty} Tue Jan 1 00:00:00 2026 {etimes} proc proc --synthetic {pid}\n"
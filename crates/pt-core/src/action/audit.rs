@@ -0,0 +1,301 @@
+//! Audit trail for action attempts.
+//!
+//! The executor hands every attempted action to an [`AuditSink`] — including
+//! dry runs, blocked actions and actions the safety governor downgraded — so
+//! each one can be traced back to its target identity, the rationale that
+//! selected it, and what actually happened.
+//! [`ParquetAuditSink`] appends these records to the telemetry `audit` table.
+
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use pt_common::ProcessIdentity;
+use pt_telemetry::{
+    audit_schema, build_audit_batch, AuditRow, BatchedWriter, TableName, WriteError, WriterConfig,
+};
+use serde::Serialize;
+use std::sync::Arc;
+use thiserror::Error;
+
+use crate::action::executor::{ActionStatus, ObservedTarget};
+use crate::decision::Action;
+use crate::plan::{ActionRationale, PlanAction};
+
+/// `event_type` written for every action attempt.
+pub const ACTION_ATTEMPT_EVENT: &str = "action_attempt";
+
+/// `actor` written by the executor.
+pub const EXECUTOR_ACTOR: &str = "executor";
+
+/// Errors while recording an audit entry.
+#[derive(Debug, Error)]
+pub enum AuditError {
+    #[error("audit write failed: {0}")]
+    Write(#[from] WriteError),
+    #[error("audit serialization failed: {0}")]
+    Serialize(#[from] serde_json::Error),
+    #[error("audit sink closed")]
+    Closed,
+}
+
+/// Everything recorded about one action attempt.
+#[derive(Debug, Clone, Serialize)]
+pub struct ActionAuditRecord {
+    pub action_id: String,
+    /// Target identity (pid + start id) the action was planned against.
+    pub target: ProcessIdentity,
+    /// Action selected by the plan.
+    pub planned_action: Action,
+    /// Action the executor actually attempted (Keep when downgraded).
+    pub applied_action: Action,
+    pub status: ActionStatus,
+    /// Why the action ended the way it did, when known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    pub dry_run: bool,
+    /// Decision rationale carried by the plan action.
+    pub rationale: ActionRationale,
+    /// Identity and state found at the target PID after execution, next to
+    /// the planned [`Self::target`]; `None` when the action never ran or the
+    /// identity provider cannot observe processes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub observed: Option<ObservedTarget>,
+}
+
+impl ActionAuditRecord {
+    pub fn new(action: &PlanAction, status: ActionStatus) -> Self {
+        Self {
+            action_id: action.action_id.clone(),
+            target: action.target.clone(),
+            planned_action: action.action,
+            applied_action: action.action,
+            status,
+            reason: None,
+            dry_run: false,
+            rationale: action.rationale.clone(),
+            observed: None,
+        }
+    }
+
+    pub fn with_applied_action(mut self, action: Action) -> Self {
+        self.applied_action = action;
+        self
+    }
+
+    pub fn with_reason(mut self, reason: Option<String>) -> Self {
+        self.reason = reason;
+        self
+    }
+
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    pub fn with_observed(mut self, observed: Option<ObservedTarget>) -> Self {
+        self.observed = observed;
+        self
+    }
+
+    /// Audit severity: `info` for applied or no-op outcomes, `warning` for
+    /// skipped or blocked actions, `error` for failures.
+    pub fn severity(&self) -> &'static str {
+        match self.status {
+            ActionStatus::Success | ActionStatus::AlreadyDead => "info",
+            ActionStatus::Skipped | ActionStatus::PreCheckBlocked { .. } => "warning",
+            _ => "error",
+        }
+    }
+
+    /// One-line summary for the audit `message` column.
+    pub fn message(&self) -> String {
+        let status = match &self.status {
+            ActionStatus::Success => "success",
+            ActionStatus::IdentityMismatch => "identity_mismatch",
+            ActionStatus::ProcessNotFound => "process_not_found",
+            ActionStatus::PermissionDenied => "permission_denied",
+            ActionStatus::Timeout => "timeout",
+            ActionStatus::Failed => "failed",
+            ActionStatus::Skipped => "skipped",
+            ActionStatus::AlreadyDead => "already_dead",
+            ActionStatus::PreCheckBlocked { .. } => "precheck_blocked",
+        };
+        let mode = if self.dry_run { " (dry run)" } else { "" };
+        match &self.reason {
+            Some(reason) => format!(
+                "{:?} pid {}: {}{}: {}",
+                self.planned_action, self.target.pid.0, status, mode, reason
+            ),
+            None => format!(
+                "{:?} pid {}: {}{}",
+                self.planned_action, self.target.pid.0, status, mode
+            ),
+        }
+    }
+}
+
+/// Destination for action audit records.
+pub trait AuditSink {
+    fn record(&self, record: &ActionAuditRecord) -> Result<(), AuditError>;
+}
+
+/// Appends audit records to the telemetry `audit` Parquet table.
+///
+/// Records are buffered by the underlying [`BatchedWriter`]; call
+/// [`ParquetAuditSink::close`] to finalize the file. Dropping the sink
+/// closes it on a best-effort basis.
+pub struct ParquetAuditSink {
+    config: WriterConfig,
+    writer: Mutex<Option<BatchedWriter>>,
+}
+
+impl ParquetAuditSink {
+    pub fn new(config: WriterConfig) -> Self {
+        let writer = BatchedWriter::new(TableName::Audit, Arc::new(audit_schema()), config.clone());
+        Self {
+            config,
+            writer: Mutex::new(Some(writer)),
+        }
+    }
+
+    /// Flush buffered records and finalize the Parquet file.
+    ///
+    /// Returns the file path, or `None` if nothing was recorded.
+    pub fn close(self) -> Result<Option<PathBuf>, AuditError> {
+        let writer = self
+            .writer
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .take();
+        match writer.map(BatchedWriter::close) {
+            Some(Ok(path)) => Ok(Some(path)),
+            Some(Err(WriteError::EmptyBuffer)) | None => Ok(None),
+            Some(Err(err)) => Err(err.into()),
+        }
+    }
+
+    fn row(record: &ActionAuditRecord) -> Result<AuditRow, AuditError> {
+        Ok(AuditRow {
+            audit_ts_micros: chrono::Utc::now().timestamp_micros(),
+            event_type: ACTION_ATTEMPT_EVENT.to_string(),
+            severity: record.severity().to_string(),
+            actor: EXECUTOR_ACTOR.to_string(),
+            target_pid: Some(record.target.pid.0 as i32),
+            target_start_id: Some(record.target.start_id.0.clone()),
+            message: record.message(),
+            details_json: Some(serde_json::to_string(record)?),
+        })
+    }
+}
+
+impl AuditSink for ParquetAuditSink {
+    fn record(&self, record: &ActionAuditRecord) -> Result<(), AuditError> {
+        let batch = build_audit_batch(&self.config, &[Self::row(record)?])?;
+        let mut writer = self
+            .writer
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        writer.as_mut().ok_or(AuditError::Closed)?.write(batch)?;
+        Ok(())
+    }
+}
+
+impl Drop for ParquetAuditSink {
+    fn drop(&mut self) {
+        let writer = self
+            .writer
+            .get_mut()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .take();
+        if let Some(writer) = writer {
+            let _ = writer.close();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plan::{ActionConfidence, ActionRouting, ActionTimeouts};
+    use pt_common::{IdentityQuality, ProcessId, StartId};
+
+    fn kill_action() -> PlanAction {
+        PlanAction {
+            action_id: "a1".to_string(),
+            target: ProcessIdentity {
+                pid: ProcessId(4242),
+                start_id: StartId("boot:1:4242".to_string()),
+                uid: 1000,
+                pgid: None,
+                sid: None,
+                quality: IdentityQuality::Full,
+            },
+            action: Action::Kill,
+            order: 0,
+            stage: 0,
+            timeouts: ActionTimeouts::default(),
+            pre_checks: Vec::new(),
+            rationale: ActionRationale {
+                expected_loss: Some(0.2),
+                expected_recovery: None,
+                expected_recovery_stddev: None,
+                posterior_odds_abandoned_vs_useful: None,
+                sprt_boundary: None,
+                posterior: None,
+                memory_mb: None,
+                has_known_signature: None,
+                category: None,
+            },
+            on_success: Vec::new(),
+            on_failure: Vec::new(),
+            blocked: false,
+            routing: ActionRouting::Direct,
+            confidence: ActionConfidence::Normal,
+            original_zombie_target: None,
+            d_state_diagnostics: None,
+        }
+    }
+
+    #[test]
+    fn record_describes_outcome() {
+        let record = ActionAuditRecord::new(&kill_action(), ActionStatus::Skipped)
+            .with_applied_action(Action::Keep)
+            .with_reason(Some("governor_tripped: cap reached".to_string()))
+            .with_dry_run(true);
+        assert_eq!(record.severity(), "warning");
+        assert_eq!(
+            record.message(),
+            "Kill pid 4242: skipped (dry run): governor_tripped: cap reached"
+        );
+
+        let row = ParquetAuditSink::row(&record).unwrap();
+        assert_eq!(row.target_start_id.as_deref(), Some("boot:1:4242"));
+        let details: serde_json::Value =
+            serde_json::from_str(row.details_json.as_deref().unwrap()).unwrap();
+        assert_eq!(details["applied_action"], "keep");
+        assert_eq!(details["rationale"]["expected_loss"], 0.2);
+    }
+
+    #[test]
+    fn parquet_sink_writes_audit_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = WriterConfig::new(
+            dir.path().to_path_buf(),
+            "pt-20260101-000000-test".to_string(),
+            "host".to_string(),
+        );
+
+        let empty = ParquetAuditSink::new(config.clone());
+        assert!(empty.close().unwrap().is_none());
+
+        let sink = ParquetAuditSink::new(config);
+        sink.record(&ActionAuditRecord::new(
+            &kill_action(),
+            ActionStatus::Success,
+        ))
+        .unwrap();
+        let path = sink.close().unwrap().expect("audit file written");
+        assert!(path.exists());
+        assert!(path.to_string_lossy().contains("audit"));
+    }
+}
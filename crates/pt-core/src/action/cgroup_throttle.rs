@@ -1,19 +1,33 @@
-//! Cgroup CPU throttle action execution.
+//! Cgroup CPU/IO throttle action execution.
 //!
-//! Implements CPU throttling via cgroup v2 cpu.max with:
+//! Implements throttling via cgroup v2 cpu.max and io.max with:
 //! - Automatic cgroup path discovery for target process
 //! - Reversal metadata capture for undo operations
 //! - Verification via read-back of cpu.max
-//! - Fallback to cgroup v1 (cpu.cfs_quota_us/cpu.cfs_period_us)
+//! - io.max bandwidth caps on every device the cgroup has touched
+//! - Fallback to cgroup v1 (cpu.cfs_quota_us/cpu.cfs_period_us, CPU only)
 //! - Graceful degradation to renice if cgroup unavailable
+//!
+//! # Safety
+//!
+//! - Never touches PID 1, the root cgroup, or the cgroup this session runs in
+//!   (including any ancestor of it, which would throttle us too)
+//! - The limits in place before the first throttle are recorded per PID in
+//!   the data directory, so Unthrottle (and executor rollback) can restore
+//!   them exactly, including from a later pt invocation
 
 use super::executor::{ActionError, ActionRunner};
-use crate::collect::cgroup::{collect_cgroup_details, CgroupVersion, CpuLimitSource};
+use crate::collect::cgroup::{
+    collect_cgroup_details, collect_cgroup_from_content, CgroupDetails, CgroupVersion,
+    CpuLimitSource, CGROUP_ROOT,
+};
 use crate::decision::Action;
 use crate::plan::PlanAction;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
 use tracing::{debug, info, warn};
 
 /// Default CPU throttle fraction (25% of current allocation or one core).
@@ -25,6 +39,12 @@ pub const DEFAULT_PERIOD_US: u64 = 100_000;
 /// Minimum quota in microseconds (1ms - prevent starvation).
 pub const MIN_QUOTA_US: i64 = 1_000;
 
+/// Default io.max read/write cap in bytes per second (1 MiB/s).
+pub const DEFAULT_THROTTLE_IO_BPS: u64 = 1024 * 1024;
+
+/// Directory (within the data directory) holding recorded limits per PID.
+const THROTTLE_STATE_DIR_NAME: &str = "throttle";
+
 /// CPU throttle action configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CpuThrottleConfig {
@@ -40,6 +60,11 @@ pub struct CpuThrottleConfig {
 
     /// Whether to record previous settings for reversal.
     pub capture_reversal: bool,
+
+    /// Read and write bandwidth cap written to io.max (cgroup v2 only).
+    /// None leaves io.max untouched.
+    #[serde(default)]
+    pub io_bps_limit: Option<u64>,
 }
 
 impl Default for CpuThrottleConfig {
@@ -49,6 +74,7 @@ impl Default for CpuThrottleConfig {
             period_us: DEFAULT_PERIOD_US,
             fallback_to_v1: true,
             capture_reversal: true,
+            io_bps_limit: Some(DEFAULT_THROTTLE_IO_BPS),
         }
     }
 }
@@ -87,6 +113,10 @@ pub struct ThrottleReversalMetadata {
     /// Source of previous limits.
     pub source: CpuLimitSource,
 
+    /// io.max lines restoring each device the throttle caps (cgroup v2 only).
+    #[serde(default)]
+    pub previous_io_max: Vec<String>,
+
     /// Timestamp when throttle was applied.
     pub applied_at: String,
 }
@@ -114,17 +144,194 @@ pub struct ThrottleResult {
 #[derive(Debug)]
 pub struct CpuThrottleActionRunner {
     config: CpuThrottleConfig,
+    cgroup_root: PathBuf,
+    proc_root: PathBuf,
+    state_dir: Option<PathBuf>,
+    reversals: Mutex<HashMap<u32, ThrottleReversalMetadata>>,
 }
 
 impl CpuThrottleActionRunner {
     pub fn new(config: CpuThrottleConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            cgroup_root: PathBuf::from(CGROUP_ROOT),
+            proc_root: PathBuf::from("/proc"),
+            state_dir: default_state_dir(),
+            reversals: Mutex::new(HashMap::new()),
+        }
     }
 
     pub fn with_defaults() -> Self {
         Self::new(CpuThrottleConfig::default())
     }
 
+    /// Read and write cgroup files beneath `root` instead of `/sys/fs/cgroup`.
+    pub fn with_cgroup_root(mut self, root: impl Into<PathBuf>) -> Self {
+        self.cgroup_root = root.into();
+        self
+    }
+
+    /// Read process cgroup membership beneath `root` instead of `/proc`.
+    pub fn with_proc_root(mut self, root: impl Into<PathBuf>) -> Self {
+        self.proc_root = root.into();
+        self
+    }
+
+    /// Persist recorded limits beneath `dir` instead of the data directory.
+    pub fn with_state_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.state_dir = Some(dir.into());
+        self
+    }
+
+    /// Limits recorded for `pid` before it was first throttled.
+    ///
+    /// Falls back to the state directory, so a throttle applied by an earlier
+    /// pt invocation can still be undone.
+    pub fn reversal(&self, pid: u32) -> Option<ThrottleReversalMetadata> {
+        if let Some(metadata) = self.lock_reversals().get(&pid) {
+            return Some(metadata.clone());
+        }
+        let path = self.state_path(pid)?;
+        let bytes = fs::read(&path).ok()?;
+        match serde_json::from_slice(&bytes) {
+            Ok(metadata) => Some(metadata),
+            Err(e) => {
+                warn!(path = %path.display(), error = %e, "ignoring unreadable throttle record");
+                None
+            }
+        }
+    }
+
+    fn state_path(&self, pid: u32) -> Option<PathBuf> {
+        self.state_dir
+            .as_ref()
+            .map(|dir| dir.join(format!("{}.json", pid)))
+    }
+
+    /// Record `metadata` in memory and, when configured, on disk.
+    #[cfg(target_os = "linux")]
+    fn store_reversal(&self, metadata: ThrottleReversalMetadata) -> Result<(), ActionError> {
+        if let Some(path) = self.state_path(metadata.pid) {
+            let json = serde_json::to_vec_pretty(&metadata).map_err(|e| {
+                ActionError::Failed(format!("failed to encode throttle record: {}", e))
+            })?;
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent).map_err(|e| {
+                    ActionError::Failed(format!("failed to create {}: {}", parent.display(), e))
+                })?;
+            }
+            fs::write(&path, json).map_err(|e| {
+                ActionError::Failed(format!("failed to write {}: {}", path.display(), e))
+            })?;
+        }
+        self.lock_reversals().insert(metadata.pid, metadata);
+        Ok(())
+    }
+
+    #[cfg(target_os = "linux")]
+    fn forget_reversal(&self, pid: u32) {
+        self.lock_reversals().remove(&pid);
+        if let Some(path) = self.state_path(pid) {
+            if let Err(e) = fs::remove_file(&path) {
+                if e.kind() != std::io::ErrorKind::NotFound {
+                    warn!(path = %path.display(), error = %e, "failed to remove throttle record");
+                }
+            }
+        }
+    }
+
+    fn lock_reversals(&self) -> std::sync::MutexGuard<'_, HashMap<u32, ThrottleReversalMetadata>> {
+        self.reversals.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Cgroup membership for a `/proc` entry (`<pid>` or `self`).
+    fn cgroup_details(&self, entry: &str) -> Option<CgroupDetails> {
+        let source = self.proc_root.join(entry).join("cgroup");
+        let bytes = fs::read(&source).ok()?;
+        collect_cgroup_from_content(
+            &String::from_utf8_lossy(&bytes),
+            &source.to_string_lossy(),
+            None,
+        )
+    }
+
+    fn v2_dir(&self, unified_path: &str) -> PathBuf {
+        self.cgroup_root.join(unified_path.trim_start_matches('/'))
+    }
+
+    fn v1_dir(&self, cpu_path: &str) -> PathBuf {
+        self.cgroup_root
+            .join("cpu")
+            .join(cpu_path.trim_start_matches('/'))
+    }
+
+    fn v2_mounted(&self) -> bool {
+        self.cgroup_root.join("cgroup.controllers").is_file()
+    }
+
+    /// Refuse the root cgroup and this session's own cgroup.
+    ///
+    /// `own` is this process's path in the same hierarchy as `cgroup_path`.
+    fn ensure_may_throttle(
+        &self,
+        pid: u32,
+        cgroup_path: &str,
+        own: Option<&str>,
+    ) -> Result<(), ActionError> {
+        if cgroup_path.trim_end_matches('/').is_empty() {
+            return Err(ActionError::Failed(format!(
+                "refusing to throttle the root cgroup (pid {})",
+                pid
+            )));
+        }
+        if let Some(own) = own {
+            if is_same_or_ancestor(cgroup_path, own) {
+                return Err(ActionError::Failed(format!(
+                    "refusing to throttle the session's own cgroup {} (pid {})",
+                    cgroup_path, pid
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Record the limits in place for `pid` unless already recorded.
+    ///
+    /// Repeated throttles keep the first snapshot so a restore returns the
+    /// process to where it was before pt touched it; devices capped for the
+    /// first time are added to it. A record for a different cgroup belongs
+    /// to an earlier process with the same PID and is replaced.
+    #[cfg(target_os = "linux")]
+    fn remember_reversal(&self, pid: u32) -> Result<(), ActionError> {
+        if !self.config.capture_reversal {
+            return Ok(());
+        }
+        let Some(current) = self.capture_reversal_metadata(pid) else {
+            return Ok(());
+        };
+        let metadata = match self.reversal(pid) {
+            Some(mut recorded) if recorded.cgroup_path == current.cgroup_path => {
+                let new_lines: Vec<String> = current
+                    .previous_io_max
+                    .into_iter()
+                    .filter(|line| {
+                        !recorded
+                            .previous_io_max
+                            .iter()
+                            .any(|l| io_line_device(l) == io_line_device(line))
+                    })
+                    .collect();
+                if new_lines.is_empty() {
+                    return Ok(());
+                }
+                recorded.previous_io_max.extend(new_lines);
+                recorded
+            }
+            _ => current,
+        };
+        self.store_reversal(metadata)
+    }
+
     /// Execute a throttle action on a process.
     #[cfg(target_os = "linux")]
     fn execute_throttle(&self, action: &PlanAction) -> Result<(), ActionError> {
@@ -134,17 +341,38 @@ impl CpuThrottleActionRunner {
             fraction = self.config.target_fraction,
             "executing CPU throttle"
         );
+        if pid <= 1 {
+            return Err(ActionError::Failed(format!(
+                "refusing to throttle PID {}",
+                pid
+            )));
+        }
 
         // Collect cgroup details for the target process
-        let cgroup_details = collect_cgroup_details(pid)
+        let cgroup_details = self
+            .cgroup_details(&pid.to_string())
             .ok_or_else(|| ActionError::Failed(format!("failed to read cgroup for pid {}", pid)))?;
+        let own = self.cgroup_details("self");
 
         // Try cgroup v2 first
         if cgroup_details.version == CgroupVersion::V2
             || cgroup_details.version == CgroupVersion::Hybrid
         {
             if let Some(ref unified_path) = cgroup_details.unified_path {
-                let result = self.apply_throttle_v2(pid, unified_path);
+                let result = if self.v2_mounted() {
+                    self.ensure_may_throttle(
+                        pid,
+                        unified_path,
+                        own.as_ref().and_then(|o| o.unified_path.as_deref()),
+                    )?;
+                    self.remember_reversal(pid)?;
+                    self.apply_throttle_v2(pid, unified_path)
+                } else {
+                    Err(ActionError::Failed(format!(
+                        "cgroup v2 is not mounted at {}",
+                        self.cgroup_root.display()
+                    )))
+                };
                 if result.is_ok() {
                     return result;
                 }
@@ -154,11 +382,24 @@ impl CpuThrottleActionRunner {
                 }
                 warn!(pid, "cgroup v2 throttle failed, trying v1 fallback");
             }
+        } else if !self.config.fallback_to_v1 {
+            return Err(ActionError::Failed(format!(
+                "cgroup v2 is not mounted at {}",
+                self.cgroup_root.display()
+            )));
         }
 
         // Try cgroup v1 if available
         if self.config.fallback_to_v1 {
             if let Some(cpu_path) = cgroup_details.v1_paths.get("cpu") {
+                self.ensure_may_throttle(
+                    pid,
+                    cpu_path,
+                    own.as_ref()
+                        .and_then(|o| o.v1_paths.get("cpu"))
+                        .map(String::as_str),
+                )?;
+                self.remember_reversal(pid)?;
                 return self.apply_throttle_v1(pid, cpu_path);
             }
         }
@@ -169,17 +410,17 @@ impl CpuThrottleActionRunner {
         )))
     }
 
-    /// Apply CPU throttle using cgroup v2 cpu.max.
+    /// Apply CPU and IO throttle using cgroup v2 cpu.max and io.max.
     #[cfg(target_os = "linux")]
     fn apply_throttle_v2(&self, pid: u32, unified_path: &str) -> Result<(), ActionError> {
-        let cgroup_root = "/sys/fs/cgroup";
-        let cpu_max_path = format!("{}{}/cpu.max", cgroup_root, unified_path);
+        let dir = self.v2_dir(unified_path);
+        let cpu_max_path = dir.join("cpu.max");
 
         // Check if cpu.max exists and is writable
-        if !Path::new(&cpu_max_path).exists() {
+        if !cpu_max_path.exists() {
             return Err(ActionError::Failed(format!(
                 "cpu.max not found at {}",
-                cpu_max_path
+                cpu_max_path.display()
             )));
         }
 
@@ -190,7 +431,7 @@ impl CpuThrottleActionRunner {
 
         debug!(
             pid,
-            path = %cpu_max_path,
+            path = %cpu_max_path.display(),
             value = %cpu_max_value,
             "writing cpu.max"
         );
@@ -204,29 +445,70 @@ impl CpuThrottleActionRunner {
             }
         })?;
 
+        let io_devices = self.apply_io_limits(pid, &dir)?;
+
         info!(
             pid,
             cgroup = unified_path,
             quota_us = quota,
             period_us = period,
+            io_devices,
             "CPU throttle applied via cgroup v2"
         );
 
         Ok(())
     }
 
+    /// Cap io.max bandwidth on every device in io.stat or io.max.
+    ///
+    /// Existing limits tighter than the cap are kept. Returns the number of
+    /// devices written; io.max is left alone when the io controller is not
+    /// enabled for the cgroup or no cap is configured.
+    #[cfg(target_os = "linux")]
+    fn apply_io_limits(&self, pid: u32, dir: &Path) -> Result<usize, ActionError> {
+        let io_max_path = dir.join("io.max");
+        let Some(bps) = self.config.io_bps_limit else {
+            return Ok(0);
+        };
+        if !io_max_path.exists() {
+            return Ok(0);
+        }
+
+        let previous = fs::read_to_string(&io_max_path).unwrap_or_default();
+        let stat = fs::read_to_string(dir.join("io.stat")).unwrap_or_default();
+        let devices = io_devices(&stat, &previous);
+        for device in &devices {
+            let entry = io_max_entry(&previous, device);
+            let line = format!(
+                "{} rbps={} wbps={}",
+                device,
+                tighter_bps(entry.get("rbps"), bps),
+                tighter_bps(entry.get("wbps"), bps)
+            );
+            debug!(pid, path = %io_max_path.display(), value = %line, "writing io.max");
+            fs::write(&io_max_path, &line).map_err(|e| {
+                if e.kind() == std::io::ErrorKind::PermissionDenied {
+                    ActionError::PermissionDenied
+                } else {
+                    ActionError::Failed(format!("failed to write io.max: {}", e))
+                }
+            })?;
+        }
+        Ok(devices.len())
+    }
+
     /// Apply CPU throttle using cgroup v1 cpu.cfs_quota_us.
     #[cfg(target_os = "linux")]
     fn apply_throttle_v1(&self, pid: u32, cpu_path: &str) -> Result<(), ActionError> {
-        let cgroup_root = "/sys/fs/cgroup/cpu";
-        let quota_path = format!("{}{}/cpu.cfs_quota_us", cgroup_root, cpu_path);
-        let period_path = format!("{}{}/cpu.cfs_period_us", cgroup_root, cpu_path);
+        let dir = self.v1_dir(cpu_path);
+        let quota_path = dir.join("cpu.cfs_quota_us");
+        let period_path = dir.join("cpu.cfs_period_us");
 
         // Check if paths exist
-        if !Path::new(&quota_path).exists() {
+        if !quota_path.exists() {
             return Err(ActionError::Failed(format!(
                 "cpu.cfs_quota_us not found at {}",
-                quota_path
+                quota_path.display()
             )));
         }
 
@@ -236,7 +518,7 @@ impl CpuThrottleActionRunner {
 
         debug!(
             pid,
-            quota_path = %quota_path,
+            quota_path = %quota_path.display(),
             quota = quota,
             period = period,
             "writing cgroup v1 CPU limits"
@@ -270,13 +552,34 @@ impl CpuThrottleActionRunner {
         Ok(())
     }
 
+    /// Current CPU limits of a cgroup membership: (quota, period, source).
+    fn read_cpu_limits(
+        &self,
+        details: &CgroupDetails,
+    ) -> Option<(Option<i64>, u64, CpuLimitSource)> {
+        if let Some(ref unified_path) = details.unified_path {
+            if let Some((quota, period)) = read_cpu_max(&self.v2_dir(unified_path).join("cpu.max"))
+            {
+                return Some((quota, period, CpuLimitSource::CgroupV2CpuMax));
+            }
+        }
+        if let Some(cpu_path) = details.v1_paths.get("cpu") {
+            let dir = self.v1_dir(cpu_path);
+            let quota = read_i64(&dir.join("cpu.cfs_quota_us"))?;
+            let period = read_i64(&dir.join("cpu.cfs_period_us"))?;
+            let quota = if quota < 0 { None } else { Some(quota) };
+            return Some((quota, period.max(0) as u64, CpuLimitSource::CgroupV1Cfs));
+        }
+        None
+    }
+
     /// Verify throttle was applied by reading back cpu.max.
     #[cfg(target_os = "linux")]
     fn verify_throttle(&self, action: &PlanAction) -> Result<(), ActionError> {
         let pid = action.target.pid.0;
 
         // Re-collect cgroup details to verify
-        let cgroup_details = collect_cgroup_details(pid).ok_or_else(|| {
+        let cgroup_details = self.cgroup_details(&pid.to_string()).ok_or_else(|| {
             ActionError::Failed(format!(
                 "failed to read cgroup for verification, pid {}",
                 pid
@@ -286,81 +589,66 @@ impl CpuThrottleActionRunner {
         let expected_quota = self.config.quota_us();
         let expected_period = self.config.period_us;
 
-        // Check v2 first
-        if let Some(ref limits) = cgroup_details.cpu_limits {
-            match limits.source {
-                CpuLimitSource::CgroupV2CpuMax => {
-                    // Verify quota and period match what we set
-                    let actual_quota = limits.quota_us.unwrap_or(-1);
-                    let actual_period = limits.period_us.unwrap_or(0);
-
-                    if actual_quota != expected_quota {
-                        return Err(ActionError::Failed(format!(
-                            "quota mismatch: expected {}, got {}",
-                            expected_quota, actual_quota
-                        )));
-                    }
-                    if actual_period != expected_period {
-                        return Err(ActionError::Failed(format!(
-                            "period mismatch: expected {}, got {}",
-                            expected_period, actual_period
-                        )));
-                    }
-                    debug!(pid, "throttle verification passed (v2)");
-                    return Ok(());
-                }
-                CpuLimitSource::CgroupV1Cfs => {
-                    // Verify v1 settings
-                    let actual_quota = limits.quota_us.unwrap_or(-1);
-                    let actual_period = limits.period_us.unwrap_or(0);
-
-                    if actual_quota != expected_quota {
-                        return Err(ActionError::Failed(format!(
-                            "v1 quota mismatch: expected {}, got {}",
-                            expected_quota, actual_quota
-                        )));
-                    }
-                    if actual_period != expected_period {
-                        return Err(ActionError::Failed(format!(
-                            "v1 period mismatch: expected {}, got {}",
-                            expected_period, actual_period
-                        )));
-                    }
-                    debug!(pid, "throttle verification passed (v1)");
-                    return Ok(());
-                }
-                CpuLimitSource::None => {
-                    return Err(ActionError::Failed(
-                        "no CPU limits found after throttle".to_string(),
-                    ));
-                }
-            }
+        let Some((quota, period, source)) = self.read_cpu_limits(&cgroup_details) else {
+            return Err(ActionError::Failed(
+                "could not verify throttle - no CPU limits in cgroup".to_string(),
+            ));
+        };
+        let prefix = match source {
+            CpuLimitSource::CgroupV1Cfs => "v1 ",
+            _ => "",
+        };
+        let actual_quota = quota.unwrap_or(-1);
+        if actual_quota != expected_quota {
+            return Err(ActionError::Failed(format!(
+                "{}quota mismatch: expected {}, got {}",
+                prefix, expected_quota, actual_quota
+            )));
         }
-
-        Err(ActionError::Failed(
-            "could not verify throttle - no CPU limits in cgroup".to_string(),
-        ))
+        if period != expected_period {
+            return Err(ActionError::Failed(format!(
+                "{}period mismatch: expected {}, got {}",
+                prefix, expected_period, period
+            )));
+        }
+        debug!(pid, source = ?source, "throttle verification passed");
+        Ok(())
     }
 
     /// Capture reversal metadata before applying throttle.
     #[cfg(target_os = "linux")]
     pub fn capture_reversal_metadata(&self, pid: u32) -> Option<ThrottleReversalMetadata> {
-        let cgroup_details = collect_cgroup_details(pid)?;
-
-        let (cgroup_path, previous_quota, previous_period, source) =
-            if let Some(ref limits) = cgroup_details.cpu_limits {
-                let path = cgroup_details
-                    .unified_path
-                    .clone()
-                    .or_else(|| cgroup_details.v1_paths.get("cpu").cloned())?;
-                (path, limits.quota_us, limits.period_us, limits.source)
-            } else {
-                let path = cgroup_details
-                    .unified_path
-                    .clone()
-                    .or_else(|| cgroup_details.v1_paths.get("cpu").cloned())?;
-                (path, None, None, CpuLimitSource::None)
-            };
+        let cgroup_details = self.cgroup_details(&pid.to_string())?;
+
+        let (previous_quota, previous_period, source) = match self.read_cpu_limits(&cgroup_details)
+        {
+            Some((quota, period, source)) => (quota, Some(period), source),
+            None => (None, None, CpuLimitSource::None),
+        };
+        let cgroup_path = match source {
+            CpuLimitSource::CgroupV1Cfs => cgroup_details.v1_paths.get("cpu").cloned(),
+            _ => cgroup_details
+                .unified_path
+                .clone()
+                .or_else(|| cgroup_details.v1_paths.get("cpu").cloned()),
+        }?;
+
+        // io.max only exists in the unified hierarchy.
+        let io_max_path = self.v2_dir(&cgroup_path).join("io.max");
+        let previous_io_max = if source != CpuLimitSource::CgroupV1Cfs
+            && self.config.io_bps_limit.is_some()
+            && io_max_path.exists()
+        {
+            let io_max = fs::read_to_string(&io_max_path).unwrap_or_default();
+            let io_stat =
+                fs::read_to_string(self.v2_dir(&cgroup_path).join("io.stat")).unwrap_or_default();
+            io_devices(&io_stat, &io_max)
+                .iter()
+                .map(|device| io_restore_line(device, &io_max_entry(&io_max, device)))
+                .collect()
+        } else {
+            Vec::new()
+        };
 
         Some(ThrottleReversalMetadata {
             pid,
@@ -368,19 +656,43 @@ impl CpuThrottleActionRunner {
             previous_quota_us: previous_quota,
             previous_period_us: previous_period,
             source,
+            previous_io_max,
             applied_at: chrono::Utc::now().to_rfc3339(),
         })
     }
 
-    /// Restore previous CPU limits from reversal metadata.
+    /// Restore the limits recorded for `pid` before it was first throttled.
+    #[cfg(target_os = "linux")]
+    pub fn release(&self, pid: u32) -> Result<(), ActionError> {
+        let metadata = self.reversal(pid).ok_or_else(|| {
+            ActionError::Failed(format!("no recorded CPU limits for pid {}", pid))
+        })?;
+        self.restore_from_metadata(&metadata)?;
+        self.forget_reversal(pid);
+        Ok(())
+    }
+
+    /// Restore previous CPU and IO limits from reversal metadata.
     #[cfg(target_os = "linux")]
     pub fn restore_from_metadata(
         &self,
         metadata: &ThrottleReversalMetadata,
     ) -> Result<(), ActionError> {
+        if !metadata.previous_io_max.is_empty() {
+            let io_max_path = self.v2_dir(&metadata.cgroup_path).join("io.max");
+            for line in &metadata.previous_io_max {
+                fs::write(&io_max_path, line)
+                    .map_err(|e| ActionError::Failed(format!("failed to restore io.max: {}", e)))?;
+            }
+            info!(
+                path = %io_max_path.display(),
+                devices = metadata.previous_io_max.len(),
+                "restored IO limits from reversal metadata"
+            );
+        }
         match metadata.source {
             CpuLimitSource::CgroupV2CpuMax => {
-                let cpu_max_path = format!("/sys/fs/cgroup{}/cpu.max", metadata.cgroup_path);
+                let cpu_max_path = self.v2_dir(&metadata.cgroup_path).join("cpu.max");
                 let value = match (metadata.previous_quota_us, metadata.previous_period_us) {
                     (Some(q), Some(p)) if q > 0 => format!("{} {}", q, p),
                     (None, Some(p)) | (_, Some(p)) => format!("max {}", p),
@@ -390,21 +702,16 @@ impl CpuThrottleActionRunner {
                     ActionError::Failed(format!("failed to restore cpu.max: {}", e))
                 })?;
                 info!(
-                    path = %cpu_max_path,
+                    path = %cpu_max_path.display(),
                     value = %value,
                     "restored CPU limits from reversal metadata"
                 );
                 Ok(())
             }
             CpuLimitSource::CgroupV1Cfs => {
-                let quota_path = format!(
-                    "/sys/fs/cgroup/cpu{}/cpu.cfs_quota_us",
-                    metadata.cgroup_path
-                );
-                let period_path = format!(
-                    "/sys/fs/cgroup/cpu{}/cpu.cfs_period_us",
-                    metadata.cgroup_path
-                );
+                let dir = self.v1_dir(&metadata.cgroup_path);
+                let quota_path = dir.join("cpu.cfs_quota_us");
+                let period_path = dir.join("cpu.cfs_period_us");
 
                 if let Some(period) = metadata.previous_period_us {
                     fs::write(&period_path, period.to_string()).map_err(|e| {
@@ -417,7 +724,7 @@ impl CpuThrottleActionRunner {
                     .map_err(|e| ActionError::Failed(format!("failed to restore quota: {}", e)))?;
 
                 info!(
-                    quota_path = %quota_path,
+                    quota_path = %quota_path.display(),
                     quota = quota_value,
                     "restored CPU limits from reversal metadata (v1)"
                 );
@@ -427,8 +734,8 @@ impl CpuThrottleActionRunner {
                 // No previous limits - set to unlimited
                 warn!("no previous limits in reversal metadata, setting to unlimited");
                 // Try v2 first
-                let cpu_max_path = format!("/sys/fs/cgroup{}/cpu.max", metadata.cgroup_path);
-                if Path::new(&cpu_max_path).exists() {
+                let cpu_max_path = self.v2_dir(&metadata.cgroup_path).join("cpu.max");
+                if cpu_max_path.exists() {
                     fs::write(&cpu_max_path, "max 100000").map_err(|e| {
                         ActionError::Failed(format!("failed to restore to unlimited (v2): {}", e))
                     })?;
@@ -436,11 +743,8 @@ impl CpuThrottleActionRunner {
                 }
 
                 // Try v1 fallback
-                let quota_path = format!(
-                    "/sys/fs/cgroup/cpu{}/cpu.cfs_quota_us",
-                    metadata.cgroup_path
-                );
-                if Path::new(&quota_path).exists() {
+                let quota_path = self.v1_dir(&metadata.cgroup_path).join("cpu.cfs_quota_us");
+                if quota_path.exists() {
                     fs::write(&quota_path, "-1").map_err(|e| {
                         ActionError::Failed(format!("failed to restore to unlimited (v1): {}", e))
                     })?;
@@ -451,11 +755,105 @@ impl CpuThrottleActionRunner {
     }
 }
 
+/// Whether `candidate` is `path` itself or one of its ancestors.
+fn is_same_or_ancestor(candidate: &str, path: &str) -> bool {
+    let candidate = candidate.trim_end_matches('/');
+    let path = path.trim_end_matches('/');
+    path == candidate
+        || path
+            .strip_prefix(candidate)
+            .is_some_and(|rest| rest.starts_with('/'))
+}
+
+/// Read cpu.max ("max 100000" or "25000 100000") as (quota, period).
+fn read_cpu_max(path: &Path) -> Option<(Option<i64>, u64)> {
+    let content = fs::read_to_string(path).ok()?;
+    let mut parts = content.split_whitespace();
+    let quota = match parts.next()? {
+        "max" => None,
+        quota => Some(quota.parse::<i64>().ok()?),
+    };
+    let period = parts.next()?.parse::<u64>().ok()?;
+    Some((quota, period))
+}
+
+fn read_i64(path: &Path) -> Option<i64> {
+    fs::read_to_string(path).ok()?.trim().parse().ok()
+}
+
+/// Directory for recorded limits: `$PROCESS_TRIAGE_DATA`, then XDG data.
+fn default_state_dir() -> Option<PathBuf> {
+    if let Ok(dir) = std::env::var("PROCESS_TRIAGE_DATA") {
+        return Some(PathBuf::from(dir).join(THROTTLE_STATE_DIR_NAME));
+    }
+    if let Ok(xdg) = std::env::var("XDG_DATA_HOME") {
+        return Some(
+            PathBuf::from(xdg)
+                .join("process_triage")
+                .join(THROTTLE_STATE_DIR_NAME),
+        );
+    }
+    dirs::data_dir().map(|base| base.join("process_triage").join(THROTTLE_STATE_DIR_NAME))
+}
+
+/// Devices to cap: those with I/O in `io.stat` plus any already in `io.max`.
+fn io_devices(io_stat: &str, io_max: &str) -> Vec<String> {
+    let mut devices: Vec<String> = Vec::new();
+    for line in io_stat.lines().chain(io_max.lines()) {
+        if let Some(device) = line.split_whitespace().next() {
+            if device.contains(':') && !devices.iter().any(|d| d == device) {
+                devices.push(device.to_string());
+            }
+        }
+    }
+    devices
+}
+
+fn io_line_device(line: &str) -> Option<&str> {
+    line.split_whitespace().next()
+}
+
+/// Key/value limits for `device` in `io.max` (empty when unlimited).
+fn io_max_entry(io_max: &str, device: &str) -> HashMap<String, String> {
+    io_max
+        .lines()
+        .find(|line| io_line_device(line) == Some(device))
+        .map(|line| {
+            line.split_whitespace()
+                .skip(1)
+                .filter_map(|kv| kv.split_once('='))
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// `limit`, unless `previous` is already tighter.
+fn tighter_bps(previous: Option<&String>, limit: u64) -> u64 {
+    previous
+        .and_then(|v| v.parse::<u64>().ok())
+        .map_or(limit, |prev| prev.min(limit))
+}
+
+/// Line restoring `device` to its previous limits (unset keys become "max").
+fn io_restore_line(device: &str, previous: &HashMap<String, String>) -> String {
+    let value = |key: &str| previous.get(key).map_or("max", String::as_str);
+    format!(
+        "{} rbps={} wbps={} riops={} wiops={}",
+        device,
+        value("rbps"),
+        value("wbps"),
+        value("riops"),
+        value("wiops")
+    )
+}
+
 #[cfg(target_os = "linux")]
 impl ActionRunner for CpuThrottleActionRunner {
     fn execute(&self, action: &PlanAction) -> Result<(), ActionError> {
         match action.action {
             Action::Throttle => self.execute_throttle(action),
+            Action::Unthrottle => self.release(action.target.pid.0),
             Action::Keep => Ok(()),
             Action::Pause
             | Action::Resume
@@ -475,6 +873,17 @@ impl ActionRunner for CpuThrottleActionRunner {
     fn verify(&self, action: &PlanAction) -> Result<(), ActionError> {
         match action.action {
             Action::Throttle => self.verify_throttle(action),
+            Action::Unthrottle => {
+                let pid = action.target.pid.0;
+                if self.reversal(pid).is_some() {
+                    Err(ActionError::Failed(format!(
+                        "CPU limits for pid {} were not restored",
+                        pid
+                    )))
+                } else {
+                    Ok(())
+                }
+            }
             Action::Keep => Ok(()),
             Action::Pause
             | Action::Resume
@@ -551,6 +960,7 @@ mod tests {
         assert_eq!(config.period_us, DEFAULT_PERIOD_US);
         assert!(config.fallback_to_v1);
         assert!(config.capture_reversal);
+        assert_eq!(config.io_bps_limit, Some(DEFAULT_THROTTLE_IO_BPS));
     }
 
     #[test]
@@ -587,6 +997,22 @@ mod tests {
         assert_eq!(config.quota_us(), MIN_QUOTA_US);
     }
 
+    #[test]
+    fn io_limits_keep_tighter_values_and_restore_unset_keys() {
+        let io_max = "8:0 rbps=max wbps=524288 riops=max wiops=max\n";
+        let entry = io_max_entry(io_max, "8:0");
+        assert_eq!(tighter_bps(entry.get("wbps"), 1_048_576), 524_288);
+        assert_eq!(tighter_bps(entry.get("rbps"), 1_048_576), 1_048_576);
+        assert_eq!(
+            io_restore_line("259:0", &io_max_entry(io_max, "259:0")),
+            "259:0 rbps=max wbps=max riops=max wiops=max"
+        );
+        assert_eq!(
+            io_devices("259:0 rbytes=5 wbytes=0\n8:0 rbytes=1\n", io_max),
+            vec!["259:0".to_string(), "8:0".to_string()]
+        );
+    }
+
     #[test]
     fn runner_can_be_created() {
         let runner = CpuThrottleActionRunner::with_defaults();
@@ -650,5 +1076,346 @@ mod tests {
             assert!(json.contains("success"));
             assert!(json.contains("cgroup_path"));
         }
+
+        mod fixture_tests {
+            use super::*;
+            use crate::action::executor::{ActionExecutor, ActionStatus, StaticIdentityProvider};
+            use crate::config::Policy;
+            use crate::decision::{DecisionOutcome, DecisionRationale, ExpectedLoss};
+            use crate::plan::{generate_plan, DecisionBundle, DecisionCandidate, Plan};
+            use pt_common::{IdentityQuality, ProcessId, ProcessIdentity, SessionId, StartId};
+            use tempfile::TempDir;
+
+            const TARGET_CPU_MAX: &str = "cgroup/user.slice/app.scope/cpu.max";
+            const TARGET_IO_MAX: &str = "cgroup/user.slice/app.scope/io.max";
+            const OWN_CGROUP: &str = "/user.slice/session-1.scope";
+
+            /// Fake `/proc` and `/sys/fs/cgroup` with a target process (pid
+            /// 4242) and this session in sibling cgroups.
+            struct Fixture {
+                dir: TempDir,
+            }
+
+            impl Fixture {
+                fn new() -> Self {
+                    let fixture = Self {
+                        dir: TempDir::new().expect("tempdir"),
+                    };
+                    fixture.write("cgroup/cgroup.controllers", "cpuset cpu io memory pids\n");
+                    fixture.set_membership("4242", "/user.slice/app.scope");
+                    fixture.set_membership("self", OWN_CGROUP);
+                    fixture.write(TARGET_CPU_MAX, "max 100000\n");
+                    fixture
+                }
+
+                /// Enable the io controller with two devices that saw I/O,
+                /// one of them already write-limited below the throttle cap.
+                fn with_io(self) -> Self {
+                    self.write(
+                        TARGET_IO_MAX,
+                        "8:0 rbps=max wbps=524288 riops=max wiops=max\n",
+                    );
+                    self.write(
+                        "cgroup/user.slice/app.scope/io.stat",
+                        "8:0 rbytes=100 wbytes=200 rios=1 wios=2\n259:0 rbytes=5 wbytes=0 rios=1 wios=0\n",
+                    );
+                    self
+                }
+
+                fn write(&self, rel: &str, content: &str) {
+                    let path = self.dir.path().join(rel);
+                    fs::create_dir_all(path.parent().unwrap()).unwrap();
+                    fs::write(path, content).unwrap();
+                }
+
+                fn read(&self, rel: &str) -> String {
+                    fs::read_to_string(self.dir.path().join(rel)).unwrap()
+                }
+
+                fn set_membership(&self, entry: &str, cgroup: &str) {
+                    self.write(
+                        &format!("proc/{}/cgroup", entry),
+                        &format!("0::{}\n", cgroup),
+                    );
+                }
+
+                fn runner_with(&self, config: CpuThrottleConfig) -> CpuThrottleActionRunner {
+                    CpuThrottleActionRunner::new(config)
+                        .with_cgroup_root(self.dir.path().join("cgroup"))
+                        .with_proc_root(self.dir.path().join("proc"))
+                        .with_state_dir(self.dir.path().join("state"))
+                }
+
+                fn runner(&self) -> CpuThrottleActionRunner {
+                    self.runner_with(CpuThrottleConfig::default())
+                }
+            }
+
+            fn make_plan(pid: u32, action: Action) -> Plan {
+                let identity = ProcessIdentity {
+                    pid: ProcessId(pid),
+                    start_id: StartId(format!("boot:1:{}", pid)),
+                    uid: 1000,
+                    pgid: None,
+                    sid: None,
+                    quality: IdentityQuality::Full,
+                };
+                let decision = DecisionOutcome {
+                    expected_loss: vec![ExpectedLoss { action, loss: 1.0 }],
+                    optimal_action: action,
+                    sprt_boundary: None,
+                    posterior_odds_abandoned_vs_useful: None,
+                    recovery_expectations: None,
+                    rationale: DecisionRationale {
+                        chosen_action: action,
+                        tie_break: false,
+                        disabled_actions: vec![],
+                        used_recovery_preference: false,
+                        posterior: None,
+                        memory_mb: None,
+                        has_known_signature: None,
+                        category: None,
+                        protection: None,
+                    },
+                    risk_sensitive: None,
+                    dro: None,
+                };
+                let bundle = DecisionBundle {
+                    session_id: SessionId("pt-20260115-120000-abcd".to_string()),
+                    policy: Policy::default(),
+                    candidates: vec![DecisionCandidate {
+                        identity,
+                        ppid: None,
+                        decision,
+                        blocked_reasons: vec![],
+                        stage_pause_before_kill: false,
+                        process_state: None,
+                        parent_identity: None,
+                        d_state_diagnostics: None,
+                    }],
+                    generated_at: Some("2026-01-15T12:00:00Z".to_string()),
+                };
+                let mut plan = generate_plan(&bundle);
+                for plan_action in &mut plan.actions {
+                    plan_action.action = action;
+                }
+                plan
+            }
+
+            fn make_action(pid: u32, action: Action) -> PlanAction {
+                make_plan(pid, action).actions[0].clone()
+            }
+
+            #[test]
+            fn throttle_records_limits_and_unthrottle_restores() {
+                let fixture = Fixture::new();
+                let runner = fixture.runner();
+
+                let throttle = make_action(4242, Action::Throttle);
+                runner.execute(&throttle).expect("throttle");
+                runner.verify(&throttle).expect("verify");
+                assert_eq!(fixture.read(TARGET_CPU_MAX), "25000 100000");
+
+                let reversal = runner.reversal(4242).expect("limits recorded");
+                assert_eq!(reversal.cgroup_path, "/user.slice/app.scope");
+                assert_eq!(reversal.source, CpuLimitSource::CgroupV2CpuMax);
+                assert_eq!(reversal.previous_quota_us, None);
+
+                let undo = make_action(4242, Action::Unthrottle);
+                runner.execute(&undo).expect("unthrottle");
+                runner.verify(&undo).expect("verify restore");
+                assert_eq!(fixture.read(TARGET_CPU_MAX), "max 100000");
+                assert!(runner.reversal(4242).is_none());
+            }
+
+            #[test]
+            fn repeated_throttle_keeps_first_snapshot() {
+                let fixture = Fixture::new();
+                fixture.write(TARGET_CPU_MAX, "50000 100000\n");
+                let runner = fixture.runner();
+
+                runner
+                    .execute(&make_action(4242, Action::Throttle))
+                    .unwrap();
+                runner
+                    .execute(&make_action(4242, Action::Throttle))
+                    .unwrap();
+                assert_eq!(
+                    runner.reversal(4242).unwrap().previous_quota_us,
+                    Some(50_000)
+                );
+
+                runner.release(4242).unwrap();
+                assert_eq!(fixture.read(TARGET_CPU_MAX), "50000 100000");
+            }
+
+            #[test]
+            fn refuses_without_cgroup_v2() {
+                let fixture = Fixture::new();
+                fs::remove_file(fixture.dir.path().join("cgroup/cgroup.controllers")).unwrap();
+                let runner = fixture.runner_with(CpuThrottleConfig {
+                    fallback_to_v1: false,
+                    ..Default::default()
+                });
+                let err = runner
+                    .execute(&make_action(4242, Action::Throttle))
+                    .unwrap_err();
+                assert!(format!("{}", err).contains("cgroup v2 is not mounted"));
+                assert_eq!(fixture.read(TARGET_CPU_MAX), "max 100000\n");
+                assert!(runner.reversal(4242).is_none());
+            }
+
+            #[test]
+            fn refuses_pid_one_and_own_cgroup() {
+                let fixture = Fixture::new();
+                fixture.set_membership("1", "/init.scope");
+                let runner = fixture.runner();
+
+                let err = runner
+                    .execute(&make_action(1, Action::Throttle))
+                    .unwrap_err();
+                assert!(format!("{}", err).contains("refusing to throttle PID 1"));
+
+                fixture.set_membership("4242", OWN_CGROUP);
+                let err = runner
+                    .execute(&make_action(4242, Action::Throttle))
+                    .unwrap_err();
+                assert!(format!("{}", err).contains("session's own cgroup"));
+
+                // An ancestor of our cgroup would throttle this session too.
+                fixture.set_membership("4242", "/user.slice");
+                assert!(runner
+                    .execute(&make_action(4242, Action::Throttle))
+                    .is_err());
+                assert!(runner.reversal(4242).is_none());
+                assert_eq!(fixture.read(TARGET_CPU_MAX), "max 100000\n");
+            }
+
+            #[test]
+            fn unthrottle_without_record_fails() {
+                let fixture = Fixture::new();
+                let err = fixture
+                    .runner()
+                    .execute(&make_action(4242, Action::Unthrottle))
+                    .unwrap_err();
+                assert!(format!("{}", err).contains("no recorded CPU limits"));
+            }
+
+            #[test]
+            fn throttle_caps_io_and_unthrottle_restores_it() {
+                let fixture = Fixture::new().with_io();
+                let runner = fixture.runner();
+
+                runner
+                    .execute(&make_action(4242, Action::Throttle))
+                    .expect("throttle");
+                // The fake io.max keeps only the last write: the NVMe device,
+                // which had no limit. 8:0 keeps its tighter wbps.
+                assert_eq!(
+                    fixture.read(TARGET_IO_MAX),
+                    "259:0 rbps=1048576 wbps=1048576"
+                );
+                assert_eq!(
+                    runner.reversal(4242).unwrap().previous_io_max,
+                    vec![
+                        "8:0 rbps=max wbps=524288 riops=max wiops=max".to_string(),
+                        "259:0 rbps=max wbps=max riops=max wiops=max".to_string(),
+                    ]
+                );
+
+                runner
+                    .execute(&make_action(4242, Action::Unthrottle))
+                    .expect("unthrottle");
+                assert_eq!(
+                    fixture.read(TARGET_IO_MAX),
+                    "259:0 rbps=max wbps=max riops=max wiops=max"
+                );
+                assert_eq!(fixture.read(TARGET_CPU_MAX), "max 100000");
+            }
+
+            #[test]
+            fn io_cap_can_be_disabled() {
+                let fixture = Fixture::new().with_io();
+                let runner = fixture.runner_with(CpuThrottleConfig {
+                    io_bps_limit: None,
+                    ..Default::default()
+                });
+                runner
+                    .execute(&make_action(4242, Action::Throttle))
+                    .unwrap();
+                assert_eq!(
+                    fixture.read(TARGET_IO_MAX),
+                    "8:0 rbps=max wbps=524288 riops=max wiops=max\n"
+                );
+                assert!(runner.reversal(4242).unwrap().previous_io_max.is_empty());
+            }
+
+            #[test]
+            fn fresh_runner_restores_persisted_limits() {
+                let fixture = Fixture::new().with_io();
+                fixture.write(TARGET_CPU_MAX, "50000 100000\n");
+                fixture
+                    .runner()
+                    .execute(&make_action(4242, Action::Throttle))
+                    .expect("throttle");
+                assert_eq!(fixture.read(TARGET_CPU_MAX), "25000 100000");
+                assert!(fixture.dir.path().join("state/4242.json").is_file());
+
+                // A later invocation only has what was written to disk.
+                let runner = fixture.runner();
+                let undo = make_action(4242, Action::Unthrottle);
+                runner.execute(&undo).expect("unthrottle");
+                runner.verify(&undo).expect("verify restore");
+                assert_eq!(fixture.read(TARGET_CPU_MAX), "50000 100000");
+                assert_eq!(
+                    fixture.read(TARGET_IO_MAX),
+                    "259:0 rbps=max wbps=max riops=max wiops=max"
+                );
+                assert!(!fixture.dir.path().join("state/4242.json").exists());
+            }
+
+            #[test]
+            fn record_from_another_cgroup_is_replaced() {
+                let fixture = Fixture::new();
+                let stale = ThrottleReversalMetadata {
+                    pid: 4242,
+                    cgroup_path: "/user.slice/old.scope".to_string(),
+                    previous_quota_us: Some(10_000),
+                    previous_period_us: Some(100_000),
+                    source: CpuLimitSource::CgroupV2CpuMax,
+                    previous_io_max: vec![],
+                    applied_at: "2026-01-01T00:00:00Z".to_string(),
+                };
+                fixture.write("state/4242.json", &serde_json::to_string(&stale).unwrap());
+
+                let runner = fixture.runner();
+                runner
+                    .execute(&make_action(4242, Action::Throttle))
+                    .unwrap();
+                let reversal = runner.reversal(4242).unwrap();
+                assert_eq!(reversal.cgroup_path, "/user.slice/app.scope");
+                assert_eq!(reversal.previous_quota_us, None);
+            }
+
+            #[test]
+            fn executor_rollback_restores_throttle() {
+                let fixture = Fixture::new();
+                let runner = fixture.runner();
+                let plan = make_plan(4242, Action::Throttle);
+                let identity =
+                    StaticIdentityProvider::default().with_identity(plan.actions[0].target.clone());
+                let executor =
+                    ActionExecutor::new(&runner, &identity, fixture.dir.path().join("lock"));
+
+                let result = executor.execute_plan(&plan).expect("execute");
+                assert_eq!(result.outcomes[0].status, ActionStatus::Success);
+                assert_eq!(fixture.read(TARGET_CPU_MAX), "25000 100000");
+
+                let summary = executor.rollback(&[result]).expect("rollback");
+                assert_eq!(summary.actions_succeeded, 1);
+                assert_eq!(fixture.read(TARGET_CPU_MAX), "max 100000");
+                assert!(runner.reversal(4242).is_none());
+            }
+        }
     }
 }
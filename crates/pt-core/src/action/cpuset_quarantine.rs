@@ -496,7 +496,8 @@ impl ActionRunner for CpusetQuarantineActionRunner {
             | Action::Restart
             | Action::Freeze
             | Action::Unfreeze
-            | Action::Throttle => Err(ActionError::Failed(format!(
+            | Action::Throttle
+            | Action::Unthrottle => Err(ActionError::Failed(format!(
                 "{:?} is not a quarantine action",
                 action.action
             ))),
@@ -515,7 +516,8 @@ impl ActionRunner for CpusetQuarantineActionRunner {
             | Action::Restart
             | Action::Freeze
             | Action::Unfreeze
-            | Action::Throttle => Ok(()),
+            | Action::Throttle
+            | Action::Unthrottle => Ok(()),
         }
     }
 }
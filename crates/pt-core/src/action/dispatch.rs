@@ -1,11 +1,11 @@
 //! Composite action runner that dispatches to specialized runners.
 
-use super::executor::{ActionError, ActionRunner};
+use super::executor::{ActionError, ActionRunner, IdentityProvider};
 use crate::decision::Action;
 use crate::plan::PlanAction;
 
 use super::renice::ReniceActionRunner;
-use super::signal::SignalActionRunner;
+use super::signal::{SignalActionRunner, SignalConfig, SignalMappingError, SuspendMechanism};
 
 #[cfg(target_os = "linux")]
 use super::cgroup_throttle::CpuThrottleActionRunner;
@@ -30,8 +30,19 @@ pub struct CompositeActionRunner {
 impl CompositeActionRunner {
     /// Construct a runner using default configurations.
     pub fn with_defaults() -> Self {
+        Self::with_signal_runner(SignalActionRunner::with_defaults())
+    }
+
+    /// Construct a runner whose suspend actions follow `config.mapping`.
+    pub fn with_signal_config(config: SignalConfig) -> Result<Self, SignalMappingError> {
+        Ok(Self::with_signal_runner(SignalActionRunner::try_new(
+            config,
+        )?))
+    }
+
+    fn with_signal_runner(signal: SignalActionRunner) -> Self {
         Self {
-            signal: SignalActionRunner::with_defaults(),
+            signal,
             renice: ReniceActionRunner::with_defaults(),
             #[cfg(target_os = "linux")]
             freeze: FreezeActionRunner::with_defaults(),
@@ -41,6 +52,22 @@ impl CompositeActionRunner {
             quarantine: CpusetQuarantineActionRunner::with_defaults(),
         }
     }
+
+    /// Whether `action` is carried out by signal delivery.
+    ///
+    /// Freeze/unfreeze only use the cgroup freezer when the signal mapping
+    /// says so; otherwise (e.g. the SIGSTOP fallback off Linux) they are
+    /// signals like pause/resume.
+    fn uses_signal(&self, action: Action) -> bool {
+        match action {
+            Action::Pause | Action::Resume | Action::Kill => true,
+            Action::Freeze | Action::Unfreeze => matches!(
+                self.signal.mapping().freeze,
+                SuspendMechanism::Signal { .. }
+            ),
+            _ => false,
+        }
+    }
 }
 
 impl Default for CompositeActionRunner {
@@ -50,7 +77,22 @@ impl Default for CompositeActionRunner {
 }
 
 impl ActionRunner for CompositeActionRunner {
+    fn revalidate(
+        &self,
+        action: &PlanAction,
+        provider: &dyn IdentityProvider,
+    ) -> Result<bool, ActionError> {
+        if self.uses_signal(action.action) {
+            self.signal.revalidate(action, provider)
+        } else {
+            Ok(true)
+        }
+    }
+
     fn execute(&self, action: &PlanAction) -> Result<(), ActionError> {
+        if self.uses_signal(action.action) {
+            return self.signal.execute(action);
+        }
         match action.action {
             Action::Keep => Ok(()),
             Action::Pause | Action::Resume | Action::Kill => self.signal.execute(action),
@@ -58,7 +100,7 @@ impl ActionRunner for CompositeActionRunner {
             #[cfg(target_os = "linux")]
             Action::Freeze | Action::Unfreeze => self.freeze.execute(action),
             #[cfg(target_os = "linux")]
-            Action::Throttle => self.throttle.execute(action),
+            Action::Throttle | Action::Unthrottle => self.throttle.execute(action),
             #[cfg(target_os = "linux")]
             Action::Quarantine | Action::Unquarantine => self.quarantine.execute(action),
             Action::Restart => Err(ActionError::Failed(
@@ -68,6 +110,7 @@ impl ActionRunner for CompositeActionRunner {
             Action::Freeze
             | Action::Unfreeze
             | Action::Throttle
+            | Action::Unthrottle
             | Action::Quarantine
             | Action::Unquarantine => Err(ActionError::Failed(
                 "action not supported on this platform".to_string(),
@@ -76,6 +119,9 @@ impl ActionRunner for CompositeActionRunner {
     }
 
     fn verify(&self, action: &PlanAction) -> Result<(), ActionError> {
+        if self.uses_signal(action.action) {
+            return self.signal.verify(action);
+        }
         match action.action {
             Action::Keep => Ok(()),
             Action::Pause | Action::Resume | Action::Kill => self.signal.verify(action),
@@ -83,7 +129,7 @@ impl ActionRunner for CompositeActionRunner {
             #[cfg(target_os = "linux")]
             Action::Freeze | Action::Unfreeze => self.freeze.verify(action),
             #[cfg(target_os = "linux")]
-            Action::Throttle => self.throttle.verify(action),
+            Action::Throttle | Action::Unthrottle => self.throttle.verify(action),
             #[cfg(target_os = "linux")]
             Action::Quarantine | Action::Unquarantine => self.quarantine.verify(action),
             Action::Restart => Ok(()),
@@ -91,6 +137,7 @@ impl ActionRunner for CompositeActionRunner {
             Action::Freeze
             | Action::Unfreeze
             | Action::Throttle
+            | Action::Unthrottle
             | Action::Quarantine
             | Action::Unquarantine => Ok(()),
         }
@@ -132,6 +179,7 @@ mod tests {
                 memory_mb: None,
                 has_known_signature: None,
                 category: None,
+                protection: None,
             },
             risk_sensitive: None,
             dro: None,
@@ -200,14 +248,90 @@ mod tests {
 
     #[cfg(not(target_os = "linux"))]
     #[test]
-    fn composite_runner_freeze_not_supported_non_linux() {
+    fn composite_runner_throttle_not_supported_non_linux() {
         let runner = CompositeActionRunner::with_defaults();
         let mut action = make_action();
-        action.action = Action::Freeze;
+        action.action = Action::Throttle;
         let err = runner.execute(&action).expect_err("expected error");
         assert!(format!("{:?}", err).contains("not supported"));
     }
 
+    #[test]
+    fn freeze_routing_follows_signal_mapping() {
+        let default = CompositeActionRunner::with_defaults();
+        assert!(default.uses_signal(Action::Pause));
+        assert!(!default.uses_signal(Action::Renice));
+        // Linux freezes through the cgroup freezer; elsewhere SIGSTOP is used.
+        assert_eq!(
+            default.uses_signal(Action::Freeze),
+            !cfg!(target_os = "linux")
+        );
+
+        let stop_cont = CompositeActionRunner::with_signal_config(SignalConfig {
+            mapping: crate::action::signal::SignalMapping {
+                freeze: SuspendMechanism::STOP_CONT,
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .unwrap();
+        assert!(stop_cont.uses_signal(Action::Freeze));
+        assert!(stop_cont.uses_signal(Action::Unfreeze));
+
+        assert!(CompositeActionRunner::with_signal_config(SignalConfig {
+            mapping: crate::action::signal::SignalMapping {
+                pause: SuspendMechanism::CgroupFreezer,
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn zombie_target_is_already_dead_not_failed() {
+        use crate::action::executor::{ActionExecutor, ActionStatus, StaticIdentityProvider};
+        use crate::action::recovery::{FailureKind, RetryPolicy};
+
+        let mut action = make_action();
+        action.action = Action::Kill;
+        let provider = StaticIdentityProvider::default().with_zombie(action.target.clone());
+        let runner = CompositeActionRunner::with_defaults();
+        assert!(matches!(
+            runner.revalidate(&action, &provider),
+            Err(ActionError::AlreadyDead)
+        ));
+
+        // Batch delivery: counted as a success, recovery is terminal.
+        let dir = tempfile::tempdir().unwrap();
+        let executor = ActionExecutor::new(&runner, &provider, dir.path().join("lock"));
+        let result = executor.apply_batch(std::slice::from_ref(&action)).unwrap();
+        assert_eq!(result.outcomes[0].status, ActionStatus::AlreadyDead);
+        assert_eq!(result.summary.actions_succeeded, 1);
+        assert_eq!(result.summary.actions_failed, 0);
+        let recovery = result.outcomes[0]
+            .recovery(0, &RetryPolicy::default())
+            .unwrap();
+        assert_eq!(recovery.kind, FailureKind::Permanent);
+        assert!(recovery.retry_action.is_none());
+
+        // Staged executor: same no-op outcome, serialized as "already_dead".
+        let mut plan = generate_plan(&DecisionBundle {
+            session_id: SessionId("pt-20260115-120000-abcd".to_string()),
+            policy: Policy::default(),
+            candidates: vec![],
+            generated_at: None,
+        });
+        plan.actions = vec![action];
+        let result = executor.execute_plan(&plan).unwrap();
+        assert_eq!(result.outcomes[0].status, ActionStatus::AlreadyDead);
+        assert_eq!(result.summary.actions_succeeded, 1);
+        assert_eq!(result.summary.actions_failed, 0);
+        let json = serde_json::to_value(&result.outcomes[0]).unwrap();
+        assert_eq!(json["status"], "already_dead");
+    }
+
     #[test]
     fn composite_runner_debug_impl() {
         let runner = CompositeActionRunner::with_defaults();
@@ -0,0 +1,317 @@
+//! Dry-run action execution.
+//!
+//! [`DryRunActionRunner`] goes through the same staged protocol as the real
+//! signal runner — identity revalidation, pre-checks, execute, verify — but
+//! never calls `kill`. Each [`ActionResult`](super::executor::ActionResult)
+//! it produces is marked `dry_run` and carries a description of the signal
+//! that would have been sent, the identity check, and the expected outcome.
+//!
+//! Unlike [`NoopActionRunner`](super::executor::NoopActionRunner), identity
+//! is revalidated against the live [`IdentityProvider`], so a stale PID that
+//! the real runner would refuse is reported as an identity mismatch here too.
+//! The runner takes the same [`SignalConfig`] as the signal runner, so a
+//! remapped pause or freeze is described with the signals actually mapped.
+
+use super::executor::{ActionError, ActionRunner, ActionStatus, IdentityProvider};
+use super::signal::{SignalConfig, SuspendMechanism};
+use crate::decision::Action;
+use crate::plan::PlanAction;
+
+/// Action runner that records intended signals without sending them.
+#[derive(Debug, Default)]
+pub struct DryRunActionRunner {
+    config: SignalConfig,
+}
+
+impl DryRunActionRunner {
+    pub fn new(config: SignalConfig) -> Self {
+        Self { config }
+    }
+
+    /// Signal target as the real runner would resolve it.
+    fn target(&self, action: &PlanAction) -> String {
+        let pid = action.target.pid.0;
+        match action.target.pgid.filter(|pgid| *pgid > 0) {
+            Some(pgid) if self.config.use_process_groups => {
+                format!("process group {pgid} (pid {pid})")
+            }
+            _ => format!("pid {pid}"),
+        }
+    }
+
+    /// Signal sequence and expected outcome for an action.
+    fn intent(&self, action: &PlanAction) -> String {
+        let target = self.target(action);
+        match action.action {
+            Action::Pause | Action::Resume | Action::Freeze | Action::Unfreeze => {
+                self.suspend_intent(action.action, &target)
+            }
+            Action::Kill => format!(
+                "SIGTERM to {target}, SIGKILL after {}ms if still alive; expected: process exited",
+                self.config.term_grace_ms
+            ),
+            Action::Keep => format!("no signal to {target}; expected: process unchanged"),
+            Action::Renice => format!("setpriority on {target}; no signal"),
+            Action::Throttle | Action::Unthrottle => {
+                format!("cgroup CPU/IO limit change on {target}; no signal")
+            }
+            Action::Quarantine | Action::Unquarantine => {
+                format!("cgroup cpuset change on {target}; no signal")
+            }
+            Action::Restart => format!("supervisor restart of {target}; no signal"),
+        }
+    }
+
+    /// Intent for a suspend-style action, following the configured mapping.
+    fn suspend_intent(&self, action: Action, target: &str) -> String {
+        let suspend = matches!(action, Action::Pause | Action::Freeze);
+        let expected = if suspend {
+            "process stopped"
+        } else {
+            "process running"
+        };
+        match self.config.mapping.mechanism_for(action) {
+            Some(SuspendMechanism::Signal { stop, resume }) => {
+                let signal = signal_name(if suspend { stop } else { resume });
+                format!("{signal} to {target}; expected: {expected}")
+            }
+            Some(SuspendMechanism::CgroupFreezer) => {
+                format!("cgroup v2 freezer change on {target}; no signal")
+            }
+            None => format!("no signal to {target}; expected: process unchanged"),
+        }
+    }
+}
+
+/// Conventional name of a signal number, for dry-run descriptions.
+fn signal_name(signal: i32) -> String {
+    let name = match signal {
+        libc::SIGSTOP => "SIGSTOP",
+        libc::SIGCONT => "SIGCONT",
+        libc::SIGTSTP => "SIGTSTP",
+        libc::SIGTERM => "SIGTERM",
+        libc::SIGKILL => "SIGKILL",
+        libc::SIGINT => "SIGINT",
+        libc::SIGHUP => "SIGHUP",
+        libc::SIGQUIT => "SIGQUIT",
+        libc::SIGUSR1 => "SIGUSR1",
+        libc::SIGUSR2 => "SIGUSR2",
+        _ => return format!("signal {signal}"),
+    };
+    name.to_string()
+}
+
+impl ActionRunner for DryRunActionRunner {
+    /// Revalidate like the signal runner, including reporting a zombie target
+    /// as [`ActionError::AlreadyDead`], so a dry run predicts its outcome.
+    fn revalidate(
+        &self,
+        action: &PlanAction,
+        provider: &dyn IdentityProvider,
+    ) -> Result<bool, ActionError> {
+        if !provider.revalidate(&action.target)? {
+            return Ok(false);
+        }
+        if action.action != Action::Keep && provider.is_zombie(&action.target) {
+            return Err(ActionError::AlreadyDead);
+        }
+        Ok(true)
+    }
+
+    fn execute(&self, _action: &PlanAction) -> Result<(), ActionError> {
+        Ok(())
+    }
+
+    fn verify(&self, _action: &PlanAction) -> Result<(), ActionError> {
+        Ok(())
+    }
+
+    fn is_dry_run(&self) -> bool {
+        true
+    }
+
+    fn describe(&self, action: &PlanAction, status: &ActionStatus) -> Option<String> {
+        let intent = self.intent(action);
+        let start_id = &action.target.start_id.0;
+        let details = match status {
+            ActionStatus::Success => {
+                format!("dry run: would send {intent} (identity verified: {start_id})")
+            }
+            ActionStatus::Skipped => format!("dry run: action blocked by plan; planned {intent}"),
+            ActionStatus::IdentityMismatch => {
+                format!("dry run: identity mismatch for {start_id}; would not send {intent}")
+            }
+            ActionStatus::PreCheckBlocked { check, reason } => {
+                format!("dry run: pre-check {check:?} blocked ({reason}); would not send {intent}")
+            }
+            other => format!("dry run: {other:?}; would not send {intent}"),
+        };
+        Some(details)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::action::executor::{ActionExecutor, StaticIdentityProvider};
+    use crate::action::signal::SignalMapping;
+    use crate::plan::{ActionRationale, ActionTimeouts, GatesSummary, Plan, PreCheck};
+    use pt_common::{IdentityQuality, ProcessId, ProcessIdentity, StartId};
+    use tempfile::tempdir;
+
+    fn identity(pid: u32, start: &str) -> ProcessIdentity {
+        ProcessIdentity {
+            pid: ProcessId(pid),
+            start_id: StartId(start.to_string()),
+            uid: 1000,
+            pgid: Some(pid),
+            sid: None,
+            quality: IdentityQuality::Full,
+        }
+    }
+
+    fn plan_with(action: Action, target: ProcessIdentity) -> Plan {
+        Plan {
+            plan_id: "plan-dry".to_string(),
+            session_id: "pt-20260115-120000-abcd".to_string(),
+            generated_at: "2026-01-15T12:00:00Z".to_string(),
+            policy_id: None,
+            policy_version: "1.0.0".to_string(),
+            actions: vec![PlanAction {
+                action_id: "act-1".to_string(),
+                target,
+                action,
+                order: 0,
+                stage: 0,
+                timeouts: ActionTimeouts::default(),
+                pre_checks: vec![PreCheck::VerifyIdentity],
+                rationale: ActionRationale {
+                    expected_loss: None,
+                    expected_recovery: None,
+                    expected_recovery_stddev: None,
+                    posterior_odds_abandoned_vs_useful: None,
+                    sprt_boundary: None,
+                    posterior: None,
+                    memory_mb: None,
+                    has_known_signature: None,
+                    category: None,
+                },
+                on_success: vec![],
+                on_failure: vec![],
+                blocked: false,
+                routing: Default::default(),
+                confidence: Default::default(),
+                original_zombie_target: None,
+                d_state_diagnostics: None,
+            }],
+            pre_toggled: vec![],
+            gates_summary: GatesSummary {
+                total_candidates: 1,
+                blocked_candidates: 0,
+                pre_toggled_actions: 0,
+            },
+        }
+    }
+
+    #[test]
+    fn kill_produces_auditable_result() {
+        let target = identity(4242, "boot:1:4242");
+        let plan = plan_with(Action::Kill, target.clone());
+        let dir = tempdir().unwrap();
+        let runner = DryRunActionRunner::new(SignalConfig {
+            term_grace_ms: 3_000,
+            use_process_groups: true,
+            ..SignalConfig::default()
+        });
+        let provider = StaticIdentityProvider::default().with_identity(target);
+        let executor = ActionExecutor::new(&runner, &provider, dir.path().join("lock"));
+
+        let result = executor.execute_plan(&plan).unwrap();
+        assert!(result.dry_run);
+        assert_eq!(result.summary.actions_succeeded, 1);
+
+        let outcome = &result.outcomes[0];
+        assert!(outcome.dry_run);
+        assert_eq!(outcome.status, ActionStatus::Success);
+        let details = outcome.details.as_deref().unwrap();
+        assert!(details.contains("SIGTERM to process group 4242"));
+        assert!(details.contains("SIGKILL after 3000ms"));
+        assert!(details.contains("identity verified: boot:1:4242"));
+
+        let json = serde_json::to_value(&result).unwrap();
+        assert_eq!(json["dry_run"], true);
+        assert_eq!(json["outcomes"][0]["dry_run"], true);
+    }
+
+    #[test]
+    fn stale_pid_is_still_caught() {
+        let planned = identity(4242, "boot:1:4242");
+        let reused = identity(4242, "boot:1:9999");
+        let plan = plan_with(Action::Pause, planned);
+        let dir = tempdir().unwrap();
+        let runner = DryRunActionRunner::default();
+        let provider = StaticIdentityProvider::default().with_identity(reused);
+        let executor = ActionExecutor::new(&runner, &provider, dir.path().join("lock"));
+
+        let result = executor.execute_plan(&plan).unwrap();
+        let outcome = &result.outcomes[0];
+        assert_eq!(outcome.status, ActionStatus::IdentityMismatch);
+        assert!(outcome.dry_run);
+        let details = outcome.details.as_deref().unwrap();
+        assert!(details.contains("identity mismatch"));
+        assert!(details.contains("would not send SIGSTOP to pid 4242"));
+    }
+
+    #[test]
+    fn remapped_pause_reports_mapped_signals() {
+        let target = identity(4242, "boot:1:4242");
+        let runner = DryRunActionRunner::new(SignalConfig {
+            mapping: SignalMapping {
+                pause: SuspendMechanism::Signal {
+                    stop: libc::SIGTSTP,
+                    resume: libc::SIGCONT,
+                },
+                freeze: SuspendMechanism::CgroupFreezer,
+            },
+            ..SignalConfig::default()
+        });
+        let pause = &plan_with(Action::Pause, target.clone()).actions[0];
+        let resume = &plan_with(Action::Resume, target.clone()).actions[0];
+        let freeze = &plan_with(Action::Freeze, target).actions[0];
+
+        assert!(runner.intent(pause).starts_with("SIGTSTP to pid 4242"));
+        assert!(runner.intent(resume).starts_with("SIGCONT to pid 4242"));
+        assert!(runner
+            .intent(freeze)
+            .starts_with("cgroup v2 freezer change"));
+    }
+
+    #[test]
+    fn zombie_target_is_already_dead() {
+        let target = identity(4242, "boot:1:4242");
+        let plan = plan_with(Action::Kill, target.clone());
+        let dir = tempdir().unwrap();
+        let runner = DryRunActionRunner::default();
+        let provider = StaticIdentityProvider::default().with_zombie(target);
+        let executor = ActionExecutor::new(&runner, &provider, dir.path().join("lock"));
+
+        let result = executor.execute_plan(&plan).unwrap();
+        let outcome = &result.outcomes[0];
+        assert_eq!(outcome.status, ActionStatus::AlreadyDead);
+        assert!(outcome.dry_run);
+    }
+
+    #[test]
+    fn runner_revalidates_even_without_precheck() {
+        let planned = identity(7, "boot:1:7");
+        let mut plan = plan_with(Action::Kill, planned);
+        plan.actions[0].pre_checks.clear();
+        let dir = tempdir().unwrap();
+        let runner = DryRunActionRunner::default();
+        let provider = StaticIdentityProvider::default();
+        let executor = ActionExecutor::new(&runner, &provider, dir.path().join("lock"));
+
+        let result = executor.execute_plan(&plan).unwrap();
+        assert_eq!(result.outcomes[0].status, ActionStatus::IdentityMismatch);
+    }
+}
@@ -1,15 +1,20 @@
 //! Staged action execution protocol.
 
+use crate::action::audit::{ActionAuditRecord, AuditSink};
+use crate::action::governor::{GovernorBudget, SafetyGovernor};
 use crate::action::prechecks::PreCheckProvider;
+use crate::action::recovery::{plan_recovery, ActionFailure, RecoveryDecision, RetryPolicy};
+use crate::decision::Action;
 use crate::plan::{Plan, PlanAction, PreCheck};
 use pt_common::ProcessIdentity;
 use serde::Serialize;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fs::OpenOptions;
 use std::io::Write;
 use std::path::{Path, PathBuf};
 use std::time::Instant;
 use thiserror::Error;
+use tracing::warn;
 
 /// Errors during plan execution.
 #[derive(Debug, Error)]
@@ -31,8 +36,13 @@ pub enum ActionError {
     PermissionDenied,
     #[error("timeout")]
     Timeout,
+    #[error("process already dead (zombie awaiting reap)")]
+    AlreadyDead,
     #[error("action failed: {0}")]
     Failed(String),
+    /// A syscall failed with an errno not mapped to a more specific variant.
+    #[error("os error: {0}")]
+    Os(#[source] std::io::Error),
 }
 
 /// Status of a single action.
@@ -46,6 +56,9 @@ pub enum ActionStatus {
     Timeout,
     Failed,
     Skipped,
+    /// Target is a zombie: it has already exited, so the action was a
+    /// successful no-op.
+    AlreadyDead,
     /// Pre-check failed (protected, data-loss risk, etc.)
     PreCheckBlocked {
         check: PreCheck,
@@ -61,6 +74,25 @@ pub struct ActionResult {
     pub time_ms: u128,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub details: Option<String>,
+    /// True when produced by a dry-run runner; no signal was sent.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub dry_run: bool,
+    /// The plan action this result belongs to, kept for rollback.
+    #[serde(skip)]
+    pub applied_action: Option<PlanAction>,
+    /// Failure classification for [`plan_recovery`], set when the action errored.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub failure: Option<ActionFailure>,
+}
+
+impl ActionResult {
+    /// Recovery decision for a failed (or already-dead) result, or `None`
+    /// if it did not fail.
+    pub fn recovery(&self, attempt: u32, policy: &RetryPolicy) -> Option<RecoveryDecision> {
+        let failure = self.failure?;
+        let action = self.applied_action.as_ref()?.action;
+        Some(plan_recovery(action, failure, attempt, policy))
+    }
 }
 
 /// Summary of execution results.
@@ -69,6 +101,47 @@ pub struct ExecutionSummary {
     pub actions_attempted: usize,
     pub actions_succeeded: usize,
     pub actions_failed: usize,
+    /// Actions deliberately not attempted, with the reason.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub skipped: Vec<SkippedAction>,
+    /// True when [`ExecutionMode::StopOnFirstError`] stopped the batch early.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub aborted: bool,
+}
+
+/// How a batch reacts to a failed action.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecutionMode {
+    /// Keep going and report every failure (default).
+    #[default]
+    ContinueOnError,
+    /// Stop at the first failure; remaining targets are skipped as `"aborted"`.
+    StopOnFirstError,
+}
+
+impl ExecutionMode {
+    /// Whether a batch in this mode stops once an action ends with `status`.
+    ///
+    /// Successes, already-dead targets and plan-level skips never stop a batch;
+    /// every other status counts as a failure.
+    pub fn stops_after(self, status: &ActionStatus) -> bool {
+        self == ExecutionMode::StopOnFirstError
+            && !matches!(
+                status,
+                ActionStatus::Success | ActionStatus::AlreadyDead | ActionStatus::Skipped
+            )
+    }
+}
+
+/// Skip reason for targets left unattempted by [`ExecutionMode::StopOnFirstError`].
+pub const ABORTED_REASON: &str = "aborted";
+
+/// An action passed over during execution or rollback.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct SkippedAction {
+    pub action_id: String,
+    pub reason: String,
 }
 
 /// Full execution result with per-action outcomes.
@@ -76,6 +149,12 @@ pub struct ExecutionSummary {
 pub struct ExecutionResult {
     pub summary: ExecutionSummary,
     pub outcomes: Vec<ActionResult>,
+    /// True when the whole plan ran through a dry-run runner.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub dry_run: bool,
+    /// True when the [`SafetyGovernor`] downgraded at least one action.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub governor_tripped: bool,
 }
 
 /// Trait for executing actions (signals, cgroup ops, etc.).
@@ -91,6 +170,17 @@ pub trait ActionRunner {
     ) -> Result<bool, ActionError> {
         Ok(true)
     }
+
+    /// Whether this runner only simulates actions.
+    fn is_dry_run(&self) -> bool {
+        false
+    }
+
+    /// Describe what executing `action` did (or would have done), given the
+    /// status the executor settled on. Used to fill [`ActionResult::details`].
+    fn describe(&self, _action: &PlanAction, _status: &ActionStatus) -> Option<String> {
+        None
+    }
 }
 
 /// No-op action runner (used for tests and scaffolding).
@@ -107,15 +197,43 @@ impl ActionRunner for NoopActionRunner {
     }
 }
 
+/// What held a target's PID when it was observed after an action ran.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum ObservedTarget {
+    /// No process holds the PID any more.
+    Gone,
+    /// A process holds the PID. Its identity differs from the planned one
+    /// when the PID has been reused.
+    Present {
+        identity: ProcessIdentity,
+        /// Process state code, e.g. `'S'`, `'T'` or `'Z'`.
+        state: char,
+    },
+}
+
 /// Trait for revalidating identity before action.
 pub trait IdentityProvider {
     fn revalidate(&self, target: &ProcessIdentity) -> Result<bool, ActionError>;
+
+    /// Whether the target has exited but not yet been reaped (zombie state).
+    fn is_zombie(&self, _target: &ProcessIdentity) -> bool {
+        false
+    }
+
+    /// Observe whatever holds the target's PID now.
+    ///
+    /// `None` means this provider cannot observe live processes.
+    fn observe(&self, _target: &ProcessIdentity) -> Option<ObservedTarget> {
+        None
+    }
 }
 
 /// Static identity provider for tests.
 #[derive(Debug, Default)]
 pub struct StaticIdentityProvider {
     identities: HashMap<u32, ProcessIdentity>,
+    zombies: HashSet<u32>,
 }
 
 impl StaticIdentityProvider {
@@ -123,6 +241,12 @@ impl StaticIdentityProvider {
         self.identities.insert(identity.pid.0, identity);
         self
     }
+
+    /// Register `identity` as a zombie: it still revalidates, but has exited.
+    pub fn with_zombie(mut self, identity: ProcessIdentity) -> Self {
+        self.zombies.insert(identity.pid.0);
+        self.with_identity(identity)
+    }
 }
 
 impl IdentityProvider for StaticIdentityProvider {
@@ -132,6 +256,23 @@ impl IdentityProvider for StaticIdentityProvider {
             None => Ok(false),
         }
     }
+
+    fn is_zombie(&self, target: &ProcessIdentity) -> bool {
+        self.zombies.contains(&target.pid.0)
+    }
+
+    /// Registered identities are reported as sleeping (`'S'`), or as
+    /// zombies (`'Z'`) when registered with [`Self::with_zombie`].
+    fn observe(&self, target: &ProcessIdentity) -> Option<ObservedTarget> {
+        let observed = match self.identities.get(&target.pid.0) {
+            Some(identity) => ObservedTarget::Present {
+                identity: identity.clone(),
+                state: if self.is_zombie(target) { 'Z' } else { 'S' },
+            },
+            None => ObservedTarget::Gone,
+        };
+        Some(observed)
+    }
 }
 
 /// Action executor with staged protocol.
@@ -139,6 +280,9 @@ pub struct ActionExecutor<'a> {
     runner: &'a dyn ActionRunner,
     identity_provider: &'a dyn IdentityProvider,
     pre_check_provider: Option<&'a dyn PreCheckProvider>,
+    governor: Option<SafetyGovernor>,
+    audit_sink: Option<&'a dyn AuditSink>,
+    mode: ExecutionMode,
     lock_path: PathBuf,
 }
 
@@ -152,6 +296,9 @@ impl<'a> ActionExecutor<'a> {
             runner,
             identity_provider,
             pre_check_provider: None,
+            governor: None,
+            audit_sink: None,
+            mode: ExecutionMode::default(),
             lock_path: lock_path.into(),
         }
     }
@@ -162,60 +309,278 @@ impl<'a> ActionExecutor<'a> {
         self
     }
 
+    /// Cap destructive actions per run; excess Kill/Restart actions are
+    /// downgraded to Keep and recorded as `governor_tripped`.
+    pub fn with_governor(mut self, governor: SafetyGovernor) -> Self {
+        self.governor = Some(governor);
+        self
+    }
+
+    /// Record every action attempt, including dry runs, blocked and
+    /// governor-downgraded actions, to `sink`.
+    pub fn with_audit_sink(mut self, sink: &'a dyn AuditSink) -> Self {
+        self.audit_sink = Some(sink);
+        self
+    }
+
+    /// Choose how [`Self::execute_plan`] reacts to a failed action.
+    pub fn with_mode(mut self, mode: ExecutionMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    fn audit(&self, record: ActionAuditRecord) {
+        let Some(sink) = self.audit_sink else {
+            return;
+        };
+        if let Err(err) = sink.record(&record) {
+            warn!(action_id = %record.action_id, error = %err, "failed to record audit entry");
+        }
+    }
+
     pub fn execute_plan(&self, plan: &Plan) -> Result<ExecutionResult, ExecutionError> {
         let _lock = ActionLock::acquire(&self.lock_path)?;
+        let budget = self.governor.map(|g| g.plan_budget(plan));
+        Ok(self.run_actions(&plan.actions, budget))
+    }
 
+    /// Apply `actions` as one batch outside of a full plan.
+    ///
+    /// Goes through the same lock, identity revalidation, pre-checks,
+    /// governor and audit sink as [`Self::execute_plan`]; the governor's
+    /// fractional cap is measured against the batch size. Failures carry an
+    /// [`ActionFailure`] so callers can run [`plan_recovery`] on just those
+    /// targets (see [`ActionResult::recovery`]).
+    pub fn apply_batch(&self, actions: &[PlanAction]) -> Result<ExecutionResult, ExecutionError> {
+        let _lock = ActionLock::acquire(&self.lock_path)?;
+        let budget = self.governor.map(|g| g.budget(actions.len()));
+        Ok(self.run_actions(actions, budget))
+    }
+
+    fn run_actions(
+        &self,
+        actions: &[PlanAction],
+        mut budget: Option<GovernorBudget>,
+    ) -> ExecutionResult {
+        let dry_run = self.runner.is_dry_run();
         let mut outcomes = Vec::new();
         let mut succeeded = 0;
         let mut failed = 0;
+        let mut skipped = Vec::new();
+        let mut governor_tripped = false;
+        let mut aborted = false;
+
+        for action in actions {
+            if aborted {
+                skipped.push(SkippedAction {
+                    action_id: action.action_id.clone(),
+                    reason: ABORTED_REASON.to_string(),
+                });
+                self.audit(
+                    ActionAuditRecord::new(action, ActionStatus::Skipped)
+                        .with_reason(Some(ABORTED_REASON.to_string()))
+                        .with_dry_run(dry_run),
+                );
+                outcomes.push(ActionResult {
+                    action_id: action.action_id.clone(),
+                    status: ActionStatus::Skipped,
+                    time_ms: 0,
+                    details: Some(ABORTED_REASON.to_string()),
+                    dry_run,
+                    applied_action: Some(action.clone()),
+                    failure: None,
+                });
+                continue;
+            }
 
-        for action in &plan.actions {
             let start = Instant::now();
-            let result = self.execute_action(action);
+            let admitted = match budget.as_mut() {
+                Some(budget) if !action.blocked => budget.admit(action),
+                _ => Ok(()),
+            };
+            if let Err(reason) = admitted {
+                warn!(
+                    action_id = %action.action_id,
+                    pid = action.target.pid.0,
+                    "{}",
+                    reason
+                );
+                governor_tripped = true;
+                let mut kept = action.clone();
+                kept.action = Action::Keep;
+                skipped.push(SkippedAction {
+                    action_id: action.action_id.clone(),
+                    reason: reason.clone(),
+                });
+                self.audit(
+                    ActionAuditRecord::new(action, ActionStatus::Skipped)
+                        .with_applied_action(Action::Keep)
+                        .with_reason(Some(reason.clone()))
+                        .with_dry_run(dry_run),
+                );
+                outcomes.push(ActionResult {
+                    action_id: action.action_id.clone(),
+                    status: ActionStatus::Skipped,
+                    time_ms: start.elapsed().as_millis(),
+                    details: Some(reason),
+                    dry_run,
+                    applied_action: Some(kept),
+                    failure: None,
+                });
+                continue;
+            }
+
+            let attempt = self.execute_action(action);
             let time_ms = start.elapsed().as_millis();
-            match &result {
-                ActionStatus::Success => succeeded += 1,
-                ActionStatus::Skipped => {}
+            match &attempt.status {
+                ActionStatus::Success | ActionStatus::AlreadyDead => succeeded += 1,
+                ActionStatus::Skipped => skipped.push(SkippedAction {
+                    action_id: action.action_id.clone(),
+                    reason: "blocked by plan".to_string(),
+                }),
                 _ => failed += 1,
             }
-
+            aborted = self.mode.stops_after(&attempt.status);
+
+            let details = self
+                .runner
+                .describe(action, &attempt.status)
+                .or(attempt.error);
+            self.audit(
+                ActionAuditRecord::new(action, attempt.status.clone())
+                    .with_reason(details.clone())
+                    .with_dry_run(dry_run)
+                    .with_observed(self.identity_provider.observe(&action.target)),
+            );
             outcomes.push(ActionResult {
                 action_id: action.action_id.clone(),
-                status: result,
+                status: attempt.status,
                 time_ms,
-                details: None,
+                details,
+                dry_run,
+                applied_action: Some(action.clone()),
+                failure: attempt.failure,
             });
         }
 
-        Ok(ExecutionResult {
+        ExecutionResult {
             summary: ExecutionSummary {
-                actions_attempted: plan.actions.len(),
+                actions_attempted: actions.len(),
                 actions_succeeded: succeeded,
                 actions_failed: failed,
+                skipped,
+                aborted,
             },
             outcomes,
-        })
+            dry_run,
+            governor_tripped,
+        }
     }
 
-    fn execute_action(&self, action: &PlanAction) -> ActionStatus {
+    /// Undo successfully applied reversible actions, most recent first.
+    ///
+    /// Each applied Pause, Freeze, Throttle and Quarantine is followed by its
+    /// inverse (Resume, Unfreeze, Unthrottle, Unquarantine), which goes
+    /// through the same identity revalidation as any other action.
+    /// Irreversible actions (Kill, Restart), actions without an inverse, and
+    /// dry-run results are recorded in [`ExecutionSummary::skipped`] instead.
+    pub fn rollback(
+        &self,
+        results: &[ExecutionResult],
+    ) -> Result<ExecutionSummary, ExecutionError> {
+        let _lock = ActionLock::acquire(&self.lock_path)?;
+
+        let mut summary = ExecutionSummary {
+            actions_attempted: 0,
+            actions_succeeded: 0,
+            actions_failed: 0,
+            skipped: Vec::new(),
+            aborted: false,
+        };
+
+        let applied = results
+            .iter()
+            .rev()
+            .flat_map(|result| result.outcomes.iter().rev())
+            .filter(|outcome| outcome.status == ActionStatus::Success);
+
+        for outcome in applied {
+            let Some(original) = &outcome.applied_action else {
+                continue;
+            };
+            let skip = |reason: &str| SkippedAction {
+                action_id: outcome.action_id.clone(),
+                reason: reason.to_string(),
+            };
+            if outcome.dry_run {
+                summary.skipped.push(skip("dry run: nothing was applied"));
+                continue;
+            }
+            let inverse = match original.action {
+                Action::Keep => continue,
+                Action::Kill | Action::Restart => {
+                    summary
+                        .skipped
+                        .push(skip(&format!("{:?} is irreversible", original.action)));
+                    continue;
+                }
+                action => match inverse_action(action) {
+                    Some(inverse) => inverse,
+                    None => {
+                        summary
+                            .skipped
+                            .push(skip(&format!("{:?} has no inverse action", action)));
+                        continue;
+                    }
+                },
+            };
+
+            let mut undo = original.clone();
+            undo.action_id = format!("{}-rollback", original.action_id);
+            undo.action = inverse;
+            undo.blocked = false;
+            undo.on_success.clear();
+            undo.on_failure.clear();
+            if !undo.pre_checks.contains(&PreCheck::VerifyIdentity) {
+                undo.pre_checks.insert(0, PreCheck::VerifyIdentity);
+            }
+
+            summary.actions_attempted += 1;
+            let status = self.execute_action(&undo).status;
+            match status {
+                ActionStatus::Success | ActionStatus::AlreadyDead => summary.actions_succeeded += 1,
+                _ => summary.actions_failed += 1,
+            }
+            let details = self.runner.describe(&undo, &status);
+            self.audit(
+                ActionAuditRecord::new(&undo, status)
+                    .with_reason(details)
+                    .with_dry_run(self.runner.is_dry_run())
+                    .with_observed(self.identity_provider.observe(&undo.target)),
+            );
+        }
+
+        Ok(summary)
+    }
+
+    fn execute_action(&self, action: &PlanAction) -> ActionAttempt {
         if action.blocked {
-            return ActionStatus::Skipped;
+            return ActionAttempt::status(ActionStatus::Skipped);
         }
 
         // Run identity verification pre-check first
         if action.pre_checks.contains(&PreCheck::VerifyIdentity) {
             match self.identity_provider.revalidate(&action.target) {
                 Ok(true) => {}
-                Ok(false) => return ActionStatus::IdentityMismatch,
-                Err(_) => return ActionStatus::IdentityMismatch,
+                Ok(false) | Err(_) => return ActionAttempt::error(ActionError::IdentityMismatch),
             }
         }
 
         // Just-in-time revalidation by the runner itself
         match self.runner.revalidate(action, self.identity_provider) {
             Ok(true) => {}
-            Ok(false) => return ActionStatus::IdentityMismatch,
-            Err(e) => return status_from_error(e),
+            Ok(false) => return ActionAttempt::error(ActionError::IdentityMismatch),
+            Err(e) => return ActionAttempt::error(e),
         }
 
         // Run other pre-checks (protected, data-loss, supervisor, session safety)
@@ -228,20 +593,76 @@ impl<'a> ActionExecutor<'a> {
             for result in results {
                 if let crate::action::prechecks::PreCheckResult::Blocked { check, reason } = result
                 {
-                    return ActionStatus::PreCheckBlocked { check, reason };
+                    return ActionAttempt::status(ActionStatus::PreCheckBlocked { check, reason });
                 }
             }
         }
 
         if let Err(err) = self.runner.execute(action) {
-            return status_from_error(err);
+            return ActionAttempt::error(err);
         }
 
         if let Err(err) = self.runner.verify(action) {
-            return status_from_error(err);
+            return ActionAttempt::error(err);
         }
 
-        ActionStatus::Success
+        ActionAttempt::status(ActionStatus::Success)
+    }
+}
+
+/// Outcome of running one action through the staged protocol.
+struct ActionAttempt {
+    status: ActionStatus,
+    /// Classification for [`plan_recovery`] when the action errored.
+    failure: Option<ActionFailure>,
+    /// The error message, used when the runner has no description.
+    error: Option<String>,
+}
+
+impl ActionAttempt {
+    fn status(status: ActionStatus) -> Self {
+        Self {
+            status,
+            failure: None,
+            error: None,
+        }
+    }
+
+    fn error(err: ActionError) -> Self {
+        Self {
+            failure: failure_from_error(&err),
+            error: Some(err.to_string()),
+            status: status_from_error(err),
+        }
+    }
+}
+
+/// The action that undoes `action`, if there is one.
+fn inverse_action(action: Action) -> Option<Action> {
+    match action {
+        Action::Pause => Some(Action::Resume),
+        Action::Freeze => Some(Action::Unfreeze),
+        Action::Throttle => Some(Action::Unthrottle),
+        Action::Quarantine => Some(Action::Unquarantine),
+        _ => None,
+    }
+}
+
+fn failure_from_error(err: &ActionError) -> Option<ActionFailure> {
+    match err {
+        ActionError::IdentityMismatch => Some(ActionFailure::IdentityMismatch),
+        ActionError::PermissionDenied => Some(ActionFailure::PermissionDenied),
+        ActionError::Timeout => Some(ActionFailure::Timeout),
+        ActionError::AlreadyDead => Some(ActionFailure::AlreadyDead),
+        ActionError::Failed(_) => Some(ActionFailure::Failed),
+        ActionError::ProcessNotFound => Some(ActionFailure::ProcessGone),
+        ActionError::Os(err) => Some(ActionFailure::from_io_error(err)),
+    }
+}
+
+impl From<ActionError> for ActionStatus {
+    fn from(err: ActionError) -> Self {
+        status_from_error(err)
     }
 }
 
@@ -251,7 +672,14 @@ fn status_from_error(err: ActionError) -> ActionStatus {
         ActionError::ProcessNotFound => ActionStatus::ProcessNotFound,
         ActionError::PermissionDenied => ActionStatus::PermissionDenied,
         ActionError::Timeout => ActionStatus::Timeout,
+        ActionError::AlreadyDead => ActionStatus::AlreadyDead,
         ActionError::Failed(_) => ActionStatus::Failed,
+        ActionError::Os(err) => match ActionFailure::from_io_error(&err) {
+            ActionFailure::PermissionDenied => ActionStatus::PermissionDenied,
+            ActionFailure::ProcessGone => ActionStatus::ProcessNotFound,
+            ActionFailure::Timeout => ActionStatus::Timeout,
+            _ => ActionStatus::Failed,
+        },
     }
 }
 
@@ -351,6 +779,7 @@ mod tests {
                 memory_mb: None,
                 has_known_signature: None,
                 category: None,
+                protection: None,
             },
             risk_sensitive: None,
             dro: None,
@@ -489,6 +918,36 @@ mod tests {
         );
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn os_error_is_classified_by_errno() {
+        let busy = ActionError::Os(std::io::Error::from_raw_os_error(libc::EAGAIN));
+        assert_eq!(
+            failure_from_error(&busy),
+            Some(ActionFailure::TransientBusy)
+        );
+        assert_eq!(status_from_error(busy), ActionStatus::Failed);
+
+        let denied = ActionError::Os(std::io::Error::from_raw_os_error(libc::EACCES));
+        assert_eq!(
+            failure_from_error(&denied),
+            Some(ActionFailure::PermissionDenied)
+        );
+        assert_eq!(status_from_error(denied), ActionStatus::PermissionDenied);
+
+        let unsupported = ActionError::Os(std::io::Error::from_raw_os_error(libc::ENOSYS));
+        let decision = plan_recovery(
+            Action::Pause,
+            failure_from_error(&unsupported).unwrap(),
+            0,
+            &RetryPolicy::default(),
+        );
+        assert_eq!(
+            decision.kind,
+            crate::action::recovery::FailureKind::Permanent
+        );
+    }
+
     // ── ActionError display ─────────────────────────────────────────
 
     #[test]
@@ -617,11 +1076,15 @@ mod tests {
             status: ActionStatus::Success,
             time_ms: 42,
             details: None,
+            dry_run: false,
+            applied_action: None,
+            failure: None,
         };
         let json = serde_json::to_string(&r).unwrap();
         assert!(json.contains("act-1"));
         assert!(json.contains("success"));
         assert!(!json.contains("details")); // skip_serializing_if None
+        assert!(!json.contains("dry_run"));
     }
 
     #[test]
@@ -631,6 +1094,9 @@ mod tests {
             status: ActionStatus::Failed,
             time_ms: 100,
             details: Some("something went wrong".to_string()),
+            dry_run: false,
+            applied_action: None,
+            failure: None,
         };
         let json = serde_json::to_string(&r).unwrap();
         assert!(json.contains("something went wrong"));
@@ -645,8 +1111,12 @@ mod tests {
                 actions_attempted: 3,
                 actions_succeeded: 2,
                 actions_failed: 1,
+                skipped: vec![],
+                aborted: false,
             },
             outcomes: vec![],
+            dry_run: false,
+            governor_tripped: false,
         };
         let json = serde_json::to_string(&r).unwrap();
         assert!(json.contains("\"actions_attempted\":3"));
@@ -708,4 +1178,484 @@ mod tests {
         // time_ms should be a small non-negative number (noop is fast)
         assert!(result.outcomes[0].time_ms < 1000);
     }
+
+    // ── Rollback ────────────────────────────────────────────────────
+
+    /// Runner that records the actions it executes, in order.
+    #[derive(Default)]
+    struct RecordingRunner {
+        executed: std::cell::RefCell<Vec<(String, Action)>>,
+    }
+
+    impl ActionRunner for RecordingRunner {
+        fn execute(&self, action: &PlanAction) -> Result<(), ActionError> {
+            self.executed
+                .borrow_mut()
+                .push((action.action_id.clone(), action.action));
+            Ok(())
+        }
+
+        fn verify(&self, _action: &PlanAction) -> Result<(), ActionError> {
+            Ok(())
+        }
+    }
+
+    fn mixed_plan() -> Plan {
+        let mut plan = make_plan();
+        let template = plan.actions[0].clone();
+        plan.actions = [
+            ("a-pause", Action::Pause),
+            ("b-kill", Action::Kill),
+            ("c-freeze", Action::Freeze),
+            ("d-restart", Action::Restart),
+            ("e-quarantine", Action::Quarantine),
+        ]
+        .into_iter()
+        .map(|(id, action)| {
+            let mut a = template.clone();
+            a.action_id = id.to_string();
+            a.action = action;
+            a
+        })
+        .collect();
+        plan
+    }
+
+    #[test]
+    fn rollback_inverts_reversible_actions_in_reverse_order() {
+        let plan = mixed_plan();
+        let dir = tempdir().unwrap();
+        let runner = RecordingRunner::default();
+        let identity_provider =
+            StaticIdentityProvider::default().with_identity(plan.actions[0].target.clone());
+        let executor = ActionExecutor::new(&runner, &identity_provider, dir.path().join("lock"));
+
+        let result = executor.execute_plan(&plan).unwrap();
+        assert_eq!(result.summary.actions_succeeded, 5);
+        runner.executed.borrow_mut().clear();
+
+        let summary = executor.rollback(&[result]).unwrap();
+        assert_eq!(summary.actions_attempted, 3);
+        assert_eq!(summary.actions_succeeded, 3);
+        assert_eq!(summary.actions_failed, 0);
+
+        let executed = runner.executed.borrow().clone();
+        assert_eq!(
+            executed,
+            vec![
+                ("e-quarantine-rollback".to_string(), Action::Unquarantine),
+                ("c-freeze-rollback".to_string(), Action::Unfreeze),
+                ("a-pause-rollback".to_string(), Action::Resume),
+            ]
+        );
+
+        let skipped: Vec<&str> = summary
+            .skipped
+            .iter()
+            .map(|s| s.action_id.as_str())
+            .collect();
+        assert_eq!(skipped, vec!["d-restart", "b-kill"]);
+        assert!(summary.skipped[1].reason.contains("irreversible"));
+    }
+
+    #[test]
+    fn rollback_skips_failed_and_revalidates_identity() {
+        let plan = mixed_plan();
+        let dir = tempdir().unwrap();
+        let runner = RecordingRunner::default();
+        let target = plan.actions[0].target.clone();
+        let identity_provider = StaticIdentityProvider::default().with_identity(target.clone());
+        let executor = ActionExecutor::new(&runner, &identity_provider, dir.path().join("lock"));
+        let mut result = executor.execute_plan(&plan).unwrap();
+        // Pretend the freeze failed: it must not be undone.
+        result.outcomes[2].status = ActionStatus::Failed;
+
+        // PID reused since the batch ran: every inverse is refused.
+        let mut reused = target;
+        reused.start_id = StartId("boot:1:999".to_string());
+        let stale_provider = StaticIdentityProvider::default().with_identity(reused);
+        let stale_executor = ActionExecutor::new(&runner, &stale_provider, dir.path().join("lock"));
+        runner.executed.borrow_mut().clear();
+
+        let summary = stale_executor.rollback(&[result]).unwrap();
+        assert_eq!(summary.actions_attempted, 2);
+        assert_eq!(summary.actions_failed, 2);
+        assert!(runner.executed.borrow().is_empty());
+    }
+
+    // ── Safety governor ─────────────────────────────────────────────
+
+    fn kill_plan(kills: usize) -> Plan {
+        let mut plan = make_plan();
+        let template = plan.actions[0].clone();
+        plan.actions = (0..kills)
+            .map(|i| {
+                let mut a = template.clone();
+                a.action_id = format!("kill-{}", i);
+                a.action = Action::Kill;
+                a
+            })
+            .collect();
+        plan.gates_summary.total_candidates = kills;
+        plan
+    }
+
+    #[test]
+    fn governor_blocks_kill_beyond_cap() {
+        let plan = kill_plan(4);
+        let dir = tempdir().unwrap();
+        let runner = RecordingRunner::default();
+        let identity_provider =
+            StaticIdentityProvider::default().with_identity(plan.actions[0].target.clone());
+        let executor = ActionExecutor::new(&runner, &identity_provider, dir.path().join("lock"))
+            .with_governor(SafetyGovernor::new(3, None));
+
+        let result = executor.execute_plan(&plan).unwrap();
+        assert!(result.governor_tripped);
+        assert_eq!(result.summary.actions_succeeded, 3);
+        assert_eq!(result.summary.actions_failed, 0);
+        assert_eq!(runner.executed.borrow().len(), 3);
+
+        let blocked = &result.outcomes[3];
+        assert_eq!(blocked.status, ActionStatus::Skipped);
+        assert!(blocked
+            .details
+            .as_deref()
+            .unwrap()
+            .starts_with(crate::action::governor::GOVERNOR_TRIPPED));
+        assert_eq!(
+            blocked.applied_action.as_ref().unwrap().action,
+            Action::Keep
+        );
+        assert_eq!(result.summary.skipped.len(), 1);
+        assert_eq!(result.summary.skipped[0].action_id, "kill-3");
+        assert!(result.summary.skipped[0]
+            .reason
+            .starts_with("governor_tripped"));
+
+        let json = serde_json::to_string(&result).unwrap();
+        assert!(json.contains("\"governor_tripped\":true"));
+    }
+
+    #[derive(Default)]
+    struct MemoryAuditSink {
+        records: std::cell::RefCell<Vec<ActionAuditRecord>>,
+    }
+
+    impl AuditSink for MemoryAuditSink {
+        fn record(
+            &self,
+            record: &ActionAuditRecord,
+        ) -> Result<(), crate::action::audit::AuditError> {
+            self.records.borrow_mut().push(record.clone());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn governor_blocked_action_is_audited() {
+        let plan = kill_plan(3);
+        let dir = tempdir().unwrap();
+        let runner = RecordingRunner::default();
+        let identity_provider =
+            StaticIdentityProvider::default().with_identity(plan.actions[0].target.clone());
+        let sink = MemoryAuditSink::default();
+        let executor = ActionExecutor::new(&runner, &identity_provider, dir.path().join("lock"))
+            .with_governor(SafetyGovernor::new(2, None))
+            .with_audit_sink(&sink);
+
+        executor.execute_plan(&plan).unwrap();
+        let records = sink.records.borrow();
+        assert_eq!(records.len(), 3);
+        assert!(records[..2]
+            .iter()
+            .all(|r| r.status == ActionStatus::Success && r.applied_action == Action::Kill));
+
+        let blocked = &records[2];
+        assert_eq!(blocked.action_id, "kill-2");
+        assert_eq!(blocked.target, plan.actions[2].target);
+        assert_eq!(blocked.status, ActionStatus::Skipped);
+        assert_eq!(blocked.planned_action, Action::Kill);
+        assert_eq!(blocked.applied_action, Action::Keep);
+        assert!(blocked
+            .reason
+            .as_deref()
+            .unwrap()
+            .starts_with(crate::action::governor::GOVERNOR_TRIPPED));
+        assert_eq!(blocked.severity(), "warning");
+    }
+
+    #[test]
+    fn audit_records_observed_target_after_execution() {
+        let plan = kill_plan(2);
+        let dir = tempdir().unwrap();
+        let runner = RecordingRunner::default();
+        let identity_provider =
+            StaticIdentityProvider::default().with_zombie(plan.actions[0].target.clone());
+        let sink = MemoryAuditSink::default();
+        let executor = ActionExecutor::new(&runner, &identity_provider, dir.path().join("lock"))
+            .with_governor(SafetyGovernor::new(1, None))
+            .with_audit_sink(&sink);
+
+        executor.execute_plan(&plan).unwrap();
+        let records = sink.records.borrow();
+        assert_eq!(
+            records[0].observed,
+            Some(ObservedTarget::Present {
+                identity: plan.actions[0].target.clone(),
+                state: 'Z',
+            })
+        );
+        // The governor-blocked action never ran, so nothing was observed.
+        assert_eq!(records[1].observed, None);
+
+        let details = serde_json::to_value(&records[0]).unwrap();
+        assert_eq!(details["observed"]["status"], "present");
+        assert_eq!(details["observed"]["state"], "Z");
+    }
+
+    #[test]
+    fn governor_fraction_cap_ignores_reversible_actions() {
+        let plan = mixed_plan();
+        let dir = tempdir().unwrap();
+        let runner = RecordingRunner::default();
+        let identity_provider =
+            StaticIdentityProvider::default().with_identity(plan.actions[0].target.clone());
+        // 5 candidates at 20%: only the first destructive action may run.
+        let executor = ActionExecutor::new(&runner, &identity_provider, dir.path().join("lock"))
+            .with_governor(SafetyGovernor::new(10, Some(0.2)));
+
+        let result = executor.execute_plan(&plan).unwrap();
+        assert!(result.governor_tripped);
+        assert_eq!(result.summary.actions_succeeded, 4);
+        let skipped: Vec<&str> = result
+            .summary
+            .skipped
+            .iter()
+            .map(|s| s.action_id.as_str())
+            .collect();
+        assert_eq!(skipped, vec!["d-restart"]);
+
+        // The downgraded restart is never rolled back or reported irreversible.
+        let summary = executor.rollback(&[result]).unwrap();
+        assert!(summary.skipped.iter().all(|s| s.action_id != "d-restart"));
+    }
+
+    #[test]
+    fn without_governor_all_kills_run() {
+        let plan = kill_plan(4);
+        let dir = tempdir().unwrap();
+        let runner = RecordingRunner::default();
+        let identity_provider =
+            StaticIdentityProvider::default().with_identity(plan.actions[0].target.clone());
+        let executor = ActionExecutor::new(&runner, &identity_provider, dir.path().join("lock"));
+
+        let result = executor.execute_plan(&plan).unwrap();
+        assert!(!result.governor_tripped);
+        assert_eq!(result.summary.actions_succeeded, 4);
+    }
+
+    // ── Batch delivery ──────────────────────────────────────────────
+
+    /// Runner that fails one action and records the others.
+    struct FlakyRunner {
+        fail_id: &'static str,
+        executed: std::cell::RefCell<Vec<String>>,
+    }
+
+    impl ActionRunner for FlakyRunner {
+        fn execute(&self, action: &PlanAction) -> Result<(), ActionError> {
+            if action.action_id == self.fail_id {
+                return Err(ActionError::Failed("signal delivery failed".to_string()));
+            }
+            self.executed.borrow_mut().push(action.action_id.clone());
+            Ok(())
+        }
+
+        fn verify(&self, _action: &PlanAction) -> Result<(), ActionError> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn apply_batch_continues_past_failure() {
+        let template = make_plan().actions[0].clone();
+        let mut actions: Vec<PlanAction> = ["t1", "t2", "t3", "t4"]
+            .into_iter()
+            .map(|id| {
+                let mut a = template.clone();
+                a.action_id = id.to_string();
+                a.action = Action::Kill;
+                a
+            })
+            .collect();
+        actions[3].blocked = true;
+
+        let dir = tempdir().unwrap();
+        let runner = FlakyRunner {
+            fail_id: "t2",
+            executed: Default::default(),
+        };
+        let identity_provider = StaticIdentityProvider::default().with_identity(template.target);
+        let executor = ActionExecutor::new(&runner, &identity_provider, dir.path().join("lock"));
+        let result = executor.apply_batch(&actions).unwrap();
+
+        assert_eq!(*runner.executed.borrow(), vec!["t1", "t3"]);
+        assert_eq!(result.summary.actions_attempted, 4);
+        assert_eq!(result.summary.actions_succeeded, 2);
+        assert_eq!(result.summary.actions_failed, 1);
+        assert_eq!(result.summary.skipped.len(), 1);
+        assert_eq!(result.summary.skipped[0].action_id, "t4");
+        assert!(!result.summary.aborted);
+
+        let failed = &result.outcomes[1];
+        assert_eq!(failed.status, ActionStatus::Failed);
+        assert_eq!(failed.failure, Some(ActionFailure::Failed));
+        assert!(failed
+            .details
+            .as_deref()
+            .unwrap()
+            .contains("signal delivery"));
+
+        let recovery = failed.recovery(0, &RetryPolicy::default()).unwrap();
+        assert_eq!(
+            recovery.kind,
+            crate::action::recovery::FailureKind::Escalate
+        );
+        assert!(result.outcomes[0]
+            .recovery(0, &RetryPolicy::default())
+            .is_none());
+
+        let json = serde_json::to_value(&result).unwrap();
+        assert_eq!(json["outcomes"][1]["failure"], "failed");
+    }
+
+    #[test]
+    fn apply_batch_is_governed_and_audited() {
+        let actions = kill_plan(3).actions;
+        let dir = tempdir().unwrap();
+        let runner = RecordingRunner::default();
+        let identity_provider =
+            StaticIdentityProvider::default().with_identity(actions[0].target.clone());
+        let sink = MemoryAuditSink::default();
+        let executor = ActionExecutor::new(&runner, &identity_provider, dir.path().join("lock"))
+            .with_governor(SafetyGovernor::new(1, None))
+            .with_audit_sink(&sink);
+
+        let result = executor.apply_batch(&actions).unwrap();
+        assert!(result.governor_tripped);
+        assert_eq!(runner.executed.borrow().len(), 1);
+        assert_eq!(sink.records.borrow().len(), 3);
+    }
+
+    #[test]
+    fn apply_batch_stop_on_first_error_aborts_remaining() {
+        let template = make_plan().actions[0].clone();
+        let actions: Vec<PlanAction> = ["t1", "t2", "t3", "t4"]
+            .into_iter()
+            .map(|id| {
+                let mut a = template.clone();
+                a.action_id = id.to_string();
+                a.action = Action::Kill;
+                a
+            })
+            .collect();
+
+        let dir = tempdir().unwrap();
+        let runner = FlakyRunner {
+            fail_id: "t2",
+            executed: Default::default(),
+        };
+        let identity_provider = StaticIdentityProvider::default().with_identity(template.target);
+        let executor = ActionExecutor::new(&runner, &identity_provider, dir.path().join("lock"))
+            .with_mode(ExecutionMode::StopOnFirstError);
+        let result = executor.apply_batch(&actions).unwrap();
+
+        assert_eq!(*runner.executed.borrow(), vec!["t1"]);
+        assert!(result.summary.aborted);
+        assert_eq!(result.summary.actions_succeeded, 1);
+        assert_eq!(result.summary.actions_failed, 1);
+        assert_eq!(
+            result.summary.skipped,
+            vec![
+                SkippedAction {
+                    action_id: "t3".to_string(),
+                    reason: ABORTED_REASON.to_string(),
+                },
+                SkippedAction {
+                    action_id: "t4".to_string(),
+                    reason: ABORTED_REASON.to_string(),
+                },
+            ]
+        );
+
+        assert_eq!(result.outcomes.len(), 4);
+        assert_eq!(result.outcomes[1].status, ActionStatus::Failed);
+        assert_eq!(result.outcomes[3].status, ActionStatus::Skipped);
+        assert_eq!(result.outcomes[3].details.as_deref(), Some("aborted"));
+
+        let json = serde_json::to_value(&result).unwrap();
+        assert_eq!(json["summary"]["aborted"], true);
+    }
+
+    #[test]
+    fn execute_plan_stop_on_first_error_aborts_remaining() {
+        let plan = kill_plan(4);
+        let dir = tempdir().unwrap();
+        let runner = FlakyRunner {
+            fail_id: "kill-1",
+            executed: Default::default(),
+        };
+        let identity_provider =
+            StaticIdentityProvider::default().with_identity(plan.actions[0].target.clone());
+        let executor = ActionExecutor::new(&runner, &identity_provider, dir.path().join("lock"))
+            .with_mode(ExecutionMode::StopOnFirstError);
+
+        let result = executor.execute_plan(&plan).unwrap();
+        assert_eq!(*runner.executed.borrow(), vec!["kill-0"]);
+        assert!(result.summary.aborted);
+        assert_eq!(result.summary.actions_succeeded, 1);
+        assert_eq!(result.summary.actions_failed, 1);
+        let aborted: Vec<_> = result
+            .summary
+            .skipped
+            .iter()
+            .filter(|s| s.reason == ABORTED_REASON)
+            .map(|s| s.action_id.as_str())
+            .collect();
+        assert_eq!(aborted, vec!["kill-2", "kill-3"]);
+        assert_eq!(result.outcomes[3].status, ActionStatus::Skipped);
+    }
+
+    #[test]
+    fn execute_plan_continues_past_failure_by_default() {
+        let plan = kill_plan(4);
+        let dir = tempdir().unwrap();
+        let runner = FlakyRunner {
+            fail_id: "kill-1",
+            executed: Default::default(),
+        };
+        let identity_provider =
+            StaticIdentityProvider::default().with_identity(plan.actions[0].target.clone());
+        let executor = ActionExecutor::new(&runner, &identity_provider, dir.path().join("lock"));
+
+        let result = executor.execute_plan(&plan).unwrap();
+        assert_eq!(
+            *runner.executed.borrow(),
+            vec!["kill-0", "kill-2", "kill-3"]
+        );
+        assert!(!result.summary.aborted);
+        assert_eq!(result.summary.actions_failed, 1);
+    }
+
+    #[test]
+    fn stops_after_only_counts_failures_in_stop_mode() {
+        let stop = ExecutionMode::StopOnFirstError;
+        assert!(stop.stops_after(&ActionStatus::Failed));
+        assert!(stop.stops_after(&ActionStatus::IdentityMismatch));
+        assert!(!stop.stops_after(&ActionStatus::Success));
+        assert!(!stop.stops_after(&ActionStatus::AlreadyDead));
+        assert!(!stop.stops_after(&ActionStatus::Skipped));
+        assert!(!ExecutionMode::ContinueOnError.stops_after(&ActionStatus::Failed));
+    }
 }
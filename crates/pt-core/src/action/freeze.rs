@@ -195,6 +195,7 @@ impl ActionRunner for FreezeActionRunner {
             | Action::Resume
             | Action::Kill
             | Action::Throttle
+            | Action::Unthrottle
             | Action::Restart
             | Action::Renice
             | Action::Quarantine
@@ -214,6 +215,7 @@ impl ActionRunner for FreezeActionRunner {
             | Action::Resume
             | Action::Kill
             | Action::Throttle
+            | Action::Unthrottle
             | Action::Restart
             | Action::Renice
             | Action::Quarantine
@@ -0,0 +1,184 @@
+//! Per-run cap on destructive actions.
+//!
+//! The [`SafetyGovernor`] is the executor's last line of defence against a
+//! runaway plan: once a run has applied `max_destructive_actions` Kill or
+//! Restart actions, or (when configured) that many make up more than
+//! `max_fraction` of the plan's candidates, every further destructive
+//! action is downgraded to Keep and reported as `governor_tripped`.
+
+use crate::config::policy::Guardrails;
+use crate::decision::Action;
+use crate::plan::{Plan, PlanAction};
+use serde::{Deserialize, Serialize};
+
+/// Reason prefix recorded for actions the governor downgraded.
+pub const GOVERNOR_TRIPPED: &str = "governor_tripped";
+
+/// Default absolute cap on destructive actions per run.
+pub const DEFAULT_MAX_DESTRUCTIVE_ACTIONS: usize = 10;
+
+/// Limits on destructive (Kill, Restart) actions within one execution run.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SafetyGovernor {
+    /// Maximum number of destructive actions applied per run.
+    pub max_destructive_actions: usize,
+    /// Maximum destructive actions as a fraction of total candidates;
+    /// `None` applies only the absolute cap.
+    pub max_fraction: Option<f64>,
+}
+
+impl Default for SafetyGovernor {
+    fn default() -> Self {
+        Self {
+            max_destructive_actions: DEFAULT_MAX_DESTRUCTIVE_ACTIONS,
+            max_fraction: None,
+        }
+    }
+}
+
+impl SafetyGovernor {
+    pub fn new(max_destructive_actions: usize, max_fraction: Option<f64>) -> Self {
+        Self {
+            max_destructive_actions,
+            max_fraction,
+        }
+    }
+
+    /// Governor using the policy's `max_kills_per_run` as the absolute cap
+    /// and `max_kill_fraction`, when set, as the fractional one.
+    pub fn from_guardrails(guardrails: &Guardrails) -> Self {
+        let governor =
+            Self::default().with_max_destructive_actions(guardrails.max_kills_per_run as usize);
+        match guardrails.max_kill_fraction {
+            Some(fraction) => governor.with_max_fraction(fraction),
+            None => governor,
+        }
+    }
+
+    pub fn with_max_destructive_actions(mut self, max: usize) -> Self {
+        self.max_destructive_actions = max;
+        self
+    }
+
+    pub fn with_max_fraction(mut self, fraction: f64) -> Self {
+        self.max_fraction = Some(fraction);
+        self
+    }
+
+    /// Whether `action` counts against the governor's budget.
+    pub fn is_destructive(action: Action) -> bool {
+        matches!(action, Action::Kill | Action::Restart)
+    }
+
+    /// Destructive actions allowed for a run over `total_candidates`.
+    ///
+    /// A configured fractional cap is rounded down but never below one, so
+    /// a small plan can still act on a single candidate; a non-finite or
+    /// non-positive fraction disables destructive actions entirely.
+    pub fn limit(&self, total_candidates: usize) -> usize {
+        let fraction_cap = match self.max_fraction {
+            None => usize::MAX,
+            Some(fraction) if fraction.is_finite() && fraction > 0.0 => {
+                ((total_candidates as f64 * fraction.min(1.0)).floor() as usize).max(1)
+            }
+            Some(_) => 0,
+        };
+        self.max_destructive_actions.min(fraction_cap)
+    }
+
+    /// Start counting for one run over `total_candidates`.
+    pub fn budget(&self, total_candidates: usize) -> GovernorBudget {
+        GovernorBudget {
+            limit: self.limit(total_candidates),
+            total_candidates,
+            admitted: 0,
+        }
+    }
+
+    /// Budget for executing `plan`, counted against the candidates its gates
+    /// saw (or its actions when the plan carries no gate summary).
+    pub fn plan_budget(&self, plan: &Plan) -> GovernorBudget {
+        let total_candidates = match plan.gates_summary.total_candidates {
+            0 => plan.actions.len(),
+            n => n,
+        };
+        self.budget(total_candidates)
+    }
+}
+
+/// Running count of destructive actions admitted during one run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GovernorBudget {
+    limit: usize,
+    total_candidates: usize,
+    admitted: usize,
+}
+
+impl GovernorBudget {
+    /// Admit `action`, or return the `governor_tripped` reason if it would
+    /// exceed the cap. Non-destructive actions are always admitted.
+    pub fn admit(&mut self, action: &PlanAction) -> Result<(), String> {
+        if !SafetyGovernor::is_destructive(action.action) {
+            return Ok(());
+        }
+        if self.admitted >= self.limit {
+            return Err(format!(
+                "{}: {:?} downgraded to Keep after {} of {} allowed destructive actions ({} candidates)",
+                GOVERNOR_TRIPPED, action.action, self.admitted, self.limit, self.total_candidates
+            ));
+        }
+        self.admitted += 1;
+        Ok(())
+    }
+
+    /// Destructive actions admitted so far.
+    pub fn admitted(&self) -> usize {
+        self.admitted
+    }
+
+    /// Cap in effect for this run.
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn limit_takes_the_tighter_cap() {
+        let governor = SafetyGovernor::new(5, Some(0.5));
+        assert_eq!(governor.limit(100), 5);
+        assert_eq!(governor.limit(6), 3);
+        assert_eq!(governor.limit(1), 1);
+        assert_eq!(governor.with_max_fraction(0.0).limit(100), 0);
+        assert_eq!(governor.with_max_fraction(f64::NAN).limit(100), 0);
+        assert_eq!(governor.with_max_fraction(4.0).limit(3), 3);
+    }
+
+    #[test]
+    fn from_guardrails_uses_max_kills_per_run() {
+        let guardrails = Guardrails {
+            max_kills_per_run: 3,
+            ..crate::config::Policy::default().guardrails
+        };
+        let governor = SafetyGovernor::from_guardrails(&guardrails);
+        assert_eq!(governor.max_destructive_actions, 3);
+        assert_eq!(governor.max_fraction, None);
+        // Without a configured fraction only the absolute cap applies.
+        assert_eq!(governor.limit(3), 3);
+    }
+
+    #[test]
+    fn from_guardrails_reads_max_kill_fraction() {
+        let guardrails = Guardrails {
+            max_kills_per_run: 10,
+            max_kill_fraction: Some(0.2),
+            ..crate::config::Policy::default().guardrails
+        };
+        let governor = SafetyGovernor::from_guardrails(&guardrails);
+        assert_eq!(governor.max_fraction, Some(0.2));
+        assert_eq!(governor.limit(20), 4);
+    }
+}
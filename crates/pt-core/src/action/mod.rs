@@ -1,5 +1,6 @@
 //! Action execution system.
 
+pub mod audit;
 #[cfg(target_os = "linux")]
 pub mod cgroup_throttle;
 #[cfg(target_os = "linux")]
@@ -12,6 +13,8 @@ pub mod freeze;
 mod repro_cpuset;
 
 pub mod dispatch;
+pub mod dry_run;
+pub mod governor;
 pub mod prechecks;
 pub mod recovery;
 pub mod recovery_tree;
@@ -20,10 +23,15 @@ pub mod renice;
 pub mod signal;
 pub mod supervisor;
 
+pub use audit::{
+    ActionAuditRecord, AuditError, AuditSink, ParquetAuditSink, ACTION_ATTEMPT_EVENT,
+    EXECUTOR_ACTOR,
+};
 #[cfg(target_os = "linux")]
 pub use cgroup_throttle::{
     can_throttle_process, CpuThrottleActionRunner, CpuThrottleConfig, ThrottleResult,
-    ThrottleReversalMetadata, DEFAULT_PERIOD_US, DEFAULT_THROTTLE_FRACTION, MIN_QUOTA_US,
+    ThrottleReversalMetadata, DEFAULT_PERIOD_US, DEFAULT_THROTTLE_FRACTION,
+    DEFAULT_THROTTLE_IO_BPS, MIN_QUOTA_US,
 };
 #[cfg(target_os = "linux")]
 pub use cpuset_quarantine::{
@@ -31,12 +39,17 @@ pub use cpuset_quarantine::{
     QuarantineReversalMetadata, DEFAULT_QUARANTINE_CPUS, MIN_QUARANTINE_CPUS,
 };
 pub use dispatch::CompositeActionRunner;
+pub use dry_run::DryRunActionRunner;
 pub use executor::{
     ActionError, ActionExecutor, ActionResult, ActionRunner, ActionStatus, ExecutionError,
-    ExecutionResult, ExecutionSummary, IdentityProvider, NoopActionRunner, StaticIdentityProvider,
+    ExecutionMode, ExecutionResult, ExecutionSummary, IdentityProvider, NoopActionRunner,
+    ObservedTarget, SkippedAction, StaticIdentityProvider, ABORTED_REASON,
 };
 #[cfg(target_os = "linux")]
 pub use freeze::{is_freeze_available, FreezeActionRunner, FreezeConfig};
+pub use governor::{
+    GovernorBudget, SafetyGovernor, DEFAULT_MAX_DESTRUCTIVE_ACTIONS, GOVERNOR_TRIPPED,
+};
 pub use recovery::{plan_recovery, ActionFailure, FailureKind, RecoveryDecision, RetryPolicy};
 pub use renice::{
     ReniceActionRunner, ReniceConfig, ReniceResult, ReniceReversalMetadata, DEFAULT_NICE_VALUE,
@@ -45,7 +58,9 @@ pub use renice::{
 #[cfg(target_os = "linux")]
 pub use signal::LiveIdentityProvider;
 #[cfg(unix)]
-pub use signal::{SignalActionRunner, SignalConfig};
+pub use signal::{
+    SignalActionRunner, SignalConfig, SignalMapping, SignalMappingError, SuspendMechanism,
+};
 #[cfg(target_os = "linux")]
 pub use supervisor::plan_action_from_container_supervision;
 pub use supervisor::{
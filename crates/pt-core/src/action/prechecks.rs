@@ -11,7 +11,8 @@
 #[cfg(target_os = "linux")]
 use crate::collect::parse_io;
 use crate::collect::protected::ProtectedFilter;
-use crate::collect::systemd::{collect_systemd_unit, SystemdUnit, SystemdUnitType};
+use crate::collect::service_context::{PlatformServiceContext, ServiceContext};
+use crate::collect::systemd::{SystemdUnit, SystemdUnitType};
 use crate::collect::ProcessState;
 use crate::config::policy::{DataLossGates, Guardrails};
 use crate::plan::PreCheck;
@@ -308,6 +309,8 @@ pub struct LivePreCheckProvider {
     config: LivePreCheckConfig,
     /// Known supervisor comm names.
     known_supervisors: HashSet<String>,
+    /// Service-manager attribution (systemd on Linux, none elsewhere).
+    service_context: Box<dyn ServiceContext>,
 }
 
 #[cfg(any(target_os = "linux", target_os = "macos"))]
@@ -341,9 +344,16 @@ impl LivePreCheckProvider {
             protected_filter,
             config,
             known_supervisors,
+            service_context: Box::new(PlatformServiceContext::default()),
         })
     }
 
+    /// Use a custom service context for supervisor attribution.
+    pub fn with_service_context(mut self, service_context: Box<dyn ServiceContext>) -> Self {
+        self.service_context = service_context;
+        self
+    }
+
     /// Read process comm (basename) from the OS.
     fn read_comm(&self, pid: u32) -> Option<String> {
         #[cfg(target_os = "linux")]
@@ -440,6 +450,7 @@ impl LivePreCheckProvider {
             protected_filter,
             config: LivePreCheckConfig::default(),
             known_supervisors,
+            service_context: Box::new(PlatformServiceContext::default()),
         }
     }
 
@@ -757,27 +768,23 @@ impl LivePreCheckProvider {
             }
         }
 
-        // Try to get systemd unit info with full metadata (Linux only)
-        #[cfg(target_os = "linux")]
-        {
-            let cgroup_unit = self.extract_cgroup_unit(pid);
-            if let Some(unit) = collect_systemd_unit(pid, cgroup_unit.as_deref()) {
-                // Filter out slice-only units (e.g., user.slice) - these aren't real supervision
-                if unit.unit_type == SystemdUnitType::Slice {
-                    trace!(pid, unit_name = %unit.name, "ignoring slice-only unit");
-                    return None;
-                }
+        // Try to get service unit info with full metadata (systemd on Linux)
+        if let Some(unit) = self.service_context.lookup(pid).unit() {
+            // Filter out slice-only units (e.g., user.slice) - these aren't real supervision
+            if unit.unit_type == SystemdUnitType::Slice {
+                trace!(pid, unit_name = %unit.name, "ignoring slice-only unit");
+                return None;
+            }
 
-                debug!(
-                    pid,
-                    unit_name = %unit.name,
-                    unit_type = ?unit.unit_type,
-                    is_main = unit.is_main_process,
-                    "detected systemd unit"
-                );
+            debug!(
+                pid,
+                unit_name = %unit.name,
+                unit_type = ?unit.unit_type,
+                is_main = unit.is_main_process,
+                "detected systemd unit"
+            );
 
-                return Some(SupervisorInfo::from_systemd_unit(unit, pid));
-            }
+            return Some(SupervisorInfo::from_systemd_unit(unit, pid));
         }
 
         // Check for launchd (macOS only)
@@ -801,29 +808,6 @@ impl LivePreCheckProvider {
 
         None
     }
-
-    /// Extract the cgroup unit name (Linux only).
-    #[cfg(target_os = "linux")]
-    fn extract_cgroup_unit(&self, pid: u32) -> Option<String> {
-        let cgroup_path = format!("/proc/{pid}/cgroup");
-        let content_bytes = std::fs::read(&cgroup_path).ok()?;
-        let content = String::from_utf8_lossy(&content_bytes);
-
-        for line in content.lines() {
-            // Look for lines with .service or .scope (not .slice - those aren't real supervision)
-            if line.contains(".service") || line.contains(".scope") {
-                // Extract unit name from path like "0::/system.slice/nginx.service"
-                if let Some(start) = line.rfind('/') {
-                    let unit = &line[start + 1..];
-                    if !unit.is_empty() {
-                        return Some(unit.to_string());
-                    }
-                }
-            }
-        }
-
-        None
-    }
 }
 
 impl PreCheckProvider for LivePreCheckProvider {
@@ -1863,11 +1847,20 @@ mod tests {
         }
 
         #[test]
-        fn live_provider_extract_cgroup_unit() {
+        fn live_provider_service_lookup() {
             let provider = LivePreCheckProvider::with_defaults();
             let pid = std::process::id();
             // Just verify the function doesn't panic
-            let _ = provider.extract_cgroup_unit(pid);
+            let _ = provider.service_context.lookup(pid);
+        }
+
+        #[test]
+        fn live_provider_without_service_manager_skips_units() {
+            let provider = LivePreCheckProvider::with_defaults()
+                .with_service_context(Box::new(crate::collect::service_context::NoServiceContext));
+            // PID 1's unit (if any) must not be reported without a manager.
+            let info = provider.is_supervisor_managed(1);
+            assert!(info.is_none_or(|i| i.systemd_unit.is_none()));
         }
 
         #[test]
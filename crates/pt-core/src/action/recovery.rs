@@ -1,6 +1,7 @@
 //! Failure recovery and retry planning for action execution.
 
 use crate::decision::Action;
+use rand::Rng;
 use serde::Serialize;
 
 /// Failure classification for recovery decisions.
@@ -22,10 +23,19 @@ pub struct RecoveryDecision {
 }
 
 /// Retry policy for recovery planning.
+///
+/// Transient retries back off exponentially from `base_backoff_ms`, doubling
+/// per attempt up to `max_backoff_ms`. With `jitter` at zero the sequence is
+/// fully deterministic; otherwise each delay is shortened by a random amount
+/// of up to `jitter` times itself so concurrent retries spread out.
 #[derive(Debug, Clone)]
 pub struct RetryPolicy {
     pub max_retries: u32,
     pub base_backoff_ms: u64,
+    /// Upper bound on any single backoff delay.
+    pub max_backoff_ms: u64,
+    /// Fraction of each delay (0.0-1.0) that may be randomly removed.
+    pub jitter: f64,
     pub term_grace_ms: u64,
 }
 
@@ -34,18 +44,89 @@ impl Default for RetryPolicy {
         Self {
             max_retries: 2,
             base_backoff_ms: 250,
+            max_backoff_ms: 8_000,
+            jitter: 0.0,
             term_grace_ms: 5_000,
         }
     }
 }
 
+impl RetryPolicy {
+    /// Deterministic backoff before retry number `attempt` (0-based):
+    /// `base_backoff_ms * 2^attempt`, capped at `max_backoff_ms`.
+    pub fn backoff_ms(&self, attempt: u32) -> u64 {
+        let factor = 1_u64.checked_shl(attempt).unwrap_or(u64::MAX);
+        self.base_backoff_ms
+            .saturating_mul(factor)
+            .min(self.max_backoff_ms)
+    }
+
+    /// Backoff for `attempt` with jitter drawn from `rng`.
+    ///
+    /// The result lies in `[backoff * (1 - jitter), backoff]`; a zero or
+    /// invalid jitter returns [`Self::backoff_ms`] without touching `rng`.
+    pub fn jittered_backoff_ms<R: Rng + ?Sized>(&self, attempt: u32, rng: &mut R) -> u64 {
+        let delay = self.backoff_ms(attempt);
+        if !(self.jitter.is_finite() && self.jitter > 0.0) || delay == 0 {
+            return delay;
+        }
+        let spread = (delay as f64 * self.jitter.min(1.0)).floor() as u64;
+        delay - rng.random_range(0..=spread)
+    }
+
+    /// Backoff for `attempt`, jittered from the thread RNG when enabled.
+    pub fn next_delay_ms(&self, attempt: u32) -> u64 {
+        self.jittered_backoff_ms(attempt, &mut rand::rng())
+    }
+}
+
 /// Action failure status from executor.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum ActionFailure {
     IdentityMismatch,
     PermissionDenied,
     Timeout,
     Failed,
+    /// Target was a zombie; there is nothing left to act on.
+    AlreadyDead,
+    /// Target exited before the action reached it (`ESRCH`).
+    ProcessGone,
+    /// The kernel asked us to try again (`EAGAIN`, `EBUSY`, `EINTR`).
+    TransientBusy,
+    /// The operation is not available on this system (`ENOSYS`, `EOPNOTSUPP`).
+    Unsupported,
+}
+
+impl ActionFailure {
+    /// Classify an OS error, preferring its errno when one is attached.
+    pub fn from_io_error(err: &std::io::Error) -> Self {
+        #[cfg(unix)]
+        if let Some(errno) = err.raw_os_error() {
+            return Self::from_errno(errno);
+        }
+        match err.kind() {
+            std::io::ErrorKind::PermissionDenied => Self::PermissionDenied,
+            std::io::ErrorKind::NotFound => Self::ProcessGone,
+            std::io::ErrorKind::WouldBlock | std::io::ErrorKind::Interrupted => Self::TransientBusy,
+            std::io::ErrorKind::TimedOut => Self::Timeout,
+            std::io::ErrorKind::Unsupported => Self::Unsupported,
+            _ => Self::Failed,
+        }
+    }
+
+    /// Classify a raw errno from a failed syscall.
+    #[cfg(unix)]
+    pub fn from_errno(errno: i32) -> Self {
+        match errno {
+            libc::EPERM | libc::EACCES => Self::PermissionDenied,
+            libc::ESRCH => Self::ProcessGone,
+            libc::EAGAIN | libc::EBUSY | libc::EINTR => Self::TransientBusy,
+            libc::ETIMEDOUT => Self::Timeout,
+            libc::ENOSYS | libc::EOPNOTSUPP => Self::Unsupported,
+            _ => Self::Failed,
+        }
+    }
 }
 
 /// Determine recovery decision based on failure, action, and attempt.
@@ -56,13 +137,17 @@ pub fn plan_recovery(
     policy: &RetryPolicy,
 ) -> RecoveryDecision {
     match failure {
-        ActionFailure::IdentityMismatch | ActionFailure::PermissionDenied => RecoveryDecision {
+        ActionFailure::IdentityMismatch
+        | ActionFailure::PermissionDenied
+        | ActionFailure::AlreadyDead
+        | ActionFailure::ProcessGone
+        | ActionFailure::Unsupported => RecoveryDecision {
             kind: FailureKind::Permanent,
             retry_action: None,
             delay_ms: None,
             attempts_left: None,
         },
-        ActionFailure::Timeout => {
+        ActionFailure::Timeout | ActionFailure::TransientBusy => {
             if attempt >= policy.max_retries {
                 RecoveryDecision {
                     kind: FailureKind::Permanent,
@@ -71,11 +156,10 @@ pub fn plan_recovery(
                     attempts_left: Some(0),
                 }
             } else {
-                let delay = policy.base_backoff_ms.saturating_mul(2_u64.pow(attempt));
                 RecoveryDecision {
                     kind: FailureKind::Transient,
                     retry_action: Some(action),
-                    delay_ms: Some(delay),
+                    delay_ms: Some(policy.next_delay_ms(attempt)),
                     attempts_left: Some(policy.max_retries - attempt),
                 }
             }
@@ -99,7 +183,7 @@ pub fn plan_recovery(
                     _ => RecoveryDecision {
                         kind: FailureKind::Transient,
                         retry_action: Some(action),
-                        delay_ms: Some(policy.base_backoff_ms),
+                        delay_ms: Some(policy.next_delay_ms(attempt)),
                         attempts_left: Some(policy.max_retries.saturating_sub(attempt)),
                     },
                 }
@@ -120,6 +204,17 @@ mod tests {
         assert!(decision.retry_action.is_none());
     }
 
+    #[test]
+    fn already_dead_is_terminal() {
+        let policy = RetryPolicy::default();
+        for action in [Action::Kill, Action::Pause] {
+            let decision = plan_recovery(action, ActionFailure::AlreadyDead, 0, &policy);
+            assert_eq!(decision.kind, FailureKind::Permanent);
+            assert!(decision.retry_action.is_none());
+            assert!(decision.delay_ms.is_none());
+        }
+    }
+
     #[test]
     fn timeout_retries_with_backoff() {
         let policy = RetryPolicy::default();
@@ -129,6 +224,124 @@ mod tests {
         assert_eq!(decision.delay_ms, Some(policy.base_backoff_ms));
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn errno_maps_to_failure() {
+        let cases = [
+            (libc::EPERM, ActionFailure::PermissionDenied),
+            (libc::EACCES, ActionFailure::PermissionDenied),
+            (libc::ESRCH, ActionFailure::ProcessGone),
+            (libc::EAGAIN, ActionFailure::TransientBusy),
+            (libc::EBUSY, ActionFailure::TransientBusy),
+            (libc::EINTR, ActionFailure::TransientBusy),
+            (libc::ETIMEDOUT, ActionFailure::Timeout),
+            (libc::ENOSYS, ActionFailure::Unsupported),
+            (libc::EOPNOTSUPP, ActionFailure::Unsupported),
+            (libc::EIO, ActionFailure::Failed),
+        ];
+        for (errno, expected) in cases {
+            assert_eq!(ActionFailure::from_errno(errno), expected, "errno {errno}");
+            let err = std::io::Error::from_raw_os_error(errno);
+            assert_eq!(
+                ActionFailure::from_io_error(&err),
+                expected,
+                "errno {errno}"
+            );
+        }
+    }
+
+    #[test]
+    fn io_error_kind_maps_without_errno() {
+        use std::io::{Error, ErrorKind};
+        let cases = [
+            (ErrorKind::PermissionDenied, ActionFailure::PermissionDenied),
+            (ErrorKind::NotFound, ActionFailure::ProcessGone),
+            (ErrorKind::WouldBlock, ActionFailure::TransientBusy),
+            (ErrorKind::Unsupported, ActionFailure::Unsupported),
+            (ErrorKind::Other, ActionFailure::Failed),
+        ];
+        for (kind, expected) in cases {
+            assert_eq!(ActionFailure::from_io_error(&Error::from(kind)), expected);
+        }
+    }
+
+    #[test]
+    fn errno_classes_drive_recovery() {
+        let policy = RetryPolicy::default();
+        let busy = plan_recovery(Action::Kill, ActionFailure::TransientBusy, 0, &policy);
+        assert_eq!(busy.kind, FailureKind::Transient);
+        assert_eq!(busy.retry_action, Some(Action::Kill));
+        assert_eq!(busy.delay_ms, Some(policy.base_backoff_ms));
+        let exhausted = plan_recovery(
+            Action::Kill,
+            ActionFailure::TransientBusy,
+            policy.max_retries,
+            &policy,
+        );
+        assert_eq!(exhausted.kind, FailureKind::Permanent);
+
+        for failure in [ActionFailure::ProcessGone, ActionFailure::Unsupported] {
+            let decision = plan_recovery(Action::Kill, failure, 0, &policy);
+            assert_eq!(decision.kind, FailureKind::Permanent);
+            assert!(decision.retry_action.is_none());
+        }
+    }
+
+    #[test]
+    fn backoff_doubles_until_capped() {
+        let policy = RetryPolicy {
+            max_retries: 10,
+            base_backoff_ms: 100,
+            max_backoff_ms: 500,
+            ..Default::default()
+        };
+        let delays: Vec<u64> = (0..5).map(|attempt| policy.backoff_ms(attempt)).collect();
+        assert_eq!(delays, vec![100, 200, 400, 500, 500]);
+        assert_eq!(policy.backoff_ms(200), 500);
+
+        let decisions: Vec<Option<u64>> = (0..4)
+            .map(|attempt| {
+                plan_recovery(Action::Pause, ActionFailure::Timeout, attempt, &policy).delay_ms
+            })
+            .collect();
+        assert_eq!(decisions, vec![Some(100), Some(200), Some(400), Some(500)]);
+        let failed = plan_recovery(Action::Resume, ActionFailure::Failed, 2, &policy);
+        assert_eq!(failed.delay_ms, Some(400));
+    }
+
+    #[test]
+    fn jitter_stays_within_bounds() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let policy = RetryPolicy {
+            base_backoff_ms: 100,
+            max_backoff_ms: 1_000,
+            jitter: 0.25,
+            ..Default::default()
+        };
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut seen = std::collections::HashSet::new();
+        for attempt in 0..6 {
+            let base = policy.backoff_ms(attempt);
+            for _ in 0..50 {
+                let delay = policy.jittered_backoff_ms(attempt, &mut rng);
+                assert!(
+                    delay <= base && delay >= base - base / 4,
+                    "{delay} vs {base}"
+                );
+                seen.insert(delay);
+            }
+        }
+        assert!(seen.len() > 6, "jitter should vary delays");
+
+        let no_jitter = RetryPolicy {
+            jitter: f64::NAN,
+            ..policy
+        };
+        assert_eq!(no_jitter.jittered_backoff_ms(3, &mut rng), 800);
+    }
+
     #[test]
     fn kill_failure_escalates_with_grace() {
         let policy = RetryPolicy::default();
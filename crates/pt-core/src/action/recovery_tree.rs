@@ -1255,6 +1255,7 @@ impl<'a> RecoveryExecutor<'a> {
                 FailureCategory::PreCheckBlocked
             }
             crate::action::executor::ActionStatus::Success
+            | crate::action::executor::ActionStatus::AlreadyDead
             | crate::action::executor::ActionStatus::Skipped => {
                 FailureCategory::UnexpectedError // Should not be called for success/skip
             }
@@ -112,7 +112,7 @@ impl ReniceActionRunner {
             Some(libc::EPERM) => Err(ActionError::PermissionDenied),
             Some(libc::EINVAL) => Err(ActionError::Failed("invalid priority value".to_string())),
             Some(libc::EACCES) => Err(ActionError::PermissionDenied),
-            _ => Err(ActionError::Failed(err.to_string())),
+            _ => Err(ActionError::Os(err)),
         }
     }
 
@@ -280,6 +280,7 @@ impl ActionRunner for ReniceActionRunner {
             | Action::Resume
             | Action::Kill
             | Action::Throttle
+            | Action::Unthrottle
             | Action::Restart
             | Action::Freeze
             | Action::Unfreeze
@@ -299,6 +300,7 @@ impl ActionRunner for ReniceActionRunner {
             | Action::Resume
             | Action::Kill
             | Action::Throttle
+            | Action::Unthrottle
             | Action::Restart
             | Action::Freeze
             | Action::Unfreeze
@@ -5,12 +5,118 @@
 //! - Staged escalation (SIGTERM → SIGKILL)
 //! - Process group awareness
 //! - Outcome verification
+//! - Configurable pause/freeze mechanism per platform ([`SignalMapping`])
 
 use super::executor::{ActionError, ActionRunner};
 use crate::decision::Action;
 use crate::plan::PlanAction;
 use std::thread;
 use std::time::{Duration, Instant};
+use thiserror::Error;
+
+/// How a suspend-style action (pause or freeze) is carried out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SuspendMechanism {
+    /// Send `stop` to suspend and `resume` to continue.
+    Signal { stop: i32, resume: i32 },
+    /// Use the cgroup v2 freezer (Linux only).
+    CgroupFreezer,
+}
+
+impl SuspendMechanism {
+    /// SIGSTOP to suspend, SIGCONT to continue.
+    pub const STOP_CONT: Self = Self::Signal {
+        stop: libc::SIGSTOP,
+        resume: libc::SIGCONT,
+    };
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Signal { .. } => "signal",
+            Self::CgroupFreezer => "cgroup freezer",
+        }
+    }
+}
+
+/// Per-action mapping from abstract suspend actions to concrete mechanisms.
+///
+/// `pause`/`resume` and `freeze`/`unfreeze` each share a mechanism. The
+/// default uses SIGSTOP/SIGCONT for pause everywhere and the cgroup freezer
+/// for freeze on Linux; elsewhere freeze falls back to SIGSTOP/SIGCONT.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SignalMapping {
+    pub pause: SuspendMechanism,
+    pub freeze: SuspendMechanism,
+}
+
+impl Default for SignalMapping {
+    fn default() -> Self {
+        Self {
+            pause: SuspendMechanism::STOP_CONT,
+            freeze: if cfg!(target_os = "linux") {
+                SuspendMechanism::CgroupFreezer
+            } else {
+                SuspendMechanism::STOP_CONT
+            },
+        }
+    }
+}
+
+/// Errors from an invalid [`SignalMapping`].
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum SignalMappingError {
+    #[error("{action} cannot use the {mechanism} on {platform}; map it to a signal instead")]
+    UnsupportedMechanism {
+        action: &'static str,
+        mechanism: &'static str,
+        platform: &'static str,
+    },
+    #[error("{action} is mapped to invalid signal {signal}")]
+    InvalidSignal { action: &'static str, signal: i32 },
+    #[error("pause must be mapped to a signal; use freeze for the cgroup freezer")]
+    PauseRequiresSignal,
+}
+
+impl SignalMapping {
+    /// Reject mechanisms that cannot be executed on the current platform.
+    ///
+    /// Pause is always signal-based; only freeze may use the cgroup freezer,
+    /// and only on Linux.
+    pub fn validate(&self) -> Result<(), SignalMappingError> {
+        if self.pause == SuspendMechanism::CgroupFreezer {
+            return Err(SignalMappingError::PauseRequiresSignal);
+        }
+        for (action, mechanism) in [("pause", self.pause), ("freeze", self.freeze)] {
+            match mechanism {
+                SuspendMechanism::Signal { stop, resume } => {
+                    for signal in [stop, resume] {
+                        if signal <= 0 {
+                            return Err(SignalMappingError::InvalidSignal { action, signal });
+                        }
+                    }
+                }
+                SuspendMechanism::CgroupFreezer if !cfg!(target_os = "linux") => {
+                    return Err(SignalMappingError::UnsupportedMechanism {
+                        action,
+                        mechanism: mechanism.name(),
+                        platform: std::env::consts::OS,
+                    });
+                }
+                SuspendMechanism::CgroupFreezer => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Mechanism used for `action`, if it is a suspend-style action.
+    pub fn mechanism_for(&self, action: Action) -> Option<SuspendMechanism> {
+        match action {
+            Action::Pause | Action::Resume => Some(self.pause),
+            Action::Freeze | Action::Unfreeze => Some(self.freeze),
+            _ => None,
+        }
+    }
+}
 
 /// Signal action runner configuration.
 #[derive(Debug, Clone)]
@@ -23,6 +129,8 @@ pub struct SignalConfig {
     pub verify_timeout_ms: u64,
     /// Whether to send signals to process groups (negative PID).
     pub use_process_groups: bool,
+    /// Mechanism used for pause/resume and freeze/unfreeze.
+    pub mapping: SignalMapping,
 }
 
 impl Default for SignalConfig {
@@ -32,6 +140,7 @@ impl Default for SignalConfig {
             poll_interval_ms: 100,
             verify_timeout_ms: 10_000,
             use_process_groups: false,
+            mapping: SignalMapping::default(),
         }
     }
 }
@@ -43,14 +152,34 @@ pub struct SignalActionRunner {
 }
 
 impl SignalActionRunner {
+    /// Create a runner from a known-good configuration.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `config.mapping` fails [`SignalMapping::validate`]; use
+    /// [`SignalActionRunner::try_new`] for user-supplied mappings.
     pub fn new(config: SignalConfig) -> Self {
-        Self { config }
+        match Self::try_new(config) {
+            Ok(runner) => runner,
+            Err(err) => panic!("invalid signal mapping: {err}"),
+        }
     }
 
     pub fn with_defaults() -> Self {
         Self::new(SignalConfig::default())
     }
 
+    /// Create a runner after checking the signal mapping is usable here.
+    pub fn try_new(config: SignalConfig) -> Result<Self, SignalMappingError> {
+        config.mapping.validate()?;
+        Ok(Self { config })
+    }
+
+    /// Mechanism mapping this runner was configured with.
+    pub fn mapping(&self) -> &SignalMapping {
+        &self.config.mapping
+    }
+
     fn resolve_group_target(&self, pid: u32, pgid: Option<u32>) -> (u32, bool) {
         let pgid = pgid.filter(|pgid| *pgid > 0);
         let use_group = self.config.use_process_groups && pgid.is_some();
@@ -87,7 +216,7 @@ impl SignalActionRunner {
             Some(libc::ESRCH) => Err(ActionError::ProcessNotFound),
             Some(libc::EPERM) => Err(ActionError::PermissionDenied),
             Some(libc::EINVAL) => Err(ActionError::Failed("invalid signal".to_string())),
-            _ => Err(ActionError::Failed(err.to_string())),
+            _ => Err(ActionError::Os(err)),
         }
     }
 
@@ -183,16 +312,33 @@ impl SignalActionRunner {
         Err(ActionError::Timeout)
     }
 
-    /// Execute a pause action (SIGSTOP).
+    /// Execute a suspend (pause/freeze) or continue (resume/unfreeze) action
+    /// with the signal its mapping names.
     #[cfg(unix)]
-    fn execute_pause(&self, action: &PlanAction) -> Result<(), ActionError> {
+    fn execute_suspend(&self, action: &PlanAction, suspend: bool) -> Result<(), ActionError> {
+        let (stop, resume) = self.suspend_signals(action.action)?;
         let pid = action.target.pid.0;
         let (target, use_group) = self.resolve_group_target(pid, action.target.pgid);
 
-        self.send_signal(target, libc::SIGSTOP, use_group)?;
+        self.send_signal(target, if suspend { stop } else { resume }, use_group)?;
         Ok(())
     }
 
+    /// Stop/continue signals configured for a suspend-style action.
+    fn suspend_signals(&self, action: Action) -> Result<(i32, i32), ActionError> {
+        match self.config.mapping.mechanism_for(action) {
+            Some(SuspendMechanism::Signal { stop, resume }) => Ok((stop, resume)),
+            Some(SuspendMechanism::CgroupFreezer) => Err(ActionError::Failed(format!(
+                "{:?} is mapped to the cgroup v2 freezer, not a signal",
+                action
+            ))),
+            None => Err(ActionError::Failed(format!(
+                "{:?} is not a suspend action",
+                action
+            ))),
+        }
+    }
+
     /// Execute a kill action (SIGTERM → SIGKILL).
     #[cfg(unix)]
     fn execute_kill(&self, action: &PlanAction) -> Result<(), ActionError> {
@@ -267,16 +413,6 @@ impl SignalActionRunner {
         self.wait_for_state_change(pid, false, Some(false), timeout)
     }
 
-    /// Execute a resume action (SIGCONT) from PlanAction.
-    #[cfg(unix)]
-    fn execute_resume(&self, action: &PlanAction) -> Result<(), ActionError> {
-        let pid = action.target.pid.0;
-        let (target, use_group) = self.resolve_group_target(pid, action.target.pgid);
-
-        self.send_signal(target, libc::SIGCONT, use_group)?;
-        Ok(())
-    }
-
     /// Verify a resume action succeeded.
     #[cfg(unix)]
     fn verify_resume(&self, action: &PlanAction) -> Result<(), ActionError> {
@@ -289,21 +425,32 @@ impl SignalActionRunner {
 
 #[cfg(unix)]
 impl ActionRunner for SignalActionRunner {
+    /// Revalidate the target and short-circuit signals to zombies.
+    ///
+    /// A zombie has already exited, so signalling it does nothing; it is
+    /// reported as [`ActionError::AlreadyDead`], which the executor records
+    /// as a successful no-op rather than a failure.
     fn revalidate(
         &self,
         action: &PlanAction,
         provider: &dyn super::executor::IdentityProvider,
     ) -> Result<bool, ActionError> {
-        provider.revalidate(&action.target)
+        if !provider.revalidate(&action.target)? {
+            return Ok(false);
+        }
+        if action.action != Action::Keep && provider.is_zombie(&action.target) {
+            return Err(ActionError::AlreadyDead);
+        }
+        Ok(true)
     }
 
     fn execute(&self, action: &PlanAction) -> Result<(), ActionError> {
         match action.action {
-            Action::Pause => self.execute_pause(action),
-            Action::Resume => self.execute_resume(action),
+            Action::Pause => self.execute_suspend(action, true),
+            Action::Resume => self.execute_suspend(action, false),
             Action::Kill => self.execute_kill(action),
             Action::Keep => Ok(()),
-            Action::Throttle => {
+            Action::Throttle | Action::Unthrottle => {
                 // Throttle requires cgroup operations, not signals
                 Err(ActionError::Failed(
                     "throttle requires cgroup support".to_string(),
@@ -321,12 +468,15 @@ impl ActionRunner for SignalActionRunner {
                     "renice requires setpriority support".to_string(),
                 ))
             }
-            Action::Freeze | Action::Unfreeze => {
-                // Freeze/Unfreeze require cgroup v2 freezer operations
-                Err(ActionError::Failed(
+            Action::Freeze | Action::Unfreeze => match self.config.mapping.freeze {
+                SuspendMechanism::Signal { .. } => {
+                    self.execute_suspend(action, action.action == Action::Freeze)
+                }
+                // Freeze/Unfreeze via cgroup v2 freezer operations
+                SuspendMechanism::CgroupFreezer => Err(ActionError::Failed(
                     "freeze/unfreeze requires cgroup v2 freezer support".to_string(),
-                ))
-            }
+                )),
+            },
             Action::Quarantine | Action::Unquarantine => {
                 // Quarantine requires cgroup cpuset operations
                 Err(ActionError::Failed(
@@ -342,7 +492,14 @@ impl ActionRunner for SignalActionRunner {
             Action::Resume => self.verify_resume(action),
             Action::Kill => self.verify_kill(action),
             Action::Keep => Ok(()),
+            Action::Freeze if self.config.mapping.freeze != SuspendMechanism::CgroupFreezer => {
+                self.verify_pause(action)
+            }
+            Action::Unfreeze if self.config.mapping.freeze != SuspendMechanism::CgroupFreezer => {
+                self.verify_resume(action)
+            }
             Action::Throttle
+            | Action::Unthrottle
             | Action::Restart
             | Action::Renice
             | Action::Freeze
@@ -388,20 +545,6 @@ impl LiveIdentityProvider {
         Self { boot_id }
     }
 
-    /// Read start_id from /proc/[pid]/stat.
-    fn read_start_id(&self, pid: u32) -> Option<String> {
-        let stat_path = format!("/proc/{pid}/stat");
-        let content_bytes = std::fs::read(&stat_path).ok()?;
-        let content = String::from_utf8_lossy(&content_bytes);
-        let comm_end = content.rfind(')')?;
-        let after_comm = content.get(comm_end + 2..)?;
-        let fields: Vec<&str> = after_comm.split_whitespace().collect();
-        // Field 19 (0-indexed from after comm) is starttime
-        let starttime = fields.get(19)?.parse::<u64>().ok()?;
-
-        Some(format!("{}:{starttime}:{pid}", self.boot_id))
-    }
-
     /// Read uid from /proc/[pid]/status.
     fn read_uid(&self, pid: u32) -> Option<u32> {
         let status_path = format!("/proc/{pid}/status");
@@ -425,25 +568,29 @@ impl Default for LiveIdentityProvider {
     }
 }
 
+#[cfg(target_os = "linux")]
+impl pt_common::StartIdSource for LiveIdentityProvider {
+    /// Read start_id from /proc/[pid]/stat.
+    fn current_start_id(&self, pid: pt_common::ProcessId) -> Option<pt_common::StartId> {
+        let starttime = pt_common::ProcStat::read(pid)?.start_time;
+
+        Some(pt_common::StartId::from_linux(
+            self.boot_id,
+            starttime,
+            pid.0,
+        ))
+    }
+}
+
 #[cfg(target_os = "linux")]
 impl super::executor::IdentityProvider for LiveIdentityProvider {
     fn revalidate(&self, target: &pt_common::ProcessIdentity) -> Result<bool, ActionError> {
         let pid = target.pid.0;
 
-        // Check if process exists
-        let stat_path = format!("/proc/{pid}/stat");
-        if !std::path::Path::new(&stat_path).exists() {
-            return Ok(false); // Process gone
-        }
-
-        // Validate start_id
-        if let Some(current_start_id) = self.read_start_id(pid) {
-            // start_id format might differ; check starttime portion
-            if !ids_match(&target.start_id.0, &current_start_id) {
-                return Ok(false); // PID was reused
-            }
-        } else {
-            return Ok(false); // Can't read identity
+        // Gone or PID reused
+        let status = pt_common::verify_identity_with(self, target.pid, &target.start_id);
+        if status != pt_common::IdentityStatus::Same {
+            return Ok(false);
         }
 
         // Validate UID
@@ -457,41 +604,28 @@ impl super::executor::IdentityProvider for LiveIdentityProvider {
 
         Ok(true)
     }
-}
 
-/// Check if two start_ids match (handle format variations).
-fn ids_match(expected: &str, current: &str) -> bool {
-    // Direct match
-    if expected == current {
-        return true;
+    fn is_zombie(&self, target: &pt_common::ProcessIdentity) -> bool {
+        pt_common::ProcStat::read(target.pid).is_some_and(|stat| stat.state == 'Z')
     }
 
-    // Extract starttime portion and compare
-    // Format may be: "boot_id:starttime:pid" or just "starttime" or "boot:starttime:pid"
-    fn extract_starttime(id: &str) -> Option<&str> {
-        let parts: Vec<&str> = id.split(':').collect();
-        match parts.len() {
-            1 => Some(parts[0]),
-            3 => Some(parts[1]),
-            _ => None,
-        }
-    }
-
-    match (extract_starttime(expected), extract_starttime(current)) {
-        (Some(e), Some(c)) => {
-            // Try integer comparison with fuzzy window (to handle ps rounding)
-            if let (Ok(e_ticks), Ok(c_ticks)) = (e.parse::<u64>(), c.parse::<u64>()) {
-                // If diff is within 1 second (100Hz = 100 ticks), treat as match
-                // We use 150 to be safe against rounding + small drift
-                let diff = e_ticks.abs_diff(c_ticks);
-                if diff <= 150 {
-                    return true;
-                }
-            }
-            // Fallback to exact string match
-            e == c
-        }
-        _ => false,
+    fn observe(
+        &self,
+        target: &pt_common::ProcessIdentity,
+    ) -> Option<super::executor::ObservedTarget> {
+        let pid = target.pid.0;
+        let observed = match pt_common::ProcStat::read(target.pid).zip(self.read_uid(pid)) {
+            Some((stat, uid)) => super::executor::ObservedTarget::Present {
+                identity: pt_common::ProcessIdentity::new(
+                    pid,
+                    pt_common::StartId::from_linux(self.boot_id, stat.start_time, pid),
+                    uid,
+                ),
+                state: stat.state,
+            },
+            None => super::executor::ObservedTarget::Gone,
+        };
+        Some(observed)
     }
 }
 
@@ -542,31 +676,108 @@ mod tests {
         assert_eq!(config.poll_interval_ms, 100);
         assert_eq!(config.verify_timeout_ms, 10_000);
         assert!(!config.use_process_groups);
+        assert_eq!(config.mapping.pause, SuspendMechanism::STOP_CONT);
+        assert!(config.mapping.validate().is_ok());
     }
 
     #[test]
-    fn ids_match_direct() {
-        assert!(ids_match("abc:123:456", "abc:123:456"));
+    fn default_freeze_mapping_matches_platform() {
+        let mapping = SignalMapping::default();
+        if cfg!(target_os = "linux") {
+            assert_eq!(mapping.freeze, SuspendMechanism::CgroupFreezer);
+        } else {
+            assert_eq!(mapping.freeze, SuspendMechanism::STOP_CONT);
+        }
     }
 
     #[test]
-    fn ids_match_starttime_only() {
-        assert!(ids_match("abc:123:456", "def:123:789"));
+    fn mapping_rejects_unsupported_mechanism() {
+        let pause_freezer = SignalMapping {
+            pause: SuspendMechanism::CgroupFreezer,
+            freeze: SuspendMechanism::STOP_CONT,
+        };
+        assert_eq!(
+            pause_freezer.validate(),
+            Err(SignalMappingError::PauseRequiresSignal)
+        );
+        assert!(SignalActionRunner::try_new(SignalConfig {
+            mapping: pause_freezer,
+            ..Default::default()
+        })
+        .is_err());
+
+        let freeze_freezer = SignalMapping {
+            pause: SuspendMechanism::STOP_CONT,
+            freeze: SuspendMechanism::CgroupFreezer,
+        };
+        let result = freeze_freezer.validate();
+        if cfg!(target_os = "linux") {
+            assert!(result.is_ok());
+        } else {
+            let err = result.unwrap_err();
+            assert!(matches!(
+                err,
+                SignalMappingError::UnsupportedMechanism {
+                    action: "freeze",
+                    ..
+                }
+            ));
+            assert!(err.to_string().contains("map it to a signal"));
+        }
+
+        let bad_signal = SignalMapping {
+            pause: SuspendMechanism::Signal {
+                stop: 0,
+                resume: libc::SIGCONT,
+            },
+            ..Default::default()
+        };
+        assert_eq!(
+            bad_signal.validate(),
+            Err(SignalMappingError::InvalidSignal {
+                action: "pause",
+                signal: 0
+            })
+        );
     }
 
     #[test]
-    fn ids_match_different() {
-        assert!(!ids_match("abc:123:456", "abc:999:456"));
+    #[should_panic(expected = "invalid signal mapping")]
+    fn new_panics_on_invalid_mapping() {
+        SignalActionRunner::new(SignalConfig {
+            mapping: SignalMapping {
+                pause: SuspendMechanism::CgroupFreezer,
+                ..Default::default()
+            },
+            ..Default::default()
+        });
     }
 
     #[test]
-    fn ids_match_fuzzy() {
-        // Within 1 second (100 ticks)
-        assert!(ids_match("abc:10000:456", "abc:10050:456"));
-        // Within 1.5 seconds (150 ticks)
-        assert!(ids_match("abc:10000:456", "abc:10150:456"));
-        // Too far (200 ticks)
-        assert!(!ids_match("abc:10000:456", "abc:10200:456"));
+    fn runner_consults_mapping() {
+        // Built directly: the freezer mapping is only valid on Linux.
+        let runner = SignalActionRunner {
+            config: SignalConfig {
+                mapping: SignalMapping {
+                    pause: SuspendMechanism::Signal {
+                        stop: libc::SIGTSTP,
+                        resume: libc::SIGCONT,
+                    },
+                    freeze: SuspendMechanism::CgroupFreezer,
+                },
+                ..Default::default()
+            },
+        };
+        assert_eq!(
+            runner.suspend_signals(Action::Pause).unwrap(),
+            (libc::SIGTSTP, libc::SIGCONT)
+        );
+        assert_eq!(
+            runner.suspend_signals(Action::Resume).unwrap(),
+            (libc::SIGTSTP, libc::SIGCONT)
+        );
+        assert!(runner.suspend_signals(Action::Freeze).is_err());
+        assert!(runner.suspend_signals(Action::Kill).is_err());
     }
 
     #[cfg(unix)]
@@ -652,6 +863,7 @@ mod tests {
                 poll_interval_ms: 10,
                 verify_timeout_ms: 1_000,
                 use_process_groups: false,
+                ..Default::default()
             });
 
             // Kill it (SIGTERM)
@@ -668,6 +880,7 @@ mod tests {
     mod linux_tests {
         use super::*;
         use crate::action::executor::IdentityProvider;
+        use pt_common::StartIdSource;
 
         #[test]
         fn live_identity_provider_validates_self() {
@@ -675,12 +888,14 @@ mod tests {
             let pid = std::process::id();
 
             // Get our start_id
-            let start_id = provider.read_start_id(pid).expect("read start_id");
+            let start_id = provider
+                .current_start_id(pt_common::ProcessId(pid))
+                .expect("read start_id");
             let uid = provider.read_uid(pid).expect("read uid");
 
             let identity = pt_common::ProcessIdentity {
                 pid: pt_common::ProcessId(pid),
-                start_id: pt_common::StartId(start_id),
+                start_id,
                 uid,
                 pgid: None,
                 sid: None,
@@ -696,12 +911,14 @@ mod tests {
             let provider = LiveIdentityProvider::new();
             let pid = std::process::id();
 
-            let start_id = provider.read_start_id(pid).expect("read start_id");
+            let start_id = provider
+                .current_start_id(pt_common::ProcessId(pid))
+                .expect("read start_id");
             let uid = provider.read_uid(pid).expect("read uid");
 
             let identity = pt_common::ProcessIdentity {
                 pid: pt_common::ProcessId(pid),
-                start_id: pt_common::StartId(start_id),
+                start_id,
                 uid: uid + 1, // Wrong UID
                 pgid: None,
                 sid: None,
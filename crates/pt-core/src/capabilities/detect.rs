@@ -226,7 +226,7 @@ pub struct ToolCapabilities {
     pub ionice: ToolCapability,
 
     /// Additional tools indexed by name.
-    #[serde(default)]
+    #[serde(default, serialize_with = "pt_common::serialize_sorted")]
     pub additional: HashMap<String, ToolCapability>,
 }
 
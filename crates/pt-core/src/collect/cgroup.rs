@@ -3,16 +3,22 @@
 //! This module provides comprehensive cgroup information for process triage:
 //! - Cgroup v1 and v2 path parsing
 //! - Resource limit extraction (CPU quota, memory limits)
+//! - Resource usage stats (memory, CPU time, throttling, pids) for the
+//!   `CgroupInspect` probe
 //! - Hierarchical cgroup detection
 //!
 //! # Data Sources
 //! - `/proc/[pid]/cgroup` - cgroup membership
 //! - `/sys/fs/cgroup/...` - cgroup limits and stats (v2)
-//! - `/sys/fs/cgroup/<controller>/...` - cgroup limits (v1)
+//! - `/sys/fs/cgroup/<controller>/...` - cgroup limits and stats (v1)
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs;
+use std::path::Path;
+
+/// Mount point of the cgroup filesystem.
+pub const CGROUP_ROOT: &str = "/sys/fs/cgroup";
 
 /// Comprehensive cgroup information for a process.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -474,6 +480,228 @@ fn read_i64_file(path: &str) -> Option<i64> {
     content.trim().parse::<i64>().ok()
 }
 
+/// Resource usage read from a process's cgroup.
+///
+/// Missing or unreadable files leave the corresponding field unset and add
+/// a warning; collection never fails outright.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CgroupStats {
+    /// Layout the stats were read from (`V2`, `V1`, or `Hybrid` when both
+    /// contributed).
+    pub layout: CgroupVersion,
+
+    /// Current memory usage in bytes (`memory.current` / `memory.usage_in_bytes`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory_current_bytes: Option<u64>,
+
+    /// Hard memory limit in bytes (None = unlimited or unavailable).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory_max_bytes: Option<u64>,
+
+    /// CPU accounting from `cpu.stat` (and `cpuacct.usage` on v1).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cpu: Option<CgroupCpuStat>,
+
+    /// Number of tasks in the cgroup (`pids.current`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pids_current: Option<u64>,
+
+    /// Files that could not be read or parsed.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+/// CPU accounting for a cgroup, normalised to microseconds.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CgroupCpuStat {
+    /// Total CPU time consumed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usage_usec: Option<u64>,
+    /// User-mode CPU time (v2 only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_usec: Option<u64>,
+    /// Kernel-mode CPU time (v2 only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub system_usec: Option<u64>,
+    /// Enforcement periods elapsed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nr_periods: Option<u64>,
+    /// Periods in which the cgroup was throttled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nr_throttled: Option<u64>,
+    /// Total time spent throttled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub throttled_usec: Option<u64>,
+}
+
+/// Collect cgroup resource stats for a live process.
+///
+/// Returns None if `/proc/[pid]/cgroup` cannot be read.
+#[cfg(target_os = "linux")]
+pub fn collect_cgroup_stats(pid: u32) -> Option<CgroupStats> {
+    let cgroup_path = format!("/proc/{}/cgroup", pid);
+    let bytes = fs::read(&cgroup_path).ok()?;
+    let details =
+        collect_cgroup_from_content(&String::from_utf8_lossy(&bytes), &cgroup_path, None)?;
+    Some(collect_cgroup_stats_at(
+        Path::new(CGROUP_ROOT),
+        details.unified_path.as_deref(),
+        &details.v1_paths,
+    ))
+}
+
+/// Read cgroup resource stats beneath `root` for the given membership.
+///
+/// Each stat prefers its v1 controller hierarchy when the process is attached
+/// to one (legacy and hybrid layouts) and otherwise reads the unified path.
+pub fn collect_cgroup_stats_at(
+    root: &Path,
+    unified_path: Option<&str>,
+    v1_paths: &HashMap<String, String>,
+) -> CgroupStats {
+    let mut stats = CgroupStats::default();
+    let mut used_v1 = false;
+    let mut used_v2 = false;
+
+    let v1_dir = |controller: &str| {
+        v1_paths
+            .get(controller)
+            .map(|path| root.join(controller).join(path.trim_start_matches('/')))
+    };
+    let v2_dir = unified_path.map(|path| root.join(path.trim_start_matches('/')));
+
+    // Memory
+    if let Some(dir) = v1_dir("memory") {
+        used_v1 = true;
+        stats.memory_current_bytes = read_stat(
+            &dir.join("memory.usage_in_bytes"),
+            &mut stats.warnings,
+            parse_u64,
+        );
+        stats.memory_max_bytes = read_stat(
+            &dir.join("memory.limit_in_bytes"),
+            &mut stats.warnings,
+            parse_v1_limit,
+        )
+        .flatten();
+    } else if let Some(ref dir) = v2_dir {
+        used_v2 = true;
+        stats.memory_current_bytes =
+            read_stat(&dir.join("memory.current"), &mut stats.warnings, parse_u64);
+        stats.memory_max_bytes =
+            read_stat(&dir.join("memory.max"), &mut stats.warnings, parse_v2_limit).flatten();
+    }
+
+    // CPU
+    if let Some(dir) = v1_dir("cpu") {
+        used_v1 = true;
+        let mut cpu = read_stat(&dir.join("cpu.stat"), &mut stats.warnings, |c| {
+            Some(parse_cpu_stat(c))
+        })
+        .unwrap_or_default();
+        if let Some(acct) = v1_dir("cpuacct") {
+            cpu.usage_usec = read_stat(&acct.join("cpuacct.usage"), &mut stats.warnings, |c| {
+                parse_u64(c).map(|ns| ns / 1_000)
+            });
+        }
+        if cpu != CgroupCpuStat::default() {
+            stats.cpu = Some(cpu);
+        }
+    } else if let Some(ref dir) = v2_dir {
+        used_v2 = true;
+        stats.cpu = read_stat(&dir.join("cpu.stat"), &mut stats.warnings, |c| {
+            Some(parse_cpu_stat(c))
+        });
+    }
+
+    // Pids
+    if let Some(dir) = v1_dir("pids") {
+        used_v1 = true;
+        stats.pids_current = read_stat(&dir.join("pids.current"), &mut stats.warnings, parse_u64);
+    } else if let Some(ref dir) = v2_dir {
+        used_v2 = true;
+        stats.pids_current = read_stat(&dir.join("pids.current"), &mut stats.warnings, parse_u64);
+    }
+
+    stats.layout = match (used_v1, used_v2) {
+        (false, true) => CgroupVersion::V2,
+        (true, false) => CgroupVersion::V1,
+        (true, true) => CgroupVersion::Hybrid,
+        (false, false) => CgroupVersion::Unknown,
+    };
+    if stats.layout == CgroupVersion::Unknown {
+        stats
+            .warnings
+            .push("no cgroup membership to read stats from".to_string());
+    }
+
+    stats
+}
+
+/// Parse `cpu.stat` content (v2 keys, or v1 `throttled_time` in ns).
+pub fn parse_cpu_stat(content: &str) -> CgroupCpuStat {
+    let mut stat = CgroupCpuStat::default();
+    for line in content.lines() {
+        let mut parts = line.split_whitespace();
+        let (Some(key), Some(value)) = (parts.next(), parts.next()) else {
+            continue;
+        };
+        let Ok(value) = value.parse::<u64>() else {
+            continue;
+        };
+        match key {
+            "usage_usec" => stat.usage_usec = Some(value),
+            "user_usec" => stat.user_usec = Some(value),
+            "system_usec" => stat.system_usec = Some(value),
+            "nr_periods" => stat.nr_periods = Some(value),
+            "nr_throttled" => stat.nr_throttled = Some(value),
+            "throttled_usec" => stat.throttled_usec = Some(value),
+            "throttled_time" => stat.throttled_usec = Some(value / 1_000),
+            _ => {}
+        }
+    }
+    stat
+}
+
+/// Read and parse one stat file, recording a warning on failure.
+fn read_stat<T>(
+    path: &Path,
+    warnings: &mut Vec<String>,
+    parse: impl FnOnce(&str) -> Option<T>,
+) -> Option<T> {
+    let bytes = match fs::read(path) {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            warnings.push(format!("{}: {}", path.display(), e));
+            return None;
+        }
+    };
+    let parsed = parse(&String::from_utf8_lossy(&bytes));
+    if parsed.is_none() {
+        warnings.push(format!("{}: unparseable content", path.display()));
+    }
+    parsed
+}
+
+fn parse_u64(content: &str) -> Option<u64> {
+    content.trim().parse().ok()
+}
+
+/// Parse a v2 limit ("max" = unlimited).
+fn parse_v2_limit(content: &str) -> Option<Option<u64>> {
+    match content.trim() {
+        "max" => Some(None),
+        value => value.parse().ok().map(Some),
+    }
+}
+
+/// Parse a v1 limit (values near PAGE_COUNTER_MAX = unlimited).
+fn parse_v1_limit(content: &str) -> Option<Option<u64>> {
+    const V1_UNLIMITED_THRESHOLD: u64 = 0x7FFFFFFFFFFFF000;
+    let value = parse_u64(content)?;
+    Some((value < V1_UNLIMITED_THRESHOLD).then_some(value))
+}
+
 /// Compute effective core count from CPU quota.
 ///
 /// Returns None if no quota is set (unlimited).
@@ -607,6 +835,147 @@ mod tests {
         assert!(details.systemd_unit.is_some());
     }
 
+    // ── CgroupStats ─────────────────────────────────────────────
+
+    /// `cpu.stat` captured from a throttled v2 container.
+    const CPU_STAT_V2: &str = "usage_usec 8419326\nuser_usec 6123880\nsystem_usec 2295446\n\
+nr_periods 4021\nnr_throttled 117\nthrottled_usec 912345\n\
+nr_bursts 0\nburst_usec 0\n";
+
+    /// `cpu.stat` captured from a v1 cpu controller.
+    const CPU_STAT_V1: &str = "nr_periods 880\nnr_throttled 12\nthrottled_time 45678901\n";
+
+    fn write(dir: &Path, name: &str, content: &str) {
+        fs::create_dir_all(dir).unwrap();
+        fs::write(dir.join(name), content).unwrap();
+    }
+
+    #[test]
+    fn test_parse_cpu_stat_v2() {
+        let stat = parse_cpu_stat(CPU_STAT_V2);
+        assert_eq!(stat.usage_usec, Some(8_419_326));
+        assert_eq!(stat.user_usec, Some(6_123_880));
+        assert_eq!(stat.system_usec, Some(2_295_446));
+        assert_eq!(stat.nr_periods, Some(4021));
+        assert_eq!(stat.nr_throttled, Some(117));
+        assert_eq!(stat.throttled_usec, Some(912_345));
+    }
+
+    #[test]
+    fn test_parse_cpu_stat_v1_converts_ns() {
+        let stat = parse_cpu_stat(CPU_STAT_V1);
+        assert_eq!(stat.nr_periods, Some(880));
+        assert_eq!(stat.throttled_usec, Some(45_678));
+        assert_eq!(stat.usage_usec, None);
+    }
+
+    #[test]
+    fn test_parse_cpu_stat_skips_garbage() {
+        let stat = parse_cpu_stat("usage_usec abc\nbogus\nnr_periods 3\n");
+        assert_eq!(stat.usage_usec, None);
+        assert_eq!(stat.nr_periods, Some(3));
+    }
+
+    #[test]
+    fn test_parse_limits() {
+        assert_eq!(parse_v2_limit("max\n"), Some(None));
+        assert_eq!(parse_v2_limit("536870912\n"), Some(Some(536_870_912)));
+        assert_eq!(parse_v2_limit("lots"), None);
+        assert_eq!(parse_v1_limit("9223372036854771712\n"), Some(None));
+        assert_eq!(parse_v1_limit("1073741824\n"), Some(Some(1_073_741_824)));
+    }
+
+    #[test]
+    fn test_cgroup_stats_unified() {
+        let root = tempfile::tempdir().unwrap();
+        let dir = root.path().join("system.slice/app.service");
+        write(&dir, "memory.current", "104857600\n");
+        write(&dir, "memory.max", "max\n");
+        write(&dir, "cpu.stat", CPU_STAT_V2);
+        write(&dir, "pids.current", "7\n");
+
+        let stats = collect_cgroup_stats_at(
+            root.path(),
+            Some("/system.slice/app.service"),
+            &HashMap::new(),
+        );
+        assert_eq!(stats.layout, CgroupVersion::V2);
+        assert_eq!(stats.memory_current_bytes, Some(104_857_600));
+        assert_eq!(stats.memory_max_bytes, None);
+        assert_eq!(stats.cpu.unwrap().nr_throttled, Some(117));
+        assert_eq!(stats.pids_current, Some(7));
+        assert!(stats.warnings.is_empty(), "{:?}", stats.warnings);
+    }
+
+    #[test]
+    fn test_cgroup_stats_legacy() {
+        let root = tempfile::tempdir().unwrap();
+        let path = "/user.slice/user-1000.slice";
+        write(
+            &root.path().join("memory/user.slice/user-1000.slice"),
+            "memory.usage_in_bytes",
+            "2048\n",
+        );
+        write(
+            &root.path().join("memory/user.slice/user-1000.slice"),
+            "memory.limit_in_bytes",
+            "4096\n",
+        );
+        write(
+            &root.path().join("cpu/user.slice/user-1000.slice"),
+            "cpu.stat",
+            CPU_STAT_V1,
+        );
+        write(
+            &root.path().join("cpuacct/user.slice/user-1000.slice"),
+            "cpuacct.usage",
+            "5000000\n",
+        );
+        write(
+            &root.path().join("pids/user.slice/user-1000.slice"),
+            "pids.current",
+            "3\n",
+        );
+
+        let v1_paths: HashMap<String, String> = ["memory", "cpu", "cpuacct", "pids"]
+            .iter()
+            .map(|c| (c.to_string(), path.to_string()))
+            .collect();
+        let stats = collect_cgroup_stats_at(root.path(), None, &v1_paths);
+        assert_eq!(stats.layout, CgroupVersion::V1);
+        assert_eq!(stats.memory_current_bytes, Some(2048));
+        assert_eq!(stats.memory_max_bytes, Some(4096));
+        let cpu = stats.cpu.unwrap();
+        assert_eq!(cpu.usage_usec, Some(5_000));
+        assert_eq!(cpu.throttled_usec, Some(45_678));
+        assert_eq!(stats.pids_current, Some(3));
+        assert!(stats.warnings.is_empty(), "{:?}", stats.warnings);
+    }
+
+    #[test]
+    fn test_cgroup_stats_missing_files_warn() {
+        let root = tempfile::tempdir().unwrap();
+        let dir = root.path().join("sparse");
+        write(&dir, "memory.current", "10\n");
+        write(&dir, "pids.current", "not-a-number\n");
+
+        let stats = collect_cgroup_stats_at(root.path(), Some("/sparse"), &HashMap::new());
+        assert_eq!(stats.memory_current_bytes, Some(10));
+        assert!(stats.cpu.is_none());
+        assert!(stats.pids_current.is_none());
+        assert!(stats.warnings.iter().any(|w| w.contains("memory.max")));
+        assert!(stats.warnings.iter().any(|w| w.contains("cpu.stat")));
+        assert!(stats.warnings.iter().any(|w| w.contains("unparseable")));
+    }
+
+    #[test]
+    fn test_cgroup_stats_without_membership() {
+        let root = tempfile::tempdir().unwrap();
+        let stats = collect_cgroup_stats_at(root.path(), None, &HashMap::new());
+        assert_eq!(stats.layout, CgroupVersion::Unknown);
+        assert_eq!(stats.warnings.len(), 1);
+    }
+
     #[test]
     fn test_cgroup_version_default() {
         let content = "";
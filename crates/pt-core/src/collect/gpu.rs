@@ -129,8 +129,11 @@ pub struct GpuSnapshot {
     /// Detected GPU devices.
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub devices: Vec<GpuDevice>,
-    /// Per-process GPU usage (keyed by PID).
-    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    /// Per-process GPU usage (keyed by PID, serialized in PID order).
+    #[serde(
+        skip_serializing_if = "HashMap::is_empty",
+        serialize_with = "pt_common::serialize_sorted"
+    )]
     pub process_usage: HashMap<u32, Vec<ProcessGpuUsage>>,
     /// Total number of GPU-using processes detected.
     pub gpu_process_count: usize,
@@ -1089,4 +1092,33 @@ GPU  Temp  AvgPwr  SCLK     MCLK     Fan  Perf    PwrCap  VRAM%  GPU%
         }
         // Note: if both are available, NVIDIA takes precedence
     }
+
+    #[test]
+    fn test_snapshot_json_is_byte_stable() {
+        let usage = |pid| ProcessGpuUsage {
+            pid,
+            gpu_index: pid % 2,
+            used_gpu_memory_mib: Some(u64::from(pid) * 16),
+            gpu_process_type: Some("C".to_string()),
+        };
+        let snapshot = GpuSnapshot {
+            has_gpu: true,
+            gpu_type: GpuType::Nvidia,
+            process_usage: (1000..1032).map(|pid| (pid, vec![usage(pid)])).collect(),
+            gpu_process_count: 32,
+            ..Default::default()
+        };
+        // Same contents, freshly hashed: iteration order differs per map.
+        let rehashed = GpuSnapshot {
+            process_usage: (1000..1032)
+                .rev()
+                .map(|pid| (pid, vec![usage(pid)]))
+                .collect(),
+            ..snapshot.clone()
+        };
+
+        let first = serde_json::to_string_pretty(&snapshot).unwrap();
+        assert_eq!(first, serde_json::to_string_pretty(&rehashed).unwrap());
+        assert!(first.find("\"1000\"").unwrap() < first.find("\"1031\"").unwrap());
+    }
 }
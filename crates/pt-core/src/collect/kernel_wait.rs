@@ -0,0 +1,315 @@
+//! Kernel wait-state evidence from `/proc/[pid]/wchan` and `/proc/[pid]/stack`.
+//!
+//! The scheduler state letter alone cannot tell a process blocked in the
+//! block layer from one parked on a futex or spinning in userspace. The
+//! kernel function a process sleeps in (`wchan`) and, when readable, its
+//! kernel stack narrow that down to a coarse [`KernelWaitState`].
+//!
+//! `/proc/[pid]/stack` normally requires `CAP_SYS_ADMIN`; when it cannot be
+//! read the collector falls back to `wchan` and records a warning instead of
+//! failing.
+//!
+//! # Data Sources
+//! - `/proc/[pid]/wchan`: symbol the task is sleeping in (`0` when running)
+//! - `/proc/[pid]/stack`: kernel stack, one `[<addr>] symbol+off/len` per line
+
+use serde::{Deserialize, Serialize};
+
+/// Coarse classification of what a process is waiting on in the kernel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KernelWaitState {
+    /// Blocked on disk, page cache or network filesystem I/O.
+    UninterruptibleIo,
+    /// Parked on a futex (lock or condition variable).
+    FutexWait,
+    /// On a CPU or runnable; not sleeping in the kernel.
+    Running,
+    /// No recognizable wait frame.
+    Unknown,
+}
+
+impl KernelWaitState {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            KernelWaitState::UninterruptibleIo => "uninterruptible_io",
+            KernelWaitState::FutexWait => "futex_wait",
+            KernelWaitState::Running => "running",
+            KernelWaitState::Unknown => "unknown",
+        }
+    }
+}
+
+impl std::fmt::Display for KernelWaitState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Scheduler entry points that sit above the interesting frame on a stack.
+const SCHEDULER_FRAMES: &[&str] = &[
+    "__schedule",
+    "schedule",
+    "schedule_preempt_disabled",
+    "schedule_timeout",
+    "schedule_hrtimeout_range",
+    "schedule_hrtimeout_range_clock",
+];
+
+/// Symbol prefixes of I/O wait paths (block layer, page cache, filesystems).
+const IO_FRAME_PREFIXES: &[&str] = &[
+    "io_schedule",
+    "bit_wait_io",
+    "blk_",
+    "folio_wait",
+    "wait_on_page",
+    "__lock_page",
+    "__folio_lock",
+    "wait_on_buffer",
+    "__wait_on_buffer",
+    "jbd2_",
+    "ext4_",
+    "xfs_",
+    "btrfs_",
+    "nfs_",
+    "rpc_wait",
+    "fuse_",
+];
+
+/// Symbol prefixes of futex waits.
+const FUTEX_FRAME_PREFIXES: &[&str] = &["futex_", "do_futex", "__x64_sys_futex"];
+
+/// Kernel wait state observed for one process.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KernelWaitSample {
+    pub pid: u32,
+    pub state: KernelWaitState,
+    /// Contents of `/proc/[pid]/wchan`, if the process was sleeping.
+    pub wchan: Option<String>,
+    /// Frame the classification was based on.
+    pub top_frame: Option<String>,
+    /// Non-fatal problems reading the sources (e.g. stack permission denied).
+    pub warnings: Vec<String>,
+}
+
+/// Parse `/proc/[pid]/stack` content into symbol names, innermost first.
+///
+/// Lines look like `[<0>] futex_wait_queue+0x6c/0xa0`; offsets and module
+/// annotations are dropped.
+pub fn parse_stack_content(content: &str) -> Vec<String> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let line = line.trim();
+            let rest = match line.strip_prefix("[<") {
+                Some(rest) => rest.split_once(">]")?.1,
+                None => line,
+            };
+            let symbol = rest.split_whitespace().next()?;
+            let symbol = symbol.split('+').next().unwrap_or(symbol);
+            (!symbol.is_empty()).then(|| symbol.to_string())
+        })
+        .collect()
+}
+
+/// Classify a single kernel symbol.
+pub fn classify_frame(symbol: &str) -> KernelWaitState {
+    if FUTEX_FRAME_PREFIXES
+        .iter()
+        .any(|prefix| symbol.starts_with(prefix))
+    {
+        KernelWaitState::FutexWait
+    } else if IO_FRAME_PREFIXES
+        .iter()
+        .any(|prefix| symbol.starts_with(prefix))
+    {
+        KernelWaitState::UninterruptibleIo
+    } else {
+        KernelWaitState::Unknown
+    }
+}
+
+/// Classify a process from its scheduler state letter, `wchan` and stack.
+///
+/// The top frame is the innermost stack frame below the generic scheduler
+/// entry points, falling back to `wchan` when no stack is available. A
+/// runnable task (`R`) with no wait symbol is [`KernelWaitState::Running`];
+/// a `D` task whose frame is unrecognized still counts as I/O, since that is
+/// what uninterruptible sleep almost always is.
+///
+/// Returns the state and the frame it was derived from.
+pub fn classify_kernel_wait(
+    state_char: char,
+    wchan: Option<&str>,
+    stack: &[String],
+) -> (KernelWaitState, Option<String>) {
+    let top_frame = stack
+        .iter()
+        .map(String::as_str)
+        .find(|frame| !SCHEDULER_FRAMES.contains(frame))
+        .or(wchan.filter(|w| !w.is_empty() && *w != "0"));
+
+    let state = match (state_char, top_frame) {
+        ('R', None) => KernelWaitState::Running,
+        (_, Some(frame)) => match classify_frame(frame) {
+            KernelWaitState::Unknown if state_char == 'D' => KernelWaitState::UninterruptibleIo,
+            other => other,
+        },
+        ('D', None) => KernelWaitState::UninterruptibleIo,
+        _ => KernelWaitState::Unknown,
+    };
+    (state, top_frame.map(str::to_string))
+}
+
+/// Sample the kernel wait state of `pid`, given its scheduler state letter.
+///
+/// Unreadable sources are reported in [`KernelWaitSample::warnings`].
+#[cfg(target_os = "linux")]
+pub fn collect_kernel_wait(pid: u32, state_char: char) -> KernelWaitSample {
+    let mut warnings = Vec::new();
+
+    let wchan = super::proc_parsers::parse_wchan(pid);
+
+    let stack_path = format!("/proc/{}/stack", pid);
+    let stack = match std::fs::read(&stack_path) {
+        Ok(bytes) => parse_stack_content(&String::from_utf8_lossy(&bytes)),
+        Err(err) if err.kind() == std::io::ErrorKind::PermissionDenied => {
+            warnings.push(format!("{}: permission denied", stack_path));
+            Vec::new()
+        }
+        Err(err) => {
+            warnings.push(format!("{}: {}", stack_path, err));
+            Vec::new()
+        }
+    };
+
+    let (state, top_frame) = classify_kernel_wait(state_char, wchan.as_deref(), &stack);
+    KernelWaitSample {
+        pid,
+        state,
+        wchan,
+        top_frame,
+        warnings,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FUTEX_STACK: &str = "\
+[<0>] futex_wait_queue+0x6c/0xa0
+[<0>] futex_wait+0x175/0x260
+[<0>] do_futex+0x106/0x1b0
+[<0>] __x64_sys_futex+0x8e/0x1d0
+[<0>] do_syscall_64+0x5d/0x90
+[<0>] entry_SYSCALL_64_after_hwframe+0x72/0xdc
+";
+
+    const IO_STACK: &str = "\
+[<0>] io_schedule+0x46/0x70
+[<0>] folio_wait_bit_common+0x13d/0x350
+[<0>] filemap_read+0x1a4/0x340
+[<0>] vfs_read+0x1f0/0x2e0
+[<0>] ksys_read+0x6b/0xf0
+[<0>] do_syscall_64+0x5d/0x90
+";
+
+    const NFS_STACK: &str = "\
+[<0>] __schedule+0x2eb/0x8d0
+[<0>] schedule+0x5b/0xd0
+[<0>] rpc_wait_bit_killable+0x11/0x70 [sunrpc]
+[<0>] __rpc_execute+0x126/0x420 [sunrpc]
+";
+
+    fn frames(content: &str) -> Vec<String> {
+        parse_stack_content(content)
+    }
+
+    #[test]
+    fn parses_stack_symbols() {
+        let parsed = frames(NFS_STACK);
+        assert_eq!(
+            parsed,
+            vec![
+                "__schedule",
+                "schedule",
+                "rpc_wait_bit_killable",
+                "__rpc_execute"
+            ]
+        );
+        assert!(frames("").is_empty());
+    }
+
+    #[test]
+    fn classifies_captured_stacks() {
+        assert_eq!(
+            classify_kernel_wait('S', Some("futex_wait_queue"), &frames(FUTEX_STACK)),
+            (
+                KernelWaitState::FutexWait,
+                Some("futex_wait_queue".to_string())
+            )
+        );
+        assert_eq!(
+            classify_kernel_wait('D', Some("io_schedule"), &frames(IO_STACK)).0,
+            KernelWaitState::UninterruptibleIo
+        );
+        // Scheduler entry frames are skipped to reach the real wait site.
+        assert_eq!(
+            classify_kernel_wait('D', None, &frames(NFS_STACK)),
+            (
+                KernelWaitState::UninterruptibleIo,
+                Some("rpc_wait_bit_killable".to_string())
+            )
+        );
+    }
+
+    #[test]
+    fn falls_back_to_wchan_without_stack() {
+        assert_eq!(
+            classify_kernel_wait('S', Some("futex_wait_queue"), &[]).0,
+            KernelWaitState::FutexWait
+        );
+        assert_eq!(
+            classify_kernel_wait('D', Some("wait_on_page_bit_common"), &[]).0,
+            KernelWaitState::UninterruptibleIo
+        );
+        assert_eq!(
+            classify_kernel_wait('S', Some("do_epoll_wait"), &[]).0,
+            KernelWaitState::Unknown
+        );
+    }
+
+    #[test]
+    fn io_wait_is_distinct_from_userspace_spin() {
+        let (blocked, _) = classify_kernel_wait('D', Some("io_schedule"), &[]);
+        let (spinning, frame) = classify_kernel_wait('R', Some("0"), &[]);
+        assert_eq!(blocked, KernelWaitState::UninterruptibleIo);
+        assert_eq!(spinning, KernelWaitState::Running);
+        assert!(frame.is_none());
+        // Unrecognized D-state frames still count as I/O.
+        assert_eq!(
+            classify_kernel_wait('D', Some("some_driver_wait"), &[]).0,
+            KernelWaitState::UninterruptibleIo
+        );
+    }
+
+    #[test]
+    fn state_serializes_snake_case() {
+        assert_eq!(
+            serde_json::to_string(&KernelWaitState::UninterruptibleIo).unwrap(),
+            "\"uninterruptible_io\""
+        );
+        assert_eq!(KernelWaitState::FutexWait.to_string(), "futex_wait");
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn collects_self_without_failing() {
+        let sample = collect_kernel_wait(std::process::id(), 'R');
+        assert_eq!(sample.pid, std::process::id());
+        // Stack access depends on privileges; either way no panic and any
+        // failure is reported as a warning.
+        assert!(sample.warnings.iter().all(|w| w.contains("/stack")));
+    }
+}
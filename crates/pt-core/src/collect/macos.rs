@@ -20,6 +20,7 @@
 //! # Platform Support
 //! This module only compiles on macOS (target_os = "macos").
 
+use super::ps_header::{PsField, PsHeader};
 use super::tool_runner::run_tool;
 use crate::events::{event_names, Phase, ProgressEmitter, ProgressEvent};
 use pt_common::{IdentityQuality, ProcessId, StartId};
@@ -29,7 +30,7 @@ use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use thiserror::Error;
-use tracing::debug;
+use tracing::{debug, warn};
 
 /// Minimal per-process snapshot from `ps` for action-time checks on macOS.
 #[derive(Debug, Clone)]
@@ -974,67 +975,103 @@ fn collect_base_process_info(
     let stdout = output.stdout_str();
     let mut info = HashMap::new();
 
-    for line in stdout.lines().skip(1) {
-        // Skip header
-        if let Some(parsed) = parse_ps_line_macos(line) {
-            info.insert(parsed.0, parsed.1);
+    let mut lines = stdout.lines();
+    let layout = match lines.next().and_then(PsHeader::parse) {
+        Some(header) => header,
+        None => {
+            // No header: fall back to the requested -o order.
+            lines = stdout.lines();
+            requested_layout()
+        }
+    };
+    let missing = layout.missing_required();
+    if !missing.is_empty() {
+        warn!(missing = ?missing, "ps header lacks required columns");
+    }
+
+    for line in lines {
+        match parse_ps_line_macos(line, &layout) {
+            Ok(parsed) => {
+                info.insert(parsed.0, parsed.1);
+            }
+            Err(e) => debug!(line = %line, error = %e, "skipping ps line"),
         }
     }
 
     Ok(info)
 }
 
-/// Parse a single ps output line for macOS.
-fn parse_ps_line_macos(line: &str) -> Option<(u32, BaseProcessInfo)> {
-    let fields: Vec<&str> = line.split_whitespace().collect();
-
-    // Need at least: pid ppid uid user pgid sess state %cpu rss vsz + lstart(5 fields) + etime + comm
-    // Total minimum: 17 fields
-    if fields.len() < 17 {
-        return None;
-    }
+/// Layout of the `-o` list in [`collect_base_process_info`].
+fn requested_layout() -> PsHeader {
+    PsHeader::from_fields(vec![
+        PsField::Pid,
+        PsField::Ppid,
+        PsField::Uid,
+        PsField::User,
+        PsField::Pgid,
+        PsField::Sid,
+        PsField::State,
+        PsField::CpuPercent,
+        PsField::Rss,
+        PsField::Vsz,
+        PsField::Lstart,
+        PsField::Elapsed,
+        PsField::Comm,
+        PsField::Args,
+    ])
+}
 
-    let pid: u32 = fields[0].parse().ok()?;
-    let ppid: u32 = fields[1].parse().ok()?;
-    let uid: u32 = fields[2].parse().ok()?;
-    let user = fields[3].to_string();
-    let pgid: u32 = fields[4].parse().ok()?;
-    let sid: u32 = fields[5].parse().ok()?;
-    let state = fields[6].chars().next().unwrap_or('?');
-    let cpu_percent: f64 = fields[7].parse().unwrap_or(0.0);
-    let rss_kb: u64 = fields[8].parse().unwrap_or(0);
-    let vsz_kb: u64 = fields[9].parse().unwrap_or(0);
-
-    // lstart is fields 10-14 (day mon date time year)
-    // etime is field 15
-    // comm is field 16
-    // args is 17+
-
-    let etime_idx = 15;
-    let comm_idx = 16;
-
-    let elapsed_secs = parse_etime_macos(fields.get(etime_idx)?).unwrap_or(0);
+/// Parse a single ps output line for macOS, locating fields via `layout`.
+fn parse_ps_line_macos(line: &str, layout: &PsHeader) -> Result<(u32, BaseProcessInfo), String> {
+    let row = layout.split_row(line)?;
+
+    let pid: u32 = row
+        .required(PsField::Pid)?
+        .parse()
+        .map_err(|_| "Invalid PID")?;
+    let ppid: u32 = row
+        .required(PsField::Ppid)?
+        .parse()
+        .map_err(|_| "Invalid PPID")?;
+    let uid: u32 = row
+        .required(PsField::Uid)?
+        .parse()
+        .map_err(|_| "Invalid UID")?;
+    let user = row.required(PsField::User)?.to_string();
+    let pgid = row.get(PsField::Pgid).and_then(|v| v.parse::<u32>().ok());
+    let sid = row.get(PsField::Sid).and_then(|v| v.parse::<u32>().ok());
+    let state = row.required(PsField::State)?.chars().next().unwrap_or('?');
+    let cpu_percent: f64 = row
+        .get(PsField::CpuPercent)
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(0.0);
+    let rss_kb: u64 = row
+        .get(PsField::Rss)
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(0);
+    let vsz_kb: u64 = row
+        .get(PsField::Vsz)
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(0);
+
+    let elapsed_secs = parse_etime_macos(row.required(PsField::Elapsed)?).unwrap_or(0);
     let now = chrono::Utc::now().timestamp();
     let start_time_unix = now - elapsed_secs as i64;
 
-    let comm = fields.get(comm_idx)?.to_string();
-    let cmdline = if fields.len() > comm_idx + 1 {
-        fields[comm_idx + 1..].join(" ")
-    } else {
-        comm.clone()
-    };
+    let comm = row.required(PsField::Comm)?.to_string();
+    let cmdline = row.into_args().unwrap_or_else(|| comm.clone());
 
     // Create start_id for macOS (less precise than Linux)
     let start_id = StartId::from_macos("unknown", start_time_unix as u64, pid);
 
-    Some((
+    Ok((
         pid,
         BaseProcessInfo {
             ppid: ProcessId(ppid),
             uid,
             user,
-            pgid: Some(pgid),
-            sid: Some(sid),
+            pgid,
+            sid,
             start_id,
             comm,
             cmdline,
@@ -5,8 +5,12 @@
 //! - Deep scan via /proc inspection (detailed, Linux-only)
 //! - macOS-specific collection via lsof/launchctl (macOS-only)
 //! - Network connection collection
+//! - Per-process TCP socket snapshots (Linux /proc, macOS lsof)
+//! - Process tree (ancestry/descendant queries) from collected PPIDs
+//! - Kernel wait state from wchan and the kernel stack (Linux-only collection)
 //! - Cgroup and resource limit collection
 //! - Systemd unit detection
+//! - Service-manager attribution behind a platform-neutral trait
 //! - Container detection (Docker, K8s, etc.)
 //! - GPU process detection (NVIDIA CUDA, AMD ROCm)
 //! - Tool runner for safe external command execution
@@ -31,7 +35,9 @@ mod deep_scan;
 #[cfg(target_os = "linux")]
 pub mod gpu;
 pub mod incremental;
+pub mod kernel_wait;
 pub mod lineage_collector;
+pub mod net;
 #[cfg(target_os = "linux")]
 pub mod network;
 #[cfg(target_os = "linux")]
@@ -39,10 +45,13 @@ pub mod network_resource_collector;
 #[cfg(target_os = "linux")]
 pub mod prober;
 pub mod proc_parsers;
+pub mod process_tree;
 pub mod protected;
 pub mod provenance_continuity;
+mod ps_header;
 mod quick_scan;
 pub mod resource_collector;
+pub mod service_context;
 pub mod shared_resource_graph;
 pub mod systemd;
 #[cfg(target_os = "linux")]
@@ -64,6 +73,12 @@ pub use deep_scan::{
     deep_scan, DeepScanError, DeepScanMetadata, DeepScanOptions, DeepScanRecord, DeepScanResult,
 };
 #[cfg(target_os = "linux")]
+pub use kernel_wait::collect_kernel_wait;
+pub use kernel_wait::{
+    classify_kernel_wait, parse_stack_content, KernelWaitSample, KernelWaitState,
+};
+pub use net::{collect_sockets, SocketInfo, SocketSnapshot, SocketSource};
+#[cfg(target_os = "linux")]
 pub use network::{
     collect_network_info, parse_proc_net_tcp, parse_proc_net_udp, parse_proc_net_unix, ListenPort,
     NetworkInfo, NetworkSnapshot, SocketCounts, TcpConnection, TcpState, UdpSocket, UnixSocket,
@@ -82,6 +97,7 @@ pub use proc_parsers::{
 pub use proc_parsers::{
     parse_environ_content, CriticalFile, CriticalFileCategory, DetectionStrength,
 };
+pub use process_tree::ProcessTree;
 pub use provenance_continuity::{
     compute_provenance_delta, pid_continuity, summarize_delta, BlastRadiusDelta, PidContinuity,
     ProvenanceDelta, ResourceChange,
@@ -105,9 +121,12 @@ pub use protected::{
 };
 
 // Re-export cgroup types
+#[cfg(target_os = "linux")]
+pub use cgroup::collect_cgroup_stats;
 pub use cgroup::{
-    collect_cgroup_details, collect_cgroup_from_content, effective_cores_from_quota, CgroupDetails,
-    CgroupProvenance, CgroupVersion, CpuLimitSource, CpuLimits, MemoryLimitSource, MemoryLimits,
+    collect_cgroup_details, collect_cgroup_from_content, collect_cgroup_stats_at,
+    effective_cores_from_quota, parse_cpu_stat, CgroupCpuStat, CgroupDetails, CgroupProvenance,
+    CgroupStats, CgroupVersion, CpuLimitSource, CpuLimits, MemoryLimitSource, MemoryLimits,
 };
 
 // Re-export systemd types (available on all platforms; collection functions
@@ -117,6 +136,14 @@ pub use systemd::{
     SystemdActiveState, SystemdDataSource, SystemdProvenance, SystemdUnit, SystemdUnitType,
 };
 
+// Re-export service-manager attribution (systemd on Linux, a no-op fallback
+// elsewhere) so callers never need to cfg-gate
+#[cfg(target_os = "linux")]
+pub use service_context::SystemdServiceContext;
+pub use service_context::{
+    NoServiceContext, PlatformServiceContext, ServiceContext, ServiceLookup,
+};
+
 // Re-export container types
 pub use container::{
     detect_container_from_cgroup, detect_container_from_markers, detect_kubernetes_from_env,
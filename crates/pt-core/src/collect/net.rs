@@ -0,0 +1,317 @@
+//! Per-process TCP socket enumeration backing the `NetSnapshot` probe.
+//!
+//! Answers "is this process actually serving traffic?" by listing the
+//! listening and connected TCP sockets a PID owns:
+//! - Linux: socket inodes from `/proc/[pid]/fd` matched against the
+//!   process's own network namespace tables (`/proc/[pid]/net/tcp{,6}`),
+//!   falling back to the global `/proc/net/tcp{,6}`
+//! - macOS: `lsof` network entries for the PID
+//!
+//! Collection never fails: without privileges (or on other platforms) the
+//! snapshot is empty, marked [`SocketSource::Unavailable`], and carries a
+//! warning explaining why.
+
+use serde::{Deserialize, Serialize};
+
+/// One TCP socket owned by a process.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SocketInfo {
+    /// Local endpoint (`addr:port`, IPv6 as `[addr]:port`).
+    pub local: String,
+    /// Remote endpoint, when connected.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remote: Option<String>,
+    /// Connection state (e.g. `LISTEN`, `ESTABLISHED`).
+    pub state: String,
+    /// Socket inode (Linux only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inode: Option<u64>,
+}
+
+impl SocketInfo {
+    /// Whether the socket is accepting connections.
+    pub fn is_listening(&self) -> bool {
+        self.state == "LISTEN"
+    }
+
+    /// Whether the socket has an established peer.
+    pub fn is_established(&self) -> bool {
+        self.state == "ESTABLISHED"
+    }
+}
+
+/// Where a socket snapshot came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SocketSource {
+    /// `/proc/[pid]/fd` + `/proc/.../net/tcp` inode mapping.
+    ProcNet,
+    /// `lsof` output.
+    Lsof,
+    /// No source could be read.
+    #[default]
+    Unavailable,
+}
+
+/// TCP sockets owned by one process.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SocketSnapshot {
+    /// Process the snapshot was taken for.
+    pub pid: u32,
+    /// Sockets found.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub sockets: Vec<SocketInfo>,
+    /// Source used.
+    pub source: SocketSource,
+    /// Reasons collection was partial or unavailable.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+impl SocketSnapshot {
+    /// Number of listening sockets.
+    pub fn listening(&self) -> usize {
+        self.sockets.iter().filter(|s| s.is_listening()).count()
+    }
+
+    /// Number of established connections.
+    pub fn established(&self) -> usize {
+        self.sockets.iter().filter(|s| s.is_established()).count()
+    }
+
+    /// Whether the process has live peers, i.e. is serving or using traffic.
+    pub fn has_active_traffic(&self) -> bool {
+        self.established() > 0
+    }
+}
+
+/// Format an endpoint, bracketing IPv6 addresses.
+fn format_endpoint(addr: &str, port: u16) -> String {
+    if addr.contains(':') {
+        format!("[{}]:{}", addr, port)
+    } else {
+        format!("{}:{}", addr, port)
+    }
+}
+
+/// Collect the TCP sockets owned by `pid`.
+#[cfg(target_os = "linux")]
+pub fn collect_sockets(pid: u32) -> SocketSnapshot {
+    let mut snapshot = SocketSnapshot {
+        pid,
+        ..Default::default()
+    };
+
+    let Some(inodes) = super::network::get_process_socket_inodes(pid) else {
+        snapshot.warnings.push(format!(
+            "cannot read /proc/{}/fd (insufficient privileges or process exited)",
+            pid
+        ));
+        return snapshot;
+    };
+
+    snapshot.source = SocketSource::ProcNet;
+    if inodes.is_empty() {
+        return snapshot;
+    }
+
+    for (table, is_ipv6) in [("tcp", false), ("tcp6", true)] {
+        let ns_path = format!("/proc/{}/net/{}", pid, table);
+        let content = std::fs::read_to_string(&ns_path)
+            .or_else(|_| std::fs::read_to_string(format!("/proc/net/{}", table)));
+        match content {
+            Ok(content) => {
+                snapshot
+                    .sockets
+                    .extend(sockets_from_proc_net(&content, is_ipv6, &inodes));
+            }
+            Err(e) => snapshot.warnings.push(format!("{}: {}", ns_path, e)),
+        }
+    }
+
+    snapshot
+}
+
+/// Collect the TCP sockets owned by `pid`.
+#[cfg(target_os = "macos")]
+pub fn collect_sockets(pid: u32) -> SocketSnapshot {
+    let mut snapshot = SocketSnapshot {
+        pid,
+        ..Default::default()
+    };
+
+    match super::macos::collect_lsof_info(pid, std::time::Duration::from_secs(5)) {
+        Ok((_, connections)) => {
+            snapshot.source = SocketSource::Lsof;
+            snapshot
+                .sockets
+                .extend(connections.into_iter().map(|c| SocketInfo {
+                    local: c.local_address,
+                    remote: c.remote_address,
+                    state: c.state.unwrap_or_default(),
+                    inode: None,
+                }));
+        }
+        Err(e) => snapshot.warnings.push(e.to_string()),
+    }
+
+    snapshot
+}
+
+/// Collect the TCP sockets owned by `pid`.
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+pub fn collect_sockets(pid: u32) -> SocketSnapshot {
+    SocketSnapshot {
+        pid,
+        warnings: vec!["socket enumeration is not supported on this platform".to_string()],
+        ..Default::default()
+    }
+}
+
+/// Select the entries of a `/proc/net/tcp{,6}` table whose inode is in `inodes`.
+#[cfg(target_os = "linux")]
+pub fn sockets_from_proc_net(
+    content: &str,
+    is_ipv6: bool,
+    inodes: &std::collections::HashSet<u64>,
+) -> Vec<SocketInfo> {
+    use super::network::{parse_proc_net_tcp_reader, TcpState};
+
+    parse_proc_net_tcp_reader(content.as_bytes(), is_ipv6)
+        .into_iter()
+        .filter(|conn| inodes.contains(&conn.inode))
+        .map(|conn| {
+            let connected = conn.remote_port != 0 && conn.state != TcpState::Listen;
+            SocketInfo {
+                local: format_endpoint(&conn.local_addr, conn.local_port),
+                remote: connected.then(|| format_endpoint(&conn.remote_addr, conn.remote_port)),
+                state: tcp_state_name(conn.state).to_string(),
+                inode: Some(conn.inode),
+            }
+        })
+        .collect()
+}
+
+/// Kernel-style name for a TCP state.
+#[cfg(target_os = "linux")]
+fn tcp_state_name(state: super::network::TcpState) -> &'static str {
+    use super::network::TcpState;
+    match state {
+        TcpState::Established => "ESTABLISHED",
+        TcpState::SynSent => "SYN_SENT",
+        TcpState::SynRecv => "SYN_RECV",
+        TcpState::FinWait1 => "FIN_WAIT1",
+        TcpState::FinWait2 => "FIN_WAIT2",
+        TcpState::TimeWait => "TIME_WAIT",
+        TcpState::Close => "CLOSE",
+        TcpState::CloseWait => "CLOSE_WAIT",
+        TcpState::LastAck => "LAST_ACK",
+        TcpState::Listen => "LISTEN",
+        TcpState::Closing => "CLOSING",
+        TcpState::Unknown => "UNKNOWN",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn socket(state: &str) -> SocketInfo {
+        SocketInfo {
+            local: "127.0.0.1:8080".to_string(),
+            remote: None,
+            state: state.to_string(),
+            inode: None,
+        }
+    }
+
+    #[test]
+    fn test_format_endpoint() {
+        assert_eq!(format_endpoint("127.0.0.1", 80), "127.0.0.1:80");
+        assert_eq!(format_endpoint("::1", 443), "[::1]:443");
+    }
+
+    #[test]
+    fn test_snapshot_counts() {
+        let snapshot = SocketSnapshot {
+            pid: 1,
+            sockets: vec![socket("LISTEN"), socket("ESTABLISHED"), socket("TIME_WAIT")],
+            source: SocketSource::ProcNet,
+            warnings: Vec::new(),
+        };
+        assert_eq!(snapshot.listening(), 1);
+        assert_eq!(snapshot.established(), 1);
+        assert!(snapshot.has_active_traffic());
+
+        let idle = SocketSnapshot {
+            sockets: vec![socket("LISTEN")],
+            ..Default::default()
+        };
+        assert!(!idle.has_active_traffic());
+    }
+
+    #[cfg(target_os = "linux")]
+    mod linux {
+        use super::super::*;
+        use std::collections::HashSet;
+
+        /// `/proc/net/tcp` captured from a host running a local web server.
+        const PROC_NET_TCP: &str = "\
+  sl  local_address rem_address   st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode
+   0: 0100007F:1F90 00000000:0000 0A 00000000:00000000 00:00000000 00000000  1000        0 41001 1 0000000000000000 100 0 0 10 0
+   1: 0100007F:1F90 0100007F:D431 01 00000000:00000000 00:00000000 00000000  1000        0 41002 1 0000000000000000 20 4 30 10 -1
+   2: 0100007F:D431 0100007F:1F90 01 00000000:00000000 00:00000000 00000000  1000        0 41003 1 0000000000000000 20 4 30 10 -1
+   3: 00000000:0016 00000000:0000 0A 00000000:00000000 00:00000000 00000000     0        0 19000 1 0000000000000000 100 0 0 10 0
+";
+
+        /// `/proc/net/tcp6` with a wildcard listener.
+        const PROC_NET_TCP6: &str = "\
+  sl  local_address                         remote_address                        st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode
+   0: 00000000000000000000000000000000:01BB 00000000000000000000000000000000:0000 0A 00000000:00000000 00:00000000 00000000     0        0 52000 1 0000000000000000 100 0 0 10 0
+";
+
+        #[test]
+        fn test_sockets_from_proc_net_filters_by_inode() {
+            let inodes: HashSet<u64> = [41001, 41002].into_iter().collect();
+            let sockets = sockets_from_proc_net(PROC_NET_TCP, false, &inodes);
+            assert_eq!(sockets.len(), 2);
+
+            assert_eq!(sockets[0].local, "127.0.0.1:8080");
+            assert_eq!(sockets[0].state, "LISTEN");
+            assert_eq!(sockets[0].remote, None);
+            assert_eq!(sockets[0].inode, Some(41001));
+
+            assert_eq!(sockets[1].state, "ESTABLISHED");
+            assert_eq!(sockets[1].remote.as_deref(), Some("127.0.0.1:54321"));
+        }
+
+        #[test]
+        fn test_sockets_from_proc_net_ipv6() {
+            let inodes: HashSet<u64> = [52000].into_iter().collect();
+            let sockets = sockets_from_proc_net(PROC_NET_TCP6, true, &inodes);
+            assert_eq!(sockets.len(), 1);
+            assert_eq!(sockets[0].local, "[::]:443");
+            assert!(sockets[0].is_listening());
+        }
+
+        #[test]
+        fn test_sockets_from_proc_net_no_owned_inodes() {
+            let sockets = sockets_from_proc_net(PROC_NET_TCP, false, &HashSet::new());
+            assert!(sockets.is_empty());
+        }
+
+        #[test]
+        fn test_collect_sockets_self() {
+            let snapshot = collect_sockets(std::process::id());
+            assert_eq!(snapshot.source, SocketSource::ProcNet);
+        }
+
+        #[test]
+        fn test_collect_sockets_missing_pid_degrades() {
+            let snapshot = collect_sockets(u32::MAX);
+            assert_eq!(snapshot.source, SocketSource::Unavailable);
+            assert!(snapshot.sockets.is_empty());
+            assert_eq!(snapshot.warnings.len(), 1);
+        }
+    }
+}
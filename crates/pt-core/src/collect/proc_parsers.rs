@@ -171,6 +171,7 @@ pub struct FdInfo {
     #[serde(default)]
     pub truncated: bool,
     /// File descriptors by type.
+    #[serde(serialize_with = "pt_common::serialize_sorted")]
     pub by_type: HashMap<String, usize>,
     /// Socket count.
     pub sockets: usize,
@@ -0,0 +1,194 @@
+//! Process tree built from collected PPIDs.
+//!
+//! Answers genealogy questions over a scan snapshot without re-reading
+//! `/proc`: who a process descends from, what it spawned, and whether a
+//! known supervisor sits anywhere above it.
+//!
+//! Snapshots are not guaranteed to be consistent trees: parents may have
+//! exited (detached subtrees), PPID 0 marks kernel roots, and PID reuse
+//! between samples can produce cycles. All walks stop at missing parents
+//! and never visit a PID twice.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use super::types::ProcessRecord;
+
+/// Parent/child relationships for a set of processes.
+#[derive(Debug, Clone, Default)]
+pub struct ProcessTree {
+    /// Child → parent (PPID 0 and self-parenting are not recorded).
+    parents: HashMap<u32, u32>,
+    /// Parent → children, in insertion order.
+    children: HashMap<u32, Vec<u32>>,
+    /// Every PID seen in the snapshot.
+    pids: HashSet<u32>,
+}
+
+impl ProcessTree {
+    /// Build a tree from `(pid, ppid)` pairs.
+    pub fn from_ppids(pairs: impl IntoIterator<Item = (u32, u32)>) -> Self {
+        let mut tree = Self::default();
+        for (pid, ppid) in pairs {
+            if !tree.pids.insert(pid) {
+                continue;
+            }
+            if ppid == 0 || ppid == pid {
+                continue;
+            }
+            tree.parents.insert(pid, ppid);
+            tree.children.entry(ppid).or_default().push(pid);
+        }
+        tree
+    }
+
+    /// Build a tree from scan records.
+    pub fn from_records<'a>(records: impl IntoIterator<Item = &'a ProcessRecord>) -> Self {
+        Self::from_ppids(records.into_iter().map(|r| (r.pid.0, r.ppid.0)))
+    }
+
+    /// Whether `pid` was present in the snapshot.
+    pub fn contains(&self, pid: u32) -> bool {
+        self.pids.contains(&pid)
+    }
+
+    /// Number of processes in the snapshot.
+    pub fn len(&self) -> usize {
+        self.pids.len()
+    }
+
+    /// Whether the snapshot is empty.
+    pub fn is_empty(&self) -> bool {
+        self.pids.is_empty()
+    }
+
+    /// Recorded parent of `pid`, if any.
+    pub fn parent(&self, pid: u32) -> Option<u32> {
+        self.parents.get(&pid).copied()
+    }
+
+    /// Direct children of `pid`.
+    pub fn children(&self, pid: u32) -> &[u32] {
+        self.children.get(&pid).map_or(&[], Vec::as_slice)
+    }
+
+    /// Ancestors of `pid`, nearest first.
+    ///
+    /// The walk ends at PPID 0, at a parent missing from the snapshot
+    /// (included once, since its PID is still known), or on a cycle.
+    pub fn ancestors(&self, pid: u32) -> Vec<u32> {
+        let mut ancestors = Vec::new();
+        let mut visited = HashSet::from([pid]);
+        let mut current = pid;
+        while let Some(parent) = self.parent(current) {
+            if !visited.insert(parent) {
+                break;
+            }
+            ancestors.push(parent);
+            if !self.contains(parent) {
+                break;
+            }
+            current = parent;
+        }
+        ancestors
+    }
+
+    /// All descendants of `pid`, breadth-first.
+    pub fn descendants(&self, pid: u32) -> Vec<u32> {
+        let mut descendants = Vec::new();
+        let mut visited = HashSet::from([pid]);
+        let mut queue = VecDeque::from([pid]);
+        while let Some(current) = queue.pop_front() {
+            for &child in self.children(current) {
+                if visited.insert(child) {
+                    descendants.push(child);
+                    queue.push_back(child);
+                }
+            }
+        }
+        descendants
+    }
+
+    /// Nearest ancestor of `pid` that is in `supervisor_pids`.
+    pub fn supervising_ancestor(&self, pid: u32, supervisor_pids: &HashSet<u32>) -> Option<u32> {
+        self.ancestors(pid)
+            .into_iter()
+            .find(|ancestor| supervisor_pids.contains(ancestor))
+    }
+
+    /// Whether any ancestor of `pid` is in `supervisor_pids`.
+    pub fn is_supervised_by(&self, pid: u32, supervisor_pids: &HashSet<u32>) -> bool {
+        self.supervising_ancestor(pid, supervisor_pids).is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// init(1) → sshd(100) → bash(200) → {vim(300), make(301) → cc(400)},
+    /// plus a detached subtree whose parent (900) already exited:
+    /// worker(910) → child(911).
+    fn sample_tree() -> ProcessTree {
+        ProcessTree::from_ppids([
+            (1, 0),
+            (100, 1),
+            (200, 100),
+            (300, 200),
+            (301, 200),
+            (400, 301),
+            (910, 900),
+            (911, 910),
+        ])
+    }
+
+    #[test]
+    fn ancestors_walk_to_root() {
+        let tree = sample_tree();
+        assert_eq!(tree.ancestors(400), vec![301, 200, 100, 1]);
+        assert_eq!(tree.ancestors(1), Vec::<u32>::new());
+        assert_eq!(tree.parent(1), None);
+    }
+
+    #[test]
+    fn detached_subtree_stops_at_missing_parent() {
+        let tree = sample_tree();
+        assert!(!tree.contains(900));
+        assert_eq!(tree.ancestors(911), vec![910, 900]);
+        assert_eq!(tree.descendants(910), vec![911]);
+        assert!(!tree.is_supervised_by(911, &HashSet::from([100])));
+    }
+
+    #[test]
+    fn descendants_are_breadth_first() {
+        let tree = sample_tree();
+        assert_eq!(tree.descendants(100), vec![200, 300, 301, 400]);
+        assert!(tree.descendants(400).is_empty());
+        assert_eq!(tree.children(200), &[300, 301]);
+    }
+
+    #[test]
+    fn supervised_by_nearest_matching_ancestor() {
+        let tree = sample_tree();
+        let supervisors = HashSet::from([100, 200]);
+        assert!(tree.is_supervised_by(400, &supervisors));
+        assert_eq!(tree.supervising_ancestor(400, &supervisors), Some(200));
+        // A supervisor does not supervise itself.
+        assert_eq!(tree.supervising_ancestor(200, &supervisors), Some(100));
+        assert!(!tree.is_supervised_by(100, &supervisors));
+    }
+
+    #[test]
+    fn cycles_terminate() {
+        let tree = ProcessTree::from_ppids([(10, 20), (20, 30), (30, 10)]);
+        assert_eq!(tree.ancestors(10), vec![20, 30]);
+        assert_eq!(tree.descendants(10), vec![30, 20]);
+    }
+
+    #[test]
+    fn self_parent_and_duplicates_ignored() {
+        let tree = ProcessTree::from_ppids([(5, 5), (6, 5), (6, 99)]);
+        assert_eq!(tree.len(), 2);
+        assert_eq!(tree.parent(5), None);
+        assert_eq!(tree.parent(6), Some(5));
+    }
+}
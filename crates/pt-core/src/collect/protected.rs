@@ -283,6 +283,7 @@ pub struct FilterResult {
 /// Filter for protected processes.
 ///
 /// Compiled patterns and lookup sets for efficient filtering at scan phase.
+#[derive(Debug, Clone, Default)]
 pub struct ProtectedFilter {
     /// Compiled protected patterns.
     patterns: Vec<CompiledProtectedPattern>,
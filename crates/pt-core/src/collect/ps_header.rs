@@ -0,0 +1,265 @@
+//! Column layout detection for `ps -o` output.
+//!
+//! GNU and BSD `ps` label the same columns differently (`S` vs `STAT`,
+//! `SID` vs `SESS`, `COMMAND` vs `COMM`/`ARGS`) and may insert extra
+//! columns. [`PsHeader`] reads the header line and maps each column to the
+//! field it carries, so rows are indexed by name instead of by a fixed
+//! per-OS position.
+
+/// Field carried by one `ps` column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PsField {
+    Pid,
+    Ppid,
+    Uid,
+    User,
+    Pgid,
+    Sid,
+    State,
+    CpuPercent,
+    Rss,
+    Vsz,
+    Tty,
+    /// `lstart`; always five whitespace-separated tokens.
+    Lstart,
+    Elapsed,
+    Comm,
+    /// Full command line; consumes the rest of the row.
+    Args,
+    /// A column we do not use (one token wide).
+    Unknown,
+}
+
+const FIELD_COUNT: usize = PsField::Unknown as usize + 1;
+
+/// Fields every row must provide.
+const REQUIRED: [PsField; 7] = [
+    PsField::Pid,
+    PsField::Ppid,
+    PsField::Uid,
+    PsField::User,
+    PsField::State,
+    PsField::Elapsed,
+    PsField::Comm,
+];
+
+impl PsField {
+    /// Header label used in error messages.
+    pub(crate) fn label(self) -> &'static str {
+        match self {
+            PsField::Pid => "PID",
+            PsField::Ppid => "PPID",
+            PsField::Uid => "UID",
+            PsField::User => "USER",
+            PsField::Pgid => "PGID",
+            PsField::Sid => "SID",
+            PsField::State => "STATE",
+            PsField::CpuPercent => "%CPU",
+            PsField::Rss => "RSS",
+            PsField::Vsz => "VSZ",
+            PsField::Tty => "TTY",
+            PsField::Lstart => "STARTED",
+            PsField::Elapsed => "ELAPSED",
+            PsField::Comm => "COMM",
+            PsField::Args => "ARGS",
+            PsField::Unknown => "?",
+        }
+    }
+
+    /// Number of whitespace-separated tokens one value occupies.
+    fn width(self) -> usize {
+        match self {
+            PsField::Lstart => 5,
+            _ => 1,
+        }
+    }
+}
+
+/// Column layout of `ps` output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct PsHeader {
+    columns: Vec<PsField>,
+}
+
+impl PsHeader {
+    /// Layout for a known `-o` column list, used when output has no header.
+    pub(crate) fn from_fields(columns: Vec<PsField>) -> Self {
+        Self { columns }
+    }
+
+    /// Parse a header line; returns `None` if `line` is a data row.
+    ///
+    /// `COMMAND` means `comm` the first time it appears and `args` after
+    /// that, matching GNU `ps -o comm,args`. Columns after `args` are
+    /// ignored since `args` runs to the end of the line.
+    pub(crate) fn parse(line: &str) -> Option<Self> {
+        let labels: Vec<String> = line
+            .split_whitespace()
+            .map(|label| label.to_ascii_uppercase())
+            .collect();
+        if !labels.iter().any(|label| label == "PID") {
+            return None;
+        }
+        let mut columns = Vec::with_capacity(labels.len());
+        for label in &labels {
+            let field = match label.as_str() {
+                "PID" => PsField::Pid,
+                "PPID" => PsField::Ppid,
+                "UID" => PsField::Uid,
+                "USER" => PsField::User,
+                "PGID" => PsField::Pgid,
+                "SID" | "SESS" | "SESSION" => PsField::Sid,
+                "S" | "STAT" | "STATE" => PsField::State,
+                "%CPU" => PsField::CpuPercent,
+                "RSS" | "RSZ" => PsField::Rss,
+                "VSZ" | "VSIZE" => PsField::Vsz,
+                "TT" | "TTY" => PsField::Tty,
+                "STARTED" | "LSTART" => PsField::Lstart,
+                "ELAPSED" | "ETIME" | "ETIMES" => PsField::Elapsed,
+                "COMM" | "UCOMM" => PsField::Comm,
+                "COMMAND" if !columns.contains(&PsField::Comm) => PsField::Comm,
+                "COMMAND" | "ARGS" | "CMD" => PsField::Args,
+                _ => PsField::Unknown,
+            };
+            columns.push(field);
+        }
+        Some(Self { columns })
+    }
+
+    /// Labels of required fields this layout has no column for.
+    pub(crate) fn missing_required(&self) -> Vec<&'static str> {
+        REQUIRED
+            .iter()
+            .filter(|field| !self.columns.contains(field))
+            .map(|field| field.label())
+            .collect()
+    }
+
+    /// Tokens a row needs to fill every column before `args`.
+    fn min_tokens(&self) -> usize {
+        self.columns
+            .iter()
+            .take_while(|field| **field != PsField::Args)
+            .map(|field| field.width())
+            .sum()
+    }
+
+    /// Split one data row into its columns.
+    pub(crate) fn split_row<'a>(&self, line: &'a str) -> Result<PsRow<'a>, String> {
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        let min = self.min_tokens();
+        if tokens.len() < min {
+            return Err(format!(
+                "Insufficient fields: expected at least {}, got {}",
+                min,
+                tokens.len()
+            ));
+        }
+
+        let mut row = PsRow {
+            values: [None; FIELD_COUNT],
+            args: None,
+        };
+        let mut pos = 0;
+        for &field in &self.columns {
+            if field == PsField::Args {
+                if pos < tokens.len() {
+                    row.args = Some(tokens[pos..].join(" "));
+                }
+                break;
+            }
+            let slot = &mut row.values[field as usize];
+            if slot.is_none() {
+                *slot = Some(tokens[pos]);
+            }
+            pos += field.width();
+        }
+        Ok(row)
+    }
+}
+
+/// One `ps` row split by column.
+#[derive(Debug)]
+pub(crate) struct PsRow<'a> {
+    values: [Option<&'a str>; FIELD_COUNT],
+    args: Option<String>,
+}
+
+impl<'a> PsRow<'a> {
+    /// Value of `field`, if the layout has that column.
+    ///
+    /// For [`PsField::Lstart`] this is only the first of its five tokens.
+    pub(crate) fn get(&self, field: PsField) -> Option<&'a str> {
+        self.values[field as usize]
+    }
+
+    /// Value of `field`, or an error naming the missing column.
+    pub(crate) fn required(&self, field: PsField) -> Result<&'a str, String> {
+        self.get(field)
+            .ok_or_else(|| format!("ps output has no {} column", field.label()))
+    }
+
+    /// The command line, or `None` if there is no `args` column or it was empty.
+    pub(crate) fn into_args(self) -> Option<String> {
+        self.args
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GNU_HEADER: &str = "    PID    PPID   UID USER      PGID   SID S %CPU   RSS    VSZ TT                        STARTED ELAPSED COMMAND         COMMAND";
+    const BSD_HEADER: &str =
+        "  PID  PPID   UID USER   PGID   SESS STAT  %CPU    RSS      VSZ TT  STARTED                      ELAPSED COMM ARGS";
+
+    #[test]
+    fn maps_gnu_and_bsd_labels_to_the_same_fields() {
+        let gnu = PsHeader::parse(GNU_HEADER).unwrap();
+        let bsd = PsHeader::parse(BSD_HEADER).unwrap();
+        assert_eq!(gnu, bsd);
+        assert!(gnu.missing_required().is_empty());
+        assert_eq!(gnu.min_tokens(), 18);
+    }
+
+    #[test]
+    fn data_rows_are_not_headers() {
+        assert!(
+            PsHeader::parse("1 0 0 root 1 1 S 0.0 0 0 ? Thu Feb 20 00:00:00 2026 3600 init")
+                .is_none()
+        );
+        assert!(PsHeader::parse("pid ppid uid user").is_some());
+    }
+
+    #[test]
+    fn extra_and_reordered_columns_are_indexed_by_name() {
+        let header = PsHeader::parse("USER PID PRI NI PPID UID STAT ETIME COMM ARGS").unwrap();
+        let row = header
+            .split_row("alice 501 31 0 1 501 Ss 1:23:45 zsh -zsh --login")
+            .unwrap();
+        assert_eq!(row.get(PsField::Pid), Some("501"));
+        assert_eq!(row.get(PsField::Ppid), Some("1"));
+        assert_eq!(row.get(PsField::State), Some("Ss"));
+        assert_eq!(row.get(PsField::Elapsed), Some("1:23:45"));
+        assert_eq!(row.get(PsField::Rss), None);
+        assert_eq!(row.into_args().as_deref(), Some("-zsh --login"));
+    }
+
+    #[test]
+    fn reports_short_rows_and_missing_columns() {
+        let header = PsHeader::parse(GNU_HEADER).unwrap();
+        let err = header.split_row("1 0 0 root 1 1 S").unwrap_err();
+        assert_eq!(err, "Insufficient fields: expected at least 18, got 7");
+
+        let header = PsHeader::parse("PID PPID COMM").unwrap();
+        assert_eq!(
+            header.missing_required(),
+            vec!["UID", "USER", "STATE", "ELAPSED"]
+        );
+        let row = header.split_row("7 1 sleep").unwrap();
+        assert_eq!(
+            row.required(PsField::Uid).unwrap_err(),
+            "ps output has no UID column"
+        );
+    }
+}
@@ -7,10 +7,15 @@
 //! - Linux: Uses procps-ng ps with extended format
 //! - macOS: Uses BSD ps with compatible format
 //!
+//! Columns are located by name from the `ps` header line (see
+//! [`PsHeader`]), so BSD vs GNU label differences and extra columns do not
+//! shift fields.
+//!
 //! # Performance
 //! - Target: <1s for 1000 processes
 //! - Single ps invocation with custom format string
 
+use super::ps_header::{PsField, PsHeader};
 use super::types::{ProcessRecord, ProcessState, ScanMetadata, ScanResult};
 use crate::events::{event_names, Phase, ProgressEmitter, ProgressEvent};
 use pt_common::{ProcessId, StartId};
@@ -151,6 +156,7 @@ pub fn quick_scan(options: &QuickScanOptions) -> Result<ScanResult, QuickScanErr
     // Parse output
     let lines = reader.lines();
     let mut header_checked = false;
+    let mut layout = requested_layout();
 
     let mut processed = 0usize;
     const PROGRESS_STEP: usize = 200;
@@ -163,12 +169,20 @@ pub fn quick_scan(options: &QuickScanOptions) -> Result<ScanResult, QuickScanErr
 
         if !header_checked {
             header_checked = true;
-            if is_header_line(&line) {
+            if let Some(header) = PsHeader::parse(&line) {
+                let missing = header.missing_required();
+                if !missing.is_empty() {
+                    warnings.push(format!(
+                        "ps header lacks required columns: {}",
+                        missing.join(", ")
+                    ));
+                }
+                layout = header;
                 continue;
             }
         }
 
-        match parse_ps_line(&line, &platform, &boot_id) {
+        match parse_ps_line(&line, &layout, &platform, &boot_id) {
             Ok(record) => {
                 // Filter kernel threads if not requested AND not targeting specific PIDs.
                 // If user explicitly asks for specific PIDs, we respect that even for kernel threads.
@@ -250,12 +264,25 @@ pub fn quick_scan(options: &QuickScanOptions) -> Result<ScanResult, QuickScanErr
     })
 }
 
-fn is_header_line(line: &str) -> bool {
-    let mut parts = line.split_whitespace();
-    matches!(
-        (parts.next(), parts.next()),
-        (Some("PID"), Some("PPID")) | (Some("pid"), Some("ppid"))
-    )
+/// Layout of the `-o` list in [`build_ps_command`], for output without a header.
+fn requested_layout() -> PsHeader {
+    PsHeader::from_fields(vec![
+        PsField::Pid,
+        PsField::Ppid,
+        PsField::Uid,
+        PsField::User,
+        PsField::Pgid,
+        PsField::Sid,
+        PsField::State,
+        PsField::CpuPercent,
+        PsField::Rss,
+        PsField::Vsz,
+        PsField::Tty,
+        PsField::Lstart,
+        PsField::Elapsed,
+        PsField::Comm,
+        PsField::Args,
+    ])
 }
 
 // ---------------------------------------------------------------------------
@@ -276,6 +303,7 @@ pub fn parse_ps_output_synthetic_linux(output: &str) -> Result<Vec<ProcessRecord
 
     let mut processes = Vec::new();
     let mut header_checked = false;
+    let mut layout = requested_layout();
 
     for (line_num, line) in output.lines().enumerate() {
         let line = line.trim();
@@ -285,17 +313,19 @@ pub fn parse_ps_output_synthetic_linux(output: &str) -> Result<Vec<ProcessRecord
 
         if !header_checked {
             header_checked = true;
-            if is_header_line(line) {
+            if let Some(header) = PsHeader::parse(line) {
+                layout = header;
                 continue;
             }
         }
 
-        let record = parse_ps_line_synthetic(line, PLATFORM, &boot_id, NOW_UNIX).map_err(|e| {
-            QuickScanError::ParseError {
-                message: e,
-                line_num: line_num + 1,
-            }
-        })?;
+        let record =
+            parse_ps_line_synthetic(line, &layout, PLATFORM, &boot_id, NOW_UNIX).map_err(|e| {
+                QuickScanError::ParseError {
+                    message: e,
+                    line_num: line_num + 1,
+                }
+            })?;
         processes.push(record);
     }
 
@@ -339,11 +369,11 @@ fn build_ps_command(platform: &str, options: &QuickScanOptions) -> Result<Comman
     match platform {
         "linux" => {
             // Linux ps format: pid ppid uid user pgid sid state %cpu rss vsz tty start_time etimes comm cmd
-            // Using -eo for custom format, -ww for wide output
+            // Using -eo for custom format, -ww for wide output. The header is
+            // kept so columns can be located by name.
             cmd.args([
                 "-eo",
                 "pid,ppid,uid,user,pgid,sid,state,%cpu,rss,vsz,tty,lstart,etimes,comm,args",
-                "--no-headers",
                 "-ww",
             ]);
         }
@@ -373,30 +403,33 @@ fn build_ps_command(platform: &str, options: &QuickScanOptions) -> Result<Comman
 /// Parse a single line of ps output into a ProcessRecord.
 fn parse_ps_line(
     line: &str,
+    layout: &PsHeader,
     platform: &str,
     boot_id: &Option<String>,
 ) -> Result<ProcessRecord, String> {
-    parse_ps_line_with_timing(line, platform, boot_id, None)
+    parse_ps_line_with_timing(line, layout, platform, boot_id, None)
 }
 
 /// Parse a single line of ps output like `parse_ps_line`, but with deterministic
 /// timing + start_id derivation and no `/proc` reads (bench/test helper).
 fn parse_ps_line_synthetic(
     line: &str,
+    layout: &PsHeader,
     platform: &str,
     boot_id: &Option<String>,
     now_unix: i64,
 ) -> Result<ProcessRecord, String> {
-    parse_ps_line_with_timing(line, platform, boot_id, Some(now_unix))
+    parse_ps_line_with_timing(line, layout, platform, boot_id, Some(now_unix))
 }
 
 fn parse_ps_line_with_timing(
     line: &str,
+    layout: &PsHeader,
     platform: &str,
     boot_id: &Option<String>,
     synthetic_now_unix: Option<i64>,
 ) -> Result<ProcessRecord, String> {
-    let parsed = parse_ps_fields(line)?;
+    let parsed = parse_ps_fields(line, layout)?;
     let (start_time_unix, elapsed) = match synthetic_now_unix {
         Some(now_unix) => parse_timing_field_at(platform, parsed.etimes_str, now_unix)?,
         None => parse_timing_field(platform, parsed.etimes_str)?,
@@ -407,19 +440,18 @@ fn parse_ps_line_with_timing(
         None => compute_start_id(platform, boot_id, start_time_unix, elapsed, parsed.pid),
     };
 
-    let tty = if parsed.tty_raw == "?" || parsed.tty_raw == "-" {
-        None
-    } else {
-        Some(parsed.tty_raw.to_string())
-    };
+    let tty = parsed
+        .tty_raw
+        .filter(|tty| !matches!(*tty, "?" | "-" | "??"))
+        .map(str::to_string);
 
     Ok(ProcessRecord {
         pid: ProcessId(parsed.pid),
         ppid: ProcessId(parsed.ppid),
         uid: parsed.uid,
         user: parsed.user.to_string(),
-        pgid: Some(parsed.pgid),
-        sid: Some(parsed.sid),
+        pgid: parsed.pgid,
+        sid: parsed.sid,
         start_id,
         comm: parsed.comm.to_string(),
         cmd: parsed.cmd,
@@ -440,63 +472,60 @@ struct ParsedPsFields<'a> {
     ppid: u32,
     uid: u32,
     user: &'a str,
-    pgid: u32,
-    sid: u32,
+    pgid: Option<u32>,
+    sid: Option<u32>,
     state_char: char,
     cpu_percent: f64,
     rss_bytes: u64,
     vsz_bytes: u64,
-    tty_raw: &'a str,
+    tty_raw: Option<&'a str>,
     etimes_str: &'a str,
     comm: &'a str,
     cmd: String,
 }
 
-fn parse_ps_fields(line: &str) -> Result<ParsedPsFields<'_>, String> {
-    let mut fields = line.split_whitespace();
-    let mut seen = 0usize;
+fn parse_ps_fields<'a>(line: &'a str, layout: &PsHeader) -> Result<ParsedPsFields<'a>, String> {
+    let row = layout.split_row(line)?;
 
-    let pid: u32 = next_ps_field(&mut fields, &mut seen)?
+    let pid: u32 = row
+        .required(PsField::Pid)?
         .parse()
         .map_err(|_| "Invalid PID")?;
-    let ppid: u32 = next_ps_field(&mut fields, &mut seen)?
+    let ppid: u32 = row
+        .required(PsField::Ppid)?
         .parse()
         .map_err(|_| "Invalid PPID")?;
-    let uid: u32 = next_ps_field(&mut fields, &mut seen)?
+    let uid: u32 = row
+        .required(PsField::Uid)?
         .parse()
         .map_err(|_| "Invalid UID")?;
-    let user = next_ps_field(&mut fields, &mut seen)?;
-    let pgid: u32 = next_ps_field(&mut fields, &mut seen)?
-        .parse()
-        .map_err(|_| "Invalid PGID")?;
-    let sid: u32 = next_ps_field(&mut fields, &mut seen)?
-        .parse()
-        .map_err(|_| "Invalid SID")?;
-    let state_char = next_ps_field(&mut fields, &mut seen)?
-        .chars()
-        .next()
-        .unwrap_or('?');
-    let cpu_percent: f64 = next_ps_field(&mut fields, &mut seen)?
-        .parse()
+    let user = row.required(PsField::User)?;
+    let pgid = row
+        .get(PsField::Pgid)
+        .map(|v| v.parse::<u32>().map_err(|_| "Invalid PGID"))
+        .transpose()?;
+    let sid = row
+        .get(PsField::Sid)
+        .map(|v| v.parse::<u32>().map_err(|_| "Invalid SID"))
+        .transpose()?;
+    let state_char = row.required(PsField::State)?.chars().next().unwrap_or('?');
+    let cpu_percent: f64 = row
+        .get(PsField::CpuPercent)
+        .and_then(|v| v.parse().ok())
         .unwrap_or(0.0);
-    let rss_bytes = next_ps_field(&mut fields, &mut seen)?
-        .parse::<u64>()
-        .unwrap_or(0)
-        .saturating_mul(1024);
-    let vsz_bytes = next_ps_field(&mut fields, &mut seen)?
-        .parse::<u64>()
-        .unwrap_or(0)
-        .saturating_mul(1024);
-    let tty_raw = next_ps_field(&mut fields, &mut seen)?;
-
-    // Skip lstart's five whitespace-delimited fields; elapsed time comes from etimes/etime.
-    for _ in 0..5 {
-        let _ = next_ps_field(&mut fields, &mut seen)?;
-    }
-
-    let etimes_str = next_ps_field(&mut fields, &mut seen)?;
-    let comm = next_ps_field(&mut fields, &mut seen)?;
-    let cmd = command_from_tail(&mut fields, comm);
+    let kib = |field| {
+        row.get(field)
+            .and_then(|v| v.parse::<u64>().ok())
+            .unwrap_or(0)
+            .saturating_mul(1024)
+    };
+    let rss_bytes = kib(PsField::Rss);
+    let vsz_bytes = kib(PsField::Vsz);
+    let tty_raw = row.get(PsField::Tty);
+    // Elapsed time comes from etimes/etime; lstart is not parsed.
+    let etimes_str = row.required(PsField::Elapsed)?;
+    let comm = row.required(PsField::Comm)?;
+    let cmd = row.into_args().unwrap_or_else(|| comm.to_string());
 
     Ok(ParsedPsFields {
         pid,
@@ -516,30 +545,6 @@ fn parse_ps_fields(line: &str) -> Result<ParsedPsFields<'_>, String> {
     })
 }
 
-fn next_ps_field<'a>(
-    fields: &mut std::str::SplitWhitespace<'a>,
-    seen: &mut usize,
-) -> Result<&'a str, String> {
-    let field = fields
-        .next()
-        .ok_or_else(|| format!("Insufficient fields: expected at least 18, got {}", *seen))?;
-    *seen += 1;
-    Ok(field)
-}
-
-fn command_from_tail<'a>(fields: &mut std::str::SplitWhitespace<'a>, comm: &'a str) -> String {
-    let Some(first) = fields.next() else {
-        return comm.to_string();
-    };
-
-    let mut cmd = String::from(first);
-    for field in fields {
-        cmd.push(' ');
-        cmd.push_str(field);
-    }
-    cmd
-}
-
 /// Parse timing fields from ps output.
 fn parse_timing_field(_platform: &str, etimes_str: &str) -> Result<(i64, Duration), String> {
     let elapsed_secs: u64 = if etimes_str.contains(':') {
@@ -780,10 +785,10 @@ mod tests {
 
     #[test]
     fn test_header_detection() {
-        assert!(is_header_line("PID PPID UID USER"));
-        assert!(is_header_line("pid ppid uid user"));
-        assert!(!is_header_line("123 1 0 root"));
-        assert!(!is_header_line("999 42 1000 alice"));
+        assert!(PsHeader::parse("PID PPID UID USER").is_some());
+        assert!(PsHeader::parse("pid ppid uid user").is_some());
+        assert!(PsHeader::parse("123 1 0 root").is_none());
+        assert!(PsHeader::parse("999 42 1000 alice").is_none());
     }
 
     #[test]
@@ -819,7 +824,7 @@ mod tests {
         let line = "1234 1 1000 testuser 1234 1234 S 0.5 10240 20480 pts/0 Tue Jan 14 10:30:00 2026 3600 bash /bin/bash -c echo hello";
         let boot_id = Some("test-boot-id".to_string());
 
-        let result = parse_ps_line(line, "linux", &boot_id);
+        let result = parse_ps_line(line, &requested_layout(), "linux", &boot_id);
         assert!(result.is_ok(), "Parse failed: {:?}", result);
 
         let record = result.unwrap();
@@ -841,7 +846,7 @@ mod tests {
         let line = "  501   1  501 alice  501  501 S  0.0  8192 40960 ??  Mon Feb 24 09:00:00 2026 1:23:45 sleep sleep";
         let boot_id: Option<String> = None;
 
-        let result = parse_ps_line(line, "macos", &boot_id);
+        let result = parse_ps_line(line, &requested_layout(), "macos", &boot_id);
         assert!(
             result.is_ok(),
             "macOS short line parse failed: {:?}",
@@ -862,7 +867,7 @@ mod tests {
         let line = "1 0 0 root 1 1 S 0.0 0 0 ? Thu Feb 20 00:00:00 2026 3600 init";
         let boot_id = Some("test".to_string());
 
-        let result = parse_ps_line(line, "linux", &boot_id);
+        let result = parse_ps_line(line, &requested_layout(), "linux", &boot_id);
         assert!(result.is_ok(), "Minimal field parse failed: {:?}", result);
 
         let record = result.unwrap();
@@ -872,6 +877,34 @@ mod tests {
         assert_eq!(record.cmd, "init");
     }
 
+    #[test]
+    fn test_parse_ps_output_with_bsd_header_and_extra_column() {
+        // BSD labels, no TT column, and an extra PRI column in the middle.
+        let output = "\
+  PID  PPID   UID USER   PGID   SESS PRI STAT  %CPU    RSS      VSZ STARTED                      ELAPSED COMM ARGS
+  501     1   501 alice   501      0  31 Ss     0.0   8192    40960 Mon Feb 24 09:00:00 2026     01:23:45 zsh -zsh
+";
+        let records = parse_ps_output_synthetic_linux(output).unwrap();
+        assert_eq!(records.len(), 1);
+        let record = &records[0];
+        assert_eq!(record.pid.0, 501);
+        assert_eq!(record.uid, 501);
+        assert_eq!(record.sid, Some(0));
+        assert_eq!(record.state, ProcessState::Sleeping);
+        assert_eq!(record.rss_bytes, 8192 * 1024);
+        assert_eq!(record.tty, None);
+        assert_eq!(record.elapsed.as_secs(), 5025);
+        assert_eq!(record.comm, "zsh");
+        assert_eq!(record.cmd, "-zsh");
+    }
+
+    #[test]
+    fn test_parse_ps_output_missing_required_column() {
+        let output = "PID PPID COMM\n7 1 sleep\n";
+        let err = parse_ps_output_synthetic_linux(output).unwrap_err();
+        assert!(err.to_string().contains("no UID column"), "{err}");
+    }
+
     // =====================================================
     // No-mock tests using real processes
     // =====================================================
@@ -0,0 +1,158 @@
+//! Service-manager attribution behind a platform-neutral trait.
+//!
+//! Call sites ask a [`ServiceContext`] which service unit (if any) owns a
+//! process without `cfg`-gating on the target OS:
+//! - Linux: [`SystemdServiceContext`] reads the unit from `/proc/<pid>/cgroup`
+//!   and enriches it via `systemctl show`
+//! - Elsewhere: [`NoServiceContext`] reports that no service manager is
+//!   available
+//!
+//! [`PlatformServiceContext`] names the implementation for the build target.
+
+#[cfg(target_os = "linux")]
+use crate::collect::systemd::collect_systemd_unit;
+use crate::collect::systemd::SystemdUnit;
+
+/// Outcome of looking up the service unit that owns a process.
+#[derive(Debug, Clone)]
+pub enum ServiceLookup {
+    /// No supported service manager exists on this platform.
+    NoServiceManager,
+    /// A service manager exists but the process is not attributed to a unit.
+    Unmanaged,
+    /// The process belongs to this unit.
+    Unit(SystemdUnit),
+}
+
+impl ServiceLookup {
+    /// The owning unit, if one was found.
+    pub fn unit(self) -> Option<SystemdUnit> {
+        match self {
+            ServiceLookup::Unit(unit) => Some(unit),
+            ServiceLookup::NoServiceManager | ServiceLookup::Unmanaged => None,
+        }
+    }
+}
+
+/// Attributes processes to service-manager units.
+pub trait ServiceContext: Send + Sync {
+    /// Name of the service manager (e.g., "systemd"), or `None` when absent.
+    fn manager_name(&self) -> Option<&'static str>;
+
+    /// Look up the unit that owns `pid`.
+    fn lookup(&self, pid: u32) -> ServiceLookup;
+}
+
+/// systemd-backed service attribution (Linux only).
+#[cfg(target_os = "linux")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemdServiceContext;
+
+#[cfg(target_os = "linux")]
+impl ServiceContext for SystemdServiceContext {
+    fn manager_name(&self) -> Option<&'static str> {
+        Some("systemd")
+    }
+
+    fn lookup(&self, pid: u32) -> ServiceLookup {
+        let cgroup_unit = std::fs::read(format!("/proc/{pid}/cgroup"))
+            .ok()
+            .and_then(|bytes| parse_cgroup_unit(&String::from_utf8_lossy(&bytes)));
+        match collect_systemd_unit(pid, cgroup_unit.as_deref()) {
+            Some(unit) => ServiceLookup::Unit(unit),
+            None => ServiceLookup::Unmanaged,
+        }
+    }
+}
+
+/// Fallback for platforms without a supported service manager.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoServiceContext;
+
+impl ServiceContext for NoServiceContext {
+    fn manager_name(&self) -> Option<&'static str> {
+        None
+    }
+
+    fn lookup(&self, _pid: u32) -> ServiceLookup {
+        ServiceLookup::NoServiceManager
+    }
+}
+
+/// Service context implementation for the build target.
+#[cfg(target_os = "linux")]
+pub type PlatformServiceContext = SystemdServiceContext;
+
+/// Service context implementation for the build target.
+#[cfg(not(target_os = "linux"))]
+pub type PlatformServiceContext = NoServiceContext;
+
+/// Extract the `.service` or `.scope` unit name from `/proc/<pid>/cgroup`
+/// content. Slices are skipped since they are not real supervision.
+pub fn parse_cgroup_unit(content: &str) -> Option<String> {
+    content
+        .lines()
+        .filter(|line| line.contains(".service") || line.contains(".scope"))
+        .filter_map(|line| line.rfind('/').map(|start| &line[start + 1..]))
+        .find(|unit| !unit.is_empty())
+        .map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_cgroup_unit_v2_service() {
+        let content = "0::/system.slice/nginx.service\n";
+        assert_eq!(parse_cgroup_unit(content).as_deref(), Some("nginx.service"));
+    }
+
+    #[test]
+    fn parse_cgroup_unit_skips_slices() {
+        let content = "0::/user.slice/user-1000.slice\n";
+        assert_eq!(parse_cgroup_unit(content), None);
+    }
+
+    #[test]
+    fn parse_cgroup_unit_v1_scope() {
+        let content = "12:pids:/user.slice/user-1000.slice/session-2.scope\n\
+                       1:name=systemd:/user.slice/user-1000.slice/session-2.scope\n";
+        assert_eq!(
+            parse_cgroup_unit(content).as_deref(),
+            Some("session-2.scope")
+        );
+    }
+
+    #[test]
+    fn no_service_context_reports_no_manager() {
+        let ctx = NoServiceContext;
+        assert_eq!(ctx.manager_name(), None);
+        assert!(matches!(ctx.lookup(1), ServiceLookup::NoServiceManager));
+        assert!(ctx.lookup(1).unit().is_none());
+    }
+
+    #[test]
+    fn platform_context_usable_as_trait_object() {
+        let ctx: Box<dyn ServiceContext> = Box::new(PlatformServiceContext::default());
+        // Must not panic for our own pid on any platform.
+        let _ = ctx.lookup(std::process::id());
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn systemd_context_names_manager() {
+        assert_eq!(SystemdServiceContext.manager_name(), Some("systemd"));
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    #[test]
+    fn platform_context_is_fallback_off_linux() {
+        let ctx = PlatformServiceContext::default();
+        assert_eq!(ctx.manager_name(), None);
+        assert!(matches!(
+            ctx.lookup(std::process::id()),
+            ServiceLookup::NoServiceManager
+        ));
+    }
+}
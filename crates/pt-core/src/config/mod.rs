@@ -1,8 +1,9 @@
 //! Configuration loading and validation for pt-core.
 //!
 //! This module handles:
-//! - Loading priors.json and policy.json files
+//! - Loading priors.json and policy.json files (or YAML, by extension)
 //! - Config resolution order (CLI > env > XDG > defaults)
+//! - Layering configuration presets beneath the policy file
 //! - Schema validation (shape/type checking via serde)
 //! - Semantic validation (probability sums, positive params)
 //! - Config snapshot generation for session artifacts
@@ -10,15 +11,21 @@
 // Re-export types from pt-config
 pub use pt_config::policy;
 pub use pt_config::priors;
+pub use pt_config::Class;
 
 pub use policy::Policy;
 pub use priors::Priors;
 
-pub use pt_config::validate::ValidationError;
+use pt_config::env_expand::{expand_env_str_with, expand_env_vars_with};
+pub use pt_config::validate::{loss_matrix_findings, ValidationError};
 use pt_config::validate::{validate_policy, validate_priors};
+use pt_config::ConfigFormat;
 
 // Re-export preset types
-pub use pt_config::preset::{get_preset, list_presets, PresetError, PresetInfo, PresetName};
+pub use pt_config::preset::{
+    get_preset, list_presets, resolve_with_presets, PresetDiff, PresetError, PresetFieldDiff,
+    PresetInfo, PresetName,
+};
 pub use pt_config::provenance::{
     EffectiveProvenanceControls, ProvenanceCollectionDepth, ProvenanceControlSurface,
     ProvenanceControlSurfaceKind, ProvenanceControls, ProvenanceExecutionContext,
@@ -28,7 +35,8 @@ pub use pt_config::provenance::{
 
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
 use thiserror::Error;
 
 /// Schema version for configuration files.
@@ -50,6 +58,9 @@ pub enum ConfigError {
         source: serde_json::Error,
     },
 
+    #[error("Invalid YAML in config file {path}: {message}")]
+    YamlParseError { path: PathBuf, message: String },
+
     #[error("Schema validation failed for {path}: {message}")]
     SchemaError { path: PathBuf, message: String },
 
@@ -65,6 +76,9 @@ pub enum ConfigError {
 
     #[error("Schema version mismatch: expected {expected}, got {actual}")]
     VersionMismatch { expected: String, actual: String },
+
+    #[error("Preset layering failed: {0}")]
+    Preset(#[from] PresetError),
 }
 
 /// Resolved configuration with provenance information.
@@ -115,8 +129,76 @@ pub struct ConfigSnapshot {
     pub config_dir: PathBuf,
 }
 
+/// Resolution layer that supplied a configuration value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfigLayer {
+    /// Explicit CLI flag.
+    Cli,
+    /// `PROCESS_TRIAGE_CONFIG` environment variable.
+    Environment,
+    /// XDG config home.
+    Xdg,
+    /// Configuration preset layered beneath the policy file.
+    Preset,
+    /// Built-in defaults.
+    Default,
+}
+
+/// A value offered by one resolution layer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct LayerValue {
+    pub layer: ConfigLayer,
+    /// Directory or file the layer pointed at (None for built-in defaults).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<PathBuf>,
+}
+
+/// How one resolved field was chosen.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct FieldTrace {
+    /// Field name (`config_dir`, `priors`, or `policy`).
+    pub field: String,
+    /// Layer whose value was used.
+    pub winner: LayerValue,
+    /// Layers that offered a value but lost.
+    pub overridden: Vec<LayerValue>,
+}
+
+/// Per-field provenance for a configuration resolution.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ResolutionTrace {
+    pub fields: Vec<FieldTrace>,
+    /// Layer that supplied each resolved priors and policy value, keyed by
+    /// dotted path (e.g. `policy.guardrails.max_kills_per_run`). Values a
+    /// loaded file omits are attributed to the presets when one of them
+    /// changed the value, otherwise to built-in defaults; arrays are
+    /// reported as a whole.
+    pub values: BTreeMap<String, LayerValue>,
+}
+
+impl ResolutionTrace {
+    /// Trace for a field by name.
+    pub fn field(&self, name: &str) -> Option<&FieldTrace> {
+        self.fields.iter().find(|f| f.field == name)
+    }
+
+    /// Layer that supplied a value by dotted path.
+    pub fn value(&self, path: &str) -> Option<&LayerValue> {
+        self.values.get(path)
+    }
+
+    /// Values supplied by a config file rather than built-in defaults.
+    pub fn file_values(&self) -> impl Iterator<Item = (&str, &LayerValue)> {
+        self.values
+            .iter()
+            .filter(|(_, origin)| origin.layer != ConfigLayer::Default)
+            .map(|(path, origin)| (path.as_str(), origin))
+    }
+}
+
 /// Configuration resolution options.
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct ConfigOptions {
     /// Explicit config directory (highest priority).
     pub config_dir: Option<PathBuf>,
@@ -124,6 +206,14 @@ pub struct ConfigOptions {
     pub priors_path: Option<PathBuf>,
     /// Explicit policy file path.
     pub policy_path: Option<PathBuf>,
+    /// Expand `${VAR}` references in config paths (explicit or from
+    /// `PROCESS_TRIAGE_CONFIG`/`XDG_CONFIG_HOME`) and in string values of
+    /// the loaded files. See [`pt_config::env_expand`].
+    pub expand_env: bool,
+    /// Presets layered, in order, beneath the policy file, which then only
+    /// needs the fields it changes. Empty uses the file (or built-in
+    /// defaults) alone. See [`resolve_with_presets`].
+    pub presets: Vec<PresetName>,
 }
 
 /// Load configuration with the standard resolution order.
@@ -133,14 +223,277 @@ pub struct ConfigOptions {
 /// 2. Environment variables (PROCESS_TRIAGE_CONFIG)
 /// 3. XDG config home (~/.config/process_triage/)
 /// 4. Built-in defaults
+///
+/// Steps 1-3 pick the policy file; [`ConfigOptions::presets`], when set,
+/// are layered beneath whichever file wins, above the built-in defaults.
 pub fn load_config(options: &ConfigOptions) -> Result<ResolvedConfig, ConfigError> {
-    let config_dir = resolve_config_dir(options)?;
+    load_config_with(options, &env_var)
+}
+
+/// Load configuration and record which layer supplied each field.
+///
+/// Resolution is identical to [`load_config`]; the trace is read-only
+/// introspection for explaining surprising values.
+pub fn resolve_config_traced(
+    options: &ConfigOptions,
+) -> Result<(ResolvedConfig, ResolutionTrace), ConfigError> {
+    resolve_config_traced_with(options, &env_var)
+}
+
+fn env_var(name: &str) -> Option<String> {
+    std::env::var(name).ok()
+}
+
+fn resolve_config_traced_with(
+    options: &ConfigOptions,
+    lookup: &dyn Fn(&str) -> Option<String>,
+) -> Result<(ResolvedConfig, ResolutionTrace), ConfigError> {
+    let options = &expand_options(options, lookup)?;
+    let config = load_expanded_config(options, lookup)?;
+    let dirs = config_dir_candidates(options, lookup)?;
+
+    let mut dir_values = dirs.iter().map(|(layer, dir)| LayerValue {
+        layer: *layer,
+        path: Some(dir.clone()),
+    });
+    let config_dir = FieldTrace {
+        field: "config_dir".to_string(),
+        winner: dir_values.next().expect("XDG layer is always present"),
+        overridden: dir_values.collect(),
+    };
+
+    let priors = trace_file(
+        "priors",
+        "priors.json",
+        options.priors_path.as_ref(),
+        &dirs,
+        config.priors_path.as_ref(),
+    );
+    let policy = trace_file(
+        "policy",
+        "policy.json",
+        options.policy_path.as_ref(),
+        &dirs,
+        config.policy_path.as_ref(),
+    );
+
+    let default_origin = |_: &str| LayerValue {
+        layer: ConfigLayer::Default,
+        path: None,
+    };
+    let preset_values = if options.presets.is_empty() {
+        None
+    } else {
+        let to_value =
+            |policy: Policy| serde_json::to_value(policy).expect("policy serializes to JSON");
+        Some((
+            to_value(resolve_with_presets(&options.presets, None)?),
+            to_value(Policy::default()),
+        ))
+    };
+    let policy_origin = |suffix: &str| match &preset_values {
+        Some((presets, defaults)) if field_at(presets, suffix) != field_at(defaults, suffix) => {
+            LayerValue {
+                layer: ConfigLayer::Preset,
+                path: None,
+            }
+        }
+        _ => default_origin(suffix),
+    };
+
+    let mut values = BTreeMap::new();
+    trace_values(
+        "priors",
+        &config.priors,
+        &priors.winner,
+        &default_origin,
+        &mut values,
+    )?;
+    trace_values(
+        "policy",
+        &config.policy,
+        &policy.winner,
+        &policy_origin,
+        &mut values,
+    )?;
+
+    let trace = ResolutionTrace {
+        fields: vec![config_dir, priors, policy],
+        values,
+    };
+
+    Ok((config, trace))
+}
+
+/// Attribute every value of a resolved config to the layer whose file set
+/// it, or to `unset(suffix)` when the file omits it.
+fn trace_values<T: Serialize>(
+    prefix: &str,
+    resolved: &T,
+    winner: &LayerValue,
+    unset: &dyn Fn(&str) -> LayerValue,
+    values: &mut BTreeMap<String, LayerValue>,
+) -> Result<(), ConfigError> {
+    let raw = match &winner.path {
+        Some(path) => {
+            let content = std::fs::read_to_string(path).map_err(|e| ConfigError::IoError {
+                path: path.clone(),
+                source: e,
+            })?;
+            Some(parse_raw_config_file::<serde_json::Value>(path, &content)?)
+        }
+        None => None,
+    };
+    let resolved = serde_json::to_value(resolved).expect("config types serialize to JSON");
+
+    let mut leaves = Vec::new();
+    collect_leaves(prefix, &resolved, &mut leaves);
+    for path in leaves {
+        let suffix = &path[prefix.len()..];
+        let set_in_file = raw.as_ref().is_some_and(|raw| sets_field(raw, suffix));
+        let origin = if set_in_file {
+            winner.clone()
+        } else {
+            unset(suffix)
+        };
+        values.insert(path, origin);
+    }
+    Ok(())
+}
+
+/// Value at `suffix` (`.a.b`) within `value`, if present.
+fn field_at<'a>(value: &'a serde_json::Value, suffix: &str) -> Option<&'a serde_json::Value> {
+    suffix
+        .split('.')
+        .skip(1)
+        .try_fold(value, |current, key| current.get(key))
+}
+
+/// Dotted paths of every non-object value under `value`.
+fn collect_leaves(path: &str, value: &serde_json::Value, out: &mut Vec<String>) {
+    match value {
+        serde_json::Value::Object(map) if !map.is_empty() => {
+            for (key, child) in map {
+                collect_leaves(&format!("{}.{}", path, key), child, out);
+            }
+        }
+        _ => out.push(path.to_string()),
+    }
+}
+
+/// Whether the raw file `value` sets the field at `suffix` (`.a.b`). A
+/// non-object value on the way (e.g. an explicit `null`) sets everything
+/// below it.
+fn sets_field(value: &serde_json::Value, suffix: &str) -> bool {
+    let mut current = value;
+    for key in suffix.split('.').skip(1) {
+        match current {
+            serde_json::Value::Object(map) => match map.get(key) {
+                Some(child) => current = child,
+                None => return false,
+            },
+            _ => return true,
+        }
+    }
+    true
+}
+
+/// Trace a config file: every layer that had the file is a candidate, and
+/// the one matching the path actually loaded wins.
+fn trace_file(
+    field: &str,
+    filename: &str,
+    explicit_path: Option<&PathBuf>,
+    dirs: &[(ConfigLayer, PathBuf)],
+    resolved: Option<&PathBuf>,
+) -> FieldTrace {
+    let mut candidates: Vec<LayerValue> = explicit_path
+        .map(|path| LayerValue {
+            layer: ConfigLayer::Cli,
+            path: Some(path.clone()),
+        })
+        .into_iter()
+        .collect();
+    candidates.extend(
+        dirs.iter()
+            .map(|(layer, dir)| (*layer, dir.join(filename)))
+            .filter(|(_, path)| path.exists())
+            .map(|(layer, path)| LayerValue {
+                layer,
+                path: Some(path),
+            }),
+    );
+
+    let winner = match candidates.iter().position(|c| c.path.as_ref() == resolved) {
+        Some(idx) => candidates.remove(idx),
+        None => LayerValue {
+            layer: ConfigLayer::Default,
+            path: None,
+        },
+    };
+
+    FieldTrace {
+        field: field.to_string(),
+        winner,
+        overridden: candidates,
+    }
+}
+
+fn load_config_with(
+    options: &ConfigOptions,
+    lookup: &dyn Fn(&str) -> Option<String>,
+) -> Result<ResolvedConfig, ConfigError> {
+    load_expanded_config(&expand_options(options, lookup)?, lookup)
+}
+
+/// `options` with `${VAR}` references in explicit paths expanded, when
+/// [`ConfigOptions::expand_env`] is set.
+fn expand_options(
+    options: &ConfigOptions,
+    lookup: &dyn Fn(&str) -> Option<String>,
+) -> Result<ConfigOptions, ConfigError> {
+    let mut expanded = options.clone();
+    if options.expand_env {
+        for (field, path) in [
+            ("config_dir", &mut expanded.config_dir),
+            ("priors_path", &mut expanded.priors_path),
+            ("policy_path", &mut expanded.policy_path),
+        ] {
+            if let Some(path) = path.as_mut() {
+                *path = expand_path(path.as_os_str(), field, lookup)?;
+            }
+        }
+    }
+    Ok(expanded)
+}
+
+/// Expand `${VAR}` references in a path. Non-UTF-8 paths are returned as is.
+fn expand_path(
+    path: &std::ffi::OsStr,
+    field: &str,
+    lookup: &dyn Fn(&str) -> Option<String>,
+) -> Result<PathBuf, ConfigError> {
+    match path.to_str() {
+        Some(text) => Ok(PathBuf::from(expand_env_str_with(text, field, lookup)?)),
+        None => Ok(PathBuf::from(path)),
+    }
+}
+
+/// Load configuration from options whose paths are already expanded.
+fn load_expanded_config(
+    options: &ConfigOptions,
+    lookup: &dyn Fn(&str) -> Option<String>,
+) -> Result<ResolvedConfig, ConfigError> {
+    let config_dir = resolve_config_dir(options, lookup)?;
+    let expand = options.expand_env.then_some(lookup);
 
     // Load priors
-    let (priors, priors_path, priors_hash) = load_priors(&config_dir, &options.priors_path)?;
+    let (priors, priors_path, priors_hash) =
+        load_priors(&config_dir, &options.priors_path, expand)?;
 
     // Load policy
-    let (policy, policy_path, policy_hash) = load_policy(&config_dir, &options.policy_path)?;
+    let (policy, policy_path, policy_hash) =
+        load_policy(&config_dir, &options.policy_path, &options.presets, expand)?;
 
     // Validate the configuration semantically
     validate_priors(&priors)?;
@@ -158,44 +511,67 @@ pub fn load_config(options: &ConfigOptions) -> Result<ResolvedConfig, ConfigErro
 }
 
 /// Resolve the config directory using the standard resolution order.
-fn resolve_config_dir(options: &ConfigOptions) -> Result<PathBuf, ConfigError> {
+fn resolve_config_dir(
+    options: &ConfigOptions,
+    lookup: &dyn Fn(&str) -> Option<String>,
+) -> Result<PathBuf, ConfigError> {
+    let (_, dir) = config_dir_candidates(options, lookup)?
+        .into_iter()
+        .next()
+        .expect("XDG layer is always present");
+    Ok(dir)
+}
+
+/// Config directories offered by each layer, highest priority first.
+fn config_dir_candidates(
+    options: &ConfigOptions,
+    lookup: &dyn Fn(&str) -> Option<String>,
+) -> Result<Vec<(ConfigLayer, PathBuf)>, ConfigError> {
+    let mut candidates = Vec::new();
+    let env_dir = |name: &str| -> Result<Option<PathBuf>, ConfigError> {
+        match lookup(name) {
+            Some(dir) if options.expand_env => expand_path(dir.as_ref(), name, lookup).map(Some),
+            dir => Ok(dir.map(PathBuf::from)),
+        }
+    };
+
     // 1. Explicit option
     if let Some(dir) = &options.config_dir {
-        return Ok(dir.clone());
+        candidates.push((ConfigLayer::Cli, dir.clone()));
     }
 
     // 2. Environment variable
-    if let Ok(dir) = std::env::var("PROCESS_TRIAGE_CONFIG") {
-        return Ok(PathBuf::from(dir));
+    if let Some(dir) = env_dir("PROCESS_TRIAGE_CONFIG")? {
+        candidates.push((ConfigLayer::Environment, dir));
     }
 
     // 3. XDG config home
-    let xdg_config = std::env::var("XDG_CONFIG_HOME")
-        .map(PathBuf::from)
-        .unwrap_or_else(|_| {
-            dirs::home_dir()
-                .unwrap_or_else(|| PathBuf::from("."))
-                .join(".config")
-        });
+    let xdg_config = env_dir("XDG_CONFIG_HOME")?.unwrap_or_else(|| {
+        dirs::home_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join(".config")
+    });
+    candidates.push((ConfigLayer::Xdg, xdg_config.join(CONFIG_DIR_NAME)));
 
-    Ok(xdg_config.join(CONFIG_DIR_NAME))
+    Ok(candidates)
 }
 
 /// Load priors configuration.
 fn load_priors(
     config_dir: &std::path::Path,
     explicit_path: &Option<PathBuf>,
+    expand: Option<&dyn Fn(&str) -> Option<String>>,
 ) -> Result<(Priors, Option<PathBuf>, Option<String>), ConfigError> {
     // Try explicit path first
     if let Some(path) = explicit_path {
-        let (priors, hash) = load_priors_from_file(path)?;
+        let (priors, hash) = load_priors_from_file(path, expand)?;
         return Ok((priors, Some(path.clone()), Some(hash)));
     }
 
     // Try config directory
     let default_path = config_dir.join("priors.json");
     if default_path.exists() {
-        let (priors, hash) = load_priors_from_file(&default_path)?;
+        let (priors, hash) = load_priors_from_file(&default_path, expand)?;
         return Ok((priors, Some(default_path), Some(hash)));
     }
 
@@ -204,29 +580,69 @@ fn load_priors(
 }
 
 /// Load policy configuration.
+///
+/// With `presets`, the policy file (if any) is a partial override merged
+/// on top of them instead of a complete policy.
 fn load_policy(
     config_dir: &std::path::Path,
     explicit_path: &Option<PathBuf>,
+    presets: &[PresetName],
+    expand: Option<&dyn Fn(&str) -> Option<String>>,
 ) -> Result<(Policy, Option<PathBuf>, Option<String>), ConfigError> {
-    // Try explicit path first
-    if let Some(path) = explicit_path {
-        let (policy, hash) = load_policy_from_file(path)?;
-        return Ok((policy, Some(path.clone()), Some(hash)));
+    // Try explicit path first, then the config directory
+    let path = match explicit_path {
+        Some(path) => Some(path.clone()),
+        None => Some(config_dir.join("policy.json")).filter(|path| path.exists()),
+    };
+
+    if !presets.is_empty() {
+        return load_layered_policy(path, presets, expand);
     }
 
-    // Try config directory
-    let default_path = config_dir.join("policy.json");
-    if default_path.exists() {
-        let (policy, hash) = load_policy_from_file(&default_path)?;
-        return Ok((policy, Some(default_path), Some(hash)));
+    match path {
+        Some(path) => {
+            let (policy, hash) = load_policy_from_file(&path, expand)?;
+            Ok((policy, Some(path), Some(hash)))
+        }
+        // Fall back to defaults
+        None => Ok((Policy::default(), None, None)),
     }
+}
 
-    // Fall back to defaults
-    Ok((Policy::default(), None, None))
+/// Load the policy file at `path` as overrides on top of `presets`.
+fn load_layered_policy(
+    path: Option<PathBuf>,
+    presets: &[PresetName],
+    expand: Option<&dyn Fn(&str) -> Option<String>>,
+) -> Result<(Policy, Option<PathBuf>, Option<String>), ConfigError> {
+    let (overrides, hash) = match &path {
+        Some(path) => {
+            let content = std::fs::read_to_string(path).map_err(|e| ConfigError::IoError {
+                path: path.clone(),
+                source: e,
+            })?;
+            let overrides: serde_json::Value = parse_config_file(path, &content, expand)?;
+            (Some(overrides), Some(compute_hash(&content)))
+        }
+        None => (None, None),
+    };
+
+    let policy = resolve_with_presets(presets, overrides.as_ref())?;
+    if policy.schema_version != CONFIG_SCHEMA_VERSION {
+        return Err(ConfigError::VersionMismatch {
+            expected: CONFIG_SCHEMA_VERSION.to_string(),
+            actual: policy.schema_version,
+        });
+    }
+
+    Ok((policy, path, hash))
 }
 
 /// Load priors from a specific file.
-fn load_priors_from_file(path: &PathBuf) -> Result<(Priors, String), ConfigError> {
+fn load_priors_from_file(
+    path: &PathBuf,
+    expand: Option<&dyn Fn(&str) -> Option<String>>,
+) -> Result<(Priors, String), ConfigError> {
     let content = std::fs::read_to_string(path).map_err(|e| ConfigError::IoError {
         path: path.clone(),
         source: e,
@@ -234,10 +650,7 @@ fn load_priors_from_file(path: &PathBuf) -> Result<(Priors, String), ConfigError
 
     let hash = compute_hash(&content);
 
-    let priors: Priors = serde_json::from_str(&content).map_err(|e| ConfigError::ParseError {
-        path: path.clone(),
-        source: e,
-    })?;
+    let priors: Priors = parse_config_file(path, &content, expand)?;
 
     // Check schema version
     if priors.schema_version != CONFIG_SCHEMA_VERSION {
@@ -251,7 +664,10 @@ fn load_priors_from_file(path: &PathBuf) -> Result<(Priors, String), ConfigError
 }
 
 /// Load policy from a specific file.
-fn load_policy_from_file(path: &PathBuf) -> Result<(Policy, String), ConfigError> {
+fn load_policy_from_file(
+    path: &PathBuf,
+    expand: Option<&dyn Fn(&str) -> Option<String>>,
+) -> Result<(Policy, String), ConfigError> {
     let content = std::fs::read_to_string(path).map_err(|e| ConfigError::IoError {
         path: path.clone(),
         source: e,
@@ -259,10 +675,7 @@ fn load_policy_from_file(path: &PathBuf) -> Result<(Policy, String), ConfigError
 
     let hash = compute_hash(&content);
 
-    let policy: Policy = serde_json::from_str(&content).map_err(|e| ConfigError::ParseError {
-        path: path.clone(),
-        source: e,
-    })?;
+    let policy: Policy = parse_config_file(path, &content, expand)?;
 
     // Check schema version
     if policy.schema_version != CONFIG_SCHEMA_VERSION {
@@ -275,6 +688,48 @@ fn load_policy_from_file(path: &PathBuf) -> Result<(Policy, String), ConfigError
     Ok((policy, hash))
 }
 
+/// Deserialize a config file, as YAML when its extension is `.yaml`/`.yml`.
+///
+/// With `expand`, `${VAR}` references in string values are resolved through
+/// it before deserialization.
+fn parse_config_file<T: serde::de::DeserializeOwned>(
+    path: &Path,
+    content: &str,
+    expand: Option<&dyn Fn(&str) -> Option<String>>,
+) -> Result<T, ConfigError> {
+    let Some(lookup) = expand else {
+        return parse_raw_config_file(path, content);
+    };
+    let mut value: serde_json::Value = parse_raw_config_file(path, content)?;
+    expand_env_vars_with(&mut value, lookup)?;
+    serde_json::from_value(value).map_err(|e| ConfigError::SchemaError {
+        path: path.to_path_buf(),
+        message: e.to_string(),
+    })
+}
+
+fn parse_raw_config_file<T: serde::de::DeserializeOwned>(
+    path: &Path,
+    content: &str,
+) -> Result<T, ConfigError> {
+    match ConfigFormat::from_path(path) {
+        ConfigFormat::Json => serde_json::from_str(content).map_err(|e| ConfigError::ParseError {
+            path: path.to_path_buf(),
+            source: e,
+        }),
+        ConfigFormat::Yaml => ConfigFormat::Yaml.parse(content).map_err(|e| {
+            let message = match e {
+                ValidationError::ParseError(message) => message,
+                other => other.to_string(),
+            };
+            ConfigError::YamlParseError {
+                path: path.to_path_buf(),
+                message,
+            }
+        }),
+    }
+}
+
 /// Compute SHA-256 hash of content (simplified - uses built-in hasher for now).
 fn compute_hash(content: &str) -> String {
     use std::collections::hash_map::DefaultHasher;
@@ -299,6 +754,8 @@ mod tests {
             config_dir: Some(temp_dir),
             priors_path: None,
             policy_path: None,
+            expand_env: false,
+            presets: Vec::new(),
         }
     }
 
@@ -319,4 +776,257 @@ mod tests {
         let json = serde_json::to_string(&snapshot);
         assert!(json.is_ok());
     }
+
+    #[test]
+    fn test_trace_attributes_env_override_of_xdg() {
+        let xdg_home = tempfile::tempdir().unwrap();
+        let xdg_dir = xdg_home.path().join(CONFIG_DIR_NAME);
+        std::fs::create_dir_all(&xdg_dir).unwrap();
+        let env_dir = tempfile::tempdir().unwrap();
+
+        let policy = serde_json::to_string(&Policy::default()).unwrap();
+        std::fs::write(xdg_dir.join("policy.json"), &policy).unwrap();
+        std::fs::write(env_dir.path().join("policy.json"), &policy).unwrap();
+
+        let xdg_var = xdg_home.path().display().to_string();
+        let env_var = env_dir.path().display().to_string();
+        let lookup = |name: &str| match name {
+            "XDG_CONFIG_HOME" => Some(xdg_var.clone()),
+            "PROCESS_TRIAGE_CONFIG" => Some(env_var.clone()),
+            _ => None,
+        };
+
+        let options = ConfigOptions::default();
+        let (config, trace) = resolve_config_traced_with(&options, &lookup).unwrap();
+        assert_eq!(
+            config.policy_path.as_deref(),
+            Some(env_dir.path().join("policy.json").as_path())
+        );
+
+        let dir = trace.field("config_dir").unwrap();
+        assert_eq!(dir.winner.layer, ConfigLayer::Environment);
+        assert_eq!(dir.overridden[0].layer, ConfigLayer::Xdg);
+
+        let policy = trace.field("policy").unwrap();
+        assert_eq!(policy.winner.layer, ConfigLayer::Environment);
+        assert_eq!(
+            policy.overridden,
+            vec![LayerValue {
+                layer: ConfigLayer::Xdg,
+                path: Some(xdg_dir.join("policy.json")),
+            }]
+        );
+
+        let priors = trace.field("priors").unwrap();
+        assert_eq!(priors.winner.layer, ConfigLayer::Default);
+        assert!(priors.overridden.is_empty());
+
+        let json = serde_json::to_value(&trace).unwrap();
+        assert_eq!(json["fields"][2]["winner"]["layer"], "environment");
+
+        let max_kills = trace.value("policy.guardrails.max_kills_per_run").unwrap();
+        assert_eq!(max_kills.layer, ConfigLayer::Environment);
+        assert_eq!(
+            max_kills.path.as_deref(),
+            Some(env_dir.path().join("policy.json").as_path())
+        );
+        let prior = trace.value("priors.classes.zombie.prior_prob").unwrap();
+        assert_eq!(prior.layer, ConfigLayer::Default);
+    }
+
+    #[test]
+    fn test_env_expansion_applies_to_paths_and_values() {
+        let dir = tempfile::tempdir().unwrap();
+        let config_dir = dir.path().join("pt");
+        std::fs::create_dir_all(&config_dir).unwrap();
+        let mut policy = serde_json::to_value(Policy::default()).unwrap();
+        policy["notes"] = serde_json::json!("owner ${PT_OWNER}");
+        std::fs::write(config_dir.join("policy.json"), policy.to_string()).unwrap();
+
+        let root = dir.path().display().to_string();
+        let lookup = |name: &str| match name {
+            "PT_ROOT" => Some(root.clone()),
+            "PT_OWNER" => Some("ops".to_string()),
+            "PROCESS_TRIAGE_CONFIG" => Some("${PT_ROOT}/pt".to_string()),
+            _ => None,
+        };
+
+        let options = ConfigOptions {
+            expand_env: true,
+            ..Default::default()
+        };
+        let (config, trace) = resolve_config_traced_with(&options, &lookup).unwrap();
+        assert_eq!(config.config_dir, config_dir);
+        assert_eq!(config.policy.notes.as_deref(), Some("owner ops"));
+        assert_eq!(
+            trace.field("policy").unwrap().winner.layer,
+            ConfigLayer::Environment
+        );
+
+        let explicit = ConfigOptions {
+            policy_path: Some(PathBuf::from("${PT_ROOT}/pt/policy.json")),
+            expand_env: true,
+            ..Default::default()
+        };
+        let config = load_config_with(&explicit, &lookup).unwrap();
+        assert_eq!(config.policy_path, Some(config_dir.join("policy.json")));
+
+        // Off by default: references stay literal.
+        let literal = load_config_with(
+            &ConfigOptions {
+                config_dir: Some(config_dir.clone()),
+                ..Default::default()
+            },
+            &lookup,
+        )
+        .unwrap();
+        assert_eq!(literal.policy.notes.as_deref(), Some("owner ${PT_OWNER}"));
+    }
+
+    #[test]
+    fn test_trace_values_distinguish_file_fields_from_defaults() {
+        let dir = tempfile::tempdir().unwrap();
+        let policy_path = dir.path().join("policy.json");
+        let mut policy = serde_json::to_value(Policy::default()).unwrap();
+        policy["guardrails"]
+            .as_object_mut()
+            .unwrap()
+            .remove("never_kill_pid");
+        policy["notes"] = serde_json::json!("partial");
+        std::fs::write(&policy_path, policy.to_string()).unwrap();
+
+        let options = ConfigOptions {
+            config_dir: Some(dir.path().join("empty")),
+            priors_path: None,
+            policy_path: Some(policy_path.clone()),
+            expand_env: false,
+            presets: Vec::new(),
+        };
+        let (_, trace) = resolve_config_traced_with(&options, &|_| None).unwrap();
+
+        let notes = trace.value("policy.notes").unwrap();
+        assert_eq!(notes.layer, ConfigLayer::Cli);
+        assert_eq!(notes.path.as_ref(), Some(&policy_path));
+
+        // Omitted from the file, so filled in by the serde default.
+        let never_kill = trace.value("policy.guardrails.never_kill_pid").unwrap();
+        assert_eq!(never_kill.layer, ConfigLayer::Default);
+        assert!(never_kill.path.is_none());
+
+        // No priors file anywhere in the resolution chain.
+        let prior = trace.value("priors.classes.useful.prior_prob").unwrap();
+        assert_eq!(prior.layer, ConfigLayer::Default);
+        assert!(trace
+            .file_values()
+            .all(|(path, _)| path.starts_with("policy.")));
+    }
+
+    #[test]
+    fn test_presets_layer_beneath_partial_policy_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let policy_path = dir.path().join("policy.json");
+        std::fs::write(
+            &policy_path,
+            r#"{"loss_matrix": {"useful": {"keep": 0.0, "kill": 750.0}}}"#,
+        )
+        .unwrap();
+
+        let options = ConfigOptions {
+            config_dir: Some(dir.path().join("empty")),
+            policy_path: Some(policy_path.clone()),
+            presets: vec![PresetName::Server],
+            ..Default::default()
+        };
+        let (config, trace) = resolve_config_traced_with(&options, &|_| None).unwrap();
+        let server = get_preset(PresetName::Server);
+
+        // The file's fields win; everything else comes from the preset.
+        assert_eq!(config.policy.loss_matrix.useful.kill, 750.0);
+        assert_eq!(
+            config.policy.robot_mode.min_posterior,
+            server.robot_mode.min_posterior
+        );
+        assert_eq!(config.policy.inherits, vec!["preset:server".to_string()]);
+        assert_eq!(config.policy_path, Some(policy_path));
+
+        let kill = trace.value("policy.loss_matrix.useful.kill").unwrap();
+        assert_eq!(kill.layer, ConfigLayer::Cli);
+        let posterior = trace.value("policy.robot_mode.min_posterior").unwrap();
+        assert_eq!(posterior.layer, ConfigLayer::Preset);
+        let schema = trace.value("policy.schema_version").unwrap();
+        assert_eq!(schema.layer, ConfigLayer::Default);
+
+        // Without presets the same partial file is not a complete policy.
+        assert!(load_config_with(
+            &ConfigOptions {
+                presets: Vec::new(),
+                ..options.clone()
+            },
+            &|_| None
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_conflicting_presets_fail_to_load() {
+        let options = ConfigOptions {
+            presets: vec![PresetName::Developer, PresetName::Paranoid],
+            ..empty_config_options()
+        };
+        assert!(matches!(
+            load_config_with(&options, &|_| None),
+            Err(ConfigError::Preset(PresetError::ConflictingLayers { .. }))
+        ));
+    }
+
+    #[test]
+    fn test_sets_field_walks_nested_keys() {
+        let raw = serde_json::json!({"a": {"b": 1}, "c": null});
+        assert!(sets_field(&raw, ".a.b"));
+        assert!(!sets_field(&raw, ".a.x"));
+        assert!(!sets_field(&raw, ".z"));
+        assert!(sets_field(&raw, ".c.d"));
+    }
+
+    #[test]
+    fn test_trace_explicit_path_wins() {
+        let dir = tempfile::tempdir().unwrap();
+        let priors_path = dir.path().join("custom-priors.json");
+        std::fs::write(
+            &priors_path,
+            serde_json::to_string(&Priors::default()).unwrap(),
+        )
+        .unwrap();
+
+        let options = ConfigOptions {
+            config_dir: Some(dir.path().to_path_buf()),
+            priors_path: Some(priors_path.clone()),
+            policy_path: None,
+            expand_env: false,
+            presets: Vec::new(),
+        };
+        let (_, trace) = resolve_config_traced_with(&options, &|_| None).unwrap();
+        let priors = trace.field("priors").unwrap();
+        assert_eq!(priors.winner.layer, ConfigLayer::Cli);
+        assert_eq!(priors.winner.path.as_ref(), Some(&priors_path));
+        assert_eq!(trace.field("config_dir").unwrap().overridden.len(), 1);
+    }
+
+    #[test]
+    fn test_yaml_policy_loaded_by_extension() {
+        let dir = tempfile::tempdir().unwrap();
+        let policy_path = dir.path().join("policy.yaml");
+        std::fs::write(
+            &policy_path,
+            serde_yaml::to_string(&Policy::default()).unwrap(),
+        )
+        .unwrap();
+        let (policy, _) = load_policy_from_file(&policy_path, None).unwrap();
+        assert_eq!(policy.schema_version, CONFIG_SCHEMA_VERSION);
+
+        std::fs::write(&policy_path, "schema_version: \"1.0.0\"\nnotes: [\n").unwrap();
+        let err = load_policy_from_file(&policy_path, None).unwrap_err();
+        assert!(matches!(err, ConfigError::YamlParseError { .. }));
+        assert!(err.to_string().contains("line"), "{}", err);
+    }
 }
@@ -342,10 +342,9 @@ fn validate_prior_bounds(lower: f64, upper: f64, class: &str) -> Result<(), Vali
 /// Validate policy configuration semantically.
 pub fn validate_policy(policy: &Policy) -> Result<(), ValidationError> {
     // Validate loss matrix
-    validate_loss_row(&policy.loss_matrix.useful, "useful")?;
-    validate_loss_row(&policy.loss_matrix.useful_bad, "useful_bad")?;
-    validate_loss_row(&policy.loss_matrix.abandoned, "abandoned")?;
-    validate_loss_row(&policy.loss_matrix.zombie, "zombie")?;
+    for (class, row) in policy.loss_matrix.rows() {
+        validate_loss_row(row, class.as_str())?;
+    }
 
     // Validate FDR alpha
     if policy.fdr_control.alpha <= 0.0 || policy.fdr_control.alpha > 1.0 {
@@ -310,6 +310,16 @@ pub struct MetricsServer {
 impl MetricsServer {
     /// Start the metrics HTTP server on a background thread.
     pub fn start(config: &MetricsConfig, metrics: DaemonMetrics) -> Result<Self, String> {
+        Self::start_with(config, move || metrics.render().map_err(|e| e.to_string()))
+    }
+
+    /// Start the server with a custom renderer for the metrics page.
+    ///
+    /// `render` is called once per scrape and must return Prometheus text.
+    pub fn start_with(
+        config: &MetricsConfig,
+        render: impl Fn() -> Result<String, String> + Send + 'static,
+    ) -> Result<Self, String> {
         let addr: SocketAddr = format!("{}:{}", config.bind, config.port)
             .parse()
             .map_err(|e| format!("invalid metrics bind address: {}", e))?;
@@ -326,7 +336,7 @@ impl MetricsServer {
         let thread = thread::Builder::new()
             .name("pt-metrics".to_string())
             .spawn(move || {
-                serve_loop(server, &metrics, &shutdown_clone, &path);
+                serve_loop(server, &render, &shutdown_clone, &path);
             })
             .map_err(|e| format!("failed to spawn metrics thread: {}", e))?;
 
@@ -371,7 +381,7 @@ impl Drop for MetricsServer {
 /// Main serve loop: accept requests, serve /metrics, reject everything else.
 fn serve_loop(
     server: tiny_http::Server,
-    metrics: &DaemonMetrics,
+    render: &dyn Fn() -> Result<String, String>,
     shutdown: &AtomicBool,
     path: &str,
 ) {
@@ -402,7 +412,7 @@ fn serve_loop(
         debug!(method = %request.method(), url = %url, "metrics scrape");
 
         if url == path || url == format!("{}/", path) {
-            match metrics.render() {
+            match render() {
                 Ok(body) => {
                     let response = tiny_http::Response::from_string(body).with_header(
                         "Content-Type: text/plain; version=0.0.4; charset=utf-8"
@@ -610,6 +620,40 @@ mod tests {
         server.shutdown();
     }
 
+    #[test]
+    fn test_server_with_custom_renderer() {
+        let shadow =
+            std::sync::Arc::new(std::sync::Mutex::new(crate::metrics::ShadowMetrics::new()));
+        shadow.lock().unwrap().record_failed_scan();
+
+        let config = MetricsConfig {
+            port: 20184 + (std::process::id() % 1000) as u16,
+            ..MetricsConfig::default()
+        };
+        let render_state = shadow.clone();
+        let server = match MetricsServer::start_with(&config, move || {
+            Ok(render_state.lock().unwrap().render())
+        }) {
+            Ok(s) => s,
+            Err(e) => {
+                eprintln!("skipping metrics server test: {}", e);
+                return;
+            }
+        };
+        std::thread::sleep(std::time::Duration::from_millis(100));
+
+        if let Ok(mut stream) = std::net::TcpStream::connect(server.addr()) {
+            use std::io::{Read, Write};
+            let _ = stream.write_all(b"GET /metrics HTTP/1.0\r\nHost: localhost\r\n\r\n");
+            let mut buf = String::new();
+            let _ = stream.read_to_string(&mut buf);
+            assert!(buf.contains("200 OK"));
+            assert!(buf.contains("pt_shadow_scans_total{status=\"failed\"} 1"));
+        }
+
+        server.shutdown();
+    }
+
     #[test]
     fn test_default_config() {
         let config = MetricsConfig::default();
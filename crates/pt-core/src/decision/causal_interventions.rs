@@ -87,6 +87,7 @@ pub fn apply_outcome(
         | Action::Resume
         | Action::Freeze
         | Action::Unfreeze
+        | Action::Unthrottle
         | Action::Quarantine
         | Action::Unquarantine => {
             return updated; // No causal priors yet
@@ -126,6 +127,7 @@ pub fn recovery_table(priors: &Priors, action: Action) -> Option<RecoveryTable>
         | Action::Resume
         | Action::Freeze
         | Action::Unfreeze
+        | Action::Unthrottle
         | Action::Quarantine
         | Action::Unquarantine => None,
     };
@@ -180,6 +182,7 @@ pub fn recovery_for_class(priors: &Priors, action: Action, class: ProcessClass)
         | Action::Resume
         | Action::Freeze
         | Action::Unfreeze
+        | Action::Unthrottle
         | Action::Quarantine
         | Action::Unquarantine => None,
     }?;
@@ -244,6 +247,7 @@ fn expected_recovery_stats_for_action(
         | Action::Resume
         | Action::Freeze
         | Action::Unfreeze
+        | Action::Unthrottle
         | Action::Quarantine
         | Action::Unquarantine => None,
     }?;
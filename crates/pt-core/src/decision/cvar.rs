@@ -101,24 +101,10 @@ pub fn compute_cvar(
     }
 
     // Get losses for this action across all classes
-    let losses_and_probs = [
-        (
-            loss_for_action_class(action, &loss_matrix.useful)?,
-            posterior.useful,
-        ),
-        (
-            loss_for_action_class(action, &loss_matrix.useful_bad)?,
-            posterior.useful_bad,
-        ),
-        (
-            loss_for_action_class(action, &loss_matrix.abandoned)?,
-            posterior.abandoned,
-        ),
-        (
-            loss_for_action_class(action, &loss_matrix.zombie)?,
-            posterior.zombie,
-        ),
-    ];
+    let losses_and_probs = loss_matrix
+        .rows()
+        .map(|(class, row)| Ok((loss_for_action_class(action, row)?, posterior[class])))
+        .collect::<Result<Vec<(f64, f64)>, CvarError>>()?;
 
     // Compute expected loss (for comparison)
     let expected_loss: f64 = losses_and_probs
@@ -209,7 +195,7 @@ fn loss_for_action_class(
             message: format!("missing restart loss for action {action:?}"),
         }),
         Action::Kill => Ok(row.kill),
-        Action::Resume | Action::Unfreeze | Action::Unquarantine => {
+        Action::Resume | Action::Unfreeze | Action::Unthrottle | Action::Unquarantine => {
             Err(CvarError::InvalidPosterior {
                 message: format!("follow-up action {action:?} has no loss"),
             })
@@ -293,7 +279,7 @@ fn tie_break_rank(action: Action) -> u8 {
         Action::Keep => 0,
         Action::Renice => 1,
         Action::Pause | Action::Resume | Action::Freeze | Action::Unfreeze => 2,
-        Action::Quarantine | Action::Unquarantine | Action::Throttle => 3,
+        Action::Quarantine | Action::Unquarantine | Action::Throttle | Action::Unthrottle => 3,
         Action::Restart => 4,
         Action::Kill => 5,
     }
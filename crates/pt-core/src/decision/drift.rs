@@ -0,0 +1,144 @@
+//! DRO trigger from feature drift between a telemetry baseline and the
+//! current scan.
+//!
+//! Drift itself is measured by [`DriftMonitor`]: baselines loaded from
+//! telemetry are registered per feature, and each feature's
+//! [`WassersteinDetector`](crate::inference::wasserstein::WassersteinDetector)
+//! decides whether the current samples drifted far enough to warrant DRO.
+//! This module gathers a scan's samples under the monitor's feature names and
+//! folds the per-feature results into a [`DroTrigger`].
+
+use std::collections::HashMap;
+
+use super::dro::DroTrigger;
+use crate::collect::ProcessRecord;
+use crate::inference::wasserstein::{DriftMonitor, DriftResult, WassersteinError};
+
+/// Feature names configured by [`DriftMonitor::new`].
+pub const CPU_FEATURE: &str = "cpu";
+pub const MEMORY_FEATURE: &str = "memory";
+pub const RUNTIME_FEATURE: &str = "runtime";
+
+/// CPU, RSS and runtime samples of a scan snapshot, keyed by the monitor's
+/// feature names. Non-finite values are dropped.
+pub fn feature_samples<'a>(
+    records: impl IntoIterator<Item = &'a ProcessRecord>,
+) -> HashMap<String, Vec<f64>> {
+    let mut samples: HashMap<String, Vec<f64>> = HashMap::new();
+    for record in records {
+        for (feature, value) in [
+            (CPU_FEATURE, record.cpu_percent),
+            (MEMORY_FEATURE, record.rss_bytes as f64),
+            (RUNTIME_FEATURE, record.elapsed.as_secs_f64()),
+        ] {
+            if value.is_finite() {
+                samples.entry(feature.to_string()).or_default().push(value);
+            }
+        }
+    }
+    samples
+}
+
+/// Check `current` against the monitor's baselines and build the matching
+/// DRO trigger.
+pub fn detect_drift(monitor: &DriftMonitor, current: &HashMap<String, Vec<f64>>) -> DroTrigger {
+    dro_trigger_from_drift(&monitor.check_all(current))
+}
+
+/// Fold per-feature drift results into a DRO trigger.
+///
+/// Features the detector rejected (e.g. too few samples) are skipped.
+/// `drift_detected` is set when any feature's detector asks for DRO, and
+/// `wasserstein_divergence` carries the largest distance-to-threshold ratio,
+/// which is unit-free across features. All other trigger conditions are
+/// left unset.
+pub fn dro_trigger_from_drift(
+    results: &HashMap<String, Result<DriftResult, WassersteinError>>,
+) -> DroTrigger {
+    let compared = results.values().filter_map(|result| result.as_ref().ok());
+    let mut drift_detected = false;
+    let mut max_ratio: Option<f64> = None;
+    for result in compared {
+        drift_detected |= result.trigger_dro;
+        max_ratio = Some(max_ratio.map_or(result.ratio, |m| m.max(result.ratio)));
+    }
+
+    DroTrigger {
+        drift_detected,
+        wasserstein_divergence: max_ratio,
+        ..DroTrigger::none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inference::wasserstein::WassersteinConfig;
+    use crate::mock_process::MockProcessBuilder;
+
+    fn uniform(offset: f64) -> Vec<f64> {
+        (0..100).map(|i| i as f64 / 100.0 + offset).collect()
+    }
+
+    /// Monitor with a fixed 0.1 threshold on `cpu`, so ratios are exact.
+    fn cpu_monitor() -> DriftMonitor {
+        let mut monitor = DriftMonitor::new();
+        monitor.add_detector(
+            CPU_FEATURE,
+            WassersteinConfig {
+                drift_threshold: 0.1,
+                adaptive_threshold: false,
+                min_samples: 10,
+                ..WassersteinConfig::default()
+            },
+        );
+        monitor.set_baseline(CPU_FEATURE, uniform(0.0));
+        monitor
+    }
+
+    fn current(samples: Vec<f64>) -> HashMap<String, Vec<f64>> {
+        HashMap::from([(CPU_FEATURE.to_string(), samples)])
+    }
+
+    #[test]
+    fn identical_distributions_do_not_trigger() {
+        let trigger = detect_drift(&cpu_monitor(), &current(uniform(0.0)));
+
+        assert!(!trigger.drift_detected);
+        assert!(!trigger.should_apply());
+        assert_eq!(trigger.wasserstein_divergence, Some(0.0));
+    }
+
+    #[test]
+    fn shifted_distribution_triggers_with_expected_divergence() {
+        let trigger = detect_drift(&cpu_monitor(), &current(uniform(0.5)));
+
+        assert!(trigger.drift_detected);
+        assert!(trigger.should_apply());
+        // W1 = 0.5 against a 0.1 threshold.
+        assert!((trigger.wasserstein_divergence.unwrap() - 5.0).abs() < 1e-6);
+        assert!(trigger.reason().starts_with("drift_detected (W="));
+    }
+
+    #[test]
+    fn sparse_or_missing_features_are_skipped() {
+        let trigger = detect_drift(&cpu_monitor(), &current(vec![0.9; 3]));
+        assert!(!trigger.drift_detected);
+        assert_eq!(trigger.wasserstein_divergence, None);
+
+        let trigger = detect_drift(&cpu_monitor(), &HashMap::new());
+        assert_eq!(trigger.wasserstein_divergence, None);
+    }
+
+    #[test]
+    fn feature_samples_use_monitor_feature_names() {
+        let records = [
+            MockProcessBuilder::new().pid(10).build(),
+            MockProcessBuilder::new().pid(11).build(),
+        ];
+        let samples = feature_samples(&records);
+        for feature in [CPU_FEATURE, MEMORY_FEATURE, RUNTIME_FEATURE] {
+            assert_eq!(samples.get(feature).map(Vec::len), Some(2));
+        }
+    }
+}
@@ -49,10 +49,37 @@
 //! worst_case = E_P[L] + ε · (L_max - L_min) / 2
 //! ```
 //!
-//! A more refined approach uses the dual formulation to compute the exact worst case.
+//! A [`GroundMetric`] makes some shifts costlier than others (e.g. `useful` →
+//! `zombie` versus `useful` → `useful_bad`); the Lipschitz constant then
+//! becomes `max_{i≠j} |L_i − L_j| / c(i,j)`.
+//!
+//! This bound can be loose. [`compute_wasserstein_dro_exact`] instead solves the
+//! exact dual:
+//!
+//! ```text
+//! sup_{Q: W(Q,P) ≤ ε} E_Q[L] = E_P[L] + min_{λ ≥ 0} [ λε + Σ_i p_i · max(0, max_j (L_j − L_i − λ·c(i,j))) ]
+//! ```
+//!
+//! The objective is convex and piecewise linear in λ, so the minimum is found
+//! by evaluating its finitely many breakpoints. [`DroMethod`] selects which of
+//! the two a Wasserstein [`AmbiguitySet`] uses.
+//!
+//! # χ² DRO
+//!
+//! A modified χ² ball `{Q : D_χ²(Q‖P) ≤ ρ}` penalizes variance instead of range:
+//!
+//! ```text
+//! sup_{Q: D_χ²(Q‖P) ≤ ρ} E_Q[L] ≤ E_P[L] + sqrt(ρ · Var_P[L])
+//! ```
+//!
+//! The inflation scales with how spread out the action's losses are under the
+//! posterior, which is often easier to reason about than a transport radius.
+//! [`AmbiguitySet`] selects between the two.
 
 use crate::config::policy::{LossMatrix, LossRow, Policy};
+use crate::config::Class;
 use crate::decision::expected_loss::Action;
+use crate::inference::ppc::{MomentPpcResult, PpcResult};
 use crate::inference::ClassScores;
 use schemars::JsonSchema;
 use serde::Serialize;
@@ -67,14 +94,142 @@ pub struct DroLoss {
     pub robust_loss: f64,
     /// Nominal expected loss (for comparison).
     pub nominal_loss: f64,
-    /// The ambiguity radius used.
+    /// The ambiguity radius used (ε for Wasserstein, ρ for χ²).
     pub epsilon: f64,
     /// The loss inflation from DRO (robust - nominal).
     pub inflation: f64,
-    /// Lipschitz constant of the loss for this action.
+    /// Sensitivity of the bound: the Lipschitz constant of the loss
+    /// (Wasserstein) or its posterior standard deviation (χ²).
     pub lipschitz: f64,
 }
 
+/// Transport cost `c(i,j)` between posterior classes, indexed by [`Class::index`].
+///
+/// Zero on the diagonal and positive elsewhere; it need not be symmetric.
+/// Off-diagonal costs must be strictly positive, since a free shift between
+/// classes with different losses makes the Lipschitz constant unbounded.
+/// The default is [`GroundMetric::uniform`].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, JsonSchema)]
+pub struct GroundMetric {
+    cost: [[f64; Class::COUNT]; Class::COUNT],
+}
+
+impl GroundMetric {
+    /// Cost 1 between any two distinct classes.
+    pub fn uniform() -> Self {
+        let mut cost = [[1.0; Class::COUNT]; Class::COUNT];
+        for (i, row) in cost.iter_mut().enumerate() {
+            row[i] = 0.0;
+        }
+        Self { cost }
+    }
+
+    /// Metric from an explicit cost matrix (`cost[from][to]`).
+    pub fn new(cost: [[f64; Class::COUNT]; Class::COUNT]) -> Result<Self, DroError> {
+        for (i, row) in cost.iter().enumerate() {
+            for (j, &c) in row.iter().enumerate() {
+                let valid = if i == j {
+                    c == 0.0
+                } else {
+                    c.is_finite() && c > 0.0
+                };
+                if !valid {
+                    return Err(DroError::InvalidGroundMetric {
+                        message: format!("cost({i},{j}) = {c}"),
+                    });
+                }
+            }
+        }
+        Ok(Self { cost })
+    }
+
+    /// Cost of moving a unit of mass from class `from` to class `to`.
+    pub fn cost(&self, from: Class, to: Class) -> f64 {
+        self.cost[from.index()][to.index()]
+    }
+}
+
+impl Default for GroundMetric {
+    fn default() -> Self {
+        Self::uniform()
+    }
+}
+
+/// How the Wasserstein worst case is computed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DroMethod {
+    /// `E_P[L] + ε·Lip(L)`; cheap and conservative.
+    #[default]
+    LipschitzBound,
+    /// Exact dual; never exceeds the Lipschitz bound for the same metric.
+    ExactDual,
+}
+
+/// Ambiguity set around the nominal posterior.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, JsonSchema)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum AmbiguitySet {
+    /// Wasserstein-1 ball of radius `epsilon` under `metric`, evaluated with
+    /// `method`.
+    Wasserstein {
+        epsilon: f64,
+        metric: GroundMetric,
+        method: DroMethod,
+    },
+    /// Modified χ² ball `D_χ²(Q‖P) ≤ rho`.
+    ChiSquare { rho: f64 },
+}
+
+impl AmbiguitySet {
+    /// Wasserstein ball with the uniform metric and the Lipschitz bound.
+    pub fn wasserstein(epsilon: f64) -> Self {
+        AmbiguitySet::Wasserstein {
+            epsilon,
+            metric: GroundMetric::uniform(),
+            method: DroMethod::LipschitzBound,
+        }
+    }
+
+    /// Size of the ball.
+    pub fn radius(&self) -> f64 {
+        match *self {
+            AmbiguitySet::Wasserstein { epsilon, .. } => epsilon,
+            AmbiguitySet::ChiSquare { rho } => rho,
+        }
+    }
+
+    /// Worst-case expected loss of `action` over this set.
+    pub fn robust_loss(
+        &self,
+        action: Action,
+        posterior: &ClassScores,
+        loss_matrix: &LossMatrix,
+    ) -> Result<DroLoss, DroError> {
+        match *self {
+            AmbiguitySet::Wasserstein {
+                epsilon,
+                metric,
+                method: DroMethod::LipschitzBound,
+            } => compute_wasserstein_dro_with_metric(
+                action,
+                posterior,
+                loss_matrix,
+                epsilon,
+                &metric,
+            ),
+            AmbiguitySet::Wasserstein {
+                epsilon,
+                metric,
+                method: DroMethod::ExactDual,
+            } => compute_wasserstein_dro_exact(action, posterior, loss_matrix, epsilon, &metric),
+            AmbiguitySet::ChiSquare { rho } => {
+                compute_chi2_dro(action, posterior, loss_matrix, rho)
+            }
+        }
+    }
+}
+
 /// DRO decision outcome.
 #[derive(Debug, Clone, Serialize, JsonSchema)]
 pub struct DroOutcome {
@@ -103,6 +258,10 @@ pub enum DroError {
     InvalidPosterior { message: String },
     #[error("invalid epsilon: must be non-negative, got {epsilon}")]
     InvalidEpsilon { epsilon: f64 },
+    #[error("invalid rho: must be non-negative, got {rho}")]
+    InvalidRho { rho: f64 },
+    #[error("invalid ground metric: {message}")]
+    InvalidGroundMetric { message: String },
     #[error("no feasible actions")]
     NoFeasibleActions,
 }
@@ -165,6 +324,16 @@ impl DroTrigger {
         }
     }
 
+    /// Flag a PPC failure when any simulated check failed.
+    pub fn record_ppc(&mut self, result: &PpcResult) {
+        self.ppc_failure |= !result.passed;
+    }
+
+    /// Flag a PPC failure when the analytic moment check failed.
+    pub fn record_moment_ppc(&mut self, result: &MomentPpcResult) {
+        self.ppc_failure |= result.failed;
+    }
+
     /// Create a trigger indicating no DRO should be applied.
     pub fn none() -> Self {
         Self {
@@ -207,33 +376,53 @@ pub fn compute_wasserstein_dro(
     posterior: &ClassScores,
     loss_matrix: &LossMatrix,
     epsilon: f64,
+) -> Result<DroLoss, DroError> {
+    compute_wasserstein_dro_with_metric(
+        action,
+        posterior,
+        loss_matrix,
+        epsilon,
+        &GroundMetric::uniform(),
+    )
+}
+
+/// Lipschitz-bound worst case under a non-uniform ground metric.
+///
+/// Same as [`compute_wasserstein_dro`] with
+/// `Lip(L) = max_{i≠j} |L_i − L_j| / c(i,j)`.
+pub fn compute_wasserstein_dro_with_metric(
+    action: Action,
+    posterior: &ClassScores,
+    loss_matrix: &LossMatrix,
+    epsilon: f64,
+    metric: &GroundMetric,
 ) -> Result<DroLoss, DroError> {
     if epsilon < 0.0 {
         return Err(DroError::InvalidEpsilon { epsilon });
     }
 
     // Get losses for this action across all classes
-    let losses = [
-        loss_for_action_class(action, &loss_matrix.useful)?,
-        loss_for_action_class(action, &loss_matrix.useful_bad)?,
-        loss_for_action_class(action, &loss_matrix.abandoned)?,
-        loss_for_action_class(action, &loss_matrix.zombie)?,
-    ];
-
-    let probs = [
-        posterior.useful,
-        posterior.useful_bad,
-        posterior.abandoned,
-        posterior.zombie,
-    ];
+    let losses = loss_matrix
+        .rows()
+        .map(|(_, row)| loss_for_action_class(action, row))
+        .collect::<Result<Vec<f64>, DroError>>()?;
+
+    let probs: Vec<f64> = posterior.iter().map(|(_, p)| p).collect();
 
     // Compute nominal expected loss
     let nominal_loss: f64 = losses.iter().zip(probs.iter()).map(|(l, p)| l * p).sum();
 
-    // Compute Lipschitz constant: max loss difference (with uniform ground metric)
-    let l_min = losses.iter().cloned().fold(f64::INFINITY, f64::min);
-    let l_max = losses.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
-    let lipschitz = l_max - l_min;
+    // Lipschitz constant: steepest loss change per unit of transport cost
+    let mut lipschitz: f64 = 0.0;
+    for from in Class::ALL {
+        for to in Class::ALL {
+            if from != to {
+                let slope =
+                    (losses[to.index()] - losses[from.index()]).abs() / metric.cost(from, to);
+                lipschitz = lipschitz.max(slope);
+            }
+        }
+    }
 
     // Worst-case expected loss under Wasserstein DRO
     // This is the canonical Lipschitz bound for Wasserstein-1 robustness
@@ -250,6 +439,166 @@ pub fn compute_wasserstein_dro(
     })
 }
 
+/// Compute the exact worst-case expected loss for a single action over a
+/// Wasserstein-1 ball with ground metric `metric`.
+///
+/// Solves the dual
+/// ```text
+/// sup_{Q: W(Q,P) ≤ ε} E_Q[L] = E_P[L] + min_{λ ≥ 0} [ λε + Σ_i p_i · max(0, max_j (L_j − L_i − λ·c(i,j))) ]
+/// ```
+/// by evaluating the piecewise-linear objective at each breakpoint. This is
+/// the value of moving up to ε of transport budget toward the worst-loss
+/// classes, so it never exceeds the largest loss. The returned `lipschitz`
+/// is the optimal λ, the marginal worst-case loss per unit of ε.
+///
+/// # Arguments
+/// * `action` - The action to compute robust loss for
+/// * `posterior` - Nominal posterior probabilities
+/// * `loss_matrix` - Loss values for each (action, class) pair
+/// * `epsilon` - Ambiguity radius (Wasserstein ball size)
+/// * `metric` - Transport cost between classes
+pub fn compute_wasserstein_dro_exact(
+    action: Action,
+    posterior: &ClassScores,
+    loss_matrix: &LossMatrix,
+    epsilon: f64,
+    metric: &GroundMetric,
+) -> Result<DroLoss, DroError> {
+    if epsilon.is_nan() || epsilon < 0.0 {
+        return Err(DroError::InvalidEpsilon { epsilon });
+    }
+
+    let losses = loss_matrix
+        .rows()
+        .map(|(_, row)| loss_for_action_class(action, row))
+        .collect::<Result<Vec<f64>, DroError>>()?;
+    let probs: Vec<f64> = posterior.iter().map(|(_, p)| p).collect();
+    let nominal_loss: f64 = losses.iter().zip(probs.iter()).map(|(l, p)| l * p).sum();
+
+    // Gain and cost of moving mass from class i to class j, for sources with mass.
+    let moves: Vec<(usize, Vec<(f64, f64)>)> = Class::ALL
+        .iter()
+        .filter(|from| probs[from.index()] > 0.0)
+        .map(|&from| {
+            let i = from.index();
+            let targets = Class::ALL
+                .iter()
+                .filter(|&&to| to != from)
+                .map(|&to| (losses[to.index()] - losses[i], metric.cost(from, to)))
+                .collect();
+            (i, targets)
+        })
+        .collect();
+
+    let dual = |lambda: f64| -> f64 {
+        lambda * epsilon
+            + moves
+                .iter()
+                .map(|(i, targets)| {
+                    let best = targets
+                        .iter()
+                        .map(|(gain, cost)| gain - lambda * cost)
+                        .fold(0.0, f64::max);
+                    probs[*i] * best
+                })
+                .sum::<f64>()
+    };
+
+    // Breakpoints: where a move stops paying, or two moves from one source tie.
+    let mut candidates = vec![0.0];
+    for (_, targets) in &moves {
+        for (a, &(gain_a, cost_a)) in targets.iter().enumerate() {
+            if gain_a > 0.0 {
+                candidates.push(gain_a / cost_a);
+            }
+            for &(gain_b, cost_b) in &targets[a + 1..] {
+                if cost_a != cost_b {
+                    let lambda = (gain_a - gain_b) / (cost_a - cost_b);
+                    if lambda > 0.0 {
+                        candidates.push(lambda);
+                    }
+                }
+            }
+        }
+    }
+
+    let (lambda, inflation) = candidates
+        .into_iter()
+        .map(|lambda| (lambda, dual(lambda)))
+        .min_by(|a, b| a.1.total_cmp(&b.1).then(a.0.total_cmp(&b.0)))
+        .unwrap_or((0.0, 0.0));
+    let inflation = inflation.max(0.0);
+
+    Ok(DroLoss {
+        action,
+        robust_loss: nominal_loss + inflation,
+        nominal_loss,
+        epsilon,
+        inflation,
+        lipschitz: lambda,
+    })
+}
+
+/// Compute worst-case expected loss for a single action over a χ² ball.
+///
+/// Uses the closed-form bound
+/// ```text
+/// worst_case = E_P[L] + sqrt(ρ · Var_P[L])
+/// ```
+/// with mean and variance weighted by the posterior. Since every Q in the
+/// ball is supported where P is, the result is capped at the largest loss
+/// among classes with positive posterior mass.
+///
+/// # Arguments
+/// * `action` - The action to compute robust loss for
+/// * `posterior` - Nominal posterior probabilities (the variance weights)
+/// * `loss_matrix` - Loss values for each (action, class) pair
+/// * `rho` - χ² divergence budget
+pub fn compute_chi2_dro(
+    action: Action,
+    posterior: &ClassScores,
+    loss_matrix: &LossMatrix,
+    rho: f64,
+) -> Result<DroLoss, DroError> {
+    if rho.is_nan() || rho < 0.0 {
+        return Err(DroError::InvalidRho { rho });
+    }
+
+    let losses = loss_matrix
+        .rows()
+        .map(|(_, row)| loss_for_action_class(action, row))
+        .collect::<Result<Vec<f64>, DroError>>()?;
+
+    let probs: Vec<f64> = posterior.iter().map(|(_, p)| p).collect();
+
+    let nominal_loss: f64 = losses.iter().zip(probs.iter()).map(|(l, p)| l * p).sum();
+    let variance: f64 = losses
+        .iter()
+        .zip(probs.iter())
+        .map(|(l, p)| p * (l - nominal_loss).powi(2))
+        .sum();
+    let std_dev = variance.max(0.0).sqrt();
+
+    let support_max = losses
+        .iter()
+        .zip(probs.iter())
+        .filter(|(_, &p)| p > 0.0)
+        .map(|(&l, _)| l)
+        .fold(f64::NEG_INFINITY, f64::max);
+
+    let robust_loss = (nominal_loss + (rho * variance).sqrt()).min(support_max.max(nominal_loss));
+    let inflation = robust_loss - nominal_loss;
+
+    Ok(DroLoss {
+        action,
+        robust_loss,
+        nominal_loss,
+        epsilon: rho,
+        inflation,
+        lipschitz: std_dev,
+    })
+}
+
 /// Get loss for an action applied to a specific class.
 fn loss_for_action_class(action: Action, row: &LossRow) -> Result<f64, DroError> {
     match action {
@@ -269,7 +618,7 @@ fn loss_for_action_class(action: Action, row: &LossRow) -> Result<f64, DroError>
             message: format!("missing restart loss for action {action:?}"),
         }),
         Action::Kill => Ok(row.kill),
-        Action::Resume | Action::Unfreeze | Action::Unquarantine => {
+        Action::Resume | Action::Unfreeze | Action::Unthrottle | Action::Unquarantine => {
             Err(DroError::InvalidPosterior {
                 message: format!("follow-up action {action:?} has no loss"),
             })
@@ -296,6 +645,28 @@ pub fn decide_with_dro(
     epsilon: f64,
     original_optimal: Action,
     reason: &str,
+) -> Result<DroOutcome, DroError> {
+    decide_with_ambiguity_set(
+        posterior,
+        policy,
+        feasible_actions,
+        AmbiguitySet::wasserstein(epsilon),
+        original_optimal,
+        reason,
+    )
+}
+
+/// Compute DRO for all feasible actions over `ambiguity_set` and select the
+/// robust optimal.
+///
+/// Same as [`decide_with_dro`], which is the Wasserstein special case.
+pub fn decide_with_ambiguity_set(
+    posterior: &ClassScores,
+    policy: &Policy,
+    feasible_actions: &[Action],
+    ambiguity_set: AmbiguitySet,
+    original_optimal: Action,
+    reason: &str,
 ) -> Result<DroOutcome, DroError> {
     if feasible_actions.is_empty() {
         return Err(DroError::NoFeasibleActions);
@@ -304,7 +675,7 @@ pub fn decide_with_dro(
     let mut dro_losses = Vec::new();
 
     for &action in feasible_actions {
-        match compute_wasserstein_dro(action, posterior, &policy.loss_matrix, epsilon) {
+        match ambiguity_set.robust_loss(action, posterior, &policy.loss_matrix) {
             Ok(dro_loss) => dro_losses.push(dro_loss),
             Err(_) => continue, // Skip actions without valid loss entries
         }
@@ -327,7 +698,7 @@ pub fn decide_with_dro(
     Ok(DroOutcome {
         applied: true,
         reason: reason.to_string(),
-        ambiguity_radius: epsilon,
+        ambiguity_radius: ambiguity_set.radius(),
         original_action: original_optimal,
         robust_action,
         worst_case_expected_loss,
@@ -360,7 +731,7 @@ fn tie_break_rank(action: Action) -> u8 {
         Action::Keep => 0,
         Action::Renice => 1,
         Action::Pause | Action::Resume | Action::Freeze | Action::Unfreeze => 2,
-        Action::Quarantine | Action::Unquarantine | Action::Throttle => 3,
+        Action::Quarantine | Action::Unquarantine | Action::Throttle | Action::Unthrottle => 3,
         Action::Restart => 4,
         Action::Kill => 5,
     }
@@ -1069,6 +1440,299 @@ mod tests {
         assert!(matches!(result, Err(DroError::InvalidPosterior { .. })));
     }
 
+    // ── χ² DRO ──────────────────────────────────────────────────────
+
+    fn balanced_posterior() -> ClassScores {
+        ClassScores {
+            useful: 0.25,
+            useful_bad: 0.25,
+            abandoned: 0.25,
+            zombie: 0.25,
+        }
+    }
+
+    fn flat_and_spread_matrix() -> LossMatrix {
+        // Keep is flat across classes; Kill swings between 0 and 100.
+        let row = |kill: f64| LossRow {
+            keep: 20.0,
+            pause: None,
+            throttle: None,
+            renice: None,
+            kill,
+            restart: None,
+        };
+        LossMatrix {
+            useful: row(100.0),
+            useful_bad: row(0.0),
+            abandoned: row(0.0),
+            zombie: row(0.0),
+        }
+    }
+
+    #[test]
+    fn chi2_zero_rho_recovers_nominal() {
+        let posterior = balanced_posterior();
+        let matrix = test_loss_matrix();
+        for action in [Action::Keep, Action::Pause, Action::Kill] {
+            let dro = compute_chi2_dro(action, &posterior, &matrix, 0.0).unwrap();
+            assert!((dro.robust_loss - dro.nominal_loss).abs() < 1e-12);
+            assert_eq!(dro.inflation, 0.0);
+        }
+    }
+
+    #[test]
+    fn chi2_matches_closed_form() {
+        let posterior = ClassScores {
+            useful: 0.2,
+            useful_bad: 0.2,
+            abandoned: 0.3,
+            zombie: 0.3,
+        };
+        let dro =
+            compute_chi2_dro(Action::Kill, &posterior, &flat_and_spread_matrix(), 0.5).unwrap();
+        // E = 20, Var = 0.2·80² + 0.8·20² = 1600.
+        assert!((dro.nominal_loss - 20.0).abs() < 1e-9);
+        assert!((dro.lipschitz - 40.0).abs() < 1e-9);
+        assert!((dro.robust_loss - (20.0 + (0.5f64 * 1600.0).sqrt())).abs() < 1e-9);
+    }
+
+    #[test]
+    fn chi2_inflates_high_variance_more_than_flat() {
+        let posterior = ClassScores {
+            useful: 0.2,
+            useful_bad: 0.2,
+            abandoned: 0.3,
+            zombie: 0.3,
+        };
+        let matrix = flat_and_spread_matrix();
+        let keep = compute_chi2_dro(Action::Keep, &posterior, &matrix, 0.5).unwrap();
+        let kill = compute_chi2_dro(Action::Kill, &posterior, &matrix, 0.5).unwrap();
+        // Same nominal loss, but only the spread-out action is penalized.
+        assert!((keep.nominal_loss - kill.nominal_loss).abs() < 1e-9);
+        assert_eq!(keep.inflation, 0.0);
+        assert!(kill.inflation > 0.0);
+    }
+
+    #[test]
+    fn chi2_capped_at_worst_supported_loss() {
+        let posterior = ClassScores {
+            useful: 0.2,
+            useful_bad: 0.2,
+            abandoned: 0.3,
+            zombie: 0.3,
+        };
+        let dro =
+            compute_chi2_dro(Action::Kill, &posterior, &flat_and_spread_matrix(), 1e6).unwrap();
+        assert_eq!(dro.robust_loss, 100.0);
+    }
+
+    #[test]
+    fn chi2_negative_rho_rejected() {
+        let result = compute_chi2_dro(
+            Action::Keep,
+            &balanced_posterior(),
+            &test_loss_matrix(),
+            -0.1,
+        );
+        assert!(matches!(result, Err(DroError::InvalidRho { .. })));
+    }
+
+    #[test]
+    fn ambiguity_set_dispatches() {
+        let posterior = balanced_posterior();
+        let matrix = test_loss_matrix();
+        let w = AmbiguitySet::wasserstein(0.1)
+            .robust_loss(Action::Kill, &posterior, &matrix)
+            .unwrap();
+        let direct = compute_wasserstein_dro(Action::Kill, &posterior, &matrix, 0.1).unwrap();
+        assert_eq!(w.robust_loss, direct.robust_loss);
+
+        let metric = GroundMetric::uniform();
+        let exact = AmbiguitySet::Wasserstein {
+            epsilon: 0.1,
+            metric,
+            method: DroMethod::ExactDual,
+        }
+        .robust_loss(Action::Kill, &posterior, &matrix)
+        .unwrap();
+        let direct =
+            compute_wasserstein_dro_exact(Action::Kill, &posterior, &matrix, 0.1, &metric).unwrap();
+        assert_eq!(exact.robust_loss, direct.robust_loss);
+
+        let c = AmbiguitySet::ChiSquare { rho: 0.2 }
+            .robust_loss(Action::Kill, &posterior, &matrix)
+            .unwrap();
+        let direct = compute_chi2_dro(Action::Kill, &posterior, &matrix, 0.2).unwrap();
+        assert_eq!(c.robust_loss, direct.robust_loss);
+        assert_eq!(AmbiguitySet::ChiSquare { rho: 0.2 }.radius(), 0.2);
+    }
+
+    #[test]
+    fn exact_dual_never_exceeds_lipschitz_bound() {
+        let matrix = test_loss_matrix();
+        let metric = GroundMetric::uniform();
+        let posteriors = [
+            balanced_posterior(),
+            ClassScores {
+                useful: 0.7,
+                useful_bad: 0.1,
+                abandoned: 0.15,
+                zombie: 0.05,
+            },
+            ClassScores {
+                useful: 0.0,
+                useful_bad: 0.0,
+                abandoned: 1.0,
+                zombie: 0.0,
+            },
+        ];
+        for posterior in &posteriors {
+            for action in [Action::Keep, Action::Pause, Action::Renice, Action::Kill] {
+                for epsilon in [0.0, 0.01, 0.1, 0.3, 1.0, 5.0] {
+                    let bound =
+                        compute_wasserstein_dro(action, posterior, &matrix, epsilon).unwrap();
+                    let exact =
+                        compute_wasserstein_dro_exact(action, posterior, &matrix, epsilon, &metric)
+                            .unwrap();
+                    assert!(
+                        exact.robust_loss <= bound.robust_loss + 1e-9,
+                        "{action:?} ε={epsilon}: exact {} > bound {}",
+                        exact.robust_loss,
+                        bound.robust_loss
+                    );
+                    assert!(exact.robust_loss >= exact.nominal_loss - 1e-9);
+                    if epsilon == 0.0 {
+                        assert!((exact.robust_loss - bound.robust_loss).abs() < 1e-9);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn exact_dual_moves_mass_to_worst_class() {
+        // Kill loses 100 on `useful` only; nominal 25 under a balanced posterior.
+        let matrix = flat_and_spread_matrix();
+        let posterior = balanced_posterior();
+        let exact = |epsilon: f64, metric: &GroundMetric| {
+            compute_wasserstein_dro_exact(Action::Kill, &posterior, &matrix, epsilon, metric)
+                .unwrap()
+        };
+
+        // Uniform metric: each unit of budget moves a unit of mass (+100).
+        let uniform = GroundMetric::uniform();
+        let small = exact(0.2, &uniform);
+        assert!((small.robust_loss - 45.0).abs() < 1e-9);
+        assert!((small.lipschitz - 100.0).abs() < 1e-9);
+        // Only 0.75 of mass can move, so the worst case saturates at 100
+        // while the Lipschitz bound keeps growing.
+        assert!((exact(1.0, &uniform).robust_loss - 100.0).abs() < 1e-9);
+        let bound = compute_wasserstein_dro(Action::Kill, &posterior, &matrix, 1.0).unwrap();
+        assert!((bound.robust_loss - 125.0).abs() < 1e-9);
+
+        // Doubling the cost of reaching `useful` halves the gain per unit ε.
+        let mut cost = [[1.0; Class::COUNT]; Class::COUNT];
+        for (i, row) in cost.iter_mut().enumerate() {
+            row[i] = 0.0;
+            if i != Class::Useful.index() {
+                row[Class::Useful.index()] = 2.0;
+            }
+        }
+        let metric = GroundMetric::new(cost).unwrap();
+        assert!((exact(0.2, &metric).robust_loss - 35.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn ground_metric_scales_lipschitz_constant() {
+        // Keep losses across classes: 0, 10, 30, 50 (see test_loss_matrix).
+        let matrix = test_loss_matrix();
+        let posterior = balanced_posterior();
+
+        // The default metric reproduces L_max − L_min.
+        let uniform = compute_wasserstein_dro_with_metric(
+            Action::Keep,
+            &posterior,
+            &matrix,
+            0.1,
+            &GroundMetric::default(),
+        )
+        .unwrap();
+        let legacy = compute_wasserstein_dro(Action::Keep, &posterior, &matrix, 0.1).unwrap();
+        assert_eq!(uniform.lipschitz, legacy.lipschitz);
+        assert_eq!(uniform.robust_loss, legacy.robust_loss);
+
+        // Cost grows with how far apart two classes are: |i − j|.
+        let mut cost = [[0.0; Class::COUNT]; Class::COUNT];
+        for (i, row) in cost.iter_mut().enumerate() {
+            for (j, c) in row.iter_mut().enumerate() {
+                *c = i.abs_diff(j) as f64;
+            }
+        }
+        let metric = GroundMetric::new(cost).unwrap();
+        let graded =
+            compute_wasserstein_dro_with_metric(Action::Keep, &posterior, &matrix, 0.1, &metric)
+                .unwrap();
+        // Steepest slope is between neighbours: max(10, 20, 20) / 1 = 20.
+        assert!((graded.lipschitz - 20.0).abs() < 1e-9);
+        assert!(graded.robust_loss < uniform.robust_loss);
+
+        let set = AmbiguitySet::Wasserstein {
+            epsilon: 0.1,
+            metric,
+            method: DroMethod::LipschitzBound,
+        };
+        let via_set = set.robust_loss(Action::Keep, &posterior, &matrix).unwrap();
+        assert_eq!(via_set.robust_loss, graded.robust_loss);
+        let exact =
+            compute_wasserstein_dro_exact(Action::Keep, &posterior, &matrix, 0.1, &metric).unwrap();
+        assert!(exact.robust_loss <= graded.robust_loss + 1e-9);
+    }
+
+    #[test]
+    fn exact_dual_rejects_bad_inputs() {
+        let mut cost = GroundMetric::uniform().cost;
+        cost[0][1] = 0.0;
+        assert!(matches!(
+            GroundMetric::new(cost),
+            Err(DroError::InvalidGroundMetric { .. })
+        ));
+        let result = compute_wasserstein_dro_exact(
+            Action::Kill,
+            &balanced_posterior(),
+            &test_loss_matrix(),
+            -0.1,
+            &GroundMetric::uniform(),
+        );
+        assert!(matches!(result, Err(DroError::InvalidEpsilon { .. })));
+    }
+
+    #[test]
+    fn decide_with_chi2_set_reports_radius() {
+        let policy = Policy {
+            loss_matrix: flat_and_spread_matrix(),
+            ..Policy::default()
+        };
+        let posterior = ClassScores {
+            useful: 0.2,
+            useful_bad: 0.2,
+            abandoned: 0.3,
+            zombie: 0.3,
+        };
+        let outcome = decide_with_ambiguity_set(
+            &posterior,
+            &policy,
+            &[Action::Keep, Action::Kill],
+            AmbiguitySet::ChiSquare { rho: 0.5 },
+            Action::Kill,
+            "test",
+        )
+        .unwrap();
+        assert_eq!(outcome.ambiguity_radius, 0.5);
+        // Equal nominal losses; χ² penalizes Kill's spread, so Keep wins.
+        assert_eq!(outcome.robust_action, Action::Keep);
+        assert!(outcome.action_changed);
+    }
+
     // ── tie_break_rank ordering ─────────────────────────────────────
 
     #[test]
@@ -1419,4 +2083,47 @@ mod tests {
             "Quarantine should use Throttle loss"
         );
     }
+
+    // ── PPC results feed the trigger ────────────────────────────────
+
+    #[test]
+    fn ppc_results_set_trigger() {
+        use crate::inference::ppc::{PpcChecker, PpcConfig, PredictiveMoments};
+
+        let checker = PpcChecker::new(PpcConfig {
+            n_samples: 200,
+            ..Default::default()
+        });
+        let obs: Vec<f64> = (0..20).map(|i| 0.9 + 0.001 * i as f64).collect();
+
+        let result = checker.check_beta(&obs, 2.0, 8.0).unwrap();
+        let mut trigger = DroTrigger::none();
+        trigger.record_ppc(&result);
+        assert_eq!(trigger.ppc_failure, !result.passed);
+        assert!(trigger.ppc_failure);
+
+        let moments = PredictiveMoments::beta(2.0, 8.0).unwrap();
+        let result = checker.check_moments(&obs, &moments).unwrap();
+        let mut trigger = DroTrigger::none();
+        trigger.record_moment_ppc(&result);
+        assert!(trigger.ppc_failure);
+        assert_eq!(trigger.reason(), "ppc_failure");
+    }
+
+    #[test]
+    fn passing_moment_ppc_leaves_trigger_unset() {
+        use crate::inference::ppc::{PpcChecker, PredictiveMoments};
+
+        let moments = PredictiveMoments::normal(0.0, 1.0).unwrap();
+        let obs: Vec<f64> = (0..40)
+            .map(|i| pt_math::normal_quantile((i as f64 + 0.5) / 40.0))
+            .collect();
+        let result = PpcChecker::default().check_moments(&obs, &moments).unwrap();
+        assert!(!result.failed);
+
+        let mut trigger = DroTrigger::none();
+        trigger.record_moment_ppc(&result);
+        assert!(!trigger.ppc_failure);
+        assert!(!trigger.should_apply());
+    }
 }
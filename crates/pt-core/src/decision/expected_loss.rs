@@ -5,6 +5,7 @@ use crate::config::priors::Priors;
 use crate::decision::causal_interventions::{expected_recovery_by_action, RecoveryExpectation};
 use crate::decision::cvar::{decide_with_cvar, CvarTrigger, RiskSensitiveOutcome};
 use crate::decision::dro::{apply_dro_gate, DroOutcome, DroTrigger};
+use crate::decision::protection_list::ProtectionVerdict;
 use crate::inference::ClassScores;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -24,6 +25,8 @@ pub enum Action {
     /// Unfreeze a previously frozen process (follow-up to Freeze, not a decision action).
     Unfreeze,
     Throttle,
+    /// Restore the CPU/IO limits recorded before a throttle (follow-up to Throttle).
+    Unthrottle,
     /// Quarantine process by restricting it to a limited cpuset (cgroup cpuset controller).
     Quarantine,
     /// Unquarantine a previously quarantined process (follow-up to Quarantine).
@@ -33,7 +36,7 @@ pub enum Action {
 }
 
 impl Action {
-    /// Actions available for decision-making (excludes Resume/Unfreeze/Unquarantine, which are follow-up actions).
+    /// Actions available for decision-making (excludes Resume/Unfreeze/Unthrottle/Unquarantine, which are follow-up actions).
     pub(crate) const ALL: [Action; 8] = [
         Action::Keep,
         Action::Renice,
@@ -56,6 +59,7 @@ impl Action {
             Action::Quarantine => 3,   // Same rank as Throttle (resource restriction)
             Action::Unquarantine => 3, // Same rank as Quarantine (both reversible)
             Action::Throttle => 3,
+            Action::Unthrottle => 3, // Same rank as Throttle (both reversible)
             Action::Restart => 4,
             Action::Kill => 5,
         }
@@ -71,6 +75,7 @@ impl Action {
                 | Action::Unfreeze
                 | Action::Renice
                 | Action::Throttle
+                | Action::Unthrottle
                 | Action::Quarantine
                 | Action::Unquarantine
         )
@@ -80,7 +85,7 @@ impl Action {
     pub fn is_follow_up(&self) -> bool {
         matches!(
             self,
-            Action::Resume | Action::Unfreeze | Action::Unquarantine
+            Action::Resume | Action::Unfreeze | Action::Unthrottle | Action::Unquarantine
         )
     }
 }
@@ -198,6 +203,20 @@ pub struct ExpectedLoss {
     pub loss: f64,
 }
 
+/// An action's place in the expected-loss ranking.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct RankedAction {
+    /// 1-based position, lowest expected loss first.
+    pub rank: usize,
+    pub action: Action,
+    pub loss: f64,
+    /// Loss above the best feasible action (negative for an infeasible
+    /// action that would have been cheaper).
+    pub delta_from_optimal: f64,
+    /// Whether the feasibility mask allows this action.
+    pub feasible: bool,
+}
+
 /// SPRT-style boundary information.
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct SprtBoundary {
@@ -225,6 +244,9 @@ pub struct DecisionRationale {
     /// Command category (e.g. "test", "dev") if detected.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub category: Option<String>,
+    /// Policy protection-list verdict that overrode normal decisioning.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protection: Option<ProtectionVerdict>,
 }
 
 /// Decision output for a single candidate.
@@ -308,6 +330,7 @@ pub fn decide_action(
             memory_mb: None,
             has_known_signature: None,
             category: None,
+            protection: None,
         },
         risk_sensitive: None,
         dro: None,
@@ -388,6 +411,7 @@ pub fn decide_action_with_recovery(
             memory_mb: None,
             has_known_signature: None,
             category: None,
+            protection: None,
         },
         risk_sensitive: None,
         dro: None,
@@ -415,6 +439,9 @@ pub fn apply_risk_sensitive_control(
     trigger: &CvarTrigger,
     alpha: f64,
 ) -> DecisionOutcome {
+    if outcome.rationale.protection.is_some() {
+        return outcome;
+    }
     if !trigger.should_apply() {
         outcome.risk_sensitive = Some(RiskSensitiveOutcome {
             applied: false,
@@ -500,6 +527,10 @@ pub fn apply_dro_control(
     trigger: &DroTrigger,
     epsilon: f64,
 ) -> DecisionOutcome {
+    if outcome.rationale.protection.is_some() {
+        return outcome;
+    }
+
     // Get feasible actions from the expected loss results
     let feasible_actions: Vec<Action> = outcome.expected_loss.iter().map(|e| e.action).collect();
 
@@ -554,15 +585,10 @@ pub(crate) fn expected_loss_for_action(
     posterior: &ClassScores,
     loss_matrix: &LossMatrix,
 ) -> Result<f64, DecisionError> {
-    let useful = loss_for_action(&loss_matrix.useful, action, "useful")?;
-    let useful_bad = loss_for_action(&loss_matrix.useful_bad, action, "useful_bad")?;
-    let abandoned = loss_for_action(&loss_matrix.abandoned, action, "abandoned")?;
-    let zombie = loss_for_action(&loss_matrix.zombie, action, "zombie")?;
-
-    let loss = posterior.useful * useful
-        + posterior.useful_bad * useful_bad
-        + posterior.abandoned * abandoned
-        + posterior.zombie * zombie;
+    let mut loss = 0.0;
+    for (class, row) in loss_matrix.rows() {
+        loss += posterior[class] * loss_for_action(row, action, class.as_str())?;
+    }
 
     // Guard against NaN/Inf propagation from invalid posteriors
     if loss.is_nan() || loss.is_infinite() {
@@ -602,35 +628,102 @@ fn loss_for_action(
             .restart
             .ok_or(DecisionError::MissingLoss { action, class }),
         Action::Kill => Ok(row.kill),
-        // Resume/Unfreeze/Unquarantine are follow-up actions, not primary decisions, so no loss entry
-        Action::Resume | Action::Unfreeze | Action::Unquarantine => {
+        // Resume/Unfreeze/Unthrottle/Unquarantine are follow-up actions, not primary decisions, so no loss entry
+        Action::Resume | Action::Unfreeze | Action::Unthrottle | Action::Unquarantine => {
             Err(DecisionError::MissingLoss { action, class })
         }
     }
 }
 
+/// Absolute tolerance under which two expected losses count as tied.
+///
+/// Losses are sums of posterior-weighted loss-matrix entries, so actions whose
+/// rows produce the same value can differ by floating-point rounding (well
+/// below 1e-12 for matrices in the usual 0–1000 range). Within this tolerance
+/// the safer action wins; see [`select_optimal_action`].
+pub const EXPECTED_LOSS_TIE_EPSILON: f64 = 1e-12;
+
 /// Select the optimal action from a list of expected losses.
 /// Returns (action, tie_break) where tie_break is true if multiple actions had equal loss.
 /// This is exposed for use by VOI computation.
+///
+/// Every action within [`EXPECTED_LOSS_TIE_EPSILON`] of the minimum loss is a
+/// candidate, and the most reversible one (lowest tie-break rank) is chosen,
+/// so the result does not depend on the order of `expected`. Actions of equal
+/// rank fall back to declaration order of [`Action`].
 pub(crate) fn select_optimal_action(expected: &[ExpectedLoss]) -> (Action, bool) {
-    let mut best = &expected[0];
+    let min_loss = expected
+        .iter()
+        .map(|e| e.loss)
+        .fold(f64::INFINITY, f64::min);
+    let mut tied = expected
+        .iter()
+        .filter(|e| e.loss - min_loss <= EXPECTED_LOSS_TIE_EPSILON);
+    let Some(first) = tied.next() else {
+        // Only reachable when every loss is NaN; keep the first action.
+        return (expected[0].action, false);
+    };
+    let mut best = first;
     let mut tie_break = false;
-    for cand in expected.iter().skip(1) {
-        if cand.loss < best.loss {
+    for cand in tied {
+        tie_break = true;
+        if tie_key(cand.action) < tie_key(best.action) {
             best = cand;
-            tie_break = false;
-        } else if (cand.loss - best.loss).abs() <= 1e-12 {
-            if cand.action.tie_break_rank() < best.action.tie_break_rank() {
-                best = cand;
-                tie_break = true;
-            } else {
-                tie_break = true;
-            }
         }
     }
     (best.action, tie_break)
 }
 
+/// Ordering among tied actions: most reversible first, then declaration order.
+fn tie_key(action: Action) -> (u8, u8) {
+    (action.tie_break_rank(), action as u8)
+}
+
+/// Rank actions by expected loss, ascending, annotated with the gap to the
+/// optimum and whether `feasibility` allows them.
+///
+/// Entries are sorted by loss, then by the tie-break order of
+/// [`select_optimal_action`]; NaN losses sort last. The optimum is the best
+/// feasible action, or the best overall when nothing is feasible. Among the
+/// candidates within [`EXPECTED_LOSS_TIE_EPSILON`] of the optimum, the one
+/// [`select_optimal_action`] would choose is moved to the front of that
+/// group, so the first feasible entry is always the chosen action.
+pub fn rank_actions(losses: &[ExpectedLoss], feasibility: &ActionFeasibility) -> Vec<RankedAction> {
+    let sort_loss = |loss: f64| if loss.is_nan() { f64::INFINITY } else { loss };
+    let mut sorted: Vec<&ExpectedLoss> = losses.iter().collect();
+    sorted.sort_by(|a, b| {
+        sort_loss(a.loss)
+            .total_cmp(&sort_loss(b.loss))
+            .then_with(|| tie_key(a.action).cmp(&tie_key(b.action)))
+    });
+
+    let any_feasible = sorted.iter().any(|e| feasibility.is_allowed(e.action));
+    let is_candidate = |e: &ExpectedLoss| !any_feasible || feasibility.is_allowed(e.action);
+    let mut optimal = 0.0;
+    if let Some(first) = sorted.iter().position(|e| is_candidate(e)) {
+        let best_loss = sort_loss(sorted[first].loss);
+        let winner = (first..sorted.len())
+            .take_while(|&i| sort_loss(sorted[i].loss) - best_loss <= EXPECTED_LOSS_TIE_EPSILON)
+            .filter(|&i| is_candidate(sorted[i]))
+            .min_by_key(|&i| tie_key(sorted[i].action))
+            .unwrap_or(first);
+        sorted[first..=winner].rotate_right(1);
+        optimal = sorted[first].loss;
+    }
+
+    sorted
+        .into_iter()
+        .enumerate()
+        .map(|(i, e)| RankedAction {
+            rank: i + 1,
+            action: e.action,
+            loss: e.loss,
+            delta_from_optimal: e.loss - optimal,
+            feasible: feasibility.is_allowed(e.action),
+        })
+        .collect()
+}
+
 fn select_action_with_recovery(
     expected: &[ExpectedLoss],
     recovery: &[RecoveryExpectation],
@@ -739,6 +832,73 @@ mod tests {
         assert!(approx_eq(keep_loss, expected, 1e-12));
     }
 
+    #[test]
+    fn class_indexed_loss_is_bit_identical_to_named_fields() {
+        let policy = policy_for_tests();
+        let m = &policy.loss_matrix;
+        let posteriors = [
+            ClassScores {
+                useful: 0.5,
+                useful_bad: 0.2,
+                abandoned: 0.2,
+                zombie: 0.1,
+            },
+            ClassScores {
+                useful: 0.013,
+                useful_bad: 0.271,
+                abandoned: 0.7,
+                zombie: 0.016,
+            },
+            ClassScores {
+                useful: 1.0 / 3.0,
+                useful_bad: 1.0 / 7.0,
+                abandoned: 1.0 - 1.0 / 3.0 - 1.0 / 7.0 - 1e-3,
+                zombie: 1e-3,
+            },
+        ];
+        let actions = [
+            (
+                Action::Keep,
+                [
+                    m.useful.keep,
+                    m.useful_bad.keep,
+                    m.abandoned.keep,
+                    m.zombie.keep,
+                ],
+            ),
+            (
+                Action::Kill,
+                [
+                    m.useful.kill,
+                    m.useful_bad.kill,
+                    m.abandoned.kill,
+                    m.zombie.kill,
+                ],
+            ),
+            (
+                Action::Pause,
+                [
+                    m.useful.pause,
+                    m.useful_bad.pause,
+                    m.abandoned.pause,
+                    m.zombie.pause,
+                ]
+                .map(Option::unwrap),
+            ),
+        ];
+        for posterior in &posteriors {
+            for (action, [u, ub, a, z]) in actions {
+                // The pre-`Class` formula, term for term.
+                let named = posterior.useful * u
+                    + posterior.useful_bad * ub
+                    + posterior.abandoned * a
+                    + posterior.zombie * z;
+                let generic = expected_loss_for_action(action, posterior, m).unwrap();
+                assert_eq!(generic.to_bits(), named.to_bits(), "{action:?}");
+            }
+        }
+    }
+
     #[test]
     fn tie_break_prefers_reversible() {
         let mut policy = policy_for_tests();
@@ -788,6 +948,157 @@ mod tests {
         assert!(outcome.rationale.tie_break);
     }
 
+    #[test]
+    fn keep_kill_tie_is_order_independent() {
+        let mut policy = policy_for_tests();
+        let row = |keep: f64, kill: f64| LossRow {
+            keep,
+            renice: None,
+            pause: None,
+            throttle: None,
+            kill,
+            restart: None,
+        };
+        // Keep and Kill cost the same for every class, so expected losses tie.
+        policy.loss_matrix = LossMatrix {
+            useful: row(4.0, 4.0),
+            useful_bad: row(4.0, 4.0),
+            abandoned: row(4.0, 4.0),
+            zombie: row(4.0, 4.0),
+        };
+        let posterior = ClassScores {
+            useful: 0.1,
+            useful_bad: 0.2,
+            abandoned: 0.3,
+            zombie: 0.4,
+        };
+        let outcome =
+            decide_action(&posterior, &policy, &ActionFeasibility::allow_all()).expect("decision");
+        assert_eq!(outcome.optimal_action, Action::Keep);
+        assert!(outcome.rationale.tie_break);
+
+        // Same result whichever way the candidates are ordered.
+        let forward = [
+            ExpectedLoss {
+                action: Action::Keep,
+                loss: 4.0,
+            },
+            ExpectedLoss {
+                action: Action::Kill,
+                loss: 4.0,
+            },
+        ];
+        let mut reversed = forward.clone();
+        reversed.reverse();
+        assert_eq!(select_optimal_action(&forward), (Action::Keep, true));
+        assert_eq!(select_optimal_action(&reversed), (Action::Keep, true));
+    }
+
+    #[test]
+    fn near_tie_within_epsilon_prefers_reversible() {
+        let kill_first = [
+            ExpectedLoss {
+                action: Action::Kill,
+                loss: 1.0 - EXPECTED_LOSS_TIE_EPSILON / 2.0,
+            },
+            ExpectedLoss {
+                action: Action::Keep,
+                loss: 1.0,
+            },
+        ];
+        assert_eq!(select_optimal_action(&kill_first), (Action::Keep, true));
+
+        // Outside the tolerance the strictly cheaper action wins.
+        let clear_win = [
+            ExpectedLoss {
+                action: Action::Keep,
+                loss: 1.0,
+            },
+            ExpectedLoss {
+                action: Action::Kill,
+                loss: 1.0 - 1e-6,
+            },
+        ];
+        assert_eq!(select_optimal_action(&clear_win), (Action::Kill, false));
+    }
+
+    fn losses(entries: &[(Action, f64)]) -> Vec<ExpectedLoss> {
+        entries
+            .iter()
+            .map(|&(action, loss)| ExpectedLoss { action, loss })
+            .collect()
+    }
+
+    #[test]
+    fn rank_actions_sorts_with_deltas() {
+        let expected = losses(&[
+            (Action::Kill, 3.0),
+            (Action::Keep, 5.0),
+            (Action::Pause, 1.0),
+            (Action::Renice, 1.0),
+        ]);
+        let ranked = rank_actions(&expected, &ActionFeasibility::allow_all());
+        let order: Vec<Action> = ranked.iter().map(|r| r.action).collect();
+        // Renice beats Pause on the tie because it is more reversible.
+        assert_eq!(
+            order,
+            vec![Action::Renice, Action::Pause, Action::Kill, Action::Keep]
+        );
+        assert_eq!(
+            ranked.iter().map(|r| r.rank).collect::<Vec<_>>(),
+            vec![1, 2, 3, 4]
+        );
+        assert_eq!(ranked[0].delta_from_optimal, 0.0);
+        assert_eq!(ranked[1].delta_from_optimal, 0.0);
+        assert_eq!(ranked[2].delta_from_optimal, 2.0);
+        assert_eq!(ranked[3].delta_from_optimal, 4.0);
+        assert_eq!(ranked[0].action, select_optimal_action(&expected).0);
+    }
+
+    #[test]
+    fn rank_actions_measures_delta_from_best_feasible() {
+        let expected = losses(&[
+            (Action::Kill, 1.0),
+            (Action::Keep, 4.0),
+            (Action::Pause, 6.0),
+        ]);
+        let feasibility = ActionFeasibility::from_process_state(false, true, None);
+        let ranked = rank_actions(&expected, &feasibility);
+
+        assert_eq!(ranked[0].action, Action::Kill);
+        assert!(!ranked[0].feasible);
+        assert_eq!(ranked[0].delta_from_optimal, -3.0);
+        assert!(ranked[1].feasible);
+        assert_eq!(ranked[1].delta_from_optimal, 0.0);
+        assert_eq!(ranked[2].delta_from_optimal, 2.0);
+    }
+
+    #[test]
+    fn rank_actions_applies_epsilon_only_at_the_top() {
+        // Each neighbour is within epsilon, but the ends are not: an
+        // epsilon-tolerant comparator would be intransitive here.
+        let step = EXPECTED_LOSS_TIE_EPSILON * 0.6;
+        let expected = losses(&[
+            (Action::Keep, 1.0 + 2.0 * step),
+            (Action::Pause, 1.0 + step),
+            (Action::Kill, 1.0),
+        ]);
+        let ranked = rank_actions(&expected, &ActionFeasibility::allow_all());
+        let order: Vec<Action> = ranked.iter().map(|r| r.action).collect();
+
+        assert_eq!(ranked[0].action, select_optimal_action(&expected).0);
+        assert_eq!(order, vec![Action::Pause, Action::Kill, Action::Keep]);
+    }
+
+    #[test]
+    fn rank_actions_puts_nan_last() {
+        let expected = losses(&[(Action::Kill, f64::NAN), (Action::Keep, 2.0)]);
+        let ranked = rank_actions(&expected, &ActionFeasibility::allow_all());
+        assert_eq!(ranked[0].action, Action::Keep);
+        assert_eq!(ranked[1].action, Action::Kill);
+        assert!(rank_actions(&[], &ActionFeasibility::allow_all()).is_empty());
+    }
+
     #[test]
     fn invalid_posterior_rejected() {
         let policy = policy_for_tests();
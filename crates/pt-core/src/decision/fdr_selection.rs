@@ -60,6 +60,8 @@ pub struct FdrSelectionResult {
     pub method: FdrMethod,
     /// BY correction factor c(m) if applicable.
     pub correction_factor: Option<f64>,
+    /// Alpha after correction (`alpha / c(m)` for eBY, `alpha` otherwise).
+    pub effective_alpha: f64,
     /// Total number of candidates evaluated.
     pub m_candidates: usize,
     /// Number of candidates selected.
@@ -132,10 +134,7 @@ pub fn select_fdr(
 
     // Compute BY correction factor c(m) = sum_{j=1..m} 1/j
     let correction = match method {
-        FdrMethod::EBy => {
-            let c_m: f64 = (1..=m).map(|j| 1.0 / j as f64).sum();
-            Some(c_m)
-        }
+        FdrMethod::EBy => Some(by_correction_factor(m)),
         _ => None,
     };
 
@@ -210,6 +209,7 @@ pub fn select_fdr(
         alpha,
         method,
         correction_factor: correction,
+        effective_alpha,
         m_candidates: m,
         selected_k,
         selection_threshold,
@@ -306,6 +306,29 @@ mod tests {
         assert!((c4 - 2.083).abs() < 0.01);
     }
 
+    #[test]
+    fn test_eby_never_selects_more_than_ebh() {
+        let candidates: Vec<FdrCandidate> = (1..=20)
+            .map(|pid| make_candidate(pid, 400.0 / pid as f64))
+            .collect();
+
+        for alpha in [0.01, 0.05, 0.1, 0.2, 0.5, 1.0] {
+            let ebh = select_fdr(&candidates, alpha, FdrMethod::EBh).unwrap();
+            let eby = select_fdr(&candidates, alpha, FdrMethod::EBy).unwrap();
+            assert!(
+                eby.selected_k <= ebh.selected_k,
+                "alpha {alpha}: eBY selected {} > eBH {}",
+                eby.selected_k,
+                ebh.selected_k
+            );
+            assert_eq!(ebh.effective_alpha, alpha);
+            assert_eq!(ebh.correction_factor, None);
+            let c_m = eby.correction_factor.unwrap();
+            assert_eq!(c_m, by_correction_factor(20));
+            assert!((eby.effective_alpha - alpha / c_m).abs() < 1e-12);
+        }
+    }
+
     #[test]
     fn test_deterministic_ordering() {
         // Selection should be deterministic for same inputs
@@ -10,6 +10,7 @@ pub mod contextual_bandits;
 pub mod cvar;
 pub mod dependency_loss;
 pub mod direct_impact;
+pub mod drift;
 pub mod dro;
 pub mod enforcer;
 pub mod escalation;
@@ -29,6 +30,8 @@ pub mod martingale_gates;
 pub mod mem_pressure;
 pub mod myopic_policy;
 pub mod ope;
+pub mod probe_scheduler;
+pub mod protection_list;
 pub mod provenance_explanation;
 pub mod provenance_scoring;
 pub mod rate_limit;
@@ -81,9 +84,15 @@ pub use direct_impact::{
     compute_direct_impact, compute_direct_impact_batch, DirectImpactComponents, DirectImpactConfig,
     DirectImpactResult,
 };
+pub use drift::{
+    detect_drift, dro_trigger_from_drift, feature_samples, CPU_FEATURE, MEMORY_FEATURE,
+    RUNTIME_FEATURE,
+};
 pub use dro::{
-    apply_dro_gate, compute_adaptive_epsilon, compute_wasserstein_dro, decide_with_dro,
-    is_de_escalation, DroError, DroLoss, DroOutcome, DroTrigger,
+    apply_dro_gate, compute_adaptive_epsilon, compute_chi2_dro, compute_wasserstein_dro,
+    compute_wasserstein_dro_exact, compute_wasserstein_dro_with_metric, decide_with_ambiguity_set,
+    decide_with_dro, is_de_escalation, AmbiguitySet, DroError, DroLoss, DroMethod, DroOutcome,
+    DroTrigger, GroundMetric,
 };
 pub use enforcer::{
     CriticalFilesSummary, EnforcerError, PolicyCheckResult, PolicyEnforcer, PolicyViolation,
@@ -91,8 +100,8 @@ pub use enforcer::{
 };
 pub use expected_loss::{
     apply_dro_control, apply_risk_sensitive_control, decide_action, decide_action_with_recovery,
-    Action, ActionFeasibility, DecisionError, DecisionOutcome, DecisionRationale, DisabledAction,
-    ExpectedLoss, SprtBoundary,
+    rank_actions, Action, ActionFeasibility, DecisionError, DecisionOutcome, DecisionRationale,
+    DisabledAction, ExpectedLoss, RankedAction, SprtBoundary, EXPECTED_LOSS_TIE_EPSILON,
 };
 pub use fdr_selection::{
     by_correction_factor, select_fdr, CandidateSelection, FdrCandidate, FdrError, FdrMethod,
@@ -119,6 +128,13 @@ pub use myopic_policy::{
 pub use ope::{
     DoublyRobustEstimator, IpsEstimator, LoggedDecision, OpeError, OpeRecommendation, OpeResult,
 };
+#[cfg(target_os = "linux")]
+pub use probe_scheduler::{CgroupInspectCollector, DeepScanCollector, KernelWaitCollector};
+pub use probe_scheduler::{
+    ProbeCollector, ProbeSchedule, ProbeScheduler, ProbeSchedulerError, ProbeStep,
+    ProbeStepOutcome, QuickScanCollector, ScheduleStopReason, DEFAULT_PROBE_SAMPLE,
+};
+pub use protection_list::{decide_with_protection, ProtectionRules, ProtectionVerdict};
 pub use provenance_explanation::{
     build_explanation, CounterfactualStory, EvidenceDirection, MissingEvidence,
     PotentialImprovement, ProvenanceExplanation, RankedEvidence,
@@ -132,8 +148,8 @@ pub use robot_constraints::{
     ConstraintSources, ConstraintViolation, RobotCandidate, RuntimeRobotConstraints,
 };
 pub use sequential::{
-    decide_sequential, prioritize_by_esn, EsnCandidate, EsnPriority, SequentialDecision,
-    SequentialError, SequentialLedgerEntry,
+    decide_sequential, decide_sequential_with_availability, prioritize_by_esn, EsnCandidate,
+    EsnPriority, SequentialDecision, SequentialError, SequentialLedgerEntry,
 };
 pub use submodular::{
     coverage_marginal_gain, coverage_utility, greedy_select_k, greedy_select_with_budget,
@@ -144,8 +160,10 @@ pub use time_bound::{
     TimeBoundOutcome,
 };
 pub use voi::{
-    compute_voi, select_probe_by_information_gain, ProbeCost, ProbeCostModel, ProbeInformationGain,
-    ProbeType, ProbeVoi, VoiAnalysis, VoiError,
+    compute_voi, compute_voi_sequential, compute_voi_with_availability,
+    select_probe_by_information_gain, ProbeAvailability, ProbeCost, ProbeCostModel,
+    ProbeInformationGain, ProbeSequence, ProbeType, ProbeVoi, VoiAnalysis, VoiError,
+    MAX_VOI_LOOKAHEAD_DEPTH,
 };
 pub use wonham_gittins::{
     compute_gittins_index, compute_gittins_schedule, GeneratorMatrix, GittinsCandidate,
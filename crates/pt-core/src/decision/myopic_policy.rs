@@ -314,7 +314,7 @@ fn get_action_cost(
             action,
             class: class_name,
         }),
-        Action::Throttle => row.throttle.ok_or(DecisionError::MissingLoss {
+        Action::Throttle | Action::Unthrottle => row.throttle.ok_or(DecisionError::MissingLoss {
             action,
             class: class_name,
         }),
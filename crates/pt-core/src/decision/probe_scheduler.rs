@@ -0,0 +1,646 @@
+//! Closed-loop probe scheduling driven by VOI.
+//!
+//! [`compute_voi`](super::voi::compute_voi) answers a single question: act
+//! now, or which probe next? [`ProbeScheduler`] closes the loop. It runs the
+//! recommended probe through a registered [`ProbeCollector`], folds the
+//! returned evidence terms into the posterior, and asks again, until VOI says
+//! to act now or an iteration or cost budget runs out. Probes the host cannot
+//! run (see [`ProbeScheduler::with_availability`]) are never recommended.
+//!
+//! Each probe runs at most once per process: a probe that ran, failed, or was
+//! skipped is removed from the candidate set, so its evidence is never folded
+//! in twice. Probes without a registered collector (for example the wait
+//! probes, which have no synchronous collector) are skipped with a logged
+//! reason, and VOI falls back to the next best probe.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use serde::Serialize;
+use thiserror::Error;
+use tracing::{debug, warn};
+
+use super::expected_loss::{decide_action, ActionFeasibility, DecisionError, DecisionOutcome};
+use super::voi::{
+    compute_voi_with_availability, ProbeAvailability, ProbeCostModel, ProbeType, VoiAnalysis,
+    VoiError,
+};
+use crate::config::policy::Policy;
+use crate::config::priors::Priors;
+use crate::inference::{
+    apply_evidence_terms, compute_posterior, ClassScores, CpuEvidence, Evidence, EvidenceTerm,
+    PosteriorError, PosteriorResult,
+};
+
+/// Default cap on probes executed per process.
+pub const DEFAULT_MAX_PROBES: usize = 5;
+
+/// Default window over which the sampling probes measure CPU use.
+pub const DEFAULT_PROBE_SAMPLE: Duration = Duration::from_millis(500);
+
+/// Runs one kind of probe against a process.
+pub trait ProbeCollector {
+    /// Probe `pid` and return the evidence terms it produced.
+    ///
+    /// An empty vector means the probe ran but observed nothing informative.
+    fn collect(&mut self, pid: u32) -> Result<Vec<EvidenceTerm>, String>;
+}
+
+impl<F> ProbeCollector for F
+where
+    F: FnMut(u32) -> Result<Vec<EvidenceTerm>, String>,
+{
+    fn collect(&mut self, pid: u32) -> Result<Vec<EvidenceTerm>, String> {
+        self(pid)
+    }
+}
+
+/// Kernel stack sampler backing [`ProbeType::StackSample`].
+#[cfg(target_os = "linux")]
+#[derive(Debug, Clone, Default)]
+pub struct KernelWaitCollector {
+    pub config: crate::inference::KernelWaitConfig,
+}
+
+#[cfg(target_os = "linux")]
+impl ProbeCollector for KernelWaitCollector {
+    fn collect(&mut self, pid: u32) -> Result<Vec<EvidenceTerm>, String> {
+        let stat = crate::collect::proc_parsers::parse_proc_stat(pid)
+            .ok_or_else(|| format!("/proc/{pid}/stat unreadable"))?;
+        let sample = crate::collect::collect_kernel_wait(pid, stat.state);
+        for warning in &sample.warnings {
+            debug!(pid, warning = %warning, "kernel wait sample warning");
+        }
+        Ok(crate::inference::kernel_wait_term(&sample, &self.config)
+            .into_iter()
+            .collect())
+    }
+}
+
+/// Terms `evidence` contributes on its own, without the prior term.
+fn observation_terms(priors: &Priors, evidence: &Evidence) -> Result<Vec<EvidenceTerm>, String> {
+    let result = compute_posterior(priors, evidence).map_err(|e| e.to_string())?;
+    Ok(result
+        .evidence_terms
+        .into_iter()
+        .filter(|term| term.feature != "prior")
+        .collect())
+}
+
+/// Fresh CPU tick sample backing [`ProbeType::QuickScan`].
+///
+/// The scan's CPU figure is a lifetime average; this measures occupancy
+/// over [`Self::sample`] instead.
+#[derive(Debug, Clone)]
+pub struct QuickScanCollector {
+    pub priors: Priors,
+    pub sample: Duration,
+    pub tick_config: crate::collect::TickDeltaConfig,
+}
+
+impl QuickScanCollector {
+    pub fn new(priors: Priors) -> Self {
+        Self {
+            priors,
+            sample: DEFAULT_PROBE_SAMPLE,
+            tick_config: crate::collect::TickDeltaConfig::default(),
+        }
+    }
+}
+
+impl ProbeCollector for QuickScanCollector {
+    fn collect(&mut self, pid: u32) -> Result<Vec<EvidenceTerm>, String> {
+        let delta = crate::collect::sample_tick_delta(pid, self.sample, &self.tick_config)
+            .ok_or_else(|| format!("/proc/{pid}/stat unreadable"))?;
+        let evidence = Evidence {
+            cpu: Some(CpuEvidence::Fraction {
+                occupancy: delta.u.clamp(0.0, 1.0),
+            }),
+            ..Evidence::default()
+        };
+        observation_terms(&self.priors, &evidence)
+    }
+}
+
+/// `/proc` I/O and socket inspection backing [`ProbeType::DeepScan`].
+#[cfg(target_os = "linux")]
+#[derive(Debug, Clone)]
+pub struct DeepScanCollector {
+    pub priors: Priors,
+}
+
+#[cfg(target_os = "linux")]
+impl ProbeCollector for DeepScanCollector {
+    fn collect(&mut self, pid: u32) -> Result<Vec<EvidenceTerm>, String> {
+        let options = crate::collect::DeepScanOptions {
+            pids: vec![pid],
+            ..Default::default()
+        };
+        let record = crate::collect::deep_scan(&options)
+            .map_err(|e| e.to_string())?
+            .processes
+            .into_iter()
+            .next()
+            .ok_or_else(|| format!("pid {pid} not found by deep scan"))?;
+        let evidence = Evidence {
+            io_active: record
+                .io
+                .as_ref()
+                .map(|io| io.read_bytes > 0 || io.write_bytes > 0),
+            net: record.network.as_ref().map(|info| {
+                let counts = &info.socket_counts;
+                counts.tcp + counts.tcp6 + counts.udp + counts.udp6 + counts.unix + counts.raw > 0
+                    || !info.listen_ports.is_empty()
+            }),
+            ..Evidence::default()
+        };
+        observation_terms(&self.priors, &evidence)
+    }
+}
+
+/// Cgroup CPU accounting backing [`ProbeType::CgroupInspect`].
+///
+/// `cpu.stat` covers every task in the cgroup, so usage is only attributed
+/// to the process when it is alone there (`pids.current` is 1, as for a
+/// per-service scope); otherwise the probe observes nothing.
+#[cfg(target_os = "linux")]
+#[derive(Debug, Clone)]
+pub struct CgroupInspectCollector {
+    pub priors: Priors,
+    pub sample: Duration,
+}
+
+#[cfg(target_os = "linux")]
+impl CgroupInspectCollector {
+    pub fn new(priors: Priors) -> Self {
+        Self {
+            priors,
+            sample: DEFAULT_PROBE_SAMPLE,
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl ProbeCollector for CgroupInspectCollector {
+    fn collect(&mut self, pid: u32) -> Result<Vec<EvidenceTerm>, String> {
+        let usage = |pid| {
+            let stats = crate::collect::collect_cgroup_stats(pid)
+                .ok_or_else(|| format!("/proc/{pid}/cgroup unreadable"))?;
+            Ok::<_, String>((stats.pids_current, stats.cpu.and_then(|cpu| cpu.usage_usec)))
+        };
+        let (tasks, Some(before)) = usage(pid)? else {
+            return Ok(Vec::new());
+        };
+        if tasks != Some(1) {
+            debug!(pid, tasks = ?tasks, "cgroup shared with other tasks; not attributing usage");
+            return Ok(Vec::new());
+        }
+        let started = std::time::Instant::now();
+        std::thread::sleep(self.sample);
+        let (_, Some(after)) = usage(pid)? else {
+            return Ok(Vec::new());
+        };
+        let elapsed_usec = started.elapsed().as_micros().max(1) as f64;
+        let evidence = Evidence {
+            cpu: Some(CpuEvidence::Fraction {
+                occupancy: (after.saturating_sub(before) as f64 / elapsed_usec).clamp(0.0, 1.0),
+            }),
+            ..Evidence::default()
+        };
+        observation_terms(&self.priors, &evidence)
+    }
+}
+
+/// What happened to a recommended probe.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum ProbeStepOutcome {
+    /// The collector ran; lists the evidence features it contributed.
+    Executed { features: Vec<String> },
+    /// No collector is registered for this probe.
+    Skipped { reason: String },
+    /// The collector returned an error.
+    Failed { error: String },
+}
+
+/// One entry in the probe trace.
+#[derive(Debug, Clone, Serialize)]
+pub struct ProbeStep {
+    pub probe: ProbeType,
+    /// Cost charged against the budget (zero for skipped probes).
+    pub cost: f64,
+    pub outcome: ProbeStepOutcome,
+    /// Posterior after this step.
+    pub posterior: ClassScores,
+}
+
+/// Why the scheduler stopped probing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScheduleStopReason {
+    /// VOI says no remaining probe is worth its cost.
+    ActNow,
+    /// The iteration cap was reached.
+    IterationCap,
+    /// The next probe would exceed the cost budget.
+    BudgetExhausted,
+    /// Every candidate probe was skipped or failed.
+    NoRunnableProbes,
+}
+
+/// Result of [`ProbeScheduler::run`].
+#[derive(Debug, Clone, Serialize)]
+pub struct ProbeSchedule {
+    /// Probes in the order they were recommended.
+    pub steps: Vec<ProbeStep>,
+    pub stop_reason: ScheduleStopReason,
+    /// Total cost of the probes that ran.
+    pub total_cost: f64,
+    /// Posterior after all executed probes.
+    pub posterior: PosteriorResult,
+    /// Last VOI analysis, if any was computed.
+    pub voi: Option<VoiAnalysis>,
+    /// Decision on the final posterior.
+    pub decision: DecisionOutcome,
+}
+
+impl ProbeSchedule {
+    /// Number of probes whose collector actually ran.
+    pub fn probes_executed(&self) -> usize {
+        self.steps
+            .iter()
+            .filter(|step| !matches!(step.outcome, ProbeStepOutcome::Skipped { .. }))
+            .count()
+    }
+}
+
+/// Errors from probe scheduling.
+#[derive(Debug, Error)]
+pub enum ProbeSchedulerError {
+    #[error("VOI error: {0}")]
+    Voi(#[from] VoiError),
+    #[error("posterior error: {0}")]
+    Posterior(#[from] PosteriorError),
+    #[error("decision error: {0}")]
+    Decision(#[from] DecisionError),
+}
+
+/// Dispatches VOI-recommended probes until acting is optimal.
+pub struct ProbeScheduler {
+    collectors: HashMap<ProbeType, Box<dyn ProbeCollector>>,
+    availability: ProbeAvailability,
+    max_iterations: usize,
+    budget: f64,
+}
+
+impl Default for ProbeScheduler {
+    fn default() -> Self {
+        Self {
+            collectors: HashMap::new(),
+            availability: ProbeAvailability::all(),
+            max_iterations: DEFAULT_MAX_PROBES,
+            budget: f64::INFINITY,
+        }
+    }
+}
+
+impl ProbeScheduler {
+    /// Scheduler with no collectors registered.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register the collectors that exist on this platform.
+    ///
+    /// `priors` turn each probe's observations into evidence terms.
+    pub fn with_default_collectors(mut self, priors: &Priors) -> Self {
+        self = self.with_collector(
+            ProbeType::QuickScan,
+            QuickScanCollector::new(priors.clone()),
+        );
+        #[cfg(target_os = "linux")]
+        {
+            self = self
+                .with_collector(
+                    ProbeType::DeepScan,
+                    DeepScanCollector {
+                        priors: priors.clone(),
+                    },
+                )
+                .with_collector(
+                    ProbeType::CgroupInspect,
+                    CgroupInspectCollector::new(priors.clone()),
+                )
+                .with_collector(ProbeType::StackSample, KernelWaitCollector::default());
+        }
+        self
+    }
+
+    /// Register `collector` for `probe`, replacing any previous one.
+    pub fn with_collector(
+        mut self,
+        probe: ProbeType,
+        collector: impl ProbeCollector + 'static,
+    ) -> Self {
+        self.collectors.insert(probe, Box::new(collector));
+        self
+    }
+
+    /// Only offer VOI the probes `availability` says this host can run.
+    pub fn with_availability(mut self, availability: ProbeAvailability) -> Self {
+        self.availability = availability;
+        self
+    }
+
+    /// Cap the number of probes run (failed probes count).
+    pub fn with_max_iterations(mut self, max_iterations: usize) -> Self {
+        self.max_iterations = max_iterations;
+        self
+    }
+
+    /// Cap the summed probe cost, in the same units as [`ProbeCostModel::cost`].
+    pub fn with_budget(mut self, budget: f64) -> Self {
+        self.budget = budget;
+        self
+    }
+
+    pub fn has_collector(&self, probe: ProbeType) -> bool {
+        self.collectors.contains_key(&probe)
+    }
+
+    /// Probe `pid` until VOI recommends acting or a cap is hit.
+    ///
+    /// `initial` is the posterior from the evidence already collected. Each
+    /// executed probe's terms are added with [`apply_evidence_terms`], and
+    /// the probe is not offered to VOI again.
+    pub fn run(
+        &mut self,
+        pid: u32,
+        initial: PosteriorResult,
+        policy: &Policy,
+        feasibility: &ActionFeasibility,
+        cost_model: &ProbeCostModel,
+    ) -> Result<ProbeSchedule, ProbeSchedulerError> {
+        let mut candidates: Vec<ProbeType> = ProbeType::ALL.to_vec();
+        let mut posterior = initial;
+        let mut steps = Vec::new();
+        let mut executed = 0;
+        let mut total_cost = 0.0;
+        let mut last_voi = None;
+
+        let stop_reason = loop {
+            if candidates.is_empty() {
+                break ScheduleStopReason::NoRunnableProbes;
+            }
+            let voi = compute_voi_with_availability(
+                &posterior.posterior,
+                policy,
+                feasibility,
+                cost_model,
+                Some(&candidates),
+                &self.availability,
+            )?;
+            let next = voi.best_probe.filter(|_| !voi.act_now).map(|probe| {
+                let cost = voi
+                    .probes
+                    .iter()
+                    .find(|p| p.probe == probe)
+                    .map_or_else(|| cost_model.cost(probe), |p| p.cost);
+                (probe, cost)
+            });
+            last_voi = Some(voi);
+            let Some((probe, cost)) = next else {
+                break ScheduleStopReason::ActNow;
+            };
+            if executed >= self.max_iterations {
+                break ScheduleStopReason::IterationCap;
+            }
+            if total_cost + cost > self.budget {
+                break ScheduleStopReason::BudgetExhausted;
+            }
+
+            let Some(collector) = self.collectors.get_mut(&probe) else {
+                debug!(
+                    pid,
+                    probe = probe.name(),
+                    "skipping probe: no collector available"
+                );
+                candidates.retain(|p| *p != probe);
+                steps.push(ProbeStep {
+                    probe,
+                    cost: 0.0,
+                    outcome: ProbeStepOutcome::Skipped {
+                        reason: format!("no collector for probe '{}'", probe.name()),
+                    },
+                    posterior: posterior.posterior,
+                });
+                continue;
+            };
+
+            executed += 1;
+            total_cost += cost;
+            // A probe's evidence is counted once, whatever the outcome.
+            candidates.retain(|p| *p != probe);
+            let outcome = match collector.collect(pid) {
+                Ok(terms) => {
+                    let features = terms.iter().map(|t| t.feature.clone()).collect();
+                    posterior = apply_evidence_terms(&posterior, terms)?;
+                    ProbeStepOutcome::Executed { features }
+                }
+                Err(error) => {
+                    warn!(pid, probe = probe.name(), error = %error, "probe failed");
+                    ProbeStepOutcome::Failed { error }
+                }
+            };
+            steps.push(ProbeStep {
+                probe,
+                cost,
+                outcome,
+                posterior: posterior.posterior,
+            });
+        };
+
+        let decision = decide_action(&posterior.posterior, policy, feasibility)?;
+        Ok(ProbeSchedule {
+            steps,
+            stop_reason,
+            total_cost,
+            posterior,
+            voi: last_voi,
+            decision,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::priors::Priors;
+    use crate::decision::expected_loss::Action;
+    use crate::decision::voi::compute_voi;
+    use crate::inference::{compute_posterior, Evidence};
+
+    fn uncertain() -> PosteriorResult {
+        let mut result = compute_posterior(&Priors::default(), &Evidence::default()).unwrap();
+        result.posterior = ClassScores {
+            useful: 0.4,
+            useful_bad: 0.1,
+            abandoned: 0.4,
+            zombie: 0.1,
+        };
+        result.log_posterior = ClassScores {
+            useful: 0.4f64.ln(),
+            useful_bad: 0.1f64.ln(),
+            abandoned: 0.4f64.ln(),
+            zombie: 0.1f64.ln(),
+        };
+        result
+    }
+
+    /// Probes cheap enough that an uncertain posterior is worth probing.
+    fn cheap_probes() -> ProbeCostModel {
+        ProbeCostModel {
+            base_multiplier: 0.01,
+            ..ProbeCostModel::default()
+        }
+    }
+
+    /// Stub that reports strong evidence of abandonment.
+    fn abandoned_stub(pid: u32) -> Result<Vec<EvidenceTerm>, String> {
+        assert_eq!(pid, 4242);
+        Ok(vec![EvidenceTerm {
+            feature: "stub".to_string(),
+            log_likelihood: ClassScores {
+                useful: -3.0,
+                useful_bad: -3.0,
+                abandoned: 0.0,
+                zombie: -3.0,
+            },
+        }])
+    }
+
+    #[test]
+    fn stub_collectors_converge_to_act_now() {
+        let policy = Policy::default();
+        let feasibility = ActionFeasibility::allow_all();
+        let cost_model = cheap_probes();
+        let initial = uncertain();
+
+        let first =
+            compute_voi(&initial.posterior, &policy, &feasibility, &cost_model, None).unwrap();
+        assert!(!first.act_now, "uncertain posterior should want a probe");
+
+        let mut scheduler = ProbeType::ALL
+            .iter()
+            .fold(ProbeScheduler::new(), |s, &probe| {
+                s.with_collector(probe, abandoned_stub)
+            });
+        let schedule = scheduler
+            .run(4242, initial, &policy, &feasibility, &cost_model)
+            .unwrap();
+
+        assert_eq!(schedule.stop_reason, ScheduleStopReason::ActNow);
+        assert!(schedule.probes_executed() >= 1);
+        assert!(schedule.probes_executed() <= DEFAULT_MAX_PROBES);
+        assert!(schedule.voi.as_ref().unwrap().act_now);
+        assert!(schedule.posterior.posterior.abandoned > 0.9);
+        assert_ne!(schedule.decision.optimal_action, Action::Keep);
+        assert!(schedule.total_cost > 0.0);
+        assert_eq!(schedule.steps[0].probe, first.best_probe.unwrap());
+        let mut probes: Vec<&str> = schedule.steps.iter().map(|s| s.probe.name()).collect();
+        probes.sort_unstable();
+        probes.dedup();
+        assert_eq!(probes.len(), schedule.steps.len(), "a probe ran twice");
+    }
+
+    #[test]
+    fn probes_without_collectors_are_skipped() {
+        let policy = Policy::default();
+        let feasibility = ActionFeasibility::allow_all();
+        let cost_model = cheap_probes();
+
+        let mut scheduler = ProbeScheduler::new();
+        let schedule = scheduler
+            .run(4242, uncertain(), &policy, &feasibility, &cost_model)
+            .unwrap();
+
+        assert_ne!(schedule.stop_reason, ScheduleStopReason::IterationCap);
+        assert_eq!(schedule.probes_executed(), 0);
+        assert_eq!(schedule.total_cost, 0.0);
+        assert!(!schedule.steps.is_empty());
+        assert!(schedule
+            .steps
+            .iter()
+            .all(|s| matches!(s.outcome, ProbeStepOutcome::Skipped { .. })));
+        assert_eq!(schedule.posterior.posterior, uncertain().posterior);
+    }
+
+    #[test]
+    fn unavailable_probes_are_never_recommended() {
+        let policy = Policy::default();
+        let feasibility = ActionFeasibility::allow_all();
+        let availability = ProbeAvailability {
+            procfs: false,
+            stack_sample: false,
+            strace: false,
+            cgroups: false,
+        };
+
+        let mut scheduler = ProbeType::ALL
+            .iter()
+            .fold(ProbeScheduler::new(), |s, &probe| {
+                s.with_collector(probe, abandoned_stub)
+            })
+            .with_availability(availability);
+        let schedule = scheduler
+            .run(4242, uncertain(), &policy, &feasibility, &cheap_probes())
+            .unwrap();
+
+        assert!(schedule
+            .steps
+            .iter()
+            .all(|step| availability.is_available(step.probe)));
+    }
+
+    #[test]
+    fn caps_stop_an_uninformative_loop() {
+        let policy = Policy::default();
+        let feasibility = ActionFeasibility::allow_all();
+        let cost_model = cheap_probes();
+        let silent = |_pid: u32| -> Result<Vec<EvidenceTerm>, String> { Ok(Vec::new()) };
+        let all_silent = || {
+            ProbeType::ALL
+                .iter()
+                .fold(ProbeScheduler::new(), |s, &probe| {
+                    s.with_collector(probe, silent)
+                })
+        };
+
+        let mut scheduler = all_silent().with_max_iterations(3);
+        let schedule = scheduler
+            .run(4242, uncertain(), &policy, &feasibility, &cost_model)
+            .unwrap();
+        assert_eq!(schedule.stop_reason, ScheduleStopReason::IterationCap);
+        assert_eq!(schedule.probes_executed(), 3);
+
+        let first = compute_voi(
+            &uncertain().posterior,
+            &policy,
+            &feasibility,
+            &cost_model,
+            None,
+        )
+        .unwrap();
+        let first_cost = first
+            .probes
+            .iter()
+            .map(|p| p.cost)
+            .fold(f64::INFINITY, f64::min);
+        let mut scheduler = all_silent().with_budget(first_cost / 2.0);
+        let schedule = scheduler
+            .run(4242, uncertain(), &policy, &feasibility, &cost_model)
+            .unwrap();
+        assert_eq!(schedule.stop_reason, ScheduleStopReason::BudgetExhausted);
+        assert!(schedule.steps.is_empty());
+    }
+}
@@ -0,0 +1,316 @@
+//! Policy protection list: name-based overrides that bypass inference.
+//!
+//! [`ProtectionList`] lets an operator pin processes by name. A
+//! `never_act_on` match forces [`Action::Keep`] no matter what the posterior
+//! says; an `always_flag` match escalates the decision to the cheapest
+//! reversible action so the process is always surfaced for review, but is
+//! never killed on the strength of its name alone. Both are applied in place of the normal
+//! expected-loss decision, and the resulting outcome carries a
+//! [`ProtectionVerdict`] in its rationale so the CVaR and DRO layers leave it
+//! alone.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::expected_loss::{
+    decide_action, Action, ActionFeasibility, DecisionError, DecisionOutcome, DecisionRationale,
+};
+use crate::collect::protected::{ProtectedFilter, ProtectedFilterError};
+use crate::config::policy::{Policy, ProtectionList};
+use crate::inference::ClassScores;
+
+/// Why a process bypassed normal decisioning.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ProtectionVerdict {
+    /// Matched `never_act_on`; the decision is forced to keep.
+    Protected { pattern: String },
+    /// Matched `always_flag`; the decision is escalated.
+    Flagged { pattern: String },
+}
+
+impl ProtectionVerdict {
+    /// The glob that produced this verdict.
+    pub fn pattern(&self) -> &str {
+        match self {
+            ProtectionVerdict::Protected { pattern } | ProtectionVerdict::Flagged { pattern } => {
+                pattern
+            }
+        }
+    }
+
+    pub fn is_protected(&self) -> bool {
+        matches!(self, ProtectionVerdict::Protected { .. })
+    }
+
+    /// Short human-readable explanation for plan output.
+    pub fn describe(&self) -> String {
+        match self {
+            ProtectionVerdict::Protected { pattern } => {
+                format!("Protected by policy protection_list (never_act_on '{pattern}')")
+            }
+            ProtectionVerdict::Flagged { pattern } => {
+                format!("Flagged by policy protection_list (always_flag '{pattern}')")
+            }
+        }
+    }
+}
+
+/// Compiled form of a policy [`ProtectionList`].
+///
+/// Each list is compiled with the same [`ProtectedFilter`] machinery used for
+/// `guardrails.protected_patterns`.
+#[derive(Debug, Clone, Default)]
+pub struct ProtectionRules {
+    never_act_on: ProtectedFilter,
+    always_flag: ProtectedFilter,
+}
+
+impl ProtectionRules {
+    /// Compile the globs in `list`.
+    pub fn new(list: &ProtectionList) -> Result<Self, ProtectedFilterError> {
+        Ok(Self {
+            never_act_on: glob_filter(&list.never_act_on, "protection_list.never_act_on")?,
+            always_flag: glob_filter(&list.always_flag, "protection_list.always_flag")?,
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.never_act_on.pattern_count() == 0 && self.always_flag.pattern_count() == 0
+    }
+
+    /// Check a process by name (`comm`).
+    ///
+    /// Only the name is matched: command lines carry arbitrary arguments
+    /// (file paths, URLs) that would let unrelated processes hit a list.
+    /// `never_act_on` is consulted first, so a process on both lists is
+    /// protected.
+    pub fn check(&self, comm: &str) -> Option<ProtectionVerdict> {
+        if let Some(pattern) = self.never_act_on.matches_any_pattern(comm) {
+            return Some(ProtectionVerdict::Protected {
+                pattern: pattern.to_string(),
+            });
+        }
+        self.always_flag
+            .matches_any_pattern(comm)
+            .map(|pattern| ProtectionVerdict::Flagged {
+                pattern: pattern.to_string(),
+            })
+    }
+}
+
+fn glob_filter(globs: &[String], path: &str) -> Result<ProtectedFilter, ProtectedFilterError> {
+    let patterns: Vec<(String, String, bool, Option<String>)> = globs
+        .iter()
+        .map(|glob| (glob.clone(), "glob".to_string(), false, None))
+        .collect();
+    ProtectedFilter::new(&patterns, &[], &[], &[]).map_err(|err| match err {
+        ProtectedFilterError::InvalidPattern { path: at, message } => {
+            ProtectedFilterError::InvalidPattern {
+                path: at.replacen("protected_patterns", path, 1),
+                message,
+            }
+        }
+    })
+}
+
+/// Decide for a process, honouring its protection verdict.
+///
+/// Without a verdict this is [`decide_action`]. A protected process gets a
+/// keep outcome without consulting the loss matrix at all. A flagged process
+/// is escalated to the cheapest feasible reversible action so it is surfaced
+/// for review; a flag alone never selects kill or restart.
+pub fn decide_with_protection(
+    verdict: Option<&ProtectionVerdict>,
+    posterior: &ClassScores,
+    policy: &Policy,
+    feasibility: &ActionFeasibility,
+) -> Result<DecisionOutcome, DecisionError> {
+    let Some(verdict) = verdict else {
+        return decide_action(posterior, policy, feasibility);
+    };
+
+    if verdict.is_protected() {
+        return Ok(protected_outcome(verdict.clone(), posterior));
+    }
+
+    let mut outcome = decide_action(posterior, policy, feasibility)?;
+    let escalated = if outcome.optimal_action.is_reversible() {
+        None
+    } else {
+        outcome
+            .expected_loss
+            .iter()
+            .filter(|entry| entry.action.is_reversible())
+            .min_by(|a, b| a.loss.total_cmp(&b.loss))
+            .map(|entry| entry.action)
+    };
+    if let Some(action) = escalated {
+        outcome.optimal_action = action;
+        outcome.rationale.chosen_action = action;
+        outcome.rationale.tie_break = false;
+    }
+    outcome.rationale.protection = Some(verdict.clone());
+    Ok(outcome)
+}
+
+fn protected_outcome(verdict: ProtectionVerdict, posterior: &ClassScores) -> DecisionOutcome {
+    DecisionOutcome {
+        expected_loss: Vec::new(),
+        optimal_action: Action::Keep,
+        sprt_boundary: None,
+        posterior_odds_abandoned_vs_useful: None,
+        recovery_expectations: None,
+        rationale: DecisionRationale {
+            chosen_action: Action::Keep,
+            tie_break: false,
+            disabled_actions: Vec::new(),
+            used_recovery_preference: false,
+            posterior: Some(*posterior),
+            memory_mb: None,
+            has_known_signature: None,
+            category: None,
+            protection: Some(verdict),
+        },
+        risk_sensitive: None,
+        dro: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decision::cvar::CvarTrigger;
+    use crate::decision::dro::DroTrigger;
+    use crate::decision::expected_loss::{apply_dro_control, apply_risk_sensitive_control};
+
+    fn rules(never_act_on: &[&str], always_flag: &[&str]) -> ProtectionRules {
+        ProtectionRules::new(&ProtectionList {
+            never_act_on: never_act_on.iter().map(|s| s.to_string()).collect(),
+            always_flag: always_flag.iter().map(|s| s.to_string()).collect(),
+        })
+        .unwrap()
+    }
+
+    fn zombie_certain() -> ClassScores {
+        ClassScores {
+            useful: 0.0,
+            useful_bad: 0.0,
+            abandoned: 0.0,
+            zombie: 1.0,
+        }
+    }
+
+    #[test]
+    fn matches_process_name_only() {
+        let rules = rules(&["postgres*"], &["leaky-worker*"]);
+        assert_eq!(
+            rules.check("postgres"),
+            Some(ProtectionVerdict::Protected {
+                pattern: "postgres*".to_string()
+            })
+        );
+        assert_eq!(
+            rules.check("leaky-worker"),
+            Some(ProtectionVerdict::Flagged {
+                pattern: "leaky-worker*".to_string()
+            })
+        );
+        // A name appearing in arguments (e.g. `vim leaky-worker.log`) is not a match.
+        assert_eq!(rules.check("vim"), None);
+        assert!(ProtectionRules::default().is_empty());
+    }
+
+    #[test]
+    fn never_act_on_wins_over_always_flag() {
+        let rules = rules(&["sshd"], &["ssh*"]);
+        assert!(rules.check("sshd").unwrap().is_protected());
+        assert!(!rules.check("ssh").unwrap().is_protected());
+    }
+
+    #[test]
+    fn protected_name_is_never_killed_even_when_certainly_zombie() {
+        let rules = rules(&["sshd"], &[]);
+        let policy = Policy::default();
+        let posterior = zombie_certain();
+        let feasibility = ActionFeasibility::allow_all();
+
+        // Sanity check: without protection this posterior is acted on.
+        let unprotected = decide_with_protection(None, &posterior, &policy, &feasibility).unwrap();
+        assert_ne!(unprotected.optimal_action, Action::Keep);
+
+        let verdict = rules.check("sshd");
+        let outcome =
+            decide_with_protection(verdict.as_ref(), &posterior, &policy, &feasibility).unwrap();
+        assert_eq!(outcome.optimal_action, Action::Keep);
+        assert!(outcome
+            .rationale
+            .protection
+            .as_ref()
+            .unwrap()
+            .is_protected());
+
+        // Downstream robustness layers are short-circuited.
+        let cvar_trigger = CvarTrigger {
+            robot_mode: true,
+            low_confidence: true,
+            high_blast_radius: true,
+            explicit_conservative: true,
+            blast_radius_mb: Some(1_000_000.0),
+        };
+        let outcome =
+            apply_risk_sensitive_control(outcome, &posterior, &policy, &cvar_trigger, 0.95);
+        let dro_trigger = DroTrigger {
+            ppc_failure: true,
+            drift_detected: true,
+            wasserstein_divergence: Some(1.0),
+            eta_tempering_reduced: true,
+            explicit_conservative: true,
+            low_model_confidence: true,
+        };
+        let outcome = apply_dro_control(outcome, &posterior, &policy, &dro_trigger, 0.5);
+        assert_eq!(outcome.optimal_action, Action::Keep);
+        assert_eq!(outcome.rationale.chosen_action, Action::Keep);
+        assert!(outcome.risk_sensitive.is_none());
+        assert!(outcome.dro.is_none());
+    }
+
+    #[test]
+    fn flagged_name_is_escalated_but_never_killed() {
+        let rules = rules(&[], &["leaky-*"]);
+        let policy = Policy::default();
+        let useful = ClassScores {
+            useful: 0.97,
+            useful_bad: 0.01,
+            abandoned: 0.01,
+            zombie: 0.01,
+        };
+        let verdict = rules.check("leaky-worker");
+
+        let outcome = decide_with_protection(
+            verdict.as_ref(),
+            &useful,
+            &policy,
+            &ActionFeasibility::allow_all(),
+        )
+        .unwrap();
+        assert_ne!(outcome.optimal_action, Action::Keep);
+        assert!(outcome.optimal_action.is_reversible());
+
+        // Even a certain zombie is not killed on the strength of a flag.
+        let outcome = decide_with_protection(
+            verdict.as_ref(),
+            &zombie_certain(),
+            &policy,
+            &ActionFeasibility::allow_all(),
+        )
+        .unwrap();
+        assert!(outcome.optimal_action.is_reversible());
+        assert_eq!(
+            outcome.rationale.protection,
+            Some(ProtectionVerdict::Flagged {
+                pattern: "leaky-*".to_string()
+            })
+        );
+    }
+}
@@ -5,8 +5,13 @@
 //! multiple candidates under a fixed budget.
 
 use crate::config::Policy;
-use crate::decision::expected_loss::{decide_action, Action, ActionFeasibility, DecisionError};
-use crate::decision::voi::{compute_voi, ProbeCostModel, ProbeType, VoiAnalysis, VoiError};
+use crate::decision::expected_loss::{
+    decide_action, rank_actions, Action, ActionFeasibility, DecisionError,
+};
+use crate::decision::voi::{
+    compute_voi_with_availability, ProbeAvailability, ProbeCostModel, ProbeType, VoiAnalysis,
+    VoiError,
+};
 use crate::inference::ClassScores;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
@@ -72,15 +77,45 @@ pub enum SequentialError {
 }
 
 /// Decide whether to act now or acquire another probe.
+///
+/// Every probe is treated as runnable; use
+/// [`decide_sequential_with_availability`] to exclude probes the host cannot
+/// execute.
 pub fn decide_sequential(
     posterior: &ClassScores,
     policy: &Policy,
     feasibility: &ActionFeasibility,
     cost_model: &ProbeCostModel,
     available_probes: Option<&[ProbeType]>,
+) -> Result<(SequentialDecision, Vec<SequentialLedgerEntry>), SequentialError> {
+    decide_sequential_with_availability(
+        posterior,
+        policy,
+        feasibility,
+        cost_model,
+        available_probes,
+        &ProbeAvailability::all(),
+    )
+}
+
+/// [`decide_sequential`] restricted to probes `availability` says can run.
+pub fn decide_sequential_with_availability(
+    posterior: &ClassScores,
+    policy: &Policy,
+    feasibility: &ActionFeasibility,
+    cost_model: &ProbeCostModel,
+    available_probes: Option<&[ProbeType]>,
+    availability: &ProbeAvailability,
 ) -> Result<(SequentialDecision, Vec<SequentialLedgerEntry>), SequentialError> {
     let decision = decide_action(posterior, policy, feasibility)?;
-    let voi = compute_voi(posterior, policy, feasibility, cost_model, available_probes)?;
+    let voi = compute_voi_with_availability(
+        posterior,
+        policy,
+        feasibility,
+        cost_model,
+        available_probes,
+        availability,
+    )?;
 
     let esn_estimate = estimate_esn(&voi);
     let should_probe = !voi.act_now && voi.best_probe.is_some();
@@ -173,14 +208,9 @@ fn estimate_esn(voi: &VoiAnalysis) -> Option<f64> {
         return None;
     }
 
-    let mut losses = voi.current_expected_loss.clone();
-    losses.sort_by(|a, b| {
-        a.loss
-            .partial_cmp(&b.loss)
-            .unwrap_or(std::cmp::Ordering::Equal)
-    });
+    let ranked = rank_actions(&voi.current_expected_loss, &ActionFeasibility::allow_all());
 
-    let gap = (losses[1].loss - losses[0].loss).max(0.0);
+    let gap = ranked[1].delta_from_optimal.max(0.0);
     if gap <= 0.0 {
         return None;
     }
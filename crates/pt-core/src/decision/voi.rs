@@ -14,19 +14,24 @@
 //! ```
 //!
 //! Note: Negative VOI means the probe is worthwhile (reduces expected loss).
+//!
+//! [`compute_voi_sequential`] extends the one-step analysis with a greedy
+//! lookahead over short probe sequences.
 
-use crate::config::policy::{LossMatrix, Policy};
+use crate::capabilities::Capabilities;
+use crate::config::policy::{LossMatrix, Policy, ProbeCosts};
 use crate::decision::expected_loss::{
     expected_loss_for_action, select_optimal_action, Action, ActionFeasibility, DecisionError,
     ExpectedLoss,
 };
 use crate::inference::ClassScores;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use thiserror::Error;
 
 /// Available probe types for gathering additional evidence.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ProbeType {
     /// Wait for a period (free but slow) - allows process state to evolve.
@@ -256,6 +261,100 @@ impl ProbeCostModel {
     pub fn cost_details(&self, probe: ProbeType) -> ProbeCost {
         self.costs.get(&probe).cloned().unwrap_or_default()
     }
+
+    /// Apply a policy's `probe_costs` overrides on top of this model.
+    ///
+    /// Listed probes replace their cost entry and `base_multiplier`, when set,
+    /// replaces the model's. Probe names use the serialized [`ProbeType`] form.
+    pub fn with_overrides(&self, overrides: &ProbeCosts) -> Result<Self, VoiError> {
+        let mut model = self.clone();
+        for (name, cost) in &overrides.costs {
+            let probe: ProbeType = serde_json::from_value(serde_json::Value::String(name.clone()))
+                .map_err(|_| VoiError::UnknownProbe { name: name.clone() })?;
+            model.costs.insert(
+                probe,
+                ProbeCost {
+                    time_seconds: cost.time_seconds,
+                    overhead: cost.overhead,
+                    intrusiveness: cost.intrusiveness,
+                    risk: cost.risk,
+                },
+            );
+        }
+        if let Some(multiplier) = overrides.base_multiplier {
+            model.base_multiplier = multiplier;
+        }
+        Ok(model)
+    }
+}
+
+/// Which probes the executor can actually run on this host.
+///
+/// Waits, quick scans and network snapshots only need `ps`-level access and
+/// are always available; the rest depend on platform data sources, tools or
+/// privileges.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ProbeAvailability {
+    /// `/proc` is readable (deep scan, I/O snapshot).
+    pub procfs: bool,
+    /// Kernel stacks can be sampled (`/proc/<pid>/stack` needs root).
+    pub stack_sample: bool,
+    /// A working `strace` binary is installed.
+    pub strace: bool,
+    /// A cgroup hierarchy is mounted.
+    pub cgroups: bool,
+}
+
+impl ProbeAvailability {
+    /// Every probe is available.
+    pub fn all() -> Self {
+        Self {
+            procfs: true,
+            stack_sample: true,
+            strace: true,
+            cgroups: true,
+        }
+    }
+
+    /// Derive availability from detected system capabilities.
+    pub fn from_capabilities(caps: &Capabilities) -> Self {
+        let procfs = caps.data_sources.procfs;
+        Self {
+            procfs,
+            stack_sample: procfs && caps.permissions.is_root,
+            strace: caps.tools.strace.available && caps.tools.strace.works,
+            cgroups: caps.data_sources.cgroup_v1 || caps.data_sources.cgroup_v2,
+        }
+    }
+
+    /// Whether the executor can run `probe`.
+    pub fn is_available(&self, probe: ProbeType) -> bool {
+        match probe {
+            ProbeType::Wait15Min
+            | ProbeType::Wait5Min
+            | ProbeType::QuickScan
+            | ProbeType::NetSnapshot => true,
+            ProbeType::DeepScan | ProbeType::IoSnapshot => self.procfs,
+            ProbeType::StackSample => self.stack_sample,
+            ProbeType::Strace => self.strace,
+            ProbeType::CgroupInspect => self.cgroups,
+        }
+    }
+
+    /// The subset of `probes` that can run, in the original order.
+    pub fn filter_available(&self, probes: &[ProbeType]) -> Vec<ProbeType> {
+        probes
+            .iter()
+            .copied()
+            .filter(|&probe| self.is_available(probe))
+            .collect()
+    }
+}
+
+impl Default for ProbeAvailability {
+    fn default() -> Self {
+        Self::all()
+    }
 }
 
 /// Expected information gain from a probe (how much it changes posteriors).
@@ -272,7 +371,7 @@ pub struct ProbeInformationGain {
 }
 
 /// Result of VOI analysis for a single probe.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ProbeVoi {
     /// Probe type.
     pub probe: ProbeType,
@@ -287,7 +386,7 @@ pub struct ProbeVoi {
 }
 
 /// Complete VOI analysis result.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct VoiAnalysis {
     /// Current expected losses for each action.
     pub current_expected_loss: Vec<ExpectedLoss>,
@@ -303,8 +402,27 @@ pub struct VoiAnalysis {
     pub act_now: bool,
     /// Explanation of the decision.
     pub rationale: String,
+    /// Probe sequence planned by [`compute_voi_sequential`], when probing is worthwhile.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sequence: Option<ProbeSequence>,
 }
 
+/// Probe sequence chosen by greedy lookahead.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProbeSequence {
+    /// Probes in the order they should be acquired.
+    pub probes: Vec<ProbeType>,
+    /// Sum of the probes' costs.
+    pub total_cost: f64,
+    /// Expected loss after acquiring every probe in the sequence.
+    pub expected_loss_after: f64,
+    /// Cumulative VOI of the sequence (negative = worthwhile).
+    pub voi: f64,
+}
+
+/// Maximum lookahead depth accepted by [`compute_voi_sequential`].
+pub const MAX_VOI_LOOKAHEAD_DEPTH: usize = 3;
+
 /// Errors from VOI computation.
 #[derive(Debug, Error)]
 pub enum VoiError {
@@ -314,6 +432,24 @@ pub enum VoiError {
     InvalidPosterior { message: String },
     #[error("no probes available")]
     NoProbesAvailable,
+    #[error("unknown probe in probe_costs: {name}")]
+    UnknownProbe { name: String },
+}
+
+/// Minimum expected loss over feasible actions.
+fn min_loss(losses: &[ExpectedLoss]) -> f64 {
+    losses.iter().map(|e| e.loss).fold(f64::INFINITY, f64::min)
+}
+
+/// Apply the policy's `probe_costs` overrides, if any, to `cost_model`.
+fn effective_cost_model<'a>(
+    policy: &Policy,
+    cost_model: &'a ProbeCostModel,
+) -> Result<std::borrow::Cow<'a, ProbeCostModel>, VoiError> {
+    Ok(match &policy.probe_costs {
+        Some(overrides) => std::borrow::Cow::Owned(cost_model.with_overrides(overrides)?),
+        None => std::borrow::Cow::Borrowed(cost_model),
+    })
 }
 
 /// Compute expected loss given posterior and loss matrix (internal helper).
@@ -434,10 +570,7 @@ fn compute_probe_voi(
     // Compute expected loss after probe
     let losses_after = compute_expected_losses(&posterior_after, loss_matrix, feasibility)?;
     let (_, _) = select_optimal_action(&losses_after);
-    let min_loss_after = losses_after
-        .iter()
-        .map(|e| e.loss)
-        .fold(f64::INFINITY, f64::min);
+    let min_loss_after = min_loss(&losses_after);
 
     // VOI = E[loss_after] - E[loss_now] - cost
     // Negative VOI means probe is worthwhile
@@ -463,12 +596,39 @@ fn compute_probe_voi(
 /// Compute VOI analysis for all available probes.
 ///
 /// Returns analysis indicating whether to act now or which probe to acquire.
+/// When the policy carries `probe_costs`, they are applied on top of
+/// `cost_model` (see [`ProbeCostModel::with_overrides`]). Every probe is
+/// treated as runnable; use [`compute_voi_with_availability`] to exclude
+/// probes the host cannot execute.
 pub fn compute_voi(
     posterior: &ClassScores,
     policy: &Policy,
     feasibility: &ActionFeasibility,
     cost_model: &ProbeCostModel,
     available_probes: Option<&[ProbeType]>,
+) -> Result<VoiAnalysis, VoiError> {
+    compute_voi_with_availability(
+        posterior,
+        policy,
+        feasibility,
+        cost_model,
+        available_probes,
+        &ProbeAvailability::all(),
+    )
+}
+
+/// [`compute_voi`] restricted to probes `availability` says can run.
+///
+/// Unavailable probes are dropped before ranking and do not appear in
+/// [`VoiAnalysis::probes`]. If the best probe overall was one of them, the
+/// rationale says so.
+pub fn compute_voi_with_availability(
+    posterior: &ClassScores,
+    policy: &Policy,
+    feasibility: &ActionFeasibility,
+    cost_model: &ProbeCostModel,
+    available_probes: Option<&[ProbeType]>,
+    availability: &ProbeAvailability,
 ) -> Result<VoiAnalysis, VoiError> {
     // Validate posterior
     let values = [
@@ -489,10 +649,10 @@ pub fn compute_voi(
     // Compute current expected losses
     let current_losses = compute_expected_losses(posterior, &policy.loss_matrix, feasibility)?;
     let (current_optimal, _) = select_optimal_action(&current_losses);
-    let current_min_loss = current_losses
-        .iter()
-        .map(|e| e.loss)
-        .fold(f64::INFINITY, f64::min);
+    let current_min_loss = min_loss(&current_losses);
+
+    let cost_model = effective_cost_model(policy, cost_model)?;
+    let cost_model = cost_model.as_ref();
 
     // Determine which probes to consider
     let probes_to_check = available_probes.unwrap_or(ProbeType::ALL);
@@ -521,6 +681,18 @@ pub fn compute_voi(
         return Err(VoiError::NoProbesAvailable);
     }
 
+    let most_negative = |vois: &[ProbeVoi]| {
+        vois.iter()
+            .min_by(|a, b| {
+                a.voi
+                    .partial_cmp(&b.voi)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .map(|p| p.probe)
+    };
+    let skipped = most_negative(&probe_vois).filter(|&probe| !availability.is_available(probe));
+    probe_vois.retain(|p| availability.is_available(p.probe));
+
     // Find best probe (most negative VOI = most worthwhile)
     let best = probe_vois.iter().min_by(|a, b| {
         a.voi
@@ -528,7 +700,7 @@ pub fn compute_voi(
             .unwrap_or(std::cmp::Ordering::Equal)
     });
 
-    let (best_probe, act_now, rationale) = match best {
+    let (best_probe, act_now, mut rationale) = match best {
         Some(p) if p.voi < 0.0 => {
             // Probe is worthwhile
             (
@@ -557,6 +729,12 @@ pub fn compute_voi(
         }
         None => (None, true, "Act now: no probes available".to_string()),
     };
+    if let Some(probe) = skipped {
+        rationale.push_str(&format!(
+            " (best theoretical probe '{}' skipped: unavailable on this host)",
+            probe.name()
+        ));
+    }
 
     Ok(VoiAnalysis {
         current_expected_loss: current_losses,
@@ -566,9 +744,91 @@ pub fn compute_voi(
         best_probe,
         act_now,
         rationale,
+        sequence: None,
     })
 }
 
+/// Plan a sequence of up to `depth` probes by greedy lookahead.
+///
+/// Starts from [`compute_voi`], then repeatedly picks the unused probe that
+/// minimizes expected loss plus cost from the current estimated posterior,
+/// updates the posterior as if that probe had been acquired, and
+/// accumulates its cost. The prefix with the most negative cumulative VOI
+/// becomes [`VoiAnalysis::sequence`], and its first probe the `best_probe`.
+///
+/// `depth` is clamped to `1..=`[`MAX_VOI_LOOKAHEAD_DEPTH`]; depth 1 yields
+/// the same recommendation as [`compute_voi`]. This is a greedy
+/// approximation to the full optimal stopping problem: it never revisits an
+/// earlier choice and uses the same point estimate of the posterior shift
+/// as the one-step analysis instead of branching over probe outcomes.
+pub fn compute_voi_sequential(
+    posterior: &ClassScores,
+    policy: &Policy,
+    feasibility: &ActionFeasibility,
+    cost_model: &ProbeCostModel,
+    available_probes: Option<&[ProbeType]>,
+    depth: usize,
+) -> Result<VoiAnalysis, VoiError> {
+    let mut analysis = compute_voi(posterior, policy, feasibility, cost_model, available_probes)?;
+    let cost_model = effective_cost_model(policy, cost_model)?;
+    let depth = depth.clamp(1, MAX_VOI_LOOKAHEAD_DEPTH);
+
+    let mut remaining: Vec<ProbeType> = available_probes.unwrap_or(ProbeType::ALL).to_vec();
+    let mut current = *posterior;
+    let mut steps = Vec::new();
+    let mut total_cost = 0.0;
+    let mut best: Option<ProbeSequence> = None;
+
+    for _ in 0..depth {
+        let mut next: Option<(usize, ClassScores, f64, f64)> = None;
+        for (idx, &probe) in remaining.iter().enumerate() {
+            let after = estimate_posterior_after_probe(&current, probe);
+            let Ok(losses) = compute_expected_losses(&after, &policy.loss_matrix, feasibility)
+            else {
+                continue;
+            };
+            let loss = min_loss(&losses);
+            let cost = cost_model.cost(probe);
+            if next.as_ref().is_none_or(|(_, _, l, c)| loss + cost < l + c) {
+                next = Some((idx, after, loss, cost));
+            }
+        }
+        let Some((idx, after, loss, cost)) = next else {
+            break;
+        };
+
+        steps.push(remaining.remove(idx));
+        total_cost += cost;
+        current = after;
+
+        let voi = loss - analysis.current_min_loss + total_cost;
+        if best.as_ref().is_none_or(|b| voi < b.voi) {
+            best = Some(ProbeSequence {
+                probes: steps.clone(),
+                total_cost,
+                expected_loss_after: loss,
+                voi,
+            });
+        }
+    }
+
+    if let Some(sequence) = best.filter(|s| s.voi < 0.0) {
+        let names: Vec<&str> = sequence.probes.iter().map(|p| p.name()).collect();
+        analysis.best_probe = sequence.probes.first().copied();
+        analysis.act_now = false;
+        analysis.rationale = format!(
+            "Probe sequence [{}] reduces expected loss by {:.2} at total cost {:.2} (net gain: {:.2})",
+            names.join(", "),
+            analysis.current_min_loss - sequence.expected_loss_after,
+            sequence.total_cost,
+            -sequence.voi
+        );
+        analysis.sequence = Some(sequence);
+    }
+
+    Ok(analysis)
+}
+
 /// Select the best probe using active sensing (entropy reduction / cost ratio).
 ///
 /// This is an alternative to pure VOI that maximizes information gain per unit cost,
@@ -581,7 +841,7 @@ pub fn select_probe_by_information_gain(
     let probes = available_probes.unwrap_or(ProbeType::ALL);
 
     // Current entropy (Shannon)
-    let current_entropy = shannon_entropy(posterior);
+    let current_entropy = posterior.entropy();
 
     let mut best_probe = None;
     let mut best_ratio = f64::NEG_INFINITY;
@@ -593,7 +853,7 @@ pub fn select_probe_by_information_gain(
         }
 
         let posterior_after = estimate_posterior_after_probe(posterior, probe);
-        let entropy_after = shannon_entropy(&posterior_after);
+        let entropy_after = posterior_after.entropy();
         let entropy_reduction = current_entropy - entropy_after;
 
         let ratio = entropy_reduction / cost;
@@ -606,24 +866,6 @@ pub fn select_probe_by_information_gain(
     best_probe
 }
 
-/// Compute Shannon entropy of posterior (in bits).
-fn shannon_entropy(posterior: &ClassScores) -> f64 {
-    let probs = [
-        posterior.useful,
-        posterior.useful_bad,
-        posterior.abandoned,
-        posterior.zombie,
-    ];
-
-    let mut entropy = 0.0;
-    for &p in &probs {
-        if p > 1e-10 {
-            entropy -= p * p.log2();
-        }
-    }
-    entropy
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -655,6 +897,91 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_policy_probe_costs_make_deep_scan_preferred() {
+        let mut value = serde_json::to_value(Policy::default()).unwrap();
+        value["probe_costs"] = serde_json::json!({
+            "costs": {
+                "deep_scan": {
+                    "time_seconds": 0.0,
+                    "overhead": 0.0,
+                    "intrusiveness": 0.0,
+                    "risk": 0.0
+                }
+            }
+        });
+        let policy = Policy::parse_json(&value.to_string()).expect("policy loads");
+        pt_config::validate::validate_policy(&policy).expect("policy validates");
+        let feasibility = ActionFeasibility::allow_all();
+        let cost_model = ProbeCostModel::default();
+
+        let baseline = compute_voi(
+            &test_posterior(),
+            &Policy::default(),
+            &feasibility,
+            &cost_model,
+            None,
+        )
+        .unwrap();
+        assert_ne!(baseline.best_probe, Some(ProbeType::DeepScan));
+
+        let analysis =
+            compute_voi(&test_posterior(), &policy, &feasibility, &cost_model, None).unwrap();
+        let deep = analysis
+            .probes
+            .iter()
+            .find(|p| p.probe == ProbeType::DeepScan)
+            .unwrap();
+        assert_eq!(deep.cost, 0.0);
+        assert_eq!(analysis.best_probe, Some(ProbeType::DeepScan));
+        assert!(!analysis.act_now);
+    }
+
+    #[test]
+    fn test_probe_cost_names_match_config_validation() {
+        let names: Vec<String> = ProbeType::ALL
+            .iter()
+            .map(|p| {
+                serde_json::to_value(p)
+                    .unwrap()
+                    .as_str()
+                    .unwrap()
+                    .to_string()
+            })
+            .collect();
+        assert_eq!(names, ProbeCosts::PROBE_NAMES);
+    }
+
+    #[test]
+    fn test_probe_cost_overrides_reject_unknown_probe() {
+        let overrides = ProbeCosts {
+            costs: [(
+                "teleport".to_string(),
+                crate::config::policy::ProbeCostOverride {
+                    time_seconds: 1.0,
+                    overhead: 0.0,
+                    intrusiveness: 0.0,
+                    risk: 0.0,
+                },
+            )]
+            .into(),
+            base_multiplier: Some(3.0),
+        };
+        let err = ProbeCostModel::default()
+            .with_overrides(&overrides)
+            .unwrap_err();
+        assert!(matches!(err, VoiError::UnknownProbe { ref name } if name == "teleport"));
+
+        let scaled = ProbeCostModel::default()
+            .with_overrides(&ProbeCosts {
+                costs: Default::default(),
+                base_multiplier: Some(3.0),
+            })
+            .unwrap();
+        let default_cost = ProbeCostModel::default().cost(ProbeType::QuickScan);
+        assert!((scaled.cost(ProbeType::QuickScan) - 3.0 * default_cost).abs() < 1e-12);
+    }
+
     #[test]
     fn test_probe_cost_model_defaults() {
         let model = ProbeCostModel::default();
@@ -788,7 +1115,7 @@ mod tests {
             abandoned: 0.25,
             zombie: 0.25,
         };
-        let entropy_uniform = shannon_entropy(&uniform);
+        let entropy_uniform = uniform.entropy();
         assert!(
             (entropy_uniform - 2.0).abs() < 0.01,
             "uniform should have ~2 bits entropy"
@@ -796,7 +1123,7 @@ mod tests {
 
         // Confident distribution has low entropy
         let confident = confident_abandoned_posterior();
-        let entropy_confident = shannon_entropy(&confident);
+        let entropy_confident = confident.entropy();
         assert!(
             entropy_confident < entropy_uniform,
             "confident should have lower entropy"
@@ -895,8 +1222,8 @@ mod tests {
 
         // Entropy should be lower for confident useful posterior vs uncertain
         let uncertain = test_posterior();
-        let entropy_confident = shannon_entropy(&posterior);
-        let entropy_uncertain = shannon_entropy(&uncertain);
+        let entropy_confident = posterior.entropy();
+        let entropy_uncertain = uncertain.entropy();
         assert!(
             entropy_confident < entropy_uncertain,
             "confident posterior should have lower entropy"
@@ -1090,6 +1417,157 @@ mod tests {
 
     // ── VoiAnalysis serde ───────────────────────────────────────────
 
+    #[test]
+    fn sequential_depth_one_matches_single_step() {
+        let posterior = test_posterior();
+        let policy = Policy::default();
+        let cost_model = ProbeCostModel::default();
+        let feasibility = ActionFeasibility::allow_all();
+
+        let single = compute_voi(&posterior, &policy, &feasibility, &cost_model, None).unwrap();
+        let seq = compute_voi_sequential(&posterior, &policy, &feasibility, &cost_model, None, 1)
+            .unwrap();
+        assert_eq!(seq.best_probe, single.best_probe);
+        assert_eq!(seq.act_now, single.act_now);
+        if let Some(sequence) = &seq.sequence {
+            assert_eq!(sequence.probes.len(), 1);
+            let best = single
+                .probes
+                .iter()
+                .map(|p| p.voi)
+                .fold(f64::INFINITY, f64::min);
+            assert!((sequence.voi - best).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn sequential_lookahead_accumulates_costs_and_caps_depth() {
+        let posterior = test_posterior();
+        let policy = Policy::default();
+        // Cheap probes so that probing pays for itself.
+        let cost_model = ProbeCostModel {
+            base_multiplier: 0.01,
+            ..Default::default()
+        };
+        let feasibility = ActionFeasibility::allow_all();
+
+        let single = compute_voi(&posterior, &policy, &feasibility, &cost_model, None).unwrap();
+        let best_single = single
+            .probes
+            .iter()
+            .map(|p| p.voi)
+            .fold(f64::INFINITY, f64::min);
+
+        let seq = compute_voi_sequential(&posterior, &policy, &feasibility, &cost_model, None, 50)
+            .unwrap();
+        let sequence = seq
+            .sequence
+            .expect("uncertain posterior should plan probes");
+        assert!(!seq.act_now);
+        assert!(
+            sequence.probes.len() >= 2,
+            "lookahead extends past one probe"
+        );
+        assert!(sequence.probes.len() <= MAX_VOI_LOOKAHEAD_DEPTH);
+        assert_eq!(seq.best_probe, sequence.probes.first().copied());
+        assert!(sequence.voi <= best_single + 1e-12);
+
+        let total: f64 = sequence.probes.iter().map(|&p| cost_model.cost(p)).sum();
+        assert!((sequence.total_cost - total).abs() < 1e-12);
+        assert!(
+            (sequence.voi - (sequence.expected_loss_after - seq.current_min_loss + total)).abs()
+                < 1e-12
+        );
+        let mut unique = sequence.probes.clone();
+        unique.dedup();
+        assert_eq!(
+            unique.len(),
+            sequence.probes.len(),
+            "probes are not repeated"
+        );
+    }
+
+    #[test]
+    fn sequential_confident_posterior_acts_now() {
+        let posterior = confident_useful_posterior();
+        let policy = Policy::default();
+        let cost_model = ProbeCostModel::default();
+        let feasibility = ActionFeasibility::allow_all();
+
+        let single = compute_voi(&posterior, &policy, &feasibility, &cost_model, None).unwrap();
+        let seq = compute_voi_sequential(&posterior, &policy, &feasibility, &cost_model, None, 2)
+            .unwrap();
+        assert!(single.act_now);
+        assert!(seq.act_now, "no sequence pays for itself");
+        assert!(seq.sequence.is_none());
+        assert_eq!(seq.rationale, single.rationale);
+    }
+
+    #[test]
+    fn probe_availability_filters_platform_probes() {
+        let all = ProbeAvailability::default();
+        assert_eq!(
+            all.filter_available(ProbeType::ALL),
+            ProbeType::ALL.to_vec()
+        );
+
+        // No /proc, no root, no strace, no cgroups (e.g. macOS).
+        let minimal = ProbeAvailability {
+            procfs: false,
+            stack_sample: false,
+            strace: false,
+            cgroups: false,
+        };
+        assert_eq!(
+            minimal.filter_available(ProbeType::ALL),
+            vec![
+                ProbeType::Wait15Min,
+                ProbeType::Wait5Min,
+                ProbeType::QuickScan,
+                ProbeType::NetSnapshot,
+            ]
+        );
+    }
+
+    #[test]
+    fn unavailable_best_probe_is_skipped_and_noted() {
+        let posterior = test_posterior();
+        let policy = Policy::default();
+        let feasibility = ActionFeasibility::allow_all();
+        let cost_model = ProbeCostModel {
+            base_multiplier: 0.0,
+            ..Default::default()
+        };
+
+        let full = compute_voi(&posterior, &policy, &feasibility, &cost_model, None).unwrap();
+        assert_eq!(full.best_probe, Some(ProbeType::Strace));
+        assert!(!full.rationale.contains("unavailable"));
+
+        let availability = ProbeAvailability {
+            strace: false,
+            ..ProbeAvailability::all()
+        };
+        let gated = compute_voi_with_availability(
+            &posterior,
+            &policy,
+            &feasibility,
+            &cost_model,
+            None,
+            &availability,
+        )
+        .unwrap();
+        assert!(gated.probes.iter().all(|p| p.probe != ProbeType::Strace));
+        assert_eq!(gated.probes.len(), ProbeType::ALL.len() - 1);
+        assert_ne!(gated.best_probe, Some(ProbeType::Strace));
+        assert!(
+            gated
+                .rationale
+                .contains("best theoretical probe 'strace' skipped: unavailable"),
+            "{}",
+            gated.rationale
+        );
+    }
+
     #[test]
     fn voi_analysis_serde_roundtrip() {
         let analysis = VoiAnalysis {
@@ -1103,6 +1581,7 @@ mod tests {
             best_probe: Some(ProbeType::QuickScan),
             act_now: false,
             rationale: "probe recommended".to_string(),
+            sequence: None,
         };
         let json = serde_json::to_string(&analysis).unwrap();
         let back: VoiAnalysis = serde_json::from_str(&json).unwrap();
@@ -1243,7 +1722,7 @@ mod tests {
             abandoned: 0.0,
             zombie: 0.0,
         };
-        let entropy = shannon_entropy(&certain);
+        let entropy = certain.entropy();
         assert!(
             entropy.abs() < 1e-6,
             "certain distribution should have 0 entropy, got {}",
@@ -1259,7 +1738,7 @@ mod tests {
             abandoned: 1.0 - 2e-15,
             zombie: 1e-15,
         };
-        let entropy = shannon_entropy(&near_zero);
+        let entropy = near_zero.entropy();
         assert!(entropy.is_finite());
         assert!(entropy >= 0.0);
     }
@@ -557,20 +557,17 @@ fn loss_for_action_state(
     state_idx: usize,
 ) -> f64 {
     // Pick the row for this state
-    let row = match state_idx {
-        0 => &loss_matrix.useful,
-        1 => &loss_matrix.useful_bad,
-        2 => &loss_matrix.abandoned,
-        3 => &loss_matrix.zombie,
-        _ => return 0.0,
+    let Some(&class) = crate::config::Class::ALL.get(state_idx) else {
+        return 0.0;
     };
+    let row = &loss_matrix[class];
     // Pick the column for this action, defaulting to 0 for missing optional costs
     match action {
         Action::Keep => row.keep,
         Action::Renice => row.renice.unwrap_or(0.0),
         Action::Pause | Action::Resume => row.pause.unwrap_or(0.0),
         Action::Freeze | Action::Unfreeze => row.pause.unwrap_or(0.0),
-        Action::Throttle => row.throttle.unwrap_or(0.0),
+        Action::Throttle | Action::Unthrottle => row.throttle.unwrap_or(0.0),
         Action::Quarantine | Action::Unquarantine => row.throttle.unwrap_or(0.0),
         Action::Restart => row.restart.unwrap_or(0.0),
         Action::Kill => row.kill,
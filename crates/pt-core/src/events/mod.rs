@@ -75,7 +75,11 @@ pub struct ProgressEvent {
     pub progress: Option<Progress>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub elapsed_ms: Option<u64>,
-    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    #[serde(
+        default,
+        skip_serializing_if = "HashMap::is_empty",
+        serialize_with = "pt_common::serialize_sorted"
+    )]
     pub details: HashMap<String, Value>,
 }
 
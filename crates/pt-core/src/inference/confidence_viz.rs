@@ -283,17 +283,17 @@ mod tests {
 
     fn mock_ledger() -> EvidenceLedger {
         EvidenceLedger {
-            posterior: PosteriorResult {
-                posterior: ClassScores {
+            posterior: PosteriorResult::new(
+                ClassScores {
                     useful: 0.05,
                     useful_bad: 0.03,
                     abandoned: 0.87,
                     zombie: 0.05,
                 },
-                log_posterior: ClassScores::default(),
-                log_odds_abandoned_useful: 2.86,
-                evidence_terms: vec![],
-            },
+                ClassScores::default(),
+                2.86,
+                vec![],
+            ),
             classification: Classification::Abandoned,
             confidence: Confidence::High,
             bayes_factors: vec![
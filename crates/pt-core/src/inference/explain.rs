@@ -307,12 +307,7 @@ mod tests {
     use crate::inference::posterior::{ClassScores, PosteriorResult};
 
     fn default_posterior() -> PosteriorResult {
-        PosteriorResult {
-            posterior: ClassScores::default(),
-            log_posterior: ClassScores::default(),
-            log_odds_abandoned_useful: 0.0,
-            evidence_terms: vec![],
-        }
+        PosteriorResult::new(ClassScores::default(), ClassScores::default(), 0.0, vec![])
     }
 
     fn bf(feature: &str, log_bf: f64) -> BayesFactorEntry {
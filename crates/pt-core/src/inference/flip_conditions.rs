@@ -192,17 +192,17 @@ mod tests {
     use std::collections::HashMap;
 
     fn mock_posterior() -> PosteriorResult {
-        PosteriorResult {
-            posterior: ClassScores {
+        PosteriorResult::new(
+            ClassScores {
                 useful: 0.05,
                 useful_bad: 0.03,
                 abandoned: 0.87,
                 zombie: 0.05,
             },
-            log_posterior: ClassScores::default(),
-            log_odds_abandoned_useful: 2.86,
-            evidence_terms: vec![],
-        }
+            ClassScores::default(),
+            2.86,
+            vec![],
+        )
     }
 
     fn bf(feature: &str, log_bf: f64) -> BayesFactorEntry {
@@ -312,17 +312,17 @@ mod tests {
     #[test]
     fn test_useful_classification() {
         let ledger = EvidenceLedger {
-            posterior: PosteriorResult {
-                posterior: ClassScores {
+            posterior: PosteriorResult::new(
+                ClassScores {
                     useful: 0.90,
                     useful_bad: 0.03,
                     abandoned: 0.05,
                     zombie: 0.02,
                 },
-                log_posterior: ClassScores::default(),
-                log_odds_abandoned_useful: -2.9,
-                evidence_terms: vec![],
-            },
+                ClassScores::default(),
+                -2.9,
+                vec![],
+            ),
             classification: Classification::Useful,
             confidence: Confidence::High,
             bayes_factors: vec![
@@ -327,21 +327,21 @@ mod tests {
     use crate::inference::posterior::EvidenceTerm;
 
     fn mock_posterior() -> PosteriorResult {
-        PosteriorResult {
-            posterior: ClassScores {
+        PosteriorResult::new(
+            ClassScores {
                 useful: 0.05,
                 useful_bad: 0.03,
                 abandoned: 0.87,
                 zombie: 0.05,
             },
-            log_posterior: ClassScores {
+            ClassScores {
                 useful: -3.0,
                 useful_bad: -3.5,
                 abandoned: -0.14,
                 zombie: -3.0,
             },
-            log_odds_abandoned_useful: 2.86,
-            evidence_terms: vec![
+            2.86,
+            vec![
                 EvidenceTerm {
                     feature: "prior".to_string(),
                     log_likelihood: ClassScores {
@@ -370,7 +370,7 @@ mod tests {
                     },
                 },
             ],
-        }
+        )
     }
 
     fn mock_ledger() -> EvidenceLedger {
@@ -485,17 +485,17 @@ mod tests {
 
     #[test]
     fn test_empty_evidence() {
-        let posterior = PosteriorResult {
-            posterior: ClassScores {
+        let posterior = PosteriorResult::new(
+            ClassScores {
                 useful: 0.25,
                 useful_bad: 0.25,
                 abandoned: 0.25,
                 zombie: 0.25,
             },
-            log_posterior: ClassScores::default(),
-            log_odds_abandoned_useful: 0.0,
-            evidence_terms: vec![],
-        };
+            ClassScores::default(),
+            0.0,
+            vec![],
+        );
         let ledger = EvidenceLedger {
             posterior: posterior.clone(),
             classification: Classification::Useful,
@@ -0,0 +1,151 @@
+//! Ancestry-aware evidence: processes under an active supervisor.
+//!
+//! A process whose ancestor is a running supervisor (agent, IDE, CI runner,
+//! terminal multiplexer) is far less likely to be abandoned than the same
+//! process detached from any parent. This module turns that observation into
+//! an [`EvidenceTerm`] so the adjustment lands in the evidence ledger via
+//! [`apply_evidence_terms`](super::apply_evidence_terms) instead of as an
+//! opaque score tweak.
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use super::posterior::{ClassScores, EvidenceTerm};
+use crate::collect::{ProcessRecord, ProcessTree};
+use crate::supervision::{SupervisorCategory, SupervisorDatabase};
+
+/// Evidence ledger label for the supervised-ancestry term.
+pub const SUPERVISED_ANCESTRY_FEATURE: &str = "supervised_ancestry";
+
+/// Configuration for the supervised-ancestry adjustment.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GenealogyConfig {
+    /// Likelihood ratio applied to the abandoned class when an ancestor is an
+    /// active supervisor (in (0, 1]; 1.0 disables the adjustment).
+    pub abandoned_likelihood_ratio: f64,
+}
+
+impl Default for GenealogyConfig {
+    fn default() -> Self {
+        Self {
+            abandoned_likelihood_ratio: 0.25,
+        }
+    }
+}
+
+/// PIDs in the snapshot whose command matches a supervisor signature.
+///
+/// PID 0/1 and orchestrators (systemd, launchd) are excluded: they are an
+/// ancestor of nearly every process, and unit-level supervision is attributed
+/// separately through the service context.
+pub fn active_supervisor_pids<'a>(
+    records: impl IntoIterator<Item = &'a ProcessRecord>,
+    database: &SupervisorDatabase,
+) -> HashSet<u32> {
+    records
+        .into_iter()
+        .filter(|record| record.pid.0 > 1)
+        .filter(|record| {
+            database
+                .find_matches(&record.comm)
+                .iter()
+                .any(|pattern| pattern.category != SupervisorCategory::Orchestrator)
+        })
+        .map(|record| record.pid.0)
+        .collect()
+}
+
+/// Evidence term down-weighting the abandoned class for `pid`, or `None` when
+/// no ancestor is an active supervisor.
+pub fn supervised_ancestry_term(
+    tree: &ProcessTree,
+    pid: u32,
+    supervisor_pids: &HashSet<u32>,
+    config: &GenealogyConfig,
+) -> Option<EvidenceTerm> {
+    let ratio = config.abandoned_likelihood_ratio;
+    if !(ratio > 0.0 && ratio < 1.0) || !tree.is_supervised_by(pid, supervisor_pids) {
+        return None;
+    }
+    Some(EvidenceTerm {
+        feature: SUPERVISED_ANCESTRY_FEATURE.to_string(),
+        log_likelihood: ClassScores {
+            useful: 0.0,
+            useful_bad: 0.0,
+            abandoned: ratio.ln(),
+            zombie: 0.0,
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inference::{apply_evidence_terms, PosteriorResult};
+    use crate::mock_process::MockProcessBuilder;
+
+    fn tree() -> ProcessTree {
+        // tmux(50) → bash(60) → job(70); detached(80) with exited parent 75
+        ProcessTree::from_ppids([(1, 0), (50, 1), (60, 50), (70, 60), (80, 75)])
+    }
+
+    #[test]
+    fn term_only_for_supervised_descendants() {
+        let supervisors = HashSet::from([50]);
+        let config = GenealogyConfig::default();
+
+        let term = supervised_ancestry_term(&tree(), 70, &supervisors, &config).unwrap();
+        assert_eq!(term.feature, SUPERVISED_ANCESTRY_FEATURE);
+        assert!((term.log_likelihood.abandoned - 0.25f64.ln()).abs() < 1e-12);
+        assert_eq!(term.log_likelihood.useful, 0.0);
+
+        assert!(supervised_ancestry_term(&tree(), 80, &supervisors, &config).is_none());
+        assert!(supervised_ancestry_term(&tree(), 50, &supervisors, &config).is_none());
+    }
+
+    #[test]
+    fn neutral_ratio_disables_term() {
+        let config = GenealogyConfig {
+            abandoned_likelihood_ratio: 1.0,
+        };
+        assert!(supervised_ancestry_term(&tree(), 70, &HashSet::from([50]), &config).is_none());
+    }
+
+    #[test]
+    fn term_lowers_abandoned_posterior() {
+        let posterior = ClassScores {
+            useful: 0.3,
+            useful_bad: 0.1,
+            abandoned: 0.5,
+            zombie: 0.1,
+        };
+        let log_posterior = ClassScores {
+            useful: posterior.useful.ln(),
+            useful_bad: posterior.useful_bad.ln(),
+            abandoned: posterior.abandoned.ln(),
+            zombie: posterior.zombie.ln(),
+        };
+        let base = PosteriorResult::new(posterior, log_posterior, 0.0, Vec::new());
+
+        let term = supervised_ancestry_term(
+            &tree(),
+            70,
+            &HashSet::from([50]),
+            &GenealogyConfig::default(),
+        )
+        .unwrap();
+        let adjusted = apply_evidence_terms(&base, [term]).unwrap();
+        assert!(adjusted.posterior.abandoned < posterior.abandoned);
+        assert!(adjusted.posterior.useful > posterior.useful);
+        assert_eq!(adjusted.evidence_terms.len(), 1);
+    }
+
+    #[test]
+    fn supervisor_pids_skip_init_and_orchestrators() {
+        let record = |pid: u32, comm: &str| MockProcessBuilder::new().pid(pid).comm(comm).build();
+        let records = [record(1, "systemd"), record(42, "tmux"), record(43, "bash")];
+        let pids = active_supervisor_pids(&records, &SupervisorDatabase::with_defaults());
+        assert_eq!(pids, HashSet::from([42]));
+    }
+}
@@ -0,0 +1,114 @@
+//! Evidence from the kernel wait state.
+//!
+//! A task stuck in uninterruptible I/O is making no progress of its own and
+//! points toward a stuck or abandoned process, while a task on the CPU is at
+//! least doing *something* (possibly a runaway). This module turns a
+//! [`KernelWaitSample`] into an [`EvidenceTerm`] so the distinction shows up
+//! in the evidence ledger.
+
+use serde::{Deserialize, Serialize};
+
+use super::posterior::{ClassScores, EvidenceTerm};
+use crate::collect::kernel_wait::{KernelWaitSample, KernelWaitState};
+
+/// Evidence ledger label for the kernel wait-state term.
+pub const KERNEL_WAIT_FEATURE: &str = "kernel_wait";
+
+/// Per-state log-likelihoods applied by [`kernel_wait_term`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KernelWaitConfig {
+    /// Blocked in uninterruptible I/O.
+    pub uninterruptible_io: ClassScores,
+    /// Parked on a futex.
+    pub futex_wait: ClassScores,
+    /// Running or runnable.
+    pub running: ClassScores,
+}
+
+impl Default for KernelWaitConfig {
+    fn default() -> Self {
+        Self {
+            uninterruptible_io: ClassScores {
+                useful: -0.5,
+                useful_bad: 0.4,
+                abandoned: 0.2,
+                zombie: 0.0,
+            },
+            futex_wait: ClassScores {
+                useful: 0.0,
+                useful_bad: 0.0,
+                abandoned: 0.1,
+                zombie: 0.0,
+            },
+            running: ClassScores {
+                useful: 0.2,
+                useful_bad: 0.2,
+                abandoned: -0.4,
+                zombie: -1.0,
+            },
+        }
+    }
+}
+
+/// Evidence term for `sample`, or `None` when the state is unknown.
+pub fn kernel_wait_term(
+    sample: &KernelWaitSample,
+    config: &KernelWaitConfig,
+) -> Option<EvidenceTerm> {
+    kernel_wait_state_term(sample.state, config)
+}
+
+/// Evidence term for a bare [`KernelWaitState`].
+pub fn kernel_wait_state_term(
+    state: KernelWaitState,
+    config: &KernelWaitConfig,
+) -> Option<EvidenceTerm> {
+    let log_likelihood = match state {
+        KernelWaitState::UninterruptibleIo => config.uninterruptible_io,
+        KernelWaitState::FutexWait => config.futex_wait,
+        KernelWaitState::Running => config.running,
+        KernelWaitState::Unknown => return None,
+    };
+    Some(EvidenceTerm {
+        feature: KERNEL_WAIT_FEATURE.to_string(),
+        log_likelihood,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collect::kernel_wait::classify_kernel_wait;
+    use crate::config::Class;
+
+    fn sample(state_char: char, wchan: &str) -> KernelWaitSample {
+        let (state, top_frame) = classify_kernel_wait(state_char, Some(wchan), &[]);
+        KernelWaitSample {
+            pid: 42,
+            state,
+            wchan: Some(wchan.to_string()),
+            top_frame,
+            warnings: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn io_wait_and_spin_produce_distinct_terms() {
+        let config = KernelWaitConfig::default();
+        let blocked = kernel_wait_term(&sample('D', "io_schedule"), &config).unwrap();
+        let spinning = kernel_wait_term(&sample('R', "0"), &config).unwrap();
+
+        assert_eq!(blocked.feature, KERNEL_WAIT_FEATURE);
+        assert_ne!(blocked.log_likelihood, spinning.log_likelihood);
+        assert!(blocked.log_likelihood[Class::Useful] < spinning.log_likelihood[Class::Useful]);
+        assert!(
+            blocked.log_likelihood[Class::Abandoned] > spinning.log_likelihood[Class::Abandoned]
+        );
+    }
+
+    #[test]
+    fn unknown_state_has_no_term() {
+        let config = KernelWaitConfig::default();
+        assert!(kernel_wait_term(&sample('S', "do_epoll_wait"), &config).is_none());
+    }
+}
@@ -27,6 +27,7 @@ pub struct EvidenceLedger {
     pub bayes_factors: Vec<BayesFactorEntry>,
     pub top_evidence: Vec<String>,
     pub why_summary: String,
+    #[serde(serialize_with = "pt_common::serialize_sorted")]
     pub evidence_glyphs: HashMap<String, String>,
 }
 
@@ -407,17 +408,17 @@ mod tests {
         abandoned: f64,
         zombie: f64,
     ) -> PosteriorResult {
-        PosteriorResult {
-            posterior: ClassScores {
+        PosteriorResult::new(
+            ClassScores {
                 useful,
                 useful_bad,
                 abandoned,
                 zombie,
             },
-            log_posterior: ClassScores::default(),
-            log_odds_abandoned_useful: 0.0,
-            evidence_terms: vec![],
-        }
+            ClassScores::default(),
+            0.0,
+            vec![],
+        )
     }
 
     fn make_posterior_with_terms(
@@ -425,17 +426,17 @@ mod tests {
         abandoned: f64,
         terms: Vec<EvidenceTerm>,
     ) -> PosteriorResult {
-        PosteriorResult {
-            posterior: ClassScores {
+        PosteriorResult::new(
+            ClassScores {
                 useful,
                 useful_bad: 0.0,
                 abandoned,
                 zombie: 0.0,
             },
-            log_posterior: ClassScores::default(),
-            log_odds_abandoned_useful: 0.0,
-            evidence_terms: terms,
-        }
+            ClassScores::default(),
+            0.0,
+            terms,
+        )
     }
 
     fn make_term(feature: &str, abandoned_ll: f64, useful_ll: f64) -> EvidenceTerm {
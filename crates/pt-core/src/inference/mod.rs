@@ -17,6 +17,7 @@ pub mod explain;
 pub mod explain_api;
 pub mod flip_conditions;
 pub mod galaxy_brain;
+pub mod genealogy;
 pub mod graph_smoothing;
 pub mod hawkes;
 pub mod hazard;
@@ -26,6 +27,7 @@ pub mod imm;
 pub mod impact;
 pub mod incremental;
 pub mod kalman;
+pub mod kernel_wait;
 pub mod kl_surprisal;
 pub mod ledger;
 pub mod ledger_display;
@@ -34,6 +36,9 @@ pub mod mpp;
 pub mod posterior;
 pub mod ppc;
 pub mod prior_override;
+pub mod process_scoring;
+#[cfg(target_os = "linux")]
+pub mod provenance;
 pub mod queueing;
 pub mod robust;
 pub mod robust_stats;
@@ -87,6 +92,9 @@ pub use evt::{
     BatchEvtAnalyzer, EstimationMethod, EvtError, EvtEvidence, GpdConfig, GpdFitter, GpdResult,
     TailType, ThresholdMethod,
 };
+pub use genealogy::{
+    active_supervisor_pids, supervised_ancestry_term, GenealogyConfig, SUPERVISED_ANCESTRY_FEATURE,
+};
 pub use graph_smoothing::{
     build_neighbors, edges_from_clusters, smooth_values, GraphSmoothingConfig, GraphSmoothingError,
     GraphSmoothingResult,
@@ -117,6 +125,9 @@ pub use incremental::{CacheStats, CachedPosterior, IncrementalConfig};
 pub use kalman::{
     FilterState, KalmanConfig, KalmanEvidence, KalmanFilter, KalmanResult, KalmanSummary,
 };
+pub use kernel_wait::{
+    kernel_wait_state_term, kernel_wait_term, KernelWaitConfig, KERNEL_WAIT_FEATURE,
+};
 pub use kl_surprisal::{
     kl_divergence_discrete, symmetric_kl_divergence, AbnormalitySeverity, BatchKlAnalyzer,
     BernoulliObservation, DeviationDirection, DeviationType, KlSurprisalAnalyzer,
@@ -136,18 +147,27 @@ pub use mpp::{
     MarkedPointProcess, MppConfig, MppEvidence, MppSummary,
 };
 pub use posterior::{
-    apply_evidence_terms, compute_posterior, ClassScores, CpuEvidence, Evidence, EvidenceTerm,
-    PosteriorError, PosteriorResult,
+    apply_evidence_terms, compute_posterior, compute_posterior_with_options, ClassScores,
+    CpuEvidence, Evidence, EvidenceContribution, EvidenceTerm, PosteriorError, PosteriorOptions,
+    PosteriorResult,
 };
 pub use ppc::{
-    AggregatedPpcEvidence, BatchPpcChecker, FallbackAction, PpcChecker, PpcConfig, PpcError,
-    PpcEvidence, PpcResult, StatisticCheck, TestStatistic,
+    AggregatedPpcEvidence, BatchPpcChecker, FallbackAction, MomentPpcResult, PpcChecker, PpcConfig,
+    PpcError, PpcEvidence, PpcResult, PredictiveMoments, StatisticCheck, TestStatistic,
 };
 pub use prior_override::{
     compute_posterior_with_overrides, resolve_priors, AppliedOverrides, CategoryPriorDefaults,
     OverriddenPrior, PriorContext, PriorSource, PriorSourceInfo, ResolvedPriors,
     UserPriorOverrides,
 };
+pub use process_scoring::{
+    match_level_label, process_evidence, score_process, state_to_flag, ProcessScore, ScoringContext,
+};
+#[cfg(target_os = "linux")]
+pub use provenance::{
+    build_provenance_inference_bundle, derive_provenance_adjustment, downgrade_confidence,
+    ProvenanceInferenceBundle, ProvenanceScoreAdjustment,
+};
 pub use queueing::{
     is_queue_saturated, EwmaEstimator, QueueStallConfig, QueueStallDetector, QueueStallResult,
 };
@@ -27,6 +27,14 @@
 //! - Reduce Safe-Bayes learning rate η
 //! - Flag in output for transparency
 //!
+//! # Analytic Checks
+//!
+//! [`PpcChecker::check_moments`] skips simulation entirely: it compares the
+//! observed mean and variance against the sampling distributions implied by
+//! the predictive's analytic moments ([`PredictiveMoments`]). It is cheap and
+//! fully deterministic; the decision layer folds its [`MomentPpcResult`]
+//! into the DRO trigger.
+//!
 //! # Example
 //!
 //! ```rust
@@ -51,7 +59,7 @@
 //! }
 //! ```
 
-use pt_math::{beta_inv_cdf, gamma_inv_cdf, normal_quantile};
+use pt_math::{beta_inv_cdf, gamma_inv_cdf, normal_cdf, normal_quantile};
 use serde::Serialize;
 use std::collections::HashMap;
 use thiserror::Error;
@@ -163,6 +171,82 @@ pub struct PpcResult {
     pub summary: String,
 }
 
+/// Analytic moments of a single draw from the posterior predictive.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct PredictiveMoments {
+    /// Expected value.
+    pub mean: f64,
+    /// Variance.
+    pub variance: f64,
+    /// Excess kurtosis (0 for a Normal), used for the variance check.
+    pub excess_kurtosis: f64,
+}
+
+impl PredictiveMoments {
+    /// Moments of Beta(α, β).
+    pub fn beta(alpha: f64, beta: f64) -> Result<Self, PpcError> {
+        if !(alpha > 0.0 && beta > 0.0) {
+            return Err(PpcError::InvalidParameters {
+                message: format!("Beta parameters must be positive: α={}, β={}", alpha, beta),
+            });
+        }
+        let sum = alpha + beta;
+        let product = alpha * beta;
+        Ok(Self {
+            mean: alpha / sum,
+            variance: product / (sum * sum * (sum + 1.0)),
+            excess_kurtosis: 6.0 * ((alpha - beta).powi(2) * (sum + 1.0) - product * (sum + 2.0))
+                / (product * (sum + 2.0) * (sum + 3.0)),
+        })
+    }
+
+    /// Moments of Gamma(shape, rate).
+    pub fn gamma(shape: f64, rate: f64) -> Result<Self, PpcError> {
+        if !(shape > 0.0 && rate > 0.0) {
+            return Err(PpcError::InvalidParameters {
+                message: format!(
+                    "Gamma parameters must be positive: shape={}, rate={}",
+                    shape, rate
+                ),
+            });
+        }
+        Ok(Self {
+            mean: shape / rate,
+            variance: shape / (rate * rate),
+            excess_kurtosis: 6.0 / shape,
+        })
+    }
+
+    /// Moments of Normal(mean, variance).
+    pub fn normal(mean: f64, variance: f64) -> Result<Self, PpcError> {
+        if variance.is_nan() || variance <= 0.0 {
+            return Err(PpcError::InvalidParameters {
+                message: format!("Normal variance must be positive: var={}", variance),
+            });
+        }
+        Ok(Self {
+            mean,
+            variance,
+            excess_kurtosis: 0.0,
+        })
+    }
+}
+
+/// Result of an analytic posterior predictive check.
+#[derive(Debug, Clone, Serialize)]
+pub struct MomentPpcResult {
+    /// Statistic with the smallest tail probability (Mean or Variance).
+    pub statistic: TestStatistic,
+    /// Observed value of that statistic.
+    pub observed_value: f64,
+    /// Its expected value under the predictive.
+    pub expected_value: f64,
+    /// Bonferroni-adjusted tail probability across both statistics.
+    pub p_value: f64,
+    /// Whether `p_value` fell below the configured alpha.
+    pub failed: bool,
+}
+
 /// Evidence for decision-core integration.
 #[derive(Debug, Clone, Serialize)]
 pub struct PpcEvidence {
@@ -328,6 +412,75 @@ impl PpcChecker {
         self.run_checks(observations, &pp_samples)
     }
 
+    /// Check observed mean and variance against analytic predictive moments.
+    ///
+    /// The sample mean is compared with N(μ, σ²/n) and the sample variance
+    /// with N(σ², σ⁴(2/(n-1) + κ/n)), κ being the excess kurtosis. The smaller
+    /// of the two tail probabilities is Bonferroni-adjusted and compared with
+    /// `alpha_threshold`; `two_sided` controls whether both tails count.
+    pub fn check_moments(
+        &self,
+        observations: &[f64],
+        moments: &PredictiveMoments,
+    ) -> Result<MomentPpcResult, PpcError> {
+        if observations.len() < self.config.min_observations.max(2) {
+            return Err(PpcError::InsufficientData {
+                needed: self.config.min_observations.max(2),
+                have: observations.len(),
+            });
+        }
+
+        let n = observations.len() as f64;
+        let mean = self.compute_statistic(observations, TestStatistic::Mean);
+        let variance = self.compute_statistic(observations, TestStatistic::Variance);
+
+        let mean_se = (moments.variance / n).sqrt();
+        let variance_se = moments.variance
+            * (2.0 / (n - 1.0) + moments.excess_kurtosis / n)
+                .max(f64::EPSILON)
+                .sqrt();
+
+        let candidates = [
+            (
+                TestStatistic::Mean,
+                mean,
+                moments.mean,
+                self.normal_tail((mean - moments.mean) / mean_se),
+            ),
+            (
+                TestStatistic::Variance,
+                variance,
+                moments.variance,
+                self.normal_tail((variance - moments.variance) / variance_se),
+            ),
+        ];
+        let (statistic, observed_value, expected_value, p_min) = candidates
+            .into_iter()
+            .min_by(|a, b| a.3.total_cmp(&b.3))
+            .expect("two candidates");
+
+        let p_value = (p_min * candidates.len() as f64).min(1.0);
+        Ok(MomentPpcResult {
+            statistic,
+            observed_value,
+            expected_value,
+            p_value,
+            failed: p_value < self.config.alpha_threshold,
+        })
+    }
+
+    /// Tail probability of a standard-normal score.
+    fn normal_tail(&self, z: f64) -> f64 {
+        if z.is_nan() {
+            return 1.0;
+        }
+        if self.config.two_sided {
+            2.0 * (1.0 - normal_cdf(z.abs()))
+        } else {
+            1.0 - normal_cdf(z)
+        }
+    }
+
     /// Run all configured checks on observations vs posterior predictive samples.
     fn run_checks(
         &self,
@@ -798,6 +951,79 @@ pub struct AggregatedPpcEvidence {
 mod tests {
     use super::*;
 
+    /// Stratified draws from Beta(α, β): what a well-specified model expects.
+    fn beta_quantile_sample(alpha: f64, beta: f64, n: usize) -> Vec<f64> {
+        (0..n)
+            .map(|i| beta_inv_cdf((i as f64 + 0.5) / n as f64, alpha, beta))
+            .collect()
+    }
+
+    #[test]
+    fn test_moment_check_passes_when_observed_matches_model() {
+        let checker = PpcChecker::default();
+        let moments = PredictiveMoments::beta(2.0, 8.0).unwrap();
+        let obs = beta_quantile_sample(2.0, 8.0, 50);
+
+        let result = checker.check_moments(&obs, &moments).unwrap();
+        assert!(!result.failed, "{:?}", result);
+        assert!(result.p_value > 0.05);
+    }
+
+    #[test]
+    fn test_moment_check_fails_on_gross_deviation() {
+        let checker = PpcChecker::default();
+        let moments = PredictiveMoments::beta(2.0, 8.0).unwrap();
+        let obs: Vec<f64> = (0..30).map(|i| 0.85 + 0.001 * i as f64).collect();
+
+        let result = checker.check_moments(&obs, &moments).unwrap();
+        assert!(result.failed);
+        assert_eq!(result.statistic, TestStatistic::Mean);
+        assert!((result.expected_value - 0.2).abs() < 1e-12);
+        assert!(result.p_value < 1e-6);
+    }
+
+    #[test]
+    fn test_moment_check_catches_variance_mismatch() {
+        let checker = PpcChecker::default();
+        let moments = PredictiveMoments::normal(0.0, 1.0).unwrap();
+        // Right mean, ten times the spread.
+        let obs: Vec<f64> = (0..40)
+            .map(|i| 10.0 * normal_quantile((i as f64 + 0.5) / 40.0))
+            .collect();
+
+        let result = checker.check_moments(&obs, &moments).unwrap();
+        assert!(result.failed);
+        assert_eq!(result.statistic, TestStatistic::Variance);
+    }
+
+    #[test]
+    fn test_moment_check_is_deterministic() {
+        let checker = PpcChecker::default();
+        let moments = PredictiveMoments::gamma(2.0, 0.5).unwrap();
+        assert_eq!(moments.mean, 4.0);
+        assert_eq!(moments.variance, 8.0);
+        let obs: Vec<f64> = (0..25).map(|i| 2.0 + 0.2 * i as f64).collect();
+
+        let a = checker.check_moments(&obs, &moments).unwrap();
+        let b = checker.check_moments(&obs, &moments).unwrap();
+        assert_eq!(a.p_value, b.p_value);
+        assert_eq!(a.failed, b.failed);
+    }
+
+    #[test]
+    fn test_moment_check_validates_inputs() {
+        let checker = PpcChecker::default();
+        assert!(PredictiveMoments::beta(0.0, 1.0).is_err());
+        assert!(PredictiveMoments::gamma(1.0, -1.0).is_err());
+        assert!(PredictiveMoments::normal(0.0, 0.0).is_err());
+
+        let moments = PredictiveMoments::normal(0.0, 1.0).unwrap();
+        assert!(matches!(
+            checker.check_moments(&[0.1, 0.2], &moments),
+            Err(PpcError::InsufficientData { .. })
+        ));
+    }
+
     #[test]
     fn test_ppc_config_default() {
         let config = PpcConfig::default();
@@ -0,0 +1,407 @@
+//! Per-process posterior scoring shared by `agent plan` and the MCP `pt_scan` tool.
+//!
+//! [`score_process`] takes one scanned process through the signature fast path
+//! (or the prior-overridden posterior), the supervised-ancestry term and, on
+//! Linux, the provenance adjustments. It returns the posterior with its
+//! evidence ledger; choosing an action is left to the caller.
+
+use std::collections::HashSet;
+
+use crate::collect::{ProcessRecord, ProcessState, ProcessTree};
+use crate::config::priors::Priors;
+#[cfg(target_os = "linux")]
+use crate::inference::provenance::{
+    build_provenance_inference_bundle, derive_provenance_adjustment, downgrade_confidence,
+    ProvenanceInferenceBundle, ProvenanceScoreAdjustment,
+};
+use crate::inference::{
+    active_supervisor_pids, apply_evidence_terms, compute_posterior_with_overrides,
+    supervised_ancestry_term, try_signature_fast_path, CpuEvidence, Evidence, EvidenceLedger,
+    FastPathConfig, FastPathSkipReason, GenealogyConfig, PosteriorResult, PriorContext,
+};
+use crate::supervision::signature::{
+    MatchLevel, ProcessMatchContext, SignatureDatabase, SignatureMatch,
+};
+use crate::supervision::SupervisorDatabase;
+
+/// Scan-wide inputs for [`score_process`].
+pub struct ScoringContext<'a> {
+    priors: &'a Priors,
+    signature_db: &'a SignatureDatabase,
+    fast_path: &'a FastPathConfig,
+    process_tree: ProcessTree,
+    supervisor_pids: HashSet<u32>,
+    genealogy: GenealogyConfig,
+    #[cfg(target_os = "linux")]
+    provenance: ProvenanceInferenceBundle,
+}
+
+impl<'a> ScoringContext<'a> {
+    /// Build the context for scoring `candidates` out of the full `scan`.
+    ///
+    /// `scan` supplies the process tree and the running supervisors;
+    /// provenance evidence is only gathered for `candidates`.
+    pub fn new(
+        priors: &'a Priors,
+        signature_db: &'a SignatureDatabase,
+        fast_path: &'a FastPathConfig,
+        scan: &[ProcessRecord],
+        candidates: &[&ProcessRecord],
+    ) -> Self {
+        #[cfg(not(target_os = "linux"))]
+        let _ = candidates;
+        Self {
+            priors,
+            signature_db,
+            fast_path,
+            process_tree: ProcessTree::from_records(scan),
+            supervisor_pids: active_supervisor_pids(scan, &SupervisorDatabase::with_defaults()),
+            genealogy: GenealogyConfig::default(),
+            #[cfg(target_os = "linux")]
+            provenance: build_provenance_inference_bundle(candidates),
+        }
+    }
+}
+
+/// Posterior and evidence ledger for one process, with how they were reached.
+pub struct ProcessScore<'a> {
+    pub posterior: PosteriorResult,
+    pub ledger: EvidenceLedger,
+    pub signature_match: Option<SignatureMatch<'a>>,
+    /// True when the signature fast path supplied the posterior.
+    pub fast_path_used: bool,
+    /// Why a matched signature did not take the fast path.
+    pub fast_path_skip_reason: Option<FastPathSkipReason>,
+    /// Where the priors came from (`"signature_fast_path"` on the fast path).
+    pub prior_source: String,
+    #[cfg(target_os = "linux")]
+    pub provenance: ProvenanceScoreAdjustment,
+}
+
+impl ProcessScore<'_> {
+    /// Probability of the most likely class; candidate thresholds compare
+    /// against this.
+    pub fn max_posterior(&self) -> f64 {
+        let posterior = &self.posterior.posterior;
+        posterior
+            .useful
+            .max(posterior.useful_bad)
+            .max(posterior.abandoned)
+            .max(posterior.zombie)
+    }
+}
+
+/// Evidence state flag for a process state.
+pub fn state_to_flag(state: ProcessState) -> Option<usize> {
+    match state {
+        ProcessState::Running => Some(0),
+        ProcessState::Sleeping => Some(1),
+        ProcessState::DiskSleep => Some(2),
+        ProcessState::Zombie => Some(3),
+        ProcessState::Stopped => Some(4),
+        ProcessState::Idle => Some(5),
+        ProcessState::Dead => Some(6),
+        ProcessState::Unknown => None,
+    }
+}
+
+/// Evidence available from a quick-scan record alone.
+///
+/// Network and I/O activity are left unset; deep-scan callers fill them in.
+pub fn process_evidence(proc: &ProcessRecord) -> Evidence {
+    Evidence {
+        cpu: Some(CpuEvidence::Fraction {
+            occupancy: (proc.cpu_percent / 100.0).clamp(0.0, 1.0),
+        }),
+        runtime_seconds: Some(proc.elapsed.as_secs_f64()),
+        orphan: Some(proc.is_orphan()),
+        tty: Some(proc.has_tty()),
+        net: None,
+        io_active: None,
+        state_flag: state_to_flag(proc.state),
+        command_category: None,
+        queue_saturated: None,
+    }
+}
+
+/// Stable label for a signature match level.
+pub fn match_level_label(level: MatchLevel) -> &'static str {
+    match level {
+        MatchLevel::None => "none",
+        MatchLevel::GenericCategory => "generic_category",
+        MatchLevel::CommandOnly => "command_only",
+        MatchLevel::CommandPlusArgs => "command_plus_args",
+        MatchLevel::ExactCommand => "exact_command",
+        MatchLevel::MultiPattern => "multi_pattern",
+    }
+}
+
+/// Score `proc` from `evidence`.
+///
+/// Returns `None` when the posterior cannot be computed.
+pub fn score_process<'a>(
+    ctx: &ScoringContext<'a>,
+    proc: &ProcessRecord,
+    evidence: &Evidence,
+) -> Option<ProcessScore<'a>> {
+    let pid = proc.pid.0;
+    let mut match_ctx = ProcessMatchContext::with_comm(&proc.comm);
+    if !proc.cmd.is_empty() {
+        match_ctx = match_ctx.cmdline(&proc.cmd);
+    }
+    let signature_match = ctx.signature_db.best_match(&match_ctx);
+
+    let mut fast_path_skip_reason = None;
+    let fast_path = match signature_match.as_ref() {
+        Some(sig_match) => match try_signature_fast_path(ctx.fast_path, Some(sig_match), pid) {
+            Ok(fast_path) => fast_path,
+            Err(reason) => {
+                fast_path_skip_reason = Some(reason);
+                None
+            }
+        },
+        None => None,
+    };
+    let fast_path_used = fast_path.is_some();
+
+    let (mut posterior, mut ledger, prior_source) = match fast_path {
+        Some(fast_path) => (
+            fast_path.posterior,
+            fast_path.ledger,
+            "signature_fast_path".to_string(),
+        ),
+        None => {
+            let prior_context = PriorContext {
+                global_priors: ctx.priors,
+                signature_match: signature_match.as_ref(),
+                category_defaults: None,
+                user_overrides: None,
+            };
+            let (result, source_info) =
+                compute_posterior_with_overrides(&prior_context, evidence).ok()?;
+            let ledger = EvidenceLedger::from_posterior_result(&result, Some(pid), None);
+            (result, ledger, source_info.source.to_string())
+        }
+    };
+
+    if let Some(term) =
+        supervised_ancestry_term(&ctx.process_tree, pid, &ctx.supervisor_pids, &ctx.genealogy)
+    {
+        match apply_evidence_terms(&posterior, [term]) {
+            Ok(adjusted) => {
+                posterior = adjusted;
+                ledger = EvidenceLedger::from_posterior_result(&posterior, Some(pid), None);
+            }
+            Err(err) => {
+                tracing::debug!(
+                    pid,
+                    error = %err,
+                    "Failed to apply supervised-ancestry evidence term"
+                );
+            }
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    let provenance = {
+        let adjustment = derive_provenance_adjustment(pid, &ctx.provenance);
+        apply_provenance_adjustment(pid, &adjustment, &mut posterior, &mut ledger);
+        adjustment
+    };
+
+    if let Some(sig_match) = signature_match.as_ref() {
+        if !fast_path_used {
+            ledger.top_evidence.insert(
+                0,
+                format!(
+                    "Signature match: {} (score={:.2}, level={})",
+                    sig_match.signature.name,
+                    sig_match.score,
+                    match_level_label(sig_match.level)
+                ),
+            );
+            ledger.why_summary = format!(
+                "Matched signature '{}' (score {:.2}, level {}, prior source {}). {}",
+                sig_match.signature.name,
+                sig_match.score,
+                match_level_label(sig_match.level),
+                prior_source,
+                ledger.why_summary
+            );
+        }
+    }
+
+    Some(ProcessScore {
+        posterior,
+        ledger,
+        signature_match,
+        fast_path_used,
+        fast_path_skip_reason,
+        prior_source,
+        #[cfg(target_os = "linux")]
+        provenance,
+    })
+}
+
+/// Fold provenance terms into `posterior` and downgrade `ledger` confidence.
+#[cfg(target_os = "linux")]
+fn apply_provenance_adjustment(
+    pid: u32,
+    adjustment: &ProvenanceScoreAdjustment,
+    posterior: &mut PosteriorResult,
+    ledger: &mut EvidenceLedger,
+) {
+    if !adjustment.evidence_terms.is_empty() {
+        match apply_evidence_terms(posterior, adjustment.evidence_terms.clone()) {
+            Ok(adjusted) => {
+                *posterior = adjusted;
+                *ledger = EvidenceLedger::from_posterior_result(posterior, Some(pid), None);
+            }
+            Err(err) => {
+                tracing::debug!(
+                    pid,
+                    error = %err,
+                    "Failed to apply provenance-derived evidence terms"
+                );
+            }
+        }
+    }
+
+    if adjustment.confidence_penalty_steps > 0 {
+        ledger.confidence =
+            downgrade_confidence(ledger.confidence, adjustment.confidence_penalty_steps);
+        if !adjustment.confidence_notes.is_empty() {
+            let joined = adjustment.confidence_notes.join("; ");
+            ledger
+                .top_evidence
+                .push(format!("Provenance confidence downgrade: {joined}"));
+            ledger.why_summary = format!("{} Provenance caveats: {}.", ledger.why_summary, joined);
+        }
+    }
+
+    for term in &adjustment.evidence_terms {
+        let glyph = if term.feature.contains("blast_radius") {
+            "🛡"
+        } else {
+            "🔗"
+        };
+        ledger
+            .evidence_glyphs
+            .insert(term.feature.clone(), glyph.to_string());
+    }
+
+    tracing::debug!(
+        pid,
+        evidence_completeness = adjustment.evidence_completeness,
+        blast_radius_risk = adjustment.blast_radius.risk_score,
+        blast_radius_confidence = adjustment.blast_radius.confidence,
+        blast_radius_level = ?adjustment.blast_radius.risk_level,
+        confidence_penalty_steps = adjustment.confidence_penalty_steps,
+        score_terms = ?adjustment
+            .evidence_terms
+            .iter()
+            .map(|term| term.feature.clone())
+            .collect::<Vec<_>>(),
+        notes = ?adjustment.confidence_notes,
+        "Applied provenance-derived scoring adjustments"
+    );
+
+    // Explanation-trace diagnostics: emit per-feature evidence selection
+    // events so provenance decisions can be diagnosed from the trace log.
+    for term in &adjustment.evidence_terms {
+        let net_shift = term.log_likelihood.abandoned - term.log_likelihood.useful;
+        let direction = if net_shift > 0.1 {
+            "toward_abandon"
+        } else if net_shift < -0.1 {
+            "toward_useful"
+        } else {
+            "neutral"
+        };
+        tracing::trace!(
+            pid,
+            feature = %term.feature,
+            abandoned_ll = term.log_likelihood.abandoned,
+            useful_ll = term.log_likelihood.useful,
+            net_shift = net_shift,
+            direction = direction,
+            "provenance_evidence_selected"
+        );
+    }
+
+    if adjustment.confidence_penalty_steps > 0 {
+        tracing::trace!(
+            pid,
+            steps = adjustment.confidence_penalty_steps,
+            reasons = ?adjustment.confidence_notes,
+            evidence_completeness = adjustment.evidence_completeness,
+            "provenance_confidence_downgraded"
+        );
+    }
+
+    if adjustment.blast_radius.total_affected > 0 {
+        tracing::trace!(
+            pid,
+            risk_score = adjustment.blast_radius.risk_score,
+            risk_level = ?adjustment.blast_radius.risk_level,
+            total_affected = adjustment.blast_radius.total_affected,
+            confidence = adjustment.blast_radius.confidence,
+            summary = %adjustment.blast_radius.summary,
+            "provenance_blast_radius_computed"
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(pid: u32, state: ProcessState) -> ProcessRecord {
+        ProcessRecord {
+            pid: pt_common::ProcessId(pid),
+            ppid: pt_common::ProcessId(1),
+            uid: 1000,
+            user: "tester".to_string(),
+            pgid: Some(pid),
+            sid: Some(pid),
+            start_id: pt_common::StartId(format!("synthetic:123:{pid}")),
+            comm: "worker".to_string(),
+            cmd: "worker --serve".to_string(),
+            state,
+            cpu_percent: 0.0,
+            rss_bytes: 1024,
+            vsz_bytes: 4096,
+            tty: None,
+            start_time_unix: 0,
+            elapsed: std::time::Duration::from_secs(86_400),
+            source: "test".to_string(),
+            container_info: None,
+        }
+    }
+
+    #[test]
+    fn state_flags_follow_state_order() {
+        assert_eq!(state_to_flag(ProcessState::Running), Some(0));
+        assert_eq!(state_to_flag(ProcessState::Zombie), Some(3));
+        assert_eq!(state_to_flag(ProcessState::Unknown), None);
+    }
+
+    #[test]
+    fn score_process_reports_posterior_and_ledger() {
+        let priors = Priors::default();
+        let db = SignatureDatabase::with_defaults();
+        let fast_path = FastPathConfig::default();
+        let scan = vec![record(200, ProcessState::Zombie)];
+        let candidates: Vec<&ProcessRecord> = scan.iter().collect();
+        let ctx = ScoringContext::new(&priors, &db, &fast_path, &scan, &candidates);
+
+        let scored = score_process(&ctx, &scan[0], &process_evidence(&scan[0])).unwrap();
+        let posterior = &scored.posterior.posterior;
+        let total =
+            posterior.useful + posterior.useful_bad + posterior.abandoned + posterior.zombie;
+        assert!((total - 1.0).abs() < 1e-9);
+        assert!(scored.max_posterior() >= posterior.zombie);
+        assert!(scored.max_posterior() >= posterior.useful);
+        assert!(!scored.prior_source.is_empty());
+        assert!(!scored.fast_path_used || scored.signature_match.is_some());
+    }
+}
@@ -197,12 +197,7 @@ pub fn try_signature_fast_path(
     Ok(Some(FastPathResult {
         classification,
         confidence,
-        posterior: PosteriorResult {
-            posterior,
-            log_posterior,
-            log_odds_abandoned_useful: log_odds,
-            evidence_terms: vec![], // No Bayesian evidence computation
-        },
+        posterior: PosteriorResult::new(posterior, log_posterior, log_odds, vec![]),
         signature_name: sig_match.signature.name.clone(),
         match_score: sig_match.score,
         ledger,
@@ -287,12 +282,7 @@ fn build_fast_path_ledger(
     let log_odds = log_posterior.abandoned - log_posterior.useful;
 
     EvidenceLedger {
-        posterior: PosteriorResult {
-            posterior: *posterior,
-            log_posterior,
-            log_odds_abandoned_useful: log_odds,
-            evidence_terms: vec![],
-        },
+        posterior: PosteriorResult::new(*posterior, log_posterior, log_odds, vec![]),
         classification,
         confidence,
         bayes_factors: vec![BayesFactorEntry {
@@ -7,6 +7,8 @@
 use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
 use std::thread;
 use std::time::{Duration, Instant};
 
@@ -41,6 +43,8 @@ pub struct Tutorial {
     pub doc_path: &'static str,
     pub commands: &'static [&'static str],
     pub hints: &'static [&'static str],
+    /// Tutorial ids that should be completed first.
+    pub prerequisites: &'static [&'static str],
     #[serde(skip_serializing)]
     pub verify_args: &'static [&'static [&'static str]],
 }
@@ -57,6 +61,7 @@ static TUTORIALS: &[Tutorial] = &[
             "Start with scan and plan-only commands before any apply step.",
             "Use robot explain to understand evidence on a single PID.",
         ],
+        prerequisites: &[],
         verify_args: VERIFY_01,
     },
     Tutorial {
@@ -70,6 +75,7 @@ static TUTORIALS: &[Tutorial] = &[
             "Prefer explain and plan before any apply command.",
             "Check command ancestry when deciding if a test process is abandoned.",
         ],
+        prerequisites: &["01"],
         verify_args: VERIFY_02,
     },
     Tutorial {
@@ -83,6 +89,7 @@ static TUTORIALS: &[Tutorial] = &[
             "Use summary and genealogy views to inspect port-holding process trees.",
             "Avoid force-killing unknown parent processes; inspect first.",
         ],
+        prerequisites: &["01"],
         verify_args: VERIFY_03,
     },
     Tutorial {
@@ -100,6 +107,7 @@ static TUTORIALS: &[Tutorial] = &[
             "Persist plan output before apply to keep a full audit trail.",
             "Prefer dry-run in CI when introducing new policy thresholds.",
         ],
+        prerequisites: &["01"],
         verify_args: VERIFY_04,
     },
     Tutorial {
@@ -116,6 +124,7 @@ static TUTORIALS: &[Tutorial] = &[
             "Use fleet report/status commands to inspect host-level outcomes.",
             "Keep apply operations gated by policy and explicit operator intent.",
         ],
+        prerequisites: &["04"],
         verify_args: VERIFY_05,
     },
     Tutorial {
@@ -129,6 +138,7 @@ static TUTORIALS: &[Tutorial] = &[
             "Shadow mode should never execute destructive actions.",
             "Compare shadow telemetry before changing thresholds.",
         ],
+        prerequisites: &["01"],
         verify_args: VERIFY_06,
     },
     Tutorial {
@@ -142,6 +152,7 @@ static TUTORIALS: &[Tutorial] = &[
             "Use deep scan selectively because it is more expensive.",
             "Fallback to quick scan when privileged probes are unavailable.",
         ],
+        prerequisites: &["01"],
         verify_args: VERIFY_07,
     },
 ];
@@ -190,6 +201,72 @@ impl LearnProgress {
         }
         self.completed_count() as f64 / total as f64
     }
+
+    /// Serialize progress for transfer to another machine.
+    pub fn export(&self) -> String {
+        serde_json::to_string_pretty(self).expect("progress serialization")
+    }
+
+    /// Merge completions from `other`, keeping the earliest timestamp for
+    /// tutorials completed on both sides. Returns how many entries changed.
+    pub fn import_merge(&mut self, other: &LearnProgress) -> usize {
+        let mut changed = 0;
+        for (id, theirs) in &other.completed {
+            match self.completed.get(id) {
+                Some(ours) if !completed_earlier(theirs, ours) => {}
+                _ => {
+                    self.completed.insert(id.clone(), theirs.clone());
+                    changed += 1;
+                }
+            }
+        }
+        changed
+    }
+}
+
+/// Whether timestamp `a` is strictly earlier than `b`. Unparseable
+/// timestamps never win over parseable ones.
+fn completed_earlier(a: &str, b: &str) -> bool {
+    let parse = |s: &str| chrono::DateTime::parse_from_rfc3339(s).ok();
+    match (parse(a), parse(b)) {
+        (Some(a), Some(b)) => a < b,
+        (Some(_), None) => true,
+        _ => false,
+    }
+}
+
+/// Result of reading an exported progress file.
+#[derive(Debug)]
+pub enum ProgressImport {
+    /// Compatible progress, ready to merge.
+    Ready(LearnProgress),
+    /// The export could not be used; show static guidance instead.
+    Fallback { reason: String },
+}
+
+/// Parse an exported progress document.
+///
+/// Unreadable documents and exports from a different major schema version
+/// are reported as [`ProgressImport::Fallback`] rather than errors.
+pub fn parse_progress_import(raw: &str) -> ProgressImport {
+    let progress = match serde_json::from_str::<LearnProgress>(raw) {
+        Ok(progress) => progress,
+        Err(e) => {
+            return ProgressImport::Fallback {
+                reason: format!("progress export is unreadable: {}", e),
+            }
+        }
+    };
+    let major = |v: &str| v.split('.').next().map(str::to_string);
+    if major(&progress.schema_version) != major(LEARN_SCHEMA_VERSION) {
+        return ProgressImport::Fallback {
+            reason: format!(
+                "progress export uses schema {} but this build expects {}",
+                progress.schema_version, LEARN_SCHEMA_VERSION
+            ),
+        };
+    }
+    ProgressImport::Ready(progress)
 }
 
 #[derive(Debug, Error)]
@@ -206,6 +283,13 @@ pub enum LearnError {
         #[source]
         source: serde_json::Error,
     },
+    #[error("Tutorial {tutorial} lists unknown prerequisite {prerequisite}")]
+    UnknownPrerequisite {
+        tutorial: String,
+        prerequisite: String,
+    },
+    #[error("Tutorial prerequisites form a cycle: {}", cycle.join(" -> "))]
+    PrerequisiteCycle { cycle: Vec<String> },
 }
 
 pub fn progress_path(config_dir: &Path) -> PathBuf {
@@ -267,11 +351,80 @@ pub fn clear_progress(progress: &mut LearnProgress) {
     progress.schema_version = LEARN_SCHEMA_VERSION.to_string();
 }
 
+/// Prerequisites of `tutorial` that are not yet completed.
+pub fn missing_prerequisites(progress: &LearnProgress, tutorial: &Tutorial) -> Vec<&'static str> {
+    tutorial
+        .prerequisites
+        .iter()
+        .copied()
+        .filter(|id| !progress.completed.contains_key(*id))
+        .collect()
+}
+
+/// Next incomplete tutorial whose prerequisites are all completed.
+///
+/// Falls back to the first incomplete tutorial if none is unlocked, so a
+/// broken catalog never leaves the learner without a suggestion.
 pub fn next_tutorial<'a>(
     progress: &LearnProgress,
     catalog: &'a [Tutorial],
 ) -> Option<&'a Tutorial> {
-    catalog.iter().find(|t| !progress.is_completed(t))
+    let mut incomplete = catalog.iter().filter(|t| !progress.is_completed(t));
+    let first = incomplete.clone().next();
+    incomplete
+        .find(|t| missing_prerequisites(progress, t).is_empty())
+        .or(first)
+}
+
+/// Check that every prerequisite exists and that they form a DAG.
+pub fn validate_catalog(catalog: &[Tutorial]) -> Result<(), LearnError> {
+    #[derive(Clone, Copy, PartialEq)]
+    enum Mark {
+        Unvisited,
+        InProgress,
+        Done,
+    }
+
+    fn visit(
+        index: usize,
+        catalog: &[Tutorial],
+        marks: &mut [Mark],
+        path: &mut Vec<String>,
+    ) -> Result<(), LearnError> {
+        let tutorial = &catalog[index];
+        match marks[index] {
+            Mark::Done => return Ok(()),
+            Mark::InProgress => {
+                let start = path.iter().position(|id| id == tutorial.id).unwrap_or(0);
+                let mut cycle = path[start..].to_vec();
+                cycle.push(tutorial.id.to_string());
+                return Err(LearnError::PrerequisiteCycle { cycle });
+            }
+            Mark::Unvisited => {}
+        }
+
+        marks[index] = Mark::InProgress;
+        path.push(tutorial.id.to_string());
+        for prerequisite in tutorial.prerequisites {
+            let Some(dep) = catalog.iter().position(|t| t.id == *prerequisite) else {
+                return Err(LearnError::UnknownPrerequisite {
+                    tutorial: tutorial.id.to_string(),
+                    prerequisite: prerequisite.to_string(),
+                });
+            };
+            visit(dep, catalog, marks, path)?;
+        }
+        path.pop();
+        marks[index] = Mark::Done;
+        Ok(())
+    }
+
+    let mut marks = vec![Mark::Unvisited; catalog.len()];
+    let mut path = Vec::new();
+    for index in 0..catalog.len() {
+        visit(index, catalog, &mut marks, &mut path)?;
+    }
+    Ok(())
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -362,6 +515,14 @@ fn run_check_with_budget(binary: &Path, args: &[&str], budget: Duration) -> Veri
     }
 }
 
+/// Maximum number of verification checks run at once.
+pub const MAX_PARALLEL_CHECKS: usize = 4;
+
+/// Run a tutorial's verification checks under per-check and total budgets.
+///
+/// Checks run concurrently (at most [`MAX_PARALLEL_CHECKS`] at a time) and
+/// results are reported in `verify_args` order. A check that cannot start
+/// before the total budget runs out is reported as `budget_exhausted`.
 pub fn verify_tutorial(
     binary: &Path,
     tutorial: &Tutorial,
@@ -369,45 +530,58 @@ pub fn verify_tutorial(
     total_budget: Duration,
 ) -> VerifyResult {
     let overall_started = Instant::now();
-    let mut checks = Vec::new();
-    let mut fallback_active = false;
-    let mut fallback_reason = None;
-    let mut all_ok = true;
-
-    for args in tutorial.verify_args {
-        let elapsed = overall_started.elapsed();
-        if elapsed >= total_budget {
-            fallback_active = true;
-            all_ok = false;
-            fallback_reason = Some("total verification budget exhausted".to_string());
-            checks.push(VerifyCheck {
-                command: command_label(args),
-                status: "budget_exhausted".to_string(),
-                exit_code: None,
-                duration_ms: elapsed.as_millis() as u64,
-                error: Some("falling back to static tutorial guidance".to_string()),
+    let next = AtomicUsize::new(0);
+    let slots: Vec<Mutex<Option<VerifyCheck>>> = tutorial
+        .verify_args
+        .iter()
+        .map(|_| Mutex::new(None))
+        .collect();
+    let workers = MAX_PARALLEL_CHECKS.min(tutorial.verify_args.len());
+
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| loop {
+                let index = next.fetch_add(1, Ordering::SeqCst);
+                let Some(args) = tutorial.verify_args.get(index) else {
+                    break;
+                };
+                let elapsed = overall_started.elapsed();
+                let check = if elapsed >= total_budget {
+                    VerifyCheck {
+                        command: command_label(args),
+                        status: "budget_exhausted".to_string(),
+                        exit_code: None,
+                        duration_ms: elapsed.as_millis() as u64,
+                        error: Some("falling back to static tutorial guidance".to_string()),
+                    }
+                } else {
+                    let remaining = total_budget.saturating_sub(elapsed);
+                    run_check_with_budget(binary, args, per_check_budget.min(remaining))
+                };
+                *slots[index].lock().expect("verify slot") = Some(check);
             });
-            break;
-        }
-
-        let remaining = total_budget.saturating_sub(elapsed);
-        let budget = per_check_budget.min(remaining);
-        let check = run_check_with_budget(binary, args, budget);
-        if check.status != "ok" {
-            all_ok = false;
-            if check.status == "timeout" {
-                fallback_active = true;
-                fallback_reason = Some("per-check verification budget exhausted".to_string());
-            }
         }
-        checks.push(check);
-    }
+    });
+
+    let checks: Vec<VerifyCheck> = slots
+        .into_iter()
+        .filter_map(|slot| slot.into_inner().expect("verify slot"))
+        .collect();
+
+    let all_ok = checks.iter().all(|c| c.status == "ok");
+    let fallback_reason = if checks.iter().any(|c| c.status == "budget_exhausted") {
+        Some("total verification budget exhausted".to_string())
+    } else if checks.iter().any(|c| c.status == "timeout") {
+        Some("per-check verification budget exhausted".to_string())
+    } else {
+        None
+    };
 
     VerifyResult {
         tutorial_id: tutorial.id.to_string(),
         tutorial_slug: tutorial.slug.to_string(),
         status: if all_ok { "ok" } else { "degraded" }.to_string(),
-        fallback_active,
+        fallback_active: fallback_reason.is_some(),
         fallback_reason,
         total_duration_ms: overall_started.elapsed().as_millis() as u64,
         checks,
@@ -454,6 +628,134 @@ mod tests {
         assert_ne!(next.id, first.id);
     }
 
+    fn tutorial(id: &'static str, prerequisites: &'static [&'static str]) -> Tutorial {
+        Tutorial {
+            id,
+            slug: id,
+            title: id,
+            goal: "",
+            doc_path: "",
+            commands: &[],
+            hints: &[],
+            prerequisites,
+            verify_args: &[],
+        }
+    }
+
+    #[test]
+    fn builtin_catalog_is_a_dag() {
+        validate_catalog(tutorials()).expect("catalog should be acyclic");
+    }
+
+    #[test]
+    fn catalog_cycle_is_rejected() {
+        let catalog = [
+            tutorial("a", &[]),
+            tutorial("b", &["d"]),
+            tutorial("c", &["b"]),
+            tutorial("d", &["c"]),
+        ];
+        match validate_catalog(&catalog) {
+            Err(LearnError::PrerequisiteCycle { cycle }) => {
+                assert_eq!(cycle, vec!["b", "d", "c", "b"]);
+            }
+            other => panic!("expected cycle, got {other:?}"),
+        }
+
+        let unknown = [tutorial("a", &["zz"])];
+        assert!(matches!(
+            validate_catalog(&unknown),
+            Err(LearnError::UnknownPrerequisite { .. })
+        ));
+    }
+
+    #[test]
+    fn next_tutorial_respects_prerequisites() {
+        let catalog = [
+            tutorial("01", &[]),
+            tutorial("02", &["03"]),
+            tutorial("03", &["01"]),
+        ];
+        let mut p = LearnProgress::default();
+        assert_eq!(next_tutorial(&p, &catalog).map(|t| t.id), Some("01"));
+
+        mark_completed(&mut p, &catalog[0]);
+        // 02 is next by index but waits on 03.
+        assert_eq!(next_tutorial(&p, &catalog).map(|t| t.id), Some("03"));
+        assert_eq!(missing_prerequisites(&p, &catalog[1]), vec!["03"]);
+
+        mark_completed(&mut p, &catalog[2]);
+        assert_eq!(next_tutorial(&p, &catalog).map(|t| t.id), Some("02"));
+    }
+
+    #[test]
+    fn fleet_tutorial_waits_for_agent_workflow() {
+        let mut p = LearnProgress::default();
+        for t in tutorials().iter().filter(|t| t.id != "04" && t.id != "05") {
+            mark_completed(&mut p, t);
+        }
+        assert_eq!(next_tutorial(&p, tutorials()).map(|t| t.id), Some("04"));
+        let fleet = find_tutorial("05").expect("tutorial");
+        assert_eq!(missing_prerequisites(&p, fleet), vec!["04"]);
+    }
+
+    fn progress_with(entries: &[(&str, &str)]) -> LearnProgress {
+        let mut p = LearnProgress::default();
+        for (id, at) in entries {
+            p.completed.insert(id.to_string(), at.to_string());
+        }
+        p
+    }
+
+    #[test]
+    fn import_merge_keeps_earliest_completion() {
+        let mut laptop = progress_with(&[
+            ("01", "2026-03-01T10:00:00+00:00"),
+            ("02", "2026-03-05T10:00:00+00:00"),
+        ]);
+        let desktop = progress_with(&[
+            ("02", "2026-03-02T10:00:00+00:00"),
+            ("03", "2026-03-03T10:00:00+00:00"),
+            ("01", "2026-04-01T10:00:00+00:00"),
+        ]);
+
+        let exported = desktop.export();
+        let ProgressImport::Ready(imported) = parse_progress_import(&exported) else {
+            panic!("export should round-trip");
+        };
+        let changed = laptop.import_merge(&imported);
+
+        assert_eq!(changed, 2);
+        assert_eq!(laptop.completed_count(), 3);
+        assert_eq!(laptop.completed["01"], "2026-03-01T10:00:00+00:00");
+        assert_eq!(laptop.completed["02"], "2026-03-02T10:00:00+00:00");
+        assert_eq!(laptop.completed["03"], "2026-03-03T10:00:00+00:00");
+
+        // Merging again is a no-op.
+        assert_eq!(laptop.import_merge(&imported), 0);
+    }
+
+    #[test]
+    fn import_falls_back_on_incompatible_schema() {
+        let mut future = progress_with(&[("01", "2026-03-01T10:00:00+00:00")]);
+        future.schema_version = "2.0.0".to_string();
+        assert!(matches!(
+            parse_progress_import(&future.export()),
+            ProgressImport::Fallback { .. }
+        ));
+        assert!(matches!(
+            parse_progress_import("not json"),
+            ProgressImport::Fallback { .. }
+        ));
+
+        let mut minor = future;
+        minor.schema_version = "1.4.0".to_string();
+        assert!(matches!(
+            parse_progress_import(&minor.export()),
+            ProgressImport::Ready(_)
+        ));
+    }
+
     #[test]
     fn verify_uses_fallback_for_zero_budget() {
         let tutorial = find_tutorial("01").expect("tutorial");
@@ -466,4 +768,67 @@ mod tests {
         assert_eq!(result.status, "degraded");
         assert!(result.fallback_active);
     }
+
+    #[cfg(unix)]
+    #[test]
+    fn verify_runs_checks_in_parallel_within_total_budget() {
+        use std::os::unix::fs::PermissionsExt;
+
+        static SLOW_ARGS: &[&[&str]] = &[&["first"], &["second"], &["third"]];
+        let dir = tempfile::tempdir().expect("tempdir");
+        let script = dir.path().join("slow-check");
+        std::fs::write(&script, "#!/bin/sh\nsleep 0.4\n").expect("write script");
+        std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).expect("chmod");
+
+        let tutorial = Tutorial {
+            verify_args: SLOW_ARGS,
+            ..tutorial("slow", &[])
+        };
+        // Serial sum is 1.2s; the parallel max is 0.4s.
+        let result = verify_tutorial(
+            &script,
+            &tutorial,
+            Duration::from_secs(2),
+            Duration::from_millis(900),
+        );
+
+        assert_eq!(result.status, "ok", "{:?}", result.checks);
+        assert!(!result.fallback_active);
+        let commands: Vec<&str> = result.checks.iter().map(|c| c.command.as_str()).collect();
+        assert_eq!(
+            commands,
+            vec!["pt-core first", "pt-core second", "pt-core third"]
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn verify_parallel_timeout_keeps_fallback() {
+        use std::os::unix::fs::PermissionsExt;
+
+        static SLOW_ARGS: &[&[&str]] = &[&["a"], &["b"]];
+        let dir = tempfile::tempdir().expect("tempdir");
+        let script = dir.path().join("hung-check");
+        std::fs::write(&script, "#!/bin/sh\nsleep 5\n").expect("write script");
+        std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).expect("chmod");
+
+        let tutorial = Tutorial {
+            verify_args: SLOW_ARGS,
+            ..tutorial("hung", &[])
+        };
+        let result = verify_tutorial(
+            &script,
+            &tutorial,
+            Duration::from_millis(100),
+            Duration::from_secs(2),
+        );
+
+        assert_eq!(result.status, "degraded");
+        assert!(result.fallback_active);
+        assert_eq!(
+            result.fallback_reason.as_deref(),
+            Some("per-check verification budget exhausted")
+        );
+        assert!(result.checks.iter().all(|c| c.status == "timeout"));
+    }
 }
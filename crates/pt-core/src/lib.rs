@@ -28,6 +28,7 @@ pub mod install;
 pub mod learn;
 pub mod logging;
 pub mod mcp;
+pub mod metrics;
 pub mod output;
 pub mod plan;
 pub mod plugin;
@@ -35,6 +36,7 @@ pub mod replay;
 pub mod schema;
 pub mod session;
 pub mod shadow;
+pub mod shutdown;
 pub mod signature_cli;
 pub mod supervision;
 pub mod verify;
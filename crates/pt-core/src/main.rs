@@ -11,9 +11,7 @@ use clap::parser::ValueSource;
 use clap::FromArgMatches;
 use clap::{Args, CommandFactory, Parser, Subcommand};
 use pt_common::{
-    normalize_lineage, CandidateProvenanceOutput, OutputFormat, OwnershipState,
-    ProvenanceConfidence, ProvenanceFeatureInput, ProvenanceRedactionState, RawLineageEvidence,
-    SessionId, SCHEMA_VERSION,
+    CandidateProvenanceOutput, NdjsonWriter, OutputFormat, ProcessId, SessionId, SCHEMA_VERSION,
 };
 #[cfg(feature = "ui")]
 use pt_common::{IdentityQuality, ProcessIdentity};
@@ -23,7 +21,8 @@ use pt_core::collect::protected::ProtectedFilter;
 #[cfg(target_os = "linux")]
 use pt_core::collect::{systemd::collect_systemd_unit, ContainerRuntime};
 use pt_core::config::{
-    get_preset, list_presets, load_config, ConfigError, ConfigOptions, PresetName, Priors,
+    get_preset, list_presets, load_config, loss_matrix_findings, resolve_config_traced,
+    ConfigError, ConfigOptions, PresetInfo, PresetName, Priors,
 };
 use pt_core::events::{
     FanoutEmitter, JsonlWriter, Phase, ProgressEmitter, ProgressEvent, SessionEmitter,
@@ -39,9 +38,10 @@ use pt_core::inference::galaxy_brain::{
 };
 use pt_core::learn::{
     clear_progress as clear_learn_progress, find_tutorial, load_progress as load_learn_progress,
-    mark_completed as mark_tutorial_completed, next_tutorial as next_learn_tutorial,
+    mark_completed as mark_tutorial_completed,
+    missing_prerequisites as missing_learn_prerequisites, next_tutorial as next_learn_tutorial,
     save_progress as save_learn_progress, tutorials as learn_tutorials,
-    verify_tutorial as verify_learn_tutorial,
+    validate_catalog as validate_learn_catalog, verify_tutorial as verify_learn_tutorial,
 };
 
 use pt_core::output::predictions::{
@@ -51,6 +51,7 @@ use pt_core::output::predictions::{
 use pt_core::output::{encode_toon_value, CompactConfig, FieldSelector, TokenEfficientOutput};
 #[cfg(feature = "ui")]
 use pt_core::plan::{generate_plan, DecisionBundle, DecisionCandidate};
+use pt_core::plugin::PluginManager;
 use pt_core::session::compare::generate_comparison_report;
 use pt_core::session::diff::{
     compute_diff, DeltaKind, DiffConfig, InferenceSummary, LifecycleTransition, ProcessDelta,
@@ -77,6 +78,7 @@ use pt_core::tui::widgets::ProcessRow;
 use pt_core::tui::{run_ftui, App, ExecutionOutcome};
 use pt_core::verify::{parse_agent_plan, verify_plan, VerifyError};
 use pt_telemetry::retention::{RetentionConfig, RetentionEnforcer, RetentionError};
+use pt_telemetry::schema::{TableName, TelemetrySchema};
 use pt_telemetry::shadow::{Observation, ShadowStorage, ShadowStorageConfig};
 use pt_telemetry::writer::default_telemetry_dir;
 #[cfg(feature = "daemon")]
@@ -116,6 +118,14 @@ struct GlobalOpts {
     #[arg(long, global = true, env = "PT_CONFIG_DIR")]
     config: Option<String>,
 
+    /// Expand ${VAR} references in config paths and config file values
+    #[arg(long, global = true, env = "PT_CONFIG_EXPAND_ENV")]
+    config_expand_env: bool,
+
+    /// Layer presets beneath the policy file, lowest first (e.g. server,ci)
+    #[arg(long, global = true, env = "PT_CONFIG_PRESET", value_delimiter = ',')]
+    config_preset: Vec<PresetName>,
+
     /// Output format
     #[arg(
         long,
@@ -290,7 +300,7 @@ impl GlobalOpts {
     }
 }
 
-/// Format structured output for JSON/TOON modes, preserving token-efficient options.
+/// Format structured output for JSON/TOON/YAML modes, preserving token-efficient options.
 fn format_structured_output(global: &GlobalOpts, value: serde_json::Value) -> String {
     match global.format {
         OutputFormat::Json => global.process_output(value),
@@ -298,6 +308,16 @@ fn format_structured_output(global: &GlobalOpts, value: serde_json::Value) -> St
             let processed = global.process_output_value(value);
             encode_toon_value(&processed)
         }
+        OutputFormat::Yaml => {
+            let processed = global.process_output_value(value);
+            match serde_yaml_ng::to_string(&processed) {
+                Ok(yaml) => yaml,
+                Err(err) => {
+                    eprintln!("yaml output: failed to serialize: {}", err);
+                    std::process::exit(ExitCode::InternalError.as_i32());
+                }
+            }
+        }
         _ => global.process_output(value),
     }
 }
@@ -351,6 +371,9 @@ enum Commands {
     /// Signature management (list, add, remove user signatures)
     Signature(pt_core::signature_cli::SignatureArgs),
 
+    /// Plugin management and diagnostics
+    Plugin(PluginArgs),
+
     /// Generate JSON schemas for agent output types
     Schema(SchemaArgs),
 
@@ -401,7 +424,7 @@ struct RunArgs {
     goal: Option<String>,
 
     /// TUI color theme (overrides environment detection)
-    #[arg(long, value_parser = ["dark", "light", "high-contrast", "no-color"])]
+    #[arg(long, value_parser = ["dark", "light", "high-contrast", "no-color", "deuteranopia"])]
     theme: Option<String>,
 
     /// Enable high-contrast mode (WCAG AAA). Shorthand for --theme=high-contrast.
@@ -417,6 +440,10 @@ struct RunArgs {
     /// Also activatable via PT_ACCESSIBLE env var.
     #[arg(long)]
     accessible: bool,
+
+    /// Stop executing a plan at the first failed action; the rest are skipped as aborted
+    #[arg(long)]
+    stop_on_error: bool,
 }
 
 #[derive(Args, Debug)]
@@ -444,9 +471,9 @@ struct ScanArgs {
 
 #[derive(Args, Debug)]
 struct DeepScanArgs {
-    /// Target specific PIDs only
-    #[arg(long, value_delimiter = ',')]
-    pids: Vec<u32>,
+    /// Target specific PIDs only (comma-separated, ranges allowed: 1000-1005,2000)
+    #[arg(long, value_parser = parse_pid_list)]
+    pids: Vec<PidList>,
 
     /// Maximum time budget for deep scan (seconds)
     #[arg(long)]
@@ -657,9 +684,23 @@ enum LearnCommands {
     Complete {
         /// Tutorial identifier
         topic: String,
+        /// Complete even if prerequisite tutorials are not yet completed
+        #[arg(long)]
+        force: bool,
     },
     /// Reset all tutorial progress
     Reset,
+    /// Export tutorial progress for another machine
+    Export {
+        /// Write the export to this file instead of stdout
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// Merge tutorial progress exported on another machine
+    Import {
+        /// Path to a file produced by `pt learn export`
+        path: PathBuf,
+    },
 }
 
 #[derive(Args, Debug)]
@@ -1103,9 +1144,9 @@ struct AgentExplainArgs {
     #[arg(long)]
     session: String,
 
-    /// PIDs to explain
-    #[arg(long, value_delimiter = ',')]
-    pids: Vec<u32>,
+    /// PIDs to explain (comma-separated, ranges allowed: 1000-1005,2000)
+    #[arg(long, value_parser = parse_pid_list)]
+    pids: Vec<PidList>,
 
     /// Target process with stable identity (format: pid:start_id)
     #[arg(long)]
@@ -1134,11 +1175,17 @@ struct AgentExplainArgs {
     /// Show what-if hypotheticals
     #[arg(long)]
     what_if: bool,
+
+    /// Run the probes VOI recommends and report the updated decision
+    #[arg(long)]
+    probe: bool,
 }
 
+use pt_core::action::{ActionAuditRecord, ActionStatus, AuditSink, ParquetAuditSink};
 #[cfg(target_os = "linux")]
 use pt_core::action::{
-    ActionRunner, IdentityProvider, LiveIdentityProvider, SignalActionRunner, SignalConfig,
+    ActionRunner, ExecutionMode, IdentityProvider, LiveIdentityProvider, SafetyGovernor,
+    SignalActionRunner, SignalConfig, ABORTED_REASON,
 };
 use pt_core::decision::{
     goal_optimizer::{
@@ -1151,13 +1198,13 @@ use pt_core::plan::{Plan, PlanAction};
 
 #[derive(Args, Debug)]
 struct AgentApplyArgs {
-    /// Session ID (required)
-    #[arg(long)]
+    /// Session ID (required; defaults to $PT_SESSION_ID)
+    #[arg(long, env = pt_common::SESSION_ID_ENV)]
     session: String,
 
-    /// PIDs to act on (default: all recommended)
-    #[arg(long, value_delimiter = ',')]
-    pids: Vec<u32>,
+    /// PIDs to act on (default: all recommended; ranges allowed: 1000-1005,2000)
+    #[arg(long, value_parser = parse_pid_list)]
+    pids: Vec<PidList>,
 
     /// Specific targets with identity (pid:start_id)
     #[arg(long, value_delimiter = ',')]
@@ -1203,7 +1250,7 @@ struct AgentApplyArgs {
     #[arg(long, value_delimiter = ',')]
     exclude_categories: Vec<String>,
 
-    /// Abort if unknown error/condition
+    /// Stop at the first failed or blocked action; the rest are reported as aborted
     #[arg(long)]
     abort_on_unknown: bool,
 
@@ -1217,6 +1264,8 @@ fn config_options(global: &GlobalOpts) -> ConfigOptions {
         config_dir: global.config.as_ref().map(PathBuf::from),
         priors_path: None,
         policy_path: None,
+        expand_env: global.config_expand_env,
+        presets: global.config_preset.clone(),
     }
 }
 
@@ -1256,6 +1305,44 @@ impl FocusMode {
     }
 }
 
+/// One `--pids` value, e.g. `1000-1005,2000`, expanded by [`ProcessId::parse_list`].
+#[derive(Debug, Clone)]
+struct PidList(Vec<u32>);
+
+fn parse_pid_list(value: &str) -> Result<PidList, String> {
+    ProcessId::parse_list(value)
+        .map(|pids| PidList(pids.into_iter().map(|pid| pid.0).collect()))
+        .map_err(|e| e.to_string())
+}
+
+/// Merge repeated `--pids` flags in order, dropping PIDs already listed.
+fn flatten_pid_lists(lists: &[PidList]) -> Vec<u32> {
+    let mut seen = HashSet::new();
+    lists
+        .iter()
+        .flat_map(|list| list.0.iter().copied())
+        .filter(|pid| seen.insert(*pid))
+        .collect()
+}
+
+#[cfg(test)]
+mod pid_list_tests {
+    use super::*;
+
+    #[test]
+    fn expands_ranges_and_merges_repeated_flags() {
+        let lists = vec![
+            parse_pid_list("1000-1002,2000").unwrap(),
+            parse_pid_list("2000,1001,3000").unwrap(),
+        ];
+        assert_eq!(
+            flatten_pid_lists(&lists),
+            vec![1000, 1001, 1002, 2000, 3000]
+        );
+        assert!(parse_pid_list("5-1").unwrap_err().contains("\"5-1\""));
+    }
+}
+
 fn parse_focus_mode(value: &str) -> Result<FocusMode, String> {
     match value.to_lowercase().as_str() {
         "all" => Ok(FocusMode::All),
@@ -1473,6 +1560,8 @@ enum ConfigCommands {
         #[arg(long)]
         file: Option<String>,
     },
+    /// Explain which layer (CLI, env, XDG, defaults) supplied each config value
+    Explain,
     /// Print JSON schema for configuration files
     Schema {
         /// Schema to print (priors or policy)
@@ -1577,6 +1666,30 @@ enum TelemetryCommands {
         #[arg(long)]
         all: bool,
     },
+    /// Print the JSON Schema of the telemetry tables
+    Schema {
+        /// Only this table (runs, proc_samples, proc_features, proc_inference,
+        /// outcomes, audit, signature_matches)
+        #[arg(long)]
+        table: Option<String>,
+    },
+}
+
+#[derive(Args, Debug)]
+struct PluginArgs {
+    #[command(subcommand)]
+    command: PluginCommands,
+}
+
+#[derive(Subcommand, Debug)]
+enum PluginCommands {
+    /// Probe every installed plugin and report whether it runs and parses
+    Doctor,
+    /// Validate a plugin directory (manifest, API version, executable, one dry run)
+    Check {
+        /// Plugin directory containing plugin.toml
+        dir: PathBuf,
+    },
 }
 
 #[derive(Args, Debug)]
@@ -1647,6 +1760,11 @@ struct ShadowStartArgs {
     /// Limit inference to a random sample of N processes
     #[arg(long)]
     sample_size: Option<usize>,
+
+    /// Serve Prometheus metrics on 127.0.0.1:PORT while running
+    #[cfg(feature = "metrics")]
+    #[arg(long)]
+    metrics_port: Option<u16>,
 }
 
 #[derive(Args, Debug)]
@@ -1681,7 +1799,8 @@ struct ShadowReportArgs {
 
 #[derive(Args, Debug)]
 struct SchemaArgs {
-    /// Type name to generate schema for (e.g., Plan, DecisionOutcome)
+    /// Type name to generate schema for (e.g., Plan, DecisionOutcome), or
+    /// `dump` for the versioned schemas of every public output type
     #[arg(value_name = "TYPE")]
     type_name: Option<String>,
 
@@ -1703,6 +1822,10 @@ struct McpArgs {
     /// Transport: stdio (default) for standard MCP integration
     #[arg(long, default_value = "stdio")]
     transport: String,
+
+    /// Minimum seconds between resource update notifications to subscribers
+    #[arg(long, default_value_t = 5)]
+    update_interval: u64,
 }
 
 #[derive(Args, Debug)]
@@ -1758,7 +1881,7 @@ use pt_core::logging::{
 // Main entry point
 // ============================================================================
 
-fn main() {
+fn main() -> std::process::ExitCode {
     let matches = Cli::command().get_matches();
     let mut cli = Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
     let format_source = matches.value_source("format");
@@ -1782,7 +1905,7 @@ fn main() {
     // But wait, if I'm an agent parsing JSON stdout, I might want JSONL stderr too.
     let log_format = if matches!(
         cli.global.format,
-        OutputFormat::Json | OutputFormat::Jsonl | OutputFormat::Toon
+        OutputFormat::Json | OutputFormat::Jsonl | OutputFormat::Ndjson | OutputFormat::Toon
     ) {
         LogFormat::Jsonl
     } else {
@@ -1813,6 +1936,7 @@ fn main() {
                     high_contrast: false,
                     reduce_motion: false,
                     accessible: false,
+                    stop_on_error: false,
                 },
             )
         }
@@ -1834,6 +1958,7 @@ fn main() {
         Some(Commands::Signature(args)) => {
             pt_core::signature_cli::run_signature(&cli.global.format, &args)
         }
+        Some(Commands::Plugin(args)) => run_plugin(&cli.global, &args),
         Some(Commands::Schema(args)) => run_schema(&cli.global, &args),
         Some(Commands::Mcp(args)) => run_mcp(&args),
         Some(Commands::Update(args)) => run_update(&cli.global, &args),
@@ -1852,7 +1977,7 @@ fn main() {
         }
     };
 
-    std::process::exit(exit_code.as_i32());
+    exit_code.into()
 }
 
 fn resolve_output_format(current: OutputFormat, source: Option<ValueSource>) -> OutputFormat {
@@ -1874,6 +1999,7 @@ fn parse_output_format(value: &str) -> Option<OutputFormat> {
     match normalized.as_str() {
         "json" => Some(OutputFormat::Json),
         "toon" => Some(OutputFormat::Toon),
+        "yaml" | "yml" => Some(OutputFormat::Yaml),
         "md" | "markdown" => Some(OutputFormat::Md),
         "jsonl" | "json-lines" | "lines" => Some(OutputFormat::Jsonl),
         "summary" | "brief" => Some(OutputFormat::Summary),
@@ -1881,6 +2007,9 @@ fn parse_output_format(value: &str) -> Option<OutputFormat> {
         "slack" => Some(OutputFormat::Slack),
         "exitcode" | "exit-code" => Some(OutputFormat::Exitcode),
         "prose" | "narrative" => Some(OutputFormat::Prose),
+        "html" => Some(OutputFormat::Html),
+        "csv" => Some(OutputFormat::Csv),
+        "ndjson" => Some(OutputFormat::Ndjson),
         _ => None,
     }
 }
@@ -1894,6 +2023,8 @@ mod output_format_tests {
     fn parse_output_format_supports_all_canonical_variants() {
         assert_eq!(parse_output_format("json"), Some(OutputFormat::Json));
         assert_eq!(parse_output_format("toon"), Some(OutputFormat::Toon));
+        assert_eq!(parse_output_format("yaml"), Some(OutputFormat::Yaml));
+        assert_eq!(parse_output_format("yml"), Some(OutputFormat::Yaml));
         assert_eq!(parse_output_format("md"), Some(OutputFormat::Md));
         assert_eq!(parse_output_format("jsonl"), Some(OutputFormat::Jsonl));
         assert_eq!(parse_output_format("summary"), Some(OutputFormat::Summary));
@@ -1904,6 +2035,9 @@ mod output_format_tests {
             Some(OutputFormat::Exitcode)
         );
         assert_eq!(parse_output_format("prose"), Some(OutputFormat::Prose));
+        assert_eq!(parse_output_format("html"), Some(OutputFormat::Html));
+        assert_eq!(parse_output_format("CSV"), Some(OutputFormat::Csv));
+        assert_eq!(parse_output_format("ndjson"), Some(OutputFormat::Ndjson));
     }
 
     #[test]
@@ -1926,7 +2060,7 @@ mod output_format_tests {
     #[test]
     fn parse_output_format_rejects_unknown_values() {
         assert_eq!(parse_output_format("compact"), None);
-        assert_eq!(parse_output_format("csv"), None);
+        assert_eq!(parse_output_format("tsv"), None);
         assert_eq!(parse_output_format(""), None);
     }
 }
@@ -1984,10 +2118,7 @@ fn run_interactive_tui(global: &GlobalOpts, args: &RunArgs) -> Result<(), String
 
     let _ = handle.update_state(SessionState::Scanning);
 
-    let config_options = ConfigOptions {
-        config_dir: global.config.as_ref().map(PathBuf::from),
-        ..Default::default()
-    };
+    let config_options = config_options(global);
     let config = load_config(&config_options).map_err(|e| format!("load config: {}", e))?;
     let priors = config.priors.clone();
     let policy = config.policy.clone();
@@ -2014,6 +2145,7 @@ fn run_interactive_tui(global: &GlobalOpts, args: &RunArgs) -> Result<(), String
                 "light" => TuiTheme::light(),
                 "high-contrast" => TuiTheme::high_contrast(),
                 "no-color" => TuiTheme::no_color(),
+                "deuteranopia" => TuiTheme::deuteranopia(),
                 _ => TuiTheme::dark(),
             };
         } else if args.high_contrast {
@@ -2105,6 +2237,11 @@ fn run_interactive_tui(global: &GlobalOpts, args: &RunArgs) -> Result<(), String
         let handle_e = handle.clone();
         let dry_run = global.dry_run;
         let shadow = global.shadow;
+        let execution_mode = if args.stop_on_error {
+            pt_core::action::ExecutionMode::StopOnFirstError
+        } else {
+            pt_core::action::ExecutionMode::ContinueOnError
+        };
 
         let execute_fn: Arc<dyn Fn(Vec<u32>) -> Result<ExecutionOutcome, String> + Send + Sync> =
             Arc::new(move |selected: Vec<u32>| {
@@ -2130,11 +2267,12 @@ fn run_interactive_tui(global: &GlobalOpts, args: &RunArgs) -> Result<(), String
                         attempted: plan.actions.len(),
                         succeeded: 0,
                         failed: 0,
+                        aborted: false,
                     });
                 }
 
                 let _ = handle_e.update_state(SessionState::Executing);
-                match execute_plan_actions(&handle_e, &policy_e, &plan) {
+                match execute_plan_actions(&handle_e, &policy_e, &plan, execution_mode) {
                     Ok(result) => {
                         write_outcomes_from_execution(&handle_e, &plan, &result)
                             .map_err(|e| format!("write outcomes: {}", e))?;
@@ -2149,6 +2287,7 @@ fn run_interactive_tui(global: &GlobalOpts, args: &RunArgs) -> Result<(), String
                             attempted: result.summary.actions_attempted,
                             succeeded: result.summary.actions_succeeded,
                             failed: result.summary.actions_failed,
+                            aborted: result.summary.aborted,
                         })
                     }
                     Err(e) => {
@@ -2302,12 +2441,13 @@ fn execute_plan_actions(
     handle: &SessionHandle,
     policy: &pt_core::config::Policy,
     plan: &Plan,
+    mode: pt_core::action::ExecutionMode,
 ) -> Result<pt_core::action::ExecutionResult, String> {
     #[cfg(target_os = "linux")]
     {
         use pt_core::action::{
             ActionExecutor, CompositeActionRunner, LiveIdentityProvider, LivePreCheckConfig,
-            LivePreCheckProvider,
+            LivePreCheckProvider, ParquetAuditSink, SafetyGovernor,
         };
         let action_dir = handle.dir.join("action");
         std::fs::create_dir_all(&action_dir).map_err(|e| format!("create action dir: {}", e))?;
@@ -2318,17 +2458,31 @@ fn execute_plan_actions(
             LivePreCheckProvider::new(Some(&policy.guardrails), LivePreCheckConfig::default())
                 .unwrap_or_else(|_| LivePreCheckProvider::with_defaults());
 
+        let audit_sink = ParquetAuditSink::new(pt_telemetry::WriterConfig::new(
+            default_telemetry_dir(),
+            handle.id.0.clone(),
+            pt_core::logging::get_host_id(),
+        ));
+
         let executor = ActionExecutor::new(&runner, &identity_provider, lock_path)
-            .with_pre_check_provider(&pre_checks);
-        executor
+            .with_pre_check_provider(&pre_checks)
+            .with_governor(SafetyGovernor::from_guardrails(&policy.guardrails))
+            .with_audit_sink(&audit_sink)
+            .with_mode(mode);
+        let result = executor
             .execute_plan(plan)
-            .map_err(|e| format!("execute plan: {}", e))
+            .map_err(|e| format!("execute plan: {}", e));
+        if let Err(e) = audit_sink.close() {
+            tracing::warn!(error = %e, "failed to finalize action audit log");
+        }
+        result
     }
     #[cfg(not(target_os = "linux"))]
     {
         let _ = policy;
         let _ = handle;
         let _ = plan;
+        let _ = mode;
         Err("execution not supported on this platform".to_string())
     }
 }
@@ -2422,6 +2576,7 @@ fn action_status_label(status: &pt_core::action::ActionStatus) -> &'static str {
         ActionStatus::Timeout => "timeout",
         ActionStatus::Failed => "failed",
         ActionStatus::Skipped => "skipped",
+        ActionStatus::AlreadyDead => "already_dead",
         ActionStatus::PreCheckBlocked { .. } => "precheck_blocked",
     }
 }
@@ -2450,6 +2605,7 @@ struct DeepSignals {
     queue_mu: Option<f64>,
     queue_stall_probability: Option<f64>,
     queue_backlog_sockets: Option<usize>,
+    kernel_wait: Option<pt_core::collect::KernelWaitState>,
 }
 
 #[cfg(feature = "ui")]
@@ -2469,6 +2625,7 @@ fn compute_probe_advice(
     let mut advice = HashMap::new();
     let cost_model = pt_core::decision::ProbeCostModel::default();
     let available_probes = [pt_core::decision::ProbeType::DeepScan];
+    let availability = pt_core::decision::ProbeAvailability::from_capabilities(&get_capabilities());
 
     for proc in processes {
         let evidence = Evidence {
@@ -2488,12 +2645,13 @@ fn compute_probe_advice(
         let Ok(posterior) = compute_posterior(priors, &evidence) else {
             continue;
         };
-        let Ok((decision, _)) = pt_core::decision::decide_sequential(
+        let Ok((decision, _)) = pt_core::decision::decide_sequential_with_availability(
             &posterior.posterior,
             policy,
             &ActionFeasibility::allow_all(),
             &cost_model,
             Some(&available_probes),
+            &availability,
         ) else {
             continue;
         };
@@ -2535,7 +2693,7 @@ fn deep_scan_target_pids(
 fn collect_deep_signals_for_pids(pids: &[u32]) -> Option<HashMap<u32, DeepSignals>> {
     #[cfg(target_os = "linux")]
     {
-        use pt_core::collect::{deep_scan, DeepScanOptions};
+        use pt_core::collect::{collect_kernel_wait, deep_scan, DeepScanOptions};
 
         if pids.is_empty() {
             return None;
@@ -2576,6 +2734,10 @@ fn collect_deep_signals_for_pids(pids: &[u32]) -> Option<HashMap<u32, DeepSignal
                 .network
                 .as_ref()
                 .map(|info| estimate_queue_metrics(info, io_active.unwrap_or(false)));
+            let kernel_wait = collect_kernel_wait(record.pid.0, record.state);
+            for warning in &kernel_wait.warnings {
+                tracing::debug!(pid = record.pid.0, warning = %warning, "kernel wait sample");
+            }
 
             map.insert(
                 record.pid.0,
@@ -2587,6 +2749,7 @@ fn collect_deep_signals_for_pids(pids: &[u32]) -> Option<HashMap<u32, DeepSignal
                     queue_mu: queue_metrics.map(|metrics| metrics.mu),
                     queue_stall_probability: queue_metrics.map(|metrics| metrics.stall_probability),
                     queue_backlog_sockets: queue_metrics.map(|metrics| metrics.backlog_sockets),
+                    kernel_wait: Some(kernel_wait.state),
                 },
             );
         }
@@ -2695,6 +2858,10 @@ fn build_tui_rows(
     } else {
         policy.clone()
     };
+    let protection_rules = ProtectionRules::new(&policy.protection_list).unwrap_or_else(|err| {
+        tracing::warn!(error = %err, "Ignoring invalid policy protection_list");
+        ProtectionRules::default()
+    });
 
     let feasibility = ActionFeasibility::allow_all();
     let mut rows = Vec::new();
@@ -2712,6 +2879,7 @@ fn build_tui_rows(
             }
         }
 
+        let protection = protection_rules.check(&proc.comm);
         let deep = deep_signals.and_then(|m| m.get(&proc.pid.0).copied());
         let probe = probe_advice.and_then(|m| m.get(&proc.pid.0));
         let evidence = Evidence {
@@ -2728,21 +2896,36 @@ fn build_tui_rows(
             command_category: None,
         };
 
-        let posterior_result = match compute_posterior(priors, &evidence) {
+        let mut posterior_result = match compute_posterior(priors, &evidence) {
             Ok(r) => r,
             Err(_) => continue,
         };
-        let mut decision_outcome =
-            match decide_action(&posterior_result.posterior, &decision_policy, &feasibility) {
-                Ok(d) => d,
-                Err(_) => continue,
-            };
+        if let Some(term) = deep
+            .and_then(|d| d.kernel_wait)
+            .and_then(|state| kernel_wait_state_term(state, &KernelWaitConfig::default()))
+        {
+            if let Ok(adjusted) = apply_evidence_terms(&posterior_result, [term]) {
+                posterior_result = adjusted;
+            }
+        }
+        let mut decision_outcome = match decide_with_protection(
+            protection.as_ref(),
+            &posterior_result.posterior,
+            &decision_policy,
+            &feasibility,
+        ) {
+            Ok(d) => d,
+            Err(_) => continue,
+        };
 
         // Populate rationale fields available in current context
         decision_outcome.rationale.memory_mb = Some(proc.rss_bytes as f64 / (1024.0 * 1024.0));
         let mut ledger =
             EvidenceLedger::from_posterior_result(&posterior_result, Some(proc.pid.0), None);
-        if let Some(probe) = probe {
+        if let Some(verdict) = &protection {
+            ledger.top_evidence.insert(0, verdict.describe());
+        }
+        if let Some(probe) = probe.filter(|_| protection.is_none()) {
             if probe.should_probe
                 && probe.recommended_probe == Some(pt_core::decision::ProbeType::DeepScan)
             {
@@ -2779,7 +2962,8 @@ fn build_tui_rows(
             .max(posterior_result.posterior.useful_bad)
             .max(posterior_result.posterior.abandoned)
             .max(posterior_result.posterior.zombie);
-        if max_posterior < MIN_POSTERIOR {
+        let flagged = protection.as_ref().is_some_and(|v| !v.is_protected());
+        if max_posterior < MIN_POSTERIOR && !flagged {
             continue;
         }
 
@@ -2790,6 +2974,11 @@ fn build_tui_rows(
         };
 
         let score = (max_posterior * 100.0).round() as u32;
+        let expected_loss = decision_outcome
+            .expected_loss
+            .iter()
+            .find(|entry| entry.action == decision_outcome.optimal_action)
+            .map(|entry| entry.loss);
         let runtime = format_duration_human(proc.elapsed.as_secs());
         let memory = format_memory_human(proc.rss_bytes);
         let galaxy_brain = render_galaxy_brain(
@@ -2823,6 +3012,7 @@ fn build_tui_rows(
         rows.push(ProcessRow {
             pid: proc.pid.0,
             score,
+            expected_loss,
             classification: classification.to_string(),
             runtime,
             memory,
@@ -2831,6 +3021,7 @@ fn build_tui_rows(
             galaxy_brain: Some(galaxy_brain),
             why_summary: Some(ledger.why_summary.clone()),
             top_evidence: ledger.top_evidence.clone(),
+            contributions: posterior_result.contributions(),
             confidence: Some(ledger.confidence.label().to_string()),
             plan_preview: Vec::new(),
             provenance_headline: None,
@@ -2979,339 +3170,23 @@ fn build_tui_rows(
 }
 
 #[cfg(target_os = "linux")]
-use pt_core::collect::{
-    collect_fd_ipc_resources, collect_lineage_for_pid, collect_listener_resources,
-    collect_local_resource_evidence, detect_listener_conflicts, parse_fd, parse_proc_net_tcp,
-    parse_proc_net_udp, NetworkSnapshot, SharedResourceGraph,
-};
+use pt_core::collect::{parse_fd, parse_proc_net_tcp, parse_proc_net_udp, NetworkSnapshot};
 use pt_core::collect::{quick_scan, ProcessRecord, QuickScanOptions, ScanResult};
 use pt_core::decision::goal_progress::{
     self, ActionOutcome as GoalActionOutcome, GoalMetric, GoalProgressReport, MetricSnapshot,
     ProgressConfig,
 };
 use pt_core::decision::{
-    apply_load_to_loss_matrix, compute_load_adjustment, decide_action, estimate_blast_radius,
-    Action, ActionFeasibility, BlastRadiusEstimate, BlastRadiusEstimatorConfig, LoadSignals,
-    RiskLevel,
+    apply_load_to_loss_matrix, compute_load_adjustment, decide_action, decide_with_protection,
+    Action, ActionFeasibility, LoadSignals, ProtectionRules,
 };
+#[cfg(feature = "ui")]
+use pt_core::inference::{apply_evidence_terms, kernel_wait_state_term, KernelWaitConfig};
 use pt_core::inference::{
-    apply_evidence_terms, compute_posterior, compute_posterior_with_overrides,
-    try_signature_fast_path, ClassScores, Confidence, CpuEvidence, Evidence, EvidenceLedger,
-    EvidenceTerm, FastPathConfig, FastPathSkipReason, PriorContext,
+    compute_posterior, match_level_label, process_evidence, score_process, state_to_flag,
+    CpuEvidence, Evidence, EvidenceLedger, FastPathConfig, FastPathSkipReason, ScoringContext,
 };
-use pt_core::supervision::signature::{MatchLevel, ProcessMatchContext, SignatureDatabase};
-
-#[cfg(target_os = "linux")]
-#[derive(Debug, Clone)]
-struct ProvenanceInferenceBundle {
-    resource_graph: SharedResourceGraph,
-    lineages: HashMap<u32, RawLineageEvidence>,
-    children: HashMap<u32, Vec<u32>>,
-}
-
-#[cfg(target_os = "linux")]
-#[derive(Debug, Clone)]
-struct ProvenanceScoreAdjustment {
-    evidence_terms: Vec<EvidenceTerm>,
-    evidence_completeness: f64,
-    confidence_penalty_steps: usize,
-    confidence_notes: Vec<String>,
-    blast_radius: BlastRadiusEstimate,
-}
-
-#[cfg(target_os = "linux")]
-impl ProvenanceScoreAdjustment {
-    /// Convert to the stable output contract type for JSON/TOON/agent consumers.
-    ///
-    /// Delegates to `CandidateProvenanceOutput::from_parts` so the contract
-    /// logic (direction thresholds, score-impact construction) lives in one
-    /// place in pt-common rather than being duplicated here.
-    fn to_candidate_output(&self) -> CandidateProvenanceOutput {
-        let feature_inputs: Vec<ProvenanceFeatureInput> = self
-            .evidence_terms
-            .iter()
-            .map(|term| ProvenanceFeatureInput {
-                feature: term.feature.clone(),
-                abandoned_ll: term.log_likelihood.abandoned,
-                useful_ll: term.log_likelihood.useful,
-            })
-            .collect();
-
-        CandidateProvenanceOutput::from_parts(
-            self.evidence_completeness,
-            self.confidence_penalty_steps,
-            self.confidence_notes.clone(),
-            &feature_inputs,
-            self.blast_radius.risk_score,
-            &format!("{:?}", self.blast_radius.risk_level).to_lowercase(),
-            self.blast_radius.confidence,
-            &self.blast_radius.summary,
-            self.blast_radius.total_affected,
-            ProvenanceRedactionState::None,
-        )
-    }
-}
-
-#[cfg(target_os = "linux")]
-fn build_provenance_inference_bundle(processes: &[&ProcessRecord]) -> ProvenanceInferenceBundle {
-    let mut lineages = HashMap::new();
-    let mut children: HashMap<u32, Vec<u32>> = HashMap::new();
-    let mut per_process_resources: Vec<(u32, Vec<pt_common::RawResourceEvidence>)> = Vec::new();
-    let mut all_resources = Vec::new();
-
-    for proc in processes {
-        let pid = proc.pid.0;
-        children.entry(proc.ppid.0).or_default().push(pid);
-        lineages.insert(pid, collect_lineage_for_pid(pid));
-
-        let fd_info = parse_fd(pid);
-        let mut resources = collect_local_resource_evidence(pid, fd_info.as_ref());
-        resources.extend(collect_listener_resources(pid));
-        resources.extend(collect_fd_ipc_resources(pid));
-        all_resources.extend(resources.iter().cloned());
-        per_process_resources.push((pid, resources));
-    }
-
-    for conflict in detect_listener_conflicts(&all_resources) {
-        if let Some((_, resources)) = per_process_resources
-            .iter_mut()
-            .find(|(pid, _)| *pid == conflict.owner_pid)
-        {
-            if let Some(existing) = resources
-                .iter_mut()
-                .find(|resource| resource.key == conflict.key && resource.kind == conflict.kind)
-            {
-                existing.state = conflict.state;
-                existing.observed_at = conflict.observed_at.clone();
-            } else {
-                resources.push(conflict);
-            }
-        }
-    }
-
-    let resource_graph = SharedResourceGraph::from_evidence(&per_process_resources);
-
-    ProvenanceInferenceBundle {
-        resource_graph,
-        lineages,
-        children,
-    }
-}
-
-#[cfg(target_os = "linux")]
-fn provenance_confidence_score(confidence: ProvenanceConfidence) -> f64 {
-    match confidence {
-        ProvenanceConfidence::High => 1.0,
-        ProvenanceConfidence::Medium => 0.75,
-        ProvenanceConfidence::Low => 0.45,
-        ProvenanceConfidence::Unknown => 0.2,
-    }
-}
-
-#[cfg(target_os = "linux")]
-fn downgrade_confidence(confidence: Confidence, steps: usize) -> Confidence {
-    let mut downgraded = confidence;
-    for _ in 0..steps {
-        downgraded = match downgraded {
-            Confidence::VeryHigh => Confidence::High,
-            Confidence::High => Confidence::Medium,
-            Confidence::Medium | Confidence::Low => Confidence::Low,
-        };
-    }
-    downgraded
-}
-
-#[cfg(target_os = "linux")]
-fn provenance_term(
-    feature: &str,
-    useful: f64,
-    useful_bad: f64,
-    abandoned: f64,
-    zombie: f64,
-) -> EvidenceTerm {
-    EvidenceTerm {
-        feature: feature.to_string(),
-        log_likelihood: ClassScores {
-            useful,
-            useful_bad,
-            abandoned,
-            zombie,
-        },
-    }
-}
-
-#[cfg(target_os = "linux")]
-fn derive_provenance_adjustment(
-    pid: u32,
-    bundle: &ProvenanceInferenceBundle,
-) -> ProvenanceScoreAdjustment {
-    let lineage = bundle.lineages.get(&pid);
-    let normalized_lineage = lineage.map(normalize_lineage);
-    let child_pids = bundle
-        .children
-        .get(&pid)
-        .map(|children| children.as_slice())
-        .unwrap_or(&[]);
-
-    let mut resolved_resources = 0usize;
-    let mut unresolved_resources = 0usize;
-    let mut conflict_resources = 0usize;
-
-    if let Some(keys) = bundle.resource_graph.process_resources.get(&pid) {
-        for key in keys {
-            let Some(resource) = bundle.resource_graph.resources.get(key) else {
-                continue;
-            };
-            let Some(holder_state) = resource.holder_states.iter().find(|state| state.pid == pid)
-            else {
-                continue;
-            };
-            match holder_state.state {
-                pt_common::ResourceState::Active | pt_common::ResourceState::Stale => {
-                    resolved_resources += 1;
-                }
-                pt_common::ResourceState::Partial | pt_common::ResourceState::Missing => {
-                    unresolved_resources += 1;
-                }
-                pt_common::ResourceState::Conflicted => {
-                    unresolved_resources += 1;
-                    conflict_resources += 1;
-                }
-            }
-        }
-    }
-
-    let lineage_confidence = normalized_lineage
-        .as_ref()
-        .map(|lineage| lineage.confidence)
-        .unwrap_or(ProvenanceConfidence::Unknown);
-    let lineage_score = provenance_confidence_score(lineage_confidence);
-    let resource_score = if resolved_resources + unresolved_resources == 0 {
-        0.7
-    } else {
-        resolved_resources as f64 / (resolved_resources + unresolved_resources) as f64
-    };
-    let mut evidence_completeness = ((lineage_score + resource_score) / 2.0).clamp(0.0, 1.0);
-
-    let mut confidence_notes = Vec::new();
-    let mut confidence_penalty_steps = 0usize;
-
-    if lineage.is_none() {
-        confidence_notes.push("missing lineage provenance".to_string());
-        confidence_penalty_steps += 1;
-    }
-    if unresolved_resources > 0 {
-        confidence_notes.push(format!(
-            "resource provenance has {unresolved_resources} unresolved edge(s)"
-        ));
-        confidence_penalty_steps += 1;
-    }
-    if let Some(normalized_lineage) = normalized_lineage.as_ref() {
-        if !normalized_lineage.downgrade_reasons.is_empty() {
-            confidence_notes.extend(normalized_lineage.downgrade_reasons.iter().cloned());
-            confidence_penalty_steps += 1;
-        }
-    }
-    if conflict_resources > 0 {
-        confidence_notes.push(format!(
-            "resource provenance has {conflict_resources} conflicting edge(s)"
-        ));
-    }
-
-    if !confidence_notes.is_empty() {
-        evidence_completeness = (evidence_completeness - 0.1).clamp(0.0, 1.0);
-    }
-
-    let blast_radius = estimate_blast_radius(
-        pid,
-        &bundle.resource_graph,
-        lineage,
-        child_pids,
-        evidence_completeness,
-        &BlastRadiusEstimatorConfig::default(),
-    );
-
-    if blast_radius.confidence < 0.5 {
-        confidence_notes.push("blast-radius estimate is low-confidence".to_string());
-        confidence_penalty_steps += 1;
-    }
-
-    let mut evidence_terms = Vec::new();
-    if let Some(normalized_lineage) = normalized_lineage.as_ref() {
-        match &normalized_lineage.ownership {
-            OwnershipState::Orphaned => {
-                evidence_terms.push(provenance_term(
-                    "provenance_ownership_orphaned",
-                    -0.55,
-                    -0.10,
-                    0.70,
-                    0.20,
-                ));
-            }
-            OwnershipState::Supervised { .. }
-            | OwnershipState::InitChild
-            | OwnershipState::AgentOwned { .. } => {
-                evidence_terms.push(provenance_term(
-                    "provenance_ownership_supervised",
-                    0.60,
-                    0.20,
-                    -0.70,
-                    -0.20,
-                ));
-            }
-            OwnershipState::ShellOwned { .. } => {
-                evidence_terms.push(provenance_term(
-                    "provenance_ownership_shell",
-                    0.35,
-                    0.15,
-                    -0.35,
-                    -0.10,
-                ));
-            }
-            OwnershipState::Unknown => {}
-        }
-    }
-
-    if blast_radius.direct.components.listener_count > 0 {
-        evidence_terms.push(provenance_term(
-            "provenance_active_listener",
-            0.55,
-            0.20,
-            -0.60,
-            -0.15,
-        ));
-    }
-
-    match blast_radius.risk_level {
-        RiskLevel::High | RiskLevel::Critical => {
-            evidence_terms.push(provenance_term(
-                "provenance_blast_radius_high",
-                0.65,
-                0.30,
-                -0.75,
-                -0.20,
-            ));
-        }
-        RiskLevel::Low if blast_radius.total_affected == 0 => {
-            evidence_terms.push(provenance_term(
-                "provenance_blast_radius_low",
-                -0.25,
-                -0.05,
-                0.35,
-                0.10,
-            ));
-        }
-        RiskLevel::Medium | RiskLevel::Low => {}
-    }
-
-    ProvenanceScoreAdjustment {
-        evidence_terms,
-        evidence_completeness,
-        confidence_penalty_steps: confidence_penalty_steps.min(3),
-        confidence_notes,
-        blast_radius,
-    }
-}
+use pt_core::supervision::signature::SignatureDatabase;
 
 fn progress_emitter(global: &GlobalOpts) -> Option<Arc<dyn ProgressEmitter>> {
     match global.format {
@@ -3475,7 +3350,7 @@ fn run_scan(global: &GlobalOpts, args: &ScanArgs) -> ExitCode {
             };
 
             match global.format {
-                OutputFormat::Json | OutputFormat::Toon => {
+                OutputFormat::Json | OutputFormat::Toon | OutputFormat::Yaml => {
                     // Enrich with schema version and session ID
                     let session_id = SessionId::new();
                     let mut output = serde_json::json!({
@@ -3490,6 +3365,29 @@ fn run_scan(global: &GlobalOpts, args: &ScanArgs) -> ExitCode {
                     // Apply token-efficient processing if options specified
                     println!("{}", format_structured_output(global, output));
                 }
+                OutputFormat::Ndjson => {
+                    let mut writer = NdjsonWriter::new(std::io::stdout().lock());
+                    let written = writer
+                        .write_metadata(
+                            SCHEMA_VERSION,
+                            serde_json::json!({
+                                "session_id": SessionId::new().0,
+                                "generated_at": chrono::Utc::now().to_rfc3339(),
+                                "metadata": result.metadata,
+                                "goal_advisory": goal_advisory,
+                            }),
+                        )
+                        .and_then(|()| {
+                            result.processes.iter().try_for_each(|process| {
+                                let record = serde_json::to_value(process).unwrap_or_default();
+                                writer.write_record(&global.process_output_value(record))
+                            })
+                        });
+                    if let Err(e) = written {
+                        eprintln!("scan: failed to write output: {}", e);
+                        return ExitCode::IoError;
+                    }
+                }
                 OutputFormat::Summary => {
                     println!(
                         "Scanned {} processes in {}ms",
@@ -3892,7 +3790,7 @@ fn run_deep_scan(global: &GlobalOpts, _args: &DeepScanArgs) -> ExitCode {
 
         let progress = progress_emitter(global);
         let options = DeepScanOptions {
-            pids: args.pids.clone(),
+            pids: flatten_pid_lists(&args.pids),
             skip_inaccessible: true,
             include_environ: false,
             use_wait_free: true,
@@ -3909,7 +3807,7 @@ fn run_deep_scan(global: &GlobalOpts, _args: &DeepScanArgs) -> ExitCode {
                 }
 
                 match global.format {
-                    OutputFormat::Json | OutputFormat::Toon => {
+                    OutputFormat::Json | OutputFormat::Toon | OutputFormat::Yaml => {
                         let session_id = SessionId::new();
                         let output = serde_json::json!({
                             "schema_version": SCHEMA_VERSION,
@@ -4957,11 +4855,7 @@ fn run_check(global: &GlobalOpts, args: &CheckArgs) -> ExitCode {
     let mut all_ok = true;
 
     // Build config options from global opts
-    let options = ConfigOptions {
-        config_dir: global.config.as_ref().map(PathBuf::from),
-        priors_path: None,
-        policy_path: None,
-    };
+    let options = config_options(global);
 
     // Check priors
     if check_all || args.priors {
@@ -5072,13 +4966,16 @@ fn run_check(global: &GlobalOpts, args: &CheckArgs) -> ExitCode {
     if all_ok {
         ExitCode::Clean
     } else {
-        ExitCode::ArgsError
+        ExitCode::ConfigError
     }
 }
 
 fn run_learn(global: &GlobalOpts, args: &LearnArgs) -> ExitCode {
     let config_dir = resolve_config_dir(global);
     let catalog = learn_tutorials();
+    if let Err(err) = validate_learn_catalog(catalog) {
+        return output_learn_error(global, "catalog", &err.to_string());
+    }
 
     let mut progress_warning = None;
     let mut progress = match load_learn_progress(&config_dir) {
@@ -5110,6 +5007,7 @@ fn run_learn(global: &GlobalOpts, args: &LearnArgs) -> ExitCode {
                         "title": t.title,
                         "completed": progress.is_completed(t),
                         "doc_path": t.doc_path,
+                        "prerequisites": t.prerequisites,
                     })
                 })
                 .collect::<Vec<_>>();
@@ -5149,6 +5047,7 @@ fn run_learn(global: &GlobalOpts, args: &LearnArgs) -> ExitCode {
                         "title": t.title,
                         "goal": t.goal,
                         "doc_path": t.doc_path,
+                        "prerequisites": t.prerequisites,
                         "completed": progress.is_completed(t),
                         "completed_at": progress.completed.get(t.id),
                     })
@@ -5210,6 +5109,8 @@ fn run_learn(global: &GlobalOpts, args: &LearnArgs) -> ExitCode {
                         "doc_path": tutorial.doc_path,
                         "commands": tutorial.commands,
                         "hints": tutorial.hints,
+                        "prerequisites": tutorial.prerequisites,
+                        "missing_prerequisites": missing_learn_prerequisites(&progress, tutorial),
                         "completed": progress.is_completed(tutorial),
                         "completed_at": progress.completed.get(tutorial.id),
                     },
@@ -5218,7 +5119,7 @@ fn run_learn(global: &GlobalOpts, args: &LearnArgs) -> ExitCode {
                 ExitCode::Clean,
             )
         }
-        Some(LearnCommands::Complete { topic }) => {
+        Some(LearnCommands::Complete { topic, force }) => {
             let tutorial = match find_tutorial(topic) {
                 Some(tutorial) => tutorial,
                 None => {
@@ -5229,6 +5130,18 @@ fn run_learn(global: &GlobalOpts, args: &LearnArgs) -> ExitCode {
                     );
                 }
             };
+            let missing = missing_learn_prerequisites(&progress, tutorial);
+            if !missing.is_empty() && !*force {
+                return output_learn_error(
+                    global,
+                    "complete",
+                    &format!(
+                        "tutorial '{}' requires {} first (use --force to skip)",
+                        tutorial.id,
+                        missing.join(", ")
+                    ),
+                );
+            }
             mark_tutorial_completed(&mut progress, tutorial);
             let saved = match save_if_needed(&progress, "complete") {
                 Ok(path) => path,
@@ -5286,6 +5199,102 @@ fn run_learn(global: &GlobalOpts, args: &LearnArgs) -> ExitCode {
                 ExitCode::Clean,
             )
         }
+        Some(LearnCommands::Export { output }) => {
+            let exported = progress.export();
+            let Some(output) = output else {
+                println!("{}", exported);
+                return ExitCode::Clean;
+            };
+            if let Err(e) = std::fs::write(output, &exported) {
+                return output_learn_error(
+                    global,
+                    "export",
+                    &format!("failed to write {}: {}", output.display(), e),
+                );
+            }
+            (
+                serde_json::json!({
+                    "schema_version": SCHEMA_VERSION,
+                    "learn_schema_version": pt_core::learn::LEARN_SCHEMA_VERSION,
+                    "status": "ok",
+                    "mode": "export",
+                    "output_path": output.display().to_string(),
+                    "progress": {
+                        "completed": progress.completed_count(),
+                        "total": catalog.len(),
+                        "ratio": progress.completion_ratio(catalog.len()),
+                    },
+                }),
+                ExitCode::Clean,
+            )
+        }
+        Some(LearnCommands::Import { path }) => {
+            let raw = match std::fs::read_to_string(path) {
+                Ok(raw) => raw,
+                Err(e) => {
+                    return output_learn_error(
+                        global,
+                        "import",
+                        &format!("failed to read {}: {}", path.display(), e),
+                    );
+                }
+            };
+            match pt_core::learn::parse_progress_import(&raw) {
+                pt_core::learn::ProgressImport::Ready(other) => {
+                    let merged = progress.import_merge(&other);
+                    let saved = match save_if_needed(&progress, "import") {
+                        Ok(path) => path,
+                        Err(err) => return output_learn_error(global, "import", &err),
+                    };
+                    (
+                        serde_json::json!({
+                            "schema_version": SCHEMA_VERSION,
+                            "learn_schema_version": pt_core::learn::LEARN_SCHEMA_VERSION,
+                            "status": "ok",
+                            "mode": "import",
+                            "merged": merged,
+                            "saved_path": saved.display().to_string(),
+                            "progress": {
+                                "completed": progress.completed_count(),
+                                "total": catalog.len(),
+                                "ratio": progress.completion_ratio(catalog.len()),
+                            },
+                            "warning": progress_warning,
+                        }),
+                        ExitCode::Clean,
+                    )
+                }
+                pt_core::learn::ProgressImport::Fallback { reason } => {
+                    let next = next_learn_tutorial(&progress, catalog);
+                    (
+                        serde_json::json!({
+                            "schema_version": SCHEMA_VERSION,
+                            "learn_schema_version": pt_core::learn::LEARN_SCHEMA_VERSION,
+                            "status": "degraded",
+                            "mode": "import",
+                            "fallback_active": true,
+                            "fallback_reason": reason,
+                            "merged": 0,
+                            "progress": {
+                                "completed": progress.completed_count(),
+                                "total": catalog.len(),
+                                "ratio": progress.completion_ratio(catalog.len()),
+                            },
+                            "next_tutorial": next.map(|t| serde_json::json!({
+                                "id": t.id,
+                                "slug": t.slug,
+                                "title": t.title,
+                                "goal": t.goal,
+                                "doc_path": t.doc_path,
+                                "commands": t.commands,
+                            })),
+                            "warning": reason,
+                        }),
+                        ExitCode::PartialFail,
+                    )
+                }
+            }
+        }
         Some(LearnCommands::Verify {
             topic,
             all,
@@ -6507,11 +6516,7 @@ fn run_agent_fleet_transfer_export(
 
     let host_id = pt_core::logging::get_host_id();
 
-    let options = ConfigOptions {
-        config_dir: global.config.as_ref().map(PathBuf::from),
-        priors_path: None,
-        policy_path: None,
-    };
+    let options = config_options(global);
 
     let config = match load_config(&options) {
         Ok(c) => c,
@@ -6525,13 +6530,7 @@ fn run_agent_fleet_transfer_export(
     };
 
     let signatures_opt: Option<PersistedSchema> = if args.include_signatures {
-        let config_dir = global
-            .config
-            .as_ref()
-            .map(PathBuf::from)
-            .or_else(|| dirs::config_dir().map(|d| d.join("process_triage")))
-            .unwrap_or_else(|| PathBuf::from("."));
-        let mut lib = PatternLibrary::new(&config_dir);
+        let mut lib = PatternLibrary::new(pattern_library_dir(global));
         if lib.load().is_ok() {
             Some(lib.export(&[
                 PatternSource::Learned,
@@ -6659,7 +6658,9 @@ fn run_agent_fleet_transfer_import(
         compute_diff, merge_priors, normalize_baseline, validate_bundle, MergeStrategy,
         TransferBundle,
     };
-    use pt_core::supervision::pattern_persistence::{ConflictResolution, PatternLibrary};
+    use pt_core::supervision::pattern_persistence::{
+        ConflictResolution, PatternLibrary, DEFAULT_LOCK_TIMEOUT,
+    };
 
     let input_path = PathBuf::from(&args.from);
     let is_ptb = input_path.extension().map(|e| e == "ptb").unwrap_or(false);
@@ -6721,11 +6722,7 @@ fn run_agent_fleet_transfer_import(
         .parse()
         .unwrap_or(MergeStrategy::Weighted);
 
-    let options = ConfigOptions {
-        config_dir: global.config.as_ref().map(PathBuf::from),
-        priors_path: None,
-        policy_path: None,
-    };
+    let options = config_options(global);
     let config = match load_config(&options) {
         Ok(c) => c,
         Err(e) => return output_config_error(global, &e),
@@ -6841,14 +6838,7 @@ fn run_agent_fleet_transfer_import(
     }
 
     let sig_result = if let Some(ref incoming_sigs) = bundle.signatures {
-        let config_dir = global
-            .config
-            .as_ref()
-            .map(PathBuf::from)
-            .or_else(|| dirs::config_dir().map(|d| d.join("process_triage")))
-            .unwrap_or_else(|| PathBuf::from("."));
-        let mut lib = PatternLibrary::new(&config_dir);
-        let _ = lib.load();
+        let mut lib = PatternLibrary::new(pattern_library_dir(global));
 
         let resolution = match strategy {
             MergeStrategy::Replace => ConflictResolution::ReplaceWithImported,
@@ -6856,16 +6846,16 @@ fn run_agent_fleet_transfer_import(
             MergeStrategy::Weighted => ConflictResolution::KeepHigherConfidence,
         };
 
-        match lib.import(incoming_sigs.clone(), resolution) {
-            Ok(result) => {
-                let _ = lib.save();
-                Some(serde_json::json!({
-                    "imported": result.imported,
-                    "updated": result.updated,
-                    "skipped": result.skipped,
-                    "conflicts": result.conflicts.len(),
-                }))
-            }
+        // Serialize with other writers (scans, MCP) so neither clobbers the other.
+        match lib.with_lock(DEFAULT_LOCK_TIMEOUT, |lib| {
+            lib.import(incoming_sigs.clone(), resolution)
+        }) {
+            Ok(result) => Some(serde_json::json!({
+                "imported": result.imported,
+                "updated": result.updated,
+                "skipped": result.skipped,
+                "conflicts": result.conflicts.len(),
+            })),
             Err(e) => {
                 eprintln!("warning: signature import failed: {}", e);
                 None
@@ -6970,11 +6960,7 @@ fn run_agent_fleet_transfer_diff(
         }
     };
 
-    let options = ConfigOptions {
-        config_dir: global.config.as_ref().map(PathBuf::from),
-        priors_path: None,
-        policy_path: None,
-    };
+    let options = config_options(global);
     let config = match load_config(&options) {
         Ok(c) => c,
         Err(e) => return output_config_error(global, &e),
@@ -7044,6 +7030,7 @@ fn run_agent_fleet_transfer_diff(
 fn run_config(global: &GlobalOpts, args: &ConfigArgs) -> ExitCode {
     match &args.command {
         ConfigCommands::Show { file } => run_config_show(global, file.as_deref()),
+        ConfigCommands::Explain => run_config_explain(global),
         ConfigCommands::Schema { file } => run_config_schema(global, file),
         ConfigCommands::Validate { path } => run_config_validate(global, path.as_ref()),
         ConfigCommands::ListPresets => run_config_list_presets(global),
@@ -7060,11 +7047,7 @@ fn run_config_show(global: &GlobalOpts, file_filter: Option<&str>) -> ExitCode {
     let session_id = SessionId::new();
 
     // Build config options from global opts
-    let options = ConfigOptions {
-        config_dir: global.config.as_ref().map(PathBuf::from),
-        priors_path: None,
-        policy_path: None,
-    };
+    let options = config_options(global);
 
     // Load configuration (will fall back to defaults if no files found)
     let config = match load_config(&options) {
@@ -7186,7 +7169,76 @@ fn run_config_show(global: &GlobalOpts, file_filter: Option<&str>) -> ExitCode {
     ExitCode::Clean
 }
 
-fn generate_config_schema(file: &str) -> Result<serde_json::Value, String> {
+fn run_config_explain(global: &GlobalOpts) -> ExitCode {
+    let session_id = SessionId::new();
+
+    let (config, trace) = match resolve_config_traced(&config_options(global)) {
+        Ok(resolved) => resolved,
+        Err(e) => {
+            return output_config_error(global, &e);
+        }
+    };
+    match global.format {
+        OutputFormat::Json | OutputFormat::Toon => {
+            let response = serde_json::json!({
+                "schema_version": SCHEMA_VERSION,
+                "session_id": session_id.0,
+                "generated_at": chrono::Utc::now().to_rfc3339(),
+                "config_dir": config.config_dir.display().to_string(),
+                "trace": &trace,
+            });
+            println!("{}", format_structured_output(global, response));
+        }
+        OutputFormat::Summary => {
+            let winners: Vec<String> = trace
+                .fields
+                .iter()
+                .map(|f| format!("{}={:?}", f.field, f.winner.layer).to_lowercase())
+                .collect();
+            println!("[{}] config explain: {}", session_id, winners.join(" "));
+        }
+        OutputFormat::Exitcode => {}
+        _ => {
+            println!("# pt-core config explain");
+            println!();
+            for field in &trace.fields {
+                println!("## {}", field.field);
+                match &field.winner.path {
+                    Some(path) => {
+                        println!("Winner: {:?} ({})", field.winner.layer, path.display())
+                    }
+                    None => println!("Winner: {:?}", field.winner.layer),
+                }
+                for lost in &field.overridden {
+                    match &lost.path {
+                        Some(path) => println!("Overridden: {:?} ({})", lost.layer, path.display()),
+                        None => println!("Overridden: {:?}", lost.layer),
+                    }
+                }
+                println!();
+            }
+            println!("## Values");
+            let mut from_files = 0;
+            for (field, origin) in trace.file_values() {
+                from_files += 1;
+                match &origin.path {
+                    Some(path) => println!("{}: {:?} ({})", field, origin.layer, path.display()),
+                    None => println!("{}: {:?}", field, origin.layer),
+                }
+            }
+            println!(
+                "{} other value(s) use built-in defaults",
+                trace.values.len() - from_files
+            );
+            println!();
+            println!("Session: {}", session_id);
+        }
+    }
+
+    ExitCode::Clean
+}
+
+fn generate_config_schema(file: &str) -> Result<serde_json::Value, String> {
     match file.trim().to_ascii_lowercase().as_str() {
         "priors" => serde_json::to_value(schemars::schema_for!(pt_core::config::Priors))
             .map_err(|e| format!("failed to serialize priors schema: {}", e)),
@@ -7244,12 +7296,16 @@ fn run_config_validate(global: &GlobalOpts, path: Option<&String>) -> ExitCode {
                 config_dir: None,
                 priors_path: Some(path_buf),
                 policy_path: None,
+                expand_env: global.config_expand_env,
+                presets: global.config_preset.clone(),
             }
         } else if p.contains("policy") {
             ConfigOptions {
                 config_dir: None,
                 priors_path: None,
                 policy_path: Some(path_buf),
+                expand_env: global.config_expand_env,
+                presets: global.config_preset.clone(),
             }
         } else {
             // Assume it's a config directory
@@ -7257,25 +7313,28 @@ fn run_config_validate(global: &GlobalOpts, path: Option<&String>) -> ExitCode {
                 config_dir: Some(path_buf),
                 priors_path: None,
                 policy_path: None,
+                expand_env: global.config_expand_env,
+                presets: global.config_preset.clone(),
             }
         }
     } else {
-        ConfigOptions {
-            config_dir: global.config.as_ref().map(PathBuf::from),
-            priors_path: None,
-            policy_path: None,
-        }
+        config_options(global)
     };
 
     // Try to load and validate
     match load_config(&options) {
         Ok(config) => {
             let snapshot = config.snapshot();
+            let warnings: Vec<String> = loss_matrix_findings(&config.policy.loss_matrix)
+                .iter()
+                .map(|finding| finding.to_string())
+                .collect();
             let response = serde_json::json!({
                 "schema_version": SCHEMA_VERSION,
                 "session_id": session_id.0,
                 "generated_at": chrono::Utc::now().to_rfc3339(),
                 "status": "valid",
+                "warnings": &warnings,
                 "priors": {
                     "path": snapshot.priors_path.as_ref().map(|p| p.display().to_string()),
                     "using_defaults": snapshot.priors_path.is_none(),
@@ -7293,7 +7352,11 @@ fn run_config_validate(global: &GlobalOpts, path: Option<&String>) -> ExitCode {
                     println!("{}", format_structured_output(global, response));
                 }
                 OutputFormat::Summary => {
-                    println!("[{}] config validate: OK", session_id);
+                    println!(
+                        "[{}] config validate: OK ({} warning(s))",
+                        session_id,
+                        warnings.len()
+                    );
                 }
                 OutputFormat::Exitcode => {}
                 _ => {
@@ -7310,6 +7373,9 @@ fn run_config_validate(global: &GlobalOpts, path: Option<&String>) -> ExitCode {
                     } else {
                         println!("Policy: using built-in defaults");
                     }
+                    for warning in &warnings {
+                        println!("Warning: {}", warning);
+                    }
                 }
             }
 
@@ -7324,10 +7390,12 @@ fn output_config_error(global: &GlobalOpts, error: &ConfigError) -> ExitCode {
     let session_id = SessionId::new();
 
     let (error_code, exit_code) = match error {
-        ConfigError::NotFound { .. } => (10, ExitCode::ArgsError),
-        ConfigError::ParseError { .. } => (11, ExitCode::ArgsError),
-        ConfigError::SchemaError { .. } => (11, ExitCode::ArgsError),
-        ConfigError::ValidationError(_) => (11, ExitCode::ArgsError),
+        ConfigError::NotFound { .. } => (10, ExitCode::ConfigError),
+        ConfigError::ParseError { .. } => (11, ExitCode::ConfigError),
+        ConfigError::YamlParseError { .. } => (11, ExitCode::ConfigError),
+        ConfigError::SchemaError { .. } => (11, ExitCode::ConfigError),
+        ConfigError::ValidationError(_) => (11, ExitCode::ConfigError),
+        ConfigError::Preset(_) => (11, ExitCode::ConfigError),
         ConfigError::IoError { .. } => (21, ExitCode::IoError),
         ConfigError::VersionMismatch { .. } => (13, ExitCode::VersionError),
     };
@@ -7504,28 +7572,18 @@ fn run_config_diff_preset(global: &GlobalOpts, preset_name: &str) -> ExitCode {
     };
 
     // Load current config
-    let options = ConfigOptions {
-        config_dir: global.config.as_ref().map(PathBuf::from),
-        priors_path: None,
-        policy_path: None,
-    };
+    let options = config_options(global);
 
-    let current_policy = match load_config(&options) {
-        Ok(c) => c.policy,
+    let current = match load_config(&options) {
+        Ok(c) => c,
         Err(e) => {
             return output_config_error(global, &e);
         }
     };
 
-    let preset_policy = get_preset(preset_name_parsed);
-
-    // Convert to JSON for comparison
-    let current_json = serde_json::to_value(&current_policy).unwrap();
-    let preset_json = serde_json::to_value(&preset_policy).unwrap();
-
-    // Find differences
-    let mut differences: Vec<serde_json::Value> = Vec::new();
-    find_json_differences("", &current_json, &preset_json, &mut differences);
+    let diff =
+        PresetInfo::from_preset(preset_name_parsed).diff_against(&current.policy, &current.priors);
+    let differences = &diff.changes;
 
     match global.format {
         OutputFormat::Json | OutputFormat::Toon => {
@@ -7554,11 +7612,8 @@ fn run_config_diff_preset(global: &GlobalOpts, preset_name: &str) -> ExitCode {
             } else {
                 println!("{} difference(s) found:", differences.len());
                 println!();
-                for diff in &differences {
-                    println!(
-                        "  {}: {} -> {}",
-                        diff["path"], diff["current"], diff["preset"]
-                    );
+                for change in differences {
+                    println!("  {}: {} -> {}", change.path, change.current, change.preset);
                 }
             }
         }
@@ -7567,53 +7622,6 @@ fn run_config_diff_preset(global: &GlobalOpts, preset_name: &str) -> ExitCode {
     ExitCode::Clean
 }
 
-/// Helper to find differences between two JSON values recursively.
-fn find_json_differences(
-    path: &str,
-    current: &serde_json::Value,
-    preset: &serde_json::Value,
-    differences: &mut Vec<serde_json::Value>,
-) {
-    match (current, preset) {
-        (serde_json::Value::Object(c_map), serde_json::Value::Object(p_map)) => {
-            // Check all keys in both
-            let mut all_keys: std::collections::HashSet<&String> = c_map.keys().collect();
-            all_keys.extend(p_map.keys());
-
-            for key in all_keys {
-                let new_path = if path.is_empty() {
-                    key.clone()
-                } else {
-                    format!("{}.{}", path, key)
-                };
-
-                let c_val = c_map.get(key).unwrap_or(&serde_json::Value::Null);
-                let p_val = p_map.get(key).unwrap_or(&serde_json::Value::Null);
-
-                find_json_differences(&new_path, c_val, p_val, differences);
-            }
-        }
-        (serde_json::Value::Array(c_arr), serde_json::Value::Array(p_arr)) => {
-            if c_arr != p_arr {
-                differences.push(serde_json::json!({
-                    "path": path,
-                    "current": current,
-                    "preset": preset,
-                }));
-            }
-        }
-        _ => {
-            if current != preset {
-                differences.push(serde_json::json!({
-                    "path": path,
-                    "current": current,
-                    "preset": preset,
-                }));
-            }
-        }
-    }
-}
-
 /// Export a preset to a file.
 fn run_config_export_preset(
     global: &GlobalOpts,
@@ -7816,7 +7824,8 @@ fn run_daemon_background(global: &GlobalOpts) -> ExitCode {
 fn run_daemon_foreground(global: &GlobalOpts, config: &pt_core::daemon::DaemonConfig) -> ExitCode {
     use pt_core::inbox::{InboxItem, InboxStore};
 
-    install_daemon_signal_handlers();
+    let _signal_guard = install_daemon_signal_handlers();
+    let shutdown = pt_core::shutdown::ShutdownCoordinator::new(&pt_core::shutdown::SHUTDOWN_SIGNAL);
     apply_daemon_nice();
 
     let _pid_lock = match try_acquire_daemon_pid_lock() {
@@ -7862,7 +7871,7 @@ fn run_daemon_foreground(global: &GlobalOpts, config: &pt_core::daemon::DaemonCo
     );
 
     loop {
-        if DAEMON_SIGNALS.should_stop() {
+        if shutdown.should_stop() {
             break;
         }
 
@@ -8029,11 +8038,11 @@ fn run_daemon_foreground(global: &GlobalOpts, config: &pt_core::daemon::DaemonCo
         state_bundle.notifications = notify_mgr.persisted_state();
         let _ = save_daemon_state(&state_path, &state_bundle);
 
-        if DAEMON_SIGNALS.should_stop() {
+        if shutdown.should_stop() {
             break;
         }
 
-        if daemon_sleep_with_interrupt(config.tick_interval_secs) {
+        if daemon_sleep_with_interrupt(&shutdown, config.tick_interval_secs) {
             continue;
         }
     }
@@ -8373,9 +8382,38 @@ fn run_telemetry(global: &GlobalOpts, _args: &TelemetryArgs) -> ExitCode {
             output_stub(global, "telemetry redact", "Redaction not yet implemented");
             ExitCode::Clean
         }
+        TelemetryCommands::Schema { table } => run_telemetry_schema(table.as_deref()),
     }
 }
 
+fn run_telemetry_schema(table: Option<&str>) -> ExitCode {
+    let output = match table {
+        Some(name) => match TableName::from_name(name) {
+            Some(table) => TelemetrySchema::to_json_schema(table),
+            None => {
+                eprintln!("telemetry schema: unknown table '{}'", name);
+                return ExitCode::ArgsError;
+            }
+        },
+        None => serde_json::Value::Object(
+            TableName::ALL
+                .into_iter()
+                .map(|table| {
+                    (
+                        table.as_str().to_string(),
+                        TelemetrySchema::to_json_schema(table),
+                    )
+                })
+                .collect(),
+        ),
+    };
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&output).unwrap_or_default()
+    );
+    ExitCode::Clean
+}
+
 fn resolve_telemetry_dir(args: &TelemetryArgs) -> PathBuf {
     args.telemetry_dir
         .as_ref()
@@ -8667,9 +8705,10 @@ fn run_telemetry_prune(
     ExitCode::Clean
 }
 
+/// SIGHUP/SIGUSR1 state for the shadow loop; SIGINT/SIGTERM go through
+/// [`pt_core::shutdown`].
 #[derive(Debug)]
 struct ShadowSignalState {
-    stop: AtomicBool,
     reload: AtomicBool,
     force_scan: AtomicBool,
 }
@@ -8677,20 +8716,11 @@ struct ShadowSignalState {
 impl ShadowSignalState {
     const fn new() -> Self {
         Self {
-            stop: AtomicBool::new(false),
             reload: AtomicBool::new(false),
             force_scan: AtomicBool::new(false),
         }
     }
 
-    fn request_stop(&self) {
-        self.stop.store(true, Ordering::Relaxed);
-    }
-
-    fn should_stop(&self) -> bool {
-        self.stop.load(Ordering::Relaxed)
-    }
-
     fn request_reload(&self) {
         self.reload.store(true, Ordering::Relaxed);
     }
@@ -8710,11 +8740,15 @@ impl ShadowSignalState {
 
 static SHADOW_SIGNALS: ShadowSignalState = ShadowSignalState::new();
 
+/// Install the shadow loop's signal handlers.
+///
+/// SIGINT/SIGTERM use the shared [`pt_core::shutdown`] handlers (first signal
+/// stops after the current iteration, second exits immediately); the
+/// returned guard restores them when dropped.
 #[cfg(unix)]
-fn install_shadow_signal_handlers() {
+fn install_shadow_signal_handlers() -> pt_core::shutdown::SignalGuard {
     unsafe extern "C" fn handler(signal: i32) {
         match signal {
-            libc::SIGTERM | libc::SIGINT => SHADOW_SIGNALS.request_stop(),
             libc::SIGHUP => {
                 SHADOW_SIGNALS.request_reload();
                 SHADOW_SIGNALS.request_force_scan();
@@ -8726,15 +8760,136 @@ fn install_shadow_signal_handlers() {
 
     unsafe {
         let handler_ptr = handler as *const () as libc::sighandler_t;
-        libc::signal(libc::SIGTERM, handler_ptr);
-        libc::signal(libc::SIGINT, handler_ptr);
         libc::signal(libc::SIGHUP, handler_ptr);
         libc::signal(libc::SIGUSR1, handler_ptr);
     }
+    pt_core::shutdown::install_signal_handlers()
 }
 
 #[cfg(not(unix))]
-fn install_shadow_signal_handlers() {}
+fn install_shadow_signal_handlers() -> pt_core::shutdown::SignalGuard {
+    pt_core::shutdown::install_signal_handlers()
+}
+
+fn run_plugin(global: &GlobalOpts, args: &PluginArgs) -> ExitCode {
+    match &args.command {
+        PluginCommands::Doctor => run_plugin_doctor(global),
+        PluginCommands::Check { dir } => run_plugin_check(global, dir),
+    }
+}
+
+fn run_plugin_check(global: &GlobalOpts, dir: &Path) -> ExitCode {
+    let report = match PluginManager::validate_plugin(dir) {
+        Ok(report) => report,
+        Err(err) => {
+            eprintln!("plugin check: {}", err);
+            return ExitCode::ArgsError;
+        }
+    };
+
+    let output = serde_json::json!({
+        "schema_version": SCHEMA_VERSION,
+        "command": "plugin check",
+        "passed": report.passed(),
+        "plugin": report,
+    });
+
+    match global.format {
+        OutputFormat::Json | OutputFormat::Toon => {
+            println!("{}", format_structured_output(global, output));
+        }
+        OutputFormat::Jsonl => {
+            println!("{}", serde_json::to_string(&output).unwrap_or_default());
+        }
+        _ => {
+            println!(
+                "Plugin {} ({}) at {}",
+                report.name,
+                format!("{:?}", report.plugin_type).to_lowercase(),
+                report.plugin_dir.display()
+            );
+            for check in &report.checks {
+                println!(
+                    "  {:<12} {:<4} {}",
+                    check.name,
+                    if check.passed { "ok" } else { "FAIL" },
+                    check.message
+                );
+            }
+        }
+    }
+
+    if report.passed() {
+        ExitCode::Clean
+    } else {
+        ExitCode::PartialFail
+    }
+}
+
+fn run_plugin_doctor(global: &GlobalOpts) -> ExitCode {
+    let config_dir = resolve_config_dir(global);
+    let manager = match PluginManager::discover(&config_dir) {
+        Ok(manager) => manager,
+        Err(err) => {
+            eprintln!("plugin doctor: {}", err);
+            return ExitCode::IoError;
+        }
+    };
+
+    let reports = manager.health_check();
+    let unhealthy = reports.iter().filter(|r| !r.is_healthy()).count();
+
+    let output = serde_json::json!({
+        "schema_version": SCHEMA_VERSION,
+        "command": "plugin doctor",
+        "plugins_dir": manager.plugins_dir().display().to_string(),
+        "plugin_count": reports.len(),
+        "unhealthy_count": unhealthy,
+        "plugins": reports,
+    });
+
+    match global.format {
+        OutputFormat::Json | OutputFormat::Toon => {
+            println!("{}", format_structured_output(global, output));
+        }
+        OutputFormat::Jsonl => {
+            println!("{}", serde_json::to_string(&output).unwrap_or_default());
+        }
+        _ => {
+            println!("Plugins directory: {}", manager.plugins_dir().display());
+            if reports.is_empty() {
+                println!("No plugins installed.");
+            }
+            for report in &reports {
+                let latency = report
+                    .latency_ms
+                    .map(|ms| format!("{}ms", ms))
+                    .unwrap_or_else(|| "-".to_string());
+                let status = serde_json::to_value(report.status)
+                    .ok()
+                    .and_then(|v| v.as_str().map(str::to_string))
+                    .unwrap_or_default();
+                println!(
+                    "  {:<24} {:<8} {:<18} {:>8}{}",
+                    report.name,
+                    format!("{:?}", report.plugin_type).to_lowercase(),
+                    status,
+                    latency,
+                    if report.disabled { "  (disabled)" } else { "" }
+                );
+                if let Some(error) = &report.error {
+                    println!("      {}", error);
+                }
+            }
+        }
+    }
+
+    if unhealthy > 0 {
+        ExitCode::PartialFail
+    } else {
+        ExitCode::Clean
+    }
+}
 
 fn run_shadow(global: &GlobalOpts, args: &ShadowArgs) -> ExitCode {
     match &args.command {
@@ -8814,9 +8969,55 @@ fn run_shadow_background(global: &GlobalOpts, args: &ShadowStartArgs) -> ExitCod
 }
 
 fn run_shadow_run(global: &GlobalOpts, args: &ShadowStartArgs) -> ExitCode {
-    install_shadow_signal_handlers();
+    let _signal_guard = install_shadow_signal_handlers();
+    let shutdown = pt_core::shutdown::ShutdownCoordinator::new(&pt_core::shutdown::SHUTDOWN_SIGNAL);
     let own_pid = std::process::id();
 
+    let metrics =
+        std::sync::Arc::new(std::sync::Mutex::new(pt_core::metrics::ShadowMetrics::new()));
+    #[cfg(feature = "metrics")]
+    let _metrics_server = match args.metrics_port {
+        Some(port) => {
+            let config = pt_core::daemon::metrics::MetricsConfig {
+                bind: "127.0.0.1".to_string(),
+                port,
+                ..Default::default()
+            };
+            let metrics = metrics.clone();
+            let render = move || {
+                metrics
+                    .lock()
+                    .map(|m| m.render())
+                    .map_err(|e| e.to_string())
+            };
+            match pt_core::daemon::metrics::MetricsServer::start_with(&config, render) {
+                Ok(server) => Some(server),
+                Err(err) => {
+                    eprintln!("shadow run: {}", err);
+                    return ExitCode::IoError;
+                }
+            }
+        }
+        None => None,
+    };
+
+    let config_watcher = match shadow_config_watcher(global) {
+        Ok(watcher) => watcher,
+        Err(err) => {
+            eprintln!("shadow run: invalid config: {}", err);
+            return ExitCode::ConfigError;
+        }
+    };
+    let config_dir = shadow_config_dir();
+    if let Err(err) = write_shadow_config(&config_dir, &config_watcher.current()) {
+        eprintln!(
+            "shadow run: failed to write config to {}: {}",
+            config_dir.display(),
+            err
+        );
+        return ExitCode::IoError;
+    }
+
     let mut iterations = args.iterations;
     let mut run_count: u32 = 0;
     let mut next_deep_at = if args.deep || args.deep_interval == 0 {
@@ -8826,12 +9027,38 @@ fn run_shadow_run(global: &GlobalOpts, args: &ShadowStartArgs) -> ExitCode {
     };
 
     loop {
-        if SHADOW_SIGNALS.should_stop() {
+        if shutdown.should_stop() {
             break;
         }
 
         if SHADOW_SIGNALS.take_reload() {
-            SHADOW_SIGNALS.request_force_scan();
+            match config_watcher.reload() {
+                Ok(()) => {
+                    let loaded = config_watcher.current();
+                    match write_shadow_config(&config_dir, &loaded) {
+                        Ok(()) => {
+                            tracing::info!(
+                                priors = ?loaded.paths.priors,
+                                policy = ?loaded.paths.policy,
+                                "shadow config reloaded"
+                            );
+                            SHADOW_SIGNALS.request_force_scan();
+                        }
+                        Err(err) => {
+                            tracing::warn!(
+                                error = %err,
+                                "failed to write reloaded shadow config, keeping previous config"
+                            );
+                        }
+                    }
+                }
+                Err(err) => {
+                    tracing::warn!(
+                        error = %err,
+                        "shadow config reload rejected, keeping previous config"
+                    );
+                }
+            }
         }
 
         let now = std::time::Instant::now();
@@ -8846,8 +9073,16 @@ fn run_shadow_run(global: &GlobalOpts, args: &ShadowStartArgs) -> ExitCode {
         }
 
         run_count = run_count.saturating_add(1);
-        match run_shadow_iteration(args, force_deep) {
-            Ok(status) => {
+        match run_shadow_iteration(global, args, force_deep, &config_dir) {
+            Ok(output) => {
+                let _ = std::io::stdout().write_all(&output.stdout);
+                if let Ok(mut metrics) = metrics.lock() {
+                    match serde_json::from_slice::<serde_json::Value>(&output.stdout) {
+                        Ok(plan) => metrics.record_plan(&plan),
+                        Err(_) => metrics.record_failed_scan(),
+                    }
+                }
+                let status = output.status;
                 if !status.success() {
                     eprintln!(
                         "shadow run: iteration {} failed (exit={})",
@@ -8857,6 +9092,9 @@ fn run_shadow_run(global: &GlobalOpts, args: &ShadowStartArgs) -> ExitCode {
                 }
             }
             Err(err) => {
+                if let Ok(mut metrics) = metrics.lock() {
+                    metrics.record_failed_scan();
+                }
                 eprintln!("shadow run: iteration {} failed: {}", run_count, err);
             }
         }
@@ -8868,7 +9106,7 @@ fn run_shadow_run(global: &GlobalOpts, args: &ShadowStartArgs) -> ExitCode {
             }
         }
 
-        if SHADOW_SIGNALS.should_stop() {
+        if shutdown.should_stop() {
             break;
         }
 
@@ -8876,7 +9114,7 @@ fn run_shadow_run(global: &GlobalOpts, args: &ShadowStartArgs) -> ExitCode {
             continue;
         }
 
-        if shadow_sleep_with_interrupt(args.interval) {
+        if shadow_sleep_with_interrupt(&shutdown, args.interval) {
             continue;
         }
     }
@@ -8902,21 +9140,62 @@ fn run_shadow_run(global: &GlobalOpts, args: &ShadowStartArgs) -> ExitCode {
     ExitCode::Clean
 }
 
+/// Watch the priors/policy files the usual config resolution selects.
+fn shadow_config_watcher(global: &GlobalOpts) -> Result<pt_config::ConfigWatcher, String> {
+    let resolved = load_config(&config_options(global)).map_err(|e| e.to_string())?;
+    pt_config::ConfigWatcher::new(
+        resolved.priors_path.as_deref(),
+        resolved.policy_path.as_deref(),
+    )
+    .map_err(|e| e.to_string())
+}
+
+/// Directory holding the last validated config; shadow scans read only this.
+fn shadow_config_dir() -> PathBuf {
+    shadow_base_dir().join("config")
+}
+
+/// Write the validated priors/policy where the `agent plan` children load
+/// them, so a rejected edit never reaches a scan.
+fn write_shadow_config(dir: &Path, loaded: &pt_config::LoadedConfig) -> Result<(), std::io::Error> {
+    fs::create_dir_all(dir)?;
+    let files = [
+        ("priors.json", serde_json::to_vec_pretty(&loaded.priors)?),
+        ("policy.json", serde_json::to_vec_pretty(&loaded.policy)?),
+    ];
+    for (name, content) in files {
+        let tmp = dir.join(format!("{}.tmp", name));
+        fs::write(&tmp, content)?;
+        fs::rename(&tmp, dir.join(name))?;
+    }
+    Ok(())
+}
+
+/// Run one `agent plan` child and capture its JSON plan on stdout.
 fn run_shadow_iteration(
+    global: &GlobalOpts,
     args: &ShadowStartArgs,
     force_deep: bool,
-) -> Result<std::process::ExitStatus, std::io::Error> {
+    config_dir: &Path,
+) -> Result<std::process::Output, std::io::Error> {
     let exe = std::env::current_exe()?;
 
     let mut cmd = std::process::Command::new(exe);
     cmd.arg("--shadow")
         .arg("--format")
         .arg("json")
-        .arg("agent")
-        .arg("plan");
+        .arg("--config")
+        .arg(config_dir);
+    if global.config_expand_env {
+        cmd.arg("--config-expand-env");
+    }
+    cmd.arg("agent")
+        .arg("plan")
+        // Each shadow scan is its own session.
+        .env_remove(pt_common::SESSION_ID_ENV);
     apply_shadow_plan_args(&mut cmd, args, force_deep);
 
-    cmd.status()
+    cmd.stderr(std::process::Stdio::inherit()).output()
 }
 
 fn run_shadow_stop(global: &GlobalOpts) -> ExitCode {
@@ -9208,6 +9487,10 @@ fn apply_shadow_start_args(cmd: &mut std::process::Command, args: &ShadowStartAr
     if let Some(sample_size) = args.sample_size {
         cmd.arg("--sample-size").arg(sample_size.to_string());
     }
+    #[cfg(feature = "metrics")]
+    if let Some(port) = args.metrics_port {
+        cmd.arg("--metrics-port").arg(port.to_string());
+    }
 }
 
 fn apply_shadow_plan_args(
@@ -9234,13 +9517,16 @@ fn apply_shadow_plan_args(
     }
 }
 
-fn shadow_sleep_with_interrupt(seconds: u64) -> bool {
+fn shadow_sleep_with_interrupt(
+    shutdown: &pt_core::shutdown::ShutdownCoordinator<'_>,
+    seconds: u64,
+) -> bool {
     if seconds == 0 {
         return false;
     }
     let mut remaining = seconds;
     while remaining > 0 {
-        if SHADOW_SIGNALS.should_stop() {
+        if shutdown.should_stop() {
             return false;
         }
         if SHADOW_SIGNALS.take_force_scan() {
@@ -9421,10 +9707,11 @@ fn acquire_global_lock(global: &GlobalOpts, command: &str) -> Result<Option<Glob
 // Daemon helpers
 // ============================================================================
 
+/// SIGHUP/SIGUSR1 state for the daemon loop; SIGINT/SIGTERM go through
+/// [`pt_core::shutdown`].
 #[cfg(feature = "daemon")]
 #[derive(Debug)]
 struct DaemonSignalState {
-    stop: AtomicBool,
     reload: AtomicBool,
     force_tick: AtomicBool,
 }
@@ -9433,20 +9720,11 @@ struct DaemonSignalState {
 impl DaemonSignalState {
     const fn new() -> Self {
         Self {
-            stop: AtomicBool::new(false),
             reload: AtomicBool::new(false),
             force_tick: AtomicBool::new(false),
         }
     }
 
-    fn request_stop(&self) {
-        self.stop.store(true, Ordering::Relaxed);
-    }
-
-    fn should_stop(&self) -> bool {
-        self.stop.load(Ordering::Relaxed)
-    }
-
     fn request_reload(&self) {
         self.reload.store(true, Ordering::Relaxed);
     }
@@ -9467,12 +9745,13 @@ impl DaemonSignalState {
 #[cfg(feature = "daemon")]
 static DAEMON_SIGNALS: DaemonSignalState = DaemonSignalState::new();
 
+/// Install the daemon's signal handlers; SIGINT/SIGTERM use the shared
+/// [`pt_core::shutdown`] handlers until the returned guard drops.
 #[cfg(feature = "daemon")]
 #[cfg(unix)]
-fn install_daemon_signal_handlers() {
+fn install_daemon_signal_handlers() -> pt_core::shutdown::SignalGuard {
     unsafe extern "C" fn handler(signal: i32) {
         match signal {
-            libc::SIGTERM | libc::SIGINT => DAEMON_SIGNALS.request_stop(),
             libc::SIGHUP => {
                 DAEMON_SIGNALS.request_reload();
                 DAEMON_SIGNALS.request_force_tick();
@@ -9490,7 +9769,7 @@ fn install_daemon_signal_handlers() {
             action.sa_sigaction = handler as *const () as usize;
             action.sa_flags = libc::SA_RESTART;
             libc::sigemptyset(&mut action.sa_mask);
-            for signal in [libc::SIGTERM, libc::SIGINT, libc::SIGHUP, libc::SIGUSR1] {
+            for signal in [libc::SIGHUP, libc::SIGUSR1] {
                 libc::sigaction(signal, &action, std::ptr::null_mut());
             }
         }
@@ -9498,26 +9777,30 @@ fn install_daemon_signal_handlers() {
         #[cfg(not(target_os = "linux"))]
         {
             let handler_ptr = handler as *const () as libc::sighandler_t;
-            libc::signal(libc::SIGTERM, handler_ptr);
-            libc::signal(libc::SIGINT, handler_ptr);
             libc::signal(libc::SIGHUP, handler_ptr);
             libc::signal(libc::SIGUSR1, handler_ptr);
         }
     }
+    pt_core::shutdown::install_signal_handlers()
 }
 
 #[cfg(feature = "daemon")]
 #[cfg(not(unix))]
-fn install_daemon_signal_handlers() {}
+fn install_daemon_signal_handlers() -> pt_core::shutdown::SignalGuard {
+    pt_core::shutdown::install_signal_handlers()
+}
 
 #[cfg(feature = "daemon")]
-fn daemon_sleep_with_interrupt(seconds: u64) -> bool {
+fn daemon_sleep_with_interrupt(
+    shutdown: &pt_core::shutdown::ShutdownCoordinator<'_>,
+    seconds: u64,
+) -> bool {
     if seconds == 0 {
         return false;
     }
     let mut remaining = seconds;
     while remaining > 0 {
-        if DAEMON_SIGNALS.should_stop() {
+        if shutdown.should_stop() {
             return false;
         }
         if DAEMON_SIGNALS.take_force_tick() {
@@ -10137,7 +10420,8 @@ fn run_mcp(args: &McpArgs) -> ExitCode {
         return ExitCode::ArgsError;
     }
 
-    let mut server = pt_core::mcp::McpServer::new();
+    let mut server = pt_core::mcp::McpServer::new()
+        .with_update_interval(std::time::Duration::from_secs(args.update_interval));
     if let Err(e) = server.run_stdio() {
         eprintln!("MCP server error: {}", e);
         return ExitCode::IoError;
@@ -10147,7 +10431,8 @@ fn run_mcp(args: &McpArgs) -> ExitCode {
 
 fn run_schema(global: &GlobalOpts, args: &SchemaArgs) -> ExitCode {
     use pt_core::schema::{
-        available_schemas, format_schema, generate_all_schemas, generate_schema, SchemaFormat,
+        available_schemas, export_schemas, format_schema, generate_all_schemas, generate_schema,
+        SchemaFormat,
     };
 
     let format = if args.compact {
@@ -10209,6 +10494,24 @@ fn run_schema(global: &GlobalOpts, args: &SchemaArgs) -> ExitCode {
         return ExitCode::Clean;
     }
 
+    // Export the versioned output schemas
+    if args.type_name.as_deref() == Some("dump") {
+        let schemas = export_schemas();
+        match global.format {
+            OutputFormat::Jsonl => {
+                for (name, schema) in schemas {
+                    let entry = serde_json::json!({"type": name, "schema": schema});
+                    println!("{}", serde_json::to_string(&entry).unwrap());
+                }
+            }
+            _ => {
+                let schemas_value = serde_json::to_value(&schemas).unwrap_or_default();
+                println!("{}", format_schema(&schemas_value, format));
+            }
+        }
+        return ExitCode::Clean;
+    }
+
     // Generate schema for a specific type
     if let Some(ref type_name) = args.type_name {
         match generate_schema(type_name) {
@@ -10230,7 +10533,7 @@ fn run_schema(global: &GlobalOpts, args: &SchemaArgs) -> ExitCode {
             }
         }
     } else {
-        eprintln!("Usage: pt schema <TYPE> | --list | --all");
+        eprintln!("Usage: pt schema <TYPE> | dump | --list | --all");
         eprintln!("\nUse 'pt schema --list' to see available types.");
         ExitCode::PartialFail
     }
@@ -11182,10 +11485,7 @@ fn run_agent_snapshot(global: &GlobalOpts, args: &AgentSnapshotArgs) -> ExitCode
     // Persist compact artifacts when we have a scan result.
     if let Some(ref scan_result) = scan_result {
         // Load config for protected filter + action policy.
-        let config_options = ConfigOptions {
-            config_dir: global.config.as_ref().map(PathBuf::from),
-            ..Default::default()
-        };
+        let config_options = config_options(global);
         if let Ok(config) = load_config(&config_options) {
             let priors = config.priors.clone();
             let policy = config.policy;
@@ -11247,6 +11547,7 @@ fn run_agent_snapshot(global: &GlobalOpts, args: &AgentSnapshotArgs) -> ExitCode
                         Action::Freeze => "freeze",
                         Action::Unfreeze => "unfreeze",
                         Action::Throttle => "throttle",
+                        Action::Unthrottle => "unthrottle",
                         Action::Quarantine => "quarantine",
                         Action::Unquarantine => "unquarantine",
                         Action::Restart => "restart",
@@ -11550,17 +11851,6 @@ fn run_agent_snapshot(global: &GlobalOpts, args: &AgentSnapshotArgs) -> ExitCode
     ExitCode::Clean
 }
 
-fn match_level_label(level: MatchLevel) -> &'static str {
-    match level {
-        MatchLevel::None => "none",
-        MatchLevel::GenericCategory => "generic_category",
-        MatchLevel::CommandOnly => "command_only",
-        MatchLevel::CommandPlusArgs => "command_plus_args",
-        MatchLevel::ExactCommand => "exact_command",
-        MatchLevel::MultiPattern => "multi_pattern",
-    }
-}
-
 fn fast_path_skip_reason_label(reason: FastPathSkipReason) -> &'static str {
     match reason {
         FastPathSkipReason::Disabled => "disabled",
@@ -11602,44 +11892,64 @@ fn run_agent_plan(global: &GlobalOpts, args: &AgentPlanArgs) -> ExitCode {
             (sid, handle, false)
         }
         None => {
-            let sid = SessionId::new();
-            let manifest =
-                SessionManifest::new(&sid, None, SessionMode::RobotPlan, args.label.clone());
-            let handle = match store.create(&manifest) {
-                Ok(handle) => handle,
+            // PT_SESSION_ID lets a wrapper pick the ID up front and hand it to
+            // `agent apply`; an existing session with that ID is resumed.
+            let env_sid = match SessionId::from_env() {
+                Ok(sid) => sid,
                 Err(e) => {
-                    eprintln!("agent plan: failed to create session: {}", e);
-                    return ExitCode::InternalError;
+                    eprintln!("agent plan: {}: {}", pt_common::SESSION_ID_ENV, e);
+                    return ExitCode::ArgsError;
                 }
             };
-            let ctx = SessionContext::new(
-                &sid,
-                pt_core::logging::get_host_id(),
-                pt_core::logging::generate_run_id(),
-                None,
-            );
-            if let Err(e) = handle.write_context(&ctx) {
-                eprintln!("agent plan: failed to write context.json: {}", e);
-                return ExitCode::InternalError;
+            let resumed = env_sid
+                .as_ref()
+                .and_then(|sid| store.open(sid).ok().map(|handle| (sid.clone(), handle)));
+            if let Some((sid, handle)) = resumed {
+                (sid, handle, false)
+            } else {
+                let sid = env_sid.unwrap_or_default();
+                let manifest =
+                    SessionManifest::new(&sid, None, SessionMode::RobotPlan, args.label.clone());
+                let handle = match store.create(&manifest) {
+                    Ok(handle) => handle,
+                    Err(e) => {
+                        eprintln!("agent plan: failed to create session: {}", e);
+                        return ExitCode::InternalError;
+                    }
+                };
+                let ctx = SessionContext::new(
+                    &sid,
+                    pt_core::logging::get_host_id(),
+                    pt_core::logging::generate_run_id(),
+                    None,
+                );
+                if let Err(e) = handle.write_context(&ctx) {
+                    eprintln!("agent plan: failed to write context.json: {}", e);
+                    return ExitCode::InternalError;
+                }
+                (sid, handle, true)
             }
-            (sid, handle, true)
         }
     };
 
     // Load configuration and priors
-    let config_options = ConfigOptions {
-        config_dir: global.config.as_ref().map(PathBuf::from),
-        ..Default::default()
-    };
+    let config_options = config_options(global);
     let config = match load_config(&config_options) {
         Ok(c) => c,
         Err(e) => {
             eprintln!("agent plan: failed to load config: {}", e);
-            return ExitCode::InternalError;
+            return ExitCode::ConfigError;
         }
     };
     let priors = config.priors.clone();
     let policy = config.policy;
+    let protection_rules = match ProtectionRules::new(&policy.protection_list) {
+        Ok(rules) => rules,
+        Err(e) => {
+            eprintln!("agent plan: invalid protection_list: {}", e);
+            return ExitCode::ConfigError;
+        }
+    };
     let fast_path_config = FastPathConfig {
         enabled: policy.signature_fast_path.enabled,
         min_confidence_threshold: policy.signature_fast_path.min_confidence_threshold,
@@ -11761,7 +12071,7 @@ fn run_agent_plan(global: &GlobalOpts, args: &AgentPlanArgs) -> ExitCode {
     let mut signature_fast_path_used_count = 0usize;
 
     let base_feasibility = ActionFeasibility::allow_all();
-    let mut shadow_recorder = if global.shadow {
+    let shadow_recorder = std::sync::Mutex::new(if global.shadow {
         match ShadowRecorder::new() {
             Ok(recorder) => Some(recorder),
             Err(err) => {
@@ -11771,9 +12081,25 @@ fn run_agent_plan(global: &GlobalOpts, args: &AgentPlanArgs) -> ExitCode {
         }
     } else {
         None
-    };
+    });
     let mut shadow_recorded = 0u64;
 
+    // First SIGINT/SIGTERM stops inference; shadow observations recorded so far
+    // are still flushed and the partial plan is written.
+    let _signal_guard = pt_core::shutdown::install_signal_handlers();
+    let mut shutdown =
+        pt_core::shutdown::ShutdownCoordinator::new(&pt_core::shutdown::SHUTDOWN_SIGNAL);
+    shutdown.register("shadow telemetry", || {
+        match shadow_recorder
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .as_mut()
+        {
+            Some(recorder) => recorder.flush().map_err(|e| format!("{:?}", e)),
+            None => Ok(()),
+        }
+    });
+
     // Apply min-age filter before sampling (if configured)
     let eligible_processes: Vec<_> = if let Some(min_age) = args.min_age {
         filter_result
@@ -11800,8 +12126,17 @@ fn run_agent_plan(global: &GlobalOpts, args: &AgentPlanArgs) -> ExitCode {
     let _current_cpu_pct: f64 = processes_to_infer.iter().map(|p| p.cpu_percent).sum();
     let probe_cost_model = pt_core::decision::ProbeCostModel::default();
     let deep_scan_probe = [pt_core::decision::ProbeType::DeepScan];
-    #[cfg(target_os = "linux")]
-    let provenance_bundle = build_provenance_inference_bundle(&processes_to_infer);
+    let probe_availability =
+        pt_core::decision::ProbeAvailability::from_capabilities(&get_capabilities());
+
+    // Process tree, running supervisors and provenance shared by every candidate.
+    let scoring = ScoringContext::new(
+        &priors,
+        &signature_db,
+        &fast_path_config,
+        &scan_result.processes,
+        &processes_to_infer,
+    );
 
     let candidates_evaluated = processes_to_infer.len();
     let total_processes = candidates_evaluated as u64;
@@ -11824,206 +12159,59 @@ fn run_agent_plan(global: &GlobalOpts, args: &AgentPlanArgs) -> ExitCode {
         );
     }
 
+    // NDJSON streams each candidate as soon as it is classified, in scan
+    // order, instead of after ranking; `--max-candidates` and `--goal` only
+    // shape the saved plan.
+    let mut ndjson = None;
+    let mut ndjson_error = None;
+    if global.format == OutputFormat::Ndjson {
+        let mut writer = NdjsonWriter::new(std::io::stdout().lock());
+        match writer.write_metadata(
+            SCHEMA_VERSION,
+            serde_json::json!({
+                "session_id": session_id.0,
+                "generated_at": chrono::Utc::now().to_rfc3339(),
+            }),
+        ) {
+            Ok(()) => ndjson = Some(writer),
+            Err(e) => ndjson_error = Some(e),
+        }
+    }
+
     // Use filtered (and optionally sampled) processes for inference
     for proc in processes_to_infer {
+        if shutdown.should_stop() {
+            break;
+        }
         // Skip PID 0/1 (extra safety - should already be filtered)
         if proc.pid.0 == 0 || proc.pid.0 == 1 {
             continue;
         }
         processed = processed.saturating_add(1);
 
-        // Build evidence from process record
-        let evidence = Evidence {
-            cpu: Some(CpuEvidence::Fraction {
-                occupancy: (proc.cpu_percent / 100.0).clamp(0.0, 1.0),
-            }),
-            runtime_seconds: Some(proc.elapsed.as_secs_f64()),
-            orphan: Some(proc.is_orphan()),
-            tty: Some(proc.has_tty()),
-            net: None,
-            io_active: None,
-            state_flag: state_to_flag(proc.state),
-            command_category: None,
-            queue_saturated: None,
-        };
+        // Policy protection list overrides whatever inference concludes.
+        let protection = protection_rules.check(&proc.comm);
 
-        let mut match_ctx = ProcessMatchContext::with_comm(&proc.comm);
-        if !proc.cmd.is_empty() {
-            match_ctx = match_ctx.cmdline(&proc.cmd);
-        }
-        let signature_match = signature_db.best_match(&match_ctx);
-        if signature_match.is_some() {
+        let evidence = process_evidence(proc);
+        let Some(scored) = score_process(&scoring, proc, &evidence) else {
+            continue;
+        };
+        if scored.signature_match.is_some() {
             signature_match_count = signature_match_count.saturating_add(1);
         }
-
-        let mut fast_path_used = false;
-        let mut fast_path_skip_reason: Option<&'static str> = None;
-        let prior_source_label: String;
-        let prior_context = PriorContext {
-            global_priors: &priors,
-            signature_match: signature_match.as_ref(),
-            category_defaults: None,
-            user_overrides: None,
-        };
-
-        let (mut posterior_result, mut ledger) = if let Some(sig_match) = signature_match.as_ref() {
-            match try_signature_fast_path(&fast_path_config, Some(sig_match), proc.pid.0) {
-                Ok(Some(fast_path)) => {
-                    fast_path_used = true;
-                    signature_fast_path_used_count =
-                        signature_fast_path_used_count.saturating_add(1);
-                    prior_source_label = "signature_fast_path".to_string();
-                    (fast_path.posterior, fast_path.ledger)
-                }
-                Ok(None) => match compute_posterior_with_overrides(&prior_context, &evidence) {
-                    Ok((result, source_info)) => {
-                        prior_source_label = source_info.source.to_string();
-                        let ledger =
-                            EvidenceLedger::from_posterior_result(&result, Some(proc.pid.0), None);
-                        (result, ledger)
-                    }
-                    Err(_) => continue,
-                },
-                Err(reason) => {
-                    fast_path_skip_reason = Some(fast_path_skip_reason_label(reason));
-                    match compute_posterior_with_overrides(&prior_context, &evidence) {
-                        Ok((result, source_info)) => {
-                            prior_source_label = source_info.source.to_string();
-                            let ledger = EvidenceLedger::from_posterior_result(
-                                &result,
-                                Some(proc.pid.0),
-                                None,
-                            );
-                            (result, ledger)
-                        }
-                        Err(_) => continue,
-                    }
-                }
-            }
-        } else {
-            match compute_posterior_with_overrides(&prior_context, &evidence) {
-                Ok((result, source_info)) => {
-                    prior_source_label = source_info.source.to_string();
-                    let ledger =
-                        EvidenceLedger::from_posterior_result(&result, Some(proc.pid.0), None);
-                    (result, ledger)
-                }
-                Err(_) => continue,
-            }
-        };
-
+        if scored.fast_path_used {
+            signature_fast_path_used_count = signature_fast_path_used_count.saturating_add(1);
+        }
+        let fast_path_used = scored.fast_path_used;
+        let fast_path_skip_reason = scored
+            .fast_path_skip_reason
+            .map(fast_path_skip_reason_label);
+        let prior_source_label = scored.prior_source;
+        let signature_match = scored.signature_match;
+        let posterior_result = scored.posterior;
+        let ledger = scored.ledger;
         #[cfg(target_os = "linux")]
-        let provenance_adjustment = {
-            let adjustment = derive_provenance_adjustment(proc.pid.0, &provenance_bundle);
-            if !adjustment.evidence_terms.is_empty() {
-                match apply_evidence_terms(&posterior_result, adjustment.evidence_terms.clone()) {
-                    Ok(adjusted) => {
-                        posterior_result = adjusted;
-                        ledger = EvidenceLedger::from_posterior_result(
-                            &posterior_result,
-                            Some(proc.pid.0),
-                            None,
-                        );
-                    }
-                    Err(err) => {
-                        tracing::debug!(
-                            pid = proc.pid.0,
-                            error = %err,
-                            "Failed to apply provenance-derived evidence terms"
-                        );
-                    }
-                }
-            }
-
-            if adjustment.confidence_penalty_steps > 0 {
-                let downgraded =
-                    downgrade_confidence(ledger.confidence, adjustment.confidence_penalty_steps);
-                if downgraded != ledger.confidence {
-                    ledger.confidence = downgraded;
-                }
-                if !adjustment.confidence_notes.is_empty() {
-                    let joined = adjustment.confidence_notes.join("; ");
-                    ledger
-                        .top_evidence
-                        .push(format!("Provenance confidence downgrade: {joined}"));
-                    ledger.why_summary =
-                        format!("{} Provenance caveats: {}.", ledger.why_summary, joined);
-                }
-            }
-
-            for term in &adjustment.evidence_terms {
-                let glyph = if term.feature.contains("blast_radius") {
-                    "🛡"
-                } else {
-                    "🔗"
-                };
-                ledger
-                    .evidence_glyphs
-                    .insert(term.feature.clone(), glyph.to_string());
-            }
-
-            tracing::debug!(
-                pid = proc.pid.0,
-                evidence_completeness = adjustment.evidence_completeness,
-                blast_radius_risk = adjustment.blast_radius.risk_score,
-                blast_radius_confidence = adjustment.blast_radius.confidence,
-                blast_radius_level = ?adjustment.blast_radius.risk_level,
-                confidence_penalty_steps = adjustment.confidence_penalty_steps,
-                score_terms = ?adjustment
-                    .evidence_terms
-                    .iter()
-                    .map(|term| term.feature.clone())
-                    .collect::<Vec<_>>(),
-                notes = ?adjustment.confidence_notes,
-                "Applied provenance-derived scoring adjustments"
-            );
-
-            // Explanation-trace diagnostics: emit per-feature evidence selection
-            // events so provenance decisions can be diagnosed from the trace log.
-            for term in &adjustment.evidence_terms {
-                let net_shift = term.log_likelihood.abandoned - term.log_likelihood.useful;
-                let direction = if net_shift > 0.1 {
-                    "toward_abandon"
-                } else if net_shift < -0.1 {
-                    "toward_useful"
-                } else {
-                    "neutral"
-                };
-                tracing::trace!(
-                    pid = proc.pid.0,
-                    feature = %term.feature,
-                    abandoned_ll = term.log_likelihood.abandoned,
-                    useful_ll = term.log_likelihood.useful,
-                    net_shift = net_shift,
-                    direction = direction,
-                    "provenance_evidence_selected"
-                );
-            }
-
-            if adjustment.confidence_penalty_steps > 0 {
-                tracing::trace!(
-                    pid = proc.pid.0,
-                    steps = adjustment.confidence_penalty_steps,
-                    reasons = ?adjustment.confidence_notes,
-                    evidence_completeness = adjustment.evidence_completeness,
-                    "provenance_confidence_downgraded"
-                );
-            }
-
-            if adjustment.blast_radius.total_affected > 0 {
-                tracing::trace!(
-                    pid = proc.pid.0,
-                    risk_score = adjustment.blast_radius.risk_score,
-                    risk_level = ?adjustment.blast_radius.risk_level,
-                    total_affected = adjustment.blast_radius.total_affected,
-                    confidence = adjustment.blast_radius.confidence,
-                    summary = %adjustment.blast_radius.summary,
-                    "provenance_blast_radius_computed"
-                );
-            }
-
-            adjustment
-        };
+        let provenance_adjustment = scored.provenance;
 
         let signature_name = signature_match.as_ref().map(|m| m.signature.name.clone());
         let signature_level = signature_match
@@ -12034,28 +12222,6 @@ fn run_agent_plan(global: &GlobalOpts, args: &AgentPlanArgs) -> ExitCode {
             .as_ref()
             .map(|m| format!("{:?}", m.signature.category));
 
-        if let Some(sig_match) = signature_match.as_ref() {
-            if !fast_path_used {
-                ledger.top_evidence.insert(
-                    0,
-                    format!(
-                        "Signature match: {} (score={:.2}, level={})",
-                        sig_match.signature.name,
-                        sig_match.score,
-                        match_level_label(sig_match.level)
-                    ),
-                );
-                ledger.why_summary = format!(
-                    "Matched signature '{}' (score {:.2}, level {}, prior source {}). {}",
-                    sig_match.signature.name,
-                    sig_match.score,
-                    match_level_label(sig_match.level),
-                    prior_source_label,
-                    ledger.why_summary
-                );
-            }
-        }
-
         // Apply state-based feasibility constraints so decisioning does not
         // recommend fundamentally invalid actions (e.g., kill for zombie/D-state).
         let state_feasibility = ActionFeasibility::from_process_state(
@@ -12066,19 +12232,29 @@ fn run_agent_plan(global: &GlobalOpts, args: &AgentPlanArgs) -> ExitCode {
         let feasibility = base_feasibility.merge(&state_feasibility);
 
         // Compute decision (optimal action based on expected loss)
-        let mut decision_outcome =
-            match decide_action(&posterior_result.posterior, &decision_policy, &feasibility) {
-                Ok(d) => d,
-                Err(_) => continue, // Skip processes that fail decision
-            };
-        let sequential_probe = pt_core::decision::decide_sequential(
+        let mut decision_outcome = match decide_with_protection(
+            protection.as_ref(),
             &posterior_result.posterior,
             &decision_policy,
             &feasibility,
-            &probe_cost_model,
-            Some(&deep_scan_probe),
-        )
-        .ok();
+        ) {
+            Ok(d) => d,
+            Err(_) => continue, // Skip processes that fail decision
+        };
+        // Probing cannot change a protection-list decision, so skip VOI.
+        let sequential_probe = if protection.is_some() {
+            None
+        } else {
+            pt_core::decision::decide_sequential_with_availability(
+                &posterior_result.posterior,
+                &decision_policy,
+                &feasibility,
+                &probe_cost_model,
+                Some(&deep_scan_probe),
+                &probe_availability,
+            )
+            .ok()
+        };
         decision_outcome.rationale.has_known_signature = Some(signature_match.is_some());
         decision_outcome.rationale.memory_mb = Some(proc.rss_bytes as f64 / (1024.0 * 1024.0));
         decision_outcome.rationale.category = signature_category.clone();
@@ -12100,13 +12276,18 @@ fn run_agent_plan(global: &GlobalOpts, args: &AgentPlanArgs) -> ExitCode {
             Action::Freeze => "freeze",
             Action::Unfreeze => "unfreeze",
             Action::Throttle => "throttle",
+            Action::Unthrottle => "unthrottle",
             Action::Quarantine => "quarantine",
             Action::Unquarantine => "unquarantine",
             Action::Restart => "restart",
             Action::Kill => "kill",
         };
 
-        if let Some(ref mut recorder) = shadow_recorder {
+        if let Some(recorder) = shadow_recorder
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .as_mut()
+        {
             match recorder.record_candidate(proc, posterior, &ledger, &decision_outcome) {
                 Ok(()) => shadow_recorded = shadow_recorded.saturating_add(1),
                 Err(err) => {
@@ -12130,8 +12311,9 @@ fn run_agent_plan(global: &GlobalOpts, args: &AgentPlanArgs) -> ExitCode {
             }
         }
 
-        // Apply threshold filter
-        if max_posterior < args.min_posterior {
+        // Apply threshold filter (flagged processes are always surfaced)
+        let flagged = protection.as_ref().is_some_and(|v| !v.is_protected());
+        if max_posterior < args.min_posterior && !flagged {
             continue;
         }
 
@@ -12208,6 +12390,8 @@ fn run_agent_plan(global: &GlobalOpts, args: &AgentPlanArgs) -> ExitCode {
                 .as_ref()
                 .map(|v| format!("Policy blocked: {}", v.message))
                 .unwrap_or_else(|| "Policy blocked".to_string())
+        } else if let Some(verdict) = &protection {
+            verdict.describe()
         } else {
             format!(
                 "Action {:?} selected{}",
@@ -12325,7 +12509,10 @@ fn run_agent_plan(global: &GlobalOpts, args: &AgentPlanArgs) -> ExitCode {
             "reversibility": match decision_outcome.optimal_action {
                 Action::Kill | Action::Restart => "irreversible",
                 Action::Pause | Action::Freeze | Action::Throttle | Action::Quarantine => "reversible",
-                Action::Resume | Action::Unfreeze | Action::Unquarantine => "reversal",
+                Action::Resume
+                | Action::Unfreeze
+                | Action::Unthrottle
+                | Action::Unquarantine => "reversal",
                 Action::Keep | Action::Renice => "no_action",
             },
             "supervisor": supervisor_info_for_plan(proc.pid.0),
@@ -12420,6 +12607,13 @@ fn run_agent_plan(global: &GlobalOpts, args: &AgentPlanArgs) -> ExitCode {
             provenance_log_odds_shift: None,
         };
 
+        if let Some(writer) = ndjson.as_mut() {
+            if let Err(e) = writer.write_record(&global.process_output_value(candidate.clone())) {
+                ndjson = None;
+                ndjson_error = Some(e);
+            }
+        }
+
         // Store candidate with max_posterior for sorting (no early break!)
         all_candidates.push((max_posterior, candidate, persisted_proc, persisted_inf));
     }
@@ -12441,10 +12635,8 @@ fn run_agent_plan(global: &GlobalOpts, args: &AgentPlanArgs) -> ExitCode {
         );
     }
 
-    if let Some(ref mut recorder) = shadow_recorder {
-        if let Err(err) = recorder.flush() {
-            eprintln!("shadow mode: failed to flush storage: {:?}", err);
-        }
+    for (name, err) in shutdown.shutdown().failed {
+        eprintln!("agent plan: failed to finalize {}: {}", name, err);
     }
 
     // Sort candidates by max_posterior descending (highest confidence first)
@@ -12917,6 +13109,9 @@ fn run_agent_plan(global: &GlobalOpts, args: &AgentPlanArgs) -> ExitCode {
             };
             println!("{}", encode_toon_value(&output_value));
         }
+        OutputFormat::Yaml => {
+            println!("{}", format_structured_output(global, plan_output.clone()));
+        }
         OutputFormat::Summary => {
             println!(
                 "[{}] agent plan: {} candidates ({} kill, {} review)",
@@ -12927,6 +13122,19 @@ fn run_agent_plan(global: &GlobalOpts, args: &AgentPlanArgs) -> ExitCode {
             );
         }
         OutputFormat::Exitcode => {}
+        OutputFormat::Html => {
+            print!("{}", pt_core::output::html::render_plan_html(&plan_output));
+        }
+        OutputFormat::Csv => {
+            print!("{}", pt_common::to_csv_rows(&candidates, true));
+        }
+        OutputFormat::Ndjson => {
+            // Candidates were already streamed during classification.
+            if let Some(e) = ndjson_error {
+                eprintln!("agent plan: failed to write output: {}", e);
+                return ExitCode::IoError;
+            }
+        }
         _ => {
             println!("# pt-core agent plan\n");
             println!("Session: {}", session_id);
@@ -12996,9 +13204,11 @@ fn run_agent_explain(global: &GlobalOpts, args: &AgentExplainArgs) -> ExitCode {
         }
     };
 
+    let probe_policy = args.probe.then(|| load_policy_for_explain(global));
+
     // Determine which PIDs to explain
     let pids_to_explain: Vec<u32> = if !args.pids.is_empty() {
-        args.pids.clone()
+        flatten_pid_lists(&args.pids)
     } else if let Some(ref target) = args.target {
         // Parse target format "pid:start_id" and extract PID
         match target.split(':').next().and_then(|s| s.parse::<u32>().ok()) {
@@ -13036,7 +13246,8 @@ fn run_agent_explain(global: &GlobalOpts, args: &AgentExplainArgs) -> ExitCode {
         let record = scan_result.processes.iter().find(|p| p.pid.0 == *pid);
         match record {
             Some(proc) => {
-                let explanation = build_process_explanation(proc, &priors, args);
+                let explanation =
+                    build_process_explanation(proc, &priors, probe_policy.as_ref(), args);
                 explanations.push(explanation);
             }
             None => {
@@ -13165,21 +13376,29 @@ fn run_agent_explain(global: &GlobalOpts, args: &AgentExplainArgs) -> ExitCode {
 
 /// Load priors from config with fallback to defaults.
 fn load_priors_for_explain(global: &GlobalOpts) -> Result<Priors, ConfigError> {
-    let opts = ConfigOptions {
-        config_dir: global.config.as_ref().map(PathBuf::from),
-        priors_path: None,
-        policy_path: None,
-    };
+    let opts = config_options(global);
     match load_config(&opts) {
         Ok(resolved) => Ok(resolved.priors),
         Err(_) => Ok(Priors::default()),
     }
 }
 
+/// Load policy from config with fallback to defaults.
+fn load_policy_for_explain(global: &GlobalOpts) -> pt_core::config::Policy {
+    let opts = config_options(global);
+    load_config(&opts)
+        .map(|resolved| resolved.policy)
+        .unwrap_or_default()
+}
+
 /// Build a JSON explanation for a single process.
+///
+/// With `probe_policy`, the probes VOI recommends are run until acting is
+/// optimal and the resulting trace and decision are included.
 fn build_process_explanation(
     proc: &ProcessRecord,
     priors: &Priors,
+    probe_policy: Option<&pt_core::config::Policy>,
     args: &AgentExplainArgs,
 ) -> serde_json::Value {
     // Convert ProcessRecord to Evidence
@@ -13263,22 +13482,25 @@ fn build_process_explanation(
         });
     }
 
-    explanation
-}
-
-/// Map ProcessState to state flag index for priors.
-fn state_to_flag(state: pt_core::collect::ProcessState) -> Option<usize> {
-    use pt_core::collect::ProcessState;
-    match state {
-        ProcessState::Running => Some(0),
-        ProcessState::Sleeping => Some(1),
-        ProcessState::DiskSleep => Some(2),
-        ProcessState::Zombie => Some(3),
-        ProcessState::Stopped => Some(4),
-        ProcessState::Idle => Some(5),
-        ProcessState::Dead => Some(6),
-        ProcessState::Unknown => None,
+    if let Some(policy) = probe_policy {
+        let mut scheduler = pt_core::decision::ProbeScheduler::new()
+            .with_default_collectors(priors)
+            .with_availability(pt_core::decision::ProbeAvailability::from_capabilities(
+                &get_capabilities(),
+            ));
+        explanation["probe_schedule"] = match scheduler.run(
+            proc.pid.0,
+            posterior_result.clone(),
+            policy,
+            &ActionFeasibility::allow_all(),
+            &pt_core::decision::ProbeCostModel::default(),
+        ) {
+            Ok(schedule) => serde_json::to_value(&schedule).unwrap_or_default(),
+            Err(e) => serde_json::json!({ "error": e.to_string() }),
+        };
     }
+
+    explanation
 }
 
 #[cfg(target_os = "linux")]
@@ -13429,6 +13651,31 @@ fn is_supervised_for_robot(_pid: u32) -> bool {
     false
 }
 
+/// Name and command line of a process about to be killed, for pattern learning.
+#[cfg(target_os = "linux")]
+fn pending_kill_decision(pid: u32) -> Option<pt_core::supervision::PendingDecision> {
+    let comm = std::fs::read_to_string(format!("/proc/{pid}/comm")).ok()?;
+    let cmdline = std::fs::read(format!("/proc/{pid}/cmdline")).ok()?;
+    Some(pt_core::supervision::PendingDecision {
+        process_name: comm.trim().to_string(),
+        cmdline: String::from_utf8_lossy(&cmdline)
+            .replace('\0', " ")
+            .trim()
+            .to_string(),
+        killed: true,
+    })
+}
+
+/// Directory holding the pattern library (`--config`, else the XDG config dir).
+fn pattern_library_dir(global: &GlobalOpts) -> PathBuf {
+    global
+        .config
+        .as_ref()
+        .map(PathBuf::from)
+        .or_else(|| dirs::config_dir().map(|d| d.join("process_triage")))
+        .unwrap_or_else(|| PathBuf::from("."))
+}
+
 #[cfg(target_os = "linux")]
 fn first_precheck_block(
     provider: &dyn pt_core::action::prechecks::PreCheckProvider,
@@ -13598,7 +13845,7 @@ fn run_agent_apply(global: &GlobalOpts, args: &AgentApplyArgs) -> ExitCode {
         Ok(cfg) => cfg,
         Err(e) => {
             eprintln!("agent apply: config error: {}", e);
-            return ExitCode::InternalError;
+            return ExitCode::ConfigError;
         }
     };
 
@@ -13685,7 +13932,7 @@ fn run_agent_apply(global: &GlobalOpts, args: &AgentApplyArgs) -> ExitCode {
             .map(|a| a.target.pid.0)
             .collect()
     } else if !args.pids.is_empty() {
-        args.pids.clone()
+        flatten_pid_lists(&args.pids)
     } else if !args.targets.is_empty() {
         args.targets
             .iter()
@@ -13925,10 +14172,29 @@ fn run_agent_apply(global: &GlobalOpts, args: &AgentApplyArgs) -> ExitCode {
     let mut outcomes: Vec<serde_json::Value> = Vec::new();
     let mut succeeded = 0usize;
     let mut failed = 0usize;
+    let mut permission_denied = 0usize;
     let mut skipped = 0usize;
     let mut blocked_by_constraints = 0usize;
     let mut blocked_by_prechecks = 0usize;
     let mut resumed_skipped = 0usize;
+    let mut aborted = false;
+    let mut governor_tripped = false;
+
+    // Audit every attempt the way ActionExecutor does, dry runs included.
+    let audit_sink = ParquetAuditSink::new(pt_telemetry::WriterConfig::new(
+        default_telemetry_dir(),
+        sid.0.clone(),
+        pt_core::logging::get_host_id(),
+    ));
+    let record_audit = |record: ActionAuditRecord| {
+        let record = record.with_dry_run(global.dry_run || global.shadow);
+        if let Err(e) = audit_sink.record(&record) {
+            tracing::warn!(action_id = %record.action_id, error = %e, "failed to record audit entry");
+        }
+    };
+    let audit = |action: &PlanAction, status: ActionStatus, reason: Option<String>| {
+        record_audit(ActionAuditRecord::new(action, status).with_reason(reason));
+    };
 
     // Handle dry-run/shadow mode or execute
     if global.dry_run || global.shadow {
@@ -14031,6 +14297,11 @@ fn run_agent_apply(global: &GlobalOpts, args: &AgentApplyArgs) -> ExitCode {
 
             skipped += 1;
             outcomes.push(serde_json::json!({"action_id": action.action_id, "pid": action.target.pid.0, "status": if global.dry_run { "dry_run" } else { "shadow" }}));
+            audit(
+                action,
+                ActionStatus::Skipped,
+                Some(if global.dry_run { "dry_run" } else { "shadow" }.to_string()),
+            );
             emit_action_event(
                 pt_core::events::event_names::ACTION_COMPLETE,
                 action_index,
@@ -14045,9 +14316,53 @@ fn run_agent_apply(global: &GlobalOpts, args: &AgentApplyArgs) -> ExitCode {
         {
             let identity_provider = LiveIdentityProvider::new();
             let signal_runner = SignalActionRunner::new(SignalConfig::default());
+            // Kills are learned from once the run winds down, under the library lock.
+            let pattern_library = std::sync::Mutex::new(pt_core::supervision::PatternLibrary::new(
+                pattern_library_dir(global),
+            ));
+            let pattern_decisions = std::sync::Mutex::new(Vec::new());
+            // First SIGINT/SIGTERM stops new actions; outcomes are still written below.
+            let _signal_guard = pt_core::shutdown::install_signal_handlers();
+            let mut shutdown =
+                pt_core::shutdown::ShutdownCoordinator::new(&pt_core::shutdown::SHUTDOWN_SIGNAL);
+            shutdown.register_pattern_library(&pattern_library, &pattern_decisions);
+            let mode = if args.abort_on_unknown {
+                ExecutionMode::StopOnFirstError
+            } else {
+                ExecutionMode::ContinueOnError
+            };
+            let mut budget =
+                SafetyGovernor::from_guardrails(&config.policy.guardrails).plan_budget(&plan);
 
             for action in &actions_to_apply {
                 action_index = action_index.saturating_add(1);
+                if shutdown.should_stop() {
+                    outcomes.push(serde_json::json!({
+                        "action_id": action.action_id,
+                        "pid": action.target.pid.0,
+                        "status": "skipped_shutdown"
+                    }));
+                    audit(
+                        action,
+                        ActionStatus::Skipped,
+                        Some("shutdown requested".to_string()),
+                    );
+                    continue;
+                }
+                if aborted {
+                    skipped += 1;
+                    outcomes.push(serde_json::json!({
+                        "action_id": action.action_id,
+                        "pid": action.target.pid.0,
+                        "status": ABORTED_REASON
+                    }));
+                    audit(
+                        action,
+                        ActionStatus::Skipped,
+                        Some(ABORTED_REASON.to_string()),
+                    );
+                    continue;
+                }
                 emit_action_event(
                     pt_core::events::event_names::ACTION_STARTED,
                     action_index,
@@ -14092,9 +14407,11 @@ fn run_agent_apply(global: &GlobalOpts, args: &AgentApplyArgs) -> ExitCode {
                         "blocked_by_plan",
                         &[],
                     );
-                    if args.abort_on_unknown {
-                        break;
-                    }
+                    audit(
+                        action,
+                        ActionStatus::Skipped,
+                        Some("blocked by plan".to_string()),
+                    );
                     continue;
                 }
 
@@ -14121,9 +14438,13 @@ fn run_agent_apply(global: &GlobalOpts, args: &AgentApplyArgs) -> ExitCode {
                         "blocked_by_constraints",
                         &[],
                     );
-                    if args.abort_on_unknown {
-                        break;
-                    }
+                    let reason = check
+                        .violations
+                        .iter()
+                        .map(|v| v.message.as_str())
+                        .collect::<Vec<_>>()
+                        .join("; ");
+                    audit(action, ActionStatus::Skipped, Some(reason));
                     continue;
                 }
                 match identity_provider.revalidate(&action.target) {
@@ -14140,12 +14461,11 @@ fn run_agent_apply(global: &GlobalOpts, args: &AgentApplyArgs) -> ExitCode {
                             "identity_mismatch",
                             &[],
                         );
-                        if args.abort_on_unknown {
-                            break;
-                        }
+                        aborted = mode.stops_after(&ActionStatus::IdentityMismatch);
+                        audit(action, ActionStatus::IdentityMismatch, None);
                         continue;
                     }
-                    Err(_) => {
+                    Err(e) => {
                         failed += 1;
                         let elapsed_ms = start.elapsed().as_millis() as u64;
                         outcomes.push(serde_json::json!({"action_id": action.action_id, "pid": action.target.pid.0, "status": "identity_check_failed", "time_ms": elapsed_ms}));
@@ -14157,9 +14477,8 @@ fn run_agent_apply(global: &GlobalOpts, args: &AgentApplyArgs) -> ExitCode {
                             "identity_check_failed",
                             &[],
                         );
-                        if args.abort_on_unknown {
-                            break;
-                        }
+                        aborted = mode.stops_after(&ActionStatus::IdentityMismatch);
+                        audit(action, ActionStatus::IdentityMismatch, Some(e.to_string()));
                         continue;
                     }
                 }
@@ -14182,16 +14501,52 @@ fn run_agent_apply(global: &GlobalOpts, args: &AgentApplyArgs) -> ExitCode {
                         "precheck_blocked",
                         &[("check", serde_json::json!(precheck_label_for_apply(&check)))],
                     );
-                    if args.abort_on_unknown {
-                        break;
-                    }
+                    let status = ActionStatus::PreCheckBlocked { check, reason };
+                    aborted = mode.stops_after(&status);
+                    audit(action, status, None);
+                    continue;
+                }
+                if let Err(reason) = budget.admit(action) {
+                    governor_tripped = true;
+                    skipped += 1;
+                    let elapsed_ms = start.elapsed().as_millis() as u64;
+                    outcomes.push(serde_json::json!({
+                        "action_id": action.action_id,
+                        "pid": action.target.pid.0,
+                        "status": "governor_tripped",
+                        "reason": reason,
+                        "time_ms": elapsed_ms
+                    }));
+                    emit_action_event(
+                        pt_core::events::event_names::ACTION_COMPLETE,
+                        action_index,
+                        Some(elapsed_ms),
+                        action,
+                        "governor_tripped",
+                        &[],
+                    );
+                    record_audit(
+                        ActionAuditRecord::new(action, ActionStatus::Skipped)
+                            .with_applied_action(Action::Keep)
+                            .with_reason(Some(reason)),
+                    );
                     continue;
                 }
+                // Captured up front: a killed process has no /proc entry left.
+                let decision = (action.action == Action::Kill)
+                    .then(|| pending_kill_decision(action.target.pid.0))
+                    .flatten();
                 match signal_runner.execute(action) {
                     Ok(()) => {
                         if action.action == Action::Kill {
                             checker.record_action(0, true);
                         }
+                        if let Some(decision) = decision {
+                            pattern_decisions
+                                .lock()
+                                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                                .push(decision);
+                        }
                         succeeded += 1;
                         let elapsed_ms = start.elapsed().as_millis() as u64;
                         outcomes.push(serde_json::json!({"action_id": action.action_id, "pid": action.target.pid.0, "status": "success", "time_ms": elapsed_ms}));
@@ -14203,9 +14558,16 @@ fn run_agent_apply(global: &GlobalOpts, args: &AgentApplyArgs) -> ExitCode {
                             "success",
                             &[],
                         );
+                        record_audit(
+                            ActionAuditRecord::new(action, ActionStatus::Success)
+                                .with_observed(identity_provider.observe(&action.target)),
+                        );
                     }
                     Err(e) => {
                         failed += 1;
+                        if matches!(e, pt_core::action::ActionError::PermissionDenied) {
+                            permission_denied += 1;
+                        }
                         let elapsed_ms = start.elapsed().as_millis() as u64;
                         outcomes.push(serde_json::json!({"action_id": action.action_id, "pid": action.target.pid.0, "status": "failed", "error": format!("{:?}", e), "time_ms": elapsed_ms}));
                         emit_action_event(
@@ -14216,9 +14578,14 @@ fn run_agent_apply(global: &GlobalOpts, args: &AgentApplyArgs) -> ExitCode {
                             "failed",
                             &[("error", serde_json::json!(format!("{:?}", e)))],
                         );
-                        if args.abort_on_unknown {
-                            break;
-                        }
+                        let reason = Some(e.to_string());
+                        let status: ActionStatus = e.into();
+                        aborted = mode.stops_after(&status);
+                        record_audit(
+                            ActionAuditRecord::new(action, status)
+                                .with_reason(reason)
+                                .with_observed(identity_provider.observe(&action.target)),
+                        );
                     }
                 }
             }
@@ -14269,6 +14636,10 @@ fn run_agent_apply(global: &GlobalOpts, args: &AgentApplyArgs) -> ExitCode {
         }
     }
 
+    if let Err(e) = audit_sink.close() {
+        tracing::warn!(error = %e, "failed to finalize action audit log");
+    }
+
     let after_scan_processes = quick_scan(&goal_progress_scan_options)
         .map(|scan| scan.processes)
         .unwrap_or_else(|_| Vec::new());
@@ -14465,7 +14836,9 @@ fn run_agent_apply(global: &GlobalOpts, args: &AgentApplyArgs) -> ExitCode {
             "skipped": skipped,
             "blocked_by_constraints": blocked_by_constraints,
             "blocked_by_prechecks": blocked_by_prechecks,
-            "resumed_skipped": resumed_skipped
+            "resumed_skipped": resumed_skipped,
+            "aborted": aborted,
+            "governor_tripped": governor_tripped
         },
         "outcomes": outcomes,
         "goal_progress": goal_progress_payload,
@@ -14510,6 +14883,8 @@ fn run_agent_apply(global: &GlobalOpts, args: &AgentApplyArgs) -> ExitCode {
 
     if (blocked_by_constraints + blocked_by_prechecks) > 0 && succeeded == 0 && failed == 0 {
         ExitCode::PolicyBlocked
+    } else if failed > 0 && failed == permission_denied && succeeded == 0 {
+        ExitCode::PermissionError
     } else if failed > 0 {
         ExitCode::PartialFail
     } else {
@@ -15668,11 +16043,7 @@ fn run_agent_list_priors(global: &GlobalOpts, args: &AgentListPriorsArgs) -> Exi
     let host_id = pt_core::logging::get_host_id();
 
     // Build config options from global opts
-    let options = ConfigOptions {
-        config_dir: global.config.as_ref().map(PathBuf::from),
-        priors_path: None,
-        policy_path: None,
-    };
+    let options = config_options(global);
 
     // Load configuration
     let config = match load_config(&options) {
@@ -15873,11 +16244,7 @@ fn run_agent_list_priors(global: &GlobalOpts, args: &AgentListPriorsArgs) -> Exi
 fn run_agent_export_priors(global: &GlobalOpts, args: &AgentExportPriorsArgs) -> ExitCode {
     let host_id = pt_core::logging::get_host_id();
 
-    let options = ConfigOptions {
-        config_dir: global.config.as_ref().map(PathBuf::from),
-        priors_path: None,
-        policy_path: None,
-    };
+    let options = config_options(global);
 
     let config = match load_config(&options) {
         Ok(c) => c,
@@ -16016,11 +16383,7 @@ fn run_agent_import_priors(global: &GlobalOpts, args: &AgentImportPriorsArgs) ->
     };
 
     // Load current config
-    let options = ConfigOptions {
-        config_dir: global.config.as_ref().map(PathBuf::from),
-        priors_path: None,
-        policy_path: None,
-    };
+    let options = config_options(global);
 
     let config = match load_config(&options) {
         Ok(c) => c,
@@ -16814,15 +17177,12 @@ fn run_agent_watch(global: &GlobalOpts, args: &AgentWatchArgs) -> ExitCode {
         }
     };
 
-    let config_options = ConfigOptions {
-        config_dir: global.config.as_ref().map(PathBuf::from),
-        ..Default::default()
-    };
+    let config_options = config_options(global);
     let config = match load_config(&config_options) {
         Ok(config) => config,
         Err(err) => {
             eprintln!("agent watch: config error: {}", err);
-            return ExitCode::InternalError;
+            return ExitCode::ConfigError;
         }
     };
     let priors = config.priors;
@@ -17275,154 +17635,6 @@ mod watch_tests {
     }
 }
 
-#[cfg(all(test, target_os = "linux"))]
-mod provenance_scoring_tests {
-    use super::*;
-    use pt_common::{
-        AncestorEntry, LineageCollectionMethod, LockMechanism, RawResourceEvidence,
-        ResourceCollectionMethod, ResourceDetails, ResourceKind, ResourceState, SupervisorEvidence,
-        SupervisorKind,
-    };
-
-    fn lock_ev(pid: u32, path: &str, state: ResourceState) -> RawResourceEvidence {
-        RawResourceEvidence {
-            kind: ResourceKind::Lockfile,
-            key: path.to_string(),
-            owner_pid: pid,
-            collection_method: ResourceCollectionMethod::ProcFd,
-            state,
-            details: ResourceDetails::Lockfile {
-                path: path.to_string(),
-                mechanism: LockMechanism::Existence,
-            },
-            observed_at: "2026-03-17T00:00:00Z".to_string(),
-        }
-    }
-
-    fn listener_ev(pid: u32, port: u16) -> RawResourceEvidence {
-        RawResourceEvidence {
-            kind: ResourceKind::Listener,
-            key: format!("tcp:0.0.0.0:{port}"),
-            owner_pid: pid,
-            collection_method: ResourceCollectionMethod::ProcNet,
-            state: ResourceState::Active,
-            details: ResourceDetails::Listener {
-                protocol: "tcp".to_string(),
-                port,
-                bind_address: "0.0.0.0".to_string(),
-            },
-            observed_at: "2026-03-17T00:00:00Z".to_string(),
-        }
-    }
-
-    fn lineage(pid: u32, ppid: u32, supervisor: Option<SupervisorEvidence>) -> RawLineageEvidence {
-        RawLineageEvidence {
-            pid,
-            ppid,
-            pgid: pid,
-            sid: pid,
-            uid: 1000,
-            user: Some("ubuntu".to_string()),
-            tty: None,
-            supervisor,
-            ancestors: if ppid > 1 {
-                vec![AncestorEntry {
-                    pid: ppid,
-                    comm: "bash".to_string(),
-                    uid: 1000,
-                }]
-            } else {
-                Vec::new()
-            },
-            collection_method: LineageCollectionMethod::Synthetic,
-            observed_at: "2026-03-17T00:00:00Z".to_string(),
-        }
-    }
-
-    fn feature_names(adjustment: &ProvenanceScoreAdjustment) -> Vec<&str> {
-        adjustment
-            .evidence_terms
-            .iter()
-            .map(|term| term.feature.as_str())
-            .collect()
-    }
-
-    #[test]
-    fn orphaned_low_blast_radius_elevates_abandonment_features() {
-        let bundle = ProvenanceInferenceBundle {
-            resource_graph: SharedResourceGraph::from_evidence(&[]),
-            lineages: HashMap::from([(100, lineage(100, 1, None))]),
-            children: HashMap::new(),
-        };
-
-        let adjustment = derive_provenance_adjustment(100, &bundle);
-        let features = feature_names(&adjustment);
-
-        assert!(features.contains(&"provenance_ownership_orphaned"));
-        assert!(features.contains(&"provenance_blast_radius_low"));
-        assert!(adjustment.confidence_penalty_steps >= 1);
-        assert!(adjustment
-            .confidence_notes
-            .iter()
-            .any(|note| note.contains("PPID=1") || note.contains("ancestor chain")));
-    }
-
-    #[test]
-    fn supervised_listener_suppresses_false_positive_path() {
-        let bundle = ProvenanceInferenceBundle {
-            resource_graph: SharedResourceGraph::from_evidence(&[(
-                200,
-                vec![listener_ev(200, 8080)],
-            )]),
-            lineages: HashMap::from([(
-                200,
-                lineage(
-                    200,
-                    2,
-                    Some(SupervisorEvidence {
-                        kind: SupervisorKind::Systemd,
-                        unit_name: Some("api.service".to_string()),
-                        auto_restart: Some(true),
-                        confidence: ProvenanceConfidence::High,
-                    }),
-                ),
-            )]),
-            children: HashMap::new(),
-        };
-
-        let adjustment = derive_provenance_adjustment(200, &bundle);
-        let features = feature_names(&adjustment);
-
-        assert!(features.contains(&"provenance_ownership_supervised"));
-        assert!(features.contains(&"provenance_active_listener"));
-        assert!(adjustment.evidence_completeness >= 0.8);
-    }
-
-    #[test]
-    fn missing_and_conflicted_provenance_downgrades_confidence() {
-        let bundle = ProvenanceInferenceBundle {
-            resource_graph: SharedResourceGraph::from_evidence(&[(
-                300,
-                vec![lock_ev(300, "/tmp/shared.lock", ResourceState::Conflicted)],
-            )]),
-            lineages: HashMap::new(),
-            children: HashMap::new(),
-        };
-
-        let adjustment = derive_provenance_adjustment(300, &bundle);
-
-        assert!(adjustment.confidence_penalty_steps >= 2);
-        assert!(adjustment
-            .confidence_notes
-            .iter()
-            .any(|note| note.contains("missing lineage provenance")));
-        assert!(adjustment
-            .confidence_notes
-            .iter()
-            .any(|note| note.contains("unresolved edge")));
-    }
-}
-
 #[cfg(all(test, feature = "ui"))]
 mod queue_metrics_tests {
     use super::*;
@@ -6,6 +6,8 @@
 pub mod protocol;
 pub mod resources;
 pub mod server;
+pub mod subscriptions;
 pub mod tools;
+pub mod validation;
 
 pub use server::McpServer;
@@ -24,6 +24,24 @@ pub struct JsonRpcResponse {
     pub error: Option<JsonRpcError>,
 }
 
+/// JSON-RPC 2.0 notification (server → client, no id).
+#[derive(Debug, Clone, Serialize)]
+pub struct JsonRpcNotification {
+    pub jsonrpc: String,
+    pub method: String,
+    pub params: serde_json::Value,
+}
+
+impl JsonRpcNotification {
+    pub fn new(method: impl Into<String>, params: serde_json::Value) -> Self {
+        Self {
+            jsonrpc: "2.0".to_string(),
+            method: method.into(),
+            params,
+        }
+    }
+}
+
 /// JSON-RPC 2.0 error object.
 #[derive(Debug, Serialize)]
 pub struct JsonRpcError {
@@ -94,6 +112,8 @@ pub struct ToolsCapability {
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ResourcesCapability {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscribe: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub list_changed: Option<bool>,
 }
@@ -224,6 +244,18 @@ mod tests {
         assert!(json.get("resources").is_none());
     }
 
+    #[test]
+    fn notification_serialize_has_no_id() {
+        let note = JsonRpcNotification::new(
+            "notifications/resources/updated",
+            serde_json::json!({"uri": "pt://processes/current"}),
+        );
+        let json = serde_json::to_value(&note).unwrap();
+        assert_eq!(json["jsonrpc"], "2.0");
+        assert_eq!(json["params"]["uri"], "pt://processes/current");
+        assert!(json.get("id").is_none());
+    }
+
     #[test]
     fn tool_content_serialize() {
         let content = ToolContent {
@@ -1,8 +1,10 @@
 //! MCP resource implementations.
 //!
-//! Resources expose read-only data: configuration, signatures, version info.
+//! Resources expose read-only data: configuration, signatures, version info,
+//! and the live process list (which clients may subscribe to).
 
 use crate::mcp::protocol::{ResourceContent, ResourceDefinition};
+use crate::mcp::subscriptions::{HIGH_RISK_SCORE, PROCESS_LIST_URI};
 
 /// Build the list of available MCP resource definitions.
 pub fn resource_definitions() -> Vec<ResourceDefinition> {
@@ -25,6 +27,14 @@ pub fn resource_definitions() -> Vec<ResourceDefinition> {
             description: "Built-in process signature library.".to_string(),
             mime_type: Some("application/json".to_string()),
         },
+        ResourceDefinition {
+            uri: PROCESS_LIST_URI.to_string(),
+            name: "Current Processes".to_string(),
+            description: "Live process list with high-risk processes highlighted. \
+                          Supports resources/subscribe for change notifications."
+                .to_string(),
+            mime_type: Some("application/json".to_string()),
+        },
         ResourceDefinition {
             uri: "pt://version".to_string(),
             name: "Version Info".to_string(),
@@ -41,6 +51,7 @@ pub fn read_resource(uri: &str) -> Result<Vec<ResourceContent>, String> {
         "pt://config/policy" => resource_policy(uri),
         "pt://signatures/builtin" => resource_signatures_builtin(uri),
         "pt://version" => resource_version(uri),
+        PROCESS_LIST_URI => resource_processes(uri),
         _ => Err(format!("Unknown resource URI: {}", uri)),
     }
 }
@@ -115,6 +126,47 @@ fn resource_signatures_builtin(uri: &str) -> Result<Vec<ResourceContent>, String
     }])
 }
 
+fn resource_processes(uri: &str) -> Result<Vec<ResourceContent>, String> {
+    let options = crate::collect::QuickScanOptions::default();
+    let scan = crate::collect::quick_scan(&options).map_err(|e| format!("Scan failed: {}", e))?;
+    let config = crate::config::load_config(&crate::config::ConfigOptions::default())
+        .map_err(|e| format!("Config error: {}", e))?;
+    let db = super::tools::load_signature_db_with_user_entries();
+    let mut risks =
+        super::tools::score_process_risks(&scan.processes, &db, &config.priors, &config.policy);
+
+    let high_risk: Vec<serde_json::Value> = scan
+        .processes
+        .iter()
+        .filter_map(|p| {
+            let (score, top_signature) = risks.remove(&p.pid.0)?;
+            (score >= HIGH_RISK_SCORE).then(|| {
+                serde_json::json!({
+                    "pid": p.pid.0,
+                    "comm": p.comm,
+                    "state": format!("{}", p.state),
+                    "score": score,
+                    "top_signature": top_signature,
+                })
+            })
+        })
+        .collect();
+
+    let result = serde_json::json!({
+        "scanned_at": scan.metadata.started_at,
+        "total_processes": scan.processes.len(),
+        "high_risk_count": high_risk.len(),
+        "high_risk": high_risk,
+    });
+
+    Ok(vec![ResourceContent {
+        uri: uri.to_string(),
+        mime_type: Some("application/json".to_string()),
+        text: serde_json::to_string_pretty(&result)
+            .map_err(|e| format!("Serialization error: {}", e))?,
+    }])
+}
+
 fn resource_version(uri: &str) -> Result<Vec<ResourceContent>, String> {
     let result = serde_json::json!({
         "name": "process_triage",
@@ -205,6 +257,6 @@ mod tests {
     #[test]
     fn resource_definitions_count() {
         let defs = resource_definitions();
-        assert_eq!(defs.len(), 4);
+        assert_eq!(defs.len(), 5);
     }
 }
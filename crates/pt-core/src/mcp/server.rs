@@ -1,28 +1,71 @@
 //! MCP server implementation with stdio transport.
 //!
 //! Reads JSON-RPC 2.0 messages from stdin, dispatches to handlers,
-//! and writes responses to stdout.
+//! and writes responses to stdout. Resource update notifications from the
+//! subscription watcher share stdout, one complete line per message.
 
+use crate::collect::{quick_scan, QuickScanOptions};
+use crate::config::{load_config, ConfigOptions};
 use crate::mcp::protocol::*;
 use crate::mcp::resources;
+use crate::mcp::subscriptions::{
+    self, Notifier, RiskScorer, Scanner, SubscriptionWatcher, DEFAULT_SCAN_INTERVAL,
+    DEFAULT_UPDATE_INTERVAL,
+};
 use crate::mcp::tools;
+use crate::mcp::validation;
+use std::collections::BTreeSet;
 use std::io::{self, BufRead, Write};
+use std::sync::Arc;
+use std::time::Duration;
 
 /// MCP server state.
 pub struct McpServer {
     initialized: bool,
+    subscriptions: BTreeSet<String>,
+    watcher: Option<SubscriptionWatcher>,
+    scan_interval: Duration,
+    update_interval: Duration,
+    scanner: Scanner,
+    notifier: Notifier,
 }
 
 impl McpServer {
     pub fn new() -> Self {
-        Self { initialized: false }
+        Self {
+            initialized: false,
+            subscriptions: BTreeSet::new(),
+            watcher: None,
+            scan_interval: DEFAULT_SCAN_INTERVAL,
+            update_interval: DEFAULT_UPDATE_INTERVAL,
+            scanner: Arc::new(|| {
+                quick_scan(&QuickScanOptions::default())
+                    .map(|scan| scan.processes)
+                    .map_err(|e| format!("Scan failed: {}", e))
+            }),
+            notifier: Arc::new(|notification| {
+                if let Ok(json) = serde_json::to_string(&notification) {
+                    let _ = write_line(&json);
+                }
+            }),
+        }
+    }
+
+    /// Set the minimum spacing between resource update notifications.
+    pub fn with_update_interval(mut self, interval: Duration) -> Self {
+        self.update_interval = interval;
+        self
+    }
+
+    /// Set how often the subscription watcher rescans processes.
+    pub fn with_scan_interval(mut self, interval: Duration) -> Self {
+        self.scan_interval = interval;
+        self
     }
 
     /// Run the stdio event loop: read lines from stdin, dispatch, write to stdout.
     pub fn run_stdio(&mut self) -> io::Result<()> {
         let stdin = io::stdin();
-        let stdout = io::stdout();
-        let mut stdout = stdout.lock();
 
         for line in stdin.lock().lines() {
             let line = line?;
@@ -39,11 +82,11 @@ impl McpServer {
                     r#"{"jsonrpc":"2.0","error":{"code":-32603,"message":"Serialization failed"}}"#
                         .to_string()
                 });
-                writeln!(stdout, "{}", json)?;
-                stdout.flush()?;
+                write_line(&json)?;
             }
         }
 
+        self.stop_watcher();
         Ok(())
     }
 
@@ -74,6 +117,8 @@ impl McpServer {
             "tools/call" => self.handle_tools_call(&request.params),
             "resources/list" => self.handle_resources_list(),
             "resources/read" => self.handle_resources_read(&request.params),
+            "resources/subscribe" => self.handle_resources_subscribe(&request.params),
+            "resources/unsubscribe" => self.handle_resources_unsubscribe(&request.params),
             "ping" => Ok(serde_json::json!({})),
             _ => Err((
                 METHOD_NOT_FOUND,
@@ -110,7 +155,10 @@ impl McpServer {
             "protocolVersion": MCP_PROTOCOL_VERSION,
             "capabilities": ServerCapabilities {
                 tools: Some(ToolsCapability { list_changed: None }),
-                resources: Some(ResourcesCapability { list_changed: None }),
+                resources: Some(ResourcesCapability {
+                    subscribe: Some(true),
+                    list_changed: None,
+                }),
             },
             "serverInfo": ServerInfo {
                 name: "process_triage".to_string(),
@@ -138,6 +186,14 @@ impl McpServer {
             .cloned()
             .unwrap_or(serde_json::json!({}));
 
+        if let Some(def) = tools::tool_definitions()
+            .into_iter()
+            .find(|d| d.name == name)
+        {
+            validation::validate_arguments(&def.input_schema, &arguments)
+                .map_err(|msg| (INVALID_PARAMS, format!("{}: {}", name, msg)))?;
+        }
+
         match tools::call_tool(name, &arguments) {
             Ok(content) => Ok(serde_json::json!({
                 "content": content,
@@ -174,6 +230,74 @@ impl McpServer {
     }
 }
 
+impl McpServer {
+    fn handle_resources_subscribe(
+        &mut self,
+        params: &serde_json::Value,
+    ) -> Result<serde_json::Value, (i32, String)> {
+        let uri = params.get("uri").and_then(|v| v.as_str()).ok_or((
+            INVALID_PARAMS,
+            "Missing 'uri' in resources/subscribe".to_string(),
+        ))?;
+        if !subscriptions::is_subscribable(uri) {
+            return Err((
+                INVALID_PARAMS,
+                format!("Resource does not support subscriptions: {}", uri),
+            ));
+        }
+
+        self.subscriptions.insert(uri.to_string());
+        if self.watcher.is_none() {
+            let config = load_config(&ConfigOptions::default())
+                .map_err(|e| (INTERNAL_ERROR, format!("Config error: {}", e)))?;
+            let db = tools::load_signature_db_with_user_entries();
+            let score: RiskScorer = Arc::new(move |processes| {
+                tools::score_process_risks(processes, &db, &config.priors, &config.policy)
+                    .into_iter()
+                    .map(|(pid, (score, _))| (pid, score))
+                    .collect()
+            });
+            self.watcher = Some(SubscriptionWatcher::spawn(
+                self.scan_interval,
+                self.update_interval,
+                Arc::clone(&self.scanner),
+                score,
+                Arc::clone(&self.notifier),
+            ));
+        }
+        Ok(serde_json::json!({}))
+    }
+
+    fn handle_resources_unsubscribe(
+        &mut self,
+        params: &serde_json::Value,
+    ) -> Result<serde_json::Value, (i32, String)> {
+        let uri = params.get("uri").and_then(|v| v.as_str()).ok_or((
+            INVALID_PARAMS,
+            "Missing 'uri' in resources/unsubscribe".to_string(),
+        ))?;
+
+        self.subscriptions.remove(uri);
+        if self.subscriptions.is_empty() {
+            self.stop_watcher();
+        }
+        Ok(serde_json::json!({}))
+    }
+
+    fn stop_watcher(&mut self) {
+        if let Some(mut watcher) = self.watcher.take() {
+            watcher.stop();
+        }
+    }
+}
+
+/// Write one message line to stdout, holding the lock for the whole line.
+fn write_line(json: &str) -> io::Result<()> {
+    let mut stdout = io::stdout().lock();
+    writeln!(stdout, "{}", json)?;
+    stdout.flush()
+}
+
 impl Default for McpServer {
     fn default() -> Self {
         Self::new()
@@ -277,6 +401,27 @@ mod tests {
         assert_eq!(result["isError"], true);
     }
 
+    #[test]
+    fn handle_tools_call_invalid_arguments() {
+        let mut s = server();
+        let resp = s
+            .handle_message(r#"{"jsonrpc":"2.0","id":15,"method":"tools/call","params":{"name":"pt_plan","arguments":{"min_score":"high"}}}"#)
+            .unwrap();
+        let err = resp.error.unwrap();
+        assert_eq!(err.code, INVALID_PARAMS);
+        assert!(err.message.contains("'min_score'"));
+        assert!(err.message.contains("expected number"));
+
+        let resp = s
+            .handle_message(r#"{"jsonrpc":"2.0","id":16,"method":"tools/call","params":{"name":"pt_explain","arguments":{}}}"#)
+            .unwrap();
+        let err = resp.error.unwrap();
+        assert_eq!(err.code, INVALID_PARAMS);
+        assert!(err
+            .message
+            .starts_with("pt_explain: Missing required argument"));
+    }
+
     #[test]
     fn handle_resources_list() {
         let mut s = server();
@@ -319,6 +464,46 @@ mod tests {
         assert_eq!(resp.error.as_ref().unwrap().code, INVALID_PARAMS);
     }
 
+    #[test]
+    fn handle_initialize_advertises_subscribe() {
+        let mut s = server();
+        let resp = s
+            .handle_message(r#"{"jsonrpc":"2.0","id":1,"method":"initialize","params":{}}"#)
+            .unwrap();
+        let result = resp.result.unwrap();
+        assert_eq!(result["capabilities"]["resources"]["subscribe"], true);
+    }
+
+    #[test]
+    fn subscribe_starts_and_unsubscribe_stops_watcher() {
+        let mut s = server();
+        s.scanner = Arc::new(|| Ok(Vec::new()));
+        s.notifier = Arc::new(|_| {});
+
+        let resp = s
+            .handle_message(r#"{"jsonrpc":"2.0","id":12,"method":"resources/subscribe","params":{"uri":"pt://processes/current"}}"#)
+            .unwrap();
+        assert!(resp.error.is_none());
+        assert!(s.watcher.is_some());
+
+        let resp = s
+            .handle_message(r#"{"jsonrpc":"2.0","id":13,"method":"resources/unsubscribe","params":{"uri":"pt://processes/current"}}"#)
+            .unwrap();
+        assert!(resp.error.is_none());
+        assert!(s.watcher.is_none());
+        assert!(s.subscriptions.is_empty());
+    }
+
+    #[test]
+    fn subscribe_rejects_static_resource() {
+        let mut s = server();
+        let resp = s
+            .handle_message(r#"{"jsonrpc":"2.0","id":14,"method":"resources/subscribe","params":{"uri":"pt://version"}}"#)
+            .unwrap();
+        assert_eq!(resp.error.as_ref().unwrap().code, INVALID_PARAMS);
+        assert!(s.watcher.is_none());
+    }
+
     #[test]
     fn server_default_not_initialized() {
         let s = McpServer::default();
@@ -0,0 +1,367 @@
+//! MCP resource subscriptions for live process state.
+//!
+//! While at least one client subscription is active, a background watcher
+//! rescans the process table and emits `notifications/resources/updated`
+//! for [`PROCESS_LIST_URI`] when the change is material: a new high-risk
+//! process appears or a tracked process changes state. Updates are
+//! debounced so a busy machine produces at most one notification per
+//! configured interval.
+
+use crate::collect::{ProcessRecord, ProcessState};
+use crate::mcp::protocol::JsonRpcNotification;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+use tracing::warn;
+
+/// URI of the subscribable live process-list resource.
+pub const PROCESS_LIST_URI: &str = "pt://processes/current";
+
+/// Default minimum spacing between update notifications.
+pub const DEFAULT_UPDATE_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Default spacing between background scans.
+pub const DEFAULT_SCAN_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Score at or above which a process counts as high-risk.
+pub const HIGH_RISK_SCORE: f64 = 0.8;
+
+/// Produces the current process list for the watcher.
+pub type Scanner = Arc<dyn Fn() -> Result<Vec<ProcessRecord>, String> + Send + Sync>;
+
+/// Scores a whole scan at once, returning each process's risk by PID.
+///
+/// Scoring takes the full scan because the process tree and running
+/// supervisors feed into each process's posterior.
+pub type RiskScorer = Arc<dyn Fn(&[ProcessRecord]) -> HashMap<u32, f64> + Send + Sync>;
+
+/// Receives notifications emitted by the watcher.
+pub type Notifier = Arc<dyn Fn(JsonRpcNotification) + Send + Sync>;
+
+/// Whether a resource URI supports `resources/subscribe`.
+pub fn is_subscribable(uri: &str) -> bool {
+    uri == PROCESS_LIST_URI
+}
+
+/// Per-process facts tracked between scans.
+#[derive(Debug, Clone, PartialEq)]
+struct TrackedProcess {
+    start_id: String,
+    state: ProcessState,
+    high_risk: bool,
+}
+
+/// Condensed view of a scan used for change detection.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProcessListSummary {
+    entries: BTreeMap<u32, TrackedProcess>,
+}
+
+impl ProcessListSummary {
+    /// Summarize a scan, marking processes whose score is at least
+    /// [`HIGH_RISK_SCORE`] as high-risk.
+    pub fn from_processes(
+        processes: &[ProcessRecord],
+        score: impl Fn(&ProcessRecord) -> f64,
+    ) -> Self {
+        let entries = processes
+            .iter()
+            .map(|p| {
+                (
+                    p.pid.0,
+                    TrackedProcess {
+                        start_id: p.start_id.0.clone(),
+                        state: p.state,
+                        high_risk: score(p) >= HIGH_RISK_SCORE,
+                    },
+                )
+            })
+            .collect();
+        Self { entries }
+    }
+
+    /// Whether `next` differs materially from `self`.
+    ///
+    /// Material changes are a process becoming high-risk (including a new
+    /// PID or a reused one) and a state transition of a surviving process.
+    /// Processes exiting and score drift below the threshold are not.
+    pub fn material_change(&self, next: &ProcessListSummary) -> bool {
+        next.entries
+            .iter()
+            .any(|(pid, new)| match self.entries.get(pid) {
+                Some(old) if old.start_id == new.start_id => {
+                    old.state != new.state || (new.high_risk && !old.high_risk)
+                }
+                _ => new.high_risk,
+            })
+    }
+}
+
+/// Rate limiter that coalesces changes into at most one update per interval.
+#[derive(Debug)]
+pub struct Debouncer {
+    interval: Duration,
+    last_emit: Option<Instant>,
+    pending: bool,
+}
+
+impl Debouncer {
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            last_emit: None,
+            pending: false,
+        }
+    }
+
+    /// Record that a change was observed.
+    pub fn mark(&mut self) {
+        self.pending = true;
+    }
+
+    /// Whether an update should be emitted at `now`; clears the pending flag
+    /// when it returns true.
+    pub fn poll(&mut self, now: Instant) -> bool {
+        if !self.pending {
+            return false;
+        }
+        let due = self
+            .last_emit
+            .is_none_or(|last| now.duration_since(last) >= self.interval);
+        if due {
+            self.pending = false;
+            self.last_emit = Some(now);
+        }
+        due
+    }
+}
+
+/// Background scanner that drives subscription notifications.
+///
+/// Dropping the watcher stops the thread and waits for it to exit.
+pub struct SubscriptionWatcher {
+    stop: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl SubscriptionWatcher {
+    /// Start watching. The first scan only establishes a baseline.
+    pub fn spawn(
+        scan_interval: Duration,
+        update_interval: Duration,
+        scanner: Scanner,
+        score: RiskScorer,
+        notifier: Notifier,
+    ) -> Self {
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread_stop = Arc::clone(&stop);
+
+        let handle = thread::spawn(move || {
+            let mut baseline: Option<ProcessListSummary> = None;
+            let mut debouncer = Debouncer::new(update_interval);
+
+            while !thread_stop.load(Ordering::SeqCst) {
+                match scanner() {
+                    Ok(processes) => {
+                        let risks = score(&processes);
+                        let summary = ProcessListSummary::from_processes(&processes, |p| {
+                            risks.get(&p.pid.0).copied().unwrap_or(0.0)
+                        });
+                        if baseline
+                            .as_ref()
+                            .is_some_and(|prev| prev.material_change(&summary))
+                        {
+                            debouncer.mark();
+                        }
+                        baseline = Some(summary);
+                    }
+                    Err(e) => warn!(error = %e, "subscription scan failed"),
+                }
+
+                if debouncer.poll(Instant::now()) {
+                    notifier(JsonRpcNotification::new(
+                        "notifications/resources/updated",
+                        serde_json::json!({ "uri": PROCESS_LIST_URI }),
+                    ));
+                }
+
+                thread::park_timeout(scan_interval);
+            }
+        });
+
+        Self {
+            stop,
+            handle: Some(handle),
+        }
+    }
+
+    /// Stop the background thread and wait for it to finish.
+    pub fn stop(&mut self) {
+        self.stop.store(true, Ordering::SeqCst);
+        if let Some(handle) = self.handle.take() {
+            handle.thread().unpark();
+            let _ = handle.join();
+        }
+    }
+}
+
+impl Drop for SubscriptionWatcher {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pt_common::{ProcessId, StartId};
+    use std::sync::Mutex;
+
+    fn record(pid: u32, state: ProcessState) -> ProcessRecord {
+        ProcessRecord {
+            pid: ProcessId(pid),
+            ppid: ProcessId(1),
+            uid: 1000,
+            user: "tester".to_string(),
+            pgid: None,
+            sid: None,
+            start_id: StartId(format!("boot:1:{pid}")),
+            comm: "worker".to_string(),
+            cmd: "worker".to_string(),
+            state,
+            cpu_percent: 0.0,
+            rss_bytes: 0,
+            vsz_bytes: 0,
+            tty: None,
+            start_time_unix: 0,
+            elapsed: Duration::from_secs(60),
+            source: "test".to_string(),
+            container_info: None,
+        }
+    }
+
+    fn zombie_is_risky(p: &ProcessRecord) -> f64 {
+        if p.state == ProcessState::Zombie {
+            0.9
+        } else {
+            0.1
+        }
+    }
+
+    fn zombie_risks(processes: &[ProcessRecord]) -> HashMap<u32, f64> {
+        processes
+            .iter()
+            .map(|p| (p.pid.0, zombie_is_risky(p)))
+            .collect()
+    }
+
+    #[test]
+    fn new_high_risk_process_is_material() {
+        let before = ProcessListSummary::from_processes(
+            &[record(1, ProcessState::Sleeping)],
+            zombie_is_risky,
+        );
+        let benign = ProcessListSummary::from_processes(
+            &[
+                record(1, ProcessState::Sleeping),
+                record(2, ProcessState::Sleeping),
+            ],
+            zombie_is_risky,
+        );
+        let risky = ProcessListSummary::from_processes(
+            &[
+                record(1, ProcessState::Sleeping),
+                record(3, ProcessState::Zombie),
+            ],
+            zombie_is_risky,
+        );
+
+        assert!(!before.material_change(&benign));
+        assert!(before.material_change(&risky));
+    }
+
+    #[test]
+    fn state_transition_is_material_but_exit_is_not() {
+        let before = ProcessListSummary::from_processes(
+            &[
+                record(1, ProcessState::Sleeping),
+                record(2, ProcessState::Sleeping),
+            ],
+            zombie_is_risky,
+        );
+        let stopped = ProcessListSummary::from_processes(
+            &[
+                record(1, ProcessState::Stopped),
+                record(2, ProcessState::Sleeping),
+            ],
+            zombie_is_risky,
+        );
+        let exited = ProcessListSummary::from_processes(
+            &[record(1, ProcessState::Sleeping)],
+            zombie_is_risky,
+        );
+
+        assert!(before.material_change(&stopped));
+        assert!(!before.material_change(&exited));
+    }
+
+    #[test]
+    fn debouncer_limits_rate() {
+        let start = Instant::now();
+        let mut debouncer = Debouncer::new(Duration::from_secs(5));
+
+        assert!(!debouncer.poll(start));
+        debouncer.mark();
+        assert!(debouncer.poll(start));
+
+        debouncer.mark();
+        assert!(!debouncer.poll(start + Duration::from_secs(1)));
+        assert!(debouncer.poll(start + Duration::from_secs(5)));
+        assert!(!debouncer.poll(start + Duration::from_secs(20)));
+    }
+
+    #[test]
+    fn watcher_notifies_and_stops() {
+        let calls = Arc::new(Mutex::new(0u32));
+        let scan_calls = Arc::clone(&calls);
+        let scanner: Scanner = Arc::new(move || {
+            let mut n = scan_calls.lock().unwrap();
+            *n += 1;
+            let state = if *n == 1 {
+                ProcessState::Sleeping
+            } else {
+                ProcessState::Zombie
+            };
+            Ok(vec![record(1, state)])
+        });
+
+        let sent = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&sent);
+        let notifier: Notifier = Arc::new(move |n| sink.lock().unwrap().push(n));
+
+        let mut watcher = SubscriptionWatcher::spawn(
+            Duration::from_millis(10),
+            Duration::from_secs(60),
+            scanner,
+            Arc::new(zombie_risks),
+            notifier,
+        );
+
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while sent.lock().unwrap().is_empty() && Instant::now() < deadline {
+            thread::sleep(Duration::from_millis(10));
+        }
+        watcher.stop();
+
+        let sent = sent.lock().unwrap();
+        assert_eq!(sent.len(), 1, "debounce allows a single update");
+        assert_eq!(sent[0].method, "notifications/resources/updated");
+        assert_eq!(sent[0].params["uri"], PROCESS_LIST_URI);
+
+        let scans_at_stop = *calls.lock().unwrap();
+        thread::sleep(Duration::from_millis(50));
+        assert_eq!(*calls.lock().unwrap(), scans_at_stop);
+    }
+}
@@ -2,11 +2,18 @@
 //!
 //! Each tool maps to a pt operation: scan, explain, history, signatures, capabilities.
 
-#[cfg(target_os = "linux")]
-use crate::collect::ScanMetadata;
+use std::collections::HashMap;
+
+use crate::collect::protected::ProtectedFilter;
 #[cfg(target_os = "linux")]
 use crate::collect::{deep_scan, DeepScanOptions};
-use crate::collect::{quick_scan, ProcessRecord, ProcessState, QuickScanOptions, ScanResult};
+use crate::collect::{
+    quick_scan, ProcessRecord, ProcessState, QuickScanOptions, ScanMetadata, ScanResult,
+};
+use crate::config::priors::Priors;
+use crate::config::{load_config, ConfigOptions, Policy};
+use crate::decision::{decide_with_protection, ActionFeasibility, ProtectionRules};
+use crate::inference::{self, process_evidence, FastPathConfig, ScoringContext};
 use crate::mcp::protocol::{ToolContent, ToolDefinition};
 use crate::signature_cli::load_user_signatures;
 use crate::supervision::signature::ProcessMatchContext;
@@ -66,7 +73,7 @@ fn collect_scan_result(deep: bool) -> Result<ScanResult, String> {
     }
 }
 
-fn load_signature_db_with_user_entries() -> SignatureDatabase {
+pub(crate) fn load_signature_db_with_user_entries() -> SignatureDatabase {
     let mut db = SignatureDatabase::new();
     db.add_default_signatures();
     if let Some(user_schema) = load_user_signatures() {
@@ -77,7 +84,10 @@ fn load_signature_db_with_user_entries() -> SignatureDatabase {
     db
 }
 
-fn score_process(process: &ProcessRecord, db: &SignatureDatabase) -> (f64, Option<String>) {
+pub(crate) fn score_process(
+    process: &ProcessRecord,
+    db: &SignatureDatabase,
+) -> (f64, Option<String>) {
     let ctx = ProcessMatchContext {
         comm: &process.comm,
         cmdline: Some(process.cmd.as_str()),
@@ -140,8 +150,9 @@ pub fn tool_definitions() -> Vec<ToolDefinition> {
     vec![
         ToolDefinition {
             name: "pt_scan".to_string(),
-            description: "Run a process scan and return candidates with scores. \
-                          Returns a JSON array of process records with evidence."
+            description: "Run a read-only process scan and return ranked candidates. \
+                          Each candidate carries its classification, posterior, and \
+                          recommended action; no action is ever executed."
                 .to_string(),
             input_schema: serde_json::json!({
                 "type": "object",
@@ -153,7 +164,7 @@ pub fn tool_definitions() -> Vec<ToolDefinition> {
                     },
                     "min_score": {
                         "type": "number",
-                        "description": "Minimum score threshold to include (0.0-1.0)",
+                        "description": "Minimum posterior of the most likely class to include (0.0-1.0), as in `agent plan --min-posterior`",
                         "default": 0.0,
                         "minimum": 0.0,
                         "maximum": 1.0
@@ -180,6 +191,7 @@ pub fn tool_definitions() -> Vec<ToolDefinition> {
                     }
                 },
                 "required": [],
+                "anyOf": [{ "required": ["pid"] }, { "required": ["comm"] }],
                 "additionalProperties": false
             }),
         },
@@ -279,45 +291,43 @@ fn tool_scan(params: &serde_json::Value) -> Result<Vec<ToolContent>, String> {
         .get("min_score")
         .and_then(|v| v.as_f64())
         .unwrap_or(0.0);
-    let scan_result = collect_scan_result(deep)?;
+    let config =
+        load_config(&ConfigOptions::default()).map_err(|e| format!("Config error: {}", e))?;
+    let scan_result =
+        quick_scan(&QuickScanOptions::default()).map_err(|e| format!("Scan failed: {}", e))?;
     let db = load_signature_db_with_user_entries();
 
-    // Process and filter candidates
-    let mut candidates = Vec::new();
-    for p in &scan_result.processes {
-        let (final_score, top_signature) = score_process(p, &db);
+    // Same gating as the CLI: protected processes never become candidates.
+    let protected_filter = ProtectedFilter::from_guardrails(&config.policy.guardrails)
+        .map_err(|e| format!("Protected filter error: {}", e))?;
+    let filter_result = protected_filter.filter_scan_result(&scan_result);
 
-        if final_score >= min_score {
-            candidates.push((p, final_score, top_signature));
-        }
-    }
+    // Deep probes add evidence to the quick scan rather than replacing it.
+    let deep_evidence = if deep {
+        collect_deep_evidence(&filter_result.passed)?
+    } else {
+        HashMap::new()
+    };
 
-    // Sort by score descending
-    candidates.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+    let candidates = build_scan_candidates(
+        &scan_result,
+        &filter_result.passed,
+        &deep_evidence,
+        &db,
+        &config.priors,
+        &config.policy,
+        min_score,
+    )?;
 
     let result = serde_json::json!({
         "scanned_at": scan_result.metadata.started_at,
         "duration_ms": scan_result.metadata.duration_ms,
         "platform": scan_result.metadata.platform,
+        "scan_type": if deep { "deep" } else { "quick" },
         "total_processes": scan_result.processes.len(),
-        "returned": candidates.len(),
-        "processes": candidates.iter().take(200).map(|(p, score, top_signature)| {
-            serde_json::json!({
-                "pid": p.pid.0,
-                "ppid": p.ppid.0,
-                "comm": p.comm,
-                "cmd": p.cmd,
-                "state": format!("{}", p.state),
-                "uid": p.uid,
-                "user": p.user,
-                "cpu_pct": p.cpu_percent,
-                "rss_bytes": p.rss_bytes,
-                "vsz_bytes": p.vsz_bytes,
-                "elapsed_sec": p.elapsed.as_secs(),
-                "score": score,
-                "top_signature": top_signature,
-            })
-        }).collect::<Vec<_>>(),
+        "protected_filtered": filter_result.filtered.len(),
+        "returned": candidates.len().min(MAX_SCAN_CANDIDATES),
+        "processes": candidates.into_iter().take(MAX_SCAN_CANDIDATES).collect::<Vec<_>>(),
     });
 
     Ok(vec![ToolContent {
@@ -327,6 +337,205 @@ fn tool_scan(params: &serde_json::Value) -> Result<Vec<ToolContent>, String> {
     }])
 }
 
+/// Maximum number of candidates returned by `pt_scan`.
+const MAX_SCAN_CANDIDATES: usize = 200;
+
+/// Deep-probe signals folded into a candidate's evidence.
+#[derive(Debug, Clone, Copy, Default)]
+struct DeepEvidence {
+    net_active: Option<bool>,
+    io_active: Option<bool>,
+}
+
+/// Deep-scan `processes` for network and I/O signals.
+///
+/// Platforms without deep scan contribute no signals.
+fn collect_deep_evidence(
+    processes: &[ProcessRecord],
+) -> Result<HashMap<u32, DeepEvidence>, String> {
+    if processes.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        let options = DeepScanOptions {
+            pids: processes.iter().map(|p| p.pid.0).collect(),
+            skip_inaccessible: true,
+            include_environ: false,
+            use_wait_free: true,
+            progress: None,
+        };
+        let result = deep_scan(&options).map_err(|e| format!("Deep scan failed: {}", e))?;
+        Ok(result
+            .processes
+            .into_iter()
+            .map(|record| {
+                let io_active = record
+                    .io
+                    .as_ref()
+                    .map(|io| io.read_bytes > 0 || io.write_bytes > 0);
+                let net_active = record.network.as_ref().map(|info| {
+                    let counts = &info.socket_counts;
+                    counts.tcp + counts.tcp6 + counts.udp + counts.udp6 + counts.unix + counts.raw
+                        > 0
+                        || !info.listen_ports.is_empty()
+                });
+                (
+                    record.pid.0,
+                    DeepEvidence {
+                        net_active,
+                        io_active,
+                    },
+                )
+            })
+            .collect())
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        Ok(HashMap::new())
+    }
+}
+
+fn fast_path_config(policy: &Policy) -> FastPathConfig {
+    FastPathConfig {
+        enabled: policy.signature_fast_path.enabled,
+        min_confidence_threshold: policy.signature_fast_path.min_confidence_threshold,
+        require_explicit_priors: policy.signature_fast_path.require_explicit_priors,
+    }
+}
+
+/// Risk score and top signature of each scanned process, keyed by PID.
+///
+/// The score is the posterior of the most likely class under
+/// [`inference::score_process`], the same number `pt_scan` ranks candidates
+/// by, so the live process resource and its subscription watcher agree with
+/// the scan tool on what counts as high-risk.
+pub(crate) fn score_process_risks(
+    processes: &[ProcessRecord],
+    db: &SignatureDatabase,
+    priors: &Priors,
+    policy: &Policy,
+) -> HashMap<u32, (f64, Option<String>)> {
+    let fast_path_config = fast_path_config(policy);
+    let candidate_refs: Vec<&ProcessRecord> = processes.iter().collect();
+    let scoring = ScoringContext::new(priors, db, &fast_path_config, processes, &candidate_refs);
+
+    processes
+        .iter()
+        .filter(|proc| proc.pid.0 != 0 && proc.pid.0 != 1)
+        .filter_map(|proc| {
+            let scored = inference::score_process(&scoring, proc, &process_evidence(proc))?;
+            let top_signature = scored
+                .signature_match
+                .as_ref()
+                .map(|m| m.signature.name.clone());
+            Some((proc.pid.0, (scored.max_posterior(), top_signature)))
+        })
+        .collect()
+}
+
+/// Run inference over scanned processes and rank them as candidates.
+///
+/// Each process is scored by [`inference::score_process`], the per-process
+/// scoring that `agent plan` uses, then decided against the policy and
+/// protection list. Candidates are ranked by the posterior of their most likely
+/// class and `min_score` thresholds on it, as `agent plan --min-posterior`
+/// does. Nothing here acts on the processes: the recommended action is
+/// advisory only.
+fn build_scan_candidates(
+    scan: &ScanResult,
+    processes: &[ProcessRecord],
+    deep: &HashMap<u32, DeepEvidence>,
+    db: &SignatureDatabase,
+    priors: &Priors,
+    policy: &Policy,
+    min_score: f64,
+) -> Result<Vec<serde_json::Value>, String> {
+    let protection_rules = ProtectionRules::new(&policy.protection_list)
+        .map_err(|e| format!("Invalid protection_list: {}", e))?;
+    let fast_path_config = fast_path_config(policy);
+    let candidate_refs: Vec<&ProcessRecord> = processes.iter().collect();
+    let scoring = ScoringContext::new(
+        priors,
+        db,
+        &fast_path_config,
+        &scan.processes,
+        &candidate_refs,
+    );
+
+    let mut candidates = Vec::new();
+    for proc in processes {
+        if proc.pid.0 == 0 || proc.pid.0 == 1 {
+            continue;
+        }
+        let deep = deep.get(&proc.pid.0).copied().unwrap_or_default();
+        let mut evidence = process_evidence(proc);
+        evidence.net = deep.net_active;
+        evidence.io_active = deep.io_active;
+        let Some(scored) = inference::score_process(&scoring, proc, &evidence) else {
+            continue;
+        };
+
+        let feasibility = ActionFeasibility::from_process_state(
+            proc.state.is_zombie(),
+            proc.state.is_disksleep(),
+            None,
+        );
+        let protection = protection_rules.check(&proc.comm);
+        let Ok(decision) = decide_with_protection(
+            protection.as_ref(),
+            &scored.posterior.posterior,
+            policy,
+            &feasibility,
+        ) else {
+            continue;
+        };
+
+        let score = scored.max_posterior();
+        let flagged = protection.as_ref().is_some_and(|v| !v.is_protected());
+        if score < min_score && !flagged {
+            continue;
+        }
+
+        let expected_loss = decision
+            .expected_loss
+            .iter()
+            .find(|e| e.action == decision.optimal_action)
+            .map(|e| e.loss);
+        candidates.push((
+            score,
+            serde_json::json!({
+                "pid": proc.pid.0,
+                "ppid": proc.ppid.0,
+                "comm": proc.comm,
+                "cmd": proc.cmd,
+                "state": format!("{}", proc.state),
+                "uid": proc.uid,
+                "user": proc.user,
+                "cpu_pct": proc.cpu_percent,
+                "rss_bytes": proc.rss_bytes,
+                "vsz_bytes": proc.vsz_bytes,
+                "elapsed_sec": proc.elapsed.as_secs(),
+                "score": score,
+                "classification": scored.ledger.classification.label(),
+                "confidence": scored.ledger.confidence.label(),
+                "posterior": scored.posterior.posterior,
+                "recommended_action": decision.optimal_action,
+                "expected_loss": expected_loss,
+                "top_signature": scored.signature_match.as_ref().map(|m| m.signature.name.clone()),
+                "why": scored.ledger.why_summary,
+            }),
+        ));
+    }
+    candidates.sort_by(|a, b| b.0.total_cmp(&a.0));
+
+    Ok(candidates
+        .into_iter()
+        .map(|(_, candidate)| candidate)
+        .collect())
+}
+
 fn tool_explain(params: &serde_json::Value) -> Result<Vec<ToolContent>, String> {
     let pid = params.get("pid").and_then(|v| v.as_u64()).map(|v| v as u32);
     let comm = params.get("comm").and_then(|v| v.as_str());
@@ -688,6 +897,77 @@ mod tests {
         assert_eq!(plan[0]["recommended_action"], "kill");
     }
 
+    #[test]
+    fn scan_candidates_carry_inference() {
+        let db = SignatureDatabase::with_defaults();
+        let make = |pid: u32, state: ProcessState, cpu: f64| ProcessRecord {
+            pid: pt_common::ProcessId(pid),
+            ppid: pt_common::ProcessId(1),
+            uid: 1000,
+            user: "tester".to_string(),
+            pgid: Some(pid),
+            sid: Some(pid),
+            start_id: pt_common::StartId(format!("synthetic:123:{pid}")),
+            comm: "worker".to_string(),
+            cmd: "worker --serve".to_string(),
+            state,
+            cpu_percent: cpu,
+            rss_bytes: 1024,
+            vsz_bytes: 4096,
+            tty: None,
+            start_time_unix: 0,
+            elapsed: std::time::Duration::from_secs(86_400),
+            source: "test".to_string(),
+            container_info: None,
+        };
+        let processes = vec![
+            make(100, ProcessState::Sleeping, 1.0),
+            make(200, ProcessState::Zombie, 0.0),
+        ];
+        let scan = ScanResult {
+            metadata: ScanMetadata {
+                scan_type: "quick".to_string(),
+                platform: "linux".to_string(),
+                boot_id: None,
+                started_at: "2026-01-01T00:00:00Z".to_string(),
+                duration_ms: 0,
+                process_count: processes.len(),
+                warnings: vec![],
+            },
+            processes: processes.clone(),
+        };
+
+        let candidates = build_scan_candidates(
+            &scan,
+            &processes,
+            &HashMap::new(),
+            &db,
+            &Priors::default(),
+            &Policy::default(),
+            0.0,
+        )
+        .unwrap();
+        assert_eq!(candidates.len(), 2);
+        for candidate in &candidates {
+            assert!(candidate["classification"].is_string());
+            assert!(candidate["posterior"]["zombie"].is_number());
+            assert!(candidate["recommended_action"].is_string());
+        }
+        assert!(candidates[0]["score"].as_f64() >= candidates[1]["score"].as_f64());
+
+        let none = build_scan_candidates(
+            &scan,
+            &[],
+            &HashMap::new(),
+            &db,
+            &Priors::default(),
+            &Policy::default(),
+            0.0,
+        )
+        .unwrap();
+        assert!(none.is_empty());
+    }
+
     #[test]
     fn tool_definitions_count() {
         let defs = tool_definitions();
@@ -0,0 +1,240 @@
+//! Validation of tool arguments against their declared input schemas.
+//!
+//! Tools publish a JSON Schema subset in `tools/list`; arguments are checked
+//! against it before dispatch so agents get an error naming the offending
+//! field and expected type instead of a tool-specific failure. Supported
+//! keywords: `type`, `properties`, `required`, `additionalProperties`,
+//! `anyOf` (of `required` alternatives), `enum`, `minimum`, `maximum`.
+
+use serde_json::Value;
+
+/// Validate `args` against a tool's `input_schema`.
+///
+/// Returns a human-readable message describing the first violation found.
+pub fn validate_arguments(schema: &Value, args: &Value) -> Result<(), String> {
+    let Some(obj) = args.as_object() else {
+        return Err(format!(
+            "Invalid arguments: expected object, got {}",
+            type_name(args)
+        ));
+    };
+    let properties = schema.get("properties").and_then(Value::as_object);
+
+    if let Some(required) = schema.get("required").and_then(Value::as_array) {
+        for field in required.iter().filter_map(Value::as_str) {
+            if !obj.contains_key(field) {
+                return Err(format!("Missing required argument '{}'", field));
+            }
+        }
+    }
+
+    if let Some(alternatives) = schema.get("anyOf").and_then(Value::as_array) {
+        let groups: Vec<Vec<&str>> = alternatives
+            .iter()
+            .filter_map(|alt| alt.get("required").and_then(Value::as_array))
+            .map(|req| req.iter().filter_map(Value::as_str).collect())
+            .collect();
+        let satisfied = groups
+            .iter()
+            .any(|group| group.iter().all(|field| obj.contains_key(*field)));
+        if !groups.is_empty() && !satisfied {
+            let names: Vec<String> = groups
+                .iter()
+                .map(|group| format!("'{}'", group.join("' + '")))
+                .collect();
+            return Err(format!(
+                "Missing required argument: one of {}",
+                names.join(", ")
+            ));
+        }
+    }
+
+    let additional_allowed = schema
+        .get("additionalProperties")
+        .and_then(Value::as_bool)
+        .unwrap_or(true);
+
+    for (name, value) in obj {
+        match properties.and_then(|p| p.get(name)) {
+            Some(prop) => validate_property(name, prop, value)?,
+            None if !additional_allowed => {
+                let mut known: Vec<&str> = properties
+                    .map(|p| p.keys().map(String::as_str).collect())
+                    .unwrap_or_default();
+                known.sort_unstable();
+                return Err(format!(
+                    "Unknown argument '{}' (expected one of: {})",
+                    name,
+                    if known.is_empty() {
+                        "none".to_string()
+                    } else {
+                        known.join(", ")
+                    }
+                ));
+            }
+            None => {}
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_property(name: &str, prop: &Value, value: &Value) -> Result<(), String> {
+    if let Some(expected) = prop.get("type").and_then(Value::as_str) {
+        if !matches_type(expected, value) {
+            return Err(format!(
+                "Invalid argument '{}': expected {}, got {}",
+                name,
+                expected,
+                type_name(value)
+            ));
+        }
+    }
+
+    if let Some(allowed) = prop.get("enum").and_then(Value::as_array) {
+        if !allowed.contains(value) {
+            let options: Vec<String> = allowed.iter().map(Value::to_string).collect();
+            return Err(format!(
+                "Invalid argument '{}': expected one of {}, got {}",
+                name,
+                options.join(", "),
+                value
+            ));
+        }
+    }
+
+    if let Some(n) = value.as_f64() {
+        if let Some(min) = prop.get("minimum").and_then(Value::as_f64) {
+            if n < min {
+                return Err(format!(
+                    "Invalid argument '{}': must be >= {}, got {}",
+                    name, min, value
+                ));
+            }
+        }
+        if let Some(max) = prop.get("maximum").and_then(Value::as_f64) {
+            if n > max {
+                return Err(format!(
+                    "Invalid argument '{}': must be <= {}, got {}",
+                    name, max, value
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn matches_type(expected: &str, value: &Value) -> bool {
+    match expected {
+        "boolean" => value.is_boolean(),
+        "integer" => value.is_i64() || value.is_u64(),
+        "number" => value.is_number(),
+        "string" => value.is_string(),
+        "array" => value.is_array(),
+        "object" => value.is_object(),
+        "null" => value.is_null(),
+        _ => true,
+    }
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(n) if n.is_f64() => "number",
+        Value::Number(_) => "integer",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mcp::tools::tool_definitions;
+    use serde_json::json;
+
+    fn schema(tool: &str) -> Value {
+        tool_definitions()
+            .into_iter()
+            .find(|d| d.name == tool)
+            .unwrap()
+            .input_schema
+    }
+
+    #[test]
+    fn valid_arguments_pass() {
+        assert!(validate_arguments(&schema("pt_plan"), &json!({})).is_ok());
+        assert!(
+            validate_arguments(&schema("pt_plan"), &json!({"deep": true, "min_score": 0.7}))
+                .is_ok()
+        );
+        assert!(validate_arguments(&schema("pt_explain"), &json!({"pid": 42})).is_ok());
+        assert!(validate_arguments(&schema("pt_explain"), &json!({"comm": "node"})).is_ok());
+    }
+
+    #[test]
+    fn plan_wrong_type_names_field() {
+        let err = validate_arguments(&schema("pt_plan"), &json!({"deep": "yes"})).unwrap_err();
+        assert_eq!(err, "Invalid argument 'deep': expected boolean, got string");
+
+        let err =
+            validate_arguments(&schema("pt_plan"), &json!({"min_score": "high"})).unwrap_err();
+        assert!(err.contains("'min_score'"));
+        assert!(err.contains("expected number"));
+    }
+
+    #[test]
+    fn plan_unknown_argument_rejected() {
+        let err = validate_arguments(&schema("pt_plan"), &json!({"dep": true})).unwrap_err();
+        assert!(err.contains("Unknown argument 'dep'"));
+        assert!(err.contains("deep"));
+    }
+
+    #[test]
+    fn explain_missing_required_field() {
+        let err = validate_arguments(&schema("pt_explain"), &json!({})).unwrap_err();
+        assert!(err.contains("Missing required argument"));
+        assert!(err.contains("'pid'"));
+        assert!(err.contains("'comm'"));
+    }
+
+    #[test]
+    fn explain_wrong_type_names_field() {
+        let err = validate_arguments(&schema("pt_explain"), &json!({"pid": "1234"})).unwrap_err();
+        assert_eq!(err, "Invalid argument 'pid': expected integer, got string");
+
+        let err = validate_arguments(&schema("pt_explain"), &json!({"pid": 12.5})).unwrap_err();
+        assert!(err.contains("expected integer, got number"));
+    }
+
+    #[test]
+    fn required_list_and_range_checked() {
+        let schema = json!({
+            "type": "object",
+            "properties": {"limit": {"type": "integer", "minimum": 1, "maximum": 10}},
+            "required": ["limit"]
+        });
+        assert_eq!(
+            validate_arguments(&schema, &json!({})).unwrap_err(),
+            "Missing required argument 'limit'"
+        );
+        assert!(validate_arguments(&schema, &json!({"limit": 0}))
+            .unwrap_err()
+            .contains(">= 1"));
+        assert!(validate_arguments(&schema, &json!({"limit": 5})).is_ok());
+    }
+
+    #[test]
+    fn enum_and_non_object_rejected() {
+        let err = validate_arguments(&schema("pt_signatures"), &json!({"category": "bogus"}))
+            .unwrap_err();
+        assert!(err.contains("'category'"));
+        assert!(err.contains("\"agent\""));
+
+        let err = validate_arguments(&schema("pt_plan"), &json!([1, 2])).unwrap_err();
+        assert!(err.contains("expected object"));
+    }
+}
@@ -0,0 +1,313 @@
+//! Prometheus text exposition for shadow-mode triage metrics.
+//!
+//! The shadow loop runs `agent plan` once per interval and feeds each plan
+//! document into [`ShadowMetrics`]. [`ShadowMetrics::render`] turns the
+//! accumulated state into Prometheus text format (version 0.0.4) without
+//! needing the `prometheus` crate; with the `metrics` feature the result is
+//! served over HTTP by [`crate::daemon::metrics::MetricsServer`].
+//!
+//! Labels are limited to a fixed set of classification and action names, so
+//! the number of series never grows with the process table. Anything outside
+//! that set is counted under `other`.
+//!
+//! ## Metrics
+//!
+//! - `pt_shadow_scans_total{status}` — shadow scans by outcome (counter)
+//! - `pt_shadow_candidates_total{class}` — candidates seen, by class (counter)
+//! - `pt_shadow_actions_total{action}` — recommended actions, by type (counter)
+//! - `pt_shadow_governor_trips_total` — recommendations blocked by policy (counter)
+//! - `pt_shadow_high_risk_candidates` — kill/restart recommendations in the latest scan (gauge)
+//! - `pt_shadow_last_scan_timestamp_seconds` — unix time of the latest scan (gauge)
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+
+use serde_json::Value;
+
+/// Classification label values.
+const CLASSES: &[&str] = &["useful", "useful_bad", "abandoned", "zombie"];
+
+/// Recommended-action label values (`review` is used for policy-blocked actions).
+const ACTIONS: &[&str] = &[
+    "keep",
+    "renice",
+    "pause",
+    "resume",
+    "freeze",
+    "unfreeze",
+    "throttle",
+    "unthrottle",
+    "quarantine",
+    "unquarantine",
+    "restart",
+    "kill",
+    "review",
+];
+
+/// Label value for anything outside the fixed sets.
+const OTHER: &str = "other";
+
+/// Recommended actions that count toward the high-risk gauge.
+const HIGH_RISK_ACTIONS: &[&str] = &["kill", "restart"];
+
+/// In-memory counters accumulated across a shadow run.
+#[derive(Debug, Clone, Default)]
+pub struct ShadowMetrics {
+    scans_succeeded: u64,
+    scans_failed: u64,
+    candidates: BTreeMap<&'static str, u64>,
+    actions: BTreeMap<&'static str, u64>,
+    governor_trips: u64,
+    high_risk_current: u64,
+    last_scan_timestamp: Option<i64>,
+}
+
+impl ShadowMetrics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fold one `agent plan` JSON document into the counters.
+    pub fn record_plan(&mut self, plan: &Value) {
+        self.scans_succeeded += 1;
+        self.last_scan_timestamp = Some(chrono::Utc::now().timestamp());
+
+        let candidates = plan["candidates"].as_array().map(Vec::as_slice);
+        let mut high_risk = 0;
+        for candidate in candidates.unwrap_or_default() {
+            let class = bounded(CLASSES, candidate["classification"].as_str());
+            *self.candidates.entry(class).or_default() += 1;
+
+            let action = bounded(ACTIONS, candidate["recommended_action"].as_str());
+            *self.actions.entry(action).or_default() += 1;
+            if HIGH_RISK_ACTIONS.contains(&action) {
+                high_risk += 1;
+            }
+
+            if candidate["policy_blocked"].as_bool() == Some(true) {
+                self.governor_trips += 1;
+            }
+        }
+        self.high_risk_current = high_risk;
+    }
+
+    /// Count a scan that produced no usable plan.
+    pub fn record_failed_scan(&mut self) {
+        self.scans_failed += 1;
+    }
+
+    pub fn scans_total(&self) -> u64 {
+        self.scans_succeeded + self.scans_failed
+    }
+
+    /// Render the current state in Prometheus text exposition format.
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+
+        header(
+            &mut out,
+            "pt_shadow_scans_total",
+            "Shadow-mode scans by status.",
+            "counter",
+        );
+        sample(
+            &mut out,
+            "pt_shadow_scans_total",
+            Some(("status", "success")),
+            self.scans_succeeded,
+        );
+        sample(
+            &mut out,
+            "pt_shadow_scans_total",
+            Some(("status", "failed")),
+            self.scans_failed,
+        );
+
+        header(
+            &mut out,
+            "pt_shadow_candidates_total",
+            "Candidates reported by shadow scans, by classification.",
+            "counter",
+        );
+        for class in CLASSES.iter().chain([&OTHER]) {
+            let count = self.candidates.get(class).copied().unwrap_or(0);
+            sample(
+                &mut out,
+                "pt_shadow_candidates_total",
+                Some(("class", class)),
+                count,
+            );
+        }
+
+        header(
+            &mut out,
+            "pt_shadow_actions_total",
+            "Recommended actions from shadow scans, by type.",
+            "counter",
+        );
+        for action in ACTIONS.iter().chain([&OTHER]) {
+            let count = self.actions.get(action).copied().unwrap_or(0);
+            sample(
+                &mut out,
+                "pt_shadow_actions_total",
+                Some(("action", action)),
+                count,
+            );
+        }
+
+        header(
+            &mut out,
+            "pt_shadow_governor_trips_total",
+            "Recommendations blocked by the policy enforcer.",
+            "counter",
+        );
+        sample(
+            &mut out,
+            "pt_shadow_governor_trips_total",
+            None,
+            self.governor_trips,
+        );
+
+        header(
+            &mut out,
+            "pt_shadow_high_risk_candidates",
+            "Kill or restart recommendations in the latest scan.",
+            "gauge",
+        );
+        sample(
+            &mut out,
+            "pt_shadow_high_risk_candidates",
+            None,
+            self.high_risk_current,
+        );
+
+        if let Some(timestamp) = self.last_scan_timestamp {
+            header(
+                &mut out,
+                "pt_shadow_last_scan_timestamp_seconds",
+                "Unix time of the latest successful shadow scan.",
+                "gauge",
+            );
+            sample(
+                &mut out,
+                "pt_shadow_last_scan_timestamp_seconds",
+                None,
+                timestamp,
+            );
+        }
+
+        out
+    }
+}
+
+/// Map `value` onto one of `allowed`, or [`OTHER`].
+fn bounded(allowed: &[&'static str], value: Option<&str>) -> &'static str {
+    value
+        .and_then(|value| allowed.iter().find(|a| **a == value))
+        .copied()
+        .unwrap_or(OTHER)
+}
+
+fn header(out: &mut String, name: &str, help: &str, kind: &str) {
+    let _ = writeln!(out, "# HELP {name} {help}");
+    let _ = writeln!(out, "# TYPE {name} {kind}");
+}
+
+fn sample(
+    out: &mut String,
+    name: &str,
+    label: Option<(&str, &str)>,
+    value: impl std::fmt::Display,
+) {
+    match label {
+        Some((key, label_value)) => {
+            let _ = writeln!(out, "{name}{{{key}=\"{label_value}\"}} {value}");
+        }
+        None => {
+            let _ = writeln!(out, "{name} {value}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use regex::Regex;
+    use serde_json::json;
+
+    fn plan() -> Value {
+        json!({
+            "candidates": [
+                {"classification": "abandoned", "recommended_action": "kill", "policy_blocked": false},
+                {"classification": "abandoned", "recommended_action": "review", "policy_blocked": true},
+                {"classification": "zombie", "recommended_action": "restart", "policy_blocked": false},
+                {"classification": "mystery", "recommended_action": "teleport"},
+            ]
+        })
+    }
+
+    /// Check every line against the Prometheus text format and return the samples.
+    fn parse(text: &str) -> BTreeMap<String, f64> {
+        let comment = Regex::new(r"^# (HELP [a-zA-Z_:][a-zA-Z0-9_:]* .+|TYPE [a-zA-Z_:][a-zA-Z0-9_:]* (counter|gauge|histogram|summary|untyped))$").unwrap();
+        let sample = Regex::new(
+            r#"^([a-zA-Z_:][a-zA-Z0-9_:]*(\{[a-zA-Z_][a-zA-Z0-9_]*="(?:[^"\\\n]|\\.)*"(,[a-zA-Z_][a-zA-Z0-9_]*="(?:[^"\\\n]|\\.)*")*\})?) (\S+)$"#,
+        )
+        .unwrap();
+        let mut samples = BTreeMap::new();
+        assert!(text.ends_with('\n'));
+        for line in text.lines() {
+            if line.starts_with('#') {
+                assert!(comment.is_match(line), "bad comment line: {line:?}");
+                continue;
+            }
+            let caps = sample
+                .captures(line)
+                .unwrap_or_else(|| panic!("bad sample line: {line:?}"));
+            let value: f64 = caps[4].parse().expect("numeric sample value");
+            assert!(samples.insert(caps[1].to_string(), value).is_none());
+        }
+        samples
+    }
+
+    #[test]
+    fn renders_valid_prometheus_text() {
+        let mut metrics = ShadowMetrics::new();
+        metrics.record_plan(&plan());
+        metrics.record_plan(&json!({"candidates": []}));
+        metrics.record_failed_scan();
+
+        let samples = parse(&metrics.render());
+        assert_eq!(samples[r#"pt_shadow_scans_total{status="success"}"#], 2.0);
+        assert_eq!(samples[r#"pt_shadow_scans_total{status="failed"}"#], 1.0);
+        assert_eq!(
+            samples[r#"pt_shadow_candidates_total{class="abandoned"}"#],
+            2.0
+        );
+        assert_eq!(samples[r#"pt_shadow_candidates_total{class="other"}"#], 1.0);
+        assert_eq!(samples[r#"pt_shadow_actions_total{action="kill"}"#], 1.0);
+        assert_eq!(samples[r#"pt_shadow_actions_total{action="other"}"#], 1.0);
+        assert_eq!(samples["pt_shadow_governor_trips_total"], 1.0);
+        // The gauge reflects the latest (empty) scan only.
+        assert_eq!(samples["pt_shadow_high_risk_candidates"], 0.0);
+        assert!(samples.contains_key("pt_shadow_last_scan_timestamp_seconds"));
+        assert_eq!(metrics.scans_total(), 3);
+    }
+
+    #[test]
+    fn label_cardinality_is_bounded() {
+        let mut metrics = ShadowMetrics::new();
+        let before = parse(&metrics.render()).len();
+        for i in 0..100 {
+            metrics.record_plan(&json!({"candidates": [
+                {"classification": format!("class{i}"), "recommended_action": format!("action{i}")},
+            ]}));
+        }
+        let samples = parse(&metrics.render());
+        // Only the timestamp gauge appears once a scan has been recorded.
+        assert_eq!(samples.len(), before + 1);
+        assert_eq!(
+            samples[r#"pt_shadow_candidates_total{class="other"}"#],
+            100.0
+        );
+    }
+}
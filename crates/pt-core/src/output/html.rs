@@ -0,0 +1,367 @@
+//! Self-contained HTML rendering of an agent plan.
+//!
+//! [`render_plan_html`] takes the same plan JSON that `--format json` prints,
+//! so the two outputs cannot drift. The document carries its CSS and a small
+//! column-sorting script inline and loads nothing external, so it can be
+//! attached to a ticket or opened straight from disk.
+
+use serde_json::Value;
+use std::fmt::Write as _;
+
+const STYLE: &str = r#"
+body { font-family: -apple-system, BlinkMacSystemFont, "Segoe UI", sans-serif; margin: 2rem; color: #1f2328; }
+h1 { font-size: 1.4rem; margin-bottom: 0.25rem; }
+.meta { color: #59636e; font-size: 0.85rem; margin-bottom: 1rem; }
+.summary { display: flex; gap: 1.5rem; margin-bottom: 1.5rem; }
+.summary div { background: #f6f8fa; border: 1px solid #d1d9e0; border-radius: 6px; padding: 0.5rem 0.9rem; }
+.summary b { display: block; font-size: 1.2rem; }
+table { border-collapse: collapse; width: 100%; font-size: 0.9rem; }
+th, td { border-bottom: 1px solid #d1d9e0; padding: 0.4rem 0.6rem; text-align: left; vertical-align: top; }
+th { background: #f6f8fa; cursor: pointer; user-select: none; white-space: nowrap; }
+th.sorted-asc::after { content: " \25B2"; }
+th.sorted-desc::after { content: " \25BC"; }
+td.num { text-align: right; font-variant-numeric: tabular-nums; }
+code { font-size: 0.85em; word-break: break-all; }
+.action { font-weight: 600; }
+.action-kill, .action-restart { color: #cf222e; }
+.action-keep { color: #1a7f37; }
+details summary { cursor: pointer; }
+details table { margin: 0.5rem 0; font-size: 0.85rem; }
+"#;
+
+const SORT_SCRIPT: &str = r#"
+document.querySelectorAll("table.sortable > thead th").forEach(function (th, col) {
+  th.addEventListener("click", function () {
+    var table = th.closest("table");
+    var body = table.tBodies[0];
+    var asc = !th.classList.contains("sorted-asc");
+    Array.from(th.parentNode.children).forEach(function (h) { h.classList.remove("sorted-asc", "sorted-desc"); });
+    th.classList.add(asc ? "sorted-asc" : "sorted-desc");
+    var key = function (row) {
+      var cell = row.cells[col];
+      return cell.dataset.sort !== undefined ? cell.dataset.sort : cell.textContent.trim();
+    };
+    Array.from(body.rows).sort(function (a, b) {
+      var x = key(a), y = key(b), nx = parseFloat(x), ny = parseFloat(y);
+      var cmp = (!isNaN(nx) && !isNaN(ny)) ? nx - ny : x.localeCompare(y);
+      return asc ? cmp : -cmp;
+    }).forEach(function (row) { body.appendChild(row); });
+  });
+});
+"#;
+
+/// Render an `agent plan` JSON document as a standalone HTML page.
+///
+/// Each entry of `plan["candidates"]` becomes one table row; its evidence,
+/// posterior and expected losses sit in a collapsible section on that row so
+/// they move with it when the table is re-sorted.
+pub fn render_plan_html(plan: &Value) -> String {
+    let session = text(&plan["session_id"]);
+    let mut html = String::new();
+    html.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+    let _ = writeln!(
+        html,
+        "<title>Process triage plan {}</title>",
+        html_escape(&session)
+    );
+    let _ = writeln!(html, "<style>{STYLE}</style>\n</head>\n<body>");
+
+    let _ = writeln!(html, "<h1>Process triage plan</h1>");
+    let _ = writeln!(
+        html,
+        "<div class=\"meta\">Session <code>{}</code> &middot; host {} &middot; generated {} &middot; pt {}</div>",
+        html_escape(&session),
+        html_escape(&text(&plan["host"]["hostname"])),
+        html_escape(&text(&plan["generated_at"])),
+        html_escape(&text(&plan["pt_version"])),
+    );
+
+    let summary = &plan["summary"];
+    html.push_str("<div class=\"summary\">\n");
+    for (label, key) in [
+        ("Processes scanned", "total_processes_scanned"),
+        ("Candidates", "candidates_returned"),
+        ("Kill", "kill_recommendations"),
+        ("Review", "review_recommendations"),
+        ("Policy blocked", "policy_blocked"),
+    ] {
+        let _ = writeln!(
+            html,
+            "<div><b>{}</b>{}</div>",
+            html_escape(&text(&summary[key])),
+            label
+        );
+    }
+    html.push_str("</div>\n");
+
+    let empty = Vec::new();
+    let candidates = plan["candidates"].as_array().unwrap_or(&empty);
+    html.push_str("<table class=\"sortable\">\n<thead>\n<tr>");
+    for header in [
+        "PID",
+        "Command",
+        "Classification",
+        "Score",
+        "Memory (MB)",
+        "CPU %",
+        "Age",
+        "Action",
+        "Rationale",
+    ] {
+        let _ = write!(html, "<th>{header}</th>");
+    }
+    html.push_str("</tr>\n</thead>\n<tbody>\n");
+    for candidate in candidates {
+        render_candidate_row(&mut html, candidate);
+    }
+    html.push_str("</tbody>\n</table>\n");
+    if candidates.is_empty() {
+        html.push_str("<p>No candidates.</p>\n");
+    }
+
+    let _ = writeln!(html, "<script>{SORT_SCRIPT}</script>\n</body>\n</html>");
+    html
+}
+
+fn render_candidate_row(html: &mut String, candidate: &Value) {
+    let action = text(&candidate["recommended_action"]);
+    html.push_str("<tr class=\"candidate\">");
+    let _ = write!(
+        html,
+        "<td class=\"num\">{}</td>",
+        html_escape(&text(&candidate["pid"]))
+    );
+    let _ = write!(
+        html,
+        "<td><code title=\"{}\">{}</code></td>",
+        html_escape(&text(&candidate["command"])),
+        html_escape(&text(&candidate["command_short"]))
+    );
+    let _ = write!(
+        html,
+        "<td>{}</td>",
+        html_escape(&text(&candidate["classification"]))
+    );
+    for key in ["score", "memory_mb", "cpu_percent"] {
+        let _ = write!(
+            html,
+            "<td class=\"num\">{}</td>",
+            html_escape(&text(&candidate[key]))
+        );
+    }
+    let _ = write!(
+        html,
+        "<td class=\"num\" data-sort=\"{}\">{}</td>",
+        html_escape(&text(&candidate["age_seconds"])),
+        html_escape(&text(&candidate["age_human"]))
+    );
+    let _ = write!(
+        html,
+        "<td class=\"action action-{}\">{}</td>",
+        html_escape(&action.to_lowercase()),
+        html_escape(&action)
+    );
+
+    html.push_str("<td><details><summary>");
+    html.push_str(&html_escape(&text(&candidate["action_rationale"])));
+    html.push_str("</summary>\n");
+    render_posterior(html, &candidate["posterior"]);
+    render_evidence(html, &candidate["evidence"]);
+    render_expected_loss(html, &candidate["expected_loss"]);
+    html.push_str("</details></td></tr>\n");
+}
+
+fn render_posterior(html: &mut String, posterior: &Value) {
+    let Some(classes) = posterior.as_object() else {
+        return;
+    };
+    html.push_str("<table><thead><tr><th>Class</th><th>Posterior</th></tr></thead><tbody>");
+    for (class, p) in classes {
+        let _ = write!(
+            html,
+            "<tr><td>{}</td><td class=\"num\">{}</td></tr>",
+            html_escape(class),
+            p.as_f64()
+                .map(|p| format!("{:.3}", p))
+                .unwrap_or_else(|| html_escape(&text(p)))
+        );
+    }
+    html.push_str("</tbody></table>\n");
+}
+
+fn render_evidence(html: &mut String, evidence: &Value) {
+    let Some(entries) = evidence.as_array().filter(|e| !e.is_empty()) else {
+        return;
+    };
+    html.push_str(
+        "<table><thead><tr><th>Evidence</th><th>Contribution</th><th>Detail</th><th>Strength</th></tr></thead><tbody>",
+    );
+    for entry in entries {
+        let _ = write!(
+            html,
+            "<tr><td>{}</td><td class=\"num\">{}</td><td>{}</td><td>{}</td></tr>",
+            html_escape(&text(&entry["factor"])),
+            html_escape(&text(&entry["contribution"])),
+            html_escape(&text(&entry["detail"])),
+            html_escape(&text(&entry["strength"]))
+        );
+    }
+    html.push_str("</tbody></table>\n");
+}
+
+fn render_expected_loss(html: &mut String, losses: &Value) {
+    let Some(entries) = losses.as_array().filter(|e| !e.is_empty()) else {
+        return;
+    };
+    html.push_str("<table><thead><tr><th>Action</th><th>Expected loss</th></tr></thead><tbody>");
+    for entry in entries {
+        let _ = write!(
+            html,
+            "<tr><td>{}</td><td class=\"num\">{}</td></tr>",
+            html_escape(&text(&entry["action"])),
+            entry["loss"]
+                .as_f64()
+                .map(|loss| format!("{:.3}", loss))
+                .unwrap_or_else(|| html_escape(&text(&entry["loss"])))
+        );
+    }
+    html.push_str("</tbody></table>\n");
+}
+
+/// Display text for a scalar JSON value; empty for null or missing.
+fn text(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn html_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const VOID_ELEMENTS: &[&str] = &["meta", "br", "hr", "img", "input", "link"];
+
+    fn plan() -> Value {
+        json!({
+            "pt_version": "2.0.0",
+            "session_id": "pt-20261018-120000-abcd",
+            "generated_at": "2026-10-18T12:00:00Z",
+            "host": {"hostname": "build-01"},
+            "summary": {
+                "total_processes_scanned": 412,
+                "candidates_returned": 2,
+                "kill_recommendations": 1,
+                "review_recommendations": 1,
+                "policy_blocked": 0,
+            },
+            "candidates": [
+                {
+                    "pid": 4242,
+                    "command": "node /srv/app/node_modules/.bin/jest --watch",
+                    "command_short": "node",
+                    "classification": "abandoned",
+                    "score": 91,
+                    "memory_mb": 512,
+                    "cpu_percent": 0.0,
+                    "age_seconds": 90000,
+                    "age_human": "1d 1h 0m",
+                    "recommended_action": "kill",
+                    "action_rationale": "Abandoned test watcher",
+                    "posterior": {"useful": 0.05, "useful_bad": 0.02, "abandoned": 0.91, "zombie": 0.02},
+                    "evidence": [
+                        {"factor": "runtime", "contribution": 23, "detail": "2.3 bits toward abandoned", "strength": "strong"},
+                    ],
+                    "expected_loss": [{"action": "Kill", "loss": 0.4}, {"action": "Keep", "loss": 8.1}],
+                },
+                {
+                    "pid": 17,
+                    "command": "sh -c 'echo <script>alert(1)</script>'",
+                    "command_short": "sh",
+                    "classification": "useful",
+                    "score": 60,
+                    "memory_mb": 1,
+                    "cpu_percent": 3.5,
+                    "age_seconds": 30,
+                    "age_human": "30s",
+                    "recommended_action": "review",
+                    "action_rationale": "Needs a human look",
+                    "posterior": {"useful": 0.6, "useful_bad": 0.1, "abandoned": 0.2, "zombie": 0.1},
+                    "evidence": [],
+                    "expected_loss": [],
+                },
+            ],
+        })
+    }
+
+    /// Check that every non-void tag is closed in order, outside `<style>` and `<script>`.
+    fn assert_well_formed(html: &str) {
+        let mut stack: Vec<String> = Vec::new();
+        let mut rest = html;
+        while let Some(start) = rest.find('<') {
+            rest = &rest[start + 1..];
+            let end = rest.find('>').expect("unterminated tag");
+            let tag = &rest[..end];
+            rest = &rest[end + 1..];
+            if tag.starts_with('!') {
+                continue;
+            }
+            if let Some(name) = tag.strip_prefix('/') {
+                assert_eq!(stack.pop().as_deref(), Some(name), "mismatched </{name}>");
+                continue;
+            }
+            let name = tag.split_whitespace().next().unwrap().to_string();
+            if VOID_ELEMENTS.contains(&name.as_str()) {
+                continue;
+            }
+            if name == "style" || name == "script" {
+                let close = format!("</{name}>");
+                let body_end = rest.find(&close).expect("unclosed raw-text element");
+                rest = &rest[body_end + close.len()..];
+                continue;
+            }
+            stack.push(name);
+        }
+        assert!(stack.is_empty(), "unclosed tags: {stack:?}");
+    }
+
+    #[test]
+    fn renders_well_formed_document_with_row_per_candidate() {
+        let html = render_plan_html(&plan());
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert_well_formed(&html);
+        assert_eq!(html.matches("<tr class=\"candidate\">").count(), 2);
+        assert!(html.contains("<td class=\"num\">4242</td>"));
+        assert!(html.contains("Abandoned test watcher"));
+        assert!(html.contains("2.3 bits toward abandoned"));
+        assert!(html.contains("<details>"));
+        // No external assets.
+        assert!(!html.contains("src="));
+        assert!(!html.contains("href="));
+    }
+
+    #[test]
+    fn escapes_command_lines() {
+        let html = render_plan_html(&plan());
+        assert!(!html.contains("<script>alert(1)</script>"));
+        assert!(html.contains("&lt;script&gt;alert(1)&lt;/script&gt;"));
+    }
+
+    #[test]
+    fn empty_plan_still_renders() {
+        let html = render_plan_html(&json!({"candidates": []}));
+        assert_well_formed(&html);
+        assert_eq!(html.matches("<tr class=\"candidate\">").count(), 0);
+        assert!(html.contains("No candidates."));
+    }
+}
@@ -508,8 +508,8 @@ fn pre_checks_for(action: Action) -> Vec<PreCheck> {
             checks.push(PreCheck::CheckSupervisor);
             checks.push(PreCheck::CheckAgentSupervision);
         }
-        // Resume/Unquarantine only need identity verification
-        Action::Resume | Action::Unquarantine => {}
+        // Resume/Unthrottle/Unquarantine only need identity verification
+        Action::Resume | Action::Unthrottle | Action::Unquarantine => {}
         Action::Keep => {}
     }
     checks
@@ -568,6 +568,7 @@ fn action_str(action: Action) -> &'static str {
         Action::Pause => "pause",
         Action::Resume => "resume",
         Action::Throttle => "throttle",
+        Action::Unthrottle => "unthrottle",
         Action::Restart => "restart",
         Action::Kill => "kill",
         Action::Freeze => "freeze",
@@ -619,6 +620,7 @@ fn action_tier(action: Action) -> u8 {
         Action::Pause => 1,
         Action::Resume => 1, // Same tier as Pause (reversible)
         Action::Throttle => 1,
+        Action::Unthrottle => 1,   // Same tier as Throttle (reversible)
         Action::Freeze => 1,       // Reversible via Unfreeze
         Action::Unfreeze => 1,     // Same tier as Freeze (reversible)
         Action::Quarantine => 1,   // Reversible via Unquarantine
@@ -668,6 +670,7 @@ mod tests {
                 memory_mb: None,
                 has_known_signature: None,
                 category: None,
+                protection: None,
             },
             risk_sensitive: None,
             dro: None,
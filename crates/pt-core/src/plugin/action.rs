@@ -18,10 +18,29 @@
 //!   "process_name": "runaway-worker",
 //!   "classification": "zombie",
 //!   "confidence": 0.97,
-//!   "session_id": "abc-123"
+//!   "session_id": "abc-123",
+//!   "posterior": {
+//!     "useful": 0.01,
+//!     "useful_bad": 0.01,
+//!     "abandoned": 0.01,
+//!     "zombie": 0.97
+//!   },
+//!   "process_summary": {
+//!     "ppid": 1,
+//!     "user": "svc",
+//!     "cmd": "runaway-worker --queue jobs",
+//!     "state": "zombie",
+//!     "cpu_percent": 0.0,
+//!     "rss_bytes": 0,
+//!     "elapsed_secs": 86400
+//!   }
 //! }
 //! ```
 //!
+//! `session_id`, `posterior` and `process_summary` are optional and omitted
+//! when unknown. New input fields are only ever added as optional keys, so
+//! plugins must ignore keys they do not recognise.
+//!
 //! **Output** (JSON on stdout):
 //! ```json
 //! {
@@ -31,6 +50,8 @@
 //! }
 //! ```
 
+use crate::collect::{ProcessRecord, ProcessState};
+use crate::inference::posterior::ClassScores;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
@@ -70,6 +91,45 @@ pub struct ActionPluginInput {
     /// Session identifier for correlation.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub session_id: Option<String>,
+    /// Full posterior over all classes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub posterior: Option<ClassScores>,
+    /// Snapshot of the target process for notification context.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub process_summary: Option<ActionProcessSummary>,
+}
+
+/// Process details included in [`ActionPluginInput`].
+#[derive(Debug, Clone, Serialize)]
+pub struct ActionProcessSummary {
+    /// Parent process ID.
+    pub ppid: u32,
+    /// Owning user.
+    pub user: String,
+    /// Full command line.
+    pub cmd: String,
+    /// Process state at scan time.
+    pub state: ProcessState,
+    /// CPU usage percentage at scan time.
+    pub cpu_percent: f64,
+    /// Resident set size in bytes.
+    pub rss_bytes: u64,
+    /// Seconds since the process started.
+    pub elapsed_secs: u64,
+}
+
+impl From<&ProcessRecord> for ActionProcessSummary {
+    fn from(record: &ProcessRecord) -> Self {
+        Self {
+            ppid: record.ppid.0,
+            user: record.user.clone(),
+            cmd: record.cmd.clone(),
+            state: record.state,
+            cpu_percent: record.cpu_percent,
+            rss_bytes: record.rss_bytes,
+            elapsed_secs: record.elapsed_seconds(),
+        }
+    }
 }
 
 /// Status of an action plugin invocation.
@@ -161,6 +221,8 @@ mod tests {
             classification: "zombie".to_string(),
             confidence: 0.97,
             session_id: Some("sess-1".to_string()),
+            posterior: None,
+            process_summary: None,
         };
         let json = serde_json::to_string(&input).unwrap();
         assert!(json.contains("\"action\":\"kill\""));
@@ -177,8 +239,49 @@ mod tests {
             classification: "abandoned".to_string(),
             confidence: 0.85,
             session_id: None,
+            posterior: None,
+            process_summary: None,
         };
         let json = serde_json::to_string(&input).unwrap();
         assert!(!json.contains("session_id"));
+        assert!(!json.contains("posterior"));
+        assert!(!json.contains("process_summary"));
+    }
+
+    #[test]
+    fn test_action_input_enriched_shape() {
+        let input = ActionPluginInput {
+            action: "kill".to_string(),
+            pid: 1234,
+            process_name: "runaway-worker".to_string(),
+            classification: "zombie".to_string(),
+            confidence: 0.97,
+            session_id: Some("abc-123".to_string()),
+            posterior: Some(ClassScores {
+                useful: 0.01,
+                useful_bad: 0.01,
+                abandoned: 0.01,
+                zombie: 0.97,
+            }),
+            process_summary: Some(ActionProcessSummary {
+                ppid: 1,
+                user: "svc".to_string(),
+                cmd: "runaway-worker --queue jobs".to_string(),
+                state: ProcessState::Zombie,
+                cpu_percent: 0.0,
+                rss_bytes: 0,
+                elapsed_secs: 86400,
+            }),
+        };
+        let value = serde_json::to_value(&input).unwrap();
+        assert_eq!(value["session_id"], "abc-123");
+        assert_eq!(value["posterior"]["zombie"], 0.97);
+        assert_eq!(value["posterior"]["useful_bad"], 0.01);
+        let summary = &value["process_summary"];
+        assert_eq!(summary["ppid"], 1);
+        assert_eq!(summary["user"], "svc");
+        assert_eq!(summary["cmd"], "runaway-worker --queue jobs");
+        assert_eq!(summary["state"], "zombie");
+        assert_eq!(summary["elapsed_secs"], 86400);
     }
 }
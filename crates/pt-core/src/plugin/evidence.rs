@@ -1,7 +1,9 @@
 //! Evidence source plugin interface.
 //!
 //! Evidence plugins receive a JSON array of PIDs on stdin and return
-//! per-process evidence as JSON on stdout. The evidence is converted into
+//! per-process evidence as JSON on stdout. All PIDs of a scan go to the
+//! plugin in one invocation unless its manifest sets `supports_batch = false`,
+//! in which case it is run once per PID. The evidence is converted into
 //! [`EvidenceTerm`] entries and folded into the posterior computation.
 //!
 //! # Plugin protocol (stdin → stdout)
@@ -35,6 +37,7 @@
 //! ```
 
 use crate::inference::posterior::{ClassScores, EvidenceTerm};
+use pt_common::ProcessId;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use thiserror::Error;
@@ -69,13 +72,34 @@ pub struct EvidencePluginInput {
     pub scan_id: Option<String>,
 }
 
+impl EvidencePluginInput {
+    /// Input covering every PID in `pids`, for a single batched invocation.
+    pub fn for_pids(pids: &[ProcessId], scan_id: Option<String>) -> Self {
+        Self {
+            pids: pids.iter().map(|pid| pid.0).collect(),
+            scan_id,
+        }
+    }
+
+    /// Split into one input per PID, for plugins without batch support.
+    pub fn per_pid(&self) -> Vec<Self> {
+        self.pids
+            .iter()
+            .map(|&pid| Self {
+                pids: vec![pid],
+                scan_id: self.scan_id.clone(),
+            })
+            .collect()
+    }
+}
+
 /// A single process evidence entry from a plugin.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PluginEvidenceEntry {
     /// Process ID this evidence applies to.
     pub pid: u32,
     /// Named features (for audit/display).
-    #[serde(default)]
+    #[serde(default, serialize_with = "pt_common::serialize_sorted")]
     pub features: HashMap<String, f64>,
     /// Per-class log-likelihoods.
     pub log_likelihoods: PluginLogLikelihoods,
@@ -158,6 +182,26 @@ pub fn evidence_for_pid(output: &EvidencePluginOutput, pid: u32) -> Option<&Plug
     output.evidence.iter().find(|e| e.pid == pid)
 }
 
+/// Key a batched plugin output by PID, keeping only the requested PIDs.
+///
+/// PIDs the plugin returned no entry for are absent. If the plugin repeats
+/// a PID, the first entry wins, matching [`evidence_for_pid`].
+pub fn evidence_for_pids(
+    output: &EvidencePluginOutput,
+    pids: &[ProcessId],
+) -> HashMap<ProcessId, PluginLogLikelihoods> {
+    let mut keyed = HashMap::with_capacity(pids.len());
+    for entry in &output.evidence {
+        let pid = ProcessId(entry.pid);
+        if pids.contains(&pid) {
+            keyed
+                .entry(pid)
+                .or_insert_with(|| entry.log_likelihoods.clone());
+        }
+    }
+    keyed
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -288,6 +332,21 @@ mod tests {
         assert!((e200.log_likelihoods.useful - (-2.0)).abs() < f64::EPSILON);
 
         assert!(evidence_for_pid(&output, 999).is_none());
+
+        let keyed = evidence_for_pids(&output, &[ProcessId(200), ProcessId(999)]);
+        assert_eq!(keyed.len(), 1);
+        assert!((keyed[&ProcessId(200)].useful - (-2.0)).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_input_for_pids_and_per_pid_split() {
+        let input =
+            EvidencePluginInput::for_pids(&[ProcessId(1), ProcessId(2)], Some("s".to_string()));
+        assert_eq!(input.pids, vec![1, 2]);
+        let split = input.per_pid();
+        assert_eq!(split.len(), 2);
+        assert_eq!(split[1].pids, vec![2]);
+        assert_eq!(split[1].scan_id.as_deref(), Some("s"));
     }
 
     #[test]
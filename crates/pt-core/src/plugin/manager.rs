@@ -9,22 +9,44 @@
 
 use std::collections::HashMap;
 use std::io::Write;
+use std::num::NonZeroUsize;
 use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
 use std::time::{Duration, Instant};
 
+use serde::Serialize;
 use tracing::{debug, info, warn};
 
 // use crate::collect::tool_runner::{ToolRunner, ToolSpec};
 use crate::plugin::action::{ActionPluginError, ActionPluginInput, ActionPluginOutput};
 use crate::plugin::evidence::{EvidencePluginError, EvidencePluginInput, EvidencePluginOutput};
-use crate::plugin::manifest::{load_manifest, ManifestError, PluginType, ResolvedPlugin};
+use crate::plugin::manifest::{
+    check_api_version, load_manifest, read_manifest, resolve_manifest, ManifestError, PluginType,
+    ResolvedPlugin, PLUGIN_API_VERSION,
+};
 
 use thiserror::Error;
 
 /// Default plugins subdirectory name under the config dir.
 const PLUGINS_DIR_NAME: &str = "plugins";
 
+/// Cooldown after a plugin first reaches its failure limit.
+pub const DEFAULT_RETRY_BASE: Duration = Duration::from_secs(30);
+
+/// Upper bound on the auto-disable cooldown.
+pub const DEFAULT_RETRY_MAX: Duration = Duration::from_secs(3600);
+
+/// Upper bound on cached evidence outputs across all plugins.
+///
+/// Keys include the full PID set, so a long-running daemon would otherwise
+/// accumulate one entry per distinct scan.
+pub const MAX_EVIDENCE_CACHE_ENTRIES: usize = 256;
+
+/// Scan identifier sent to evidence plugins during a health check.
+pub const HEALTH_CHECK_SCAN_ID: &str = "health-check";
+
 /// Errors from the plugin manager.
 #[derive(Debug, Error)]
 pub enum PluginManagerError {
@@ -41,6 +63,132 @@ pub enum PluginManagerError {
     NoPlugins,
 }
 
+/// Outcome of a plugin health check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PluginHealthStatus {
+    /// Plugin ran and returned parseable output.
+    Healthy,
+    /// Plugin command does not exist.
+    MissingExecutable,
+    /// Plugin command exists but is not executable.
+    NotExecutable,
+    /// Plugin could not be spawned or exited unsuccessfully.
+    Failed,
+    /// Plugin exceeded its invocation timeout.
+    Timeout,
+    /// Plugin ran but its output did not parse.
+    InvalidOutput,
+}
+
+/// Health report for a single plugin.
+#[derive(Debug, Clone, Serialize)]
+pub struct PluginHealth {
+    /// Plugin name.
+    pub name: String,
+    /// Plugin type.
+    pub plugin_type: PluginType,
+    /// Health check outcome.
+    pub status: PluginHealthStatus,
+    /// Whether the plugin is currently disabled by the manager.
+    pub disabled: bool,
+    /// Measured invocation latency (absent if the plugin was never run).
+    pub latency_ms: Option<u64>,
+    /// Error detail for unhealthy plugins.
+    pub error: Option<String>,
+}
+
+impl PluginHealth {
+    /// Whether the plugin passed the health check.
+    pub fn is_healthy(&self) -> bool {
+        self.status == PluginHealthStatus::Healthy
+    }
+}
+
+/// One check performed by [`PluginManager::validate_plugin`].
+#[derive(Debug, Clone, Serialize)]
+pub struct ValidationCheck {
+    /// Check name (`manifest`, `api_version`, `executable`, `invocation`).
+    pub name: &'static str,
+    /// Whether the check passed.
+    pub passed: bool,
+    /// What was found, or why the check failed.
+    pub message: String,
+}
+
+/// Result of validating a plugin directory before deployment.
+#[derive(Debug, Clone, Serialize)]
+pub struct ValidationReport {
+    /// Directory that was validated.
+    pub plugin_dir: PathBuf,
+    /// Plugin name from the manifest.
+    pub name: String,
+    /// Plugin type from the manifest.
+    pub plugin_type: PluginType,
+    /// Checks in the order they ran.
+    pub checks: Vec<ValidationCheck>,
+}
+
+impl ValidationReport {
+    /// Whether every check passed.
+    pub fn passed(&self) -> bool {
+        self.checks.iter().all(|c| c.passed)
+    }
+
+    fn record(&mut self, name: &'static str, outcome: Result<String, String>) {
+        let (passed, message) = match outcome {
+            Ok(message) => (true, message),
+            Err(message) => (false, message),
+        };
+        self.checks.push(ValidationCheck {
+            name,
+            passed,
+            message,
+        });
+    }
+}
+
+/// Runtime state of a loaded plugin, as reported by [`PluginManager::plugin_health`].
+#[derive(Debug, Clone, Serialize)]
+pub struct PluginStatus {
+    /// Plugin name.
+    pub name: String,
+    /// Plugin type.
+    pub plugin_type: PluginType,
+    /// Whether the plugin would be invoked right now.
+    pub enabled: bool,
+    /// Whether the plugin was disabled via [`PluginManager::disable`].
+    pub manually_disabled: bool,
+    /// Consecutive failure count.
+    pub consecutive_failures: u32,
+    /// Time until the plugin is retried (present while auto-disabled).
+    pub retry_in_ms: Option<u64>,
+}
+
+/// Exponential cooldown applied once a plugin reaches its failure limit.
+#[derive(Debug, Clone, Copy)]
+struct RetryBackoff {
+    base: Duration,
+    max: Duration,
+}
+
+impl RetryBackoff {
+    /// Cooldown for the `step`-th failure past the limit (0 = first).
+    fn cooldown(self, step: u32) -> Duration {
+        let factor = 1u32.checked_shl(step).unwrap_or(u32::MAX);
+        self.base.saturating_mul(factor).min(self.max)
+    }
+}
+
+impl Default for RetryBackoff {
+    fn default() -> Self {
+        Self {
+            base: DEFAULT_RETRY_BASE,
+            max: DEFAULT_RETRY_MAX,
+        }
+    }
+}
+
 /// Per-plugin runtime state tracked by the manager.
 #[derive(Debug)]
 struct PluginState {
@@ -48,8 +196,10 @@ struct PluginState {
     plugin: ResolvedPlugin,
     /// Consecutive failure count.
     consecutive_failures: u32,
-    /// Whether the plugin has been auto-disabled.
+    /// Whether the plugin has been manually disabled.
     disabled: bool,
+    /// When an auto-disabled plugin becomes eligible for another attempt.
+    next_retry_at: Option<Instant>,
     /// Last invocation duration (for telemetry).
     last_duration: Option<Duration>,
 }
@@ -60,30 +210,66 @@ impl PluginState {
             plugin,
             consecutive_failures: 0,
             disabled: false,
+            next_retry_at: None,
             last_duration: None,
         }
     }
 
+    /// Whether the plugin may be invoked (not disabled, not cooling down).
+    fn is_active(&self) -> bool {
+        !self.disabled && self.next_retry_at.is_none_or(|at| Instant::now() >= at)
+    }
+
     fn record_success(&mut self, duration: Duration) {
         self.consecutive_failures = 0;
+        self.next_retry_at = None;
         self.last_duration = Some(duration);
     }
 
-    fn record_failure(&mut self) {
+    /// Count a failure; at or past the limit, back off before the next
+    /// attempt, doubling the cooldown with each further failure.
+    fn record_failure(&mut self, backoff: RetryBackoff) {
         self.consecutive_failures += 1;
         let max = self.plugin.manifest.limits.max_failures;
         if self.consecutive_failures >= max {
+            let cooldown = backoff.cooldown(self.consecutive_failures - max);
             warn!(
                 plugin = %self.plugin.manifest.name,
                 failures = self.consecutive_failures,
+                cooldown_secs = cooldown.as_secs_f64(),
                 "auto-disabling plugin after {} consecutive failures",
                 self.consecutive_failures,
             );
-            self.disabled = true;
+            self.next_retry_at = Some(Instant::now() + cooldown);
+        }
+    }
+
+    fn status(&self) -> PluginStatus {
+        let retry_in = self
+            .next_retry_at
+            .and_then(|at| at.checked_duration_since(Instant::now()))
+            .filter(|d| !d.is_zero());
+        PluginStatus {
+            name: self.plugin.manifest.name.clone(),
+            plugin_type: self.plugin.manifest.plugin_type,
+            enabled: self.is_active(),
+            manually_disabled: self.disabled,
+            consecutive_failures: self.consecutive_failures,
+            retry_in_ms: retry_in.map(|d| d.as_millis() as u64),
         }
     }
 }
 
+/// Evidence cache key: plugin name plus the sorted, deduplicated PID set.
+type EvidenceCacheKey = (String, Vec<u32>);
+
+/// A cached evidence plugin result.
+#[derive(Debug)]
+struct CachedEvidence {
+    output: EvidencePluginOutput,
+    expires_at: Instant,
+}
+
 /// Plugin manager handles discovery, loading, and invocation of plugins.
 #[derive(Debug)]
 pub struct PluginManager {
@@ -91,6 +277,12 @@ pub struct PluginManager {
     plugins: HashMap<String, PluginState>,
     /// Directory where plugins are stored.
     plugins_dir: PathBuf,
+    /// Maximum number of evidence plugins run concurrently.
+    max_parallel: usize,
+    /// Evidence outputs for plugins that opt in via `cache_ttl_secs`.
+    evidence_cache: HashMap<EvidenceCacheKey, CachedEvidence>,
+    /// Cooldown schedule for auto-disabled plugins.
+    backoff: RetryBackoff,
 }
 
 impl PluginManager {
@@ -107,6 +299,9 @@ impl PluginManager {
             return Ok(Self {
                 plugins: HashMap::new(),
                 plugins_dir: plugins_dir.to_path_buf(),
+                max_parallel: default_max_parallel(),
+                evidence_cache: HashMap::new(),
+                backoff: RetryBackoff::default(),
             });
         }
 
@@ -148,6 +343,9 @@ impl PluginManager {
         Ok(Self {
             plugins,
             plugins_dir: plugins_dir.to_path_buf(),
+            max_parallel: default_max_parallel(),
+            evidence_cache: HashMap::new(),
+            backoff: RetryBackoff::default(),
         })
     }
 
@@ -156,9 +354,35 @@ impl PluginManager {
         Self {
             plugins: HashMap::new(),
             plugins_dir: PathBuf::new(),
+            max_parallel: default_max_parallel(),
+            evidence_cache: HashMap::new(),
+            backoff: RetryBackoff::default(),
         }
     }
 
+    /// Set the maximum number of evidence plugins run concurrently.
+    ///
+    /// Values below 1 are treated as 1 (sequential execution).
+    pub fn with_max_parallel(mut self, max_parallel: usize) -> Self {
+        self.max_parallel = max_parallel.max(1);
+        self
+    }
+
+    /// Set the auto-disable cooldown: `base` after a plugin first reaches
+    /// its failure limit, doubling per further failure up to `max`.
+    pub fn with_retry_backoff(mut self, base: Duration, max: Duration) -> Self {
+        self.backoff = RetryBackoff {
+            base,
+            max: max.max(base),
+        };
+        self
+    }
+
+    /// Maximum number of evidence plugins run concurrently.
+    pub fn max_parallel(&self) -> usize {
+        self.max_parallel
+    }
+
     /// The plugins directory path.
     pub fn plugins_dir(&self) -> &Path {
         &self.plugins_dir
@@ -171,7 +395,7 @@ impl PluginManager {
 
     /// Number of active (non-disabled) plugins.
     pub fn active_count(&self) -> usize {
-        self.plugins.values().filter(|s| !s.disabled).count()
+        self.plugins.values().filter(|s| s.is_active()).count()
     }
 
     /// List loaded plugin names.
@@ -183,7 +407,7 @@ impl PluginManager {
     pub fn evidence_plugins(&self) -> Vec<&ResolvedPlugin> {
         self.plugins
             .values()
-            .filter(|s| !s.disabled && s.plugin.manifest.plugin_type == PluginType::Evidence)
+            .filter(|s| s.is_active() && s.plugin.manifest.plugin_type == PluginType::Evidence)
             .map(|s| &s.plugin)
             .collect()
     }
@@ -192,14 +416,21 @@ impl PluginManager {
     pub fn action_plugins(&self) -> Vec<&ResolvedPlugin> {
         self.plugins
             .values()
-            .filter(|s| !s.disabled && s.plugin.manifest.plugin_type == PluginType::Action)
+            .filter(|s| s.is_active() && s.plugin.manifest.plugin_type == PluginType::Action)
             .map(|s| &s.plugin)
             .collect()
     }
 
-    /// Check if a specific plugin is disabled.
+    /// Check if a specific plugin is disabled (manually or cooling down).
     pub fn is_disabled(&self, name: &str) -> bool {
-        self.plugins.get(name).is_none_or(|s| s.disabled)
+        self.plugins.get(name).is_none_or(|s| !s.is_active())
+    }
+
+    /// Runtime state of every loaded plugin, sorted by name.
+    pub fn plugin_health(&self) -> Vec<PluginStatus> {
+        let mut statuses: Vec<PluginStatus> = self.plugins.values().map(|s| s.status()).collect();
+        statuses.sort_by(|a, b| a.name.cmp(&b.name));
+        statuses
     }
 
     /// Manually disable a plugin.
@@ -209,24 +440,80 @@ impl PluginManager {
         }
     }
 
-    /// Manually re-enable a plugin (resets failure counter).
+    /// Manually re-enable a plugin (resets failure counter and cooldown).
     pub fn enable(&mut self, name: &str) {
         if let Some(state) = self.plugins.get_mut(name) {
             state.disabled = false;
             state.consecutive_failures = 0;
+            state.next_retry_at = None;
+        }
+    }
+
+    /// Drop all cached evidence outputs.
+    pub fn clear_cache(&mut self) {
+        self.evidence_cache.clear();
+    }
+
+    /// Return a cached output for `plugin` if it opted in and the entry is fresh.
+    fn cached_evidence(
+        &self,
+        plugin: &ResolvedPlugin,
+        key: &EvidenceCacheKey,
+    ) -> Option<EvidencePluginOutput> {
+        plugin.manifest.cache_ttl()?;
+        let entry = self.evidence_cache.get(key)?;
+        (Instant::now() < entry.expires_at).then(|| entry.output.clone())
+    }
+
+    /// Remember a successful output for `plugin` if it opted in to caching.
+    ///
+    /// Expired entries are evicted first; if the cache is still at
+    /// [`MAX_EVIDENCE_CACHE_ENTRIES`], the entry closest to expiry goes.
+    fn store_evidence(
+        &mut self,
+        plugin: &ResolvedPlugin,
+        key: EvidenceCacheKey,
+        output: &EvidencePluginOutput,
+    ) {
+        let Some(ttl) = plugin.manifest.cache_ttl() else {
+            return;
+        };
+        let now = Instant::now();
+        self.evidence_cache
+            .retain(|_, entry| now < entry.expires_at);
+        if self.evidence_cache.len() >= MAX_EVIDENCE_CACHE_ENTRIES
+            && !self.evidence_cache.contains_key(&key)
+        {
+            let soonest = self
+                .evidence_cache
+                .iter()
+                .min_by_key(|(_, entry)| entry.expires_at)
+                .map(|(key, _)| key.clone());
+            if let Some(soonest) = soonest {
+                self.evidence_cache.remove(&soonest);
+            }
         }
+        self.evidence_cache.insert(
+            key,
+            CachedEvidence {
+                output: output.clone(),
+                expires_at: now + ttl,
+            },
+        );
     }
 
     /// Invoke an evidence plugin and return its output.
     ///
-    /// Returns `Ok(None)` if the plugin is disabled.
+    /// Returns `Ok(None)` if the plugin is disabled. Plugins that declare
+    /// `cache_ttl_secs` reuse their previous output for the same PID set
+    /// until the TTL expires.
     pub fn invoke_evidence(
         &mut self,
         plugin_name: &str,
         input: &EvidencePluginInput,
     ) -> Result<Option<EvidencePluginOutput>, EvidencePluginError> {
         match self.plugins.get(plugin_name) {
-            Some(s) if s.disabled => return Ok(None),
+            Some(s) if !s.is_active() => return Ok(None),
             Some(s) if s.plugin.manifest.plugin_type != PluginType::Evidence => {
                 return Err(EvidencePluginError::ExecutionFailed {
                     plugin: plugin_name.to_string(),
@@ -242,77 +529,44 @@ impl PluginManager {
             }
         }
 
-        // Get plugin info (need to borrow immutably first, then mutably)
-        let state =
-            self.plugins
-                .get(plugin_name)
-                .ok_or_else(|| EvidencePluginError::ExecutionFailed {
-                    plugin: plugin_name.to_string(),
-                    message: "plugin state not found".to_string(),
-                })?;
-        let command_path = state.plugin.command_path.clone();
-        let args = state.plugin.manifest.args.clone();
-        let timeout_ms = state.plugin.manifest.timeouts.invoke_ms;
-        let max_output = state.plugin.manifest.limits.max_output_bytes;
-        let plugin_dir = state.plugin.plugin_dir.clone();
-
         let input_json =
             serde_json::to_vec(input).map_err(|e| EvidencePluginError::ExecutionFailed {
                 plugin: plugin_name.to_string(),
                 message: format!("failed to serialize input: {e}"),
             })?;
 
-        match invoke_subprocess(
-            &command_path,
-            &args,
-            &plugin_dir,
-            &input_json,
-            timeout_ms,
-            max_output,
-        ) {
-            Ok((stdout, duration)) => {
-                match crate::plugin::evidence::parse_evidence_output(plugin_name, &stdout) {
-                    Ok(output) => {
-                        let state = self.plugins.get_mut(plugin_name).ok_or_else(|| {
-                            EvidencePluginError::ExecutionFailed {
-                                plugin: plugin_name.to_string(),
-                                message: "plugin state not found".to_string(),
-                            }
-                        })?;
-                        state.record_success(duration);
-                        Ok(Some(output))
-                    }
-                    Err(e) => {
-                        let state = self.plugins.get_mut(plugin_name).ok_or_else(|| {
-                            EvidencePluginError::ExecutionFailed {
-                                plugin: plugin_name.to_string(),
-                                message: "plugin state not found".to_string(),
-                            }
-                        })?;
-                        state.record_failure();
-                        Err(e)
-                    }
-                }
+        let plugin = self
+            .plugins
+            .get(plugin_name)
+            .map(|s| s.plugin.clone())
+            .ok_or_else(|| EvidencePluginError::ExecutionFailed {
+                plugin: plugin_name.to_string(),
+                message: "plugin state not found".to_string(),
+            })?;
+
+        let key = evidence_cache_key(plugin_name, input);
+        if let Some(output) = self.cached_evidence(&plugin, &key) {
+            debug!(plugin = %plugin_name, "evidence plugin cache hit");
+            return Ok(Some(output));
+        }
+
+        let outcome = run_evidence_plugin_for(&plugin, input, &input_json);
+        let state = self.plugins.get_mut(plugin_name).ok_or_else(|| {
+            EvidencePluginError::ExecutionFailed {
+                plugin: plugin_name.to_string(),
+                message: "plugin state not found".to_string(),
             }
-            Err(msg) => {
-                let state = self.plugins.get_mut(plugin_name).ok_or_else(|| {
-                    EvidencePluginError::ExecutionFailed {
-                        plugin: plugin_name.to_string(),
-                        message: "plugin state not found".to_string(),
-                    }
-                })?;
-                state.record_failure();
-                if msg.contains("timed out") {
-                    Err(EvidencePluginError::Timeout {
-                        plugin: plugin_name.to_string(),
-                        timeout_ms,
-                    })
-                } else {
-                    Err(EvidencePluginError::ExecutionFailed {
-                        plugin: plugin_name.to_string(),
-                        message: msg,
-                    })
-                }
+        })?;
+
+        match outcome {
+            Ok((output, duration)) => {
+                state.record_success(duration);
+                self.store_evidence(&plugin, key, &output);
+                Ok(Some(output))
+            }
+            Err(e) => {
+                state.record_failure(self.backoff);
+                Err(e)
             }
         }
     }
@@ -326,7 +580,7 @@ impl PluginManager {
         input: &ActionPluginInput,
     ) -> Result<Option<ActionPluginOutput>, ActionPluginError> {
         match self.plugins.get(plugin_name) {
-            Some(s) if s.disabled => return Ok(None),
+            Some(s) if !s.is_active() => return Ok(None),
             Some(s) if s.plugin.manifest.plugin_type != PluginType::Action => {
                 return Err(ActionPluginError::ExecutionFailed {
                     plugin: plugin_name.to_string(),
@@ -342,110 +596,186 @@ impl PluginManager {
             }
         }
 
-        let state =
-            self.plugins
-                .get(plugin_name)
-                .ok_or_else(|| ActionPluginError::ExecutionFailed {
-                    plugin: plugin_name.to_string(),
-                    message: "plugin state not found".to_string(),
-                })?;
-        let command_path = state.plugin.command_path.clone();
-        let args = state.plugin.manifest.args.clone();
-        let timeout_ms = state.plugin.manifest.timeouts.invoke_ms;
-        let max_output = state.plugin.manifest.limits.max_output_bytes;
-        let plugin_dir = state.plugin.plugin_dir.clone();
-
         let input_json =
             serde_json::to_vec(input).map_err(|e| ActionPluginError::ExecutionFailed {
                 plugin: plugin_name.to_string(),
                 message: format!("failed to serialize input: {e}"),
             })?;
 
-        match invoke_subprocess(
-            &command_path,
-            &args,
-            &plugin_dir,
-            &input_json,
-            timeout_ms,
-            max_output,
-        ) {
-            Ok((stdout, duration)) => {
-                match crate::plugin::action::parse_action_output(plugin_name, &stdout) {
-                    Ok(output) => {
-                        let state = self.plugins.get_mut(plugin_name).ok_or_else(|| {
-                            ActionPluginError::ExecutionFailed {
-                                plugin: plugin_name.to_string(),
-                                message: "plugin state not found".to_string(),
-                            }
-                        })?;
-                        state.record_success(duration);
-                        Ok(Some(output))
-                    }
-                    Err(e) => {
-                        let state = self.plugins.get_mut(plugin_name).ok_or_else(|| {
-                            ActionPluginError::ExecutionFailed {
-                                plugin: plugin_name.to_string(),
-                                message: "plugin state not found".to_string(),
-                            }
-                        })?;
-                        state.record_failure();
-                        Err(e)
-                    }
-                }
+        let state = self.plugins.get_mut(plugin_name).ok_or_else(|| {
+            ActionPluginError::ExecutionFailed {
+                plugin: plugin_name.to_string(),
+                message: "plugin state not found".to_string(),
             }
-            Err(msg) => {
-                let state = self.plugins.get_mut(plugin_name).ok_or_else(|| {
-                    ActionPluginError::ExecutionFailed {
-                        plugin: plugin_name.to_string(),
-                        message: "plugin state not found".to_string(),
-                    }
-                })?;
-                state.record_failure();
-                if msg.contains("timed out") {
-                    Err(ActionPluginError::Timeout {
-                        plugin: plugin_name.to_string(),
-                        timeout_ms,
-                    })
-                } else {
-                    Err(ActionPluginError::ExecutionFailed {
-                        plugin: plugin_name.to_string(),
-                        message: msg,
-                    })
-                }
+        })?;
+
+        match run_action_plugin(&state.plugin, &input_json) {
+            Ok((output, duration)) => {
+                state.record_success(duration);
+                Ok(Some(output))
+            }
+            Err(e) => {
+                state.record_failure(self.backoff);
+                Err(e)
             }
         }
     }
 
+    /// Probe every loaded plugin with a minimal well-formed input.
+    ///
+    /// Action plugins only get the executable check: any input they receive
+    /// may trigger a real notification or hook. Disabled plugins are probed
+    /// too, so a plugin can be diagnosed after
+    /// auto-disable. Health checks do not touch failure counters. Reports are
+    /// sorted by plugin name.
+    pub fn health_check(&self) -> Vec<PluginHealth> {
+        let mut reports: Vec<PluginHealth> = self
+            .plugins
+            .values()
+            .map(|state| {
+                let mut report = probe_plugin(&state.plugin);
+                report.disabled = !state.is_active();
+                report
+            })
+            .collect();
+        reports.sort_by(|a, b| a.name.cmp(&b.name));
+        reports
+    }
+
+    /// Validate a plugin directory without installing it.
+    ///
+    /// Checks that the manifest's fields are valid, that its API version is
+    /// compatible with [`PLUGIN_API_VERSION`], and that the command exists
+    /// and is executable, then runs an evidence plugin once with the
+    /// health-check input to confirm it returns parseable output within its
+    /// timeout. Action plugins are never invoked, and the invocation is
+    /// skipped if an earlier check failed.
+    ///
+    /// Errors only when `plugin.toml` is missing or cannot be parsed.
+    pub fn validate_plugin(dir: &Path) -> Result<ValidationReport, ManifestError> {
+        let manifest = read_manifest(dir)?;
+        let mut report = ValidationReport {
+            plugin_dir: dir.to_path_buf(),
+            name: manifest.name.clone(),
+            plugin_type: manifest.plugin_type,
+            checks: Vec::new(),
+        };
+        let api_version = check_api_version(&manifest.api_version, PLUGIN_API_VERSION)
+            .map(|()| {
+                format!(
+                    "api_version {} is compatible with {}",
+                    manifest.api_version, PLUGIN_API_VERSION
+                )
+            })
+            .map_err(|e| e.to_string());
+
+        let resolved = match resolve_manifest(manifest, dir) {
+            Ok(resolved) => {
+                report.record("manifest", Ok("manifest is valid".to_string()));
+                report.record("api_version", api_version);
+                let executable = check_executable(&resolved.command_path)
+                    .map(|()| format!("{} is executable", resolved.command_path.display()))
+                    .map_err(|(_, error)| error);
+                report.record("executable", executable);
+                Some(resolved)
+            }
+            Err(e @ ManifestError::CommandNotFound { .. }) => {
+                report.record("manifest", Ok("manifest is valid".to_string()));
+                report.record("api_version", api_version);
+                report.record("executable", Err(e.to_string()));
+                None
+            }
+            Err(e) => {
+                report.record("manifest", Err(e.to_string()));
+                report.record("api_version", api_version);
+                None
+            }
+        };
+
+        let invocation = match resolved {
+            Some(plugin) if plugin.manifest.plugin_type == PluginType::Action => {
+                Ok("not invoked: action plugins run only for real actions".to_string())
+            }
+            Some(plugin) if report.passed() => {
+                let health = probe_plugin(&plugin);
+                let latency = health.latency_ms.unwrap_or_default();
+                match health.error {
+                    None => Ok(format!("returned parseable output in {latency}ms")),
+                    Some(error) => Err(error),
+                }
+            }
+            _ => Err("skipped: earlier checks failed".to_string()),
+        };
+        report.record("invocation", invocation);
+
+        Ok(report)
+    }
+
     /// Invoke all active evidence plugins and collect results.
     ///
+    /// Plugins run concurrently, at most [`max_parallel`](Self::max_parallel)
+    /// at a time; each keeps its own timeout and output cap. Results are
+    /// returned sorted by plugin name so downstream aggregation is
+    /// independent of completion order.
+    ///
     /// Failed/timed-out plugins are logged and skipped (graceful degradation).
     pub fn invoke_all_evidence(
         &mut self,
         input: &EvidencePluginInput,
     ) -> Vec<(String, EvidencePluginOutput)> {
-        let names: Vec<String> = self
-            .evidence_plugins()
-            .iter()
-            .map(|p| p.manifest.name.clone())
-            .collect();
+        let mut plugins: Vec<ResolvedPlugin> =
+            self.evidence_plugins().into_iter().cloned().collect();
+        if plugins.is_empty() {
+            return Vec::new();
+        }
+        plugins.sort_by(|a, b| a.manifest.name.cmp(&b.manifest.name));
+
+        let input_json = match serde_json::to_vec(input) {
+            Ok(json) => json,
+            Err(e) => {
+                warn!(error = %e, "failed to serialize evidence plugin input");
+                return Vec::new();
+            }
+        };
 
         let mut results = Vec::new();
+        let mut pending = Vec::new();
+
+        for plugin in plugins {
+            let key = evidence_cache_key(plugin.id(), input);
+            match self.cached_evidence(&plugin, &key) {
+                Some(output) => {
+                    debug!(plugin = %plugin.id(), "evidence plugin cache hit");
+                    results.push((plugin.id().to_string(), output));
+                }
+                None => pending.push(plugin),
+            }
+        }
 
-        for name in names {
-            match self.invoke_evidence(&name, input) {
-                Ok(Some(output)) => {
+        let outcomes = run_bounded(&pending, self.max_parallel, |plugin| {
+            run_evidence_plugin_for(plugin, input, &input_json)
+        });
+
+        for (plugin, outcome) in pending.iter().zip(outcomes) {
+            let name = plugin.id();
+            let Some(state) = self.plugins.get_mut(name) else {
+                continue;
+            };
+            match outcome {
+                Ok((output, duration)) => {
+                    state.record_success(duration);
                     debug!(plugin = %name, entries = output.evidence.len(), "evidence plugin succeeded");
-                    results.push((name, output));
-                }
-                Ok(None) => {
-                    debug!(plugin = %name, "evidence plugin disabled, skipping");
+                    self.store_evidence(plugin, evidence_cache_key(name, input), &output);
+                    results.push((name.to_string(), output));
                 }
                 Err(e) => {
+                    state.record_failure(self.backoff);
                     warn!(plugin = %name, error = %e, "evidence plugin failed, skipping");
                 }
             }
         }
 
+        results.sort_by(|a, b| a.0.cmp(&b.0));
         results
     }
 
@@ -483,6 +813,266 @@ impl PluginManager {
     }
 }
 
+/// Build the cache key for an evidence invocation (PID order is irrelevant).
+fn evidence_cache_key(plugin_name: &str, input: &EvidencePluginInput) -> EvidenceCacheKey {
+    let mut pids = input.pids.clone();
+    pids.sort_unstable();
+    pids.dedup();
+    (plugin_name.to_string(), pids)
+}
+
+/// Default evidence-plugin concurrency: one worker per available CPU.
+fn default_max_parallel() -> usize {
+    std::thread::available_parallelism()
+        .map(NonZeroUsize::get)
+        .unwrap_or(1)
+}
+
+/// Run an evidence plugin subprocess and parse its output.
+fn run_evidence_plugin(
+    plugin: &ResolvedPlugin,
+    input_json: &[u8],
+) -> Result<(EvidencePluginOutput, Duration), EvidencePluginError> {
+    run_evidence_plugin_within(plugin, input_json, plugin.manifest.timeouts.invoke_ms)
+}
+
+/// [`run_evidence_plugin`] with an explicit invocation timeout.
+fn run_evidence_plugin_within(
+    plugin: &ResolvedPlugin,
+    input_json: &[u8],
+    timeout_ms: u64,
+) -> Result<(EvidencePluginOutput, Duration), EvidencePluginError> {
+    let name = plugin.id();
+
+    match invoke_subprocess(
+        &plugin.command_path,
+        &plugin.manifest.args,
+        &plugin.plugin_dir,
+        input_json,
+        timeout_ms,
+        plugin.manifest.limits.max_output_bytes,
+    ) {
+        Ok((stdout, duration)) => {
+            let output = crate::plugin::evidence::parse_evidence_output(name, &stdout)?;
+            Ok((output, duration))
+        }
+        Err(msg) if msg.contains("timed out") => Err(EvidencePluginError::Timeout {
+            plugin: name.to_string(),
+            timeout_ms,
+        }),
+        Err(msg) => Err(EvidencePluginError::ExecutionFailed {
+            plugin: name.to_string(),
+            message: msg,
+        }),
+    }
+}
+
+/// Run an evidence plugin for `input`, honoring its `supports_batch` flag.
+///
+/// Batch-capable plugins get `input_json` (the serialized `input`) in a
+/// single invocation. Others are invoked once per PID and their entries
+/// merged; the first failing PID fails the whole call. The per-PID calls
+/// share the manifest's `invoke_ms` as one total deadline, so a long PID
+/// list takes no longer than a single batch invocation would be allowed to.
+fn run_evidence_plugin_for(
+    plugin: &ResolvedPlugin,
+    input: &EvidencePluginInput,
+    input_json: &[u8],
+) -> Result<(EvidencePluginOutput, Duration), EvidencePluginError> {
+    if plugin.manifest.supports_batch || input.pids.len() <= 1 {
+        return run_evidence_plugin(plugin, input_json);
+    }
+
+    let timeout_ms = plugin.manifest.timeouts.invoke_ms;
+    let deadline = Instant::now() + Duration::from_millis(timeout_ms);
+    let timed_out = || EvidencePluginError::Timeout {
+        plugin: plugin.id().to_string(),
+        timeout_ms,
+    };
+
+    let mut merged: Option<EvidencePluginOutput> = None;
+    let mut total = Duration::ZERO;
+    for single in input.per_pid() {
+        let remaining_ms = deadline
+            .saturating_duration_since(Instant::now())
+            .as_millis() as u64;
+        if remaining_ms == 0 {
+            return Err(timed_out());
+        }
+        let json =
+            serde_json::to_vec(&single).map_err(|e| EvidencePluginError::ExecutionFailed {
+                plugin: plugin.id().to_string(),
+                message: format!("failed to serialize input: {e}"),
+            })?;
+        let (output, duration) =
+            run_evidence_plugin_within(plugin, &json, remaining_ms).map_err(|e| match e {
+                EvidencePluginError::Timeout { .. } => timed_out(),
+                other => other,
+            })?;
+        total += duration;
+        match merged.as_mut() {
+            Some(acc) => acc.evidence.extend(output.evidence),
+            None => merged = Some(output),
+        }
+    }
+    let output = merged.ok_or_else(|| EvidencePluginError::EmptyOutput {
+        plugin: plugin.id().to_string(),
+    })?;
+    Ok((output, total))
+}
+
+/// Run an action plugin subprocess and parse its output.
+fn run_action_plugin(
+    plugin: &ResolvedPlugin,
+    input_json: &[u8],
+) -> Result<(ActionPluginOutput, Duration), ActionPluginError> {
+    let name = plugin.id();
+    let timeout_ms = plugin.manifest.timeouts.invoke_ms;
+
+    match invoke_subprocess(
+        &plugin.command_path,
+        &plugin.manifest.args,
+        &plugin.plugin_dir,
+        input_json,
+        timeout_ms,
+        plugin.manifest.limits.max_output_bytes,
+    ) {
+        Ok((stdout, duration)) => {
+            let output = crate::plugin::action::parse_action_output(name, &stdout)?;
+            Ok((output, duration))
+        }
+        Err(msg) if msg.contains("timed out") => Err(ActionPluginError::Timeout {
+            plugin: name.to_string(),
+            timeout_ms,
+        }),
+        Err(msg) => Err(ActionPluginError::ExecutionFailed {
+            plugin: name.to_string(),
+            message: msg,
+        }),
+    }
+}
+
+/// Check that a plugin command exists and is runnable.
+fn check_executable(path: &Path) -> Result<(), (PluginHealthStatus, String)> {
+    let metadata = std::fs::metadata(path).map_err(|e| {
+        (
+            PluginHealthStatus::MissingExecutable,
+            format!("{}: {e}", path.display()),
+        )
+    })?;
+    if !metadata.is_file() {
+        return Err((
+            PluginHealthStatus::MissingExecutable,
+            format!("{} is not a file", path.display()),
+        ));
+    }
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        if metadata.permissions().mode() & 0o111 == 0 {
+            return Err((
+                PluginHealthStatus::NotExecutable,
+                format!("{} is not executable", path.display()),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Check a plugin's executable and, for evidence plugins, invoke it once
+/// with a minimal input; report its health.
+fn probe_plugin(plugin: &ResolvedPlugin) -> PluginHealth {
+    let mut report = PluginHealth {
+        name: plugin.id().to_string(),
+        plugin_type: plugin.manifest.plugin_type,
+        status: PluginHealthStatus::Healthy,
+        disabled: false,
+        latency_ms: None,
+        error: None,
+    };
+
+    if let Err((status, error)) = check_executable(&plugin.command_path) {
+        report.status = status;
+        report.error = Some(error);
+        return report;
+    }
+
+    // Action plugins act on whatever input they get (a notifier would page
+    // someone), so they are only checked for an executable command.
+    if plugin.manifest.plugin_type == PluginType::Action {
+        return report;
+    }
+
+    let input_json = match serde_json::to_vec(&EvidencePluginInput {
+        pids: Vec::new(),
+        scan_id: Some(HEALTH_CHECK_SCAN_ID.to_string()),
+    }) {
+        Ok(json) => json,
+        Err(e) => {
+            report.status = PluginHealthStatus::Failed;
+            report.error = Some(format!("failed to serialize input: {e}"));
+            return report;
+        }
+    };
+
+    let start = Instant::now();
+    let outcome = run_evidence_plugin(plugin, &input_json).map_err(|e| {
+        let status = match e {
+            EvidencePluginError::InvalidOutput { .. } | EvidencePluginError::EmptyOutput { .. } => {
+                PluginHealthStatus::InvalidOutput
+            }
+            EvidencePluginError::Timeout { .. } => PluginHealthStatus::Timeout,
+            EvidencePluginError::ExecutionFailed { .. } => PluginHealthStatus::Failed,
+        };
+        (status, e.to_string())
+    });
+    report.latency_ms = Some(start.elapsed().as_millis() as u64);
+
+    if let Err((status, error)) = outcome {
+        report.status = status;
+        report.error = Some(error);
+    }
+
+    report
+}
+
+/// Apply `f` to every item using at most `max_parallel` worker threads.
+///
+/// Outputs are returned in item order regardless of completion order.
+fn run_bounded<T, R, F>(items: &[T], max_parallel: usize, f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let workers = max_parallel.clamp(1, items.len().max(1));
+    let next = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+
+    std::thread::scope(|scope| {
+        for _ in 0..workers {
+            let tx = tx.clone();
+            let (next, f) = (&next, &f);
+            scope.spawn(move || loop {
+                let idx = next.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(idx) else {
+                    break;
+                };
+                if tx.send((idx, f(item))).is_err() {
+                    break;
+                }
+            });
+        }
+    });
+    drop(tx);
+
+    let mut outputs: Vec<(usize, R)> = rx.into_iter().collect();
+    outputs.sort_by_key(|(idx, _)| *idx);
+    outputs.into_iter().map(|(_, r)| r).collect()
+}
+
 /// Execute a plugin subprocess with stdin/stdout JSON protocol.
 ///
 /// Returns the stdout bytes and execution duration on success,
@@ -585,6 +1175,8 @@ fn invoke_subprocess(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::plugin::evidence::evidence_for_pids;
+    use pt_common::ProcessId;
     use tempfile::TempDir;
 
     fn create_plugin_dir(parent: &Path, name: &str, plugin_type: &str, script: &str) -> PathBuf {
@@ -772,6 +1364,8 @@ echo '{"plugin": "test-action", "status": "ok", "message": "notified"}'
             classification: "zombie".to_string(),
             confidence: 0.99,
             session_id: None,
+            posterior: None,
+            process_summary: None,
         };
 
         let result = mgr.invoke_action("test-action", &input).unwrap();
@@ -837,6 +1431,57 @@ echo '{"plugin": "test-action", "status": "ok", "message": "notified"}'
         assert!(result.is_none());
     }
 
+    #[test]
+    fn test_auto_disabled_plugin_retries_after_backoff() {
+        let dir = TempDir::new().unwrap();
+        let plugins_dir = dir.path().join("plugins");
+        std::fs::create_dir_all(&plugins_dir).unwrap();
+        create_plugin_dir(&plugins_dir, "flaky", "evidence", "#!/bin/sh\nexit 1");
+
+        let mut mgr = PluginManager::discover_from(&plugins_dir)
+            .unwrap()
+            .with_retry_backoff(Duration::from_millis(50), Duration::from_secs(60));
+        let input = EvidencePluginInput {
+            pids: vec![1],
+            scan_id: None,
+        };
+
+        let _ = mgr.invoke_evidence("flaky", &input);
+        let _ = mgr.invoke_evidence("flaky", &input);
+        assert!(mgr.is_disabled("flaky"));
+        let status = &mgr.plugin_health()[0];
+        assert!(!status.enabled);
+        assert!(!status.manually_disabled);
+        assert_eq!(status.consecutive_failures, 2);
+        assert!(status.retry_in_ms.is_some_and(|ms| ms <= 50));
+
+        // Cooldown elapsed: the plugin gets another attempt.
+        std::thread::sleep(Duration::from_millis(60));
+        assert!(!mgr.is_disabled("flaky"));
+        assert!(mgr.plugin_health()[0].retry_in_ms.is_none());
+        assert!(mgr.invoke_evidence("flaky", &input).is_err());
+        assert!(mgr.is_disabled("flaky"));
+        assert_eq!(mgr.plugin_health()[0].consecutive_failures, 3);
+
+        mgr.enable("flaky");
+        let status = &mgr.plugin_health()[0];
+        assert!(status.enabled);
+        assert_eq!(status.consecutive_failures, 0);
+    }
+
+    #[test]
+    fn test_retry_backoff_doubles_up_to_cap() {
+        let backoff = RetryBackoff {
+            base: Duration::from_secs(30),
+            max: Duration::from_secs(200),
+        };
+        assert_eq!(backoff.cooldown(0), Duration::from_secs(30));
+        assert_eq!(backoff.cooldown(1), Duration::from_secs(60));
+        assert_eq!(backoff.cooldown(2), Duration::from_secs(120));
+        assert_eq!(backoff.cooldown(3), Duration::from_secs(200));
+        assert_eq!(backoff.cooldown(u32::MAX), Duration::from_secs(200));
+    }
+
     #[test]
     fn test_invoke_all_evidence() {
         let dir = TempDir::new().unwrap();
@@ -862,6 +1507,413 @@ echo '{"plugin":"a","version":"1","evidence":[{"pid":1,"features":{},"log_likeli
         assert_eq!(results[0].0, "a");
     }
 
+    #[test]
+    fn test_invoke_all_evidence_runs_in_parallel() {
+        let dir = TempDir::new().unwrap();
+        let plugins_dir = dir.path().join("plugins");
+        std::fs::create_dir_all(&plugins_dir).unwrap();
+
+        for name in ["slow-a", "slow-b", "slow-c"] {
+            let script = format!(
+                r#"#!/bin/sh
+sleep 1
+echo '{{"plugin":"{name}","version":"1","evidence":[{{"pid":1,"features":{{}},"log_likelihoods":{{"useful":0,"useful_bad":0,"abandoned":-1,"zombie":0}}}}]}}'
+"#
+            );
+            create_plugin_dir(&plugins_dir, name, "evidence", &script);
+        }
+
+        let mut mgr = PluginManager::discover_from(&plugins_dir)
+            .unwrap()
+            .with_max_parallel(3);
+        let input = EvidencePluginInput {
+            pids: vec![1],
+            scan_id: None,
+        };
+
+        let start = Instant::now();
+        let results = mgr.invoke_all_evidence(&input);
+        let elapsed = start.elapsed();
+
+        assert_eq!(results.len(), 3);
+        assert!(
+            elapsed < Duration::from_millis(2500),
+            "expected ~1s wall time, took {elapsed:?}"
+        );
+        let names: Vec<&str> = results.iter().map(|(n, _)| n.as_str()).collect();
+        assert_eq!(names, vec!["slow-a", "slow-b", "slow-c"]);
+    }
+
+    #[test]
+    fn test_invoke_all_evidence_isolates_failures() {
+        let dir = TempDir::new().unwrap();
+        let plugins_dir = dir.path().join("plugins");
+        std::fs::create_dir_all(&plugins_dir).unwrap();
+
+        let script = r#"#!/bin/sh
+echo '{"plugin":"good","version":"1","evidence":[]}'
+"#;
+        create_plugin_dir(&plugins_dir, "good", "evidence", script);
+        create_plugin_dir(&plugins_dir, "broken", "evidence", "#!/bin/sh\nexit 1");
+
+        let mut mgr = PluginManager::discover_from(&plugins_dir)
+            .unwrap()
+            .with_max_parallel(2);
+        let input = EvidencePluginInput {
+            pids: vec![1],
+            scan_id: None,
+        };
+
+        let results = mgr.invoke_all_evidence(&input);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].0, "good");
+        assert!(!mgr.is_disabled("broken"));
+        mgr.invoke_all_evidence(&input);
+        assert!(mgr.is_disabled("broken"));
+    }
+
+    #[test]
+    fn test_with_max_parallel_floor() {
+        let mgr = PluginManager::empty().with_max_parallel(0);
+        assert_eq!(mgr.max_parallel(), 1);
+    }
+
+    #[test]
+    fn test_health_check_reports_each_plugin() {
+        let dir = TempDir::new().unwrap();
+        let plugins_dir = dir.path().join("plugins");
+        std::fs::create_dir_all(&plugins_dir).unwrap();
+
+        let script = r#"#!/bin/sh
+echo '{"plugin":"good","version":"1","evidence":[]}'
+"#;
+        create_plugin_dir(&plugins_dir, "good", "evidence", script);
+        create_plugin_dir(&plugins_dir, "garbage", "evidence", "#!/bin/sh\necho nope");
+        create_plugin_dir(&plugins_dir, "crashing", "evidence", "#!/bin/sh\nexit 3");
+        // An action plugin that would fail if it were ever invoked.
+        let invoked = dir.path().join("notified");
+        let notify = format!("#!/bin/sh\ntouch \"{}\"\nexit 3", invoked.display());
+        create_plugin_dir(&plugins_dir, "notify", "action", &notify);
+
+        let mut mgr = PluginManager::discover_from(&plugins_dir).unwrap();
+        mgr.disable("notify");
+
+        let reports = mgr.health_check();
+        let names: Vec<&str> = reports.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, vec!["crashing", "garbage", "good", "notify"]);
+
+        assert_eq!(reports[0].status, PluginHealthStatus::Failed);
+        assert!(reports[0].error.as_deref().unwrap().contains("code 3"));
+        assert_eq!(reports[1].status, PluginHealthStatus::InvalidOutput);
+        assert!(reports[2].is_healthy());
+        assert!(reports[2].latency_ms.is_some());
+        assert!(reports[2].error.is_none());
+        assert!(reports[3].is_healthy());
+        assert!(reports[3].disabled);
+        assert!(reports[3].latency_ms.is_none());
+        assert!(!invoked.exists(), "health check invoked an action plugin");
+
+        // Health checks never count toward auto-disable.
+        assert!(!mgr.is_disabled("crashing"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_health_check_not_executable() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = TempDir::new().unwrap();
+        let plugins_dir = dir.path().join("plugins");
+        std::fs::create_dir_all(&plugins_dir).unwrap();
+
+        let plugin_dir = create_plugin_dir(&plugins_dir, "noexec", "evidence", "#!/bin/sh\n");
+        std::fs::set_permissions(
+            plugin_dir.join("run.sh"),
+            std::fs::Permissions::from_mode(0o644),
+        )
+        .unwrap();
+
+        let mgr = PluginManager::discover_from(&plugins_dir).unwrap();
+        let reports = mgr.health_check();
+        assert_eq!(reports.len(), 1);
+        assert_eq!(reports[0].status, PluginHealthStatus::NotExecutable);
+        assert!(reports[0].latency_ms.is_none());
+    }
+
+    #[test]
+    fn test_validate_plugin_passes_every_check() {
+        let dir = TempDir::new().unwrap();
+        let script = r#"#!/bin/sh
+echo '{"plugin":"good","version":"1","evidence":[]}'
+"#;
+        let plugin_dir = create_plugin_dir(dir.path(), "good", "evidence", script);
+
+        let report = PluginManager::validate_plugin(&plugin_dir).unwrap();
+        assert_eq!(report.name, "good");
+        let names: Vec<_> = report.checks.iter().map(|c| c.name).collect();
+        assert_eq!(
+            names,
+            vec!["manifest", "api_version", "executable", "invocation"]
+        );
+        assert!(report.passed(), "{:?}", report.checks);
+    }
+
+    #[test]
+    fn test_validate_plugin_reports_failures() {
+        let dir = TempDir::new().unwrap();
+
+        // Bad output: every static check passes, the invocation fails.
+        let plugin_dir =
+            create_plugin_dir(dir.path(), "garbage", "evidence", "#!/bin/sh\necho nope\n");
+        let report = PluginManager::validate_plugin(&plugin_dir).unwrap();
+        assert!(!report.passed());
+        assert!(report.checks[..3].iter().all(|c| c.passed));
+        assert!(!report.checks[3].passed);
+
+        // Incompatible API version and a missing command: nothing is run.
+        let plugin_dir = create_plugin_dir(dir.path(), "broken", "action", "#!/bin/sh\n");
+        let manifest = std::fs::read_to_string(plugin_dir.join("plugin.toml")).unwrap();
+        let manifest = manifest.replace(
+            r#"command = "run.sh""#,
+            "command = \"missing.sh\"\napi_version = \"2\"",
+        );
+        std::fs::write(plugin_dir.join("plugin.toml"), manifest).unwrap();
+        let report = PluginManager::validate_plugin(&plugin_dir).unwrap();
+        let failed: Vec<_> = report
+            .checks
+            .iter()
+            .filter(|c| !c.passed)
+            .map(|c| c.name)
+            .collect();
+        assert_eq!(failed, vec!["api_version", "executable", "invocation"]);
+        assert!(report.checks[3].message.starts_with("skipped"));
+
+        // No manifest at all is an error, not a report.
+        assert!(matches!(
+            PluginManager::validate_plugin(dir.path()),
+            Err(ManifestError::NotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn test_single_pid_plugin_is_invoked_per_pid() {
+        let dir = TempDir::new().unwrap();
+        let plugins_dir = dir.path().join("plugins");
+        std::fs::create_dir_all(&plugins_dir).unwrap();
+
+        // Echo one entry per input PID and count spawns.
+        let script = r#"#!/bin/sh
+input=$(cat)
+echo x >> "$(dirname "$0")/spawns"
+pids=$(echo "$input" | sed 's/.*"pids":\[\([0-9,]*\)\].*/\1/' | tr ',' ' ')
+entries=""
+for pid in $pids; do
+  entry="{\"pid\":$pid,\"log_likelihoods\":{\"useful\":0,\"useful_bad\":0,\"abandoned\":-1,\"zombie\":0}}"
+  entries="${entries:+$entries,}$entry"
+done
+echo "{\"plugin\":\"p\",\"version\":\"1\",\"evidence\":[$entries]}"
+"#;
+        let batch_dir = create_plugin_dir(&plugins_dir, "batched", "evidence", script);
+        let single_dir = create_plugin_dir(&plugins_dir, "single", "evidence", script);
+        let manifest_path = single_dir.join("plugin.toml");
+        let manifest = std::fs::read_to_string(&manifest_path).unwrap();
+        std::fs::write(
+            &manifest_path,
+            manifest.replace(
+                r#"command = "run.sh""#,
+                "command = \"run.sh\"\nsupports_batch = false",
+            ),
+        )
+        .unwrap();
+
+        let mut mgr = PluginManager::discover_from(&plugins_dir).unwrap();
+        let pids: Vec<ProcessId> = [11, 22, 33].into_iter().map(ProcessId).collect();
+        let input = EvidencePluginInput::for_pids(&pids, None);
+        let results = mgr.invoke_all_evidence(&input);
+        assert_eq!(results.len(), 2);
+        for (name, output) in &results {
+            let keyed = evidence_for_pids(output, &pids);
+            assert_eq!(keyed.len(), 3, "{name}");
+            assert_eq!(keyed[&ProcessId(22)].abandoned, -1.0);
+        }
+
+        let spawns = |dir: &Path| {
+            std::fs::read_to_string(dir.join("spawns"))
+                .unwrap()
+                .lines()
+                .count()
+        };
+        assert_eq!(spawns(&batch_dir), 1);
+        assert_eq!(spawns(&single_dir), 3);
+    }
+
+    #[test]
+    fn test_per_pid_fallback_shares_one_deadline() {
+        let dir = TempDir::new().unwrap();
+        let plugins_dir = dir.path().join("plugins");
+        std::fs::create_dir_all(&plugins_dir).unwrap();
+
+        // Each call fits the timeout on its own; ten of them do not.
+        let script = r#"#!/bin/sh
+cat > /dev/null
+sleep 0.3
+echo '{"plugin":"slow","version":"1","evidence":[]}'
+"#;
+        let plugin_dir = create_plugin_dir(&plugins_dir, "slow", "evidence", script);
+        let manifest_path = plugin_dir.join("plugin.toml");
+        let manifest = std::fs::read_to_string(&manifest_path)
+            .unwrap()
+            .replace(
+                r#"command = "run.sh""#,
+                "command = \"run.sh\"\nsupports_batch = false",
+            )
+            .replace("invoke_ms = 5000", "invoke_ms = 1000");
+        std::fs::write(&manifest_path, manifest).unwrap();
+
+        let mgr = PluginManager::discover_from(&plugins_dir).unwrap();
+        let plugin = mgr.plugins["slow"].plugin.clone();
+        let pids: Vec<ProcessId> = (1..=10).map(ProcessId).collect();
+        let input = EvidencePluginInput::for_pids(&pids, None);
+        let json = serde_json::to_vec(&input).unwrap();
+
+        let start = Instant::now();
+        let err = run_evidence_plugin_for(&plugin, &input, &json).unwrap_err();
+        assert!(
+            matches!(
+                err,
+                EvidencePluginError::Timeout {
+                    timeout_ms: 1000,
+                    ..
+                }
+            ),
+            "{err}"
+        );
+        assert!(start.elapsed() < Duration::from_secs(3));
+    }
+
+    fn set_cache_ttl(plugin_dir: &Path, ttl_secs: i64) {
+        let manifest_path = plugin_dir.join("plugin.toml");
+        let manifest = std::fs::read_to_string(&manifest_path).unwrap();
+        let manifest = manifest.replace(
+            "command = \"run.sh\"\n",
+            &format!("command = \"run.sh\"\ncache_ttl_secs = {ttl_secs}\n"),
+        );
+        std::fs::write(manifest_path, manifest).unwrap();
+    }
+
+    #[test]
+    fn test_evidence_cache_hit_skips_subprocess() {
+        let dir = TempDir::new().unwrap();
+        let plugins_dir = dir.path().join("plugins");
+        std::fs::create_dir_all(&plugins_dir).unwrap();
+
+        let counter = dir.path().join("invocations");
+        let script = format!(
+            r#"#!/bin/sh
+echo x >> "{}"
+echo '{{"plugin":"cached","version":"1","evidence":[]}}'
+"#,
+            counter.display()
+        );
+        let plugin_dir = create_plugin_dir(&plugins_dir, "cached", "evidence", &script);
+        set_cache_ttl(&plugin_dir, 60);
+
+        let mut mgr = PluginManager::discover_from(&plugins_dir).unwrap();
+        let invocations = || {
+            std::fs::read_to_string(&counter)
+                .map(|s| s.lines().count())
+                .unwrap_or(0)
+        };
+
+        let first = EvidencePluginInput {
+            pids: vec![3, 1, 2],
+            scan_id: Some("scan-1".to_string()),
+        };
+        assert!(mgr.invoke_evidence("cached", &first).unwrap().is_some());
+        assert_eq!(invocations(), 1);
+
+        // Same PID set in a different order hits the cache.
+        let second = EvidencePluginInput {
+            pids: vec![2, 3, 1],
+            scan_id: Some("scan-2".to_string()),
+        };
+        assert!(mgr.invoke_evidence("cached", &second).unwrap().is_some());
+        assert_eq!(mgr.invoke_all_evidence(&second).len(), 1);
+        assert_eq!(invocations(), 1);
+
+        // A different PID set misses.
+        let third = EvidencePluginInput {
+            pids: vec![4],
+            scan_id: None,
+        };
+        mgr.invoke_evidence("cached", &third).unwrap();
+        assert_eq!(invocations(), 2);
+
+        mgr.clear_cache();
+        mgr.invoke_evidence("cached", &first).unwrap();
+        assert_eq!(invocations(), 3);
+    }
+
+    #[test]
+    fn test_evidence_cache_is_bounded() {
+        let dir = TempDir::new().unwrap();
+        let plugins_dir = dir.path().join("plugins");
+        std::fs::create_dir_all(&plugins_dir).unwrap();
+
+        let script = r#"#!/bin/sh
+echo '{"plugin":"bounded","version":"1","evidence":[]}'
+"#;
+        let plugin_dir = create_plugin_dir(&plugins_dir, "bounded", "evidence", script);
+        set_cache_ttl(&plugin_dir, 60);
+        let mut mgr = PluginManager::discover_from(&plugins_dir).unwrap();
+
+        let plugin = mgr.plugins["bounded"].plugin.clone();
+        let output = EvidencePluginOutput {
+            plugin: "bounded".to_string(),
+            version: "1".to_string(),
+            evidence: vec![],
+        };
+        for pid in 0..(MAX_EVIDENCE_CACHE_ENTRIES as u32 + 10) {
+            mgr.store_evidence(&plugin, ("bounded".to_string(), vec![pid]), &output);
+        }
+        assert_eq!(mgr.evidence_cache.len(), MAX_EVIDENCE_CACHE_ENTRIES);
+
+        // Expired entries are dropped on the next insert.
+        for entry in mgr.evidence_cache.values_mut() {
+            entry.expires_at = Instant::now();
+        }
+        mgr.store_evidence(&plugin, ("bounded".to_string(), vec![u32::MAX]), &output);
+        assert_eq!(mgr.evidence_cache.len(), 1);
+    }
+
+    #[test]
+    fn test_evidence_cache_is_opt_in() {
+        let dir = TempDir::new().unwrap();
+        let plugins_dir = dir.path().join("plugins");
+        std::fs::create_dir_all(&plugins_dir).unwrap();
+
+        let counter = dir.path().join("invocations");
+        let script = format!(
+            r#"#!/bin/sh
+echo x >> "{}"
+echo '{{"plugin":"uncached","version":"1","evidence":[]}}'
+"#,
+            counter.display()
+        );
+        create_plugin_dir(&plugins_dir, "uncached", "evidence", &script);
+
+        let mut mgr = PluginManager::discover_from(&plugins_dir).unwrap();
+        let input = EvidencePluginInput {
+            pids: vec![1],
+            scan_id: None,
+        };
+        mgr.invoke_evidence("uncached", &input).unwrap();
+        mgr.invoke_evidence("uncached", &input).unwrap();
+
+        let count = std::fs::read_to_string(&counter).unwrap().lines().count();
+        assert_eq!(count, 2);
+    }
+
     #[test]
     fn test_discover_via_config_dir() {
         let dir = TempDir::new().unwrap();
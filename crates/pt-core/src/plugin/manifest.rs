@@ -19,12 +19,20 @@
 //! [plugin.limits]
 //! max_output_bytes = 1048576
 //! ```
+//!
+//! Evidence plugins backed by slow external systems can set
+//! `cache_ttl_secs` to reuse their output for an unchanged PID set.
+//! Plugins that can only handle one PID per run set `supports_batch = false`.
 
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 use thiserror::Error;
 
 /// Current plugin API version.
+///
+/// Compared with semver rules: a manifest is compatible when its major
+/// version matches, regardless of minor/patch.
 pub const PLUGIN_API_VERSION: &str = "1";
 
 /// Default invocation timeout in milliseconds.
@@ -56,14 +64,20 @@ pub enum ManifestError {
         source: std::io::Error,
     },
 
-    #[error("unsupported API version: expected {expected}, got {actual}")]
-    ApiVersionMismatch { expected: String, actual: String },
+    #[error("incompatible plugin API version {found} (supported: {supported}.x)")]
+    IncompatibleApiVersion { found: String, supported: String },
+
+    #[error("invalid plugin API version: {value:?} (expected MAJOR[.MINOR[.PATCH]])")]
+    InvalidApiVersion { value: String },
 
     #[error("missing required field: {field}")]
     MissingField { field: String },
 
     #[error("plugin command not found: {path}")]
     CommandNotFound { path: PathBuf },
+
+    #[error("invalid cache_ttl_secs: {value} (must be non-negative)")]
+    InvalidCacheTtl { value: i64 },
 }
 
 /// Plugin type (evidence source or action hook).
@@ -98,7 +112,7 @@ pub struct PluginLimits {
     /// Maximum output size in bytes.
     #[serde(default = "default_max_output")]
     pub max_output_bytes: usize,
-    /// Maximum consecutive failures before auto-disable.
+    /// Consecutive failures before the plugin is auto-disabled for a cooldown.
     #[serde(default = "default_max_failures")]
     pub max_failures: u32,
 }
@@ -149,6 +163,24 @@ pub struct PluginManifest {
     /// Only meaningful for evidence plugins.
     #[serde(default = "default_weight")]
     pub weight: f64,
+    /// How long (seconds) to reuse evidence output for the same PID set.
+    /// Absent or 0 disables caching. Only meaningful for evidence plugins.
+    #[serde(default)]
+    pub cache_ttl_secs: Option<i64>,
+    /// Whether the plugin accepts several PIDs per invocation. When false the
+    /// manager invokes it once per PID. Only meaningful for evidence plugins.
+    #[serde(default = "default_supports_batch")]
+    pub supports_batch: bool,
+}
+
+impl PluginManifest {
+    /// Evidence cache TTL, or `None` if caching is disabled.
+    pub fn cache_ttl(&self) -> Option<Duration> {
+        match self.cache_ttl_secs {
+            Some(secs) if secs > 0 => Some(Duration::from_secs(secs as u64)),
+            _ => None,
+        }
+    }
 }
 
 /// A fully resolved plugin with its directory path.
@@ -171,6 +203,13 @@ impl ResolvedPlugin {
 
 /// Load and validate a plugin manifest from a directory.
 pub fn load_manifest(plugin_dir: &Path) -> Result<ResolvedPlugin, ManifestError> {
+    let manifest = read_manifest(plugin_dir)?;
+    check_api_version(&manifest.api_version, PLUGIN_API_VERSION)?;
+    resolve_manifest(manifest, plugin_dir)
+}
+
+/// Read and parse `plugin.toml` without validating its contents.
+pub(crate) fn read_manifest(plugin_dir: &Path) -> Result<PluginManifest, ManifestError> {
     let manifest_path = plugin_dir.join("plugin.toml");
 
     if !manifest_path.exists() {
@@ -189,16 +228,16 @@ pub fn load_manifest(plugin_dir: &Path) -> Result<ResolvedPlugin, ManifestError>
         source: e,
     })?;
 
-    let manifest = file.plugin;
-
-    // Validate API version
-    if manifest.api_version != PLUGIN_API_VERSION {
-        return Err(ManifestError::ApiVersionMismatch {
-            expected: PLUGIN_API_VERSION.to_string(),
-            actual: manifest.api_version,
-        });
-    }
+    Ok(file.plugin)
+}
 
+/// Validate a parsed manifest's fields and resolve its command path.
+///
+/// Does not check the API version; see [`check_api_version`].
+pub(crate) fn resolve_manifest(
+    manifest: PluginManifest,
+    plugin_dir: &Path,
+) -> Result<ResolvedPlugin, ManifestError> {
     // Validate required fields
     if manifest.name.is_empty() {
         return Err(ManifestError::MissingField {
@@ -211,6 +250,12 @@ pub fn load_manifest(plugin_dir: &Path) -> Result<ResolvedPlugin, ManifestError>
         });
     }
 
+    if let Some(ttl) = manifest.cache_ttl_secs {
+        if ttl < 0 {
+            return Err(ManifestError::InvalidCacheTtl { value: ttl });
+        }
+    }
+
     // Validate command path doesn't escape plugin directory
     if manifest.command.contains("..") {
         return Err(ManifestError::CommandNotFound {
@@ -253,6 +298,41 @@ pub fn load_manifest(plugin_dir: &Path) -> Result<ResolvedPlugin, ManifestError>
     })
 }
 
+/// Parse a `MAJOR[.MINOR[.PATCH]]` version; missing components default to 0.
+fn parse_api_version(value: &str) -> Option<(u64, u64, u64)> {
+    let mut parts = value.trim().split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next().map_or(Some(0), |p| p.parse().ok())?;
+    let patch = parts.next().map_or(Some(0), |p| p.parse().ok())?;
+    if parts.next().is_some() {
+        return None;
+    }
+    Some((major, minor, patch))
+}
+
+/// Check a manifest's declared API version against the supported one.
+///
+/// Versions are compatible when their major components match; newer and
+/// older minors are both accepted.
+pub fn check_api_version(found: &str, supported: &str) -> Result<(), ManifestError> {
+    let (found_major, _, _) =
+        parse_api_version(found).ok_or_else(|| ManifestError::InvalidApiVersion {
+            value: found.to_string(),
+        })?;
+    let (supported_major, _, _) =
+        parse_api_version(supported).ok_or_else(|| ManifestError::InvalidApiVersion {
+            value: supported.to_string(),
+        })?;
+
+    if found_major != supported_major {
+        return Err(ManifestError::IncompatibleApiVersion {
+            found: found.to_string(),
+            supported: supported_major.to_string(),
+        });
+    }
+    Ok(())
+}
+
 fn default_invoke_timeout() -> u64 {
     DEFAULT_INVOKE_TIMEOUT_MS
 }
@@ -271,6 +351,9 @@ fn default_plugin_type() -> PluginType {
 fn default_weight() -> f64 {
     1.0
 }
+fn default_supports_batch() -> bool {
+    true
+}
 
 #[cfg(test)]
 mod tests {
@@ -381,7 +464,74 @@ command = "/usr/bin/true"
         let result = load_manifest(dir.path());
         assert!(matches!(
             result.unwrap_err(),
-            ManifestError::ApiVersionMismatch { .. }
+            ManifestError::IncompatibleApiVersion { .. }
+        ));
+    }
+
+    #[test]
+    fn test_newer_minor_api_version_accepted() {
+        let dir = TempDir::new().unwrap();
+        write_manifest(
+            dir.path(),
+            r#"
+[plugin]
+name = "newer-minor"
+version = "1.0.0"
+api_version = "1.4"
+command = "/usr/bin/true"
+"#,
+        );
+
+        let resolved = load_manifest(dir.path()).unwrap();
+        assert_eq!(resolved.manifest.api_version, "1.4");
+    }
+
+    #[test]
+    fn test_invalid_api_version() {
+        let dir = TempDir::new().unwrap();
+        write_manifest(
+            dir.path(),
+            r#"
+[plugin]
+name = "garbled"
+version = "1.0.0"
+api_version = "one"
+command = "/usr/bin/true"
+"#,
+        );
+
+        let result = load_manifest(dir.path());
+        assert!(matches!(
+            result.unwrap_err(),
+            ManifestError::InvalidApiVersion { .. }
+        ));
+    }
+
+    #[test]
+    fn test_check_api_version_semver_rules() {
+        // Same version.
+        assert!(check_api_version("1.2", "1.2").is_ok());
+        assert!(check_api_version("1", "1.0.0").is_ok());
+        // Older minor.
+        assert!(check_api_version("1.0", "1.2").is_ok());
+        // Newer minor.
+        assert!(check_api_version("1.3.1", "1.2").is_ok());
+        // Newer major.
+        match check_api_version("2.0", "1.2") {
+            Err(ManifestError::IncompatibleApiVersion { found, supported }) => {
+                assert_eq!(found, "2.0");
+                assert_eq!(supported, "1");
+            }
+            other => panic!("expected IncompatibleApiVersion, got {other:?}"),
+        }
+        // Older major.
+        assert!(matches!(
+            check_api_version("0.9", "1.2"),
+            Err(ManifestError::IncompatibleApiVersion { .. })
+        ));
+        assert!(matches!(
+            check_api_version("1.2.3.4", "1"),
+            Err(ManifestError::InvalidApiVersion { .. })
         ));
     }
 
@@ -441,6 +591,80 @@ command = "/usr/bin/true"
         assert_eq!(resolved.manifest.plugin_type, PluginType::Action);
     }
 
+    #[test]
+    fn test_cache_ttl() {
+        let dir = TempDir::new().unwrap();
+        write_manifest(
+            dir.path(),
+            r#"
+[plugin]
+name = "cached"
+version = "1.0.0"
+command = "/usr/bin/true"
+cache_ttl_secs = 30
+"#,
+        );
+
+        let resolved = load_manifest(dir.path()).unwrap();
+        assert_eq!(resolved.manifest.cache_ttl(), Some(Duration::from_secs(30)));
+    }
+
+    #[test]
+    fn test_cache_ttl_default_and_zero_disable_caching() {
+        let dir = TempDir::new().unwrap();
+        write_manifest(
+            dir.path(),
+            r#"
+[plugin]
+name = "uncached"
+version = "1.0.0"
+command = "/usr/bin/true"
+"#,
+        );
+        assert!(load_manifest(dir.path())
+            .unwrap()
+            .manifest
+            .cache_ttl()
+            .is_none());
+
+        write_manifest(
+            dir.path(),
+            r#"
+[plugin]
+name = "uncached"
+version = "1.0.0"
+command = "/usr/bin/true"
+cache_ttl_secs = 0
+"#,
+        );
+        assert!(load_manifest(dir.path())
+            .unwrap()
+            .manifest
+            .cache_ttl()
+            .is_none());
+    }
+
+    #[test]
+    fn test_negative_cache_ttl_rejected() {
+        let dir = TempDir::new().unwrap();
+        write_manifest(
+            dir.path(),
+            r#"
+[plugin]
+name = "bad-ttl"
+version = "1.0.0"
+command = "/usr/bin/true"
+cache_ttl_secs = -5
+"#,
+        );
+
+        let result = load_manifest(dir.path());
+        assert!(matches!(
+            result.unwrap_err(),
+            ManifestError::InvalidCacheTtl { value: -5 }
+        ));
+    }
+
     #[test]
     fn test_absolute_command_path() {
         let dir = TempDir::new().unwrap();
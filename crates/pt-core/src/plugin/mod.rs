@@ -28,7 +28,8 @@
 //!
 //! - Plugins run as subprocesses with configurable timeouts
 //! - Output size is capped to prevent memory exhaustion
-//! - Plugins are auto-disabled after repeated failures
+//! - Plugins are auto-disabled after repeated failures, with an exponential
+//!   cooldown before they are retried
 //! - Action plugins can only notify — they cannot kill or signal processes
 
 pub mod action;
@@ -36,17 +37,22 @@ pub mod evidence;
 pub mod manager;
 pub mod manifest;
 
-pub use manager::PluginManager;
+pub use manager::{
+    PluginHealth, PluginHealthStatus, PluginManager, PluginStatus, ValidationCheck,
+    ValidationReport, DEFAULT_RETRY_BASE, DEFAULT_RETRY_MAX, HEALTH_CHECK_SCAN_ID,
+};
 pub use manifest::{
-    load_manifest, ManifestError, PluginLimits, PluginManifest, PluginTimeouts, PluginType,
-    ResolvedPlugin, PLUGIN_API_VERSION,
+    check_api_version, load_manifest, ManifestError, PluginLimits, PluginManifest, PluginTimeouts,
+    PluginType, ResolvedPlugin, PLUGIN_API_VERSION,
 };
 
 pub use evidence::{
-    evidence_for_pid, parse_evidence_output, to_evidence_term, EvidencePluginError,
-    EvidencePluginInput, EvidencePluginOutput, PluginEvidenceEntry, PluginLogLikelihoods,
+    evidence_for_pid, evidence_for_pids, parse_evidence_output, to_evidence_term,
+    EvidencePluginError, EvidencePluginInput, EvidencePluginOutput, PluginEvidenceEntry,
+    PluginLogLikelihoods,
 };
 
 pub use action::{
-    parse_action_output, ActionPluginError, ActionPluginInput, ActionPluginOutput, ActionStatus,
+    parse_action_output, ActionPluginError, ActionPluginInput, ActionPluginOutput,
+    ActionProcessSummary, ActionStatus,
 };
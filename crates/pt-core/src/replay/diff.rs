@@ -0,0 +1,449 @@
+//! Structural diff between two replay snapshots.
+//!
+//! Used when reproducing a bug to see exactly what changed between two
+//! captures: which processes appeared or disappeared, how per-process
+//! resource usage and state moved, and whether the system context differs.
+//! The diff serializes to JSON for inclusion in bug reports.
+//!
+//! [`diff_scans`] compares the triage results of two scans instead, for
+//! showing only what changed since the previous scan.
+
+use super::snapshot::{ReplayInferenceResult, ReplaySnapshot, SystemContext};
+use crate::collect::{ProcessRecord, ProcessState};
+use crate::decision::Action;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Differences between two replay snapshots (`before` → `after`).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SnapshotDiff {
+    /// Name of the baseline snapshot.
+    pub before: String,
+
+    /// Name of the compared snapshot.
+    pub after: String,
+
+    /// PIDs present only in the compared snapshot (sorted).
+    pub added_pids: Vec<u32>,
+
+    /// PIDs present only in the baseline snapshot (sorted).
+    pub removed_pids: Vec<u32>,
+
+    /// Per-process changes for PIDs present in both snapshots (sorted by PID).
+    pub changed: Vec<ProcessDelta>,
+
+    /// System context fields that differ.
+    pub context_changes: Vec<FieldChange>,
+}
+
+impl SnapshotDiff {
+    /// Whether the two snapshots are equivalent for diffing purposes.
+    pub fn is_empty(&self) -> bool {
+        self.added_pids.is_empty()
+            && self.removed_pids.is_empty()
+            && self.changed.is_empty()
+            && self.context_changes.is_empty()
+    }
+}
+
+/// Field-level changes for a single process.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProcessDelta {
+    /// Process ID.
+    pub pid: u32,
+
+    /// Command name (from the compared snapshot).
+    pub comm: String,
+
+    /// CPU usage change, in percent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cpu_percent: Option<ValueDelta<f64>>,
+
+    /// Resident set size change, in bytes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rss_bytes: Option<ValueDelta<u64>>,
+
+    /// Virtual memory size change, in bytes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vsz_bytes: Option<ValueDelta<u64>>,
+
+    /// Process state change.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<ValueDelta<ProcessState>>,
+}
+
+/// Before/after pair for a changed value.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ValueDelta<T> {
+    pub before: T,
+    pub after: T,
+}
+
+/// A changed [`SystemContext`] field, rendered as strings.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldChange {
+    pub field: String,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+/// Triage changes between two scans (`prev` → `current`).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ScanDelta {
+    /// PIDs only present in the current scan (sorted).
+    pub new_pids: Vec<u32>,
+
+    /// PIDs only present in the previous scan (sorted).
+    pub gone_pids: Vec<u32>,
+
+    /// Processes whose classification or recommended action changed
+    /// (sorted by PID).
+    pub changed: Vec<TriageChange>,
+}
+
+impl ScanDelta {
+    /// Whether nothing changed between the two scans.
+    pub fn is_empty(&self) -> bool {
+        self.new_pids.is_empty() && self.gone_pids.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Triage change for a process present in both scans.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TriageChange {
+    /// Process ID.
+    pub pid: u32,
+
+    /// Command name (from the current scan).
+    pub comm: String,
+
+    /// Classification change.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub classification: Option<ValueDelta<String>>,
+
+    /// Recommended action change.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recommended_action: Option<ValueDelta<Action>>,
+}
+
+/// Compare the triage results of two scans.
+///
+/// Results are matched by PID. A PID whose `start_id` differs is a reused
+/// PID and is reported as gone and new rather than changed; results without
+/// a `start_id` (older recordings) are matched on PID alone.
+pub fn diff_scans(prev: &[ReplayInferenceResult], current: &[ReplayInferenceResult]) -> ScanDelta {
+    let before: BTreeMap<u32, &ReplayInferenceResult> = prev.iter().map(|r| (r.pid, r)).collect();
+    let after: BTreeMap<u32, &ReplayInferenceResult> = current.iter().map(|r| (r.pid, r)).collect();
+
+    let mut delta = ScanDelta::default();
+    for (pid, old) in &before {
+        match after.get(pid) {
+            Some(new) if same_incarnation(old, new) => {
+                let change = TriageChange {
+                    pid: *pid,
+                    comm: new.comm.clone(),
+                    classification: changed(&old.classification, &new.classification),
+                    recommended_action: changed(&old.recommended_action, &new.recommended_action),
+                };
+                if change.classification.is_some() || change.recommended_action.is_some() {
+                    delta.changed.push(change);
+                }
+            }
+            Some(_) => {
+                delta.gone_pids.push(*pid);
+                delta.new_pids.push(*pid);
+            }
+            None => delta.gone_pids.push(*pid),
+        }
+    }
+    delta
+        .new_pids
+        .extend(after.keys().filter(|pid| !before.contains_key(pid)));
+    delta.new_pids.sort_unstable();
+    delta
+}
+
+fn same_incarnation(old: &ReplayInferenceResult, new: &ReplayInferenceResult) -> bool {
+    old.start_id.is_empty() || new.start_id.is_empty() || old.start_id == new.start_id
+}
+
+/// CPU differences below this many percentage points are treated as noise.
+const CPU_EPSILON: f64 = 1e-9;
+
+impl ReplaySnapshot {
+    /// Compute the differences between this snapshot and `other`.
+    ///
+    /// Processes are matched by PID. A PID whose `start_id` differs is a
+    /// reused PID and is reported as removed and added rather than changed.
+    pub fn diff(&self, other: &ReplaySnapshot) -> SnapshotDiff {
+        let before: BTreeMap<u32, &ProcessRecord> =
+            self.processes.iter().map(|p| (p.pid.0, p)).collect();
+        let after: BTreeMap<u32, &ProcessRecord> =
+            other.processes.iter().map(|p| (p.pid.0, p)).collect();
+
+        let mut diff = SnapshotDiff {
+            before: self.name.clone(),
+            after: other.name.clone(),
+            ..SnapshotDiff::default()
+        };
+
+        for (pid, old) in &before {
+            match after.get(pid) {
+                Some(new) if new.start_id == old.start_id => {
+                    if let Some(delta) = diff_process(old, new) {
+                        diff.changed.push(delta);
+                    }
+                }
+                Some(_) => {
+                    diff.removed_pids.push(*pid);
+                    diff.added_pids.push(*pid);
+                }
+                None => diff.removed_pids.push(*pid),
+            }
+        }
+        diff.added_pids
+            .extend(after.keys().filter(|pid| !before.contains_key(pid)));
+        diff.added_pids.sort_unstable();
+
+        diff.context_changes = diff_context(&self.context, &other.context);
+        diff
+    }
+}
+
+fn changed<T: PartialEq + Clone>(before: &T, after: &T) -> Option<ValueDelta<T>> {
+    (before != after).then(|| ValueDelta {
+        before: before.clone(),
+        after: after.clone(),
+    })
+}
+
+fn diff_process(old: &ProcessRecord, new: &ProcessRecord) -> Option<ProcessDelta> {
+    let cpu_percent =
+        ((old.cpu_percent - new.cpu_percent).abs() > CPU_EPSILON).then_some(ValueDelta {
+            before: old.cpu_percent,
+            after: new.cpu_percent,
+        });
+
+    let delta = ProcessDelta {
+        pid: new.pid.0,
+        comm: new.comm.clone(),
+        cpu_percent,
+        rss_bytes: changed(&old.rss_bytes, &new.rss_bytes),
+        vsz_bytes: changed(&old.vsz_bytes, &new.vsz_bytes),
+        state: changed(&old.state, &new.state),
+    };
+
+    let any = delta.cpu_percent.is_some()
+        || delta.rss_bytes.is_some()
+        || delta.vsz_bytes.is_some()
+        || delta.state.is_some();
+    any.then_some(delta)
+}
+
+fn diff_context(old: &SystemContext, new: &SystemContext) -> Vec<FieldChange> {
+    let fields: [(&str, Option<String>, Option<String>); 6] = [
+        (
+            "hostname_hash",
+            old.hostname_hash.clone(),
+            new.hostname_hash.clone(),
+        ),
+        ("boot_id", old.boot_id.clone(), new.boot_id.clone()),
+        (
+            "recorded_at",
+            Some(old.recorded_at.clone()),
+            Some(new.recorded_at.clone()),
+        ),
+        (
+            "platform",
+            Some(old.platform.clone()),
+            Some(new.platform.clone()),
+        ),
+        (
+            "total_memory_bytes",
+            old.total_memory_bytes.map(|v| v.to_string()),
+            new.total_memory_bytes.map(|v| v.to_string()),
+        ),
+        (
+            "cpu_count",
+            old.cpu_count.map(|v| v.to_string()),
+            new.cpu_count.map(|v| v.to_string()),
+        ),
+    ];
+
+    fields
+        .into_iter()
+        .filter(|(_, before, after)| before != after)
+        .map(|(field, before, after)| FieldChange {
+            field: field.to_string(),
+            before,
+            after,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{Policy, Priors};
+    use crate::replay::{replay_inference, scenarios};
+
+    #[test]
+    fn test_diff_identical_is_empty() {
+        let snapshot = scenarios::mixed_workload();
+        let diff = snapshot.diff(&snapshot);
+        assert!(diff.is_empty());
+        assert_eq!(diff.before, diff.after);
+    }
+
+    #[test]
+    fn test_diff_added_and_removed() {
+        let before = scenarios::mixed_workload();
+        let mut after = before.clone();
+        let removed = after.processes.remove(0);
+        let mut added = after.processes[0].clone();
+        added.pid.0 = 999_999;
+        after.processes.push(added);
+
+        let diff = before.diff(&after);
+        assert_eq!(diff.removed_pids, vec![removed.pid.0]);
+        assert_eq!(diff.added_pids, vec![999_999]);
+        assert!(diff.changed.is_empty());
+    }
+
+    #[test]
+    fn test_diff_field_deltas() {
+        let before = scenarios::mixed_workload();
+        let mut after = before.clone();
+        after.processes[0].cpu_percent += 25.0;
+        after.processes[0].rss_bytes += 4096;
+        after.processes[1].state = ProcessState::Zombie;
+
+        let diff = before.diff(&after);
+        assert_eq!(diff.changed.len(), 2);
+
+        let first = diff
+            .changed
+            .iter()
+            .find(|d| d.pid == before.processes[0].pid.0)
+            .unwrap();
+        let cpu = first.cpu_percent.as_ref().unwrap();
+        assert!((cpu.after - cpu.before - 25.0).abs() < 1e-9);
+        let rss = first.rss_bytes.as_ref().unwrap();
+        assert_eq!(rss.after - rss.before, 4096);
+        assert!(first.state.is_none());
+
+        let second = diff
+            .changed
+            .iter()
+            .find(|d| d.pid == before.processes[1].pid.0)
+            .unwrap();
+        assert_eq!(second.state.as_ref().unwrap().after, ProcessState::Zombie);
+    }
+
+    #[test]
+    fn test_diff_pid_reuse_reported_as_replacement() {
+        let before = scenarios::mixed_workload();
+        let mut after = before.clone();
+        after.processes[0].start_id.0.push_str("-reused");
+
+        let diff = before.diff(&after);
+        let pid = before.processes[0].pid.0;
+        assert_eq!(diff.removed_pids, vec![pid]);
+        assert_eq!(diff.added_pids, vec![pid]);
+        assert!(diff.changed.is_empty());
+    }
+
+    #[test]
+    fn test_diff_context_and_json() {
+        let before = scenarios::mixed_workload();
+        let mut after = before.clone();
+        after.name = "later".to_string();
+        after.context.boot_id = Some("other-boot".to_string());
+        after.context.cpu_count = Some(64);
+
+        let diff = before.diff(&after);
+        let fields: Vec<&str> = diff
+            .context_changes
+            .iter()
+            .map(|c| c.field.as_str())
+            .collect();
+        assert!(fields.contains(&"boot_id"));
+        assert!(fields.contains(&"cpu_count"));
+
+        let json = serde_json::to_string(&diff).unwrap();
+        let parsed: SnapshotDiff = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, diff);
+        assert_eq!(parsed.after, "later");
+    }
+
+    fn replayed(snapshot: &ReplaySnapshot) -> Vec<ReplayInferenceResult> {
+        replay_inference(snapshot, &Priors::default(), &Policy::default()).unwrap()
+    }
+
+    #[test]
+    fn test_diff_scans_identical_is_empty() {
+        let results = replayed(&scenarios::mixed_workload());
+        assert!(results.iter().all(|r| !r.start_id.is_empty()));
+        assert!(diff_scans(&results, &results).is_empty());
+    }
+
+    #[test]
+    fn test_diff_scans_new_gone_and_changed() {
+        let prev = replayed(&scenarios::mixed_workload());
+        let mut current = prev.clone();
+        let gone = current.remove(0);
+        let mut fresh = current[0].clone();
+        fresh.pid = 999_999;
+        fresh.start_id = "boot:1:999999".to_string();
+        current.push(fresh);
+
+        let target = current[0].pid;
+        let old_action = current[0].recommended_action;
+        current[0].classification = "zombie".to_string();
+        current[0].recommended_action = if old_action == Action::Kill {
+            Action::Keep
+        } else {
+            Action::Kill
+        };
+        let relabelled = current[1].pid;
+        let label = current[1].classification.clone();
+        current[1].classification = format!("{}-changed", label);
+
+        let delta = diff_scans(&prev, &current);
+        assert_eq!(delta.gone_pids, vec![gone.pid]);
+        assert_eq!(delta.new_pids, vec![999_999]);
+        assert_eq!(delta.changed.len(), 2);
+
+        let first = delta.changed.iter().find(|c| c.pid == target).unwrap();
+        assert_eq!(
+            first.recommended_action.as_ref().unwrap().before,
+            old_action
+        );
+        let second = delta.changed.iter().find(|c| c.pid == relabelled).unwrap();
+        assert_eq!(second.classification.as_ref().unwrap().before, label);
+        assert!(second.recommended_action.is_none());
+
+        let json = serde_json::to_string(&delta).unwrap();
+        let parsed: ScanDelta = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, delta);
+    }
+
+    #[test]
+    fn test_diff_scans_pid_reuse_is_gone_and_new() {
+        let prev = replayed(&scenarios::mixed_workload());
+        let mut current = prev.clone();
+        current[0].start_id.push_str("-reused");
+        current[0].classification = "zombie".to_string();
+
+        let delta = diff_scans(&prev, &current);
+        assert_eq!(delta.gone_pids, vec![prev[0].pid]);
+        assert_eq!(delta.new_pids, vec![prev[0].pid]);
+        assert!(delta.changed.is_empty());
+
+        // Without start ids the same PID is treated as the same process.
+        current[0].start_id.clear();
+        let delta = diff_scans(&prev, &current);
+        assert!(delta.gone_pids.is_empty());
+        assert_eq!(delta.changed.len(), 1);
+    }
+}
@@ -38,14 +38,35 @@
 //! let snapshot = scenarios::zombie_tree();
 //! let snapshot = scenarios::memory_leak();
 //! let snapshot = scenarios::mixed_workload();
+//! let snapshot = scenarios::oom_aftermath();
+//! ```
+//!
+//! # Diffing
+//!
+//! ```ignore
+//! let diff = before.diff(&after);
+//! println!("{}", serde_json::to_string_pretty(&diff)?);
+//!
+//! // Triage changes only: new/gone PIDs and changed classifications/actions.
+//! let delta = diff_scans(&previous_results, &current_results);
 //! ```
 
+pub mod diff;
 pub mod scenarios;
 pub mod snapshot;
 
+pub use diff::{
+    diff_scans, FieldChange, ProcessDelta, ScanDelta, SnapshotDiff, TriageChange, ValueDelta,
+};
+
 pub use snapshot::{
-    load_snapshot, record_snapshot, replay_inference, DeepSignalRecord, ReplayError,
-    ReplayInferenceResult, ReplayMetadata, ReplaySnapshot, SystemContext,
+    load_snapshot, migrate_snapshot, record_snapshot, replay_inference, replay_inference_filtered,
+    replay_inference_filtered_with_options, replay_inference_with_options, DeepSignalRecord,
+    ReplayError, ReplayFilter, ReplayInferenceResult, ReplayMetadata, ReplayOptions,
+    ReplaySnapshot, SystemContext, DEFAULT_REPLAY_SEED,
 };
 
-pub use scenarios::{ci_build, dev_machine, memory_leak, mixed_workload, stuck_tests, zombie_tree};
+pub use scenarios::{
+    ci_build, dev_machine, fork_storm, memory_leak, mixed_workload, oom_aftermath, stuck_tests,
+    zombie_tree, ScenarioConfig,
+};
@@ -11,12 +11,53 @@
 use super::snapshot::{DeepSignalRecord, ReplayMetadata, ReplaySnapshot, SystemContext};
 use crate::collect::{ProcessRecord, ProcessState};
 use pt_common::{ProcessId, StartId};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use std::collections::HashMap;
 use std::time::Duration;
 
 /// Mock boot ID for scenario processes.
 const SCENARIO_BOOT_ID: &str = "00000000-0000-0000-0000-000000000001";
 
+/// Seed used by the zero-argument scenario functions.
+pub const DEFAULT_SCENARIO_SEED: u64 = 0;
+
+/// Base recording instant for scenarios (2026-01-01T00:00:00Z).
+const SCENARIO_EPOCH_UNIX: i64 = 1_767_225_600;
+
+/// Window after [`SCENARIO_EPOCH_UNIX`] that seeded recording times fall in.
+const SCENARIO_WINDOW_SECS: i64 = 365 * 86_400;
+
+/// Generation parameters for the built-in scenarios.
+///
+/// Scenarios never read the wall clock: every timestamp is derived from the
+/// seed, so two snapshots generated with the same seed serialize to
+/// byte-identical JSON.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScenarioConfig {
+    /// Seed for all generated variation (currently the recording instant).
+    pub seed: u64,
+}
+
+impl Default for ScenarioConfig {
+    fn default() -> Self {
+        Self::with_seed(DEFAULT_SCENARIO_SEED)
+    }
+}
+
+impl ScenarioConfig {
+    /// Create a config with the given seed.
+    pub fn with_seed(seed: u64) -> Self {
+        Self { seed }
+    }
+
+    /// Unix timestamp at which the scenario is considered recorded.
+    fn recorded_at_unix(&self) -> i64 {
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        SCENARIO_EPOCH_UNIX + rng.random_range(0..SCENARIO_WINDOW_SECS)
+    }
+}
+
 /// Helper: build a process record with minimal boilerplate.
 struct ProcBuilder {
     rec: ProcessRecord,
@@ -40,7 +81,8 @@ impl ProcBuilder {
                 rss_bytes: 10 * 1024 * 1024,
                 vsz_bytes: 50 * 1024 * 1024,
                 tty: None,
-                start_time_unix: chrono::Utc::now().timestamp() - 3600,
+                // Filled in from the scenario clock by `build_scenario`.
+                start_time_unix: 0,
                 elapsed: Duration::from_secs(3600),
                 source: "scenario".to_string(),
                 container_info: None,
@@ -70,7 +112,12 @@ impl ProcBuilder {
 
     fn elapsed_secs(mut self, secs: u64) -> Self {
         self.rec.elapsed = Duration::from_secs(secs);
-        self.rec.start_time_unix = chrono::Utc::now().timestamp() - secs as i64;
+        self
+    }
+
+    fn pgid(mut self, pgid: u32) -> Self {
+        self.rec.pgid = Some(pgid);
+        self.rec.sid = Some(pgid);
         self
     }
 
@@ -84,18 +131,22 @@ impl ProcBuilder {
     }
 }
 
-/// Helper: current timestamp string.
-fn now_rfc3339() -> String {
-    chrono::Utc::now().to_rfc3339()
-}
-
 /// Helper: build a snapshot from a list of processes.
+///
+/// Process start times are derived from the configured recording instant
+/// and each process's elapsed time.
 fn build_scenario(
+    config: &ScenarioConfig,
     name: &str,
     description: &str,
-    processes: Vec<crate::collect::ProcessRecord>,
+    mut processes: Vec<crate::collect::ProcessRecord>,
     deep_signals: HashMap<u32, DeepSignalRecord>,
 ) -> ReplaySnapshot {
+    let recorded_at = config.recorded_at_unix();
+    for proc in &mut processes {
+        proc.start_time_unix = recorded_at - proc.elapsed.as_secs() as i64;
+    }
+
     ReplaySnapshot {
         schema_version: super::snapshot::REPLAY_SCHEMA_VERSION.to_string(),
         name: name.to_string(),
@@ -103,16 +154,20 @@ fn build_scenario(
         context: SystemContext {
             hostname_hash: Some("scenario-host".to_string()),
             boot_id: Some("00000000-0000-0000-0000-000000000001".to_string()),
-            recorded_at: now_rfc3339(),
+            recorded_at: chrono::DateTime::from_timestamp(recorded_at, 0)
+                .unwrap_or_default()
+                .to_rfc3339(),
             platform: "linux".to_string(),
             total_memory_bytes: Some(16 * 1024 * 1024 * 1024), // 16 GB
             cpu_count: Some(8),
         },
         scan_metadata: ReplayMetadata {
+            schema_version: super::snapshot::REPLAY_METADATA_VERSION,
             scan_type: "scenario".to_string(),
             duration_ms: 0,
             process_count: processes.len(),
             warnings: vec![],
+            replay_seed: None,
         },
         processes,
         deep_signals,
@@ -127,6 +182,11 @@ fn build_scenario(
 /// - 1 active webserver (should not be killed)
 /// - 1 idle editor (should be kept)
 pub fn stuck_tests() -> ReplaySnapshot {
+    stuck_tests_with(&ScenarioConfig::default())
+}
+
+/// [`stuck_tests`] generated with an explicit [`ScenarioConfig`].
+pub fn stuck_tests_with(config: &ScenarioConfig) -> ReplaySnapshot {
     let mut deep = HashMap::new();
 
     let processes = vec![
@@ -218,6 +278,7 @@ pub fn stuck_tests() -> ReplaySnapshot {
     );
 
     build_scenario(
+        config,
         "stuck_tests",
         "Multiple stuck test runners consuming resources. Expected: tests recommended for kill, webserver and editor kept.",
         processes,
@@ -232,6 +293,11 @@ pub fn stuck_tests() -> ReplaySnapshot {
 /// - 1 normal database process
 /// - 2 healthy worker processes
 pub fn memory_leak() -> ReplaySnapshot {
+    memory_leak_with(&ScenarioConfig::default())
+}
+
+/// [`memory_leak`] generated with an explicit [`ScenarioConfig`].
+pub fn memory_leak_with(config: &ScenarioConfig) -> ReplaySnapshot {
     let mut deep = HashMap::new();
 
     let processes = vec![
@@ -285,6 +351,7 @@ pub fn memory_leak() -> ReplaySnapshot {
     );
 
     build_scenario(
+        config,
         "memory_leak",
         "Web application with gradual memory growth (8GB RSS after 5 days). Workers healthy.",
         processes,
@@ -300,6 +367,11 @@ pub fn memory_leak() -> ReplaySnapshot {
 /// - 1 orphaned child (reparented to init)
 /// - 2 normal background services
 pub fn zombie_tree() -> ReplaySnapshot {
+    zombie_tree_with(&ScenarioConfig::default())
+}
+
+/// [`zombie_tree`] generated with an explicit [`ScenarioConfig`].
+pub fn zombie_tree_with(config: &ScenarioConfig) -> ReplaySnapshot {
     let processes = vec![
         // Zombie parent - crashed build system
         ProcBuilder::new(30001, "make", "make -j8 all")
@@ -368,6 +440,7 @@ pub fn zombie_tree() -> ReplaySnapshot {
     ];
 
     build_scenario(
+        config,
         "zombie_tree",
         "Orphaned process tree from crashed build system. 5 zombies + 1 orphan, 2 normal services.",
         processes,
@@ -377,6 +450,11 @@ pub fn zombie_tree() -> ReplaySnapshot {
 
 /// Scenario: Typical CI build environment with diverse process types.
 pub fn ci_build() -> ReplaySnapshot {
+    ci_build_with(&ScenarioConfig::default())
+}
+
+/// [`ci_build`] generated with an explicit [`ScenarioConfig`].
+pub fn ci_build_with(config: &ScenarioConfig) -> ReplaySnapshot {
     let mut deep = HashMap::new();
 
     let processes = vec![
@@ -454,6 +532,7 @@ pub fn ci_build() -> ReplaySnapshot {
     );
 
     build_scenario(
+        config,
         "ci_build",
         "Typical CI environment: active build, stale zombie, docker daemon, log collector.",
         processes,
@@ -463,6 +542,11 @@ pub fn ci_build() -> ReplaySnapshot {
 
 /// Scenario: Developer workstation with typical mixed workload.
 pub fn dev_machine() -> ReplaySnapshot {
+    dev_machine_with(&ScenarioConfig::default())
+}
+
+/// [`dev_machine`] generated with an explicit [`ScenarioConfig`].
+pub fn dev_machine_with(config: &ScenarioConfig) -> ReplaySnapshot {
     let mut deep = HashMap::new();
 
     let processes = vec![
@@ -548,6 +632,7 @@ pub fn dev_machine() -> ReplaySnapshot {
     );
 
     build_scenario(
+        config,
         "dev_machine",
         "Developer workstation: editor, terminal, dev server, forgotten processes, orphaned npm.",
         processes,
@@ -557,6 +642,11 @@ pub fn dev_machine() -> ReplaySnapshot {
 
 /// Scenario: Mixed workload with various process types for comprehensive testing.
 pub fn mixed_workload() -> ReplaySnapshot {
+    mixed_workload_with(&ScenarioConfig::default())
+}
+
+/// [`mixed_workload`] generated with an explicit [`ScenarioConfig`].
+pub fn mixed_workload_with(config: &ScenarioConfig) -> ReplaySnapshot {
     let mut deep = HashMap::new();
 
     let processes = vec![
@@ -648,6 +738,7 @@ pub fn mixed_workload() -> ReplaySnapshot {
     );
 
     build_scenario(
+        config,
         "mixed_workload",
         "Mixed: healthy server/db, abandoned batch job, zombie, CPU hog, stopped editor, short-lived process.",
         processes,
@@ -655,6 +746,270 @@ pub fn mixed_workload() -> ReplaySnapshot {
     )
 }
 
+/// Number of forked children in the [`fork_storm`] scenario.
+const FORK_STORM_CHILDREN: u32 = 300;
+
+/// Scenario: Thundering-herd fork storm.
+///
+/// Simulates a runaway `:(){ :|:& };:` style fork bomb that started a
+/// minute ago:
+/// - 1 root shell (the storm origin, attached to a terminal)
+/// - 300 short-lived forked shells in one process group, each forked by an
+///   earlier member so ancestry forms a binary tree rooted at the origin
+/// - every 10th child already exited and was never reaped (zombie)
+/// - every 25th child lost its parent and was reparented to init (orphan)
+/// - 2 normal background services outside the storm
+///
+/// Children are spread evenly across the storm's lifetime and CPU rises
+/// with spawn order, so aggregate load escalates toward the newest forks.
+///
+/// Expected outcome under default priors and policy (regression fixture):
+/// - the 30 unreaped children classify as `zombie`
+/// - the reparented orphans and the oldest, coolest forks as `abandoned`
+/// - the newest, hottest forks as `useful_bad`
+/// - the bulk of live forks still look `useful` when judged one process
+///   at a time; the storm is only visible through shared ancestry and
+///   process group, which is what this scenario exists to exercise.
+pub fn fork_storm() -> ReplaySnapshot {
+    fork_storm_with(&ScenarioConfig::default())
+}
+
+/// [`fork_storm`] generated with an explicit [`ScenarioConfig`].
+pub fn fork_storm_with(config: &ScenarioConfig) -> ReplaySnapshot {
+    const ROOT_PID: u32 = 70000;
+    const STORM_SECS: u64 = 60;
+    const BOMB: &str = "bash -c ':(){ :|:& };:'";
+
+    let mut processes = Vec::with_capacity(FORK_STORM_CHILDREN as usize + 3);
+
+    processes.push(
+        ProcBuilder::new(ROOT_PID, "bash", BOMB)
+            .ppid(2000)
+            .pgid(ROOT_PID)
+            .state(ProcessState::Running)
+            .cpu(35.0)
+            .rss(4 * 1024 * 1024)
+            .elapsed_secs(STORM_SECS)
+            .tty(Some("pts/4"))
+            .build(),
+    );
+
+    for i in 1..=FORK_STORM_CHILDREN {
+        let pid = ROOT_PID + i;
+        // Binary fork tree: child i was forked by child i / 2 (0 = root).
+        let parent = ROOT_PID + i / 2;
+        let spawn_offset = STORM_SECS * u64::from(i) / u64::from(FORK_STORM_CHILDREN);
+        let elapsed = (STORM_SECS - spawn_offset).max(1);
+        let progress = f64::from(i) / f64::from(FORK_STORM_CHILDREN);
+
+        let builder = ProcBuilder::new(pid, "bash", BOMB)
+            .pgid(ROOT_PID)
+            .elapsed_secs(elapsed);
+
+        let rec = if i % 10 == 0 {
+            builder
+                .ppid(parent)
+                .state(ProcessState::Zombie)
+                .cpu(0.0)
+                .rss(0)
+                .build()
+        } else if i % 25 == 0 {
+            builder
+                .ppid(1)
+                .state(ProcessState::Sleeping)
+                .cpu(0.0)
+                .rss(2 * 1024 * 1024)
+                .build()
+        } else {
+            builder
+                .ppid(parent)
+                .state(ProcessState::Running)
+                .cpu(5.0 + 90.0 * progress)
+                .rss(2 * 1024 * 1024)
+                .tty(Some("pts/4"))
+                .build()
+        };
+        processes.push(rec);
+    }
+
+    processes.push(
+        ProcBuilder::new(71001, "sshd", "/usr/sbin/sshd -D")
+            .ppid(1)
+            .cpu(0.0)
+            .rss(8 * 1024 * 1024)
+            .elapsed_secs(30 * 86400)
+            .build(),
+    );
+    processes.push(
+        ProcBuilder::new(71002, "systemd-journal", "/lib/systemd/systemd-journald")
+            .ppid(1)
+            .cpu(1.0)
+            .rss(32 * 1024 * 1024)
+            .elapsed_secs(30 * 86400)
+            .build(),
+    );
+
+    build_scenario(
+        config,
+        "fork_storm",
+        "Fork bomb: 1 origin shell + 300 forked children in one process group with escalating CPU, 2 normal services.",
+        processes,
+        HashMap::new(),
+    )
+}
+
+/// Scenario: Aftermath of the Linux OOM killer.
+///
+/// Simulates a host shortly after the OOM killer shot a memory-hungry
+/// JVM build daemon:
+/// - the JVM's children, reparented to init (orphans, no TTY, idle)
+/// - a half-dead gunicorn supervisor stuck in uninterruptible sleep
+///   with its RSS collapsed after its own worker was killed
+/// - that killed worker, left unreaped as a zombie
+/// - one surviving gunicorn worker still serving requests
+/// - postgres, whose RSS dropped sharply once its page cache was
+///   reclaimed, plus sshd and journald
+///
+/// Correct triage:
+/// - kill the three JVM orphans (`abandoned`; their parent is gone and
+///   they will never be asked for work again)
+/// - treat the gunicorn worker zombie as `zombie`; it clears once its
+///   parent reaps it, so the fix is restarting the stuck supervisor, not
+///   signalling the zombie
+/// - restart (not just kill) the D-state supervisor so the surviving
+///   worker keeps a parent that reaps and respawns
+/// - keep the surviving worker, postgres, sshd and journald; a sudden RSS
+///   drop after reclaim is not evidence of abandonment
+///
+/// Judged one process at a time under default priors and policy, the
+/// replay pipeline gets the orphans and the zombie right but also flags
+/// the init-parented services as `abandoned`: it cannot see that systemd
+/// supervises them. The surviving worker stays far more `useful` than the
+/// orphans. Distinguishing reparented orphans from daemons is what this
+/// scenario exists to exercise.
+pub fn oom_aftermath() -> ReplaySnapshot {
+    oom_aftermath_with(&ScenarioConfig::default())
+}
+
+/// [`oom_aftermath`] generated with an explicit [`ScenarioConfig`].
+pub fn oom_aftermath_with(config: &ScenarioConfig) -> ReplaySnapshot {
+    let mut deep = HashMap::new();
+
+    let processes = vec![
+        // Orphans of the killed JVM (was PID 80000), reparented to init
+        ProcBuilder::new(80001, "java", "java -jar /opt/gradle/lib/gradle-worker.jar")
+            .ppid(1)
+            .state(ProcessState::Sleeping)
+            .cpu(0.0)
+            .rss(64 * 1024 * 1024)
+            .elapsed_secs(6 * 3600)
+            .build(),
+        ProcBuilder::new(
+            80002,
+            "kotlin-daemon",
+            "java -cp kotlin-compiler.jar KotlinCompileDaemon",
+        )
+        .ppid(1)
+        .state(ProcessState::Sleeping)
+        .cpu(0.0)
+        .rss(96 * 1024 * 1024)
+        .elapsed_secs(6 * 3600)
+        .build(),
+        ProcBuilder::new(80003, "sh", "sh -c ./gradlew test --offline")
+            .ppid(1)
+            .state(ProcessState::Sleeping)
+            .cpu(0.0)
+            .rss(2 * 1024 * 1024)
+            .elapsed_secs(6 * 3600)
+            .build(),
+        // Half-dead supervisor: stuck in D state, RSS collapsed
+        ProcBuilder::new(80010, "gunicorn", "gunicorn: master [app:wsgi]")
+            .ppid(1)
+            .state(ProcessState::DiskSleep)
+            .cpu(0.0)
+            .rss(3 * 1024 * 1024)
+            .elapsed_secs(3 * 86400)
+            .build(),
+        // Worker killed by the OOM killer, never reaped by the stuck master
+        ProcBuilder::new(80011, "gunicorn", "gunicorn: worker [app:wsgi]")
+            .ppid(80010)
+            .state(ProcessState::Zombie)
+            .cpu(0.0)
+            .rss(0)
+            .elapsed_secs(3 * 86400)
+            .build(),
+        // Surviving worker still serving requests
+        ProcBuilder::new(80012, "gunicorn", "gunicorn: worker [app:wsgi]")
+            .ppid(80010)
+            .state(ProcessState::Running)
+            .cpu(20.0)
+            .rss(180 * 1024 * 1024)
+            .elapsed_secs(3 * 86400)
+            .build(),
+        // Postgres after page cache reclaim
+        ProcBuilder::new(80020, "postgres", "postgres: main process")
+            .ppid(1)
+            .state(ProcessState::Sleeping)
+            .cpu(4.0)
+            .rss(48 * 1024 * 1024)
+            .elapsed_secs(30 * 86400)
+            .build(),
+        ProcBuilder::new(80021, "sshd", "/usr/sbin/sshd -D")
+            .ppid(1)
+            .state(ProcessState::Sleeping)
+            .cpu(0.0)
+            .rss(8 * 1024 * 1024)
+            .elapsed_secs(30 * 86400)
+            .build(),
+        ProcBuilder::new(80022, "systemd-journal", "/lib/systemd/systemd-journald")
+            .ppid(1)
+            .state(ProcessState::Sleeping)
+            .cpu(1.0)
+            .rss(32 * 1024 * 1024)
+            .elapsed_secs(30 * 86400)
+            .build(),
+    ];
+
+    for pid in [80001, 80002, 80003] {
+        deep.insert(
+            pid,
+            DeepSignalRecord {
+                net_active: Some(false),
+                io_active: Some(false),
+            },
+        );
+    }
+    deep.insert(
+        80010,
+        DeepSignalRecord {
+            net_active: Some(false),
+            io_active: Some(false),
+        },
+    );
+    deep.insert(
+        80012,
+        DeepSignalRecord {
+            net_active: Some(true),
+            io_active: Some(true),
+        },
+    );
+    deep.insert(
+        80020,
+        DeepSignalRecord {
+            net_active: Some(true),
+            io_active: Some(true),
+        },
+    );
+
+    build_scenario(
+        config,
+        "oom_aftermath",
+        "After an OOM kill: 3 orphans reparented to init, a stuck supervisor with collapsed RSS, 1 unreaped worker, 1 surviving worker, 3 services.",
+        processes,
+        deep,
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -713,6 +1068,82 @@ mod tests {
         assert_eq!(snapshot.processes.len(), 7);
     }
 
+    #[test]
+    fn test_fork_storm_scenario() {
+        let snapshot = fork_storm();
+        assert_eq!(snapshot.name, "fork_storm");
+        assert_eq!(snapshot.processes.len(), FORK_STORM_CHILDREN as usize + 3);
+
+        // Every storm member shares the origin's process group and descends
+        // from it (or was reparented to init).
+        let pids: std::collections::HashSet<u32> =
+            snapshot.processes.iter().map(|p| p.pid.0).collect();
+        let storm: Vec<_> = snapshot
+            .processes
+            .iter()
+            .filter(|p| p.pgid == Some(70000))
+            .collect();
+        assert_eq!(storm.len(), FORK_STORM_CHILDREN as usize + 1);
+        for p in storm.iter().skip(1) {
+            assert!(p.ppid.0 == 1 || pids.contains(&p.ppid.0));
+            assert!(p.elapsed.as_secs() <= 60);
+        }
+
+        let priors = Priors::default();
+        let policy = Policy::default();
+        let results = replay_inference(&snapshot, &priors, &policy).unwrap();
+        let class_of = |pid: u32| {
+            results
+                .iter()
+                .find(|r| r.pid == pid)
+                .map(|r| r.classification.as_str())
+                .unwrap()
+        };
+
+        for i in 1..=FORK_STORM_CHILDREN {
+            let pid = 70000 + i;
+            if i % 10 == 0 {
+                assert_eq!(class_of(pid), "zombie", "PID {pid}");
+            } else if i % 25 == 0 {
+                assert_eq!(class_of(pid), "abandoned", "PID {pid}");
+            }
+        }
+        assert_eq!(class_of(70001), "abandoned");
+        assert_eq!(class_of(70000 + FORK_STORM_CHILDREN - 1), "useful_bad");
+
+        let mut counts: HashMap<&str, usize> = HashMap::new();
+        for r in results.iter().filter(|r| (70001..=70300).contains(&r.pid)) {
+            *counts.entry(r.classification.as_str()).or_default() += 1;
+        }
+        assert_eq!(counts.get("zombie"), Some(&30));
+        assert!(counts.get("useful").copied().unwrap_or(0) > counts["useful_bad"]);
+    }
+
+    #[test]
+    fn test_oom_aftermath_scenario() {
+        let snapshot = oom_aftermath();
+        assert_eq!(snapshot.name, "oom_aftermath");
+        assert_eq!(snapshot.processes.len(), 9);
+
+        let reparented: Vec<u32> = snapshot
+            .processes
+            .iter()
+            .filter(|p| p.ppid.0 == 1 && p.tty.is_none())
+            .map(|p| p.pid.0)
+            .collect();
+        assert!(reparented.starts_with(&[80001, 80002, 80003]));
+        let supervisor = snapshot.processes.iter().find(|p| p.pid.0 == 80010);
+        assert_eq!(supervisor.unwrap().state, ProcessState::DiskSleep);
+
+        let results = replay_inference(&snapshot, &Priors::default(), &Policy::default()).unwrap();
+        let result = |pid: u32| results.iter().find(|r| r.pid == pid).unwrap();
+        for orphan in [80001, 80002, 80003] {
+            assert_eq!(result(orphan).classification, "abandoned", "PID {orphan}");
+        }
+        assert_eq!(result(80011).classification, "zombie");
+        assert!(result(80012).posterior.useful > 1e3 * result(80001).posterior.useful);
+    }
+
     #[test]
     fn test_all_scenarios_replay() {
         let priors = Priors::default();
@@ -725,6 +1156,8 @@ mod tests {
             ci_build,
             dev_machine,
             mixed_workload,
+            fork_storm,
+            oom_aftermath,
         ] {
             let snapshot = scenario_fn();
             let results = replay_inference(&snapshot, &priors, &policy)
@@ -753,6 +1186,38 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_zombie_tree_is_deterministic() {
+        let a = serde_json::to_string(&zombie_tree()).unwrap();
+        let b = serde_json::to_string(&zombie_tree()).unwrap();
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_same_seed_same_snapshot() {
+        let config = ScenarioConfig::with_seed(42);
+        for scenario_fn in [
+            stuck_tests_with,
+            memory_leak_with,
+            zombie_tree_with,
+            ci_build_with,
+            dev_machine_with,
+            mixed_workload_with,
+            fork_storm_with,
+            oom_aftermath_with,
+        ] {
+            let a = serde_json::to_string(&scenario_fn(&config)).unwrap();
+            let b = serde_json::to_string(&scenario_fn(&config)).unwrap();
+            assert_eq!(a, b);
+        }
+
+        let other = zombie_tree_with(&ScenarioConfig::with_seed(43));
+        assert_ne!(
+            zombie_tree_with(&config).context.recorded_at,
+            other.context.recorded_at
+        );
+    }
+
     #[test]
     fn test_scenarios_serialize_roundtrip() {
         for scenario_fn in [
@@ -762,6 +1227,8 @@ mod tests {
             ci_build,
             dev_machine,
             mixed_workload,
+            fork_storm,
+            oom_aftermath,
         ] {
             let snapshot = scenario_fn();
             let json = serde_json::to_string(&snapshot).unwrap();
@@ -6,6 +6,7 @@ use crate::config::Policy;
 use crate::decision::expected_loss::{Action, ActionFeasibility};
 use crate::decision::myopic_policy::compute_loss_table;
 use crate::inference::posterior::{compute_posterior, ClassScores, CpuEvidence, Evidence};
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::Path;
@@ -14,6 +15,17 @@ use thiserror::Error;
 /// Schema version for replay snapshot files.
 pub const REPLAY_SCHEMA_VERSION: &str = "1.0.0";
 
+/// Current version of the [`ReplayMetadata`] layout.
+///
+/// Bumped whenever snapshot fields change; each bump must register a
+/// migration step in `METADATA_MIGRATIONS`. Snapshots written before
+/// versioning was introduced have no `schema_version` in their metadata
+/// and load as v1.
+pub const REPLAY_METADATA_VERSION: u32 = 2;
+
+/// Seed used when neither [`ReplayOptions`] nor the snapshot specifies one.
+pub const DEFAULT_REPLAY_SEED: u64 = 0;
+
 /// Errors that can occur during replay operations.
 #[derive(Debug, Error)]
 pub enum ReplayError {
@@ -26,6 +38,12 @@ pub enum ReplayError {
     #[error("snapshot schema version {found} is not compatible (expected {expected})")]
     IncompatibleSchema { found: String, expected: String },
 
+    #[error("snapshot metadata version {found} is newer than supported version {supported}")]
+    UnsupportedVersion { found: u32, supported: u32 },
+
+    #[error("no migration registered from snapshot metadata version {from}")]
+    MissingMigration { from: u32 },
+
     #[error("snapshot has no processes")]
     EmptySnapshot,
 
@@ -58,8 +76,12 @@ pub struct ReplaySnapshot {
     /// Process records from the scan.
     pub processes: Vec<ProcessRecord>,
 
-    /// Optional deep signal data per PID.
-    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    /// Optional deep signal data per PID (serialized in PID order).
+    #[serde(
+        default,
+        skip_serializing_if = "HashMap::is_empty",
+        serialize_with = "pt_common::serialize_sorted"
+    )]
     pub deep_signals: HashMap<u32, DeepSignalRecord>,
 }
 
@@ -92,6 +114,10 @@ pub struct SystemContext {
 /// Metadata about the original scan.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReplayMetadata {
+    /// Snapshot layout version (see [`REPLAY_METADATA_VERSION`]).
+    #[serde(default = "legacy_metadata_version")]
+    pub schema_version: u32,
+
     /// Original scan type.
     pub scan_type: String,
 
@@ -104,6 +130,15 @@ pub struct ReplayMetadata {
     /// Warnings from the original scan.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub warnings: Vec<String>,
+
+    /// Seed to replay with when [`ReplayOptions::seed`] is unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub replay_seed: Option<u64>,
+}
+
+/// Version assumed for snapshots recorded before metadata was versioned.
+fn legacy_metadata_version() -> u32 {
+    1
 }
 
 /// Deep scan signals for a single process (optional enrichment).
@@ -119,11 +154,15 @@ pub struct DeepSignalRecord {
 }
 
 /// Result of replaying inference for a single process.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ReplayInferenceResult {
     /// Process ID.
     pub pid: u32,
 
+    /// Process incarnation, used to tell a reused PID from the same process.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub start_id: String,
+
     /// Command name.
     pub comm: String,
 
@@ -149,6 +188,88 @@ pub struct ReplayInferenceResult {
     pub evidence_terms: Vec<String>,
 }
 
+/// Options controlling a replay run.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ReplayOptions {
+    /// Seed for stochastic replay steps. Classification and the decision are
+    /// deterministic, so the current pipeline gives the same results for
+    /// every seed. `None` uses the snapshot's recorded seed, then
+    /// [`DEFAULT_REPLAY_SEED`].
+    pub seed: Option<u64>,
+}
+
+impl ReplayOptions {
+    /// Options replaying with a fixed seed.
+    pub fn with_seed(seed: u64) -> Self {
+        Self { seed: Some(seed) }
+    }
+
+    /// Seed a replay of `snapshot` with these options uses.
+    pub fn resolve_seed(&self, snapshot: &ReplaySnapshot) -> u64 {
+        self.seed
+            .or(snapshot.scan_metadata.replay_seed)
+            .unwrap_or(DEFAULT_REPLAY_SEED)
+    }
+}
+
+/// Selects a subset of snapshot processes for partial replay.
+///
+/// All configured criteria must match; an empty filter matches everything.
+#[derive(Debug, Clone, Default)]
+pub struct ReplayFilter {
+    /// Only replay these PIDs (empty = any PID).
+    pub pids: Vec<u32>,
+
+    /// Only replay processes whose command line or name matches.
+    pub command: Option<Regex>,
+
+    /// Only keep results classified as one of these labels
+    /// (`useful`, `useful_bad`, `abandoned`, `zombie`; empty = any).
+    pub classifications: Vec<String>,
+}
+
+impl ReplayFilter {
+    /// Filter selecting an explicit set of PIDs.
+    pub fn pids(pids: impl IntoIterator<Item = u32>) -> Self {
+        Self {
+            pids: pids.into_iter().collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Additionally require the command line or name to match `pattern`.
+    pub fn with_command(mut self, pattern: &str) -> Result<Self, regex::Error> {
+        self.command = Some(Regex::new(pattern)?);
+        Ok(self)
+    }
+
+    /// Additionally require the replayed classification to be `label`.
+    pub fn with_classification(mut self, label: impl Into<String>) -> Self {
+        self.classifications.push(label.into());
+        self
+    }
+
+    /// Whether a process passes the PID and command criteria.
+    fn selects(&self, proc: &ProcessRecord) -> bool {
+        if !self.pids.is_empty() && !self.pids.contains(&proc.pid.0) {
+            return false;
+        }
+        match &self.command {
+            Some(re) => re.is_match(&proc.cmd) || re.is_match(&proc.comm),
+            None => true,
+        }
+    }
+
+    /// Whether a replay result passes the classification criterion.
+    fn keeps(&self, result: &ReplayInferenceResult) -> bool {
+        self.classifications.is_empty()
+            || self
+                .classifications
+                .iter()
+                .any(|c| c == &result.classification)
+    }
+}
+
 // ── Recording ───────────────────────────────────────────────────────────
 
 /// Record a live scan result into a replay snapshot.
@@ -181,10 +302,12 @@ pub fn record_snapshot(
             cpu_count: None,
         },
         scan_metadata: ReplayMetadata {
+            schema_version: REPLAY_METADATA_VERSION,
             scan_type: scan.metadata.scan_type.clone(),
             duration_ms: scan.metadata.duration_ms,
             process_count: scan.processes.len(),
             warnings: scan.metadata.warnings.clone(),
+            replay_seed: Some(DEFAULT_REPLAY_SEED),
         },
         processes: scan.processes.clone(),
         deep_signals: HashMap::new(),
@@ -194,9 +317,12 @@ pub fn record_snapshot(
 // ── Loading ─────────────────────────────────────────────────────────────
 
 /// Load a replay snapshot from a JSON file.
+///
+/// Snapshots recorded by older versions are upgraded in place via
+/// [`migrate_snapshot`]; snapshots from a newer, unknown version are rejected.
 pub fn load_snapshot(path: &Path) -> Result<ReplaySnapshot, ReplayError> {
     let content = std::fs::read_to_string(path)?;
-    let snapshot: ReplaySnapshot = serde_json::from_str(&content)?;
+    let mut snapshot: ReplaySnapshot = serde_json::from_str(&content)?;
 
     // Version compatibility check (major version must match)
     let major = snapshot
@@ -220,9 +346,52 @@ pub fn load_snapshot(path: &Path) -> Result<ReplaySnapshot, ReplayError> {
         });
     }
 
+    let from_version = snapshot.scan_metadata.schema_version;
+    migrate_snapshot(&mut snapshot, from_version)?;
+
     Ok(snapshot)
 }
 
+/// Upgrades a snapshot from one metadata version to the next.
+type MigrationStep = fn(&mut ReplaySnapshot);
+
+/// Registered metadata upgrades, keyed by the version they upgrade from.
+const METADATA_MIGRATIONS: &[(u32, MigrationStep)] = &[(1, migrate_v1_to_v2)];
+
+/// Version 1 -> 2: metadata gained `schema_version`, which
+/// serde fills in, so no fields need rewriting.
+fn migrate_v1_to_v2(_snapshot: &mut ReplaySnapshot) {}
+
+/// Migrate a snapshot from an older metadata version to current.
+///
+/// Applies each registered step in turn and stamps the result with
+/// [`REPLAY_METADATA_VERSION`].
+pub fn migrate_snapshot(
+    snapshot: &mut ReplaySnapshot,
+    from_version: u32,
+) -> Result<(), ReplayError> {
+    if from_version > REPLAY_METADATA_VERSION {
+        return Err(ReplayError::UnsupportedVersion {
+            found: from_version,
+            supported: REPLAY_METADATA_VERSION,
+        });
+    }
+
+    // Unversioned snapshots predate v2 and load as v1.
+    let mut version = from_version.max(1);
+    while version < REPLAY_METADATA_VERSION {
+        let (_, step) = METADATA_MIGRATIONS
+            .iter()
+            .find(|(from, _)| *from == version)
+            .ok_or(ReplayError::MissingMigration { from: version })?;
+        step(snapshot);
+        version += 1;
+    }
+    snapshot.scan_metadata.schema_version = REPLAY_METADATA_VERSION;
+
+    Ok(())
+}
+
 impl ReplaySnapshot {
     /// Save the snapshot to a JSON file.
     pub fn save(&self, path: &Path) -> Result<(), ReplayError> {
@@ -231,6 +400,11 @@ impl ReplaySnapshot {
         Ok(())
     }
 
+    /// Record `seed` as the seed future replays of this snapshot use.
+    pub fn set_replay_seed(&mut self, seed: u64) {
+        self.scan_metadata.replay_seed = Some(seed);
+    }
+
     /// Reconstruct a ScanResult from the snapshot for integration with
     /// the existing pipeline.
     pub fn to_scan_result(&self) -> ScanResult {
@@ -284,49 +458,114 @@ pub fn replay_inference(
     priors: &Priors,
     policy: &Policy,
 ) -> Result<Vec<ReplayInferenceResult>, ReplayError> {
-    let feasibility = ActionFeasibility::allow_all();
-    let mut results = Vec::with_capacity(snapshot.processes.len());
-
-    for proc in &snapshot.processes {
-        let deep = snapshot.deep_signals.get(&proc.pid.0);
+    replay_inference_with_options(snapshot, priors, policy, &ReplayOptions::default())
+}
 
-        // Build evidence from the process record + optional deep signals
-        let evidence = build_evidence(proc, deep);
+/// [`replay_inference`] with explicit [`ReplayOptions`].
+///
+/// No replay step currently draws from the seed, so results are identical
+/// across runs regardless of `options`.
+pub fn replay_inference_with_options(
+    snapshot: &ReplaySnapshot,
+    priors: &Priors,
+    policy: &Policy,
+    _options: &ReplayOptions,
+) -> Result<Vec<ReplayInferenceResult>, ReplayError> {
+    let feasibility = ActionFeasibility::allow_all();
+    snapshot
+        .processes
+        .iter()
+        .map(|proc| replay_process(snapshot, proc, priors, policy, &feasibility))
+        .collect()
+}
 
-        // Compute posterior
-        let posterior =
-            compute_posterior(priors, &evidence).map_err(|e| ReplayError::Inference {
-                pid: proc.pid.0,
-                message: e.to_string(),
-            })?;
+/// Replay only the processes selected by `filter`.
+///
+/// Uses the same per-process pipeline as [`replay_inference`], so results
+/// for a selected PID are identical to those from a full replay.
+pub fn replay_inference_filtered(
+    snapshot: &ReplaySnapshot,
+    priors: &Priors,
+    policy: &Policy,
+    filter: &ReplayFilter,
+) -> Result<Vec<ReplayInferenceResult>, ReplayError> {
+    replay_inference_filtered_with_options(
+        snapshot,
+        priors,
+        policy,
+        filter,
+        &ReplayOptions::default(),
+    )
+}
 
-        // Determine action via myopic policy
-        let decision =
-            decide_from_belief_for_replay(&posterior.posterior, &policy.loss_matrix, &feasibility);
+/// [`replay_inference_filtered`] with explicit [`ReplayOptions`].
+///
+/// Results for a selected PID match [`replay_inference_with_options`] run
+/// with the same options.
+pub fn replay_inference_filtered_with_options(
+    snapshot: &ReplaySnapshot,
+    priors: &Priors,
+    policy: &Policy,
+    filter: &ReplayFilter,
+    _options: &ReplayOptions,
+) -> Result<Vec<ReplayInferenceResult>, ReplayError> {
+    let feasibility = ActionFeasibility::allow_all();
+    let mut results = Vec::new();
 
-        let classification = classify(&posterior.posterior);
-        let evidence_labels: Vec<String> = posterior
-            .evidence_terms
-            .iter()
-            .map(|t| t.feature.clone())
-            .collect();
-
-        results.push(ReplayInferenceResult {
-            pid: proc.pid.0,
-            comm: proc.comm.clone(),
-            cmd: proc.cmd.clone(),
-            state: proc.state.to_string(),
-            posterior: posterior.posterior,
-            classification,
-            recommended_action: decision.0,
-            expected_loss: decision.1,
-            evidence_terms: evidence_labels,
-        });
+    for proc in snapshot.processes.iter().filter(|p| filter.selects(p)) {
+        let result = replay_process(snapshot, proc, priors, policy, &feasibility)?;
+        if filter.keeps(&result) {
+            results.push(result);
+        }
     }
 
     Ok(results)
 }
 
+/// Run a single snapshot process through evidence, posterior, and decision.
+fn replay_process(
+    snapshot: &ReplaySnapshot,
+    proc: &ProcessRecord,
+    priors: &Priors,
+    policy: &Policy,
+    feasibility: &ActionFeasibility,
+) -> Result<ReplayInferenceResult, ReplayError> {
+    let deep = snapshot.deep_signals.get(&proc.pid.0);
+
+    // Build evidence from the process record + optional deep signals
+    let evidence = build_evidence(proc, deep);
+
+    // Compute posterior
+    let posterior = compute_posterior(priors, &evidence).map_err(|e| ReplayError::Inference {
+        pid: proc.pid.0,
+        message: e.to_string(),
+    })?;
+
+    // Determine action via myopic policy
+    let decision =
+        decide_from_belief_for_replay(&posterior.posterior, &policy.loss_matrix, feasibility);
+
+    let classification = classify(&posterior.posterior);
+    let evidence_labels: Vec<String> = posterior
+        .evidence_terms
+        .iter()
+        .map(|t| t.feature.clone())
+        .collect();
+
+    Ok(ReplayInferenceResult {
+        pid: proc.pid.0,
+        start_id: proc.start_id.0.clone(),
+        comm: proc.comm.clone(),
+        cmd: proc.cmd.clone(),
+        state: proc.state.to_string(),
+        posterior: posterior.posterior,
+        classification,
+        recommended_action: decision.0,
+        expected_loss: decision.1,
+        evidence_terms: evidence_labels,
+    })
+}
+
 /// Build Evidence struct from a ProcessRecord and optional deep signals.
 fn build_evidence(proc: &ProcessRecord, deep: Option<&DeepSignalRecord>) -> Evidence {
     let cpu = if proc.cpu_percent >= 0.0 {
@@ -361,6 +600,10 @@ fn build_evidence(proc: &ProcessRecord, deep: Option<&DeepSignalRecord>) -> Evid
 }
 
 /// Classify a posterior into its highest-probability class label.
+///
+/// Exact ties go to the least destructive class, in the order useful,
+/// useful_bad, abandoned, zombie, so a tie never labels a process as more
+/// killable than the evidence supports.
 fn classify(posterior: &ClassScores) -> String {
     let scores = [
         ("useful", posterior.useful),
@@ -369,11 +612,16 @@ fn classify(posterior: &ClassScores) -> String {
         ("zombie", posterior.zombie),
     ];
 
+    let best = scores
+        .iter()
+        .map(|(_, p)| *p)
+        .filter(|p| !p.is_nan())
+        .fold(f64::NEG_INFINITY, f64::max);
     scores
         .iter()
-        .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
-        .map(|(name, _)| name.to_string())
-        .unwrap_or_else(|| "unknown".to_string())
+        .find(|(_, p)| *p == best)
+        .map_or("unknown", |(name, _)| *name)
+        .to_string()
 }
 
 /// Determine optimal action from posterior using the myopic policy.
@@ -436,6 +684,61 @@ mod tests {
         assert_eq!(loaded.name, "test-snapshot");
     }
 
+    const V1_FIXTURE: &str = include_str!("../../tests/fixtures/replay_snapshot_v1.json");
+
+    #[test]
+    fn test_load_v1_snapshot_migrates() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("v1.json");
+        std::fs::write(&path, V1_FIXTURE).unwrap();
+
+        let snapshot = load_snapshot(&path).unwrap();
+        assert_eq!(
+            snapshot.scan_metadata.schema_version,
+            REPLAY_METADATA_VERSION
+        );
+        assert_eq!(snapshot.name, "v1-fixture");
+        assert_eq!(snapshot.processes.len(), 2);
+        assert_eq!(snapshot.processes[1].state, ProcessState::Zombie);
+        assert_eq!(snapshot.deep_signals[&4242].io_active, Some(true));
+
+        // Re-saving writes the current version.
+        snapshot.save(&path).unwrap();
+        let reloaded = load_snapshot(&path).unwrap();
+        assert_eq!(
+            reloaded.scan_metadata.schema_version,
+            REPLAY_METADATA_VERSION
+        );
+    }
+
+    #[test]
+    fn test_every_older_metadata_version_has_a_migration() {
+        for version in 1..REPLAY_METADATA_VERSION {
+            assert!(
+                METADATA_MIGRATIONS.iter().any(|(from, _)| *from == version),
+                "no migration from v{version}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_load_future_version_rejected() {
+        let mut value: serde_json::Value = serde_json::from_str(V1_FIXTURE).unwrap();
+        value["scan_metadata"]["schema_version"] = (REPLAY_METADATA_VERSION + 1).into();
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("future.json");
+        std::fs::write(&path, value.to_string()).unwrap();
+
+        match load_snapshot(&path).unwrap_err() {
+            ReplayError::UnsupportedVersion { found, supported } => {
+                assert_eq!(found, REPLAY_METADATA_VERSION + 1);
+                assert_eq!(supported, REPLAY_METADATA_VERSION);
+            }
+            other => panic!("unexpected error: {other}"),
+        }
+    }
+
     #[test]
     fn test_record_empty_scan_fails() {
         let scan = ScanResult {
@@ -533,6 +836,93 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_replay_filtered_by_pid_matches_full_replay() {
+        let scan = MockScanBuilder::new()
+            .with_zombie(100)
+            .with_orphan(200, "node")
+            .with_orphan(300, "python3")
+            .build();
+        let snapshot = record_snapshot(&scan, Some("filter-pid")).unwrap();
+        let priors = Priors::default();
+        let policy = Policy::default();
+
+        let full = replay_inference(&snapshot, &priors, &policy).unwrap();
+        let filtered =
+            replay_inference_filtered(&snapshot, &priors, &policy, &ReplayFilter::pids([200]))
+                .unwrap();
+
+        assert_eq!(filtered.len(), 1);
+        let expected = full.iter().find(|r| r.pid == 200).unwrap();
+        assert_eq!(filtered[0].pid, 200);
+        assert_eq!(filtered[0].classification, expected.classification);
+        assert_eq!(filtered[0].recommended_action, expected.recommended_action);
+        assert!((filtered[0].expected_loss - expected.expected_loss).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_replay_filtered_uses_options_seed() {
+        let scan = MockScanBuilder::new()
+            .with_zombie(100)
+            .with_orphan(200, "node")
+            .with_orphan(300, "python3")
+            .build();
+        let snapshot = record_snapshot(&scan, Some("filter-seed")).unwrap();
+        let priors = Priors::default();
+        let policy = Policy::default();
+        let options = ReplayOptions::with_seed(42);
+
+        let full = replay_inference_with_options(&snapshot, &priors, &policy, &options).unwrap();
+        let filtered = replay_inference_filtered_with_options(
+            &snapshot,
+            &priors,
+            &policy,
+            &ReplayFilter::pids([200, 300]),
+            &options,
+        )
+        .unwrap();
+
+        let expected: Vec<_> = full.into_iter().filter(|r| r.pid != 100).collect();
+        assert_eq!(filtered, expected);
+    }
+
+    #[test]
+    fn test_replay_filtered_by_command_and_classification() {
+        let scan = MockScanBuilder::new()
+            .with_zombie(100)
+            .with_orphan(200, "node")
+            .with_orphan(300, "python3")
+            .build();
+        let snapshot = record_snapshot(&scan, Some("filter-cmd")).unwrap();
+        let priors = Priors::default();
+        let policy = Policy::default();
+
+        let by_cmd = ReplayFilter::default().with_command("^python").unwrap();
+        let results = replay_inference_filtered(&snapshot, &priors, &policy, &by_cmd).unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].pid, 300);
+
+        let full = replay_inference(&snapshot, &priors, &policy).unwrap();
+        let label = full[0].classification.clone();
+        let by_class = ReplayFilter::default().with_classification(label.clone());
+        let results = replay_inference_filtered(&snapshot, &priors, &policy, &by_class).unwrap();
+        let expected = full.iter().filter(|r| r.classification == label).count();
+        assert_eq!(results.len(), expected);
+        assert!(results.iter().all(|r| r.classification == label));
+
+        let none = ReplayFilter::pids([100]).with_classification("no-such-class");
+        assert!(
+            replay_inference_filtered(&snapshot, &priors, &policy, &none)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_replay_filter_invalid_regex() {
+        assert!(ReplayFilter::default().with_command("(unclosed").is_err());
+    }
+
     #[test]
     fn test_build_evidence_with_deep_signals() {
         let proc = MockProcessBuilder::new()
@@ -555,24 +945,87 @@ mod tests {
 
     #[test]
     fn test_classify_labels() {
+        let useful = ClassScores {
+            useful: 0.8,
+            useful_bad: 0.1,
+            abandoned: 0.05,
+            zombie: 0.05,
+        };
+        assert_eq!(classify(&useful), "useful");
+        let zombie = ClassScores {
+            useful: 0.05,
+            useful_bad: 0.05,
+            abandoned: 0.1,
+            zombie: 0.8,
+        };
+        assert_eq!(classify(&zombie), "zombie");
+    }
+
+    #[test]
+    fn test_classify_tie_prefers_least_destructive_class() {
+        let useful_tie = ClassScores {
+            useful: 0.4,
+            useful_bad: 0.1,
+            abandoned: 0.4,
+            zombie: 0.1,
+        };
+        assert_eq!(classify(&useful_tie), "useful");
+        let abandoned_tie = ClassScores {
+            useful: 0.1,
+            useful_bad: 0.1,
+            abandoned: 0.4,
+            zombie: 0.4,
+        };
+        assert_eq!(classify(&abandoned_tie), "abandoned");
+        let all_tied = ClassScores {
+            useful: 0.25,
+            useful_bad: 0.25,
+            abandoned: 0.25,
+            zombie: 0.25,
+        };
+        assert_eq!(classify(&all_tied), "useful");
+        let unknown = ClassScores {
+            useful: f64::NAN,
+            useful_bad: f64::NAN,
+            abandoned: f64::NAN,
+            zombie: f64::NAN,
+        };
+        assert_eq!(classify(&unknown), "unknown");
+    }
+
+    #[test]
+    fn test_replay_with_fixed_seed_is_identical() {
+        let scan = MockScanBuilder::new()
+            .with_zombie(100)
+            .with_orphan(200, "node")
+            .with_orphan(300, "python3")
+            .build();
+        let mut snapshot = record_snapshot(&scan, Some("seeded")).unwrap();
         assert_eq!(
-            classify(&ClassScores {
-                useful: 0.8,
-                useful_bad: 0.1,
-                abandoned: 0.05,
-                zombie: 0.05,
-            }),
-            "useful"
+            snapshot.scan_metadata.replay_seed,
+            Some(DEFAULT_REPLAY_SEED)
         );
+        let priors = Priors::default();
+        let policy = Policy::default();
+        let options = ReplayOptions::with_seed(42);
+
+        let first = replay_inference_with_options(&snapshot, &priors, &policy, &options).unwrap();
+        let second = replay_inference_with_options(&snapshot, &priors, &policy, &options).unwrap();
+        assert_eq!(first, second);
         assert_eq!(
-            classify(&ClassScores {
-                useful: 0.05,
-                useful_bad: 0.05,
-                abandoned: 0.1,
-                zombie: 0.8,
-            }),
-            "zombie"
+            serde_json::to_string(&first).unwrap(),
+            serde_json::to_string(&second).unwrap()
         );
+
+        // A seed recorded in the snapshot is used when the options have none
+        // and survives a save/load round trip.
+        snapshot.set_replay_seed(42);
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("seeded.json");
+        snapshot.save(&path).unwrap();
+        let loaded = load_snapshot(&path).unwrap();
+        assert_eq!(ReplayOptions::default().resolve_seed(&loaded), 42);
+        assert_eq!(replay_inference(&loaded, &priors, &policy).unwrap(), first);
     }
 
     #[test]
@@ -19,6 +19,9 @@
 //!
 //! # Generate all schemas
 //! pt schema --all
+//!
+//! # Export the versioned output schemas for validation
+//! pt schema dump
 //! ```
 
 use schemars::schema_for;
@@ -36,12 +39,26 @@ pub use crate::decision::expected_loss::{
     Action, ActionFeasibility, DecisionOutcome, DecisionRationale, DisabledAction, ExpectedLoss,
     SprtBoundary,
 };
+pub use crate::decision::voi::{ProbeType, ProbeVoi, VoiAnalysis};
 pub use crate::plan::{
     ActionConfidence, ActionHook, ActionRationale, ActionRouting, ActionTimeouts,
     DStateDiagnostics, GatesSummary, Plan, PlanAction, PreCheck,
 };
+pub use pt_common::SCHEMA_VERSION;
 pub use pt_common::{IdentityQuality, ProcessId, ProcessIdentity, SessionId, StartId};
 
+/// Keyword carrying [`SCHEMA_VERSION`] in every exported schema.
+pub const SCHEMA_VERSION_KEY: &str = "x-schema-version";
+
+/// Top-level output types exported by [`export_schemas`].
+pub const EXPORTED_SCHEMAS: &[&str] = &[
+    "ScanResult",
+    "Plan",
+    "DecisionOutcome",
+    "DroOutcome",
+    "VoiAnalysis",
+];
+
 /// Available schema types with their descriptions.
 pub fn available_schemas() -> Vec<(&'static str, &'static str)> {
     vec![
@@ -84,6 +101,10 @@ pub fn available_schemas() -> Vec<(&'static str, &'static str)> {
         ("RiskSensitiveOutcome", "Risk-sensitive decision outcome"),
         ("DroLoss", "DRO computation result"),
         ("DroOutcome", "Distributionally robust optimization outcome"),
+        // Value of information types
+        ("ProbeType", "Probe that can gather additional evidence"),
+        ("ProbeVoi", "Value of information for a single probe"),
+        ("VoiAnalysis", "Complete value-of-information analysis"),
         // Causal intervention types
         (
             "ProcessClass",
@@ -144,6 +165,10 @@ pub fn generate_schema(type_name: &str) -> Option<Value> {
         "RiskSensitiveOutcome" => schema_for!(RiskSensitiveOutcome),
         "DroLoss" => schema_for!(DroLoss),
         "DroOutcome" => schema_for!(DroOutcome),
+        // Value of information types
+        "ProbeType" => schema_for!(ProbeType),
+        "ProbeVoi" => schema_for!(ProbeVoi),
+        "VoiAnalysis" => schema_for!(VoiAnalysis),
         // Causal intervention types
         "ProcessClass" => schema_for!(ProcessClass),
         "RecoveryExpectation" => schema_for!(RecoveryExpectation),
@@ -177,6 +202,24 @@ pub fn generate_all_schemas() -> BTreeMap<String, Value> {
     schemas
 }
 
+/// Schemas for the public output types in [`EXPORTED_SCHEMAS`], each pinned
+/// to [`SCHEMA_VERSION`] under [`SCHEMA_VERSION_KEY`].
+pub fn export_schemas() -> BTreeMap<&'static str, Value> {
+    EXPORTED_SCHEMAS
+        .iter()
+        .filter_map(|&name| {
+            let mut schema = generate_schema(name)?;
+            if let Value::Object(map) = &mut schema {
+                map.insert(
+                    SCHEMA_VERSION_KEY.to_string(),
+                    Value::String(SCHEMA_VERSION.to_string()),
+                );
+            }
+            Some((name, schema))
+        })
+        .collect()
+}
+
 /// Schema output format.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SchemaFormat {
@@ -236,6 +279,54 @@ mod tests {
         assert!(all.contains_key("ProcessRecord"));
     }
 
+    #[test]
+    fn test_export_schemas_are_versioned_json_schemas() {
+        let exported = export_schemas();
+        assert_eq!(
+            exported.keys().copied().collect::<Vec<_>>(),
+            vec![
+                "DecisionOutcome",
+                "DroOutcome",
+                "Plan",
+                "ScanResult",
+                "VoiAnalysis"
+            ]
+        );
+
+        for (name, schema) in &exported {
+            let object = schema.as_object().expect("schema is an object");
+            assert_eq!(
+                object.get(SCHEMA_VERSION_KEY).and_then(Value::as_str),
+                Some(SCHEMA_VERSION),
+                "{} is not pinned to the schema version",
+                name
+            );
+            assert!(
+                object
+                    .get("$schema")
+                    .and_then(Value::as_str)
+                    .is_some_and(|uri| uri.starts_with("https://json-schema.org/")),
+                "{} lacks a JSON Schema dialect",
+                name
+            );
+            assert_eq!(object.get("title").and_then(Value::as_str), Some(*name));
+            assert_eq!(object.get("type").and_then(Value::as_str), Some("object"));
+
+            // Every local reference resolves to a definition in the schema.
+            let text = serde_json::to_string(schema).unwrap();
+            for reference in text.split("\"$ref\":\"").skip(1) {
+                let pointer = reference.split('"').next().unwrap();
+                let pointer = pointer.strip_prefix('#').expect("local reference");
+                assert!(
+                    schema.pointer(pointer).is_some(),
+                    "{} has dangling $ref #{}",
+                    name,
+                    pointer
+                );
+            }
+        }
+    }
+
     #[test]
     fn test_format_schema() {
         let schema = generate_schema("Action").unwrap();
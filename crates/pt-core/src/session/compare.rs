@@ -29,7 +29,9 @@ pub struct ComparisonReport {
 /// Per-class process count comparison.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ClassDistributionComparison {
+    #[serde(serialize_with = "pt_common::serialize_sorted")]
     pub old_counts: HashMap<String, usize>,
+    #[serde(serialize_with = "pt_common::serialize_sorted")]
     pub new_counts: HashMap<String, usize>,
     pub changes: Vec<ClassChange>,
 }
@@ -47,7 +49,9 @@ pub struct ClassChange {
 /// Per-action recommendation count comparison.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ActionDistributionComparison {
+    #[serde(serialize_with = "pt_common::serialize_sorted")]
     pub old_counts: HashMap<String, usize>,
+    #[serde(serialize_with = "pt_common::serialize_sorted")]
     pub new_counts: HashMap<String, usize>,
     pub changes: Vec<ActionChange>,
 }
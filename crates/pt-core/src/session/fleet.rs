@@ -47,8 +47,10 @@ pub struct HostEntry {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HostSummary {
     /// Classification distribution: class_name → count.
+    #[serde(serialize_with = "pt_common::serialize_sorted")]
     pub class_counts: HashMap<String, u32>,
     /// Action distribution: action_name → count.
+    #[serde(serialize_with = "pt_common::serialize_sorted")]
     pub action_counts: HashMap<String, u32>,
     /// Mean posterior score for candidates on this host.
     pub mean_candidate_score: f64,
@@ -63,8 +65,10 @@ pub struct FleetAggregate {
     pub total_processes: u32,
     pub total_candidates: u32,
     /// Merged classification counts across all hosts.
+    #[serde(serialize_with = "pt_common::serialize_sorted")]
     pub class_counts: HashMap<String, u32>,
     /// Merged action counts across all hosts.
+    #[serde(serialize_with = "pt_common::serialize_sorted")]
     pub action_counts: HashMap<String, u32>,
     /// Fleet-wide mean candidate score.
     pub mean_candidate_score: f64,
@@ -99,6 +103,7 @@ pub struct SafetyBudget {
     /// Alpha remaining.
     pub alpha_remaining: f64,
     /// Per-host alpha allocations.
+    #[serde(serialize_with = "pt_common::serialize_sorted")]
     pub host_allocations: HashMap<String, f64>,
     /// Pooled fleet-wide FDR status for kill recommendations.
     pub pooled_fdr: PooledFdrStatus,
@@ -122,8 +127,10 @@ pub struct PooledFdrStatus {
     /// BY correction factor when applicable.
     pub correction_factor: Option<f64>,
     /// Approved kill counts per host.
+    #[serde(serialize_with = "pt_common::serialize_sorted")]
     pub selected_by_host: HashMap<String, u32>,
     /// Rejected kill counts per host.
+    #[serde(serialize_with = "pt_common::serialize_sorted")]
     pub rejected_by_host: HashMap<String, u32>,
 }
 
@@ -246,6 +246,7 @@ fn action_to_recommendation(action: Action) -> &'static str {
         Action::Freeze => "freeze",
         Action::Unfreeze => "unfreeze",
         Action::Throttle => "throttle",
+        Action::Unthrottle => "unthrottle",
         Action::Quarantine => "quarantine",
         Action::Unquarantine => "unquarantine",
         Action::Restart => "restart",
@@ -493,17 +494,17 @@ mod tests {
         why: &str,
     ) -> EvidenceLedger {
         EvidenceLedger {
-            posterior: PosteriorResult {
-                posterior: ClassScores {
+            posterior: PosteriorResult::new(
+                ClassScores {
                     useful: 0.1,
                     useful_bad: 0.1,
                     abandoned: 0.7,
                     zombie: 0.1,
                 },
-                log_posterior: ClassScores::default(),
-                log_odds_abandoned_useful: 2.0,
-                evidence_terms: vec![],
-            },
+                ClassScores::default(),
+                2.0,
+                vec![],
+            ),
             classification: Classification::Abandoned,
             confidence,
             bayes_factors: factors,
@@ -541,6 +542,7 @@ mod tests {
                 memory_mb: None,
                 has_known_signature: None,
                 category: None,
+                protection: None,
             },
             risk_sensitive: None,
             dro: None,
@@ -0,0 +1,375 @@
+//! Graceful shutdown on SIGINT/SIGTERM.
+//!
+//! The first termination signal only sets a flag. Loops that poll
+//! [`ShutdownCoordinator::should_stop`] stop taking new work, and
+//! [`ShutdownCoordinator::shutdown`] then runs the registered finalizers
+//! (flush telemetry writers, save pattern libraries) exactly once before the
+//! command returns normally. A second signal exits immediately with
+//! [`FORCED_EXIT_CODE`] without running anything.
+//!
+//! Handlers are only installed for the lifetime of the [`SignalGuard`]
+//! returned by [`install_signal_handlers`], so commands that never poll the
+//! flag keep the default die-on-signal behavior.
+
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Mutex;
+
+use pt_telemetry::BatchedWriter;
+use serde::Serialize;
+use tracing::warn;
+
+use crate::supervision::pattern_persistence::DEFAULT_LOCK_TIMEOUT;
+use crate::supervision::{LearningError, PatternLearner, PatternLibrary, PendingDecision};
+
+/// Exit status used when a second signal forces an immediate exit.
+pub const FORCED_EXIT_CODE: i32 = 130;
+
+/// Count of termination signals received.
+#[derive(Debug)]
+pub struct ShutdownSignal {
+    signals: AtomicU32,
+}
+
+impl ShutdownSignal {
+    pub const fn new() -> Self {
+        Self {
+            signals: AtomicU32::new(0),
+        }
+    }
+
+    /// Record a termination request; returns how many have been received.
+    ///
+    /// Async-signal-safe.
+    pub fn notify(&self) -> u32 {
+        self.signals.fetch_add(1, Ordering::SeqCst) + 1
+    }
+
+    pub fn is_requested(&self) -> bool {
+        self.signals.load(Ordering::SeqCst) > 0
+    }
+}
+
+impl Default for ShutdownSignal {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Process-wide signal state written by the handlers.
+pub static SHUTDOWN_SIGNAL: ShutdownSignal = ShutdownSignal::new();
+
+/// Restores the previous SIGINT/SIGTERM dispositions when dropped.
+#[cfg(unix)]
+pub struct SignalGuard {
+    previous: Vec<(libc::c_int, libc::sigaction)>,
+}
+
+#[cfg(not(unix))]
+pub struct SignalGuard;
+
+#[cfg(unix)]
+extern "C" fn handle_termination(_signal: libc::c_int) {
+    if SHUTDOWN_SIGNAL.notify() >= 2 {
+        unsafe { libc::_exit(FORCED_EXIT_CODE) };
+    }
+}
+
+/// Route SIGINT and SIGTERM to [`SHUTDOWN_SIGNAL`] until the guard drops.
+#[cfg(unix)]
+pub fn install_signal_handlers() -> SignalGuard {
+    let mut previous = Vec::new();
+    unsafe {
+        let mut action: libc::sigaction = std::mem::zeroed();
+        action.sa_sigaction = handle_termination as *const () as usize;
+        action.sa_flags = libc::SA_RESTART;
+        libc::sigemptyset(&mut action.sa_mask);
+        for signal in [libc::SIGINT, libc::SIGTERM] {
+            let mut old: libc::sigaction = std::mem::zeroed();
+            if libc::sigaction(signal, &action, &mut old) == 0 {
+                previous.push((signal, old));
+            }
+        }
+    }
+    SignalGuard { previous }
+}
+
+#[cfg(not(unix))]
+pub fn install_signal_handlers() -> SignalGuard {
+    SignalGuard
+}
+
+#[cfg(unix)]
+impl Drop for SignalGuard {
+    fn drop(&mut self) {
+        for (signal, old) in &self.previous {
+            unsafe {
+                libc::sigaction(*signal, old, std::ptr::null_mut());
+            }
+        }
+    }
+}
+
+type Finalizer<'a> = Box<dyn FnOnce() -> Result<(), String> + 'a>;
+
+/// Outcome of [`ShutdownCoordinator::shutdown`].
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ShutdownReport {
+    /// Whether a termination signal had been received.
+    pub interrupted: bool,
+    /// Finalizers that succeeded, in registration order.
+    pub completed: Vec<String>,
+    /// Finalizers that failed, with their error.
+    pub failed: Vec<(String, String)>,
+}
+
+/// Runs registered finalizers once when a command winds down.
+///
+/// Finalizers run on [`Self::shutdown`], or on drop if it was never called,
+/// so early returns still flush and save.
+pub struct ShutdownCoordinator<'a> {
+    signal: &'a ShutdownSignal,
+    finalizers: Vec<(String, Finalizer<'a>)>,
+}
+
+impl<'a> ShutdownCoordinator<'a> {
+    pub fn new(signal: &'a ShutdownSignal) -> Self {
+        Self {
+            signal,
+            finalizers: Vec::new(),
+        }
+    }
+
+    /// Run `finalizer` on shutdown.
+    pub fn register(
+        &mut self,
+        name: impl Into<String>,
+        finalizer: impl FnOnce() -> Result<(), String> + 'a,
+    ) {
+        self.finalizers.push((name.into(), Box::new(finalizer)));
+    }
+
+    /// Flush `writer`'s buffered batches on shutdown.
+    pub fn register_writer(&mut self, name: impl Into<String>, writer: &'a Mutex<BatchedWriter>) {
+        self.register(name, move || {
+            writer
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .flush()
+                .map_err(|e| e.to_string())
+        });
+    }
+
+    /// Learn from `decisions` and save `library` on shutdown.
+    ///
+    /// The decisions are replayed inside [`PatternLibrary::with_lock`], which
+    /// re-reads the library first, so patterns written by other processes in
+    /// the meantime are kept rather than overwritten.
+    pub fn register_pattern_library(
+        &mut self,
+        library: &'a Mutex<PatternLibrary>,
+        decisions: &'a Mutex<Vec<PendingDecision>>,
+    ) {
+        self.register("pattern library", move || {
+            let decisions = std::mem::take(
+                &mut *decisions
+                    .lock()
+                    .unwrap_or_else(|poisoned| poisoned.into_inner()),
+            );
+            if decisions.is_empty() {
+                return Ok(());
+            }
+            let mut library = library
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+            library
+                .with_lock(DEFAULT_LOCK_TIMEOUT, |library| {
+                    let mut learner = PatternLearner::new(library);
+                    for decision in &decisions {
+                        match learner.record_decision(
+                            &decision.process_name,
+                            &decision.cmdline,
+                            decision.killed,
+                        ) {
+                            Ok(_) => {}
+                            Err(LearningError::Persistence(e)) => return Err(e),
+                            Err(e) => warn!(
+                                process = %decision.process_name,
+                                error = %e,
+                                "skipping unlearnable decision"
+                            ),
+                        }
+                    }
+                    Ok(())
+                })
+                .map_err(|e| e.to_string())
+        });
+    }
+
+    /// Whether a termination signal arrived; callers should stop taking work.
+    pub fn should_stop(&self) -> bool {
+        self.signal.is_requested()
+    }
+
+    /// Run every pending finalizer once.
+    ///
+    /// Failures are logged and reported but do not stop later finalizers.
+    /// Calling this again runs nothing.
+    pub fn shutdown(&mut self) -> ShutdownReport {
+        let mut report = ShutdownReport {
+            interrupted: self.signal.is_requested(),
+            ..Default::default()
+        };
+        for (name, finalizer) in self.finalizers.drain(..) {
+            match finalizer() {
+                Ok(()) => report.completed.push(name),
+                Err(error) => {
+                    warn!(finalizer = %name, error = %error, "shutdown finalizer failed");
+                    report.failed.push((name, error));
+                }
+            }
+        }
+        report
+    }
+}
+
+impl Drop for ShutdownCoordinator<'_> {
+    fn drop(&mut self) {
+        if !self.finalizers.is_empty() {
+            self.shutdown();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::supervision::{PatternSource, SupervisorCategory, SupervisorSignature};
+    use pt_telemetry::{audit_schema, build_audit_batch, AuditRow, TableName, WriterConfig};
+    use std::cell::Cell;
+    use std::sync::Arc;
+
+    #[test]
+    fn signal_stops_work_and_finalizers_run_once() {
+        let signal = ShutdownSignal::new();
+        let flushes = Cell::new(0);
+        let saves = Cell::new(0);
+        let mut coordinator = ShutdownCoordinator::new(&signal);
+        coordinator.register("telemetry", || {
+            flushes.set(flushes.get() + 1);
+            Ok(())
+        });
+        coordinator.register("patterns", || {
+            saves.set(saves.get() + 1);
+            Err("disk full".to_string())
+        });
+
+        let mut processed = 0;
+        for item in 0..10 {
+            if coordinator.should_stop() {
+                break;
+            }
+            processed += 1;
+            if item == 3 {
+                // What the handler does on the first SIGTERM.
+                assert_eq!(signal.notify(), 1);
+            }
+        }
+        assert_eq!(processed, 4);
+
+        let report = coordinator.shutdown();
+        assert!(report.interrupted);
+        assert_eq!(report.completed, vec!["telemetry"]);
+        assert_eq!(report.failed.len(), 1);
+
+        // Neither a second shutdown nor the drop reruns anything.
+        assert!(coordinator.shutdown().completed.is_empty());
+        drop(coordinator);
+        assert_eq!(flushes.get(), 1);
+        assert_eq!(saves.get(), 1);
+    }
+
+    #[test]
+    fn drop_runs_pending_finalizers() {
+        let signal = ShutdownSignal::new();
+        let runs = Cell::new(0);
+        {
+            let mut coordinator = ShutdownCoordinator::new(&signal);
+            coordinator.register("once", || {
+                runs.set(runs.get() + 1);
+                Ok(())
+            });
+        }
+        assert_eq!(runs.get(), 1);
+    }
+
+    #[test]
+    fn flushes_writer_and_learns_under_library_lock() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = WriterConfig::new(
+            dir.path().join("telemetry"),
+            "pt-20261018-000000-test".to_string(),
+            "host".to_string(),
+        )
+        .with_batch_size(1_000);
+        let row = AuditRow {
+            audit_ts_micros: 0,
+            event_type: "test".to_string(),
+            severity: "info".to_string(),
+            actor: "test".to_string(),
+            target_pid: None,
+            target_start_id: None,
+            message: "buffered".to_string(),
+            details_json: None,
+        };
+        let mut writer =
+            BatchedWriter::new(TableName::Audit, Arc::new(audit_schema()), config.clone());
+        writer
+            .write(build_audit_batch(&config, &[row]).unwrap())
+            .unwrap();
+        assert!(writer.output_path().is_none(), "still only buffered");
+        let writer = Mutex::new(writer);
+
+        let config_dir = dir.path().join("config");
+        let library = Mutex::new(PatternLibrary::new(&config_dir));
+        let decisions = Mutex::new(vec![
+            PendingDecision {
+                process_name: "node".to_string(),
+                cmdline: "node --watch".to_string(),
+                killed: true,
+            };
+            3
+        ]);
+
+        // Another process saves a pattern after this library was opened.
+        let mut other = PatternLibrary::new(&config_dir);
+        other
+            .add_custom(
+                SupervisorSignature::new("my_worker", SupervisorCategory::Other)
+                    .with_process_patterns(vec![r"^my_worker$"]),
+            )
+            .unwrap();
+        other.save().unwrap();
+
+        let signal = ShutdownSignal::new();
+        signal.notify();
+        let mut coordinator = ShutdownCoordinator::new(&signal);
+        coordinator.register_writer("audit", &writer);
+        coordinator.register_pattern_library(&library, &decisions);
+        let report = coordinator.shutdown();
+        assert_eq!(report.completed, vec!["audit", "pattern library"]);
+        assert!(report.failed.is_empty());
+        drop(coordinator);
+
+        assert!(writer.lock().unwrap().output_path().is_some());
+        assert!(decisions.lock().unwrap().is_empty());
+
+        let mut reloaded = PatternLibrary::new(&config_dir);
+        reloaded.load().unwrap();
+        assert!(reloaded.get_pattern("my_worker").is_some(), "not clobbered");
+        assert!(reloaded
+            .export(&[PatternSource::Learned])
+            .patterns
+            .iter()
+            .any(|p| p.signature.name.starts_with("learned_node_")));
+    }
+}
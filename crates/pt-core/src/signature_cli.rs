@@ -1231,6 +1231,7 @@ fn run_signature_stats(format: &OutputFormat, min_matches: u32, sort_by: &str) -
                         "accept_count": s.accept_count,
                         "reject_count": s.reject_count,
                         "acceptance_rate": s.acceptance_rate(),
+                        "acceptance_ci95": s.acceptance_interval(0.95),
                         "computed_confidence": s.computed_confidence,
                         "first_seen": s.first_seen,
                         "last_match": s.last_match,
@@ -1262,16 +1263,13 @@ fn run_signature_stats(format: &OutputFormat, min_matches: u32, sort_by: &str) -
                 println!("# Signature Statistics ({} patterns)", stat_entries.len());
                 println!();
                 println!(
-                    "{:30} {:>8} {:>8} {:>8} {:>8}",
-                    "NAME", "MATCHES", "ACCEPTS", "REJECTS", "RATE"
+                    "{:30} {:>8} {:>8} {:>8} {:>20}",
+                    "NAME", "MATCHES", "ACCEPTS", "REJECTS", "RATE (95% CI)"
                 );
-                println!("{}", "-".repeat(66));
+                println!("{}", "-".repeat(78));
 
                 for (name, s) in &stat_entries {
-                    let rate: String = s
-                        .acceptance_rate()
-                        .map(|r| format!("{:.1}%", r * 100.0))
-                        .unwrap_or_else(|| "-".to_string());
+                    let rate = s.acceptance_summary(Some(0.95));
 
                     // Truncate name if too long
                     let display_name: String = if name.len() > 30 {
@@ -1281,7 +1279,7 @@ fn run_signature_stats(format: &OutputFormat, min_matches: u32, sort_by: &str) -
                     };
 
                     println!(
-                        "{:30} {:>8} {:>8} {:>8} {:>8}",
+                        "{:30} {:>8} {:>8} {:>8} {:>20}",
                         display_name, s.match_count, s.accept_count, s.reject_count, rate
                     );
                 }
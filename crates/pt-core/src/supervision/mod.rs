@@ -90,12 +90,13 @@ pub use orphan::{
 };
 pub use pattern_learning::{
     CommandNormalizer, DecisionAction, LearningError, PatternCandidate, PatternLearner,
-    PatternObservation, SpecificityLevel,
+    PatternObservation, PendingDecision, SpecificityLevel,
 };
 pub use pattern_persistence::{
     migrate_schema, AllPatternStats, ConfidenceSnapshot, ConflictResolution, DisabledPatterns,
-    ImportConflict, ImportResult, PatternLibrary, PatternLifecycle, PatternSource, PatternStats,
-    PersistedPattern, PersistedSchema, PersistenceError, SchemaMetadata,
+    ImportConflict, ImportResult, OriginCounts, PatternLibrary, PatternLifecycle,
+    PatternSearchFilter, PatternSource, PatternStats, PersistedPattern, PersistedSchema,
+    PersistenceError, SchemaMetadata,
 };
 pub use session::{
     check_session_protection, is_in_protected_session, ScreenInfo, SessionAnalyzer, SessionConfig,
@@ -393,6 +393,14 @@ pub enum DecisionAction {
     Spare,
 }
 
+/// A kill/spare decision buffered until it can be fed to a [`PatternLearner`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PendingDecision {
+    pub process_name: String,
+    pub cmdline: String,
+    pub killed: bool,
+}
+
 /// Pattern learner that integrates with PatternLibrary.
 pub struct PatternLearner<'a> {
     library: &'a mut PatternLibrary,
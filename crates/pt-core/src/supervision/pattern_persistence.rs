@@ -35,11 +35,12 @@
 //! ```
 
 use super::signature::{SignatureError, SignatureSchema, SupervisorSignature, SCHEMA_VERSION};
+use pt_math::binomial::binomial_ci_wilson;
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use thiserror::Error;
 
 /// Default configuration directory name.
@@ -63,6 +64,16 @@ const DISABLED_FILE: &str = "disabled.json";
 /// Pattern statistics filename.
 const STATS_FILE: &str = "pattern_stats.json";
 
+/// Advisory lock file serializing library writers.
+const LOCK_FILE: &str = ".library.lock";
+
+/// How often a waiting writer retries the library lock.
+#[cfg(unix)]
+const LOCK_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Default time to wait for the library lock in [`PatternLibrary::with_lock`].
+pub const DEFAULT_LOCK_TIMEOUT: Duration = Duration::from_secs(5);
+
 /// Errors from pattern persistence operations.
 #[derive(Debug, Error)]
 pub enum PersistenceError {
@@ -95,6 +106,9 @@ pub enum PersistenceError {
 
     #[error("Config directory not found and could not be created")]
     ConfigDirNotFound,
+
+    #[error("Pattern library is locked by another writer ({path}); gave up after {timeout:?}")]
+    Locked { path: PathBuf, timeout: Duration },
 }
 
 /// Pattern lifecycle states.
@@ -211,6 +225,18 @@ pub struct PatternStats {
     /// Historical confidence values (for trend analysis).
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub confidence_history: Vec<ConfidenceSnapshot>,
+    /// Counts imported from other libraries, keyed by origin id. They are
+    /// already included in the totals above.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub origins: BTreeMap<String, OriginCounts>,
+}
+
+/// Match counts one origin library contributed to a pattern's stats.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OriginCounts {
+    pub match_count: u32,
+    pub accept_count: u32,
+    pub reject_count: u32,
 }
 
 impl PatternStats {
@@ -255,10 +281,100 @@ impl PatternStats {
         }
     }
 
+    /// Wilson score interval on the acceptance rate at `confidence` (e.g. 0.95).
+    ///
+    /// Unlike [`Self::acceptance_rate`] this is defined with no matches,
+    /// where it spans the whole of `(0.0, 1.0)`.
+    pub fn acceptance_interval(&self, confidence: f64) -> (f64, f64) {
+        binomial_ci_wilson(
+            u64::from(self.accept_count.min(self.match_count)),
+            u64::from(self.match_count),
+            confidence,
+        )
+    }
+
+    /// Acceptance as `accepted/total` for display.
+    ///
+    /// With a `confidence` level the rate is shown as a Wilson interval,
+    /// e.g. `3/4 (30%–95%)`; otherwise as the bare fraction, e.g. `3/4 (75%)`.
+    pub fn acceptance_summary(&self, confidence: Option<f64>) -> String {
+        let counts = format!("{}/{}", self.accept_count, self.match_count);
+        match (confidence, self.acceptance_rate()) {
+            (Some(confidence), _) => {
+                let (lo, hi) = self.acceptance_interval(confidence);
+                format!("{counts} ({:.0}%–{:.0}%)", lo * 100.0, hi * 100.0)
+            }
+            (None, Some(rate)) => format!("{counts} ({:.0}%)", rate * 100.0),
+            (None, None) => counts,
+        }
+    }
+
     /// Get suggested lifecycle based on stats.
     pub fn suggested_lifecycle(&self) -> PatternLifecycle {
         PatternLifecycle::from_stats(self.computed_confidence.unwrap_or(0.0), self.match_count)
     }
+
+    /// Counts recorded by this library itself, excluding imported origins.
+    pub fn local_counts(&self) -> OriginCounts {
+        let imported = self
+            .origins
+            .values()
+            .fold(OriginCounts::default(), |acc, c| OriginCounts {
+                match_count: acc.match_count.saturating_add(c.match_count),
+                accept_count: acc.accept_count.saturating_add(c.accept_count),
+                reject_count: acc.reject_count.saturating_add(c.reject_count),
+            });
+        OriginCounts {
+            match_count: self.match_count.saturating_sub(imported.match_count),
+            accept_count: self.accept_count.saturating_sub(imported.accept_count),
+            reject_count: self.reject_count.saturating_sub(imported.reject_count),
+        }
+    }
+
+    /// Fold in stats exported by library `other_origin` into a library whose
+    /// own origin is `local_origin`.
+    ///
+    /// `other`'s local counts and every origin it imported itself are stored
+    /// per origin, replacing what was previously imported from that origin
+    /// (counts only grow, so the larger one wins). Counts that originated
+    /// here are never re-imported. Merging the same export twice, or stats
+    /// that travelled A→B→A, therefore leaves the totals unchanged. The
+    /// earlier `first_seen` and later `last_match` win, and confidence is
+    /// recomputed from the combined counts.
+    pub fn merge(&mut self, local_origin: &str, other_origin: &str, other: &PatternStats) {
+        let local = self.local_counts();
+        let contributions = std::iter::once((other_origin, other.local_counts()))
+            .chain(other.origins.iter().map(|(id, c)| (id.as_str(), *c)));
+        for (origin, counts) in contributions {
+            if origin == local_origin || counts == OriginCounts::default() {
+                continue;
+            }
+            let entry = self.origins.entry(origin.to_string()).or_default();
+            if counts.match_count >= entry.match_count {
+                *entry = counts;
+            }
+        }
+        self.match_count = local.match_count;
+        self.accept_count = local.accept_count;
+        self.reject_count = local.reject_count;
+        for counts in self.origins.values() {
+            self.match_count = self.match_count.saturating_add(counts.match_count);
+            self.accept_count = self.accept_count.saturating_add(counts.accept_count);
+            self.reject_count = self.reject_count.saturating_add(counts.reject_count);
+        }
+        self.first_seen = match (self.first_seen, other.first_seen) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+        self.last_match = self.last_match.max(other.last_match);
+        self.confidence_history
+            .extend(other.confidence_history.iter().cloned());
+        self.confidence_history
+            .sort_by_key(|s| (s.timestamp, s.match_count));
+        self.confidence_history
+            .dedup_by(|a, b| a.timestamp == b.timestamp && a.match_count == b.match_count);
+        self.update_confidence();
+    }
 }
 
 /// A snapshot of confidence at a point in time.
@@ -298,6 +414,11 @@ pub struct PersistedPattern {
     /// When this pattern was last updated (unix epoch seconds).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub updated_at: Option<u64>,
+
+    /// Usage statistics carried in exports; the library itself keeps stats
+    /// in [`AllPatternStats`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stats: Option<PatternStats>,
 }
 
 fn default_version() -> String {
@@ -319,6 +440,7 @@ impl PersistedPattern {
             version: default_version(),
             created_at: now,
             updated_at: now,
+            stats: None,
         }
     }
 
@@ -634,6 +756,68 @@ pub struct ImportConflict {
     pub imported_confidence: Option<f64>,
 }
 
+/// Restricts [`PatternLibrary::search_with`] results.
+///
+/// Empty source or lifecycle lists match everything.
+#[derive(Debug, Clone, Default)]
+pub struct PatternSearchFilter {
+    /// Only return patterns from these sources.
+    pub sources: Vec<PatternSource>,
+    /// Only return patterns in these lifecycle states.
+    pub lifecycles: Vec<PatternLifecycle>,
+}
+
+impl PatternSearchFilter {
+    /// Also accept patterns from `source`.
+    pub fn with_source(mut self, source: PatternSource) -> Self {
+        self.sources.push(source);
+        self
+    }
+
+    /// Also accept patterns in `lifecycle`.
+    pub fn with_lifecycle(mut self, lifecycle: PatternLifecycle) -> Self {
+        self.lifecycles.push(lifecycle);
+        self
+    }
+
+    fn accepts(&self, pattern: &PersistedPattern) -> bool {
+        (self.sources.is_empty() || self.sources.contains(&pattern.source))
+            && (self.lifecycles.is_empty() || self.lifecycles.contains(&pattern.lifecycle))
+    }
+}
+
+/// Split text into lowercase alphanumeric tokens.
+fn search_tokens(text: &str) -> HashSet<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Relevance of `pattern` to a lowercased query and its tokens.
+///
+/// Each field (name ×3, category ×2, notes ×1) scores 1 for containing the
+/// whole query plus the fraction of query tokens it shares.
+fn search_score(pattern: &PersistedPattern, query: &str, query_tokens: &HashSet<String>) -> f64 {
+    let category = pattern.signature.category.to_string();
+    let fields = [
+        (pattern.signature.name.as_str(), 3.0),
+        (category.as_str(), 2.0),
+        (pattern.signature.notes.as_deref().unwrap_or(""), 1.0),
+    ];
+    fields
+        .iter()
+        .map(|(text, weight)| {
+            let text = text.to_lowercase();
+            let substring = if text.contains(query) { 1.0 } else { 0.0 };
+            let field_tokens = search_tokens(&text);
+            let overlap =
+                query_tokens.intersection(&field_tokens).count() as f64 / query_tokens.len() as f64;
+            weight * (substring + overlap)
+        })
+        .sum()
+}
+
 /// Pattern library manager.
 ///
 /// This struct manages the persistent storage of patterns including:
@@ -661,6 +845,9 @@ pub struct PatternLibrary {
     /// Pattern statistics.
     stats: AllPatternStats,
 
+    /// Id written to exports and used to key stats imported from elsewhere.
+    origin: String,
+
     /// Whether any changes need saving.
     dirty: bool,
 }
@@ -675,10 +862,17 @@ impl PatternLibrary {
             custom: PersistedSchema::new(),
             disabled: DisabledPatterns::default(),
             stats: AllPatternStats::default(),
+            origin: crate::logging::get_host_id(),
             dirty: false,
         }
     }
 
+    /// Override the origin id (the host id by default).
+    pub fn with_origin(mut self, origin: impl Into<String>) -> Self {
+        self.origin = origin.into();
+        self
+    }
+
     /// Create with default config directory (~/.config/process_triage).
     pub fn with_default_config() -> Result<Self, PersistenceError> {
         let config_dir = dirs::config_dir()
@@ -777,6 +971,31 @@ impl PatternLibrary {
         Ok(())
     }
 
+    /// Run a load-mutate-save cycle while holding the library's write lock.
+    ///
+    /// Takes an exclusive advisory lock (`flock` on Unix) on the patterns
+    /// directory, re-reads every file so `f` sees the latest state written by
+    /// any other process, runs `f`, and saves before releasing the lock.
+    /// Concurrent writers therefore serialize instead of overwriting each
+    /// other's changes. Unsaved in-memory changes are discarded by the
+    /// re-read.
+    ///
+    /// Waits at most `timeout` for the lock, then returns
+    /// [`PersistenceError::Locked`]. On non-Unix platforms no lock is taken.
+    pub fn with_lock<T>(
+        &mut self,
+        timeout: Duration,
+        f: impl FnOnce(&mut Self) -> Result<T, PersistenceError>,
+    ) -> Result<T, PersistenceError> {
+        self.ensure_directories()?;
+        let _lock = LibraryLock::acquire(&self.patterns_dir().join(LOCK_FILE), timeout)?;
+
+        self.load()?;
+        let result = f(self)?;
+        self.save()?;
+        Ok(result)
+    }
+
     /// Initialize built-in patterns from defaults.
     ///
     /// This is called during installation or upgrade to write the default
@@ -823,6 +1042,44 @@ impl PatternLibrary {
         patterns
     }
 
+    /// Find patterns whose name, category, or notes match `query`.
+    ///
+    /// Matching is case-insensitive: a field matches if it contains the whole
+    /// query or shares any of its words. Results are ordered by relevance,
+    /// name matches first, then by name. Disabled and removed patterns are
+    /// included. An empty query returns every pattern, sorted by name.
+    pub fn search(&self, query: &str) -> Vec<&PersistedPattern> {
+        self.search_with(query, &PatternSearchFilter::default())
+    }
+
+    /// Like [`Self::search`], keeping only patterns accepted by `filter`.
+    pub fn search_with(&self, query: &str, filter: &PatternSearchFilter) -> Vec<&PersistedPattern> {
+        let query = query.trim().to_lowercase();
+        let query_tokens = search_tokens(&query);
+        let mut scored: Vec<(f64, &PersistedPattern)> = self
+            .custom
+            .patterns
+            .iter()
+            .chain(self.learned.patterns.iter())
+            .chain(self.built_in.patterns.iter())
+            .filter(|p| filter.accepts(p))
+            .filter_map(|p| {
+                if query_tokens.is_empty() {
+                    return Some((0.0, p));
+                }
+                let score = search_score(p, &query, &query_tokens);
+                (score > 0.0).then_some((score, p))
+            })
+            .collect();
+
+        scored.sort_by(|(a_score, a), (b_score, b)| {
+            b_score
+                .total_cmp(a_score)
+                .then_with(|| a.signature.name.cmp(&b.signature.name))
+        });
+        scored.into_iter().map(|(_, p)| p).collect()
+    }
+
     /// Get a pattern by name.
     pub fn get_pattern(&self, name: &str) -> Option<&PersistedPattern> {
         self.custom
@@ -972,7 +1229,10 @@ impl PatternLibrary {
             .all_active_patterns()
             .into_iter()
             .filter(|p| include_sources.contains(&p.source))
-            .cloned()
+            .map(|p| PersistedPattern {
+                stats: self.stats.get(&p.signature.name).cloned(),
+                ..p.clone()
+            })
             .collect();
 
         PersistedSchema {
@@ -981,12 +1241,20 @@ impl PatternLibrary {
             metadata: Some(SchemaMetadata {
                 exported_at: now,
                 description: Some("Exported pattern library".to_string()),
+                source_system: Some(self.origin.clone()),
                 ..Default::default()
             }),
         }
     }
 
     /// Import patterns with conflict resolution.
+    ///
+    /// Stats embedded in imported patterns seed the library's stats for new
+    /// patterns and are merged into the existing stats on
+    /// [`ConflictResolution::Merge`]; other resolutions leave stats alone.
+    /// Imported counts are keyed by the export's `source_system` (or its
+    /// export time when that is missing), so re-importing an export does
+    /// not count it twice; see [`PatternStats::merge`].
     pub fn import(
         &mut self,
         schema: PersistedSchema,
@@ -994,10 +1262,20 @@ impl PatternLibrary {
     ) -> Result<ImportResult, PersistenceError> {
         schema.validate()?;
 
+        let origin = schema
+            .metadata
+            .as_ref()
+            .and_then(|m| {
+                m.source_system
+                    .clone()
+                    .or_else(|| m.exported_at.map(|t| format!("export-{t}")))
+            })
+            .unwrap_or_else(|| "unknown".to_string());
         let mut result = ImportResult::default();
 
         for mut imported_pattern in schema.patterns {
             imported_pattern.source = PatternSource::Imported;
+            let imported_stats = imported_pattern.stats.take();
 
             let existing = self.get_pattern(&imported_pattern.signature.name);
 
@@ -1039,7 +1317,11 @@ impl PatternLibrary {
                             let _ = self.remove_pattern(&name);
                             self.custom.patterns.push(imported_pattern);
                         }
-                        // Stats will accumulate naturally
+                        if let Some(stats) = &imported_stats {
+                            self.stats
+                                .get_or_create(&name)
+                                .merge(&self.origin, &origin, stats);
+                        }
                         result.updated += 1;
                     }
                 }
@@ -1047,6 +1329,11 @@ impl PatternLibrary {
                 result.conflicts.push(conflict);
             } else {
                 // No conflict, just add
+                if let Some(stats) = &imported_stats {
+                    self.stats
+                        .get_or_create(&imported_pattern.signature.name)
+                        .merge(&self.origin, &origin, stats);
+                }
                 self.custom.patterns.push(imported_pattern);
                 result.imported += 1;
             }
@@ -1097,6 +1384,124 @@ impl PatternLibrary {
 
         transitions
     }
+
+    /// Permanently drop `Removed` tombstones last updated more than
+    /// `older_than` ago, returning how many were pruned.
+    ///
+    /// Recent tombstones are kept so sync peers still see the removal.
+    /// Tombstones without any timestamp are kept, since their age is unknown.
+    /// Statistics of pruned patterns are dropped too; built-in patterns are
+    /// never touched.
+    pub fn compact(&mut self, older_than: Duration) -> usize {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        self.compact_at(now.saturating_sub(older_than.as_secs()))
+    }
+
+    /// Garbage-collect `Removed` tombstones older than `older_than_secs`.
+    ///
+    /// Same as [`Self::compact`] with the window given in seconds.
+    pub fn gc(&mut self, older_than_secs: u64) -> usize {
+        self.compact(Duration::from_secs(older_than_secs))
+    }
+
+    /// Drop `Removed` tombstones whose last update is before `cutoff`
+    /// (unix epoch seconds).
+    fn compact_at(&mut self, cutoff: u64) -> usize {
+        let is_stale = |p: &PersistedPattern| {
+            p.lifecycle == PatternLifecycle::Removed
+                && p.updated_at.or(p.created_at).is_some_and(|ts| ts < cutoff)
+        };
+
+        let stale: Vec<String> = self
+            .learned
+            .patterns
+            .iter()
+            .chain(self.custom.patterns.iter())
+            .filter(|p| is_stale(p))
+            .map(|p| p.signature.name.clone())
+            .collect();
+        if stale.is_empty() {
+            return 0;
+        }
+
+        self.learned.patterns.retain(|p| !is_stale(p));
+        self.custom.patterns.retain(|p| !is_stale(p));
+        for name in &stale {
+            // A built-in pattern with the same name keeps its stats.
+            if !self
+                .built_in
+                .patterns
+                .iter()
+                .any(|p| &p.signature.name == name)
+            {
+                self.stats.patterns.remove(name);
+            }
+        }
+        self.dirty = true;
+        stale.len()
+    }
+}
+
+/// Exclusive advisory lock on the library's lock file, released on drop.
+struct LibraryLock {
+    #[cfg_attr(not(unix), allow(dead_code))]
+    file: fs::File,
+}
+
+impl LibraryLock {
+    fn acquire(path: &Path, timeout: Duration) -> Result<Self, PersistenceError> {
+        let file = fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path)?;
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::io::AsRawFd;
+            let deadline = std::time::Instant::now() + timeout;
+            loop {
+                let result =
+                    unsafe { libc::flock(file.as_raw_fd(), libc::LOCK_EX | libc::LOCK_NB) };
+                if result == 0 {
+                    break;
+                }
+                let err = std::io::Error::last_os_error();
+                if err.kind() != std::io::ErrorKind::WouldBlock {
+                    return Err(PersistenceError::Io(err));
+                }
+                if std::time::Instant::now() >= deadline {
+                    return Err(PersistenceError::Locked {
+                        path: path.to_path_buf(),
+                        timeout,
+                    });
+                }
+                std::thread::sleep(LOCK_POLL_INTERVAL);
+            }
+        }
+        #[cfg(not(unix))]
+        let _ = timeout;
+
+        Ok(Self { file })
+    }
+}
+
+impl Drop for LibraryLock {
+    fn drop(&mut self) {
+        #[cfg(unix)]
+        {
+            use std::os::unix::io::AsRawFd;
+            // The lock file itself is left in place; removing it would let a
+            // waiter lock a stale inode while a newcomer locks a fresh one.
+            unsafe {
+                libc::flock(self.file.as_raw_fd(), libc::LOCK_UN);
+            }
+        }
+    }
 }
 
 /// Migrate schema from an older version to current.
@@ -1150,6 +1555,66 @@ mod tests {
         }
     }
 
+    fn search_library() -> PatternLibrary {
+        let mut library = PatternLibrary::new(std::env::temp_dir());
+        let mut agent = make_test_signature("claude_code").with_notes("Anthropic agent CLI");
+        agent.category = super::super::types::SupervisorCategory::Agent;
+        library.add_custom(agent).unwrap();
+        library
+            .add_custom(make_test_signature("vscode_server").with_notes("Remote code editor"))
+            .unwrap();
+        library
+            .add_learned(make_test_signature("jest_worker").with_notes("Spawned by claude runs"))
+            .unwrap();
+        library
+    }
+
+    fn names<'a>(patterns: &[&'a PersistedPattern]) -> Vec<&'a str> {
+        patterns.iter().map(|p| p.signature.name.as_str()).collect()
+    }
+
+    #[test]
+    fn test_search_ranks_name_matches_first() {
+        let library = search_library();
+
+        // Name hit (weight 3) outranks a notes-only hit (weight 1).
+        assert_eq!(
+            names(&library.search("Claude")),
+            vec!["claude_code", "jest_worker"]
+        );
+        // Token overlap: "code" is a word of claude_code, a substring of vscode_server.
+        assert_eq!(
+            names(&library.search("code")),
+            vec!["claude_code", "vscode_server"]
+        );
+        assert_eq!(names(&library.search("AGENT")), vec!["claude_code"]);
+        assert!(library.search("kubernetes").is_empty());
+        assert_eq!(library.search("  ").len(), 3);
+    }
+
+    #[test]
+    fn test_search_filters_by_source_and_lifecycle() {
+        let mut library = search_library();
+        let learned = PatternSearchFilter::default().with_source(PatternSource::Learned);
+        assert_eq!(
+            names(&library.search_with("", &learned)),
+            vec!["jest_worker"]
+        );
+        assert!(library.search_with("vscode", &learned).is_empty());
+
+        library
+            .get_pattern_mut("vscode_server")
+            .unwrap()
+            .transition_lifecycle(PatternLifecycle::Deprecated)
+            .unwrap();
+        let deprecated =
+            PatternSearchFilter::default().with_lifecycle(PatternLifecycle::Deprecated);
+        assert_eq!(
+            names(&library.search_with("code", &deprecated)),
+            vec!["vscode_server"]
+        );
+    }
+
     #[test]
     fn test_pattern_lifecycle_transitions() {
         use PatternLifecycle::*;
@@ -1438,6 +1903,22 @@ mod tests {
         assert!((stats.acceptance_rate().unwrap() - 0.0).abs() < 0.001);
     }
 
+    #[test]
+    fn test_stats_acceptance_interval() {
+        let mut stats = PatternStats::default();
+        assert_eq!(stats.acceptance_interval(0.95), (0.0, 1.0));
+        assert_eq!(stats.acceptance_summary(None), "0/0");
+
+        for accepted in [true, true, false, true] {
+            stats.record_match(accepted);
+        }
+        let (lo, hi) = stats.acceptance_interval(0.95);
+        assert!((lo - 0.3006).abs() < 1e-3);
+        assert!((hi - 0.9544).abs() < 1e-3);
+        assert_eq!(stats.acceptance_summary(Some(0.95)), "3/4 (30%–95%)");
+        assert_eq!(stats.acceptance_summary(None), "3/4 (75%)");
+    }
+
     #[test]
     fn test_stats_laplace_smoothing() {
         let mut stats = PatternStats::default();
@@ -2101,6 +2582,132 @@ mod tests {
         assert!((p.signature.confidence_weight - 0.9).abs() < 0.001);
     }
 
+    #[test]
+    fn test_import_merge_combines_embedded_stats() {
+        let dir = tempdir().expect("tempdir");
+        let mut lib = PatternLibrary::new(dir.path());
+        lib.add_custom(make_test_signature("merged")).unwrap();
+        {
+            let stats = lib.stats.get_or_create("merged");
+            stats.match_count = 4;
+            stats.accept_count = 3;
+            stats.reject_count = 1;
+            stats.first_seen = Some(2_000);
+            stats.last_match = Some(5_000);
+        }
+
+        let mut imported =
+            PersistedPattern::new(make_test_signature("merged"), PatternSource::Imported);
+        imported.stats = Some(PatternStats {
+            match_count: 6,
+            accept_count: 5,
+            reject_count: 1,
+            first_seen: Some(1_000),
+            last_match: Some(4_000),
+            ..Default::default()
+        });
+        let mut fresh =
+            PersistedPattern::new(make_test_signature("fresh"), PatternSource::Imported);
+        fresh.stats = Some(PatternStats {
+            match_count: 2,
+            accept_count: 2,
+            ..Default::default()
+        });
+        // Round-trip through JSON, as a real import file would.
+        let schema = PersistedSchema {
+            schema_version: SCHEMA_VERSION,
+            patterns: vec![imported, fresh],
+            metadata: None,
+        };
+        let schema = PersistedSchema::from_json(&schema.to_json().unwrap()).unwrap();
+
+        let result = lib.import(schema, ConflictResolution::Merge).unwrap();
+        assert_eq!((result.updated, result.imported), (1, 1));
+
+        let stats = lib.get_stats("merged").unwrap();
+        assert_eq!(stats.match_count, 10);
+        assert_eq!(stats.accept_count, 8);
+        assert_eq!(stats.reject_count, 2);
+        assert_eq!(stats.first_seen, Some(1_000));
+        assert_eq!(stats.last_match, Some(5_000));
+        // Laplace smoothing over the merged counts: (8+1)/(10+2).
+        assert!((stats.computed_confidence.unwrap() - 0.75).abs() < 1e-9);
+
+        assert_eq!(lib.get_stats("fresh").unwrap().match_count, 2);
+        // Stats live in AllPatternStats, not on the stored pattern.
+        assert!(lib.get_pattern("fresh").unwrap().stats.is_none());
+    }
+
+    #[test]
+    fn test_reimporting_an_export_is_idempotent() {
+        let dir = tempdir().expect("tempdir");
+        let mut lib = PatternLibrary::new(dir.path()).with_origin("host-b");
+        lib.add_custom(make_test_signature("repeat")).unwrap();
+        lib.stats.get_or_create("repeat").match_count = 1;
+        lib.stats.get_or_create("repeat").accept_count = 1;
+
+        let mut imported =
+            PersistedPattern::new(make_test_signature("repeat"), PatternSource::Imported);
+        imported.stats = Some(PatternStats {
+            match_count: 6,
+            accept_count: 5,
+            reject_count: 1,
+            ..Default::default()
+        });
+        let schema = PersistedSchema {
+            schema_version: SCHEMA_VERSION,
+            patterns: vec![imported],
+            metadata: Some(SchemaMetadata {
+                source_system: Some("host-a".to_string()),
+                ..Default::default()
+            }),
+        };
+
+        lib.import(schema.clone(), ConflictResolution::Merge)
+            .unwrap();
+        lib.import(schema, ConflictResolution::Merge).unwrap();
+
+        let stats = lib.get_stats("repeat").unwrap();
+        assert_eq!(stats.match_count, 7);
+        assert_eq!(stats.accept_count, 6);
+        assert_eq!(stats.origins.len(), 1);
+        assert_eq!(stats.local_counts().match_count, 1);
+    }
+
+    #[test]
+    fn test_round_trip_import_does_not_double_count() {
+        let dir_a = tempdir().expect("tempdir");
+        let dir_b = tempdir().expect("tempdir");
+        let mut a = PatternLibrary::new(dir_a.path()).with_origin("host-a");
+        let mut b = PatternLibrary::new(dir_b.path()).with_origin("host-b");
+
+        a.add_custom(make_test_signature("shared")).unwrap();
+        for accepted in [true, true, true, false] {
+            a.stats.record_match("shared", accepted);
+        }
+
+        b.import(
+            a.export(&[PatternSource::Custom]),
+            ConflictResolution::Merge,
+        )
+        .unwrap();
+        b.stats.record_match("shared", true);
+        b.stats.record_match("shared", true);
+        assert_eq!(b.get_stats("shared").unwrap().match_count, 6);
+
+        let export_b = b.export(&[PatternSource::Imported]);
+        a.import(export_b.clone(), ConflictResolution::Merge)
+            .unwrap();
+        a.import(export_b, ConflictResolution::Merge).unwrap();
+
+        let stats = a.get_stats("shared").unwrap();
+        assert_eq!(stats.match_count, 6);
+        assert_eq!(stats.accept_count, 5);
+        assert_eq!(stats.reject_count, 1);
+        assert_eq!(stats.origins.keys().collect::<Vec<_>>(), vec!["host-b"]);
+        assert_eq!(stats.origins["host-b"].match_count, 2);
+    }
+
     #[test]
     fn test_import_no_conflict() {
         let dir = tempdir().expect("tempdir");
@@ -2135,6 +2742,90 @@ mod tests {
         assert_eq!(schema.signatures.len(), 2);
     }
 
+    // ── PatternLibrary: compact ─────────────────────────────────────
+
+    #[test]
+    fn test_library_compact_prunes_only_stale_tombstones() {
+        let dir = tempdir().expect("tempdir");
+        let mut lib = PatternLibrary::new(dir.path());
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let day = 24 * 60 * 60;
+
+        lib.add_learned(make_test_signature("stale_learned"))
+            .unwrap();
+        lib.add_learned(make_test_signature("fresh_learned"))
+            .unwrap();
+        lib.add_custom(make_test_signature("stale_custom")).unwrap();
+        lib.add_custom(make_test_signature("old_active")).unwrap();
+        for (name, lifecycle, age_days) in [
+            ("stale_learned", PatternLifecycle::Removed, 90),
+            ("fresh_learned", PatternLifecycle::Removed, 2),
+            ("stale_custom", PatternLifecycle::Removed, 45),
+            ("old_active", PatternLifecycle::Stable, 365),
+        ] {
+            let pattern = lib.get_pattern_mut(name).unwrap();
+            pattern.lifecycle = lifecycle;
+            pattern.updated_at = Some(now - age_days * day);
+        }
+        lib.dirty = false;
+
+        let window = Duration::from_secs(30 * day);
+        assert_eq!(lib.compact(window), 2);
+        assert!(lib.dirty);
+        assert!(lib.get_pattern("stale_learned").is_none());
+        assert!(lib.get_pattern("stale_custom").is_none());
+        assert!(lib.get_pattern("fresh_learned").is_some());
+        assert!(lib.get_pattern("old_active").is_some());
+
+        // Nothing left to prune: no-op that leaves the library clean.
+        lib.dirty = false;
+        assert_eq!(lib.compact(window), 0);
+        assert!(!lib.dirty);
+    }
+
+    #[test]
+    fn test_library_gc_age_cutoff_boundary_and_stats() {
+        let dir = tempdir().expect("tempdir");
+        let mut lib = PatternLibrary::new(dir.path());
+        let cutoff = 1_000_000;
+
+        for (name, updated_at) in [
+            ("just_before", cutoff - 1),
+            ("at_cutoff", cutoff),
+            ("just_after", cutoff + 1),
+        ] {
+            lib.add_learned(make_test_signature(name)).unwrap();
+            lib.record_match(name, true);
+            let pattern = lib.get_pattern_mut(name).unwrap();
+            pattern.lifecycle = PatternLifecycle::Removed;
+            pattern.updated_at = Some(updated_at);
+        }
+        let mut builtin = PersistedPattern::builtin(make_test_signature("builtin_tomb"));
+        builtin.lifecycle = PatternLifecycle::Removed;
+        builtin.updated_at = Some(0);
+        lib.built_in.patterns.push(builtin);
+        lib.record_match("builtin_tomb", false);
+
+        // Only tombstones strictly older than the cutoff go.
+        assert_eq!(lib.compact_at(cutoff), 1);
+        assert!(lib.get_pattern("just_before").is_none());
+        assert!(lib.get_stats("just_before").is_none());
+        for kept in ["at_cutoff", "just_after"] {
+            assert!(lib.get_pattern(kept).is_some(), "{kept}");
+            assert!(lib.get_stats(kept).is_some(), "{kept}");
+        }
+        assert!(lib.get_pattern("builtin_tomb").is_some());
+        assert!(lib.get_stats("builtin_tomb").is_some());
+
+        // Real clock: every remaining learned tombstone is decades old.
+        assert_eq!(lib.gc(24 * 60 * 60), 2);
+        assert!(lib.get_pattern("builtin_tomb").is_some());
+        assert_eq!(lib.gc(0), 0);
+    }
+
     // ── PatternLibrary: initialize_built_in ─────────────────────────
 
     #[test]
@@ -2278,4 +2969,69 @@ mod tests {
             ConflictResolution::KeepHigherConfidence
         );
     }
+
+    #[test]
+    fn test_with_lock_concurrent_writers_both_survive() {
+        use std::sync::{Arc, Barrier};
+
+        let dir = tempdir().expect("tempdir");
+        let barrier = Arc::new(Barrier::new(2));
+        let handles: Vec<_> = ["from_scan", "from_mcp"]
+            .into_iter()
+            .map(|name| {
+                let config_dir = dir.path().to_path_buf();
+                let barrier = Arc::clone(&barrier);
+                std::thread::spawn(move || {
+                    let mut lib = PatternLibrary::new(config_dir);
+                    lib.load().unwrap();
+                    barrier.wait();
+                    lib.with_lock(DEFAULT_LOCK_TIMEOUT, |lib| {
+                        // Widen the window in which an unlocked writer would clobber.
+                        std::thread::sleep(Duration::from_millis(50));
+                        lib.add_learned(make_test_signature(name))
+                    })
+                    .unwrap();
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        let mut lib = PatternLibrary::new(dir.path());
+        lib.load().unwrap();
+        assert!(lib.get_pattern("from_scan").is_some());
+        assert!(lib.get_pattern("from_mcp").is_some());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_with_lock_times_out_when_held() {
+        let dir = tempdir().expect("tempdir");
+        let mut lib = PatternLibrary::new(dir.path());
+        lib.ensure_directories().unwrap();
+        let lock_path = lib.patterns_dir().join(LOCK_FILE);
+        let held = LibraryLock::acquire(&lock_path, Duration::ZERO).unwrap();
+
+        let timeout = Duration::from_millis(30);
+        let err = lib
+            .with_lock(timeout, |lib| {
+                lib.add_custom(make_test_signature("blocked"))
+            })
+            .unwrap_err();
+        match err {
+            PersistenceError::Locked { path, timeout: t } => {
+                assert_eq!(path, lock_path);
+                assert_eq!(t, timeout);
+            }
+            other => panic!("expected Locked, got {other:?}"),
+        }
+
+        drop(held);
+        lib.with_lock(timeout, |lib| {
+            lib.add_custom(make_test_signature("unblocked"))
+        })
+        .unwrap();
+        assert!(!lib.dirty);
+    }
 }
@@ -465,15 +465,7 @@ impl SupervisorSignature {
 
     /// Validate the signature.
     pub fn validate(&self) -> Result<(), SignatureError> {
-        if self.name.is_empty() {
-            return Err(SignatureError::Invalid("name cannot be empty".into()));
-        }
-
-        if self.confidence_weight < 0.0 || self.confidence_weight > 1.0 {
-            return Err(SignatureError::Invalid(
-                "confidence_weight must be between 0.0 and 1.0".into(),
-            ));
-        }
+        self.validate_fields()?;
 
         // Validate regex patterns
         for pattern in &self.patterns.process_names {
@@ -513,6 +505,21 @@ impl SupervisorSignature {
             }
         }
 
+        Ok(())
+    }
+
+    /// Validate everything except the regex patterns.
+    fn validate_fields(&self) -> Result<(), SignatureError> {
+        if self.name.is_empty() {
+            return Err(SignatureError::Invalid("name cannot be empty".into()));
+        }
+
+        if self.confidence_weight < 0.0 || self.confidence_weight > 1.0 {
+            return Err(SignatureError::Invalid(
+                "confidence_weight must be between 0.0 and 1.0".into(),
+            ));
+        }
+
         // Validate priors
         self.priors.validate()?;
 
@@ -522,6 +529,24 @@ impl SupervisorSignature {
         Ok(())
     }
 
+    /// Fingerprint of the schema version, name and every regex string, used
+    /// to key compiled patterns so that any edit recompiles.
+    fn pattern_fingerprint(&self) -> u64 {
+        use std::hash::{Hash, Hasher};
+
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        SCHEMA_VERSION.hash(&mut hasher);
+        self.name.hash(&mut hasher);
+        self.patterns.process_names.hash(&mut hasher);
+        self.patterns.arg_patterns.hash(&mut hasher);
+        self.patterns.working_dir_patterns.hash(&mut hasher);
+        self.patterns.parent_patterns.hash(&mut hasher);
+        let mut env: Vec<_> = self.patterns.environment_vars.iter().collect();
+        env.sort();
+        env.hash(&mut hasher);
+        hasher.finish()
+    }
+
     /// Convert to a SupervisorPattern (process name only).
     pub fn to_supervisor_pattern(&self) -> SupervisorPattern {
         SupervisorPattern::new(
@@ -862,7 +887,49 @@ impl<'a> ProcessMatchContext<'a> {
     }
 }
 
+/// Compiled regexes of one signature.
+#[derive(Debug, Clone)]
+struct CompiledPatterns {
+    process: Vec<regex::Regex>,
+    args: Vec<regex::Regex>,
+    working_dir: Vec<regex::Regex>,
+    parent: Vec<regex::Regex>,
+    env: HashMap<String, regex::Regex>,
+}
+
+impl CompiledPatterns {
+    fn compile(patterns: &SignaturePatterns) -> Result<Self, SignatureError> {
+        let compile = |pattern: &String| {
+            regex::Regex::new(pattern).map_err(|e| SignatureError::InvalidRegex {
+                pattern: pattern.clone(),
+                error: e.to_string(),
+            })
+        };
+        let compile_all = |list: &[String]| list.iter().map(compile).collect::<Result<_, _>>();
+
+        let mut env = HashMap::with_capacity(patterns.environment_vars.len());
+        for (var_name, pattern) in &patterns.environment_vars {
+            if !pattern.is_empty() && pattern != ".*" {
+                env.insert(var_name.clone(), compile(pattern)?);
+            }
+        }
+
+        Ok(Self {
+            process: compile_all(&patterns.process_names)?,
+            args: compile_all(&patterns.arg_patterns)?,
+            working_dir: compile_all(&patterns.working_dir_patterns)?,
+            parent: compile_all(&patterns.parent_patterns)?,
+            env,
+        })
+    }
+}
+
 /// Unified signature database combining all detection methods.
+///
+/// Each signature's regexes are compiled once when it is added. Compiled
+/// patterns are also cached by a fingerprint of the schema version, the
+/// signature name and its regex strings, so [`Self::reload_schema`] only
+/// recompiles signatures that were added or edited.
 #[derive(Debug, Clone, Default)]
 pub struct SignatureDatabase {
     /// All loaded signatures.
@@ -878,6 +945,10 @@ pub struct SignatureDatabase {
     /// Compiled regex patterns for environment variables (cached).
     /// Maps variable name to compiled regex.
     env_regexes: Vec<HashMap<String, regex::Regex>>,
+    /// Compiled patterns by signature fingerprint.
+    compile_cache: HashMap<u64, CompiledPatterns>,
+    /// Number of signatures compiled so far (cache misses).
+    compilations: usize,
 }
 
 impl SignatureDatabase {
@@ -890,6 +961,8 @@ impl SignatureDatabase {
             working_dir_regexes: vec![],
             parent_regexes: vec![],
             env_regexes: vec![],
+            compile_cache: HashMap::new(),
+            compilations: 0,
         }
     }
 
@@ -916,72 +989,68 @@ impl SignatureDatabase {
     }
 
     /// Add a signature and compile its patterns.
+    ///
+    /// Patterns already compiled for an identical signature are reused.
     pub fn add(&mut self, signature: SupervisorSignature) -> Result<(), SignatureError> {
-        signature.validate()?;
-
-        // Pre-compile all regexes to ensure success before updating state
-        let mut proc_res = Vec::with_capacity(signature.patterns.process_names.len());
-        for pattern in &signature.patterns.process_names {
-            proc_res.push(regex::Regex::new(pattern).map_err(|e| {
-                SignatureError::InvalidRegex {
-                    pattern: pattern.clone(),
-                    error: e.to_string(),
-                }
-            })?);
-        }
+        signature.validate_fields()?;
+
+        let fingerprint = signature.pattern_fingerprint();
+        let compiled = match self.compile_cache.get(&fingerprint) {
+            Some(compiled) => compiled.clone(),
+            None => {
+                // Compile everything before updating state
+                let compiled = CompiledPatterns::compile(&signature.patterns)?;
+                self.compilations += 1;
+                self.compile_cache.insert(fingerprint, compiled.clone());
+                compiled
+            }
+        };
 
-        let mut arg_res = Vec::with_capacity(signature.patterns.arg_patterns.len());
-        for pattern in &signature.patterns.arg_patterns {
-            arg_res.push(
-                regex::Regex::new(pattern).map_err(|e| SignatureError::InvalidRegex {
-                    pattern: pattern.clone(),
-                    error: e.to_string(),
-                })?,
-            );
-        }
+        self.process_regexes.push(compiled.process);
+        self.arg_regexes.push(compiled.args);
+        self.working_dir_regexes.push(compiled.working_dir);
+        self.parent_regexes.push(compiled.parent);
+        self.env_regexes.push(compiled.env);
+        self.signatures.push(signature);
 
-        let mut wd_res = Vec::with_capacity(signature.patterns.working_dir_patterns.len());
-        for pattern in &signature.patterns.working_dir_patterns {
-            wd_res.push(
-                regex::Regex::new(pattern).map_err(|e| SignatureError::InvalidRegex {
-                    pattern: pattern.clone(),
-                    error: e.to_string(),
-                })?,
-            );
-        }
+        Ok(())
+    }
 
-        let mut parent_res = Vec::with_capacity(signature.patterns.parent_patterns.len());
-        for pattern in &signature.patterns.parent_patterns {
-            parent_res.push(regex::Regex::new(pattern).map_err(|e| {
-                SignatureError::InvalidRegex {
-                    pattern: pattern.clone(),
-                    error: e.to_string(),
-                }
-            })?);
-        }
+    /// Number of signatures compiled since the database was created.
+    ///
+    /// Adding a signature identical to one seen before does not count.
+    pub fn compilations(&self) -> usize {
+        self.compilations
+    }
 
-        let mut env_res = HashMap::with_capacity(signature.patterns.environment_vars.len());
-        for (var_name, pattern) in &signature.patterns.environment_vars {
-            if !pattern.is_empty() && pattern != ".*" {
-                env_res.insert(
-                    var_name.clone(),
-                    regex::Regex::new(pattern).map_err(|e| SignatureError::InvalidRegex {
-                        pattern: pattern.clone(),
-                        error: e.to_string(),
-                    })?,
-                );
+    /// Replace all signatures with those in `schema`.
+    ///
+    /// Unchanged signatures reuse their compiled patterns; compiled patterns
+    /// of signatures no longer present are dropped. On error the database is
+    /// left as it was.
+    pub fn reload_schema(&mut self, schema: SignatureSchema) -> Result<usize, SignatureError> {
+        let mut next = Self {
+            compile_cache: std::mem::take(&mut self.compile_cache),
+            compilations: self.compilations,
+            ..Self::new()
+        };
+        match next.load_schema(schema) {
+            Ok(loaded) => {
+                let live: std::collections::HashSet<u64> = next
+                    .signatures
+                    .iter()
+                    .map(SupervisorSignature::pattern_fingerprint)
+                    .collect();
+                next.compile_cache.retain(|key, _| live.contains(key));
+                *self = next;
+                Ok(loaded)
+            }
+            Err(err) => {
+                self.compile_cache = next.compile_cache;
+                self.compilations = next.compilations;
+                Err(err)
             }
         }
-
-        // All compiled successfully, update state
-        self.process_regexes.push(proc_res);
-        self.arg_regexes.push(arg_res);
-        self.working_dir_regexes.push(wd_res);
-        self.parent_regexes.push(parent_res);
-        self.env_regexes.push(env_res);
-        self.signatures.push(signature);
-
-        Ok(())
     }
 
     /// Load signatures from a schema.
@@ -2568,6 +2637,51 @@ mod tests {
         assert!(matches[0].level >= MatchLevel::CommandOnly);
     }
 
+    #[test]
+    fn test_reload_schema_compiles_each_signature_once() {
+        let schema_of = |names: Vec<SupervisorSignature>| SignatureSchema {
+            schema_version: SCHEMA_VERSION,
+            signatures: names,
+            metadata: None,
+        };
+        let signatures: Vec<SupervisorSignature> = (0..50)
+            .map(|i| {
+                SupervisorSignature::new(format!("sig-{i}"), SupervisorCategory::Agent)
+                    .with_process_patterns(vec![format!("^proc-{i}$").as_str()])
+                    .with_working_dir_patterns(vec!["^/srv/"])
+            })
+            .collect();
+
+        let mut db = SignatureDatabase::new();
+        assert_eq!(db.reload_schema(schema_of(signatures.clone())).unwrap(), 50);
+        assert_eq!(db.compilations(), 50);
+
+        for pid in 0..10_000 {
+            let comm = format!("proc-{}", pid % 50);
+            let ctx = ProcessMatchContext::with_comm(&comm).cwd("/srv/app");
+            assert_eq!(db.match_process(&ctx).len(), 1);
+        }
+        assert_eq!(db.compilations(), 50);
+
+        db.reload_schema(schema_of(signatures.clone())).unwrap();
+        assert_eq!(db.compilations(), 50);
+
+        let mut edited = signatures.clone();
+        edited[7] = edited[7]
+            .clone()
+            .with_process_patterns(vec!["^proc-seven$"]);
+        db.reload_schema(schema_of(edited.clone())).unwrap();
+        assert_eq!(db.compilations(), 51);
+        assert_eq!(db.find_by_process_name("proc-seven").len(), 1);
+
+        let mut broken = edited;
+        broken[3] = broken[3].clone().with_process_patterns(vec!["(unclosed"]);
+        let err = db.reload_schema(schema_of(broken)).unwrap_err();
+        assert!(matches!(err, SignatureError::InvalidRegex { .. }));
+        assert_eq!(db.len(), 50);
+        assert_eq!(db.find_by_process_name("proc-seven").len(), 1);
+    }
+
     #[test]
     fn test_match_process_no_match() {
         let db = SignatureDatabase::with_defaults();
@@ -37,7 +37,7 @@ use ftui::{
 use super::events::KeyBindings;
 use super::layout::{Breakpoint, LayoutState, ResponsiveLayout};
 use super::msg::{ExecutionOutcome, Msg};
-use super::theme::Theme;
+use super::theme::{Theme, ThemeMode};
 use super::widgets::{
     AuxPanel, ConfirmChoice, ConfirmDialog, ConfirmDialogState, DetailView, HelpOverlay,
     ProcessDetail, ProcessRow, ProcessTable, ProcessTableState, SearchInput, SearchInputState,
@@ -103,6 +103,8 @@ pub struct App {
     detail_visible: bool,
     /// Current detail view mode.
     detail_view: DetailView,
+    /// Evidence contribution scroll offset and the PID it applies to.
+    contribution_scroll: (Option<u32>, usize),
     /// Optional goal summary lines to display.
     goal_summary: Option<Vec<String>>,
     /// Injected refresh operation for ftui Cmd::task (Send + 'static).
@@ -161,6 +163,7 @@ impl App {
             layout_state: LayoutState::new(80, 24),
             detail_visible: true,
             detail_view: DetailView::Summary,
+            contribution_scroll: (None, 0),
             goal_summary: None,
             refresh_op: None,
             execute_op: None,
@@ -269,6 +272,10 @@ impl App {
                 .with_description("Disable colors for no-color terminals")
                 .with_tags(&["theme", "no-color"])
                 .with_category("Settings"),
+            ActionItem::new("settings.theme.deuteranopia", "Switch theme: colorblind")
+                .with_description("Apply the deuteranopia-safe theme with shape cues")
+                .with_tags(&["theme", "colorblind", "accessible"])
+                .with_category("Settings"),
         ];
 
         // Deterministic lexical fallback for ties when match scores are equal.
@@ -306,6 +313,17 @@ impl App {
         self.detail_view
     }
 
+    /// Evidence contribution scroll offset for the current row.
+    ///
+    /// The offset belongs to the row it was set on, so moving the cursor
+    /// starts the next process at its most influential term.
+    pub fn current_contribution_scroll(&self) -> usize {
+        match (self.contribution_scroll, self.process_table.current_row()) {
+            ((Some(pid), offset), Some(row)) if row.pid == pid => offset,
+            _ => 0,
+        }
+    }
+
     /// Update layout state for new terminal size.
     /// Returns true if breakpoint changed.
     pub fn update_layout(&mut self, width: u16, height: u16) -> bool {
@@ -428,6 +446,21 @@ impl App {
         self.detail_visible = true;
     }
 
+    fn scroll_contributions(&mut self, down: bool) {
+        let Some(row) = self.process_table.current_row() else {
+            return;
+        };
+        let (pid, max) = (row.pid, row.contributions.len().saturating_sub(1));
+        let offset = self.current_contribution_scroll();
+        let offset = if down {
+            (offset + 1).min(max)
+        } else {
+            offset.saturating_sub(1)
+        };
+        self.contribution_scroll = (Some(pid), offset);
+        self.detail_visible = true;
+    }
+
     fn announce_accessible(&mut self, message: impl Into<String>) {
         if !self.accessible {
             return;
@@ -460,6 +493,7 @@ impl App {
             "settings.theme.light" => "Switch theme light",
             "settings.theme.high_contrast" => "Switch theme high contrast",
             "settings.theme.no_color" => "Switch theme no color",
+            "settings.theme.deuteranopia" => "Switch theme colorblind",
             _ => "Unknown command",
         }
     }
@@ -519,6 +553,7 @@ impl App {
             "settings.theme.light" => self.theme = Theme::light(),
             "settings.theme.high_contrast" => self.theme = Theme::high_contrast(),
             "settings.theme.no_color" => self.theme = Theme::no_color(),
+            "settings.theme.deuteranopia" => self.theme = Theme::deuteranopia(),
 
             _ => {
                 tracing::warn!(
@@ -728,10 +763,12 @@ impl App {
             }
             Msg::SearchInput(c) => {
                 self.search.type_char(c);
+                self.apply_search_filter();
                 FtuiCmd::none()
             }
             Msg::SearchBackspace => {
                 self.search.backspace();
+                self.apply_search_filter();
                 FtuiCmd::none()
             }
             Msg::SearchCommit => {
@@ -750,10 +787,12 @@ impl App {
             }
             Msg::SearchHistoryUp => {
                 self.search.history_prev();
+                self.apply_search_filter();
                 FtuiCmd::none()
             }
             Msg::SearchHistoryDown => {
                 self.search.history_next();
+                self.apply_search_filter();
                 FtuiCmd::none()
             }
 
@@ -825,6 +864,7 @@ impl App {
                                 attempted: selected_count,
                                 succeeded: 0,
                                 failed: 0,
+                                aborted: false,
                             }))
                         }),
                     ])
@@ -904,6 +944,11 @@ impl App {
                         "skeleton" => "Execution not wired yet (skeleton mode)".to_string(),
                         other => format!("Execution finished ({})", other),
                     }
+                } else if outcome.aborted {
+                    format!(
+                        "Execution stopped after first failure: {} succeeded, {} failed ({} attempted)",
+                        outcome.succeeded, outcome.failed, outcome.attempted
+                    )
                 } else {
                     format!(
                         "Execution complete: {} succeeded, {} failed ({} attempted)",
@@ -949,12 +994,7 @@ impl App {
             }
 
             Msg::SwitchTheme(name) => {
-                self.theme = match name.to_lowercase().as_str() {
-                    "light" => Theme::light(),
-                    "high_contrast" | "high-contrast" | "hc" => Theme::high_contrast(),
-                    "no_color" | "no-color" => Theme::no_color(),
-                    _ => Theme::dark(),
-                };
+                self.theme = Theme::from_mode(ThemeMode::from_name(&name).unwrap_or_default());
                 FtuiCmd::none()
             }
 
@@ -1063,6 +1103,12 @@ impl App {
             FtuiKeyCode::Char('r') => return FtuiCmd::msg(Msg::RequestRefresh),
             FtuiKeyCode::Char('s') => self.set_detail_view(DetailView::Summary),
             FtuiKeyCode::Char('t') => self.set_detail_view(DetailView::Genealogy),
+            FtuiKeyCode::Char('[') => self.scroll_contributions(false),
+            FtuiKeyCode::Char(']') => self.scroll_contributions(true),
+            FtuiKeyCode::Char(c @ ('o' | 'O')) => {
+                self.process_table.handle_sort_key(c);
+                self.set_status(format!("Sort: {}", self.process_table.sort_label()));
+            }
             FtuiKeyCode::Char('g') => {
                 if self.detail_view == DetailView::GalaxyBrain {
                     self.set_detail_view(DetailView::Summary);
@@ -1100,10 +1146,22 @@ impl App {
                 self.focus = FocusTarget::ProcessList;
                 self.update_focus();
             }
-            FtuiKeyCode::Up => self.search.history_prev(),
-            FtuiKeyCode::Down => self.search.history_next(),
-            FtuiKeyCode::Backspace => self.search.backspace(),
-            FtuiKeyCode::Char(c) => self.search.type_char(c),
+            FtuiKeyCode::Up => {
+                self.search.history_prev();
+                self.apply_search_filter();
+            }
+            FtuiKeyCode::Down => {
+                self.search.history_next();
+                self.apply_search_filter();
+            }
+            FtuiKeyCode::Backspace => {
+                self.search.backspace();
+                self.apply_search_filter();
+            }
+            FtuiKeyCode::Char(c) => {
+                self.search.type_char(c);
+                self.apply_search_filter();
+            }
             _ => {}
         }
         FtuiCmd::none()
@@ -1211,6 +1269,7 @@ impl FtuiModel for App {
                     .theme(&self.theme)
                     .row(current_row, selected)
                     .view(self.detail_view)
+                    .contribution_scroll(self.current_contribution_scroll())
                     .render_ftui(detail_area, frame);
             }
         }
@@ -1238,7 +1297,8 @@ impl FtuiModel for App {
             .mode(status_mode)
             .selected_count(self.process_table.selected_count());
         if let Some(ref filter) = self.process_table.filter {
-            status_bar = status_bar.filter(filter);
+            let (matching, total) = self.process_table.match_counts();
+            status_bar = status_bar.filter(filter).match_count(matching, total);
         }
         if let Some(ref msg) = self.status_message {
             status_bar = status_bar.message(msg);
@@ -1330,6 +1390,7 @@ pub fn run_ftui(app: App, config: ProgramConfig) -> TuiResult<()> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::inference::{ClassScores, EvidenceContribution};
 
     #[test]
     fn test_app_new() {
@@ -1435,6 +1496,45 @@ mod tests {
         assert!(app.process_table.focused);
     }
 
+    #[test]
+    fn test_search_filters_incrementally() {
+        let mut app = App::new();
+        let rows = vec![make_row(11), make_row(12), make_row(21)];
+        <App as FtuiModel>::update(&mut app, Msg::ProcessesScanned(rows));
+        <App as FtuiModel>::update(&mut app, Msg::EnterSearchMode);
+
+        // Typing narrows on each keystroke (case-insensitive).
+        <App as FtuiModel>::update(&mut app, Msg::SearchInput('P'));
+        assert_eq!(app.process_table.match_counts(), (3, 3));
+        for c in "roc_1".chars() {
+            <App as FtuiModel>::update(&mut app, Msg::SearchInput(c));
+        }
+        assert_eq!(app.process_table.match_counts(), (2, 3));
+        <App as FtuiModel>::update(&mut app, Msg::SearchInput('2'));
+        assert_eq!(app.process_table.match_counts(), (1, 3));
+        assert_eq!(app.process_table.current_row().unwrap().pid, 12);
+
+        // No match produces an explicit empty state.
+        <App as FtuiModel>::update(&mut app, Msg::SearchInput('9'));
+        assert_eq!(app.process_table.match_counts(), (0, 3));
+        assert!(app
+            .process_table
+            .empty_message()
+            .starts_with("No processes match"));
+
+        // Backspacing widens again and keeps the surviving row focused.
+        <App as FtuiModel>::update(&mut app, Msg::SearchBackspace);
+        <App as FtuiModel>::update(&mut app, Msg::SearchBackspace);
+        assert_eq!(app.process_table.match_counts(), (2, 3));
+
+        // Clearing the query shows every row.
+        while !app.search.value().is_empty() {
+            <App as FtuiModel>::update(&mut app, Msg::SearchBackspace);
+        }
+        assert!(app.process_table.filter.is_none());
+        assert_eq!(app.process_table.match_counts(), (3, 3));
+    }
+
     #[test]
     fn test_search_cancel_returns_to_normal() {
         let mut app = App::new();
@@ -1550,6 +1650,7 @@ mod tests {
         ProcessRow {
             pid,
             score: 50,
+            expected_loss: None,
             classification: "REVIEW".to_string(),
             runtime: "1h".to_string(),
             memory: "10M".to_string(),
@@ -1558,6 +1659,7 @@ mod tests {
             galaxy_brain: None,
             why_summary: None,
             top_evidence: vec![],
+            contributions: Vec::new(),
             confidence: None,
             plan_preview: vec![],
             provenance_headline: None,
@@ -1567,6 +1669,69 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_selected_row_exposes_contributions() {
+        let mut app = App::new();
+        let mut row = make_row(7);
+        row.contributions = (1..=3)
+            .map(|rank| EvidenceContribution {
+                feature: format!("term_{rank}"),
+                log_likelihood_delta: ClassScores {
+                    useful: 0.0,
+                    useful_bad: 0.0,
+                    abandoned: 0.0,
+                    zombie: 0.0,
+                },
+                influence: 1.0 / rank as f64,
+                rank,
+            })
+            .collect();
+        <App as FtuiModel>::update(&mut app, Msg::ProcessesScanned(vec![make_row(6), row]));
+        <App as FtuiModel>::update(&mut app, Msg::CursorDown);
+
+        let current = app.process_table.current_row().unwrap();
+        assert_eq!(current.pid, 7);
+        assert_eq!(current.contributions.len(), 3);
+        assert_eq!(current.contributions[0].feature, "term_1");
+    }
+
+    #[test]
+    fn test_contribution_scroll_clamps_and_resets_on_cursor_move() {
+        let mut app = App::new();
+        let mut row = make_row(7);
+        row.contributions = (1..=3)
+            .map(|rank| EvidenceContribution {
+                feature: format!("term_{rank}"),
+                log_likelihood_delta: ClassScores {
+                    useful: 0.0,
+                    useful_bad: 0.0,
+                    abandoned: 0.0,
+                    zombie: 0.0,
+                },
+                influence: 1.0,
+                rank,
+            })
+            .collect();
+        <App as FtuiModel>::update(&mut app, Msg::ProcessesScanned(vec![row, make_row(8)]));
+        let key = |c| Msg::KeyPressed(FtuiKeyEvent::new(FtuiKeyCode::Char(c)));
+
+        <App as FtuiModel>::update(&mut app, key('['));
+        assert_eq!(app.current_contribution_scroll(), 0);
+        for _ in 0..5 {
+            <App as FtuiModel>::update(&mut app, key(']'));
+        }
+        assert_eq!(app.current_contribution_scroll(), 2);
+        <App as FtuiModel>::update(&mut app, key('['));
+        assert_eq!(app.current_contribution_scroll(), 1);
+
+        <App as FtuiModel>::update(&mut app, Msg::CursorDown);
+        assert_eq!(app.current_contribution_scroll(), 0);
+        <App as FtuiModel>::update(&mut app, key(']'));
+        assert_eq!(app.current_contribution_scroll(), 0);
+        <App as FtuiModel>::update(&mut app, Msg::CursorUp);
+        assert_eq!(app.current_contribution_scroll(), 0);
+    }
+
     #[test]
     fn test_processes_scanned_updates_table() {
         let mut app = App::new();
@@ -1603,6 +1768,7 @@ mod tests {
             attempted: 3,
             succeeded: 2,
             failed: 1,
+            aborted: false,
         };
         <App as FtuiModel>::update(&mut app, Msg::ExecutionComplete(Ok(outcome)));
         let status = app.status_message.as_deref().unwrap();
@@ -1610,6 +1776,22 @@ mod tests {
         assert!(status.contains("1 failed"));
     }
 
+    #[test]
+    fn test_execution_complete_aborted() {
+        let mut app = App::new();
+        let outcome = ExecutionOutcome {
+            mode: None,
+            attempted: 2,
+            succeeded: 1,
+            failed: 1,
+            aborted: true,
+        };
+        <App as FtuiModel>::update(&mut app, Msg::ExecutionComplete(Ok(outcome)));
+        let status = app.status_message.as_deref().unwrap();
+        assert!(status.contains("stopped after first failure"));
+        assert!(status.contains("1 failed"));
+    }
+
     #[test]
     fn test_execution_complete_dry_run() {
         let mut app = App::new();
@@ -1618,6 +1800,7 @@ mod tests {
             attempted: 5,
             succeeded: 0,
             failed: 0,
+            aborted: false,
         };
         <App as FtuiModel>::update(&mut app, Msg::ExecutionComplete(Ok(outcome)));
         assert!(app.status_message.as_deref().unwrap().contains("dry_run"));
@@ -24,6 +24,8 @@ pub struct ExecutionOutcome {
     pub attempted: usize,
     pub succeeded: usize,
     pub failed: usize,
+    /// True when the run stopped at the first failure and skipped the rest.
+    pub aborted: bool,
 }
 
 /// Single message type used by the ftui model update loop.
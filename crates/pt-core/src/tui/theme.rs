@@ -22,6 +22,31 @@ pub enum ThemeMode {
     HighContrast,
     /// No color — respects `NO_COLOR` environment variable.
     NoColor,
+    /// Colorblind-safe (deuteranopia) palette with shape cues.
+    Deuteranopia,
+}
+
+impl ThemeMode {
+    /// All theme modes.
+    pub const ALL: [ThemeMode; 5] = [
+        ThemeMode::Dark,
+        ThemeMode::Light,
+        ThemeMode::HighContrast,
+        ThemeMode::NoColor,
+        ThemeMode::Deuteranopia,
+    ];
+
+    /// Parse a theme name as accepted by `--theme` and the command palette.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "dark" => Some(ThemeMode::Dark),
+            "light" => Some(ThemeMode::Light),
+            "high_contrast" | "high-contrast" | "hc" => Some(ThemeMode::HighContrast),
+            "no_color" | "no-color" => Some(ThemeMode::NoColor),
+            "deuteranopia" | "colorblind" => Some(ThemeMode::Deuteranopia),
+            _ => None,
+        }
+    }
 }
 
 /// Domain-specific RGB color definitions for WCAG validation.
@@ -91,6 +116,17 @@ const HC_MUTED: FtuiRgb = FtuiRgb::new(200, 200, 200);
 const HC_BORDER: FtuiRgb = FtuiRgb::new(255, 255, 255);
 const HC_BORDER_FOCUSED: FtuiRgb = FtuiRgb::new(255, 255, 0);
 
+// Deuteranopia-safe colors (Okabe-Ito palette; no red/green pairing)
+const CB_BG: FtuiRgb = FtuiRgb::new(30, 30, 30);
+const CB_FG: FtuiRgb = FtuiRgb::new(220, 220, 220);
+const CB_KILL: FtuiRgb = FtuiRgb::new(230, 159, 0);
+const CB_REVIEW: FtuiRgb = FtuiRgb::new(204, 121, 167);
+const CB_SPARE: FtuiRgb = FtuiRgb::new(86, 180, 233);
+const CB_HIGHLIGHT: FtuiRgb = FtuiRgb::new(240, 228, 66);
+const CB_MUTED: FtuiRgb = FtuiRgb::new(128, 128, 128);
+const CB_BORDER: FtuiRgb = FtuiRgb::new(80, 80, 80);
+const CB_BORDER_FOCUSED: FtuiRgb = FtuiRgb::new(240, 228, 66);
+
 impl Theme {
     /// Auto-detect theme from environment variables.
     ///
@@ -108,6 +144,17 @@ impl Theme {
         Self::dark()
     }
 
+    /// Create the theme for a given mode.
+    pub fn from_mode(mode: ThemeMode) -> Self {
+        match mode {
+            ThemeMode::Dark => Self::dark(),
+            ThemeMode::Light => Self::light(),
+            ThemeMode::HighContrast => Self::high_contrast(),
+            ThemeMode::NoColor => Self::no_color(),
+            ThemeMode::Deuteranopia => Self::deuteranopia(),
+        }
+    }
+
     /// Create a dark theme (default).
     pub fn dark() -> Self {
         let ftui_theme = ThemeBuilder::new()
@@ -252,6 +299,51 @@ impl Theme {
         }
     }
 
+    /// Create a colorblind-safe theme for deuteranopia.
+    ///
+    /// Kill/review/spare use orange/purple/blue rather than red/yellow/green,
+    /// and classifications carry shape markers (see
+    /// [`classification_marker`](Self::classification_marker)) so color is
+    /// never the only cue.
+    pub fn deuteranopia() -> Self {
+        let ftui_theme = ThemeBuilder::new()
+            .background(ftui::Color::rgb(CB_BG.r, CB_BG.g, CB_BG.b))
+            .text(ftui::Color::rgb(CB_FG.r, CB_FG.g, CB_FG.b))
+            .error(ftui::Color::rgb(CB_KILL.r, CB_KILL.g, CB_KILL.b))
+            .warning(ftui::Color::rgb(CB_REVIEW.r, CB_REVIEW.g, CB_REVIEW.b))
+            .success(ftui::Color::rgb(CB_SPARE.r, CB_SPARE.g, CB_SPARE.b))
+            .primary(ftui::Color::rgb(
+                CB_HIGHLIGHT.r,
+                CB_HIGHLIGHT.g,
+                CB_HIGHLIGHT.b,
+            ))
+            .text_muted(ftui::Color::rgb(CB_MUTED.r, CB_MUTED.g, CB_MUTED.b))
+            .border(ftui::Color::rgb(CB_BORDER.r, CB_BORDER.g, CB_BORDER.b))
+            .border_focused(ftui::Color::rgb(
+                CB_BORDER_FOCUSED.r,
+                CB_BORDER_FOCUSED.g,
+                CB_BORDER_FOCUSED.b,
+            ))
+            .build();
+
+        let classification = ClassificationColors {
+            kill: CB_KILL,
+            review: CB_REVIEW,
+            spare: CB_SPARE,
+            bg: CB_BG,
+            fg: CB_FG,
+        };
+
+        let sheet = build_stylesheet(&classification, false);
+
+        Self {
+            mode: ThemeMode::Deuteranopia,
+            ftui_theme,
+            stylesheet: sheet,
+            classification,
+        }
+    }
+
     /// Create a no-color theme for terminals without color support.
     /// Respects the `NO_COLOR` environment variable (<https://no-color.org/>).
     pub fn no_color() -> Self {
@@ -292,6 +384,35 @@ impl Theme {
         self.stylesheet.get_or_default(name)
     }
 
+    /// Shape marker prefixed to a classification label.
+    ///
+    /// Empty for color themes; the deuteranopia theme uses distinct glyphs
+    /// so kill/review/spare never differ by color alone.
+    pub fn classification_marker(&self, classification: &str) -> &'static str {
+        if self.mode != ThemeMode::Deuteranopia {
+            return "";
+        }
+        match classification.to_uppercase().as_str() {
+            "KILL" => "\u{2716} ",
+            "REVIEW" => "\u{25C6} ",
+            "SPARE" => "\u{2713} ",
+            _ => "",
+        }
+    }
+
+    /// Shape marker prefixed to the status bar's selection count.
+    ///
+    /// Like [`classification_marker`](Self::classification_marker), only the
+    /// deuteranopia theme uses one, and it differs from every classification
+    /// marker so a selection is not mistaken for a KILL verdict.
+    pub fn selection_marker(&self) -> &'static str {
+        if self.mode == ThemeMode::Deuteranopia {
+            "\u{25CF} "
+        } else {
+            ""
+        }
+    }
+
     /// Get the current color profile based on terminal capabilities.
     pub fn color_profile() -> ColorProfile {
         ColorProfile::detect()
@@ -453,6 +574,21 @@ fn build_no_color_stylesheet() -> StyleSheet {
 mod tests {
     use super::*;
 
+    /// Style classes every theme's stylesheet must define.
+    const REQUIRED_CLASSES: &[&str] = &[
+        "classification.kill",
+        "classification.review",
+        "classification.spare",
+        "table.header",
+        "table.selected",
+        "search.highlight",
+        "status.error",
+        "status.warning",
+        "status.success",
+        "border.normal",
+        "border.focused",
+    ];
+
     #[test]
     fn test_default_theme_is_dark_or_env_driven() {
         // Default respects env vars, but with no env vars set it should be dark
@@ -543,6 +679,58 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_every_theme_defines_required_classes() {
+        for mode in ThemeMode::ALL {
+            let theme = Theme::from_mode(mode);
+            assert_eq!(theme.mode, mode);
+            for class in REQUIRED_CLASSES {
+                assert!(
+                    theme.stylesheet().contains(class),
+                    "Theme {mode:?} missing stylesheet class: {class}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_theme_mode_from_name() {
+        assert_eq!(ThemeMode::from_name("dark"), Some(ThemeMode::Dark));
+        assert_eq!(ThemeMode::from_name("HC"), Some(ThemeMode::HighContrast));
+        assert_eq!(
+            ThemeMode::from_name("deuteranopia"),
+            Some(ThemeMode::Deuteranopia)
+        );
+        assert_eq!(ThemeMode::from_name("neon"), None);
+    }
+
+    #[test]
+    fn test_deuteranopia_theme_is_accessible() {
+        let theme = Theme::deuteranopia();
+        let failures = theme.validate_wcag_aa();
+        assert!(
+            failures.is_empty(),
+            "Deuteranopia WCAG AA failures: {failures:?}"
+        );
+
+        // Kill and spare must differ in the blue channel, not just red/green.
+        assert!(CB_SPARE.b.abs_diff(CB_KILL.b) > 150);
+
+        let markers: Vec<&str> = ["KILL", "REVIEW", "SPARE"]
+            .iter()
+            .map(|c| theme.classification_marker(c))
+            .collect();
+        assert!(markers.iter().all(|m| !m.is_empty()));
+        assert_ne!(markers[0], markers[1]);
+        assert_ne!(markers[0], markers[2]);
+        assert_ne!(markers[1], markers[2]);
+        assert!(!markers.contains(&theme.selection_marker()));
+        assert!(!theme.selection_marker().is_empty());
+
+        assert_eq!(Theme::dark().classification_marker("KILL"), "");
+        assert_eq!(Theme::dark().selection_marker(), "");
+    }
+
     #[test]
     fn test_no_color_stylesheet_has_required_classes() {
         let theme = Theme::no_color();
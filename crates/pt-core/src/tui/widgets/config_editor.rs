@@ -2,6 +2,12 @@
 //!
 //! Form-based editor for modifying Process Triage configuration values.
 //! Uses ftui's Block and Paragraph for rendering.
+//!
+//! Fields are validated on every keystroke, so unparseable numbers are
+//! flagged immediately. When a base policy is attached, each field named by
+//! its dotted policy path (e.g. `fdr_control.alpha`) is also checked against
+//! the `pt-config` policy rules. Applying is blocked while any field carries
+//! an error.
 
 use ftui::text::{Line as FtuiLine, Span as FtuiSpan, Text as FtuiText};
 use ftui::widgets::block::{Alignment as FtuiAlignment, Block as FtuiBlock};
@@ -10,6 +16,10 @@ use ftui::widgets::Widget as FtuiWidget;
 use ftui::PackedRgba;
 use ftui::Style as FtuiStyle;
 
+use pt_config::validate::{validate_policy, ValidationError};
+use pt_config::Policy;
+use serde_json::Value;
+
 use crate::tui::theme::Theme;
 
 /// A configuration field with name, value, and type.
@@ -148,7 +158,9 @@ impl<'a> ConfigEditor<'a> {
         let focused = state.focused;
         let any_modified = state.fields.iter().any(|f| f.modified);
 
-        let title = if any_modified {
+        let title = if !state.is_valid() {
+            " Configuration [invalid] "
+        } else if any_modified {
             " Configuration [modified] "
         } else {
             " Configuration "
@@ -236,6 +248,8 @@ pub struct ConfigEditorState {
     pub cursor: usize,
     /// Whether currently editing a field.
     pub editing: bool,
+    /// Base policy that edited values are validated against.
+    policy: Option<Policy>,
 }
 
 impl Default for ConfigEditorState {
@@ -252,9 +266,16 @@ impl ConfigEditorState {
             fields: Vec::new(),
             cursor: 0,
             editing: false,
+            policy: None,
         }
     }
 
+    /// Validate fields against `policy` using the `pt-config` policy rules.
+    pub fn with_policy(mut self, policy: Policy) -> Self {
+        self.policy = Some(policy);
+        self
+    }
+
     /// Set the fields.
     pub fn set_fields(&mut self, fields: Vec<ConfigField>) {
         self.fields = fields;
@@ -301,8 +322,8 @@ impl ConfigEditorState {
             if let Some(field) = self.fields.get_mut(self.cursor) {
                 field.value.push(ch);
                 field.modified = true;
-                field.error = None;
             }
+            self.validate_current();
         }
     }
 
@@ -312,39 +333,48 @@ impl ConfigEditorState {
             if let Some(field) = self.fields.get_mut(self.cursor) {
                 field.value.pop();
                 field.modified = true;
-                field.error = None;
             }
+            self.validate_current();
+        }
+    }
+
+    /// Validate every field, returning whether all are valid.
+    pub fn validate_all(&mut self) -> bool {
+        for field in &mut self.fields {
+            field.error = validate_field(field, self.policy.as_ref());
+        }
+        self.is_valid()
+    }
+
+    /// Validate the modified fields and return them for applying.
+    ///
+    /// Fails with the first invalid field's error, moving the cursor there,
+    /// so nothing is applied while any field is invalid.
+    pub fn apply(&mut self) -> Result<Vec<ConfigField>, String> {
+        if !self.validate_all() {
+            let (idx, field) = self
+                .fields
+                .iter()
+                .enumerate()
+                .find(|(_, f)| f.error.is_some())
+                .expect("invalid state has an erroring field");
+            let message = format!(
+                "{}: {}",
+                field.name,
+                field.error.as_deref().unwrap_or_default()
+            );
+            self.cursor = idx;
+            return Err(message);
         }
+        let applied = self.fields.iter().filter(|f| f.modified).cloned().collect();
+        self.mark_saved();
+        Ok(applied)
     }
 
     /// Validate current field value.
     fn validate_current(&mut self) {
         if let Some(field) = self.fields.get_mut(self.cursor) {
-            field.error = match field.field_type {
-                ConfigFieldType::Integer => {
-                    if field.value.parse::<i64>().is_err() {
-                        Some("Invalid integer".to_string())
-                    } else {
-                        None
-                    }
-                }
-                ConfigFieldType::Float => {
-                    if field.value.parse::<f64>().is_err() {
-                        Some("Invalid number".to_string())
-                    } else {
-                        None
-                    }
-                }
-                ConfigFieldType::Boolean => {
-                    let v = field.value.to_lowercase();
-                    if !["true", "false", "yes", "no", "1", "0"].contains(&v.as_str()) {
-                        Some("Must be true/false".to_string())
-                    } else {
-                        None
-                    }
-                }
-                _ => None,
-            };
+            field.error = validate_field(field, self.policy.as_ref());
         }
     }
 
@@ -371,6 +401,60 @@ impl ConfigEditorState {
     }
 }
 
+/// Validate a field's value, returning the error to show inline.
+fn validate_field(field: &ConfigField, policy: Option<&Policy>) -> Option<String> {
+    let value = match parse_field_value(field) {
+        Ok(value) => value,
+        Err(message) => return Some(message),
+    };
+    policy.and_then(|policy| policy_error(policy, &field.name, value))
+}
+
+/// Parse a field's text according to its type.
+fn parse_field_value(field: &ConfigField) -> Result<Value, String> {
+    match field.field_type {
+        ConfigFieldType::Integer => field
+            .value
+            .parse::<i64>()
+            .map(Value::from)
+            .map_err(|_| "Invalid integer".to_string()),
+        ConfigFieldType::Float => match field.value.parse::<f64>() {
+            Ok(v) if v.is_finite() => Ok(Value::from(v)),
+            _ => Err("Invalid number".to_string()),
+        },
+        ConfigFieldType::Boolean => match field.value.to_lowercase().as_str() {
+            "true" | "yes" | "1" => Ok(Value::Bool(true)),
+            "false" | "no" | "0" => Ok(Value::Bool(false)),
+            _ => Err("Must be true/false".to_string()),
+        },
+        ConfigFieldType::Text | ConfigFieldType::Select => Ok(Value::String(field.value.clone())),
+    }
+}
+
+/// Substitute `value` at the dotted `path` of `policy` and run the policy
+/// rules, keeping only errors that concern this field.
+///
+/// Paths that do not name a policy value are not checked.
+fn policy_error(policy: &Policy, path: &str, value: Value) -> Option<String> {
+    let mut doc = serde_json::to_value(policy).ok()?;
+    let pointer = format!("/{}", path.replace('.', "/"));
+    *doc.pointer_mut(&pointer)? = value;
+    let candidate: Policy = match serde_json::from_value(doc) {
+        Ok(candidate) => candidate,
+        Err(e) => return Some(format!("Invalid value: {}", e)),
+    };
+    match validate_policy(&candidate) {
+        Err(ValidationError::InvalidValue { field, message })
+            if field == path
+                || field.starts_with(&format!("{}.", path))
+                || path.starts_with(&format!("{}.", field)) =>
+        {
+            Some(message)
+        }
+        _ => None,
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -567,6 +651,110 @@ mod tests {
         assert!(!state.editing);
     }
 
+    // ── Policy validation tests ─────────────────────────────────────
+
+    fn policy_fields() -> Vec<ConfigField> {
+        vec![
+            ConfigField {
+                name: "fdr_control.alpha".to_string(),
+                value: "0.05".to_string(),
+                field_type: ConfigFieldType::Float,
+                description: "FDR target".to_string(),
+                modified: false,
+                error: None,
+            },
+            ConfigField {
+                name: "loss_matrix.useful.kill".to_string(),
+                value: "100".to_string(),
+                field_type: ConfigFieldType::Float,
+                description: "Loss of killing a useful process".to_string(),
+                modified: false,
+                error: None,
+            },
+        ]
+    }
+
+    fn policy_state() -> ConfigEditorState {
+        let mut state = ConfigEditorState::new().with_policy(Policy::default());
+        state.set_fields(policy_fields());
+        state
+    }
+
+    fn retype(state: &mut ConfigEditorState, value: &str) {
+        state.start_edit();
+        while !state.fields[state.cursor].value.is_empty() {
+            state.backspace();
+        }
+        for ch in value.chars() {
+            state.type_char(ch);
+        }
+    }
+
+    #[test]
+    fn test_numeric_fields_flag_unparseable_input_immediately() {
+        let mut state = ConfigEditorState::new();
+        state.set_fields(sample_fields());
+        state.start_edit();
+        state.type_char('x');
+        assert_eq!(state.fields[0].error.as_deref(), Some("Invalid integer"));
+        assert!(!state.is_valid());
+
+        state.backspace();
+        assert!(state.fields[0].error.is_none());
+        assert!(state.is_valid());
+    }
+
+    #[test]
+    fn test_out_of_range_probability_flagged_on_edit() {
+        let mut state = policy_state();
+        retype(&mut state, "1.5");
+        let error = state.fields[0].error.as_deref().unwrap();
+        assert!(error.contains("[0, 1]"), "{error}");
+        assert!(!state.is_valid());
+
+        retype(&mut state, "0.1");
+        assert!(state.fields[0].error.is_none());
+        assert!(state.is_valid());
+    }
+
+    #[test]
+    fn test_negative_loss_flagged_on_edit() {
+        let mut state = policy_state();
+        state.cursor_down();
+        retype(&mut state, "-5");
+        let error = state.fields[1].error.as_deref().unwrap();
+        assert!(error.contains("non-negative"), "{error}");
+    }
+
+    #[test]
+    fn test_apply_blocked_while_invalid() {
+        let mut state = policy_state();
+        state.cursor_down();
+        retype(&mut state, "-5");
+        state.stop_edit();
+        state.cursor_up();
+
+        let err = state.apply().unwrap_err();
+        assert!(err.starts_with("loss_matrix.useful.kill"), "{err}");
+        assert_eq!(state.cursor, 1);
+        assert!(state.is_modified());
+
+        retype(&mut state, "50");
+        state.stop_edit();
+        let applied = state.apply().unwrap();
+        assert_eq!(applied.len(), 1);
+        assert_eq!(applied[0].value, "50");
+        assert!(!state.is_modified());
+    }
+
+    #[test]
+    fn test_unknown_policy_path_is_not_checked() {
+        let mut state = ConfigEditorState::new().with_policy(Policy::default());
+        state.set_fields(sample_fields());
+        retype(&mut state, "-3");
+        assert!(state.fields[0].error.is_none());
+    }
+
     // ── Builder tests ───────────────────────────────────────────────
 
     #[test]
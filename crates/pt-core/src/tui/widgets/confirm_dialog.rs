@@ -82,6 +82,25 @@ impl<'a> ConfirmDialog<'a> {
         self
     }
 
+    /// Normal and focused button styles, from the theme when one is set.
+    fn button_styles(&self) -> (FtuiStyle, FtuiStyle) {
+        if let Some(theme) = self.theme {
+            let sheet = theme.stylesheet();
+            (
+                sheet.get_or_default("border.normal"),
+                sheet.get_or_default("table.selected"),
+            )
+        } else {
+            (
+                FtuiStyle::default(),
+                FtuiStyle::new()
+                    .fg(PackedRgba::rgb(0, 0, 0))
+                    .bg(PackedRgba::rgb(0, 255, 255))
+                    .bold(),
+            )
+        }
+    }
+
     /// Set button labels.
     pub fn labels(mut self, yes: &'a str, no: &'a str) -> Self {
         self.yes_label = yes;
@@ -110,21 +129,7 @@ impl<'a> ConfirmDialog<'a> {
         };
 
         // Build button styles from theme
-        let (button_style, focused_style) = if let Some(theme) = self.theme {
-            let sheet = theme.stylesheet();
-            (
-                sheet.get_or_default("border.normal"),
-                sheet.get_or_default("table.selected"),
-            )
-        } else {
-            (
-                FtuiStyle::default(),
-                FtuiStyle::new()
-                    .fg(PackedRgba::rgb(0, 0, 0))
-                    .bg(PackedRgba::rgb(0, 255, 255))
-                    .bold(),
-            )
-        };
+        let (button_style, focused_style) = self.button_styles();
 
         // Build dialog with custom Yes/No buttons
         let dialog = FtuiDialog::custom(format!(" {} ", self.title), full_message)
@@ -165,21 +170,7 @@ impl<'a> ConfirmDialog<'a> {
             self.message.to_string()
         };
 
-        let (button_style, focused_style) = if let Some(theme) = self.theme {
-            let sheet = theme.stylesheet();
-            (
-                sheet.get_or_default("border.normal"),
-                sheet.get_or_default("table.selected"),
-            )
-        } else {
-            (
-                FtuiStyle::default(),
-                FtuiStyle::new()
-                    .fg(PackedRgba::rgb(0, 0, 0))
-                    .bg(PackedRgba::rgb(0, 255, 255))
-                    .bold(),
-            )
-        };
+        let (button_style, focused_style) = self.button_styles();
 
         let dialog = FtuiDialog::custom(format!(" {} ", self.title), full_message)
             .button(FtuiDialogButton::new(self.yes_label, "yes"))
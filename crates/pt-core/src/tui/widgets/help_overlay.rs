@@ -116,10 +116,18 @@ const ACTIONS: &[Binding] = &[
         key: "g",
         desc: "Galaxy-brain view",
     },
+    Binding {
+        key: "[ / ]",
+        desc: "Scroll evidence terms",
+    },
     Binding {
         key: "v",
         desc: "Toggle goal view",
     },
+    Binding {
+        key: "o / O",
+        desc: "Cycle sort column / order",
+    },
 ];
 
 const GENERAL: &[Binding] = &[
@@ -11,9 +11,16 @@ use ftui::widgets::Widget as FtuiWidget;
 use ftui::PackedRgba;
 use ftui::Style as FtuiStyle;
 
+use crate::inference::EvidenceContribution;
 use crate::tui::theme::Theme;
 use crate::tui::widgets::ProcessRow;
 
+/// Width of the influence bar drawn for each evidence contribution.
+const CONTRIBUTION_BAR_WIDTH: usize = 10;
+
+/// Width reserved for the feature name in a contribution line.
+const CONTRIBUTION_LABEL_WIDTH: usize = 16;
+
 /// Detail pane modes.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum DetailView {
@@ -30,6 +37,7 @@ pub struct ProcessDetail<'a> {
     row: Option<&'a ProcessRow>,
     selected: bool,
     view: DetailView,
+    contribution_scroll: usize,
 }
 
 impl<'a> Default for ProcessDetail<'a> {
@@ -46,6 +54,7 @@ impl<'a> ProcessDetail<'a> {
             row: None,
             selected: false,
             view: DetailView::Summary,
+            contribution_scroll: 0,
         }
     }
 
@@ -68,6 +77,14 @@ impl<'a> ProcessDetail<'a> {
         self
     }
 
+    /// Set the first evidence contribution shown in the summary view.
+    ///
+    /// Offsets past the end are clamped so the last page stays filled.
+    pub fn contribution_scroll(mut self, offset: usize) -> Self {
+        self.contribution_scroll = offset;
+        self
+    }
+
     // ── ftui style helpers ──────────────────────────────────────────
 
     fn classification_ftui_style(&self, classification: &str) -> FtuiStyle {
@@ -245,11 +262,16 @@ impl<'a> ProcessDetail<'a> {
             )]));
         }
 
-        for item in &row.top_evidence {
-            evidence.push(FtuiLine::from_spans([FtuiSpan::styled(
-                format!("\u{2022} {}", item),
-                self.value_ftui_style(),
-            )]));
+        if row.contributions.is_empty() {
+            for item in &row.top_evidence {
+                evidence.push(FtuiLine::from_spans([FtuiSpan::styled(
+                    format!("\u{2022} {}", item),
+                    self.value_ftui_style(),
+                )]));
+            }
+        } else {
+            let available = evidence_height.saturating_sub(evidence.len());
+            evidence.extend(self.build_contribution_lines(&row.contributions, available));
         }
 
         if evidence.len() > evidence_height {
@@ -294,6 +316,88 @@ impl<'a> ProcessDetail<'a> {
         (evidence, action)
     }
 
+    /// Contribution bars for at most `max_lines` lines, starting at the
+    /// current scroll offset, with a trailing hint when terms are hidden.
+    fn build_contribution_lines(
+        &self,
+        contributions: &[EvidenceContribution],
+        max_lines: usize,
+    ) -> Vec<FtuiLine> {
+        if max_lines == 0 {
+            return Vec::new();
+        }
+        let mut ordered: Vec<&EvidenceContribution> = contributions.iter().collect();
+        ordered.sort_by_key(|c| c.rank);
+
+        let total = ordered.len();
+        let visible = if total > max_lines {
+            max_lines.saturating_sub(1).max(1)
+        } else {
+            total
+        };
+        let offset = self.contribution_scroll.min(total - visible);
+        let max_influence = ordered
+            .iter()
+            .map(|c| c.influence.abs())
+            .fold(0.0_f64, f64::max);
+
+        let mut lines: Vec<FtuiLine> = ordered[offset..offset + visible]
+            .iter()
+            .map(|c| self.contribution_line(c, max_influence))
+            .collect();
+        if visible < total && lines.len() < max_lines {
+            lines.push(FtuiLine::from_spans([FtuiSpan::styled(
+                format!(
+                    "{}-{} of {} terms ([/] to scroll)",
+                    offset + 1,
+                    offset + visible,
+                    total
+                ),
+                self.label_ftui_style(),
+            )]));
+        }
+        lines
+    }
+
+    fn contribution_line(
+        &self,
+        contribution: &EvidenceContribution,
+        max_influence: f64,
+    ) -> FtuiLine {
+        let magnitude = contribution.influence.abs();
+        let filled = if max_influence > 0.0 && magnitude.is_finite() {
+            ((magnitude / max_influence) * CONTRIBUTION_BAR_WIDTH as f64).round() as usize
+        } else {
+            0
+        };
+        let filled = filled.min(CONTRIBUTION_BAR_WIDTH);
+        // Solid bars reinforced the decision, shaded bars argued against it.
+        let glyph = if contribution.influence >= 0.0 {
+            '\u{2588}'
+        } else {
+            '\u{2591}'
+        };
+        let bar: String = std::iter::repeat_n(glyph, filled)
+            .chain(std::iter::repeat_n(' ', CONTRIBUTION_BAR_WIDTH - filled))
+            .collect();
+
+        FtuiLine::from_spans([
+            FtuiSpan::styled(
+                format!(
+                    "{:<width$.width$} ",
+                    contribution.feature,
+                    width = CONTRIBUTION_LABEL_WIDTH
+                ),
+                self.label_ftui_style(),
+            ),
+            FtuiSpan::styled(bar, self.value_ftui_style()),
+            FtuiSpan::styled(
+                format!(" {:+.2}", contribution.influence),
+                self.value_ftui_style(),
+            ),
+        ])
+    }
+
     fn build_galaxy_brain_sections(
         &self,
         row: &ProcessRow,
@@ -452,6 +556,7 @@ mod tests {
         ProcessRow {
             pid: 4242,
             score: 91,
+            expected_loss: None,
             classification: "KILL".to_string(),
             runtime: "3h 12m".to_string(),
             memory: "1.2 GB".to_string(),
@@ -463,6 +568,7 @@ mod tests {
                 "runtime (2.8 bits toward abandoned)".to_string(),
                 "cpu_idle (1.6 bits toward abandoned)".to_string(),
             ],
+            contributions: Vec::new(),
             confidence: Some("high".to_string()),
             plan_preview: Vec::new(),
             provenance_headline: Some("Provenance: low blast radius; moderate evidence".to_string()),
@@ -483,6 +589,24 @@ mod tests {
         }
     }
 
+    fn contribution(feature: &str, influence: f64, rank: usize) -> EvidenceContribution {
+        EvidenceContribution {
+            feature: feature.to_string(),
+            log_likelihood_delta: crate::inference::ClassScores {
+                useful: -influence / 2.0,
+                useful_bad: 0.0,
+                abandoned: influence / 2.0,
+                zombie: 0.0,
+            },
+            influence,
+            rank,
+        }
+    }
+
+    fn line_text(line: &FtuiLine) -> String {
+        line.spans().iter().map(|s| s.content.as_ref()).collect()
+    }
+
     // ── DetailView enum ─────────────────────────────────────────────
 
     #[test]
@@ -616,6 +740,55 @@ mod tests {
         assert!(action.len() >= 3);
     }
 
+    #[test]
+    fn build_summary_lists_contributions_by_rank() {
+        let mut row = sample_row();
+        row.contributions = vec![
+            contribution("cpu_idle", -1.0, 2),
+            contribution("runtime", 4.0, 1),
+        ];
+        let d = ProcessDetail::new();
+        let (evidence, _) = d.build_summary_sections(&row, 10);
+        let text: Vec<String> = evidence.iter().map(line_text).collect();
+
+        assert_eq!(text.len(), 4);
+        assert!(text[2].starts_with("runtime"));
+        assert!(text[2].contains(&"\u{2588}".repeat(CONTRIBUTION_BAR_WIDTH)));
+        assert!(text[2].ends_with("+4.00"));
+        assert!(text[3].starts_with("cpu_idle"));
+        assert!(text[3].contains(&"\u{2591}".repeat(3)));
+        assert!(text[3].ends_with("-1.00"));
+        assert!(!text.iter().any(|t| t.contains("bits toward")));
+    }
+
+    #[test]
+    fn contribution_lines_scroll_and_clamp() {
+        let contributions: Vec<EvidenceContribution> = (1..=10)
+            .map(|rank| contribution(&format!("term_{rank}"), 10.0 - rank as f64, rank))
+            .collect();
+
+        let lines = ProcessDetail::new().build_contribution_lines(&contributions, 4);
+        let text: Vec<String> = lines.iter().map(line_text).collect();
+        assert_eq!(text.len(), 4);
+        assert!(text[0].starts_with("term_1 "));
+        assert_eq!(text[3], "1-3 of 10 terms ([/] to scroll)");
+
+        let lines = ProcessDetail::new()
+            .contribution_scroll(4)
+            .build_contribution_lines(&contributions, 4);
+        assert!(line_text(&lines[0]).starts_with("term_5 "));
+
+        let lines = ProcessDetail::new()
+            .contribution_scroll(usize::MAX)
+            .build_contribution_lines(&contributions, 4);
+        assert!(line_text(&lines[2]).starts_with("term_10"));
+        assert_eq!(line_text(&lines[3]), "8-10 of 10 terms ([/] to scroll)");
+
+        assert!(ProcessDetail::new()
+            .build_contribution_lines(&contributions, 0)
+            .is_empty());
+    }
+
     #[test]
     fn build_galaxy_brain_pending() {
         let row = sample_row();
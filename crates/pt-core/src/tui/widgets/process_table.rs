@@ -16,6 +16,7 @@ use ftui::Style as FtuiStyle;
 use crate::tui::theme::Theme;
 use crate::{
     decision::Action,
+    inference::EvidenceContribution,
     plan::{ActionConfidence, ActionRouting, Plan, PlanAction, PreCheck},
 };
 
@@ -35,6 +36,40 @@ pub enum SortColumn {
     Memory,
     /// Sort by command name.
     Command,
+    /// Sort by expected loss of the recommended action (missing last).
+    ExpectedLoss,
+}
+
+impl SortColumn {
+    /// Columns in the order the sort key cycles through them.
+    pub const CYCLE: [SortColumn; 7] = [
+        SortColumn::Score,
+        SortColumn::ExpectedLoss,
+        SortColumn::Pid,
+        SortColumn::Classification,
+        SortColumn::Runtime,
+        SortColumn::Memory,
+        SortColumn::Command,
+    ];
+
+    /// Next column in [`SortColumn::CYCLE`], wrapping around.
+    pub fn next(self) -> Self {
+        let idx = Self::CYCLE.iter().position(|c| *c == self).unwrap_or(0);
+        Self::CYCLE[(idx + 1) % Self::CYCLE.len()]
+    }
+
+    /// Short label for the title bar.
+    pub fn label(self) -> &'static str {
+        match self {
+            SortColumn::Pid => "pid",
+            SortColumn::Score => "score",
+            SortColumn::Classification => "class",
+            SortColumn::Runtime => "runtime",
+            SortColumn::Memory => "memory",
+            SortColumn::Command => "command",
+            SortColumn::ExpectedLoss => "loss",
+        }
+    }
 }
 
 /// Sort order.
@@ -64,6 +99,8 @@ pub struct ProcessRow {
     pub pid: u32,
     /// Process score (0-100+).
     pub score: u32,
+    /// Expected loss of the recommended action, if computed.
+    pub expected_loss: Option<f64>,
     /// Classification label (KILL, REVIEW, SPARE).
     pub classification: String,
     /// Runtime in human-readable format.
@@ -80,6 +117,8 @@ pub struct ProcessRow {
     pub why_summary: Option<String>,
     /// Top evidence lines (human-readable).
     pub top_evidence: Vec<String>,
+    /// Per-term posterior contributions, most influential first.
+    pub contributions: Vec<EvidenceContribution>,
     /// Confidence label for the classification.
     pub confidence: Option<String>,
     /// Preview lines for the planned actions (stage/prechecks/confidence).
@@ -336,8 +375,13 @@ impl<'a> ProcessTable<'a> {
                 }
 
                 // Classification (styled)
+                let marker = self
+                    .theme
+                    .map(|t| t.classification_marker(&row.classification))
+                    .unwrap_or_default();
+                let class_label = format!("{}{}", marker, row.classification);
                 cells.push(FtuiText::from_line(FtuiLine::from_spans([
-                    FtuiSpan::styled(row.classification.clone(), class_style),
+                    FtuiSpan::styled(class_label, class_style),
                 ])));
 
                 // Runtime
@@ -408,11 +452,7 @@ impl<'a> FtuiStatefulWidget for ProcessTable<'a> {
         let visible = state.visible_rows();
 
         if visible.is_empty() {
-            let msg = if state.filter.is_some() {
-                "No matching processes"
-            } else {
-                "No process candidates found"
-            };
+            let msg = state.empty_message();
             let muted_style = self
                 .theme
                 .map(|t| t.class("status.warning"))
@@ -470,11 +510,7 @@ impl<'a> ProcessTable<'a> {
         let visible = state.visible_rows();
 
         if visible.is_empty() {
-            let msg = if state.filter.is_some() {
-                "No matching processes"
-            } else {
-                "No process candidates found"
-            };
+            let msg = state.empty_message();
             let muted_style = self
                 .theme
                 .map(|t| t.class("status.warning"))
@@ -633,10 +669,37 @@ impl ProcessTableState {
     }
 
     /// Set the filter query.
+    ///
+    /// The cursor stays on the same PID if that row still matches;
+    /// otherwise it returns to the top.
     pub fn set_filter(&mut self, filter: Option<String>) {
+        let focused_pid = self.current_row().map(|row| row.pid);
         self.filter = filter;
         self.cursor = 0;
         self.scroll_offset = 0;
+        if let Some(pid) = focused_pid {
+            if let Some(idx) = self.visible_rows().iter().position(|row| row.pid == pid) {
+                self.cursor = idx;
+                self.ensure_cursor_visible();
+            }
+        }
+    }
+
+    /// `(matching, total)` row counts under the current filter.
+    pub fn match_counts(&self) -> (usize, usize) {
+        (self.visible_rows().len(), self.rows.len())
+    }
+
+    /// Placeholder text shown when no rows are visible.
+    pub fn empty_message(&self) -> String {
+        match self.filter {
+            Some(ref filter) => format!(
+                "No processes match \"{}\" (0 of {})",
+                filter,
+                self.rows.len()
+            ),
+            None => "No process candidates found".to_string(),
+        }
     }
 
     /// Get visible rows (after filtering).
@@ -787,7 +850,43 @@ impl ProcessTableState {
     pub fn set_sort(&mut self, column: SortColumn, order: SortOrder) {
         self.sort_column = column;
         self.sort_order = order;
-        self.sort();
+        self.resort();
+    }
+
+    /// Advance to the next sort column, keeping the current order.
+    pub fn cycle_sort_column(&mut self) {
+        self.sort_column = self.sort_column.next();
+        self.resort();
+    }
+
+    /// Flip the sort order on the current column.
+    pub fn toggle_sort_order(&mut self) {
+        self.sort_order = match self.sort_order {
+            SortOrder::Ascending => SortOrder::Descending,
+            SortOrder::Descending => SortOrder::Ascending,
+        };
+        self.resort();
+    }
+
+    /// Current sort column and direction, e.g. `score ▼`.
+    pub fn sort_label(&self) -> String {
+        let arrow = match self.sort_order {
+            SortOrder::Ascending => "▲",
+            SortOrder::Descending => "▼",
+        };
+        format!("{} {}", self.sort_column.label(), arrow)
+    }
+
+    /// Handle a sort key: `o` cycles the column, `O` flips the order.
+    ///
+    /// Returns true if the key was consumed.
+    pub fn handle_sort_key(&mut self, key: char) -> bool {
+        match key {
+            'o' => self.cycle_sort_column(),
+            'O' => self.toggle_sort_order(),
+            _ => return false,
+        }
+        true
     }
 
     /// Toggle sort on a column.
@@ -801,7 +900,19 @@ impl ProcessTableState {
             self.sort_column = column;
             self.sort_order = SortOrder::Descending;
         }
+        self.resort();
+    }
+
+    /// Re-sort in place, keeping the cursor on the same PID.
+    fn resort(&mut self) {
+        let focused_pid = self.current_row().map(|row| row.pid);
         self.sort();
+        if let Some(pid) = focused_pid {
+            if let Some(idx) = self.visible_rows().iter().position(|row| row.pid == pid) {
+                self.cursor = idx;
+                self.ensure_cursor_visible();
+            }
+        }
     }
 
     /// Sort rows by current column and order.
@@ -825,6 +936,10 @@ impl ProcessTableState {
                 SortColumn::Runtime => a.runtime.cmp(&b.runtime),
                 SortColumn::Memory => a.memory.cmp(&b.memory),
                 SortColumn::Command => a.command.cmp(&b.command),
+                // Missing values stay last in either direction.
+                SortColumn::ExpectedLoss => {
+                    return compare_expected_loss(a.expected_loss, b.expected_loss, order)
+                }
             };
             match order {
                 SortOrder::Ascending => cmp,
@@ -834,6 +949,22 @@ impl ProcessTableState {
     }
 }
 
+/// Order expected losses, always placing missing or NaN values last.
+fn compare_expected_loss(a: Option<f64>, b: Option<f64>, order: SortOrder) -> std::cmp::Ordering {
+    use std::cmp::Ordering;
+    let a = a.filter(|v| !v.is_nan());
+    let b = b.filter(|v| !v.is_nan());
+    match (a, b) {
+        (Some(a), Some(b)) => match order {
+            SortOrder::Ascending => a.total_cmp(&b),
+            SortOrder::Descending => b.total_cmp(&a),
+        },
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Plan preview helpers
 // ---------------------------------------------------------------------------
@@ -922,6 +1053,7 @@ mod tests {
             ProcessRow {
                 pid: 1234,
                 score: 85,
+                expected_loss: None,
                 classification: "KILL".to_string(),
                 runtime: "2h 30m".to_string(),
                 memory: "512 MB".to_string(),
@@ -930,6 +1062,7 @@ mod tests {
                 galaxy_brain: None,
                 why_summary: Some("Classified as abandoned with high confidence.".to_string()),
                 top_evidence: vec!["runtime (2.4 bits toward abandoned)".to_string()],
+                contributions: Vec::new(),
                 confidence: Some("high".to_string()),
                 plan_preview: Vec::new(),
                 provenance_headline: None,
@@ -940,6 +1073,7 @@ mod tests {
             ProcessRow {
                 pid: 5678,
                 score: 35,
+                expected_loss: None,
                 classification: "REVIEW".to_string(),
                 runtime: "1h 15m".to_string(),
                 memory: "256 MB".to_string(),
@@ -948,12 +1082,18 @@ mod tests {
                 galaxy_brain: None,
                 why_summary: None,
                 top_evidence: Vec::new(),
+                contributions: Vec::new(),
                 confidence: Some("medium".to_string()),
                 plan_preview: Vec::new(),
+                provenance_headline: None,
+                provenance_sections: Vec::new(),
+                provenance_caveats: Vec::new(),
+                blast_radius_risk: None,
             },
             ProcessRow {
                 pid: 9012,
                 score: 15,
+                expected_loss: None,
                 classification: "SPARE".to_string(),
                 runtime: "30m".to_string(),
                 memory: "128 MB".to_string(),
@@ -962,8 +1102,13 @@ mod tests {
                 galaxy_brain: None,
                 why_summary: None,
                 top_evidence: Vec::new(),
+                contributions: Vec::new(),
                 confidence: Some("low".to_string()),
                 plan_preview: Vec::new(),
+                provenance_headline: None,
+                provenance_sections: Vec::new(),
+                provenance_caveats: Vec::new(),
+                blast_radius_risk: None,
             },
         ]
     }
@@ -1059,6 +1204,27 @@ mod tests {
         assert_eq!(state.visible_rows().len(), 3);
     }
 
+    #[test]
+    fn test_filter_preserves_surviving_cursor_row() {
+        let mut state = ProcessTableState::new();
+        state.set_rows(sample_rows());
+        state.cursor_down();
+        assert_eq!(state.current_row().unwrap().pid, 5678);
+
+        state.set_filter(Some("d".to_string()));
+        assert_eq!(state.current_row().unwrap().pid, 5678);
+        assert_eq!(state.match_counts(), (2, 3));
+
+        state.set_filter(Some("zzz".to_string()));
+        assert_eq!(state.match_counts(), (0, 3));
+        assert!(state.current_row().is_none());
+        assert_eq!(state.empty_message(), "No processes match \"zzz\" (0 of 3)");
+
+        state.set_filter(None);
+        assert_eq!(state.match_counts(), (3, 3));
+        assert_eq!(state.cursor, 0);
+    }
+
     #[test]
     fn test_current_row_reflects_filter() {
         let mut state = ProcessTableState::new();
@@ -1249,6 +1415,72 @@ mod tests {
         assert_eq!(state.rows[0].pid, 1234);
     }
 
+    #[test]
+    fn test_sort_keys_cycle_column_and_toggle_order() {
+        let mut state = ProcessTableState::new();
+        state.set_rows(sample_rows());
+        assert_eq!(state.sort_column, SortColumn::Score);
+
+        assert!(state.handle_sort_key('o'));
+        assert_eq!(state.sort_column, SortColumn::ExpectedLoss);
+        assert_eq!(state.sort_order, SortOrder::Descending);
+
+        assert!(state.handle_sort_key('O'));
+        assert_eq!(state.sort_column, SortColumn::ExpectedLoss);
+        assert_eq!(state.sort_order, SortOrder::Ascending);
+        assert_eq!(state.sort_label(), "loss ▲");
+
+        assert!(!state.handle_sort_key('z'));
+
+        for _ in 0..SortColumn::CYCLE.len() {
+            state.handle_sort_key('o');
+        }
+        assert_eq!(state.sort_column, SortColumn::ExpectedLoss);
+    }
+
+    #[test]
+    fn test_resort_keeps_cursor_on_same_pid() {
+        let mut state = ProcessTableState::new();
+        state.set_rows(sample_rows());
+        state.cursor_down();
+        assert_eq!(state.current_row().unwrap().pid, 5678);
+
+        state.set_sort(SortColumn::Pid, SortOrder::Descending);
+        assert_eq!(state.rows[0].pid, 9012);
+        assert_eq!(state.current_row().unwrap().pid, 5678);
+
+        state.cursor_home();
+        state.toggle_sort_order();
+        assert_eq!(state.rows[0].pid, 1234);
+        assert_eq!(state.current_row().unwrap().pid, 9012);
+        assert_eq!(state.cursor, 2);
+    }
+
+    #[test]
+    fn test_expected_loss_sort_puts_missing_last() {
+        let mut rows = sample_rows();
+        rows[0].expected_loss = Some(f64::NAN);
+        rows[1].expected_loss = Some(2.5);
+        rows[2].expected_loss = Some(0.5);
+        let mut extra = rows[2].clone();
+        extra.pid = 4444;
+        extra.expected_loss = None;
+        rows.push(extra);
+
+        let mut state = ProcessTableState::new();
+        state.set_rows(rows);
+
+        state.set_sort(SortColumn::ExpectedLoss, SortOrder::Descending);
+        let pids: Vec<u32> = state.rows.iter().map(|r| r.pid).collect();
+        assert_eq!(&pids[..2], &[5678, 9012]);
+        assert!(pids[2..].contains(&1234) && pids[2..].contains(&4444));
+
+        state.toggle_sort_order();
+        let pids: Vec<u32> = state.rows.iter().map(|r| r.pid).collect();
+        assert_eq!(&pids[..2], &[9012, 5678]);
+        assert!(pids[2..].contains(&1234) && pids[2..].contains(&4444));
+    }
+
     // ── Column visibility tests ───────────────────────────────────────
 
     #[test]
@@ -72,6 +72,8 @@ pub struct StatusBar<'a> {
     selected_count: usize,
     /// Active filter text (if any).
     filter: Option<&'a str>,
+    /// `(matching, total)` rows under the active filter.
+    match_count: Option<(usize, usize)>,
     /// Custom status message (overrides auto-generated content).
     message: Option<&'a str>,
 }
@@ -90,6 +92,7 @@ impl<'a> StatusBar<'a> {
             mode: StatusMode::Normal,
             selected_count: 0,
             filter: None,
+            match_count: None,
             message: None,
         }
     }
@@ -118,6 +121,12 @@ impl<'a> StatusBar<'a> {
         self
     }
 
+    /// Set how many rows match the active filter out of the total.
+    pub fn match_count(mut self, matching: usize, total: usize) -> Self {
+        self.match_count = Some((matching, total));
+        self
+    }
+
     /// Set a custom status message (overrides auto-generated content).
     pub fn message(mut self, message: &'a str) -> Self {
         self.message = Some(message);
@@ -135,12 +144,19 @@ impl<'a> StatusBar<'a> {
         let mut parts = Vec::new();
 
         if self.selected_count > 0 {
-            parts.push(format!("{} selected", self.selected_count));
+            let marker = self.theme.map(|t| t.selection_marker()).unwrap_or_default();
+            parts.push(format!("{}{} selected", marker, self.selected_count));
         }
 
         if let Some(filter) = self.filter {
             if !filter.is_empty() {
-                parts.push(format!("Filter: \"{}\"", filter));
+                match self.match_count {
+                    Some((matching, total)) => parts.push(format!(
+                        "Filter: \"{}\" ({} of {})",
+                        filter, matching, total
+                    )),
+                    None => parts.push(format!("Filter: \"{}\"", filter)),
+                }
             }
         }
 
@@ -242,6 +258,27 @@ mod tests {
         assert!(text.contains("Filter: \"node\""));
     }
 
+    #[test]
+    fn test_build_left_colorblind_marker() {
+        let theme = Theme::deuteranopia();
+        let bar = StatusBar::new().theme(&theme).selected_count(2);
+        assert_eq!(bar.build_left_text(), "\u{25CF} 2 selected");
+
+        let dark = Theme::dark();
+        let bar = StatusBar::new().theme(&dark).selected_count(2);
+        assert_eq!(bar.build_left_text(), "2 selected");
+    }
+
+    #[test]
+    fn test_build_left_with_match_count() {
+        let bar = StatusBar::new().filter("node").match_count(2, 7);
+        assert_eq!(bar.build_left_text(), "Filter: \"node\" (2 of 7)");
+
+        // Counts only show alongside an active filter.
+        let bar = StatusBar::new().match_count(7, 7);
+        assert_eq!(bar.build_left_text(), "Ready");
+    }
+
     #[test]
     fn test_build_left_custom_message() {
         let bar = StatusBar::new().selected_count(3).message("Custom status");
@@ -546,6 +546,7 @@ mod staged_kill_escalation {
             poll_interval_ms: 100,
             verify_timeout_ms: 5000,
             use_process_groups: false,
+            ..Default::default()
         });
 
         let kill_action = make_kill_action(pid, "e2e-graceful-kill", vec![]);
@@ -609,6 +610,7 @@ mod staged_kill_escalation {
             poll_interval_ms: 50,
             verify_timeout_ms: 5000,
             use_process_groups: false,
+            ..Default::default()
         });
 
         let kill_action = make_kill_action(pid, "e2e-force-kill", vec![]);
@@ -363,7 +363,7 @@ fn test_check_with_invalid_policy_json_in_config_dir() {
         ])
         .assert()
         .failure()
-        .code(10) // ArgsError - parse failure
+        .code(17) // ConfigError - parse failure
         .get_output()
         .stdout
         .clone();
@@ -393,7 +393,7 @@ fn test_check_with_invalid_priors_json_in_config_dir() {
         ])
         .assert()
         .failure()
-        .code(10) // ArgsError
+        .code(17) // ConfigError
         .get_output()
         .stdout
         .clone();
@@ -71,6 +71,39 @@ mod plan_basic {
             .stdout(predicate::str::contains("session_id"));
     }
 
+    #[test]
+    fn plan_adopts_session_id_from_env() {
+        let data_dir = tempdir().expect("temp data dir");
+        let sid = "pt-20260115-143022-a7xq";
+        let stdout = pt_core_fast()
+            .env("PROCESS_TRIAGE_DATA", data_dir.path())
+            .env("PT_SESSION_ID", sid)
+            .args([
+                "--format",
+                "json",
+                "agent",
+                "plan",
+                "--sample-size",
+                TEST_SAMPLE_SIZE,
+            ])
+            .assert()
+            .code(predicate::in_iter([0, 1]))
+            .get_output()
+            .stdout
+            .clone();
+        let plan: Value = serde_json::from_slice(&stdout).expect("plan JSON");
+        assert_eq!(plan["session_id"], sid);
+
+        // A malformed value is rejected instead of starting a fresh session.
+        pt_core_fast()
+            .env("PROCESS_TRIAGE_DATA", data_dir.path())
+            .env("PT_SESSION_ID", "pt-bogus")
+            .args(["agent", "plan", "--sample-size", TEST_SAMPLE_SIZE])
+            .assert()
+            .code(10)
+            .stderr(predicate::str::contains("PT_SESSION_ID"));
+    }
+
     #[test]
     fn plan_produces_valid_json() {
         let output = pt_core_fast()
@@ -515,6 +548,67 @@ mod plan_formats {
             .stdout(predicate::str::contains("pt-core"));
     }
 
+    #[test]
+    fn plan_html_format() {
+        pt_core_fast()
+            .args([
+                "--format",
+                "html",
+                "agent",
+                "plan",
+                "--sample-size",
+                TEST_SAMPLE_SIZE,
+            ])
+            .assert()
+            .code(predicate::in_iter([0, 1]))
+            .stdout(predicate::str::starts_with("<!DOCTYPE html>"))
+            .stdout(predicate::str::contains("</html>"));
+    }
+
+    #[test]
+    fn plan_csv_format() {
+        pt_core_fast()
+            .args([
+                "--format",
+                "csv",
+                "agent",
+                "plan",
+                "--sample-size",
+                TEST_SAMPLE_SIZE,
+            ])
+            .assert()
+            .code(predicate::in_iter([0, 1]))
+            .stdout(predicate::str::starts_with(
+                "pid,command,classification,recommended_action,posterior_useful,posterior_abandoned\r\n",
+            ));
+    }
+
+    #[test]
+    fn plan_ndjson_format() {
+        let output = pt_core_fast()
+            .args([
+                "--format",
+                "ndjson",
+                "agent",
+                "plan",
+                "--sample-size",
+                TEST_SAMPLE_SIZE,
+            ])
+            .assert()
+            .code(predicate::in_iter([0, 1]))
+            .get_output()
+            .stdout
+            .clone();
+        let text = String::from_utf8(output).expect("utf-8 stdout");
+        let records: Vec<Value> = text
+            .lines()
+            .map(|line| serde_json::from_str(line).expect("each line is a JSON object"))
+            .collect();
+        assert_eq!(records[0]["record"], "metadata");
+        assert!(records[0]["schema_version"].is_string());
+        assert!(records[1..].iter().all(|r| r["pid"].is_u64()));
+    }
+
     #[test]
     fn plan_exitcode_format() {
         // Exitcode format produces no output on success
@@ -584,8 +584,8 @@ mod negative_paths {
                 TEST_SAMPLE_SIZE,
             ])
             .assert()
-            // Exit 20 = config load failure, which is the expected graceful error
-            .code(predicate::in_iter([0, 1, 2, 3, 20]))
+            // Exit 17 = config load failure (ERR_CONFIG), which is the expected graceful error
+            .code(predicate::in_iter([0, 1, 2, 3, 17]))
             .get_output()
             .clone();
 
@@ -637,8 +637,8 @@ mod negative_paths {
                 TEST_SAMPLE_SIZE,
             ])
             .assert()
-            // Exit 20 = config validation failure is acceptable for malformed input
-            .code(predicate::in_iter([0, 1, 2, 3, 20]));
+            // Exit 17 = config validation failure (ERR_CONFIG) is acceptable for malformed input
+            .code(predicate::in_iter([0, 1, 2, 3, 17]));
     }
 
     #[test]
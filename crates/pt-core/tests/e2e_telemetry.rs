@@ -1,4 +1,4 @@
-//! CLI E2E tests for `pt-core telemetry status` and `pt-core telemetry prune`.
+//! CLI E2E tests for `pt-core telemetry status`, `prune` and `schema`.
 //!
 //! Validates:
 //! - Exit codes for success and failure paths
@@ -559,3 +559,56 @@ fn test_telemetry_prune_with_aggressive_config() {
     );
     assert!(!file.exists(), "file should be deleted");
 }
+
+// ============================================================================
+// Schema Command Tests
+// ============================================================================
+
+#[test]
+fn test_telemetry_schema_all_tables() {
+    let output = pt_core()
+        .args(["telemetry", "schema"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    let json: Value = serde_json::from_slice(&output).expect("parse JSON output");
+    for table in [
+        "runs",
+        "proc_samples",
+        "proc_features",
+        "proc_inference",
+        "outcomes",
+        "audit",
+    ] {
+        assert_eq!(json[table]["title"], table);
+        assert!(json[table]["properties"]["session_id"].is_object());
+    }
+}
+
+#[test]
+fn test_telemetry_schema_single_table() {
+    let output = pt_core()
+        .args(["telemetry", "schema", "--table", "audit"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    let json: Value = serde_json::from_slice(&output).expect("parse JSON output");
+    assert_eq!(json["title"], "audit");
+    assert_eq!(json["properties"]["event_type"]["type"], "string");
+    assert!(json["x-schema-version"].is_string());
+}
+
+#[test]
+fn test_telemetry_schema_unknown_table_exit_code() {
+    pt_core()
+        .args(["telemetry", "schema", "--table", "bogus"])
+        .assert()
+        .code(10)
+        .stderr(predicate::str::contains("unknown table"));
+}
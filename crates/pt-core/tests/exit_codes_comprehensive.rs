@@ -584,6 +584,7 @@ mod stability {
             16,
             "IdentityError must be 16"
         );
+        assert_eq!(ExitCode::ConfigError.as_i32(), 17, "ConfigError must be 17");
 
         // Internal Errors (20-29)
         assert_eq!(
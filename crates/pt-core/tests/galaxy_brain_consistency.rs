@@ -430,22 +430,22 @@ fn test_evidence_ledger_classification_matches_posterior() {
     );
 
     // Test with abandoned-dominant posterior
-    let abandoned_result = PosteriorResult {
-        posterior: ClassScores {
+    let abandoned_result = PosteriorResult::new(
+        ClassScores {
             useful: 0.1,
             useful_bad: 0.05,
             abandoned: 0.8,
             zombie: 0.05,
         },
-        log_posterior: ClassScores {
+        ClassScores {
             useful: 0.1_f64.ln(),
             useful_bad: 0.05_f64.ln(),
             abandoned: 0.8_f64.ln(),
             zombie: 0.05_f64.ln(),
         },
-        log_odds_abandoned_useful: (0.8 / 0.1_f64).ln(),
-        evidence_terms: vec![],
-    };
+        (0.8 / 0.1_f64).ln(),
+        vec![],
+    );
 
     let ledger = EvidenceLedger::from_posterior_result(&abandoned_result, None, None);
     assert_eq!(
@@ -456,22 +456,22 @@ fn test_evidence_ledger_classification_matches_posterior() {
     );
 
     // Test with useful-dominant posterior
-    let useful_result = PosteriorResult {
-        posterior: ClassScores {
+    let useful_result = PosteriorResult::new(
+        ClassScores {
             useful: 0.85,
             useful_bad: 0.05,
             abandoned: 0.05,
             zombie: 0.05,
         },
-        log_posterior: ClassScores {
+        ClassScores {
             useful: 0.85_f64.ln(),
             useful_bad: 0.05_f64.ln(),
             abandoned: 0.05_f64.ln(),
             zombie: 0.05_f64.ln(),
         },
-        log_odds_abandoned_useful: (0.05 / 0.85_f64).ln(),
-        evidence_terms: vec![],
-    };
+        (0.05 / 0.85_f64).ln(),
+        vec![],
+    );
 
     let ledger = EvidenceLedger::from_posterior_result(&useful_result, None, None);
     assert_eq!(
@@ -489,62 +489,62 @@ fn test_evidence_ledger_confidence_thresholds() {
     log_test!("INFO", "Testing EvidenceLedger confidence thresholds");
 
     // VeryHigh: > 0.99
-    let very_high = PosteriorResult {
-        posterior: ClassScores {
+    let very_high = PosteriorResult::new(
+        ClassScores {
             useful: 0.995,
             useful_bad: 0.002,
             abandoned: 0.002,
             zombie: 0.001,
         },
-        log_posterior: ClassScores::default(),
-        log_odds_abandoned_useful: 0.0,
-        evidence_terms: vec![],
-    };
+        ClassScores::default(),
+        0.0,
+        vec![],
+    );
     let ledger = EvidenceLedger::from_posterior_result(&very_high, None, None);
     assert_eq!(ledger.confidence, Confidence::VeryHigh);
 
     // High: > 0.95, <= 0.99
-    let high = PosteriorResult {
-        posterior: ClassScores {
+    let high = PosteriorResult::new(
+        ClassScores {
             useful: 0.97,
             useful_bad: 0.01,
             abandoned: 0.01,
             zombie: 0.01,
         },
-        log_posterior: ClassScores::default(),
-        log_odds_abandoned_useful: 0.0,
-        evidence_terms: vec![],
-    };
+        ClassScores::default(),
+        0.0,
+        vec![],
+    );
     let ledger = EvidenceLedger::from_posterior_result(&high, None, None);
     assert_eq!(ledger.confidence, Confidence::High);
 
     // Medium: > 0.80, <= 0.95
-    let medium = PosteriorResult {
-        posterior: ClassScores {
+    let medium = PosteriorResult::new(
+        ClassScores {
             useful: 0.85,
             useful_bad: 0.05,
             abandoned: 0.05,
             zombie: 0.05,
         },
-        log_posterior: ClassScores::default(),
-        log_odds_abandoned_useful: 0.0,
-        evidence_terms: vec![],
-    };
+        ClassScores::default(),
+        0.0,
+        vec![],
+    );
     let ledger = EvidenceLedger::from_posterior_result(&medium, None, None);
     assert_eq!(ledger.confidence, Confidence::Medium);
 
     // Low: <= 0.80
-    let low = PosteriorResult {
-        posterior: ClassScores {
+    let low = PosteriorResult::new(
+        ClassScores {
             useful: 0.75,
             useful_bad: 0.10,
             abandoned: 0.10,
             zombie: 0.05,
         },
-        log_posterior: ClassScores::default(),
-        log_odds_abandoned_useful: 0.0,
-        evidence_terms: vec![],
-    };
+        ClassScores::default(),
+        0.0,
+        vec![],
+    );
     let ledger = EvidenceLedger::from_posterior_result(&low, None, None);
     assert_eq!(ledger.confidence, Confidence::Low);
 
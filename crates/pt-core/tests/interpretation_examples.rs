@@ -39,7 +39,9 @@ fn get_action_tier(action: Action) -> ActionTier {
             ActionTier::Review
         }
         Action::Kill | Action::Restart => ActionTier::Act,
-        Action::Resume | Action::Unfreeze | Action::Unquarantine => ActionTier::Keep,
+        Action::Resume | Action::Unfreeze | Action::Unthrottle | Action::Unquarantine => {
+            ActionTier::Keep
+        }
     }
 }
 
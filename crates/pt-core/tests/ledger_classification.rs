@@ -3,22 +3,22 @@ use pt_core::inference::posterior::{ClassScores, PosteriorResult};
 
 #[test]
 fn test_ledger_classification_useful_bad() {
-    let result = PosteriorResult {
-        posterior: ClassScores {
+    let result = PosteriorResult::new(
+        ClassScores {
             useful: 0.1,
             useful_bad: 0.8,
             abandoned: 0.05,
             zombie: 0.05,
         },
-        log_posterior: ClassScores {
+        ClassScores {
             useful: (0.1f64).ln(),
             useful_bad: (0.8f64).ln(),
             abandoned: (0.05f64).ln(),
             zombie: (0.05f64).ln(),
         },
-        log_odds_abandoned_useful: (0.05f64 / 0.1f64).ln(),
-        evidence_terms: vec![],
-    };
+        (0.05f64 / 0.1f64).ln(),
+        vec![],
+    );
 
     let ledger = EvidenceLedger::from_posterior_result(&result, None, None);
 
@@ -32,22 +32,22 @@ fn test_ledger_classification_useful_bad() {
 
 #[test]
 fn test_ledger_classification_zombie() {
-    let result = PosteriorResult {
-        posterior: ClassScores {
+    let result = PosteriorResult::new(
+        ClassScores {
             useful: 0.05,
             useful_bad: 0.05,
             abandoned: 0.1,
             zombie: 0.8,
         },
-        log_posterior: ClassScores {
+        ClassScores {
             useful: (0.05f64).ln(),
             useful_bad: (0.05f64).ln(),
             abandoned: (0.1f64).ln(),
             zombie: (0.8f64).ln(),
         },
-        log_odds_abandoned_useful: (0.1f64 / 0.05f64).ln(),
-        evidence_terms: vec![],
-    };
+        (0.1f64 / 0.05f64).ln(),
+        vec![],
+    );
 
     let ledger = EvidenceLedger::from_posterior_result(&result, None, None);
 
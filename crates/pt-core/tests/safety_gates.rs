@@ -93,6 +93,7 @@ fn make_test_plan(pid: u32, uid: u32, pre_checks: Vec<PreCheck>) -> Plan {
             memory_mb: None,
             has_known_signature: None,
             category: None,
+            protection: None,
         },
         risk_sensitive: None,
         dro: None,
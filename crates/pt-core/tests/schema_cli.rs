@@ -178,3 +178,36 @@ fn schema_unknown_type_returns_error_with_hint() {
         ))
         .stderr(predicate::str::contains("pt schema --list"));
 }
+
+#[test]
+fn schema_dump_exports_versioned_output_schemas() {
+    let output = pt_core()
+        .args(["--format", "json", "schema", "dump"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    let json: Value = serde_json::from_slice(&output).expect("schema dump should return JSON");
+    let schemas = json
+        .as_object()
+        .expect("schema dump should return an object keyed by type");
+    for name in [
+        "ScanResult",
+        "Plan",
+        "DecisionOutcome",
+        "DroOutcome",
+        "VoiAnalysis",
+    ] {
+        let schema = schemas
+            .get(name)
+            .unwrap_or_else(|| panic!("schema dump should include {}", name));
+        assert_eq!(
+            schema["x-schema-version"],
+            pt_common::SCHEMA_VERSION,
+            "{} should carry the schema version",
+            name
+        );
+    }
+}
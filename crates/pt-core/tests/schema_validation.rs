@@ -723,6 +723,9 @@ mod output_format {
         assert_eq!(format!("{}", OutputFormat::Slack), "slack");
         assert_eq!(format!("{}", OutputFormat::Exitcode), "exitcode");
         assert_eq!(format!("{}", OutputFormat::Prose), "prose");
+        assert_eq!(format!("{}", OutputFormat::Html), "html");
+        assert_eq!(format!("{}", OutputFormat::Csv), "csv");
+        assert_eq!(format!("{}", OutputFormat::Ndjson), "ndjson");
     }
 
     /// OutputFormat should serialize to lowercase string.
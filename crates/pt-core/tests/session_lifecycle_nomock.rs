@@ -135,6 +135,7 @@ fn make_decision() -> DecisionOutcome {
             memory_mb: None,
             has_known_signature: None,
             category: None,
+            protection: None,
         },
         risk_sensitive: None,
         dro: None,
@@ -53,6 +53,7 @@ fn make_row(
     ProcessRow {
         pid,
         score,
+        expected_loss: None,
         classification: classification.to_string(),
         runtime: runtime.to_string(),
         memory: memory.to_string(),
@@ -61,8 +62,13 @@ fn make_row(
         galaxy_brain: galaxy_brain.map(|s| s.to_string()),
         why_summary: None,
         top_evidence: vec![],
+        contributions: Vec::new(),
         confidence: None,
         plan_preview: vec![],
+        provenance_headline: None,
+        provenance_sections: vec![],
+        provenance_caveats: vec![],
+        blast_radius_risk: None,
     }
 }
 
@@ -799,6 +805,7 @@ fn execution_complete_ok() {
             attempted: 3,
             succeeded: 2,
             failed: 1,
+            aborted: false,
         })),
     );
     // Should not crash; status is set
@@ -23,21 +23,21 @@ fn render_app_view(app: &App, width: u16, height: u16) -> ftui::Buffer {
 }
 
 fn sample_posterior() -> PosteriorResult {
-    PosteriorResult {
-        posterior: ClassScores {
+    PosteriorResult::new(
+        ClassScores {
             useful: 0.12,
             useful_bad: 0.08,
             abandoned: 0.72,
             zombie: 0.08,
         },
-        log_posterior: ClassScores {
+        ClassScores {
             useful: -2.1,
             useful_bad: -2.5,
             abandoned: -0.4,
             zombie: -2.4,
         },
-        log_odds_abandoned_useful: 1.7,
-        evidence_terms: vec![
+        1.7,
+        vec![
             EvidenceTerm {
                 feature: "age_days".to_string(),
                 log_likelihood: ClassScores {
@@ -57,7 +57,7 @@ fn sample_posterior() -> PosteriorResult {
                 },
             },
         ],
-    }
+    )
 }
 
 fn sample_trace() -> String {
@@ -75,6 +75,7 @@ fn sample_row(trace: Option<String>) -> ProcessRow {
     ProcessRow {
         pid: 4242,
         score: 91,
+        expected_loss: None,
         classification: "KILL".to_string(),
         runtime: "3h 12m".to_string(),
         memory: "1.2 GB".to_string(),
@@ -83,8 +84,13 @@ fn sample_row(trace: Option<String>) -> ProcessRow {
         galaxy_brain: trace,
         why_summary: Some("Old + idle + orphaned".to_string()),
         top_evidence: vec!["PPID=1".to_string(), "Idle>2h".to_string()],
+        contributions: Vec::new(),
         confidence: Some("high".to_string()),
         plan_preview: vec!["SIGTERM -> SIGKILL".to_string()],
+        provenance_headline: None,
+        provenance_sections: vec![],
+        provenance_caveats: vec![],
+        blast_radius_risk: None,
     }
 }
 
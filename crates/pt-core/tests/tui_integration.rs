@@ -18,6 +18,7 @@ fn sample_row() -> ProcessRow {
     ProcessRow {
         pid: 4242,
         score: 91,
+        expected_loss: None,
         classification: "KILL".to_string(),
         runtime: "3h 12m".to_string(),
         memory: "1.2 GB".to_string(),
@@ -26,8 +27,13 @@ fn sample_row() -> ProcessRow {
         galaxy_brain: Some("Galaxy-Brain Mode\nPosterior Distribution".to_string()),
         why_summary: Some("Old + idle + orphaned".to_string()),
         top_evidence: vec!["PPID=1".to_string(), "Idle>2h".to_string()],
+        contributions: Vec::new(),
         confidence: Some("high".to_string()),
         plan_preview: vec!["SIGTERM -> SIGKILL".to_string()],
+        provenance_headline: None,
+        provenance_sections: Vec::new(),
+        provenance_caveats: Vec::new(),
+        blast_radius_risk: None,
     }
 }
 
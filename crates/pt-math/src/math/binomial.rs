@@ -13,8 +13,14 @@
 //!
 //! The posterior predictive for a new observation window of size `n2` follows
 //! the Beta-Binomial distribution.
+//!
+//! Frequentist confidence intervals for a binomial proportion (Wilson score
+//! and Clopper-Pearson) are provided for reporting observed rates with few
+//! trials.
 
 use super::bernoulli::BetaParams;
+use super::beta::beta_inv_cdf;
+use super::normal::normal_quantile;
 use super::stable::log_beta;
 
 /// Compute posterior parameters after observing k successes in n trials.
@@ -196,6 +202,78 @@ pub fn effective_sample_size(n: f64, eta: f64) -> f64 {
     eta * n
 }
 
+/// Wilson score interval for a binomial proportion.
+///
+/// Returns `(lo, hi)` covering the true success probability with the given
+/// two-sided `confidence` (e.g. 0.95). Unlike the normal approximation it
+/// stays inside [0, 1] and is informative at `0/n` and `n/n`. With no trials
+/// the interval is the whole of `(0, 1)`.
+///
+/// Returns `(NaN, NaN)` if `successes > trials` or `confidence` is not in (0, 1).
+pub fn binomial_ci_wilson(successes: u64, trials: u64, confidence: f64) -> (f64, f64) {
+    if successes > trials || !(confidence > 0.0 && confidence < 1.0) {
+        return (f64::NAN, f64::NAN);
+    }
+    if trials == 0 {
+        return (0.0, 1.0);
+    }
+
+    let n = trials as f64;
+    let p_hat = successes as f64 / n;
+    let z = normal_quantile(0.5 + confidence / 2.0);
+    let z2 = z * z;
+
+    let denom = 1.0 + z2 / n;
+    let center = (p_hat + z2 / (2.0 * n)) / denom;
+    let half_width = z * (p_hat * (1.0 - p_hat) / n + z2 / (4.0 * n * n)).sqrt() / denom;
+
+    let lo = if successes == 0 {
+        0.0
+    } else {
+        (center - half_width).max(0.0)
+    };
+    let hi = if successes == trials {
+        1.0
+    } else {
+        (center + half_width).min(1.0)
+    };
+    (lo, hi)
+}
+
+/// Clopper-Pearson ("exact") interval for a binomial proportion.
+///
+/// Inverts the binomial test via Beta quantiles:
+/// `lo = Beta⁻¹(α/2; k, n-k+1)`, `hi = Beta⁻¹(1-α/2; k+1, n-k)` with
+/// `α = 1 - confidence`. Conservative (coverage ≥ `confidence`). The bounds
+/// are pinned to 0 at `k = 0` and to 1 at `k = n`; with no trials the
+/// interval is `(0, 1)`.
+///
+/// Returns `(NaN, NaN)` if `successes > trials` or `confidence` is not in (0, 1).
+pub fn binomial_ci_clopper_pearson(successes: u64, trials: u64, confidence: f64) -> (f64, f64) {
+    if successes > trials || !(confidence > 0.0 && confidence < 1.0) {
+        return (f64::NAN, f64::NAN);
+    }
+    if trials == 0 {
+        return (0.0, 1.0);
+    }
+
+    let k = successes as f64;
+    let n = trials as f64;
+    let alpha = 1.0 - confidence;
+
+    let lo = if successes == 0 {
+        0.0
+    } else {
+        beta_inv_cdf(alpha / 2.0, k, n - k + 1.0)
+    };
+    let hi = if successes == trials {
+        1.0
+    } else {
+        beta_inv_cdf(1.0 - alpha / 2.0, k + 1.0, n - k)
+    };
+    (lo, hi)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -539,6 +617,85 @@ mod tests {
         }
     }
 
+    // =======================================================================
+    // Binomial confidence interval tests
+    // =======================================================================
+
+    #[test]
+    fn wilson_known_intervals() {
+        // Reference values from the closed-form Wilson score interval, z = 1.959964.
+        let cases = [
+            (3, 4, 0.300642, 0.954413),
+            (5, 10, 0.236593, 0.763407),
+            (0, 10, 0.0, 0.277533),
+            (10, 10, 0.722467, 1.0),
+            (81, 263, 0.255289, 0.366210),
+        ];
+        for (k, n, lo, hi) in cases {
+            let (got_lo, got_hi) = binomial_ci_wilson(k, n, 0.95);
+            assert!(
+                approx_eq(got_lo, lo, 1e-3) && approx_eq(got_hi, hi, 1e-3),
+                "{k}/{n}: ({got_lo}, {got_hi}) != ({lo}, {hi})"
+            );
+        }
+    }
+
+    #[test]
+    fn clopper_pearson_known_intervals() {
+        let cases = [
+            (3, 4, 0.194120, 0.993690),
+            (5, 10, 0.187086, 0.812914),
+            // 0/n upper bound is 1 - (α/2)^(1/n).
+            (0, 10, 0.0, 1.0 - 0.025f64.powf(0.1)),
+            (10, 10, 0.025f64.powf(0.1), 1.0),
+        ];
+        for (k, n, lo, hi) in cases {
+            let (got_lo, got_hi) = binomial_ci_clopper_pearson(k, n, 0.95);
+            assert!(
+                approx_eq(got_lo, lo, 1e-4) && approx_eq(got_hi, hi, 1e-4),
+                "{k}/{n}: ({got_lo}, {got_hi}) != ({lo}, {hi})"
+            );
+        }
+    }
+
+    #[test]
+    fn binomial_ci_edges_are_well_defined() {
+        for ci in [binomial_ci_wilson, binomial_ci_clopper_pearson] {
+            assert_eq!(ci(0, 0, 0.95), (0.0, 1.0));
+
+            let (lo, hi) = ci(0, 5, 0.95);
+            assert_eq!(lo, 0.0);
+            assert!(hi > 0.0 && hi < 1.0);
+
+            let (lo, hi) = ci(5, 5, 0.95);
+            assert!(lo > 0.0 && lo < 1.0);
+            assert_eq!(hi, 1.0);
+
+            let (lo, hi) = ci(6, 5, 0.95);
+            assert!(lo.is_nan() && hi.is_nan());
+            assert!(ci(1, 2, 1.0).0.is_nan());
+            assert!(ci(1, 2, 0.0).0.is_nan());
+        }
+    }
+
+    #[test]
+    fn binomial_ci_clopper_pearson_contains_wilson() {
+        // Clopper-Pearson is conservative relative to Wilson.
+        for (k, n) in [(1, 4), (3, 4), (7, 20), (50, 100)] {
+            let (w_lo, w_hi) = binomial_ci_wilson(k, n, 0.95);
+            let (c_lo, c_hi) = binomial_ci_clopper_pearson(k, n, 0.95);
+            assert!(c_lo <= w_lo + 1e-9 && c_hi >= w_hi - 1e-9, "{k}/{n}");
+        }
+    }
+
+    #[test]
+    fn binomial_ci_narrows_with_more_trials() {
+        let (lo_small, hi_small) = binomial_ci_wilson(3, 4, 0.95);
+        let (lo_large, hi_large) = binomial_ci_wilson(300, 400, 0.95);
+        assert!(hi_large - lo_large < hi_small - lo_small);
+        assert!(lo_large < 0.75 && hi_large > 0.75);
+    }
+
     // =======================================================================
     // Robustness tests
     // =======================================================================
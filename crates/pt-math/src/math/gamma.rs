@@ -13,14 +13,76 @@
 //! The density is: `f(t) = β^α / Γ(α) * t^(α-1) * e^(-βt)`
 //!
 //! This is equivalent to scale parameterization with `θ = 1/β`.
+//!
+//! Also provides `ln_gamma` and `digamma` (ψ) for maximum-likelihood fitting
+//! of Gamma/Beta priors from observed samples.
+
+use std::f64::consts::PI;
 
 use super::stable::log_gamma;
 
+// Digamma: shift the argument up to this value before using the asymptotic series
+const DIGAMMA_ASYMPTOTIC_MIN: f64 = 10.0;
+// B₂ₖ / 2k for k = 1..=7
+const DIGAMMA_SERIES: [f64; 7] = [
+    1.0 / 12.0,
+    -1.0 / 120.0,
+    1.0 / 252.0,
+    -1.0 / 240.0,
+    1.0 / 132.0,
+    -691.0 / 32760.0,
+    1.0 / 12.0,
+];
+
 // Constants for incomplete gamma computation
 const GAMMAINC_MAX_ITERS: usize = 200;
 const GAMMAINC_EPS: f64 = 3.0e-12;
 const GAMMAINC_FPMIN: f64 = 1.0e-30;
 
+/// Natural log of the Gamma function, `ln |Γ(x)|`.
+///
+/// Lanczos approximation (g = 7, n = 9) with the reflection formula for
+/// `x < 0.5`. Returns NaN at the poles (zero and negative integers).
+pub fn ln_gamma(x: f64) -> f64 {
+    log_gamma(x)
+}
+
+/// Digamma function ψ(x) = d/dx ln Γ(x).
+///
+/// Uses the recurrence `ψ(x) = ψ(x + 1) - 1/x` to shift small arguments into
+/// the range of the asymptotic (Bernoulli) series, and the reflection formula
+/// `ψ(x) = ψ(1 - x) - π / tan(πx)` for `x <= 0`. Returns NaN at the poles.
+pub fn digamma(x: f64) -> f64 {
+    if x.is_nan() || x == f64::NEG_INFINITY {
+        return f64::NAN;
+    }
+    if x == f64::INFINITY {
+        return f64::INFINITY;
+    }
+    if x <= 0.0 {
+        if x == x.floor() {
+            return f64::NAN;
+        }
+        return digamma(1.0 - x) - PI / (PI * x).tan();
+    }
+
+    let mut x = x;
+    let mut shift = 0.0;
+    while x < DIGAMMA_ASYMPTOTIC_MIN {
+        shift -= 1.0 / x;
+        x += 1.0;
+    }
+
+    // ψ(x) ~ ln x - 1/(2x) - Σ B₂ₖ / (2k x²ᵏ), evaluated by Horner in 1/x²
+    let inv2 = 1.0 / (x * x);
+    let series = DIGAMMA_SERIES
+        .iter()
+        .rev()
+        .fold(0.0, |acc, c| acc * inv2 + c)
+        * inv2;
+    shift + x.ln() - 0.5 / x - series
+}
+
 /// Log of the Gamma distribution PDF at t.
 ///
 /// Uses rate parameterization: `f(t) = β^α / Γ(α) * t^(α-1) * e^(-βt)`
@@ -491,6 +553,79 @@ mod tests {
         diff / max_ab <= rel_tol
     }
 
+    const EULER_GAMMA: f64 = 0.577_215_664_901_532_9;
+
+    #[test]
+    fn ln_gamma_known_values() {
+        assert!(approx_eq(ln_gamma(0.5), PI.sqrt().ln(), 1e-12));
+        assert!(approx_eq(ln_gamma(1.0), 0.0, 1e-12));
+        assert!(approx_eq(ln_gamma(2.0), 0.0, 1e-12));
+        assert!(approx_eq(ln_gamma(10.0), 362_880.0_f64.ln(), 1e-10));
+        assert!(ln_gamma(0.0).is_nan());
+        assert!(ln_gamma(-3.0).is_nan());
+    }
+
+    #[test]
+    fn digamma_known_values() {
+        assert!(approx_eq(digamma(1.0), -EULER_GAMMA, 1e-12));
+        // ψ(1/2) = -γ - 2 ln 2
+        assert!(approx_eq(
+            digamma(0.5),
+            -EULER_GAMMA - 2.0 * 2.0_f64.ln(),
+            1e-12
+        ));
+        // ψ(n) = -γ + H(n-1)
+        assert!(approx_eq(
+            digamma(4.0),
+            -EULER_GAMMA + 1.0 + 0.5 + 1.0 / 3.0,
+            1e-12
+        ));
+        assert!(rel_eq(digamma(0.01), -100.560_885_457_868_67, 1e-12));
+    }
+
+    #[test]
+    fn digamma_recurrence_over_fitting_range() {
+        let mut x: f64 = 0.01;
+        while x < 1e4 {
+            let lhs = digamma(x + 1.0);
+            let rhs = digamma(x) + 1.0 / x;
+            assert!(
+                approx_eq(lhs, rhs, 1e-10 * rhs.abs().max(1.0)),
+                "ψ(x+1) = ψ(x) + 1/x failed at x={}: {} vs {}",
+                x,
+                lhs,
+                rhs
+            );
+            x *= 1.7;
+        }
+    }
+
+    #[test]
+    fn digamma_matches_ln_gamma_derivative() {
+        for x in [0.05, 0.7, 3.3, 42.0, 900.0] {
+            let h = 1e-5 * x;
+            let numeric = (ln_gamma(x + h) - ln_gamma(x - h)) / (2.0 * h);
+            assert!(
+                rel_eq(digamma(x), numeric, 1e-6),
+                "ψ({}) = {} but d/dx ln Γ = {}",
+                x,
+                digamma(x),
+                numeric
+            );
+        }
+    }
+
+    #[test]
+    fn digamma_reflection_and_poles() {
+        // ψ(-1/2) = ψ(3/2) = 2 - γ - 2 ln 2
+        let expected = 2.0 - EULER_GAMMA - 2.0 * 2.0_f64.ln();
+        assert!(approx_eq(digamma(-0.5), expected, 1e-12));
+        assert!(digamma(0.0).is_nan());
+        assert!(digamma(-2.0).is_nan());
+        assert!(digamma(f64::NAN).is_nan());
+        assert_eq!(digamma(f64::INFINITY), f64::INFINITY);
+    }
+
     #[test]
     fn rel_eq_matches_itself() {
         assert!(rel_eq(1.0, 1.0, 1e-10));
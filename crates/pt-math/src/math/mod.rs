@@ -10,3 +10,5 @@ pub mod normal;
 pub mod posterior;
 pub mod precomputed;
 pub mod stable;
+
+pub use gamma::{digamma, ln_gamma};
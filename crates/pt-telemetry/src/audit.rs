@@ -0,0 +1,139 @@
+//! Rows for the `audit` table.
+//!
+//! Shared by the telemetry recorder and by callers that append audit
+//! records directly through a [`BatchedWriter`](crate::writer::BatchedWriter).
+
+use std::sync::Arc;
+
+use arrow::array::{Int32Array, StringArray, TimestampMicrosecondArray};
+use arrow::record_batch::RecordBatch;
+
+use crate::schema::audit_schema;
+use crate::writer::{WriteError, WriterConfig};
+
+/// One row of the `audit` table, minus the session and host columns that
+/// come from the [`WriterConfig`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuditRow {
+    pub audit_ts_micros: i64,
+    pub event_type: String,
+    pub severity: String,
+    pub actor: String,
+    pub target_pid: Option<i32>,
+    pub target_start_id: Option<String>,
+    pub message: String,
+    pub details_json: Option<String>,
+}
+
+/// Build an `audit` record batch from `rows`.
+pub fn build_audit_batch(
+    config: &WriterConfig,
+    rows: &[AuditRow],
+) -> Result<RecordBatch, WriteError> {
+    let audit_ts = TimestampMicrosecondArray::from(
+        rows.iter()
+            .map(|row| Some(row.audit_ts_micros))
+            .collect::<Vec<_>>(),
+    )
+    .with_timezone("UTC");
+    let session_id = StringArray::from(
+        rows.iter()
+            .map(|_| Some(config.session_id.as_str()))
+            .collect::<Vec<_>>(),
+    );
+    let event_type = StringArray::from(
+        rows.iter()
+            .map(|row| Some(row.event_type.as_str()))
+            .collect::<Vec<_>>(),
+    );
+    let severity = StringArray::from(
+        rows.iter()
+            .map(|row| Some(row.severity.as_str()))
+            .collect::<Vec<_>>(),
+    );
+    let actor = StringArray::from(
+        rows.iter()
+            .map(|row| Some(row.actor.as_str()))
+            .collect::<Vec<_>>(),
+    );
+    let target_pid = Int32Array::from(rows.iter().map(|row| row.target_pid).collect::<Vec<_>>());
+    let target_start_id = StringArray::from(
+        rows.iter()
+            .map(|row| row.target_start_id.as_deref())
+            .collect::<Vec<_>>(),
+    );
+    let message = StringArray::from(
+        rows.iter()
+            .map(|row| Some(row.message.as_str()))
+            .collect::<Vec<_>>(),
+    );
+    let details_json = StringArray::from(
+        rows.iter()
+            .map(|row| row.details_json.as_deref())
+            .collect::<Vec<_>>(),
+    );
+    let host_id = StringArray::from(
+        rows.iter()
+            .map(|_| Some(config.host_id.as_str()))
+            .collect::<Vec<_>>(),
+    );
+
+    RecordBatch::try_new(
+        Arc::new(audit_schema()),
+        vec![
+            Arc::new(audit_ts),
+            Arc::new(session_id),
+            Arc::new(event_type),
+            Arc::new(severity),
+            Arc::new(actor),
+            Arc::new(target_pid),
+            Arc::new(target_start_id),
+            Arc::new(message),
+            Arc::new(details_json),
+            Arc::new(host_id),
+        ],
+    )
+    .map_err(WriteError::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::Array;
+    use std::path::PathBuf;
+
+    #[test]
+    fn batch_carries_start_id_and_config_columns() {
+        let config = WriterConfig::new(
+            PathBuf::from("/tmp"),
+            "pt-20260101-000000-abcd".to_string(),
+            "host-1".to_string(),
+        );
+        let rows = vec![AuditRow {
+            audit_ts_micros: 1_000,
+            event_type: "action_attempt".to_string(),
+            severity: "info".to_string(),
+            actor: "executor".to_string(),
+            target_pid: Some(42),
+            target_start_id: Some("boot:123:42".to_string()),
+            message: "kill success".to_string(),
+            details_json: None,
+        }];
+
+        let batch = build_audit_batch(&config, &rows).unwrap();
+        assert_eq!(batch.num_rows(), 1);
+        let column = |name: &str| {
+            batch
+                .column_by_name(name)
+                .unwrap()
+                .as_any()
+                .downcast_ref::<StringArray>()
+                .unwrap()
+                .clone()
+        };
+        assert_eq!(column("target_start_id").value(0), "boot:123:42");
+        assert_eq!(column("session_id").value(0), "pt-20260101-000000-abcd");
+        assert_eq!(column("host_id").value(0), "host-1");
+        assert!(column("details_json").is_null(0));
+    }
+}
@@ -6,6 +6,7 @@
 //! - Path layout and partitioning helpers
 //! - Shadow mode observation storage with tiered retention
 
+pub mod audit;
 pub mod disruptor;
 pub mod recorder;
 pub mod retention;
@@ -13,16 +14,17 @@ pub mod schema;
 pub mod shadow;
 pub mod writer;
 
+pub use audit::{build_audit_batch, AuditRow};
 pub use schema::{
     audit_schema, outcomes_schema, proc_features_schema, proc_inference_schema,
-    proc_samples_schema, runs_schema, TableName, TelemetrySchema,
+    proc_samples_schema, runs_schema, TableName, TelemetrySchema, JSON_SCHEMA_DIALECT,
 };
 pub use shadow::{
     shadow_observations_schema, BeliefState, EventType, EventsResult, HistoryResult, Observation,
     ObservationSummary, ProcessEvent, RetentionTier, ScoreResult, ShadowStorage,
     ShadowStorageConfig, ShadowStorageError, StateSnapshot, StorageStats,
 };
-pub use writer::{BatchedWriter, WriteError, WriterConfig};
+pub use writer::{BatchedWriter, Compression, WriteError, WriterConfig};
 
 /// Schema version for telemetry tables.
 pub const SCHEMA_VERSION: &str = "1.0.0";
@@ -30,5 +32,8 @@ pub const SCHEMA_VERSION: &str = "1.0.0";
 /// Default batch size for buffered writes.
 pub const DEFAULT_BATCH_SIZE: usize = 1000;
 
+/// Default cap on estimated buffered bytes before a flush (64 MiB).
+pub const DEFAULT_MAX_BUFFERED_BYTES: usize = 64 * 1024 * 1024;
+
 /// Default flush interval in seconds.
 pub const DEFAULT_FLUSH_INTERVAL_SECS: u64 = 30;
@@ -9,9 +9,7 @@ use std::thread;
 use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use tracing::warn;
 
-use arrow::array::{Int32Array, StringArray, TimestampMicrosecondArray};
-use arrow::record_batch::RecordBatch;
-
+use crate::audit::{build_audit_batch, AuditRow};
 use crate::disruptor::{FixedSizeEvent, TelemetryRingBuffer, MAX_DETAILS_LEN};
 use crate::schema::TableName;
 use crate::shadow::EventType;
@@ -24,17 +22,6 @@ pub struct TelemetryRecorder {
     flusher_handle: Option<thread::JoinHandle<()>>,
 }
 
-#[derive(Debug)]
-struct AuditRow {
-    audit_ts_micros: i64,
-    event_type: String,
-    severity: &'static str,
-    actor: &'static str,
-    target_pid: Option<i32>,
-    message: String,
-    details_json: Option<String>,
-}
-
 impl TelemetryRecorder {
     /// Create a new telemetry recorder.
     pub fn new(capacity: usize, config: WriterConfig) -> Self {
@@ -147,9 +134,10 @@ fn audit_row_from_event(event: &FixedSizeEvent) -> AuditRow {
     AuditRow {
         audit_ts_micros: (event.timestamp_ns / 1_000) as i64,
         event_type,
-        severity: "info",
-        actor: "telemetry_recorder",
+        severity: "info".to_string(),
+        actor: "telemetry_recorder".to_string(),
         target_pid: Some(event.pid as i32),
+        target_start_id: None,
         message,
         details_json: (!details.is_empty())
             .then(|| serde_json::json!({ "details": details }).to_string()),
@@ -171,65 +159,3 @@ fn flush_pending_rows(
     pending_rows.clear();
     Ok(())
 }
-
-fn build_audit_batch(
-    config: &WriterConfig,
-    rows: &[AuditRow],
-) -> Result<RecordBatch, crate::writer::WriteError> {
-    let audit_ts = TimestampMicrosecondArray::from(
-        rows.iter()
-            .map(|row| Some(row.audit_ts_micros))
-            .collect::<Vec<_>>(),
-    )
-    .with_timezone("UTC");
-    let session_id = StringArray::from(
-        rows.iter()
-            .map(|_| Some(config.session_id.as_str()))
-            .collect::<Vec<_>>(),
-    );
-    let event_type = StringArray::from(
-        rows.iter()
-            .map(|row| Some(row.event_type.as_str()))
-            .collect::<Vec<_>>(),
-    );
-    let severity = StringArray::from(
-        rows.iter()
-            .map(|row| Some(row.severity))
-            .collect::<Vec<_>>(),
-    );
-    let actor = StringArray::from(rows.iter().map(|row| Some(row.actor)).collect::<Vec<_>>());
-    let target_pid = Int32Array::from(rows.iter().map(|row| row.target_pid).collect::<Vec<_>>());
-    let target_start_id = StringArray::from(vec![None::<&str>; rows.len()]);
-    let message = StringArray::from(
-        rows.iter()
-            .map(|row| Some(row.message.as_str()))
-            .collect::<Vec<_>>(),
-    );
-    let details_json = StringArray::from(
-        rows.iter()
-            .map(|row| row.details_json.as_deref())
-            .collect::<Vec<_>>(),
-    );
-    let host_id = StringArray::from(
-        rows.iter()
-            .map(|_| Some(config.host_id.as_str()))
-            .collect::<Vec<_>>(),
-    );
-
-    RecordBatch::try_new(
-        Arc::new(crate::schema::audit_schema()),
-        vec![
-            Arc::new(audit_ts),
-            Arc::new(session_id),
-            Arc::new(event_type),
-            Arc::new(severity),
-            Arc::new(actor),
-            Arc::new(target_pid),
-            Arc::new(target_start_id),
-            Arc::new(message),
-            Arc::new(details_json),
-            Arc::new(host_id),
-        ],
-    )
-    .map_err(crate::writer::WriteError::from)
-}
@@ -283,6 +283,7 @@ pub struct RetentionPreview {
     pub bytes_to_free: u64,
 
     /// Per-table breakdown.
+    #[serde(serialize_with = "pt_common::serialize_sorted")]
     pub by_table: HashMap<String, TablePreview>,
 
     /// Current total usage.
@@ -329,6 +330,7 @@ pub struct RetentionStatus {
     pub total_files: usize,
 
     /// Per-table statistics.
+    #[serde(serialize_with = "pt_common::serialize_sorted")]
     pub by_table: HashMap<String, TableStatus>,
 
     /// Configured disk budget.
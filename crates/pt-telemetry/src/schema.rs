@@ -7,10 +7,17 @@
 //! - `proc_inference`: Inference results
 //! - `outcomes`: Action outcomes and feedback
 //! - `audit`: Audit trail
+//!
+//! [`TelemetrySchema::to_json_schema`] describes any table as a JSON Schema
+//! document so downstream warehouses can validate Parquet files against it.
 
 use arrow::datatypes::{DataType, Field, Schema, TimeUnit};
+use serde_json::{json, Map, Value};
 use std::sync::Arc;
 
+/// JSON Schema dialect emitted by [`TelemetrySchema::to_json_schema`].
+pub const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
 /// Table names for telemetry storage.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum TableName {
@@ -24,6 +31,22 @@ pub enum TableName {
 }
 
 impl TableName {
+    /// Every telemetry table, in layout order.
+    pub const ALL: [TableName; 7] = [
+        TableName::Runs,
+        TableName::ProcSamples,
+        TableName::ProcFeatures,
+        TableName::ProcInference,
+        TableName::Outcomes,
+        TableName::Audit,
+        TableName::SignatureMatches,
+    ];
+
+    /// Look up a table by its directory name (e.g. `proc_samples`).
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|table| table.as_str() == name)
+    }
+
     /// Get the string name for directory layout.
     pub fn as_str(&self) -> &'static str {
         match self {
@@ -107,6 +130,71 @@ impl TelemetrySchema {
             TableName::SignatureMatches => self.signature_matches.clone(),
         }
     }
+
+    /// Describe `table` as a JSON Schema document.
+    ///
+    /// Each column becomes a property carrying its Arrow type in
+    /// `x-arrow-type`; non-nullable columns are `required` and nullable
+    /// ones also accept `null`. The telemetry [`SCHEMA_VERSION`](crate::SCHEMA_VERSION)
+    /// is recorded in `x-schema-version`.
+    pub fn to_json_schema(table: TableName) -> Value {
+        let schema = Self::new().get(table);
+        let mut properties = Map::new();
+        let mut required = Vec::new();
+        for field in schema.fields() {
+            properties.insert(field.name().clone(), field_json_schema(field));
+            if !field.is_nullable() {
+                required.push(Value::String(field.name().clone()));
+            }
+        }
+        json!({
+            "$schema": JSON_SCHEMA_DIALECT,
+            "title": table.as_str(),
+            "type": "object",
+            "x-schema-version": crate::SCHEMA_VERSION,
+            "properties": properties,
+            "required": required,
+            "additionalProperties": false,
+        })
+    }
+}
+
+/// JSON Schema for one Arrow column, including nullability.
+fn field_json_schema(field: &Field) -> Value {
+    let mut schema = data_type_json_schema(field.data_type());
+    if field.is_nullable() {
+        if let Some(ty) = schema.get("type").cloned() {
+            schema["type"] = json!([ty, "null"]);
+        }
+    }
+    schema["x-arrow-type"] = Value::String(field.data_type().to_string());
+    schema
+}
+
+/// JSON Schema for an Arrow type's values, ignoring nullability.
+fn data_type_json_schema(data_type: &DataType) -> Value {
+    match data_type {
+        DataType::Boolean => json!({ "type": "boolean" }),
+        DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::Int64
+        | DataType::UInt8
+        | DataType::UInt16
+        | DataType::UInt32
+        | DataType::UInt64 => json!({ "type": "integer" }),
+        DataType::Float16 | DataType::Float32 | DataType::Float64 => json!({ "type": "number" }),
+        DataType::Utf8 | DataType::LargeUtf8 => json!({ "type": "string" }),
+        DataType::Timestamp(_, _) | DataType::Date64 => {
+            json!({ "type": "string", "format": "date-time" })
+        }
+        DataType::Date32 => json!({ "type": "string", "format": "date" }),
+        DataType::List(item) | DataType::LargeList(item) => {
+            json!({ "type": "array", "items": field_json_schema(item) })
+        }
+        DataType::Dictionary(_, value) => data_type_json_schema(value),
+        _ => json!({}),
+    }
 }
 
 impl Default for TelemetrySchema {
@@ -529,6 +617,43 @@ mod tests {
         assert!(schema.field_with_name("outcome_available").is_ok());
     }
 
+    #[test]
+    fn test_table_name_from_name() {
+        for table in TableName::ALL {
+            assert_eq!(TableName::from_name(table.as_str()), Some(table));
+        }
+        assert_eq!(TableName::from_name("nope"), None);
+    }
+
+    #[test]
+    fn test_to_json_schema_covers_every_column() {
+        for table in TableName::ALL {
+            let arrow = TelemetrySchema::new().get(table);
+            let doc = TelemetrySchema::to_json_schema(table);
+            assert_eq!(doc["title"], table.as_str());
+            assert_eq!(doc["x-schema-version"], crate::SCHEMA_VERSION);
+            let properties = doc["properties"].as_object().unwrap();
+            assert_eq!(properties.len(), arrow.fields().len(), "{table}");
+            let required = doc["required"].as_array().unwrap();
+            let non_nullable = arrow.fields().iter().filter(|f| !f.is_nullable()).count();
+            assert_eq!(required.len(), non_nullable, "{table}");
+        }
+    }
+
+    #[test]
+    fn test_to_json_schema_types_and_nullability() {
+        let doc = TelemetrySchema::to_json_schema(TableName::Runs);
+        let props = &doc["properties"];
+        assert_eq!(props["session_id"]["type"], "string");
+        assert_eq!(props["uid"]["type"], json!(["integer", "null"]));
+        assert_eq!(props["uid"]["x-arrow-type"], "Int32");
+        assert_eq!(props["deep_scan"]["type"], "boolean");
+        assert_eq!(props["started_at"]["format"], "date-time");
+        let required = doc["required"].as_array().unwrap();
+        assert!(required.contains(&json!("session_id")));
+        assert!(!required.contains(&json!("uid")));
+    }
+
     #[test]
     fn test_proc_inference_signature_fields() {
         let schema = proc_inference_schema();
@@ -10,7 +10,7 @@ use std::sync::Arc;
 use arrow::array::RecordBatch;
 use arrow::datatypes::Schema;
 use parquet::arrow::ArrowWriter;
-use parquet::basic::{Compression, Encoding, ZstdLevel};
+use parquet::basic::{Compression as ParquetCompression, Encoding, ZstdLevel};
 use parquet::file::properties::{WriterProperties, WriterVersion};
 use thiserror::Error;
 
@@ -38,6 +38,80 @@ pub enum WriteError {
 
     #[error("Buffer empty")]
     EmptyBuffer,
+
+    #[error("Invalid writer config: {0}")]
+    InvalidConfig(String),
+}
+
+/// Parquet compression codec for telemetry files.
+///
+/// Higher zstd levels shrink files further but cost more CPU per flush, so
+/// write latency grows with the level: levels 1-3 are cheap, the default of
+/// 3 is a good balance, and levels above ~15 are markedly slower for modest
+/// extra savings. `Snappy` is faster than any zstd level at a worse ratio;
+/// `Uncompressed` spends no CPU at all.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compression {
+    /// Zstandard at `level` (1-22).
+    Zstd {
+        level: i32,
+    },
+    Snappy,
+    Uncompressed,
+}
+
+impl Compression {
+    /// Lowest accepted zstd level.
+    pub const MIN_ZSTD_LEVEL: i32 = 1;
+    /// Highest accepted zstd level.
+    pub const MAX_ZSTD_LEVEL: i32 = 22;
+    /// Zstd level used by default.
+    pub const DEFAULT_ZSTD_LEVEL: i32 = 3;
+
+    /// Zstd at `level`, rejecting levels outside 1-22.
+    pub fn zstd(level: i32) -> Result<Self, WriteError> {
+        let compression = Compression::Zstd { level };
+        compression.validate()?;
+        Ok(compression)
+    }
+
+    /// Check that the codec settings are usable.
+    pub fn validate(&self) -> Result<(), WriteError> {
+        match *self {
+            Compression::Zstd { level }
+                if !(Self::MIN_ZSTD_LEVEL..=Self::MAX_ZSTD_LEVEL).contains(&level) =>
+            {
+                Err(WriteError::InvalidConfig(format!(
+                    "zstd level {} out of range {}-{}",
+                    level,
+                    Self::MIN_ZSTD_LEVEL,
+                    Self::MAX_ZSTD_LEVEL
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn to_parquet(self) -> Result<ParquetCompression, WriteError> {
+        self.validate()?;
+        Ok(match self {
+            Compression::Zstd { level } => {
+                let level = ZstdLevel::try_new(level)
+                    .map_err(|e| WriteError::InvalidConfig(e.to_string()))?;
+                ParquetCompression::ZSTD(level)
+            }
+            Compression::Snappy => ParquetCompression::SNAPPY,
+            Compression::Uncompressed => ParquetCompression::UNCOMPRESSED,
+        })
+    }
+}
+
+impl Default for Compression {
+    fn default() -> Self {
+        Compression::Zstd {
+            level: Self::DEFAULT_ZSTD_LEVEL,
+        }
+    }
 }
 
 /// Configuration for the batched writer.
@@ -46,7 +120,7 @@ pub struct WriterConfig {
     /// Directory for telemetry files.
     pub base_dir: PathBuf,
 
-    /// Compression codec.
+    /// Compression codec; see [`Compression`] for the latency trade-off.
     pub compression: Compression,
 
     /// Row group size in bytes.
@@ -55,6 +129,12 @@ pub struct WriterConfig {
     /// Maximum rows to buffer before flushing.
     pub batch_size: usize,
 
+    /// Maximum estimated bytes to buffer before flushing.
+    ///
+    /// Bounds memory when rows are wide; whichever of this and
+    /// `batch_size` is reached first triggers the flush.
+    pub max_buffered_bytes: usize,
+
     /// Session ID for file naming.
     pub session_id: String,
 
@@ -67,9 +147,10 @@ impl WriterConfig {
     pub fn new(base_dir: PathBuf, session_id: String, host_id: String) -> Self {
         WriterConfig {
             base_dir,
-            compression: Compression::ZSTD(ZstdLevel::try_new(3).expect("valid zstd level")),
+            compression: Compression::default(),
             row_group_size: 512 * 1024, // 512KB default
             batch_size: crate::DEFAULT_BATCH_SIZE,
+            max_buffered_bytes: crate::DEFAULT_MAX_BUFFERED_BYTES,
             session_id,
             host_id,
         }
@@ -77,16 +158,29 @@ impl WriterConfig {
 
     /// Use snappy compression instead of zstd.
     pub fn with_snappy(mut self) -> Self {
-        self.compression = Compression::SNAPPY;
+        self.compression = Compression::Snappy;
         self
     }
 
+    /// Set the compression codec, rejecting out-of-range zstd levels.
+    pub fn with_compression(mut self, compression: Compression) -> Result<Self, WriteError> {
+        compression.validate()?;
+        self.compression = compression;
+        Ok(self)
+    }
+
     /// Set custom batch size.
     pub fn with_batch_size(mut self, size: usize) -> Self {
         self.batch_size = size;
         self
     }
 
+    /// Set custom buffered byte limit.
+    pub fn with_max_buffered_bytes(mut self, bytes: usize) -> Self {
+        self.max_buffered_bytes = bytes;
+        self
+    }
+
     /// Set custom row group size.
     pub fn with_row_group_size(mut self, size: usize) -> Self {
         self.row_group_size = size;
@@ -101,6 +195,7 @@ pub struct BatchedWriter {
     config: WriterConfig,
     buffer: Vec<RecordBatch>,
     rows_buffered: usize,
+    bytes_buffered: usize,
     output_path: Option<PathBuf>,
     temp_path: Option<PathBuf>,
     writer: Option<ArrowWriter<File>>,
@@ -115,6 +210,7 @@ impl BatchedWriter {
             config,
             buffer: Vec::new(),
             rows_buffered: 0,
+            bytes_buffered: 0,
             output_path: None,
             temp_path: None,
             writer: None,
@@ -123,13 +219,17 @@ impl BatchedWriter {
 
     /// Write a record batch to the buffer.
     ///
-    /// If the buffer exceeds the batch size, it will be flushed to disk.
+    /// If the buffer reaches the batch size or the buffered byte limit, it
+    /// will be flushed to disk.
     pub fn write(&mut self, batch: RecordBatch) -> Result<(), WriteError> {
         let num_rows = batch.num_rows();
+        self.bytes_buffered += batch.get_array_memory_size();
         self.buffer.push(batch);
         self.rows_buffered += num_rows;
 
-        if self.rows_buffered >= self.config.batch_size {
+        if self.rows_buffered >= self.config.batch_size
+            || self.bytes_buffered >= self.config.max_buffered_bytes
+        {
             self.flush()?;
         }
 
@@ -149,6 +249,7 @@ impl BatchedWriter {
 
         let writer = self.writer.as_mut().ok_or(WriteError::NotInitialized)?;
         let mut written_rows = 0usize;
+        let mut written_bytes = 0usize;
 
         // Write buffered batches, but keep the current and remaining batches in memory
         // if a later write fails so callers can decide how to recover.
@@ -156,16 +257,24 @@ impl BatchedWriter {
             if let Err(err) = writer.write(batch) {
                 self.buffer.drain(..written_batches);
                 self.rows_buffered = self.rows_buffered.saturating_sub(written_rows);
+                self.bytes_buffered = self.bytes_buffered.saturating_sub(written_bytes);
                 return Err(err.into());
             }
             written_rows += batch.num_rows();
+            written_bytes += batch.get_array_memory_size();
         }
 
         self.buffer.clear();
         self.rows_buffered = 0;
+        self.bytes_buffered = 0;
         Ok(())
     }
 
+    /// Estimated in-memory size of the batches waiting to be flushed.
+    pub fn buffered_bytes(&self) -> usize {
+        self.bytes_buffered
+    }
+
     /// Close the writer and finalize the file.
     pub fn close(mut self) -> Result<PathBuf, WriteError> {
         if self.writer.is_none() && self.buffer.is_empty() {
@@ -208,7 +317,7 @@ impl BatchedWriter {
         // Configure writer properties
         let props = WriterProperties::builder()
             .set_writer_version(WriterVersion::PARQUET_2_0)
-            .set_compression(self.config.compression)
+            .set_compression(self.config.compression.to_parquet()?)
             .set_max_row_group_size(self.config.row_group_size)
             // Dictionary encoding for string columns
             .set_dictionary_enabled(true)
@@ -396,7 +505,8 @@ mod tests {
             "host123".to_string(),
         );
         assert_eq!(config.batch_size, crate::DEFAULT_BATCH_SIZE);
-        assert!(matches!(config.compression, Compression::ZSTD(_)));
+        assert_eq!(config.max_buffered_bytes, crate::DEFAULT_MAX_BUFFERED_BYTES);
+        assert_eq!(config.compression, Compression::Zstd { level: 3 });
     }
 
     #[test]
@@ -407,7 +517,75 @@ mod tests {
             "host123".to_string(),
         )
         .with_snappy();
-        assert!(matches!(config.compression, Compression::SNAPPY));
+        assert_eq!(config.compression, Compression::Snappy);
+    }
+
+    #[test]
+    fn test_zstd_level_out_of_range_is_rejected() {
+        for level in [0, 23, -1] {
+            let err = Compression::zstd(level).unwrap_err();
+            assert!(matches!(err, WriteError::InvalidConfig(_)), "{level}");
+        }
+        assert!(Compression::zstd(1).is_ok());
+        assert!(Compression::zstd(22).is_ok());
+
+        let config = WriterConfig::new(
+            PathBuf::from("/tmp/test"),
+            "pt-test".to_string(),
+            "host123".to_string(),
+        );
+        let err = config
+            .with_compression(Compression::Zstd { level: 30 })
+            .unwrap_err();
+        assert!(err.to_string().contains("zstd level 30"));
+    }
+
+    #[test]
+    fn test_each_codec_round_trips() {
+        let cases = [
+            (
+                Compression::zstd(1).unwrap(),
+                ParquetCompression::ZSTD(ZstdLevel::try_new(1).unwrap()),
+            ),
+            (
+                Compression::zstd(19).unwrap(),
+                ParquetCompression::ZSTD(ZstdLevel::try_new(19).unwrap()),
+            ),
+            (Compression::Snappy, ParquetCompression::SNAPPY),
+            (Compression::Uncompressed, ParquetCompression::UNCOMPRESSED),
+        ];
+        for (compression, expected) in cases {
+            let temp_dir = TempDir::new().unwrap();
+            let schema = Arc::new(crate::schema::audit_schema());
+            let config = WriterConfig::new(
+                temp_dir.path().to_path_buf(),
+                "pt-20260115-143022-codec".to_string(),
+                "test-host".to_string(),
+            )
+            .with_compression(compression)
+            .unwrap();
+
+            let mut writer = BatchedWriter::new(TableName::Audit, schema.clone(), config);
+            let batch = create_test_batch(&schema);
+            writer.write(batch.clone()).unwrap();
+            let path = writer.close().unwrap();
+
+            let builder =
+                ParquetRecordBatchReaderBuilder::try_new(File::open(path).unwrap()).unwrap();
+            let column = builder.metadata().row_group(0).column(0);
+            // Parquet records the codec but not the zstd level.
+            assert_eq!(
+                std::mem::discriminant(&column.compression()),
+                std::mem::discriminant(&expected),
+                "{compression:?}"
+            );
+            let batches = builder
+                .build()
+                .unwrap()
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            assert_eq!(batches, vec![batch], "{compression:?}");
+        }
     }
 
     #[test]
@@ -525,8 +703,15 @@ mod tests {
             "row count should track unwritten batch"
         );
 
+        assert_eq!(
+            writer.buffered_bytes(),
+            create_incompatible_batch().get_array_memory_size(),
+            "byte estimate should track unwritten batch"
+        );
+
         writer.buffer.clear();
         writer.rows_buffered = 0;
+        writer.bytes_buffered = 0;
         let path = writer.close().unwrap();
 
         let file = File::open(path).unwrap();
@@ -537,6 +722,30 @@ mod tests {
         assert_eq!(total_rows, 1, "successful rows should remain persisted");
     }
 
+    #[test]
+    fn test_flushes_when_buffered_bytes_exceed_limit() {
+        let temp_dir = TempDir::new().unwrap();
+        let schema = Arc::new(crate::schema::audit_schema());
+        let batch_bytes = create_test_batch(&schema).get_array_memory_size();
+        let config = WriterConfig::new(
+            temp_dir.path().to_path_buf(),
+            "pt-20260115-143022-size".to_string(),
+            "abc123".to_string(),
+        )
+        .with_batch_size(1_000)
+        .with_max_buffered_bytes(batch_bytes * 2);
+
+        let mut writer = BatchedWriter::new(TableName::Audit, schema.clone(), config);
+        writer.write(create_test_batch(&schema)).unwrap();
+        assert_eq!(writer.buffered_bytes(), batch_bytes);
+        assert!(writer.output_path().is_none(), "below both limits");
+
+        writer.write(create_test_batch(&schema)).unwrap();
+        assert_eq!(writer.buffered_bytes(), 0, "byte limit should flush");
+        assert_eq!(writer.rows_buffered, 0);
+        assert!(writer.output_path().is_some());
+    }
+
     #[test]
     fn test_drop_after_flush_failure_does_not_publish_partial_file() {
         let temp_dir = TempDir::new().unwrap();